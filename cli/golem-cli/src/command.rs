@@ -18,6 +18,7 @@ use crate::command::cloud::CloudSubcommand;
 use crate::command::component::ComponentSubcommand;
 use crate::command::plugin::PluginSubcommand;
 use crate::command::profile::ProfileSubcommand;
+use crate::command::rib::RibSubcommand;
 use crate::command::worker::WorkerSubcommand;
 use crate::config::{BuildProfileName, ProfileName};
 use crate::log::LogColorize;
@@ -539,6 +540,15 @@ pub enum GolemCliSubcommand {
         component_name: ComponentOptionalComponentName,
         /// Optional component version to use, defaults to latest component version
         version: Option<u64>,
+        /// Execute a Rib script file non-interactively instead of starting the REPL,
+        /// exiting with a non-zero exit code if the script fails
+        #[arg(long)]
+        script: Option<PathBuf>,
+    },
+    /// Test Rib scripts without running workers
+    Rib {
+        #[clap(subcommand)]
+        subcommand: RibSubcommand,
     },
     /// Generate shell completion
     Completion {
@@ -994,9 +1004,15 @@ pub mod worker {
         ComponentOptionalComponentName, NewWorkerArgument, StreamArgs, WorkerFunctionArgument,
         WorkerFunctionName, WorkerNameArg,
     };
-    use crate::model::{IdempotencyKey, WorkerUpdateMode};
+    use crate::command::worker::bulk::WorkerBulkSubcommand;
+    use crate::command::worker::ttl::WorkerTtlSubcommand;
+    use crate::model::{IdempotencyKey, WorkerName, WorkerUpdateMode};
+    use chrono::{DateTime, Utc};
     use clap::Subcommand;
     use golem_client::model::ScanCursor;
+    use golem_common::model::oplog::InvocationPriority;
+    use std::path::PathBuf;
+    use uuid::Uuid;
 
     #[derive(Debug, Subcommand)]
     pub enum WorkerSubcommand {
@@ -1009,6 +1025,9 @@ pub mod worker {
             /// Worker environment variables
             #[arg(short, long, value_parser = parse_key_val, value_name = "ENV=VAL")]
             env: Vec<(String, String)>,
+            /// Worker labels, can be used to filter workers
+            #[arg(long = "label", value_parser = parse_key_val, value_name = "KEY=VAL")]
+            labels: Vec<(String, String)>,
         },
         // TODO: json args
         /// Invoke (or enqueue invocation for) worker
@@ -1025,6 +1044,9 @@ pub mod worker {
             /// Set idempotency key for the call, use "-" for auto generated key
             #[clap(long, short)]
             idempotency_key: Option<IdempotencyKey>,
+            /// Priority of the invocation in the worker's queue of pending invocations: low, normal, high or urgent
+            #[clap(long)]
+            priority: Option<InvocationPriority>,
             #[clap(long, short)]
             /// Connect to the worker before invoke (the worker must already exist)
             /// and live stream its standard output, error and log channels
@@ -1048,7 +1070,8 @@ pub mod worker {
             component_name: ComponentOptionalComponentName,
             /// Filter for worker metadata in form of `property op value`.
             ///
-            /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`.
+            /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`,
+            /// `label.team = payments`.
             /// Can be used multiple times (AND condition is applied between them)
             #[arg(long)]
             filter: Vec<String>,
@@ -1114,6 +1137,15 @@ pub mod worker {
             #[arg(long, conflicts_with = "from")]
             query: Option<String>,
         },
+        /// Extracts the outgoing HTTP requests and responses recorded in a worker's oplog
+        /// into an HTTP fixture file, which executors can serve requests from
+        HttpFixtures {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Path of the fixture file to write
+            #[arg(long, short)]
+            output: PathBuf,
+        },
         /// Reverts a worker by undoing its last recorded operations
         Revert {
             #[command(flatten)]
@@ -1125,6 +1157,27 @@ pub mod worker {
             #[arg(long, conflicts_with = "last_oplog_index")]
             number_of_invocations: Option<u64>,
         },
+        /// Creates a new worker by copying the oplog of an existing one, up to an optional oplog index
+        Fork {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Name of the new worker, created in the same component
+            target_worker_name: WorkerName,
+            /// Index of the last oplog entry to copy. If missing, the whole oplog is copied
+            #[arg(long)]
+            oplog_index: Option<u64>,
+        },
+        /// Sets or removes labels of a worker
+        Labels {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Labels to set, overwriting existing values with the same key
+            #[arg(long, value_parser = parse_key_val, value_name = "KEY=VAL")]
+            set: Vec<(String, String)>,
+            /// Keys of labels to remove
+            #[arg(long, value_name = "KEY")]
+            remove: Vec<String>,
+        },
         /// Cancels an enqueued invocation if it has not started yet
         CancelInvocation {
             #[command(flatten)]
@@ -1132,6 +1185,236 @@ pub mod worker {
             /// Idempotency key of the invocation to be cancelled
             idempotency_key: IdempotencyKey,
         },
+        /// Gets the state and result of an invocation
+        InvocationResult {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Idempotency key of the invocation
+            idempotency_key: IdempotencyKey,
+            /// Wait at most this many seconds for the invocation to finish
+            #[arg(long, short)]
+            wait: Option<u64>,
+        },
+        /// Lists the pending invocations of a worker, in the order they are going to be processed
+        Invocations {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+        },
+        /// Changes the priority of a pending invocation
+        SetInvocationPriority {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Idempotency key of the invocation
+            idempotency_key: IdempotencyKey,
+            /// New priority of the invocation: low, normal, high or urgent
+            priority: InvocationPriority,
+        },
+        /// Cancels all the pending invocations of a worker which have not started yet
+        PurgeInvocations {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+        },
+        /// Lists the sagas coordinated by a worker
+        Sagas {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+        },
+        /// Aborts a saga coordinated by a worker, compensating its performed steps.
+        /// Aborting a saga whose compensation failed retries the compensation.
+        AbortSaga {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// ID of the saga to be aborted
+            saga_id: Uuid,
+            /// Reason of the abort, recorded on the saga
+            #[arg(long)]
+            reason: Option<String>,
+        },
+        /// Schedules an invocation of a worker function, either once or recurring by a cron expression
+        Schedule {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Worker function name to invoke
+            function_name: WorkerFunctionName,
+            /// Worker function arguments in WAVE format
+            arguments: Vec<WorkerFunctionArgument>,
+            /// Invoke the function once, at the given point in time (RFC 3339)
+            #[arg(long, conflicts_with = "cron", required_unless_present = "cron")]
+            at: Option<DateTime<Utc>>,
+            /// Invoke the function at every point in time matching the given cron expression (UTC)
+            #[arg(long)]
+            cron: Option<String>,
+        },
+        /// Lists the invocation schedules of a worker
+        Schedules {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+        },
+        /// Lists the promises of a worker which are not completed yet
+        Promises {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+        },
+        /// Cancels an invocation schedule
+        CancelSchedule {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// ID of the schedule to be cancelled
+            schedule_id: Uuid,
+        },
+        /// Runs an operation on all workers of a component matching a filter, as a background job
+        Bulk {
+            #[command(subcommand)]
+            subcommand: WorkerBulkSubcommand,
+        },
+        /// Manages time-to-live policies, which delete idle or exited workers automatically
+        Ttl {
+            #[command(subcommand)]
+            subcommand: WorkerTtlSubcommand,
+        },
+    }
+
+    pub mod bulk {
+        use crate::command::shared_args::{
+            ComponentOptionalComponentName, WorkerFunctionArgument, WorkerFunctionName,
+        };
+        use crate::model::WorkerUpdateMode;
+        use clap::{Args, Subcommand};
+        use uuid::Uuid;
+
+        #[derive(Debug, Args)]
+        pub struct WorkerSelectionArgs {
+            #[command(flatten)]
+            pub component_name: ComponentOptionalComponentName,
+            /// Filter for selecting workers in form of `property op value`, see `worker list`.
+            /// Can be used multiple times (AND condition is applied between them),
+            /// if not specified, all workers of the component are selected
+            #[arg(long)]
+            pub filter: Vec<String>,
+            /// The maximum number of workers processed at the same time
+            #[arg(long)]
+            pub concurrency: Option<u32>,
+        }
+
+        #[derive(Debug, Subcommand)]
+        pub enum WorkerBulkSubcommand {
+            /// Updates the selected workers
+            Update {
+                #[command(flatten)]
+                selection: WorkerSelectionArgs,
+                /// Update mode - auto or manual (default is auto)
+                #[arg(long)]
+                mode: Option<WorkerUpdateMode>,
+                /// The new version of the updated workers (default is the latest version)
+                #[arg(long)]
+                target_version: Option<u64>,
+            },
+            /// Interrupts the selected workers
+            Interrupt {
+                #[command(flatten)]
+                selection: WorkerSelectionArgs,
+                /// Simulate a crash instead, so the workers start recovering immediately
+                #[arg(long, default_value_t = false)]
+                recover_immediately: bool,
+            },
+            /// Resumes the selected workers
+            Resume {
+                #[command(flatten)]
+                selection: WorkerSelectionArgs,
+                /// Resume the workers even if they are not suspended, interrupted or idle
+                #[arg(long, default_value_t = false)]
+                force: bool,
+            },
+            /// Deletes the selected workers
+            Delete {
+                #[command(flatten)]
+                selection: WorkerSelectionArgs,
+            },
+            /// Enqueues an invocation on each of the selected workers
+            Invoke {
+                #[command(flatten)]
+                selection: WorkerSelectionArgs,
+                /// Worker function name to invoke
+                #[arg(long)]
+                function: WorkerFunctionName,
+                /// Worker function arguments in WAVE format, can be used multiple times
+                #[arg(long = "arg")]
+                arguments: Vec<WorkerFunctionArgument>,
+            },
+            /// Gets the progress and the per-worker results of a bulk operation
+            Get {
+                /// ID of the bulk operation
+                operation_id: Uuid,
+                #[command(flatten)]
+                component_name: ComponentOptionalComponentName,
+            },
+            /// Lists the bulk operations of a component
+            List {
+                #[command(flatten)]
+                component_name: ComponentOptionalComponentName,
+            },
+        }
+    }
+
+    pub mod ttl {
+        use crate::command::shared_args::{ComponentOptionalComponentName, WorkerNameArg};
+        use clap::{Args, Subcommand};
+
+        #[derive(Debug, Args)]
+        pub struct WorkerTtlPolicyArgs {
+            /// Delete the workers once they have been idle or exited for this long, e.g. `30m` or `7days`
+            #[arg(long)]
+            pub max_idle_time: Option<humantime::Duration>,
+            /// Delete the workers once they are older than this, e.g. `30days`
+            #[arg(long)]
+            pub max_age: Option<humantime::Duration>,
+            /// Delete the workers as soon as they exit
+            #[arg(long, default_value_t = false)]
+            pub delete_on_exit: bool,
+        }
+
+        #[derive(Debug, Subcommand)]
+        pub enum WorkerTtlSubcommand {
+            /// Sets the time-to-live policy of a worker, overriding the policy of its component
+            Set {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+                #[command(flatten)]
+                policy: WorkerTtlPolicyArgs,
+            },
+            /// Gets the time-to-live policy set on a worker
+            Get {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+            },
+            /// Removes the time-to-live policy of a worker, so the policy of its component applies
+            Remove {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+            },
+            /// Sets the time-to-live policy of all workers of a component
+            SetComponent {
+                #[command(flatten)]
+                component_name: ComponentOptionalComponentName,
+                #[command(flatten)]
+                policy: WorkerTtlPolicyArgs,
+            },
+            /// Gets the time-to-live policy of a component
+            GetComponent {
+                #[command(flatten)]
+                component_name: ComponentOptionalComponentName,
+            },
+            /// Removes the time-to-live policy of a component
+            RemoveComponent {
+                #[command(flatten)]
+                component_name: ComponentOptionalComponentName,
+            },
+            /// Lists the workers of a component deleted by their time-to-live policy
+            Deletions {
+                #[command(flatten)]
+                component_name: ComponentOptionalComponentName,
+            },
+        }
     }
 }
 
@@ -1455,6 +1738,20 @@ pub mod plugin {
     }
 }
 
+pub mod rib {
+    use clap::Subcommand;
+    use std::path::PathBuf;
+
+    #[derive(Debug, Subcommand)]
+    pub enum RibSubcommand {
+        /// Run Rib test files (`*.rib.test`), where worker function calls are served from mocks
+        Test {
+            /// Rib test files or directories containing them, defaults to the current directory
+            paths: Vec<PathBuf>,
+        },
+    }
+}
+
 pub mod profile {
     use crate::command::profile::config::ProfileConfigSubcommand;
     use crate::config::ProfileName;
@@ -606,7 +606,7 @@ impl ApiDefinitionCommandHandler {
         let manifest_api_definition = DiffableHttpApiDefinition::from_manifest(
             server_diffable_api_definition.as_ref(),
             api_definition_name,
-            &api_definition.value,
+            api_definition,
             latest_component_versions,
         )?;
 
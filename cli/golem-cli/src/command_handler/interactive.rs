@@ -109,6 +109,30 @@ impl InteractiveHandler {
         )
     }
 
+    pub fn confirm_bulk_delete_workers(
+        &self,
+        component_name: &ComponentName,
+        filters: &[String],
+    ) -> anyhow::Result<bool> {
+        let selection = if filters.is_empty() {
+            "all workers".log_color_warn().to_string()
+        } else {
+            format!(
+                "all workers matching {}",
+                filters.join(" AND ").log_color_highlight()
+            )
+        };
+        self.confirm(
+            false,
+            format!(
+                "Are you sure you want to delete {} of component {}?",
+                selection,
+                component_name.0.log_color_highlight()
+            ),
+            None,
+        )
+    }
+
     pub fn confirm_undeploy_api_from_sites_for_redeploy(
         &self,
         api: &str,
@@ -43,6 +43,7 @@ use crate::command_handler::partial_match::ErrorHandler;
 use crate::command_handler::plugin::PluginCommandHandler;
 use crate::command_handler::profile::config::ProfileConfigCommandHandler;
 use crate::command_handler::profile::ProfileCommandHandler;
+use crate::command_handler::rib::RibCommandHandler;
 use crate::command_handler::rib_repl::RibReplHandler;
 use crate::command_handler::worker::WorkerCommandHandler;
 use crate::context::Context;
@@ -71,6 +72,7 @@ mod log;
 mod partial_match;
 mod plugin;
 mod profile;
+mod rib;
 mod rib_repl;
 mod worker;
 
@@ -345,12 +347,16 @@ impl<Hooks: CommandHandlerHooks + 'static> CommandHandler<Hooks> {
             GolemCliSubcommand::Repl {
                 component_name,
                 version,
+                script,
             } => {
                 self.ctx
                     .rib_repl_handler()
-                    .cmd_repl(component_name.component_name, version)
+                    .cmd_repl(component_name.component_name, version, script)
                     .await
             }
+            GolemCliSubcommand::Rib { subcommand } => {
+                self.ctx.rib_handler().handle_command(subcommand).await
+            }
             GolemCliSubcommand::Completion { shell } => self.cmd_completion(shell),
         }
     }
@@ -392,6 +398,7 @@ pub trait Handlers {
     fn plugin_handler(&self) -> PluginCommandHandler;
     fn profile_config_handler(&self) -> ProfileConfigCommandHandler;
     fn profile_handler(&self) -> ProfileCommandHandler;
+    fn rib_handler(&self) -> RibCommandHandler;
     fn rib_repl_handler(&self) -> RibReplHandler;
     fn worker_handler(&self) -> WorkerCommandHandler;
 }
@@ -493,6 +500,10 @@ impl Handlers for Arc<Context> {
         ProfileCommandHandler::new(self.clone())
     }
 
+    fn rib_handler(&self) -> RibCommandHandler {
+        RibCommandHandler::new(self.clone())
+    }
+
     fn rib_repl_handler(&self) -> RibReplHandler {
         RibReplHandler::new(self.clone())
     }
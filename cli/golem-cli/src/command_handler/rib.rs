@@ -0,0 +1,213 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::rib::RibSubcommand;
+use crate::context::Context;
+use crate::error::NonSuccessfulExit;
+use crate::fs;
+use crate::log::{log_action, log_error_action, logln, LogColorize, LogIndent};
+use anyhow::{anyhow, bail};
+use golem_common::model::component_metadata::ComponentMetadata;
+use rib::{
+    ComponentDependency, ComponentDependencyKey, RibCompiler, RibCompilerConfig, RibTestCase,
+    RibTestOutcome,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use uuid::Uuid;
+use walkdir::WalkDir;
+
+const RIB_TEST_FILE_SUFFIX: &str = ".rib.test";
+
+/// A Rib test file (`*.rib.test`), in YAML format.
+///
+/// The Rib script is either inlined (`rib`), or loaded from a file (`ribFile`), and
+/// the types of worker functions are taken from the component WASM (`componentWasm`).
+/// Paths are relative to the test file.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RibTestFile {
+    #[serde(default)]
+    component_name: Option<String>,
+    #[serde(default)]
+    component_wasm: Option<String>,
+    #[serde(default)]
+    rib: Option<String>,
+    #[serde(default)]
+    rib_file: Option<String>,
+    tests: Vec<RibTestCase>,
+}
+
+pub struct RibCommandHandler {
+    #[allow(unused)]
+    ctx: Arc<Context>,
+}
+
+impl RibCommandHandler {
+    pub fn new(ctx: Arc<Context>) -> Self {
+        Self { ctx }
+    }
+
+    pub async fn handle_command(&self, subcommand: RibSubcommand) -> anyhow::Result<()> {
+        match subcommand {
+            RibSubcommand::Test { paths } => self.cmd_test(paths).await,
+        }
+    }
+
+    async fn cmd_test(&self, paths: Vec<PathBuf>) -> anyhow::Result<()> {
+        let test_files = collect_test_files(paths);
+
+        if test_files.is_empty() {
+            bail!("No Rib test files ({RIB_TEST_FILE_SUFFIX}) found");
+        }
+
+        let mut passed = 0;
+        let mut failed = 0;
+
+        for test_file in &test_files {
+            log_action(
+                "Running",
+                format!(
+                    "Rib tests in {}",
+                    test_file.display().to_string().log_color_highlight()
+                ),
+            );
+            let _indent = LogIndent::new();
+
+            let (rib, compiler, test_cases) = match load_test_file(test_file) {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    log_error_action("Failed", format!("to load the test file: {err:#}"));
+                    failed += 1;
+                    continue;
+                }
+            };
+
+            for test_case in test_cases {
+                match test_case.run(&rib, &compiler).await {
+                    RibTestOutcome::Passed => {
+                        logln(format!(
+                            "{} {}",
+                            "✔".log_color_ok_highlight(),
+                            test_case.name
+                        ));
+                        passed += 1;
+                    }
+                    RibTestOutcome::Failed(reason) => {
+                        logln(format!(
+                            "{} {}: {}",
+                            "✘".log_color_error_highlight(),
+                            test_case.name,
+                            reason.log_color_warn()
+                        ));
+                        failed += 1;
+                    }
+                }
+            }
+        }
+
+        logln("");
+        logln(format!(
+            "Rib tests: {} passed, {} failed",
+            passed.to_string().log_color_ok_highlight(),
+            failed.to_string().log_color_error_highlight()
+        ));
+
+        if failed > 0 {
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+}
+
+fn collect_test_files(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let paths = if paths.is_empty() {
+        vec![PathBuf::from(".")]
+    } else {
+        paths
+    };
+
+    let mut test_files = paths
+        .into_iter()
+        .flat_map(|path| {
+            if path.is_dir() {
+                WalkDir::new(path)
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.into_path())
+                    .filter(|path| {
+                        path.is_file() && path.to_string_lossy().ends_with(RIB_TEST_FILE_SUFFIX)
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                vec![path]
+            }
+        })
+        .collect::<Vec<_>>();
+
+    test_files.sort();
+    test_files.dedup();
+    test_files
+}
+
+fn load_test_file(path: &Path) -> anyhow::Result<(String, RibCompiler, Vec<RibTestCase>)> {
+    let test_file: RibTestFile = serde_yaml::from_str(&fs::read_to_string(path)?)?;
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+
+    let rib = match (test_file.rib, test_file.rib_file) {
+        (Some(rib), None) => rib,
+        (None, Some(rib_file)) => fs::read_to_string(base_dir.join(rib_file))?,
+        _ => bail!("exactly one of rib or ribFile is required"),
+    };
+
+    let rib = rib::resolve_imports(&rib, &rib::FileSystemImportResolver::new(base_dir))
+        .map_err(|err| anyhow!(err))?;
+
+    let component_dependencies = match test_file.component_wasm {
+        Some(component_wasm) => {
+            let component_wasm = base_dir.join(component_wasm);
+            let metadata = ComponentMetadata::analyse_component(
+                &fs::read(&component_wasm)?,
+                HashMap::new(),
+                vec![],
+            )
+            .map_err(|err| anyhow!("failed to analyse {}: {err}", component_wasm.display()))?;
+
+            let component_name = test_file.component_name.unwrap_or_else(|| {
+                component_wasm
+                    .file_stem()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default()
+            });
+
+            vec![ComponentDependency::new(
+                ComponentDependencyKey {
+                    component_name,
+                    component_id: Uuid::nil(),
+                    root_package_name: metadata.root_package_name().clone(),
+                    root_package_version: metadata.root_package_version().clone(),
+                },
+                metadata.exports().to_vec(),
+            )]
+        }
+        None => vec![],
+    };
+
+    let compiler = RibCompiler::new(RibCompilerConfig::new(component_dependencies, vec![]));
+
+    Ok((rib, compiler, test_file.tests))
+}
@@ -23,13 +23,14 @@ use crate::model::{ComponentName, ComponentNameMatchKind, IdempotencyKey, Worker
 use anyhow::bail;
 use async_trait::async_trait;
 use golem_rib_repl::{
-    ReplComponentDependencies, RibDependencyManager, RibRepl, RibReplConfig, WorkerFunctionInvoke,
+    DefaultReplResultPrinter, ReplComponentDependencies, ReplPrinter, RibDependencyManager,
+    RibRepl, RibReplConfig, WorkerFunctionInvoke,
 };
 use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::json::OptionallyValueAndTypeJson;
 use golem_wasm_rpc::ValueAndType;
 use rib::{ComponentDependency, ComponentDependencyKey};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -47,6 +48,7 @@ impl RibReplHandler {
         &self,
         component_name: Option<ComponentName>,
         component_version: Option<u64>,
+        script: Option<PathBuf>,
     ) -> anyhow::Result<()> {
         let selected_components = self
             .ctx
@@ -104,6 +106,23 @@ impl RibReplHandler {
         })
         .await?;
 
+        if let Some(script) = script {
+            let printer = DefaultReplResultPrinter;
+
+            return match repl.run_script(&script).await {
+                Ok(result) => {
+                    if let Some(result) = result {
+                        printer.print_rib_result(&result);
+                    }
+                    Ok(())
+                }
+                Err(err) => {
+                    printer.print_rib_execution_error(&err);
+                    bail!(NonSuccessfulExit)
+                }
+            };
+        }
+
         logln("");
 
         self.ctx
@@ -177,6 +196,7 @@ impl WorkerFunctionInvoke for RibReplHandler {
                 function_name,
                 arguments,
                 IdempotencyKey::new(),
+                None,
                 false,
                 None,
             )
@@ -0,0 +1,306 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::{WorkerFunctionArgument, WorkerFunctionName};
+use crate::command::worker::bulk::{WorkerBulkSubcommand, WorkerSelectionArgs};
+use crate::command_handler::worker::{
+    resolve_function_name, wave_args_to_invoke_args, WorkerCommandHandler,
+};
+use crate::command_handler::Handlers;
+use crate::error::service::AnyhowMapServiceError;
+use crate::error::NonSuccessfulExit;
+use crate::log::{log_action, log_warn_action, logln, LogColorize};
+use crate::model::component::Component;
+use crate::model::text::fmt::log_error;
+use crate::model::text::worker::format_bulk_operation_kind;
+use crate::model::{ComponentName, WorkerUpdateMode};
+use anyhow::{anyhow, bail};
+use golem_client::api::WorkerClient;
+use golem_client::model::{
+    BulkDeleteOperation, BulkInterruptOperation, BulkInvokeOperation, BulkOperationKind,
+    BulkOperationRequest, BulkResumeOperation, BulkUpdateOperation,
+};
+use golem_common::model::WorkerFilter;
+use itertools::Itertools;
+use uuid::Uuid;
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_bulk(&self, subcommand: WorkerBulkSubcommand) -> anyhow::Result<()> {
+        match subcommand {
+            WorkerBulkSubcommand::Update {
+                selection,
+                mode,
+                target_version,
+            } => {
+                self.cmd_bulk_update(
+                    selection,
+                    mode.unwrap_or(WorkerUpdateMode::Automatic),
+                    target_version,
+                )
+                .await
+            }
+            WorkerBulkSubcommand::Interrupt {
+                selection,
+                recover_immediately,
+            } => {
+                self.start_bulk_operation(selection, |_| {
+                    Ok(BulkOperationKind::BulkInterruptOperation(
+                        BulkInterruptOperation {
+                            recover_immediately: Some(recover_immediately),
+                        },
+                    ))
+                })
+                .await
+            }
+            WorkerBulkSubcommand::Resume { selection, force } => {
+                self.start_bulk_operation(selection, |_| {
+                    Ok(BulkOperationKind::BulkResumeOperation(
+                        BulkResumeOperation { force: Some(force) },
+                    ))
+                })
+                .await
+            }
+            WorkerBulkSubcommand::Delete { selection } => self.cmd_bulk_delete(selection).await,
+            WorkerBulkSubcommand::Invoke {
+                selection,
+                function,
+                arguments,
+            } => self.cmd_bulk_invoke(selection, &function, arguments).await,
+            WorkerBulkSubcommand::Get {
+                operation_id,
+                component_name,
+            } => {
+                self.cmd_bulk_get(component_name.component_name, operation_id)
+                    .await
+            }
+            WorkerBulkSubcommand::List { component_name } => {
+                self.cmd_bulk_list(component_name.component_name).await
+            }
+        }
+    }
+
+    async fn cmd_bulk_update(
+        &self,
+        selection: WorkerSelectionArgs,
+        mode: WorkerUpdateMode,
+        target_version: Option<u64>,
+    ) -> anyhow::Result<()> {
+        self.start_bulk_operation(selection, |component| {
+            Ok(BulkOperationKind::BulkUpdateOperation(
+                BulkUpdateOperation {
+                    mode: match mode {
+                        WorkerUpdateMode::Automatic => {
+                            golem_client::model::WorkerUpdateMode::Automatic
+                        }
+                        WorkerUpdateMode::Manual => golem_client::model::WorkerUpdateMode::Manual,
+                    },
+                    target_version: target_version
+                        .unwrap_or(component.versioned_component_id.version),
+                },
+            ))
+        })
+        .await
+    }
+
+    async fn cmd_bulk_delete(&self, selection: WorkerSelectionArgs) -> anyhow::Result<()> {
+        let component = self
+            .bulk_target_component(selection.component_name.component_name.as_ref())
+            .await?;
+
+        if !self
+            .ctx
+            .interactive_handler()
+            .confirm_bulk_delete_workers(&component.component_name, &selection.filter)?
+        {
+            bail!(NonSuccessfulExit);
+        }
+
+        self.start_bulk_operation_on(&component, selection, |_| {
+            Ok(BulkOperationKind::BulkDeleteOperation(
+                BulkDeleteOperation {},
+            ))
+        })
+        .await
+    }
+
+    async fn cmd_bulk_invoke(
+        &self,
+        selection: WorkerSelectionArgs,
+        function_name: &WorkerFunctionName,
+        arguments: Vec<WorkerFunctionArgument>,
+    ) -> anyhow::Result<()> {
+        self.start_bulk_operation(selection, |component| {
+            let function =
+                resolve_function_name(component, &component.component_name, function_name)?;
+            let params = wave_args_to_invoke_args(component, &function, arguments)?;
+            Ok(BulkOperationKind::BulkInvokeOperation(
+                BulkInvokeOperation { function, params },
+            ))
+        })
+        .await
+    }
+
+    async fn cmd_bulk_get(
+        &self,
+        component_name: Option<ComponentName>,
+        operation_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let component = self.bulk_target_component(component_name.as_ref()).await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_bulk_operation(
+                &component.versioned_component_id.component_id,
+                &operation_id,
+            )
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_bulk_list(&self, component_name: Option<ComponentName>) -> anyhow::Result<()> {
+        let component = self.bulk_target_component(component_name.as_ref()).await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_bulk_operations(&component.versioned_component_id.component_id)
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result.operations);
+
+        Ok(())
+    }
+
+    async fn start_bulk_operation(
+        &self,
+        selection: WorkerSelectionArgs,
+        operation: impl FnOnce(&Component) -> anyhow::Result<BulkOperationKind>,
+    ) -> anyhow::Result<()> {
+        let component = self
+            .bulk_target_component(selection.component_name.component_name.as_ref())
+            .await?;
+
+        self.start_bulk_operation_on(&component, selection, operation)
+            .await
+    }
+
+    async fn start_bulk_operation_on(
+        &self,
+        component: &Component,
+        selection: WorkerSelectionArgs,
+        operation: impl FnOnce(&Component) -> anyhow::Result<BulkOperationKind>,
+    ) -> anyhow::Result<()> {
+        let filter = if selection.filter.is_empty() {
+            None
+        } else {
+            Some(WorkerFilter::from(selection.filter.clone()).map_err(|err| anyhow!(err))?)
+        };
+        let operation = operation(component)?;
+
+        log_warn_action(
+            "Starting",
+            format!(
+                "bulk {} on workers of component {}{}",
+                format_bulk_operation_kind(&operation).log_color_highlight(),
+                component.component_name.0.log_color_highlight(),
+                if selection.filter.is_empty() {
+                    "".to_string()
+                } else {
+                    format!(
+                        " matching {}",
+                        selection.filter.iter().join(" AND ").log_color_highlight()
+                    )
+                }
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .start_bulk_operation(
+                &component.versioned_component_id.component_id,
+                &BulkOperationRequest {
+                    filter,
+                    operation,
+                    concurrency: selection.concurrency,
+                },
+            )
+            .await
+            .map_service_error()?;
+
+        log_action("Started", "bulk operation");
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    /// Bulk operations are started on a single component, so the selection must not be ambiguous
+    async fn bulk_target_component(
+        &self,
+        component_name: Option<&ComponentName>,
+    ) -> anyhow::Result<Component> {
+        self.ctx.silence_app_context_init().await;
+
+        let selected_components = self
+            .ctx
+            .component_handler()
+            .must_select_components_by_app_dir_or_name(component_name)
+            .await?;
+
+        if selected_components.component_names.len() != 1 {
+            log_error(format!(
+                "Bulk operations require a single component, but multiple components are selected! ({})",
+                selected_components
+                    .component_names
+                    .iter()
+                    .map(|cn| cn.0.log_color_highlight())
+                    .join(", ")
+            ));
+            logln("");
+            logln("Switch to an application directory with only one component or explicitly specify the requested component name.");
+            logln("");
+            bail!(NonSuccessfulExit);
+        }
+
+        let component_name = &selected_components.component_names[0];
+        match self
+            .ctx
+            .component_handler()
+            .component(
+                selected_components.project.as_ref(),
+                component_name.into(),
+                None,
+            )
+            .await?
+        {
+            Some(component) => Ok(component),
+            None => {
+                log_error(format!(
+                    "Component not found: {}",
+                    component_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+}
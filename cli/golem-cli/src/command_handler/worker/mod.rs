@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bulk;
 mod stream;
 mod stream_output;
+mod ttl;
 
 use crate::command::shared_args::{
     NewWorkerArgument, StreamArgs, WorkerFunctionArgument, WorkerFunctionName, WorkerNameArg,
@@ -24,6 +26,7 @@ use crate::command_handler::Handlers;
 use crate::context::Context;
 use crate::error::service::{AnyhowMapServiceError, ServiceError};
 use crate::error::NonSuccessfulExit;
+use crate::fs;
 use crate::fuzzy::{Error, FuzzySearch};
 use crate::log::{log_action, log_error_action, log_warn_action, logln, LogColorize, LogIndent};
 use crate::model::app::ApplicationComponentSelectMode;
@@ -45,17 +48,24 @@ use crate::model::{
     WorkersMetadataResponseView,
 };
 use anyhow::{anyhow, bail};
+use chrono::{DateTime, Utc};
 use colored::Colorize;
 use golem_client::api::WorkerClient;
 use golem_client::model::{
-    ComponentType, InvokeResult, PublicOplogEntry, ScanCursor, UpdateRecord,
+    AbortSagaRequest, ChangeInvocationPriorityRequest, ComponentType, CronInvocationSchedule,
+    InvocationScheduleSpec, InvocationStatus, InvokeResult, OnceInvocationSchedule,
+    PublicOplogEntry, ScanCursor, ScheduleInvocationRequest, UpdateRecord,
 };
 use golem_client::model::{
-    InvokeParameters as InvokeParametersCloud, RevertLastInvocations as RevertLastInvocationsCloud,
+    ForkWorkerRequest as ForkWorkerRequestCloud, InvokeParameters as InvokeParametersCloud,
+    RevertLastInvocations as RevertLastInvocationsCloud,
     RevertToOplogIndex as RevertToOplogIndexCloud, RevertWorkerTarget as RevertWorkerTargetCloud,
+    UpdateWorkerLabelsRequest as UpdateWorkerLabelsRequestCloud,
     UpdateWorkerRequest as UpdateWorkerRequestCloud,
     WorkerCreationRequest as WorkerCreationRequestCloud,
 };
+use golem_common::model::http_fixture::HttpFixture;
+use golem_common::model::oplog::InvocationPriority;
 use golem_common::model::public_oplog::OplogCursor;
 use golem_common::model::worker::WasiConfigVars;
 use golem_wasm_ast::analysis::AnalysedType;
@@ -63,6 +73,7 @@ use golem_wasm_rpc::json::OptionallyValueAndTypeJson;
 use golem_wasm_rpc::{parse_value_and_type, ValueAndType};
 use itertools::{EitherOrBoth, Itertools};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::timeout;
@@ -83,13 +94,15 @@ impl WorkerCommandHandler {
                 worker_name,
                 arguments,
                 env,
-            } => self.cmd_new(worker_name, arguments, env).await,
+                labels,
+            } => self.cmd_new(worker_name, arguments, env, labels).await,
             WorkerSubcommand::Invoke {
                 worker_name,
                 function_name,
                 arguments,
                 enqueue,
                 idempotency_key,
+                priority,
                 stream,
                 stream_args,
             } => {
@@ -99,6 +112,7 @@ impl WorkerCommandHandler {
                     arguments,
                     enqueue,
                     idempotency_key,
+                    priority,
                     stream,
                     stream_args,
                 )
@@ -150,6 +164,10 @@ impl WorkerCommandHandler {
                 from,
                 query,
             } => self.cmd_oplog(worker_name, from, query).await,
+            WorkerSubcommand::HttpFixtures {
+                worker_name,
+                output,
+            } => self.cmd_http_fixtures(worker_name, output).await,
             WorkerSubcommand::Revert {
                 worker_name,
                 last_oplog_index,
@@ -158,6 +176,19 @@ impl WorkerCommandHandler {
                 self.cmd_revert(worker_name, last_oplog_index, number_of_invocations)
                     .await
             }
+            WorkerSubcommand::Fork {
+                worker_name,
+                target_worker_name,
+                oplog_index,
+            } => {
+                self.cmd_fork(worker_name, target_worker_name, oplog_index)
+                    .await
+            }
+            WorkerSubcommand::Labels {
+                worker_name,
+                set,
+                remove,
+            } => self.cmd_labels(worker_name, set, remove).await,
             WorkerSubcommand::CancelInvocation {
                 worker_name,
                 idempotency_key,
@@ -165,6 +196,52 @@ impl WorkerCommandHandler {
                 self.cmd_cancel_invocation(worker_name, idempotency_key)
                     .await
             }
+            WorkerSubcommand::InvocationResult {
+                worker_name,
+                idempotency_key,
+                wait,
+            } => {
+                self.cmd_invocation_result(worker_name, idempotency_key, wait)
+                    .await
+            }
+            WorkerSubcommand::Invocations { worker_name } => {
+                self.cmd_invocations(worker_name).await
+            }
+            WorkerSubcommand::SetInvocationPriority {
+                worker_name,
+                idempotency_key,
+                priority,
+            } => {
+                self.cmd_set_invocation_priority(worker_name, idempotency_key, priority)
+                    .await
+            }
+            WorkerSubcommand::PurgeInvocations { worker_name } => {
+                self.cmd_purge_invocations(worker_name).await
+            }
+            WorkerSubcommand::Sagas { worker_name } => self.cmd_sagas(worker_name).await,
+            WorkerSubcommand::AbortSaga {
+                worker_name,
+                saga_id,
+                reason,
+            } => self.cmd_abort_saga(worker_name, saga_id, reason).await,
+            WorkerSubcommand::Schedule {
+                worker_name,
+                function_name,
+                arguments,
+                at,
+                cron,
+            } => {
+                self.cmd_schedule(worker_name, &function_name, arguments, at, cron)
+                    .await
+            }
+            WorkerSubcommand::Schedules { worker_name } => self.cmd_schedules(worker_name).await,
+            WorkerSubcommand::Promises { worker_name } => self.cmd_promises(worker_name).await,
+            WorkerSubcommand::CancelSchedule {
+                worker_name,
+                schedule_id,
+            } => self.cmd_cancel_schedule(worker_name, schedule_id).await,
+            WorkerSubcommand::Bulk { subcommand } => self.cmd_bulk(subcommand).await,
+            WorkerSubcommand::Ttl { subcommand } => self.cmd_ttl(subcommand).await,
         }
     }
 
@@ -173,6 +250,7 @@ impl WorkerCommandHandler {
         worker_name: WorkerNameArg,
         arguments: Vec<NewWorkerArgument>,
         env: Vec<(String, String)>,
+        labels: Vec<(String, String)>,
     ) -> anyhow::Result<()> {
         self.ctx.silence_app_context_init().await;
 
@@ -217,6 +295,7 @@ impl WorkerCommandHandler {
             worker_name.clone(),
             arguments,
             env.into_iter().collect(),
+            labels.into_iter().collect(),
         )
         .await?;
 
@@ -236,6 +315,7 @@ impl WorkerCommandHandler {
         arguments: Vec<WorkerFunctionArgument>,
         enqueue: bool,
         idempotency_key: Option<IdempotencyKey>,
+        priority: Option<InvocationPriority>,
         stream: bool,
         stream_args: StreamArgs,
     ) -> anyhow::Result<()> {
@@ -278,58 +358,8 @@ impl WorkerCommandHandler {
             )
             .await?;
 
-        let matched_function_name =
-            fuzzy_match_function_name(function_name, component.metadata.exports());
-        let function_name = match matched_function_name {
-            Ok(match_) => {
-                log_fuzzy_match(&match_);
-                match_.option
-            }
-            Err(error) => {
-                let component_functions =
-                    show_exported_functions(component.metadata.exports(), false);
-
-                match error {
-                    Error::Ambiguous {
-                        highlighted_options,
-                        ..
-                    } => {
-                        logln("");
-                        log_error(format!(
-                            "The requested function name ({}) is ambiguous.",
-                            function_name.log_color_error_highlight()
-                        ));
-                        logln("");
-                        logln("Did you mean one of");
-                        for option in highlighted_options {
-                            logln(format!(" - {}", option.bold()));
-                        }
-                        logln("?");
-                        logln("");
-                        log_text_view(&AvailableFunctionNamesHelp {
-                            component_name: worker_name_match.component_name.0,
-                            function_names: component_functions,
-                        });
-
-                        bail!(NonSuccessfulExit);
-                    }
-                    Error::NotFound { .. } => {
-                        logln("");
-                        log_error(format!(
-                            "The requested function name ({}) was not found.",
-                            function_name.log_color_error_highlight()
-                        ));
-                        logln("");
-                        log_text_view(&AvailableFunctionNamesHelp {
-                            component_name: worker_name_match.component_name.0,
-                            function_names: component_functions,
-                        });
-
-                        bail!(NonSuccessfulExit);
-                    }
-                }
-            }
-        };
+        let function_name =
+            resolve_function_name(&component, &worker_name_match.component_name, function_name)?;
 
         if enqueue {
             log_action(
@@ -360,6 +390,7 @@ impl WorkerCommandHandler {
                 &function_name,
                 arguments,
                 idempotency_key.clone(),
+                priority,
                 enqueue,
                 stream.then_some(stream_args),
             )
@@ -509,6 +540,61 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_http_fixtures(
+        &self,
+        worker_name: WorkerNameArg,
+        output: PathBuf,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let batch_size = self.ctx.http_batch_size();
+        let mut cursor = Option::<OplogCursor>::None;
+        let mut entries = Vec::<PublicOplogEntry>::new();
+        loop {
+            let clients = self.ctx.golem_clients().await?;
+
+            let result = clients
+                .worker
+                .get_oplog(
+                    &component.versioned_component_id.component_id,
+                    &worker_name.0,
+                    None,
+                    batch_size,
+                    cursor.as_ref(),
+                    None,
+                )
+                .await
+                .map_service_error()?;
+
+            entries.extend(result.entries.into_iter().map(|entry| entry.entry));
+            cursor = result.next;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        let fixture = HttpFixture::from_oplog(&entries)
+            .map_err(|err| anyhow!("Failed to extract HTTP fixtures: {err}"))?;
+        fs::write_str(&output, serde_json::to_string_pretty(&fixture)?)?;
+
+        log_action(
+            "Exported",
+            format!(
+                "{} HTTP interaction(s) of worker {} to {}",
+                fixture.interactions.len(),
+                format_worker_name_match(&worker_name_match),
+                output.display().to_string().log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
     async fn cmd_revert(
         &self,
         worker_name: WorkerNameArg,
@@ -572,6 +658,101 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_fork(
+        &self,
+        worker_name: WorkerNameArg,
+        target_worker_name: WorkerName,
+        oplog_index: Option<u64>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_action(
+            "Forking",
+            format!(
+                "worker {} to {}",
+                format_worker_name_match(&worker_name_match),
+                target_worker_name.0.log_color_highlight()
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .fork_worker(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &ForkWorkerRequestCloud {
+                    target_worker_name: target_worker_name.0.clone(),
+                    oplog_index_cutoff: oplog_index,
+                },
+            )
+            .await
+            .map_service_error()?;
+
+        log_action(
+            "Forked",
+            format!(
+                "worker {} at oplog index {}",
+                target_worker_name.0.log_color_highlight(),
+                result.oplog_index_cutoff.to_string().log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn cmd_labels(
+        &self,
+        worker_name: WorkerNameArg,
+        set: Vec<(String, String)>,
+        remove: Vec<String>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_action(
+            "Updating",
+            format!(
+                "labels of worker {}",
+                format_worker_name_match(&worker_name_match)
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        clients
+            .worker
+            .update_worker_labels(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &UpdateWorkerLabelsRequestCloud {
+                    set: set.into_iter().collect(),
+                    remove,
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_service_error()?;
+
+        log_action(
+            "Updated",
+            format!(
+                "labels of worker {}",
+                format_worker_name_match(&worker_name_match)
+            ),
+        );
+
+        Ok(())
+    }
+
     async fn cmd_cancel_invocation(
         &self,
         worker_name: WorkerNameArg,
@@ -615,6 +796,399 @@ impl WorkerCommandHandler {
         Ok(())
     }
 
+    async fn cmd_invocation_result(
+        &self,
+        worker_name: WorkerNameArg,
+        idempotency_key: IdempotencyKey,
+        wait: Option<u64>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        if let Some(wait) = wait {
+            log_action(
+                "Waiting",
+                format!(
+                    "at most {} seconds for the invocation to finish",
+                    wait.to_string().log_color_highlight()
+                ),
+            );
+        }
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let response = clients
+            .worker
+            .get_invocation_result(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &idempotency_key.0,
+                wait.map(|wait| wait * 1000),
+            )
+            .await
+            .map_service_error()?;
+
+        match response.status {
+            InvocationStatus::Pending => {
+                log_action("Pending", "the invocation has not started yet");
+            }
+            InvocationStatus::Running => {
+                log_action("Running", "the invocation has not finished yet");
+            }
+            InvocationStatus::Completed => {
+                log_action("Completed", "invocation");
+                self.ctx
+                    .log_handler()
+                    .log_view(&InvokeResultView::new_invocation_result(
+                        idempotency_key,
+                        response.result,
+                    ));
+            }
+            InvocationStatus::Failed => {
+                log_error(format!(
+                    "The invocation failed: {}",
+                    response.error.unwrap_or_default()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_invocations(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_pending_invocations(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result.invocations);
+
+        Ok(())
+    }
+
+    async fn cmd_set_invocation_priority(
+        &self,
+        worker_name: WorkerNameArg,
+        idempotency_key: IdempotencyKey,
+        priority: InvocationPriority,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_action(
+            "Changing invocation priority",
+            format!(
+                "to {} for worker {} using idempotency key: {}",
+                priority.to_string().log_color_highlight(),
+                format_worker_name_match(&worker_name_match),
+                idempotency_key.0.log_color_highlight()
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let changed = clients
+            .worker
+            .change_invocation_priority(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &idempotency_key.0,
+                &ChangeInvocationPriorityRequest { priority },
+            )
+            .await
+            .map(|result| result.changed)
+            .map_service_error()?;
+
+        if changed {
+            log_action("Changed", "invocation priority");
+        } else {
+            log_warn_action("Failed", "to change priority, invocation already started");
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_purge_invocations(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_warn_action(
+            "Purging pending invocations",
+            format!("of worker {}", format_worker_name_match(&worker_name_match)),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let canceled = clients
+            .worker
+            .purge_pending_invocations(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map(|result| result.canceled)
+            .map_service_error()?;
+
+        log_action(
+            "Canceled",
+            format!(
+                "{} pending invocation(s)",
+                canceled.len().to_string().log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+        for idempotency_key in canceled {
+            logln(idempotency_key);
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_sagas(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_sagas(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result.sagas);
+
+        Ok(())
+    }
+
+    async fn cmd_abort_saga(
+        &self,
+        worker_name: WorkerNameArg,
+        saga_id: Uuid,
+        reason: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_warn_action(
+            "Aborting saga",
+            format!(
+                "{} of worker {}",
+                saga_id.to_string().log_color_highlight(),
+                format_worker_name_match(&worker_name_match),
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let saga = clients
+            .worker
+            .abort_saga(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &saga_id,
+                &AbortSagaRequest { reason },
+            )
+            .await
+            .map(|result| result.saga)
+            .map_service_error()?;
+
+        let remaining = (saga.compensations.len() as u32).saturating_sub(saga.compensated);
+        if remaining == 0 {
+            log_action("Aborted", "saga, no compensations to perform");
+        } else {
+            log_action(
+                "Aborted",
+                format!(
+                    "saga, performing {} compensation(s) in the background",
+                    remaining.to_string().log_color_highlight()
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_schedule(
+        &self,
+        worker_name: WorkerNameArg,
+        function_name: &WorkerFunctionName,
+        arguments: Vec<WorkerFunctionArgument>,
+        at: Option<DateTime<Utc>>,
+        cron: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let function_name =
+            resolve_function_name(&component, &worker_name_match.component_name, function_name)?;
+        let arguments = wave_args_to_invoke_args(&component, &function_name, arguments)?;
+
+        let schedule = match (at, cron) {
+            (Some(at), None) => {
+                InvocationScheduleSpec::OnceInvocationSchedule(OnceInvocationSchedule { at })
+            }
+            (None, Some(expression)) => {
+                InvocationScheduleSpec::CronInvocationSchedule(CronInvocationSchedule {
+                    expression,
+                })
+            }
+            _ => bail!("Expected either at or cron"),
+        };
+
+        log_action(
+            "Scheduling",
+            format!(
+                "invocation for worker {}/{}",
+                format_worker_name_match(&worker_name_match),
+                format_export(&function_name)
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .schedule_invocation(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &function_name,
+                &ScheduleInvocationRequest {
+                    params: arguments,
+                    schedule,
+                },
+            )
+            .await
+            .map_service_error()?;
+
+        log_action("Scheduled", "invocation");
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_schedules(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_invocation_schedules(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result.schedules);
+
+        Ok(())
+    }
+
+    async fn cmd_promises(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_pending_promises(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result.promises);
+
+        Ok(())
+    }
+
+    async fn cmd_cancel_schedule(
+        &self,
+        worker_name: WorkerNameArg,
+        schedule_id: Uuid,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_warn_action(
+            "Canceling schedule",
+            format!(
+                "{} of worker {}",
+                schedule_id.to_string().log_color_highlight(),
+                format_worker_name_match(&worker_name_match),
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let canceled = clients
+            .worker
+            .cancel_invocation_schedule(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &schedule_id,
+            )
+            .await
+            .map(|result| result.canceled)
+            .map_service_error()?;
+
+        if canceled {
+            log_action("Canceled", "");
+        } else {
+            log_warn_action("Failed", "to cancel, schedule not found");
+        }
+
+        Ok(())
+    }
+
     async fn cmd_list(
         &self,
         component_name: Option<ComponentName>,
@@ -851,6 +1425,7 @@ impl WorkerCommandHandler {
         worker_name: String,
         args: Vec<String>,
         env: HashMap<String, String>,
+        labels: HashMap<String, String>,
     ) -> anyhow::Result<()> {
         let clients = self.ctx.golem_clients().await?;
 
@@ -863,6 +1438,7 @@ impl WorkerCommandHandler {
                     args,
                     env,
                     wasi_config_vars: WasiConfigVars::default(),
+                    labels,
                 },
             )
             .await
@@ -877,6 +1453,7 @@ impl WorkerCommandHandler {
         function_name: &str,
         arguments: Vec<OptionallyValueAndTypeJson>,
         idempotency_key: IdempotencyKey,
+        priority: Option<InvocationPriority>,
         enqueue: bool,
         stream_args: Option<StreamArgs>,
     ) -> anyhow::Result<Option<InvokeResult>> {
@@ -921,6 +1498,7 @@ impl WorkerCommandHandler {
                             &worker_name.0,
                             Some(&idempotency_key.0),
                             function_name,
+                            priority.as_ref(),
                             &InvokeParametersCloud { params: arguments },
                         )
                         .await
@@ -935,6 +1513,7 @@ impl WorkerCommandHandler {
                                 &worker_name.0,
                                 Some(&idempotency_key.0),
                                 function_name,
+                                priority.as_ref(),
                                 &InvokeParametersCloud { params: arguments },
                             )
                             .await
@@ -950,6 +1529,7 @@ impl WorkerCommandHandler {
                             &component.versioned_component_id.component_id,
                             Some(&idempotency_key.0),
                             function_name,
+                            priority.as_ref(),
                             &InvokeParametersCloud { params: arguments },
                         )
                         .await
@@ -963,6 +1543,7 @@ impl WorkerCommandHandler {
                                 &component.versioned_component_id.component_id,
                                 Some(&idempotency_key.0),
                                 function_name,
+                                priority.as_ref(),
                                 &InvokeParametersCloud { params: arguments },
                             )
                             .await
@@ -1331,6 +1912,7 @@ impl WorkerCommandHandler {
             worker_metadata.worker_id.worker_name,
             worker_metadata.args,
             worker_metadata.env,
+            worker_metadata.labels,
         )
         .await?;
         log_action("Recreated", "worker");
@@ -1432,6 +2014,56 @@ impl WorkerCommandHandler {
         Ok((component, worker_name.clone()))
     }
 
+    /// Selects the component for commands operating on exactly one component, such as the worker TTL commands
+    async fn single_target_component(
+        &self,
+        component_name: Option<&ComponentName>,
+    ) -> anyhow::Result<Component> {
+        self.ctx.silence_app_context_init().await;
+
+        let selected_components = self
+            .ctx
+            .component_handler()
+            .must_select_components_by_app_dir_or_name(component_name)
+            .await?;
+
+        if selected_components.component_names.len() != 1 {
+            log_error(format!(
+                "The command requires a single component, but multiple components are selected! ({})",
+                selected_components
+                    .component_names
+                    .iter()
+                    .map(|cn| cn.0.log_color_highlight())
+                    .join(", ")
+            ));
+            logln("");
+            logln("Switch to an application directory with only one component or explicitly specify the requested component name.");
+            logln("");
+            bail!(NonSuccessfulExit);
+        }
+
+        let component_name = &selected_components.component_names[0];
+        match self
+            .ctx
+            .component_handler()
+            .component(
+                selected_components.project.as_ref(),
+                component_name.into(),
+                None,
+            )
+            .await?
+        {
+            Some(component) => Ok(component),
+            None => {
+                log_error(format!(
+                    "Component not found: {}",
+                    component_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit)
+            }
+        }
+    }
+
     async fn resume_worker(
         &self,
         component: &Component,
@@ -1719,6 +2351,64 @@ impl WorkerCommandHandler {
     }
 }
 
+fn resolve_function_name(
+    component: &Component,
+    component_name: &ComponentName,
+    function_name: &WorkerFunctionName,
+) -> anyhow::Result<String> {
+    let matched_function_name =
+        fuzzy_match_function_name(function_name, component.metadata.exports());
+    match matched_function_name {
+        Ok(match_) => {
+            log_fuzzy_match(&match_);
+            Ok(match_.option)
+        }
+        Err(error) => {
+            let component_functions = show_exported_functions(component.metadata.exports(), false);
+
+            match error {
+                Error::Ambiguous {
+                    highlighted_options,
+                    ..
+                } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested function name ({}) is ambiguous.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    logln("Did you mean one of");
+                    for option in highlighted_options {
+                        logln(format!(" - {}", option.bold()));
+                    }
+                    logln("?");
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component_name.0.clone(),
+                        function_names: component_functions,
+                    });
+
+                    bail!(NonSuccessfulExit);
+                }
+                Error::NotFound { .. } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested function name ({}) was not found.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component_name.0.clone(),
+                        function_names: component_functions,
+                    });
+
+                    bail!(NonSuccessfulExit);
+                }
+            }
+        }
+    }
+}
+
 fn wave_args_to_invoke_args(
     component: &Component,
     function_name: &str,
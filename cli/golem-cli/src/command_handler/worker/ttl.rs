@@ -0,0 +1,271 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::WorkerNameArg;
+use crate::command::worker::ttl::{WorkerTtlPolicyArgs, WorkerTtlSubcommand};
+use crate::command_handler::worker::WorkerCommandHandler;
+use crate::command_handler::Handlers;
+use crate::error::service::AnyhowMapServiceError;
+use crate::error::NonSuccessfulExit;
+use crate::log::{log_action, log_warn_action, logln, LogColorize};
+use crate::model::text::fmt::{format_worker_name_match, log_error};
+use crate::model::ComponentName;
+use anyhow::bail;
+use golem_client::api::WorkerClient;
+use golem_client::model::WorkerTtlPolicy;
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_ttl(&self, subcommand: WorkerTtlSubcommand) -> anyhow::Result<()> {
+        match subcommand {
+            WorkerTtlSubcommand::Set {
+                worker_name,
+                policy,
+            } => self.cmd_ttl_set(worker_name, policy).await,
+            WorkerTtlSubcommand::Get { worker_name } => self.cmd_ttl_get(worker_name).await,
+            WorkerTtlSubcommand::Remove { worker_name } => self.cmd_ttl_remove(worker_name).await,
+            WorkerTtlSubcommand::SetComponent {
+                component_name,
+                policy,
+            } => {
+                self.cmd_ttl_set_component(component_name.component_name, policy)
+                    .await
+            }
+            WorkerTtlSubcommand::GetComponent { component_name } => {
+                self.cmd_ttl_get_component(component_name.component_name)
+                    .await
+            }
+            WorkerTtlSubcommand::RemoveComponent { component_name } => {
+                self.cmd_ttl_remove_component(component_name.component_name)
+                    .await
+            }
+            WorkerTtlSubcommand::Deletions { component_name } => {
+                self.cmd_ttl_deletions(component_name.component_name).await
+            }
+        }
+    }
+
+    async fn cmd_ttl_set(
+        &self,
+        worker_name: WorkerNameArg,
+        policy: WorkerTtlPolicyArgs,
+    ) -> anyhow::Result<()> {
+        let policy = ttl_policy_from_args(policy)?;
+
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_action(
+            "Setting",
+            format!(
+                "time-to-live policy of worker {}",
+                format_worker_name_match(&worker_name_match)
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .set_worker_ttl_policy(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &policy,
+            )
+            .await
+            .map_service_error()?;
+
+        log_action("Set", "time-to-live policy");
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_ttl_get(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_worker_ttl_policy(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_ttl_remove(&self, worker_name: WorkerNameArg) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        log_warn_action(
+            "Removing",
+            format!(
+                "time-to-live policy of worker {}",
+                format_worker_name_match(&worker_name_match)
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        clients
+            .worker
+            .delete_worker_ttl_policy(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+            )
+            .await
+            .map_service_error()?;
+
+        log_action("Removed", "time-to-live policy");
+
+        Ok(())
+    }
+
+    async fn cmd_ttl_set_component(
+        &self,
+        component_name: Option<ComponentName>,
+        policy: WorkerTtlPolicyArgs,
+    ) -> anyhow::Result<()> {
+        let policy = ttl_policy_from_args(policy)?;
+
+        let component = self
+            .single_target_component(component_name.as_ref())
+            .await?;
+
+        log_action(
+            "Setting",
+            format!(
+                "time-to-live policy of the workers of component {}",
+                component.component_name.0.log_color_highlight()
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .set_component_ttl_policy(&component.versioned_component_id.component_id, &policy)
+            .await
+            .map_service_error()?;
+
+        log_action("Set", "time-to-live policy");
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_ttl_get_component(
+        &self,
+        component_name: Option<ComponentName>,
+    ) -> anyhow::Result<()> {
+        let component = self
+            .single_target_component(component_name.as_ref())
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_component_ttl_policy(&component.versioned_component_id.component_id)
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result);
+
+        Ok(())
+    }
+
+    async fn cmd_ttl_remove_component(
+        &self,
+        component_name: Option<ComponentName>,
+    ) -> anyhow::Result<()> {
+        let component = self
+            .single_target_component(component_name.as_ref())
+            .await?;
+
+        log_warn_action(
+            "Removing",
+            format!(
+                "time-to-live policy of the workers of component {}",
+                component.component_name.0.log_color_highlight()
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        clients
+            .worker
+            .delete_component_ttl_policy(&component.versioned_component_id.component_id)
+            .await
+            .map_service_error()?;
+
+        log_action("Removed", "time-to-live policy");
+
+        Ok(())
+    }
+
+    async fn cmd_ttl_deletions(&self, component_name: Option<ComponentName>) -> anyhow::Result<()> {
+        let component = self
+            .single_target_component(component_name.as_ref())
+            .await?;
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_worker_ttl_deletions(&component.versioned_component_id.component_id)
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&result.deletions);
+
+        Ok(())
+    }
+}
+
+fn ttl_policy_from_args(args: WorkerTtlPolicyArgs) -> anyhow::Result<WorkerTtlPolicy> {
+    if args.max_idle_time.is_none() && args.max_age.is_none() && !args.delete_on_exit {
+        log_error("The time-to-live policy requires at least one limit");
+        logln("");
+        logln("Use --max-idle-time, --max-age or --delete-on-exit to define when workers get deleted.");
+        logln("");
+        bail!(NonSuccessfulExit);
+    }
+
+    Ok(WorkerTtlPolicy {
+        max_idle_time_millis: args
+            .max_idle_time
+            .map(|duration| std::time::Duration::from(duration).as_millis() as u64),
+        max_age_millis: args
+            .max_age
+            .map(|duration| std::time::Duration::from(duration).as_millis() as u64),
+        delete_on_exit: args.delete_on_exit,
+    })
+}
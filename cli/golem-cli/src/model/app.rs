@@ -347,6 +347,13 @@ pub fn includes_from_yaml_file(source: &Path) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// Resolves `import "<path>";` statements in Rib scripts of an application manifest,
+/// relative to the directory of the manifest source
+pub fn resolve_rib_imports(source: &Path, rib: &str) -> Result<String, rib::RibImportError> {
+    let base_dir = source.parent().unwrap_or(Path::new("."));
+    rib::resolve_imports(rib, &rib::FileSystemImportResolver::new(base_dir))
+}
+
 #[derive(Clone, Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum ResolvedComponentProperties {
@@ -2491,16 +2498,29 @@ mod app_builder {
                                         match rib_script.as_ref().map(|s| s.as_str()) {
                                             Some(rib) => {
                                                 check_not_empty(validation, property_name, rib);
-                                                if let Some(err) = rib::from_string(rib).err() {
-                                                    validation.add_error(
-                                                        format!(
-                                                            "Failed to parse property {} as Rib:\n{}\n{}\n{}",
-                                                            property_name.log_color_highlight(),
-                                                            err.to_string().lines().map(|l| format!("  {l}")).join("\n").log_color_warn(),
-                                                            "Rib source:".log_color_highlight(),
-                                                            format_rib_source_for_error(rib, &err),
-                                                        )
-                                                    );
+                                                match resolve_rib_imports(&api_definition.source, rib) {
+                                                    Ok(rib) => {
+                                                        if let Some(err) = rib::from_string(&rib).err() {
+                                                            validation.add_error(
+                                                                format!(
+                                                                    "Failed to parse property {} as Rib:\n{}\n{}\n{}",
+                                                                    property_name.log_color_highlight(),
+                                                                    err.to_string().lines().map(|l| format!("  {l}")).join("\n").log_color_warn(),
+                                                                    "Rib source:".log_color_highlight(),
+                                                                    format_rib_source_for_error(&rib, &err),
+                                                                )
+                                                            );
+                                                        }
+                                                    }
+                                                    Err(err) => {
+                                                        validation.add_error(
+                                                            format!(
+                                                                "Failed to resolve imports in property {}: {}",
+                                                                property_name.log_color_highlight(),
+                                                                err.to_string().log_color_warn(),
+                                                            )
+                                                        );
+                                                    }
                                                 }
                                             }
                                             None => {
@@ -14,7 +14,7 @@
 
 use crate::log::LogColorize;
 use crate::model::api::to_method_pattern;
-use crate::model::app::HttpApiDefinitionName;
+use crate::model::app::{resolve_rib_imports, HttpApiDefinitionName, WithSource};
 use crate::model::app_raw::{
     HttpApiDefinition, HttpApiDefinitionBindingType, HttpApiDefinitionRoute,
 };
@@ -28,6 +28,7 @@ use golem_client::model::{
 };
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::path::Path;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DiffableHttpApiDefinition(pub HttpApiDefinitionRequest);
@@ -67,9 +68,12 @@ impl DiffableHttpApiDefinition {
     pub fn from_manifest(
         server_api_def: Option<&DiffableHttpApiDefinition>,
         name: &HttpApiDefinitionName,
-        api_definition: &HttpApiDefinition,
+        api_definition: &WithSource<HttpApiDefinition>,
         latest_component_versions: &BTreeMap<String, Component>,
     ) -> anyhow::Result<Self> {
+        let source = api_definition.source.as_path();
+        let api_definition = &api_definition.value;
+
         let mut manifest_api_def = Self(HttpApiDefinitionRequest {
             id: name.to_string(),
             version: api_definition.version.clone(),
@@ -77,7 +81,7 @@ impl DiffableHttpApiDefinition {
             routes: api_definition
                 .routes
                 .iter()
-                .map(|route| normalize_http_api_route(latest_component_versions, route))
+                .map(|route| normalize_http_api_route(source, latest_component_versions, route))
                 .collect::<Result<Vec<_>, _>>()?,
             draft: true,
         });
@@ -104,6 +108,7 @@ impl DiffSerialize for DiffableHttpApiDefinition {
 }
 
 fn normalize_http_api_route(
+    source: &Path,
     latest_component_versions: &BTreeMap<String, Component>,
     route: &HttpApiDefinitionRoute,
 ) -> anyhow::Result<RouteRequestData> {
@@ -144,18 +149,21 @@ fn normalize_http_api_route(
                     })
             },
             worker_name: None,
-            idempotency_key: normalize_rib_property(&route.binding.idempotency_key)?,
-            invocation_context: normalize_rib_property(&route.binding.invocation_context)?,
-            response: normalize_rib_property(&route.binding.response)?,
+            idempotency_key: normalize_rib_property(source, &route.binding.idempotency_key)?,
+            invocation_context: normalize_rib_property(source, &route.binding.invocation_context)?,
+            response: normalize_rib_property(source, &route.binding.response)?,
         },
         security: route.security.clone(),
     })
 }
 
-fn normalize_rib_property(rib: &Option<String>) -> anyhow::Result<Option<String>> {
+fn normalize_rib_property(source: &Path, rib: &Option<String>) -> anyhow::Result<Option<String>> {
     rib.as_ref()
-        .map(|r| r.as_str())
-        .map(normalize_rib_source_code)
+        .map(|rib| {
+            let rib = resolve_rib_imports(source, rib)
+                .map_err(|err| anyhow!("Failed to resolve Rib imports: {err}"))?;
+            normalize_rib_source_code(&rib)
+        })
         .transpose()
 }
 
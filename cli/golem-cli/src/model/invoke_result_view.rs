@@ -52,6 +52,29 @@ impl InvokeResultView {
         }
     }
 
+    pub fn new_invocation_result(
+        idempotency_key: IdempotencyKey,
+        result: Option<ValueAndType>,
+    ) -> Self {
+        let wave = match &result {
+            Some(result) if !type_wave_compatible(&result.typ) => None,
+            Some(result) => match Self::try_wave_format(result.clone()) {
+                Ok(wave) => Some(vec![wave]),
+                Err(err) => {
+                    log_error(format!("{err}"));
+                    None
+                }
+            },
+            None => Some(vec![]),
+        };
+
+        Self {
+            idempotency_key: idempotency_key.0,
+            result_json: result,
+            result_wave: wave,
+        }
+    }
+
     pub fn new_enqueue(idempotency_key: IdempotencyKey) -> Self {
         Self {
             idempotency_key: idempotency_key.0,
@@ -455,6 +455,7 @@ pub struct WorkerMetadataView {
     pub project_id: Option<ProjectId>,
     pub args: Vec<String>,
     pub env: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
     pub status: golem_client::model::WorkerStatus,
     pub component_version: u64,
     pub retry_count: u64,
@@ -465,6 +466,7 @@ pub struct WorkerMetadataView {
     pub last_error: Option<String>,
     pub component_size: u64,
     pub total_linear_memory_size: u64,
+    pub file_system_usage: u64,
     pub exported_resource_instances: HashMap<String, ExportedResourceInstanceDescription>,
     pub agent_instances: HashMap<AgentInstanceKey, AgentInstanceDescription>,
 }
@@ -487,6 +489,7 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             project_id: value.project_id,
             args: value.args,
             env: value.env,
+            labels: value.labels,
             status: value.status,
             component_version: value.component_version,
             retry_count: value.retry_count,
@@ -496,6 +499,7 @@ impl From<WorkerMetadata> for WorkerMetadataView {
             last_error: value.last_error,
             component_size: value.component_size,
             total_linear_memory_size: value.total_linear_memory_size,
+            file_system_usage: value.file_system_usage,
             exported_resource_instances: value.exported_resource_instances,
             agent_instances: value.agent_instances,
         }
@@ -510,6 +514,7 @@ pub struct WorkerMetadata {
     pub created_by: Option<AccountId>,
     pub args: Vec<String>,
     pub env: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
     pub status: golem_client::model::WorkerStatus,
     pub component_version: u64,
     pub retry_count: u64,
@@ -519,6 +524,7 @@ pub struct WorkerMetadata {
     pub last_error: Option<String>,
     pub component_size: u64,
     pub total_linear_memory_size: u64,
+    pub file_system_usage: u64,
     pub exported_resource_instances: HashMap<String, ExportedResourceInstanceDescription>,
     pub agent_instances: HashMap<AgentInstanceKey, AgentInstanceDescription>,
 }
@@ -532,6 +538,7 @@ impl WorkerMetadata {
             project_id: None,
             args: value.args,
             env: value.env,
+            labels: value.labels,
             status: value.status,
             component_version: value.component_version,
             retry_count: value.retry_count,
@@ -541,6 +548,7 @@ impl WorkerMetadata {
             last_error: value.last_error,
             component_size: value.component_size,
             total_linear_memory_size: value.total_linear_memory_size,
+            file_system_usage: value.file_system_usage,
             exported_resource_instances: HashMap::from_iter(
                 value.exported_resource_instances.into_iter().map(|desc| {
                     let key = desc.key.resource_id.to_string();
@@ -24,7 +24,12 @@ use base64::Engine;
 use chrono::{DateTime, Utc};
 use cli_table::{format::Justify, Table};
 use colored::Colorize;
-use golem_client::model::{PublicOplogEntry, UpdateRecord};
+use golem_client::model::{
+    BulkOperation, BulkOperationKind, BulkOperationReport, BulkOperationStatus, InvocationSchedule,
+    InvocationScheduleSpec, PendingInvocation, PendingPromise, PublicOplogEntry, Saga, SagaStatus,
+    UpdateRecord, WorkerTtlDeletion, WorkerTtlDeletionReason, WorkerTtlPolicy,
+    WorkerTtlPolicyResponse,
+};
 use golem_common::model::agent::{BinaryReference, DataValue, ElementValue, TextReference};
 use golem_common::model::public_oplog::{
     PluginInstallationDescription, PublicAttributeValue, PublicUpdateDescription,
@@ -168,6 +173,11 @@ impl MessageWithFields for WorkerGetView {
                 &self.metadata.total_linear_memory_size,
                 format_binary_size,
             )
+            .fmt_field(
+                "File system usage",
+                &self.metadata.file_system_usage,
+                format_binary_size,
+            )
             .fmt_field_optional(
                 "Arguments",
                 &self.metadata.args,
@@ -184,6 +194,17 @@ impl MessageWithFields for WorkerGetView {
                         .join(";")
                 },
             )
+            .fmt_field_optional(
+                "Labels",
+                &self.metadata.labels,
+                !self.metadata.labels.is_empty(),
+                |labels| {
+                    labels
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v.bold()))
+                        .join(";")
+                },
+            )
             .fmt_field_optional("Status", &self.metadata.status, self.precise, format_status)
             .fmt_field_optional(
                 "Retry count",
@@ -298,6 +319,364 @@ impl TextView for InvokeResultView {
     }
 }
 
+fn format_invocation_schedule_spec(spec: &InvocationScheduleSpec) -> String {
+    match spec {
+        InvocationScheduleSpec::OnceInvocationSchedule(once) => format!("once at {}", once.at),
+        InvocationScheduleSpec::CronInvocationSchedule(cron) => {
+            format!("cron {}", cron.expression)
+        }
+    }
+}
+
+impl TextView for InvocationSchedule {
+    fn log(&self) {
+        logln(format!(
+            "Schedule {} invokes {} {}",
+            format_main_id(&self.schedule_id),
+            format_export(&self.function_name),
+            format_invocation_schedule_spec(&self.schedule),
+        ));
+        match &self.next_invocation_at {
+            Some(next_invocation_at) => logln(format!(
+                "Next invocation at {}",
+                format_message_highlight(next_invocation_at)
+            )),
+            None => logln("No more invocations are scheduled"),
+        }
+    }
+}
+
+#[derive(Table)]
+struct InvocationScheduleTableView {
+    #[table(title = "Schedule ID")]
+    pub schedule_id: String,
+    #[table(title = "Function")]
+    pub function_name: String,
+    #[table(title = "Schedule")]
+    pub schedule: String,
+    #[table(title = "Next invocation at")]
+    pub next_invocation_at: String,
+    #[table(title = "Invocations", justify = "Justify::Right")]
+    pub invocation_count: u64,
+}
+
+impl From<&InvocationSchedule> for InvocationScheduleTableView {
+    fn from(value: &InvocationSchedule) -> Self {
+        Self {
+            schedule_id: value.schedule_id.to_string(),
+            function_name: value.function_name.clone(),
+            schedule: format_invocation_schedule_spec(&value.schedule),
+            next_invocation_at: value
+                .next_invocation_at
+                .map(|at| at.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            invocation_count: value.invocation_count,
+        }
+    }
+}
+
+impl TextView for Vec<InvocationSchedule> {
+    fn log(&self) {
+        log_table::<_, InvocationScheduleTableView>(self);
+    }
+}
+
+#[derive(Table)]
+struct PendingPromiseTableView {
+    #[table(title = "Oplog index", justify = "Justify::Right")]
+    pub oplog_idx: u64,
+    #[table(title = "Created at")]
+    pub created_at: String,
+}
+
+impl From<&PendingPromise> for PendingPromiseTableView {
+    fn from(value: &PendingPromise) -> Self {
+        Self {
+            oplog_idx: value.promise_id.oplog_idx.into(),
+            created_at: value.created_at.to_string(),
+        }
+    }
+}
+
+impl TextView for Vec<PendingPromise> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("The worker has no pending promises");
+        } else {
+            log_table::<_, PendingPromiseTableView>(self);
+        }
+    }
+}
+
+#[derive(Table)]
+struct PendingInvocationTableView {
+    #[table(title = "Priority")]
+    pub priority: String,
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+    #[table(title = "Invocation")]
+    pub invocation: String,
+    #[table(title = "Enqueued at")]
+    pub enqueued_at: String,
+}
+
+impl From<&PendingInvocation> for PendingInvocationTableView {
+    fn from(value: &PendingInvocation) -> Self {
+        let invocation = match (&value.function_name, value.target_version) {
+            (Some(function_name), _) => function_name.clone(),
+            (None, Some(target_version)) => format!("update to version {target_version}"),
+            (None, None) => "-".to_string(),
+        };
+        Self {
+            priority: value.priority.to_string(),
+            idempotency_key: value
+                .idempotency_key
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+            invocation,
+            enqueued_at: value.enqueued_at.to_string(),
+        }
+    }
+}
+
+impl TextView for Vec<PendingInvocation> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("The worker has no pending invocations");
+        } else {
+            log_table::<_, PendingInvocationTableView>(self);
+        }
+    }
+}
+
+fn format_saga_status(status: &SagaStatus) -> &'static str {
+    match status {
+        SagaStatus::Running => "Running",
+        SagaStatus::Completed => "Completed",
+        SagaStatus::Compensating => "Compensating",
+        SagaStatus::Compensated => "Compensated",
+        SagaStatus::CompensationFailed => "Compensation failed",
+    }
+}
+
+#[derive(Table)]
+struct SagaTableView {
+    #[table(title = "Saga id")]
+    pub saga_id: String,
+    #[table(title = "Status")]
+    pub status: String,
+    #[table(title = "Started at")]
+    pub started_at: String,
+    #[table(title = "Compensations", justify = "Justify::Right")]
+    pub compensations: String,
+    #[table(title = "Error")]
+    pub error: String,
+}
+
+impl From<&Saga> for SagaTableView {
+    fn from(value: &Saga) -> Self {
+        Self {
+            saga_id: value.saga_id.to_string(),
+            status: format_saga_status(&value.status).to_string(),
+            started_at: value.started_at.to_string(),
+            compensations: format!("{}/{}", value.compensated, value.compensations.len()),
+            error: value.error.clone().unwrap_or_else(|| "-".to_string()),
+        }
+    }
+}
+
+impl TextView for Vec<Saga> {
+    fn log(&self) {
+        if self.is_empty() {
+            logln("The worker has not coordinated any sagas");
+        } else {
+            log_table::<_, SagaTableView>(self);
+        }
+    }
+}
+
+pub fn format_bulk_operation_kind(operation: &BulkOperationKind) -> &'static str {
+    match operation {
+        BulkOperationKind::BulkUpdateOperation(_) => "update",
+        BulkOperationKind::BulkInterruptOperation(_) => "interrupt",
+        BulkOperationKind::BulkResumeOperation(_) => "resume",
+        BulkOperationKind::BulkDeleteOperation(_) => "delete",
+        BulkOperationKind::BulkInvokeOperation(_) => "invoke",
+    }
+}
+
+fn format_bulk_operation_status(status: &BulkOperationStatus) -> &'static str {
+    match status {
+        BulkOperationStatus::Running => "Running",
+        BulkOperationStatus::Completed => "Completed",
+        BulkOperationStatus::Failed => "Failed",
+    }
+}
+
+fn format_bulk_operation_progress(operation: &BulkOperation) -> String {
+    let processed = operation.succeeded + operation.failed;
+    match operation.total {
+        Some(total) => format!("{processed}/{total}"),
+        None => format!("{processed}/?"),
+    }
+}
+
+impl TextView for BulkOperation {
+    fn log(&self) {
+        logln(format!(
+            "Bulk {} {} is {}",
+            format_bulk_operation_kind(&self.operation),
+            format_main_id(&self.operation_id),
+            format_message_highlight(format_bulk_operation_status(&self.status)),
+        ));
+        logln(format!(
+            "Processed {} workers, succeeded: {}, failed: {}",
+            format_message_highlight(&format_bulk_operation_progress(self)),
+            format_message_highlight(&self.succeeded),
+            if self.failed > 0 {
+                format_warn(&self.failed)
+            } else {
+                format_message_highlight(&self.failed)
+            },
+        ));
+        if let Some(error) = &self.error {
+            logln(format!("Error: {}", format_error(error)));
+        }
+    }
+}
+
+#[derive(Table)]
+struct BulkOperationTableView {
+    #[table(title = "Operation ID")]
+    pub operation_id: String,
+    #[table(title = "Operation")]
+    pub operation: String,
+    #[table(title = "Status")]
+    pub status: String,
+    #[table(title = "Progress", justify = "Justify::Right")]
+    pub progress: String,
+    #[table(title = "Failed", justify = "Justify::Right")]
+    pub failed: u64,
+    #[table(title = "Created at")]
+    pub created_at: String,
+}
+
+impl From<&BulkOperation> for BulkOperationTableView {
+    fn from(value: &BulkOperation) -> Self {
+        Self {
+            operation_id: value.operation_id.to_string(),
+            operation: format_bulk_operation_kind(&value.operation).to_string(),
+            status: format_bulk_operation_status(&value.status).to_string(),
+            progress: format_bulk_operation_progress(value),
+            failed: value.failed,
+            created_at: value.created_at.to_string(),
+        }
+    }
+}
+
+impl TextView for Vec<BulkOperation> {
+    fn log(&self) {
+        log_table::<_, BulkOperationTableView>(self);
+    }
+}
+
+impl TextView for BulkOperationReport {
+    fn log(&self) {
+        self.operation.log();
+
+        let failures = self
+            .results
+            .iter()
+            .filter_map(|result| {
+                result
+                    .error
+                    .as_ref()
+                    .map(|error| (&result.worker_name, error))
+            })
+            .collect::<Vec<_>>();
+
+        if !failures.is_empty() {
+            logln("");
+            logln(format_warn("Failed workers:"));
+            for (worker_name, error) in failures {
+                logln(format!("  {}: {}", format_id(worker_name), error));
+            }
+        }
+    }
+}
+
+fn format_worker_ttl_policy(policy: &WorkerTtlPolicy) -> String {
+    let mut limits = Vec::new();
+    if let Some(millis) = policy.max_idle_time_millis {
+        limits.push(format!(
+            "max idle time {}",
+            humantime::format_duration(std::time::Duration::from_millis(millis))
+        ));
+    }
+    if let Some(millis) = policy.max_age_millis {
+        limits.push(format!(
+            "max age {}",
+            humantime::format_duration(std::time::Duration::from_millis(millis))
+        ));
+    }
+    if policy.delete_on_exit {
+        limits.push("delete on exit".to_string());
+    }
+    limits.join(", ")
+}
+
+fn format_worker_ttl_deletion_reason(reason: &WorkerTtlDeletionReason) -> &'static str {
+    match reason {
+        WorkerTtlDeletionReason::MaxIdleTime => "Max idle time",
+        WorkerTtlDeletionReason::MaxAge => "Max age",
+        WorkerTtlDeletionReason::Exited => "Exited",
+    }
+}
+
+impl TextView for WorkerTtlPolicyResponse {
+    fn log(&self) {
+        match &self.policy {
+            Some(policy) => logln(format!(
+                "Time-to-live policy: {}",
+                format_message_highlight(&format_worker_ttl_policy(policy))
+            )),
+            None => logln("No time-to-live policy is set"),
+        }
+    }
+}
+
+#[derive(Table)]
+struct WorkerTtlDeletionTableView {
+    #[table(title = "Worker name")]
+    pub worker_name: String,
+    #[table(title = "Reason")]
+    pub reason: String,
+    #[table(title = "Policy")]
+    pub policy: String,
+    #[table(title = "Created at")]
+    pub worker_created_at: String,
+    #[table(title = "Deleted at")]
+    pub deleted_at: String,
+}
+
+impl From<&WorkerTtlDeletion> for WorkerTtlDeletionTableView {
+    fn from(value: &WorkerTtlDeletion) -> Self {
+        Self {
+            worker_name: value.worker_id.worker_name.clone(),
+            reason: format_worker_ttl_deletion_reason(&value.reason).to_string(),
+            policy: format_worker_ttl_policy(&value.policy),
+            worker_created_at: value.worker_created_at.to_string(),
+            deleted_at: value.deleted_at.to_string(),
+        }
+    }
+}
+
+impl TextView for Vec<WorkerTtlDeletion> {
+    fn log(&self) {
+        log_table::<_, WorkerTtlDeletionTableView>(self);
+    }
+}
+
 impl TextView for Vec<(u64, PublicOplogEntry)> {
     fn log(&self) {
         for (idx, entry) in self {
@@ -831,6 +1210,49 @@ impl TextView for PublicOplogEntry {
                     format_id(&params.key.agent_id)
                 ));
             }
+            PublicOplogEntry::SetLabel(params) => {
+                logln(format_message_highlight("SET LABEL"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}key:               {}",
+                    format_id(&params.key)
+                ));
+                logln(format!(
+                    "{pad}value:             {}",
+                    format_id(&params.value)
+                ));
+            }
+            PublicOplogEntry::RemoveLabel(params) => {
+                logln(format_message_highlight("REMOVE LABEL"));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}key:               {}",
+                    format_id(&params.key)
+                ));
+            }
+            PublicOplogEntry::ChangePendingInvocationPriority(params) => {
+                logln(format_message_highlight(
+                    "CHANGE PENDING INVOCATION PRIORITY",
+                ));
+                logln(format!(
+                    "{pad}at:                {}",
+                    format_id(&params.timestamp)
+                ));
+                logln(format!(
+                    "{pad}idempotency key:   {}",
+                    format_id(&params.idempotency_key),
+                ));
+                logln(format!(
+                    "{pad}priority:          {}",
+                    format_id(&params.priority)
+                ));
+            }
         }
     }
 }
@@ -182,12 +182,16 @@ impl LimitsApi {
         let auth = self.auth_service.authorization(token.as_ref()).await?;
 
         let mut updates: HashMap<AccountId, i64> = HashMap::new();
+        let mut storage_updates: HashMap<AccountId, i64> = HashMap::new();
 
         for (k, v) in limits.updates.iter() {
             updates.insert(AccountId::from(k.as_str()), *v);
         }
+        for (k, v) in limits.storage_updates.iter() {
+            storage_updates.insert(AccountId::from(k.as_str()), *v);
+        }
 
-        for account_id in updates.keys() {
+        for account_id in updates.keys().chain(storage_updates.keys()) {
             self.auth_service
                 .authorize_account_action(&auth, account_id, &AccountAction::UpdateLimits)
                 .await?;
@@ -196,6 +200,9 @@ impl LimitsApi {
         self.plan_limit_service
             .record_fuel_consumption(updates)
             .await?;
+        self.plan_limit_service
+            .record_storage_usage(storage_updates)
+            .await?;
 
         Ok(Json(UpdateResourceLimitsResponse {}))
     }
@@ -164,13 +164,17 @@ impl LimitsGrpcApi {
     ) -> Result<(), LimitsError> {
         let auth = self.auth(metadata).await?;
         let mut updates: HashMap<AccountId, i64> = HashMap::new();
+        let mut storage_updates: HashMap<AccountId, i64> = HashMap::new();
         if let Some(batch_updates) = request.resource_limits {
             for (k, v) in batch_updates.updates {
                 updates.insert(AccountId::from(k.as_str()), v);
             }
+            for (k, v) in batch_updates.storage_updates {
+                storage_updates.insert(AccountId::from(k.as_str()), v);
+            }
         }
 
-        for account_id in updates.keys() {
+        for account_id in updates.keys().chain(storage_updates.keys()) {
             self.auth_service
                 .authorize_account_action(&auth, account_id, &AccountAction::UpdateLimits)
                 .await?;
@@ -179,6 +183,9 @@ impl LimitsGrpcApi {
         self.plan_limit_service
             .record_fuel_consumption(updates)
             .await?;
+        self.plan_limit_service
+            .record_storage_usage(storage_updates)
+            .await?;
 
         Ok(())
     }
@@ -51,6 +51,7 @@ pub enum LimitsEndpointError {
 pub struct ResourceLimits {
     pub available_fuel: i64,
     pub max_memory_per_worker: i64,
+    pub available_storage: i64,
 }
 
 impl From<ResourceLimits> for golem_api_grpc::proto::golem::common::ResourceLimits {
@@ -58,6 +59,7 @@ impl From<ResourceLimits> for golem_api_grpc::proto::golem::common::ResourceLimi
         Self {
             available_fuel: value.available_fuel,
             max_memory_per_worker: value.max_memory_per_worker,
+            available_storage: value.available_storage,
         }
     }
 }
@@ -67,6 +69,7 @@ impl From<golem_api_grpc::proto::golem::common::ResourceLimits> for ResourceLimi
         Self {
             available_fuel: value.available_fuel,
             max_memory_per_worker: value.max_memory_per_worker,
+            available_storage: value.available_storage,
         }
     }
 }
@@ -104,6 +107,9 @@ pub struct ProjectGrantDataRequest {
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Object)]
 pub struct BatchUpdateResourceLimits {
     pub updates: HashMap<String, i64>,
+    #[serde(default)]
+    #[oai(default)]
+    pub storage_updates: HashMap<String, i64>,
 }
 
 impl From<BatchUpdateResourceLimits>
@@ -112,6 +118,7 @@ impl From<BatchUpdateResourceLimits>
     fn from(value: BatchUpdateResourceLimits) -> Self {
         Self {
             updates: value.updates,
+            storage_updates: value.storage_updates,
         }
     }
 }
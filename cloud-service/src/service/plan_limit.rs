@@ -28,6 +28,7 @@ use golem_common::model::AccountId;
 use golem_common::model::ProjectId;
 use golem_common::SafeDisplay;
 use golem_service_base::repo::RepoError;
+use std::cmp::max;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::num::TryFromIntError;
@@ -135,6 +136,12 @@ pub trait PlanLimitService: Send + Sync {
         updates: HashMap<AccountId, i64>,
     ) -> Result<(), PlanLimitError>;
 
+    /// Record changes of the storage used by workers' file systems - internal API for executors
+    async fn record_storage_usage(
+        &self,
+        updates: HashMap<AccountId, i64>,
+    ) -> Result<(), PlanLimitError>;
+
     /// Update component limit.
     async fn update_component_limit(
         &self,
@@ -216,9 +223,12 @@ impl PlanLimitService for PlanLimitServiceDefault {
         let plan = self.get_plan(account_id).await?;
         let fuel = self.account_fuel_repo.get(account_id).await?;
         let available_fuel = plan.plan_data.monthly_gas_limit - fuel;
+        let used_storage = self.account_used_storage_repo.get(account_id).await?;
+        let available_storage = max(0, i64::from(plan.plan_data.storage_limit) - used_storage);
         Ok(ResourceLimits {
             available_fuel,
             max_memory_per_worker: 100 * 1024 * 1024,
+            available_storage,
         })
     }
 
@@ -234,6 +244,19 @@ impl PlanLimitService for PlanLimitServiceDefault {
         Ok(())
     }
 
+    async fn record_storage_usage(
+        &self,
+        updates: HashMap<AccountId, i64>,
+    ) -> Result<(), PlanLimitError> {
+        for (account_id, update) in updates {
+            self.get_plan(&account_id).await?;
+            self.account_used_storage_repo
+                .update(&account_id, update)
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn update_component_limit(
         &self,
         account_id: &AccountId,
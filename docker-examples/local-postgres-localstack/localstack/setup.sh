@@ -6,6 +6,7 @@ awslocal s3api create-bucket --bucket golem-oplog-payload
 awslocal s3api create-bucket --bucket golem-oplog-archive-1
 awslocal s3api create-bucket --bucket golem-initial-component-files
 awslocal s3api create-bucket --bucket golem-component-store
+awslocal s3api create-bucket --bucket golem-worker-files
 
 # signal setup is done
 awslocal s3api create-bucket --bucket signal-ready
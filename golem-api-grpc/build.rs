@@ -83,12 +83,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/token/v1/token_service.proto",
                 "proto/golem/worker/complete_parameters.proto",
                 "proto/golem/worker/idempotency_key.proto",
+                "proto/golem/worker/invocation_schedule.proto",
                 "proto/golem/worker/invoke_parameters.proto",
                 "proto/golem/worker/invoke_result.proto",
                 "proto/golem/worker/log_event.proto",
+                "proto/golem/worker/pending_invocation.proto",
+                "proto/golem/worker/pending_promise.proto",
                 "proto/golem/worker/promise_id.proto",
                 "proto/golem/worker/public_oplog.proto",
+                "proto/golem/worker/saga.proto",
                 "proto/golem/worker/update_mode.proto",
+                "proto/golem/worker/v1/invocation_result.proto",
                 "proto/golem/worker/v1/worker_error.proto",
                 "proto/golem/worker/v1/worker_execution_error.proto",
                 "proto/golem/worker/v1/worker_service.proto",
@@ -98,6 +103,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "proto/golem/worker/worker_id.proto",
                 "proto/golem/worker/worker_metadata.proto",
                 "proto/golem/worker/worker_status.proto",
+                "proto/golem/worker/worker_ttl.proto",
                 "proto/golem/workerexecutor/v1/worker_executor.proto",
                 "proto/grpc/health/v1/health.proto",
             ],
@@ -71,6 +71,10 @@ fn generate(yaml_path: PathBuf, out_dir: OsString) {
                 "golem_common::model::ExportedResourceInstanceDescription",
             ),
             ("GolemError", "golem_common::model::error::GolemError"),
+            (
+                "InvocationPriority",
+                "golem_common::model::oplog::InvocationPriority",
+            ),
             (
                 "PluginInstallationAction",
                 "golem_common::model::plugin::PluginInstallationAction",
@@ -38,6 +38,13 @@ newtype_uuid!(ProjectGrantId);
 newtype_uuid!(ProjectPolicyId);
 newtype_uuid!(TokenId, golem_api_grpc::proto::golem::token::TokenId);
 
+newtype_uuid!(
+    InvocationScheduleId,
+    golem_api_grpc::proto::golem::worker::InvocationScheduleId
+);
+
+newtype_uuid!(SagaId, golem_api_grpc::proto::golem::worker::SagaId);
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord, Hash, Encode, Decode)]
 #[cfg_attr(feature = "model", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
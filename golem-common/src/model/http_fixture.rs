@@ -0,0 +1,509 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::base64::Base64;
+use crate::model::oplog::OplogIndex;
+use crate::model::public_oplog::{
+    ImportedFunctionInvokedParameters, PublicDurableFunctionType, PublicOplogEntry,
+    WriteRemoteBatchedParameters,
+};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{print_value_and_type, Value, ValueAndType};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Outgoing HTTP requests of a worker and the responses they got, extracted from its oplog.
+///
+/// A fixture can be used to serve the outgoing requests of workers without reaching out to the
+/// real services, for example for running component tests offline.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpFixture {
+    pub interactions: Vec<HttpInteraction>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpInteraction {
+    pub request: HttpFixtureRequest,
+    pub response: HttpFixtureResponse,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpFixtureRequest {
+    pub method: String,
+    /// Authority and path of the request, without the scheme
+    pub uri: String,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum HttpFixtureResponse {
+    /// The response was received. The body only contains the parts the worker has read.
+    #[serde(rename_all = "camelCase")]
+    Received {
+        status: u16,
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+        #[serde(default)]
+        body: Base64,
+    },
+    /// The request failed without getting a response
+    #[serde(rename_all = "camelCase")]
+    Failed {
+        /// Case of the WASI HTTP `error-code` the request failed with, missing if it failed
+        /// with an error of the executor
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+        error: String,
+    },
+}
+
+impl HttpFixture {
+    const RESPONSE_FUNCTION: &'static str = "http::types::future_incoming_response::get";
+    const BODY_READ_FUNCTIONS: [&'static str; 2] = [
+        "http::types::incoming_body_stream::read",
+        "http::types::incoming_body_stream::blocking_read",
+    ];
+
+    /// Collects the completed outgoing HTTP requests from a worker's public oplog
+    pub fn from_oplog<'a>(
+        entries: impl IntoIterator<Item = &'a PublicOplogEntry>,
+    ) -> Result<Self, String> {
+        let mut interactions = Vec::new();
+        let mut by_begin_index = HashMap::<OplogIndex, usize>::new();
+
+        for entry in entries {
+            let PublicOplogEntry::ImportedFunctionInvoked(params) = entry else {
+                continue;
+            };
+            let PublicDurableFunctionType::WriteRemoteBatched(WriteRemoteBatchedParameters {
+                index: Some(begin_index),
+            }) = params.durable_function_type
+            else {
+                continue;
+            };
+
+            if params.function_name == Self::RESPONSE_FUNCTION {
+                if let Some(response) = parse_response(&params.response)? {
+                    let interaction = HttpInteraction {
+                        request: parse_request(&params.request)?,
+                        response,
+                    };
+                    // A request retried after a restart replaces its earlier, interrupted attempt
+                    match by_begin_index.get(&begin_index) {
+                        Some(idx) => interactions[*idx] = interaction,
+                        None => {
+                            by_begin_index.insert(begin_index, interactions.len());
+                            interactions.push(interaction);
+                        }
+                    }
+                }
+            } else if Self::BODY_READ_FUNCTIONS.contains(&params.function_name.as_str()) {
+                if let Some(HttpFixtureResponse::Received { body, .. }) = by_begin_index
+                    .get(&begin_index)
+                    .map(|idx| &mut interactions[*idx].response)
+                {
+                    body.0.extend(parse_body_chunk(params)?);
+                }
+            }
+        }
+
+        Ok(Self { interactions })
+    }
+}
+
+fn parse_request(request: &ValueAndType) -> Result<HttpFixtureRequest, String> {
+    let fields = record_fields(request)?;
+    let uri = string(field(&fields, "uri")?)?;
+    let method = match variant_case(field(&fields, "method")?)? {
+        ("other", Some(method)) => string(method)?,
+        (method, _) => method.to_uppercase(),
+    };
+    let headers = list(field(&fields, "headers")?)?
+        .into_iter()
+        .map(|header| match header.value {
+            Value::Tuple(items) if items.len() == 2 => {
+                Ok((string_value(&items[0])?, string_value(&items[1])?))
+            }
+            _ => Err("Unexpected HTTP header representation".to_string()),
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(HttpFixtureRequest {
+        method,
+        uri,
+        headers,
+    })
+}
+
+fn parse_response(response: &ValueAndType) -> Result<Option<HttpFixtureResponse>, String> {
+    match variant_case(response)? {
+        ("pending", _) => Ok(None),
+        ("headers-received", Some(received)) => {
+            let fields = record_fields(&received)?;
+            let status = match field(&fields, "status")?.value {
+                Value::U16(status) => status,
+                _ => return Err("Unexpected HTTP status representation".to_string()),
+            };
+            let headers = list(field(&fields, "headers")?)?
+                .into_iter()
+                .map(|header| match header.value {
+                    Value::Tuple(items) if items.len() == 2 => Ok((
+                        string_value(&items[0])?,
+                        String::from_utf8_lossy(&bytes_value(&items[1])?).to_string(),
+                    )),
+                    _ => Err("Unexpected HTTP header representation".to_string()),
+                })
+                .collect::<Result<_, String>>()?;
+            Ok(Some(HttpFixtureResponse::Received {
+                status,
+                headers,
+                body: Base64::default(),
+            }))
+        }
+        ("http-error", Some(error)) => Ok(Some(HttpFixtureResponse::Failed {
+            code: Some(variant_case(&error)?.0.to_string()),
+            error: print_value_and_type(&error)?,
+        })),
+        (_, Some(error)) => Ok(Some(HttpFixtureResponse::Failed {
+            code: None,
+            error: print_value_and_type(&error)?,
+        })),
+        (case, None) => Ok(Some(HttpFixtureResponse::Failed {
+            code: None,
+            error: case.to_string(),
+        })),
+    }
+}
+
+fn parse_body_chunk(params: &ImportedFunctionInvokedParameters) -> Result<Vec<u8>, String> {
+    match (&params.response.value, &params.response.typ) {
+        (Value::Result(Ok(Some(chunk))), AnalysedType::Result(_)) => bytes_value(chunk),
+        // Reaching the end of the body or failing to read it does not add anything to it
+        (Value::Result(_), AnalysedType::Result(_)) => Ok(Vec::new()),
+        _ => Err("Unexpected HTTP body chunk representation".to_string()),
+    }
+}
+
+fn record_fields(value: &ValueAndType) -> Result<Vec<(String, ValueAndType)>, String> {
+    match (&value.value, &value.typ) {
+        (Value::Record(values), AnalysedType::Record(typ)) => Ok(typ
+            .fields
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                (
+                    field.name.clone(),
+                    ValueAndType::new(value.clone(), field.typ.clone()),
+                )
+            })
+            .collect()),
+        _ => Err(format!("Expected a record, got {value}")),
+    }
+}
+
+fn field(fields: &[(String, ValueAndType)], name: &str) -> Result<ValueAndType, String> {
+    fields
+        .iter()
+        .find(|(field_name, _)| field_name == name)
+        .map(|(_, value)| value.clone())
+        .ok_or_else(|| format!("Missing field {name}"))
+}
+
+fn variant_case(value: &ValueAndType) -> Result<(&str, Option<ValueAndType>), String> {
+    match (&value.value, &value.typ) {
+        (
+            Value::Variant {
+                case_idx,
+                case_value,
+            },
+            AnalysedType::Variant(typ),
+        ) => {
+            let case = typ
+                .cases
+                .get(*case_idx as usize)
+                .ok_or_else(|| format!("Invalid case index {case_idx}"))?;
+            let case_value = match (case_value, &case.typ) {
+                (Some(case_value), Some(case_typ)) => {
+                    Some(ValueAndType::new((**case_value).clone(), case_typ.clone()))
+                }
+                _ => None,
+            };
+            Ok((case.name.as_str(), case_value))
+        }
+        _ => Err(format!("Expected a variant, got {value}")),
+    }
+}
+
+fn list(value: ValueAndType) -> Result<Vec<ValueAndType>, String> {
+    match (value.value, value.typ) {
+        (Value::List(items), AnalysedType::List(typ)) => Ok(items
+            .into_iter()
+            .map(|item| ValueAndType::new(item, (*typ.inner).clone()))
+            .collect()),
+        _ => Err("Expected a list".to_string()),
+    }
+}
+
+fn string(value: ValueAndType) -> Result<String, String> {
+    string_value(&value.value)
+}
+
+fn string_value(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        _ => Err("Expected a string".to_string()),
+    }
+}
+
+fn bytes_value(value: &Value) -> Result<Vec<u8>, String> {
+    match value {
+        Value::List(items) => items
+            .iter()
+            .map(|item| match item {
+                Value::U8(byte) => Ok(*byte),
+                _ => Err("Expected a byte".to_string()),
+            })
+            .collect(),
+        _ => Err("Expected a list of bytes".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::model::base64::Base64;
+    use crate::model::http_fixture::{
+        HttpFixture, HttpFixtureRequest, HttpFixtureResponse, HttpInteraction,
+    };
+    use crate::model::oplog::OplogIndex;
+    use crate::model::public_oplog::{
+        ImportedFunctionInvokedParameters, PublicDurableFunctionType, PublicOplogEntry,
+        WriteRemoteBatchedParameters,
+    };
+    use crate::model::Timestamp;
+    use golem_wasm_rpc::IntoValueAndType;
+    use golem_wasm_rpc_derive::IntoValue;
+    use std::collections::{BTreeMap, HashMap};
+
+    // Mirrors of the worker executor's serialized HTTP types, producing the same public oplog values
+
+    #[derive(IntoValue)]
+    enum SerializableHttpMethod {
+        Get,
+        Post,
+        Other(String),
+    }
+
+    #[derive(IntoValue)]
+    struct SerializableHttpRequest {
+        uri: String,
+        method: SerializableHttpMethod,
+        headers: HashMap<String, String>,
+    }
+
+    #[derive(IntoValue)]
+    enum SerializableResponse {
+        Pending,
+        HeadersReceived(SerializableResponseHeaders),
+        HttpError(SerializableErrorCode),
+    }
+
+    #[derive(IntoValue)]
+    struct SerializableResponseHeaders {
+        status: u16,
+        headers: HashMap<String, Vec<u8>>,
+    }
+
+    #[derive(IntoValue)]
+    #[allow(dead_code)]
+    enum SerializableErrorCode {
+        DnsTimeout,
+        ConnectionRefused,
+        InternalError(Option<String>),
+    }
+
+    fn invoked(
+        function_name: &str,
+        begin_index: u64,
+        request: impl IntoValueAndType,
+        response: impl IntoValueAndType,
+    ) -> PublicOplogEntry {
+        PublicOplogEntry::ImportedFunctionInvoked(ImportedFunctionInvokedParameters {
+            timestamp: Timestamp::now_utc(),
+            function_name: function_name.to_string(),
+            request: request.into_value_and_type(),
+            response: response.into_value_and_type(),
+            durable_function_type: PublicDurableFunctionType::WriteRemoteBatched(
+                WriteRemoteBatchedParameters {
+                    index: Some(OplogIndex::from_u64(begin_index)),
+                },
+            ),
+        })
+    }
+
+    fn request(method: SerializableHttpMethod, uri: &str) -> SerializableHttpRequest {
+        SerializableHttpRequest {
+            uri: uri.to_string(),
+            method,
+            headers: HashMap::from([("accept".to_string(), "text/plain".to_string())]),
+        }
+    }
+
+    fn response(status: u16) -> SerializableResponse {
+        SerializableResponse::HeadersReceived(SerializableResponseHeaders {
+            status,
+            headers: HashMap::from([("content-type".to_string(), b"text/plain".to_vec())]),
+        })
+    }
+
+    fn chunk(begin_index: u64, uri: &str, chunk: Result<&[u8], &str>) -> PublicOplogEntry {
+        invoked(
+            "http::types::incoming_body_stream::blocking_read",
+            begin_index,
+            request(SerializableHttpMethod::Get, uri),
+            chunk
+                .map(|chunk| chunk.to_vec())
+                .map_err(|err| err.to_string()),
+        )
+    }
+
+    const GET: &str = "http::types::future_incoming_response::get";
+
+    #[test]
+    fn requests_and_bodies_are_extracted() {
+        let oplog = vec![
+            invoked(
+                GET,
+                2,
+                request(SerializableHttpMethod::Get, "example.com/a"),
+                SerializableResponse::Pending,
+            ),
+            invoked(
+                GET,
+                2,
+                request(SerializableHttpMethod::Get, "example.com/a"),
+                response(200),
+            ),
+            invoked(
+                GET,
+                5,
+                request(
+                    SerializableHttpMethod::Other("PROPFIND".to_string()),
+                    "example.com/b",
+                ),
+                SerializableResponse::HttpError(SerializableErrorCode::ConnectionRefused),
+            ),
+            chunk(2, "example.com/a", Ok(b"hello ")),
+            chunk(2, "example.com/a", Ok(b"world")),
+            chunk(2, "example.com/a", Err("closed")),
+        ];
+
+        let fixture = HttpFixture::from_oplog(&oplog).unwrap();
+
+        let expected_request = |method: &str, uri: &str| HttpFixtureRequest {
+            method: method.to_string(),
+            uri: uri.to_string(),
+            headers: BTreeMap::from([("accept".to_string(), "text/plain".to_string())]),
+        };
+        assert_eq!(
+            fixture,
+            HttpFixture {
+                interactions: vec![
+                    HttpInteraction {
+                        request: expected_request("GET", "example.com/a"),
+                        response: HttpFixtureResponse::Received {
+                            status: 200,
+                            headers: BTreeMap::from([(
+                                "content-type".to_string(),
+                                "text/plain".to_string()
+                            )]),
+                            body: Base64(b"hello world".to_vec()),
+                        },
+                    },
+                    HttpInteraction {
+                        request: expected_request("PROPFIND", "example.com/b"),
+                        response: HttpFixtureResponse::Failed {
+                            code: Some("connection-refused".to_string()),
+                            error: "connection-refused".to_string()
+                        },
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn retried_request_replaces_interrupted_attempt() {
+        let oplog = vec![
+            invoked(
+                GET,
+                2,
+                request(SerializableHttpMethod::Post, "example.com/a"),
+                response(503),
+            ),
+            chunk(2, "example.com/a", Ok(b"unavailable")),
+            invoked(
+                GET,
+                2,
+                request(SerializableHttpMethod::Post, "example.com/a"),
+                response(201),
+            ),
+            chunk(2, "example.com/a", Ok(b"created")),
+        ];
+
+        let fixture = HttpFixture::from_oplog(&oplog).unwrap();
+
+        assert_eq!(fixture.interactions.len(), 1);
+        assert!(matches!(
+            &fixture.interactions[0].response,
+            HttpFixtureResponse::Received { status: 201, body, .. } if body.0 == b"created"
+        ));
+    }
+
+    #[test]
+    fn fixture_json_roundtrip() {
+        let json = r#"{
+            "interactions": [
+                {
+                    "request": { "method": "GET", "uri": "example.com/a" },
+                    "response": { "type": "received", "status": 200, "body": "aGVsbG8=" }
+                },
+                {
+                    "request": { "method": "GET", "uri": "example.com/b" },
+                    "response": { "type": "failed", "code": "dns-timeout", "error": "dns-timeout" }
+                }
+            ]
+        }"#;
+
+        let fixture: HttpFixture = serde_json::from_str(json).unwrap();
+        let roundtrip: HttpFixture =
+            serde_json::from_str(&serde_json::to_string(&fixture).unwrap()).unwrap();
+
+        assert_eq!(fixture, roundtrip);
+        assert_eq!(
+            fixture.interactions[0].response,
+            HttpFixtureResponse::Received {
+                status: 200,
+                headers: BTreeMap::new(),
+                body: Base64(b"hello".to_vec()),
+            }
+        );
+    }
+}
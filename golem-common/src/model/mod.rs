@@ -21,6 +21,7 @@ pub mod component_constraint;
 pub mod component_metadata;
 pub mod error;
 pub mod exports;
+pub mod http_fixture;
 pub mod invocation_context;
 pub mod lucene;
 pub mod oplog;
@@ -38,7 +39,7 @@ pub mod worker;
 pub use crate::base_model::*;
 use crate::model::agent::DataValue;
 use crate::model::invocation_context::InvocationContextStack;
-use crate::model::oplog::{TimestampedUpdateDescription, WorkerResourceId};
+use crate::model::oplog::{InvocationPriority, TimestampedUpdateDescription, WorkerResourceId};
 use crate::model::regions::DeletedRegions;
 use bincode::de::{BorrowDecoder, Decoder};
 use bincode::enc::Encoder;
@@ -277,6 +278,24 @@ pub enum ScheduledAction {
         function_input: Vec<Value>,
         invocation_context: InvocationContextStack,
     },
+    /// Performs the next occurrence of an invocation schedule created through the API.
+    /// The invocation details are stored with the schedule itself, so a canceled
+    /// schedule's pending occurrence turns into a no-op.
+    InvokeScheduled {
+        account_id: AccountId,
+        owned_worker_id: OwnedWorkerId,
+        schedule_id: InvocationScheduleId,
+        scheduled_at: Timestamp,
+    },
+    /// Deletes the worker if it violates its time-to-live policy, or schedules the check again for
+    /// the time it will. Does nothing if the worker's oplog has grown since `last_oplog_index`, as
+    /// the worker becoming idle again schedules a new check.
+    CheckWorkerTtl {
+        account_id: AccountId,
+        owned_worker_id: OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+        scheduled_at: Timestamp,
+    },
 }
 
 impl ScheduledAction {
@@ -293,6 +312,12 @@ impl ScheduledAction {
             ScheduledAction::Invoke {
                 owned_worker_id, ..
             } => owned_worker_id.clone(),
+            ScheduledAction::InvokeScheduled {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
+            ScheduledAction::CheckWorkerTtl {
+                owned_worker_id, ..
+            } => owned_worker_id.clone(),
         }
     }
 }
@@ -311,6 +336,14 @@ impl Display for ScheduledAction {
             ScheduledAction::Invoke {
                 owned_worker_id, ..
             } => write!(f, "invoke[{owned_worker_id}]"),
+            ScheduledAction::InvokeScheduled {
+                owned_worker_id,
+                schedule_id,
+                ..
+            } => write!(f, "invoke-scheduled[{owned_worker_id}, {schedule_id}]"),
+            ScheduledAction::CheckWorkerTtl {
+                owned_worker_id, ..
+            } => write!(f, "check-ttl[{owned_worker_id}]"),
         }
     }
 }
@@ -712,6 +745,11 @@ pub struct WorkerStatusRecord {
     /// The component version at the starting point of the replay. Will be the version of the Create oplog entry
     /// if only automatic updates were used or the version of the latest snapshot based update
     pub component_version_for_replay: ComponentVersion,
+    /// User-defined labels of the worker
+    pub labels: BTreeMap<String, String>,
+    /// Priorities of the pending invocations which are not `InvocationPriority::Normal`.
+    /// `pending_invocations` is kept ordered by these priorities.
+    pub invocation_priorities: HashMap<IdempotencyKey, InvocationPriority>,
 }
 
 impl<Context> bincode::Decode<Context> for WorkerStatusRecord {
@@ -734,6 +772,8 @@ impl<Context> bincode::Decode<Context> for WorkerStatusRecord {
             active_plugins: Decode::decode(decoder)?,
             deleted_regions: Decode::decode(decoder)?,
             component_version_for_replay: Decode::decode(decoder)?,
+            labels: Decode::decode(decoder)?,
+            invocation_priorities: Decode::decode(decoder)?,
         })
     }
 }
@@ -759,6 +799,8 @@ impl<'de, Context> BorrowDecode<'de, Context> for WorkerStatusRecord {
             active_plugins: BorrowDecode::borrow_decode(decoder)?,
             deleted_regions: BorrowDecode::borrow_decode(decoder)?,
             component_version_for_replay: BorrowDecode::borrow_decode(decoder)?,
+            labels: BorrowDecode::borrow_decode(decoder)?,
+            invocation_priorities: BorrowDecode::borrow_decode(decoder)?,
         })
     }
 }
@@ -783,6 +825,8 @@ impl Default for WorkerStatusRecord {
             active_plugins: HashSet::new(),
             deleted_regions: DeletedRegions::new(),
             component_version_for_replay: 0,
+            labels: BTreeMap::new(),
+            invocation_priorities: HashMap::new(),
         }
     }
 }
@@ -1224,6 +1268,32 @@ impl Display for WorkerWasiConfigVarsFilter {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerLabelFilter {
+    pub name: String,
+    pub comparator: StringFilterComparator,
+    pub value: String,
+}
+
+impl WorkerLabelFilter {
+    pub fn new(name: String, comparator: StringFilterComparator, value: String) -> Self {
+        Self {
+            name,
+            comparator,
+            value,
+        }
+    }
+}
+
+impl Display for WorkerLabelFilter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "label.{} {} {}", self.name, self.comparator, self.value)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Encode, Decode)]
 #[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
 #[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
@@ -1316,6 +1386,7 @@ pub enum WorkerFilter {
     Or(WorkerOrFilter),
     Not(WorkerNotFilter),
     WasiConfigVars(WorkerWasiConfigVarsFilter),
+    Label(WorkerLabelFilter),
 }
 
 impl WorkerFilter {
@@ -1376,6 +1447,16 @@ impl WorkerFilter {
                     .map(|ev| comparator.matches(ev, &value))
                     .unwrap_or(false)
             }
+            WorkerFilter::Label(WorkerLabelFilter {
+                name,
+                comparator,
+                value,
+            }) => metadata
+                .last_known_status
+                .labels
+                .get(&name)
+                .map(|label_value| comparator.matches(label_value, &value))
+                .unwrap_or(false),
             WorkerFilter::CreatedAt(WorkerCreatedAtFilter { comparator, value }) => {
                 comparator.matches(&metadata.created_at, &value)
             }
@@ -1437,6 +1518,10 @@ impl WorkerFilter {
         WorkerFilter::WasiConfigVars(WorkerWasiConfigVarsFilter::new(name, comparator, value))
     }
 
+    pub fn new_label(name: String, comparator: StringFilterComparator, value: String) -> Self {
+        WorkerFilter::Label(WorkerLabelFilter::new(name, comparator, value))
+    }
+
     pub fn new_version(comparator: FilterComparator, value: ComponentVersion) -> Self {
         WorkerFilter::Version(WorkerVersionFilter::new(comparator, value))
     }
@@ -1479,6 +1564,9 @@ impl Display for WorkerFilter {
             WorkerFilter::WasiConfigVars(filter) => {
                 write!(f, "{filter}")
             }
+            WorkerFilter::Label(filter) => {
+                write!(f, "{filter}")
+            }
             WorkerFilter::Not(filter) => {
                 write!(f, "{filter}")
             }
@@ -1529,6 +1617,14 @@ impl FromStr for WorkerFilter {
                         value.to_string(),
                     ))
                 }
+                _ if arg.starts_with("label.") => {
+                    let name = &arg[6..];
+                    Ok(WorkerFilter::new_label(
+                        name.to_string(),
+                        comparator.parse()?,
+                        value.to_string(),
+                    ))
+                }
                 _ => Err(format!("Invalid filter: {s}")),
             }
         } else {
@@ -2337,6 +2433,7 @@ mod tests {
             parent: None,
             last_known_status: WorkerStatusRecord {
                 component_version: 1,
+                labels: BTreeMap::from([("team".to_string(), "payments".to_string())]),
                 ..WorkerStatusRecord::default()
             },
         };
@@ -2407,6 +2504,29 @@ mod tests {
             "value2".to_string(),
         )
         .matches(&worker_metadata));
+
+        assert!(WorkerFilter::new_label(
+            "team".to_string(),
+            StringFilterComparator::Equal,
+            "payments".to_string(),
+        )
+        .matches(&worker_metadata));
+
+        assert!(!WorkerFilter::new_label(
+            "owner".to_string(),
+            StringFilterComparator::NotEqual,
+            "payments".to_string(),
+        )
+        .matches(&worker_metadata));
+
+        assert_eq!(
+            WorkerFilter::from_str("label.team == payments").unwrap(),
+            WorkerFilter::new_label(
+                "team".to_string(),
+                StringFilterComparator::Equal,
+                "payments".to_string(),
+            )
+        );
     }
 
     #[test]
@@ -33,6 +33,7 @@ use nonempty_collections::NEVec;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use uuid::Uuid;
@@ -281,6 +282,62 @@ pub enum PersistenceLevel {
     Smart,
 }
 
+/// Priority of a pending invocation in a worker's invocation queue.
+///
+/// Pending invocations are processed in descending priority order, and in the order they
+/// were enqueued within the same priority level.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Encode,
+    Decode,
+    Serialize,
+    Deserialize,
+    IntoValue,
+)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Enum))]
+pub enum InvocationPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Urgent,
+}
+
+impl Display for InvocationPriority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvocationPriority::Low => write!(f, "low"),
+            InvocationPriority::Normal => write!(f, "normal"),
+            InvocationPriority::High => write!(f, "high"),
+            InvocationPriority::Urgent => write!(f, "urgent"),
+        }
+    }
+}
+
+impl FromStr for InvocationPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "low" => Ok(InvocationPriority::Low),
+            "normal" => Ok(InvocationPriority::Normal),
+            "high" => Ok(InvocationPriority::High),
+            "urgent" => Ok(InvocationPriority::Urgent),
+            _ => Err(format!(
+                "Invalid invocation priority: {s}, expected one of low, normal, high, urgent"
+            )),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Encode, Decode)]
 pub enum OplogEntry {
     /// The worker has completed an invocation
@@ -481,6 +538,21 @@ pub enum OplogEntry {
         timestamp: Timestamp,
         key: AgentInstanceKey,
     },
+    /// Sets a user-defined label on the worker, overwriting the previous value of the same key
+    SetLabel {
+        timestamp: Timestamp,
+        key: String,
+        value: String,
+    },
+    /// Removes a user-defined label from the worker
+    RemoveLabel { timestamp: Timestamp, key: String },
+    /// Changes the priority of a pending invocation, moving it to the end of the new priority
+    /// level in the worker's invocation queue
+    ChangePendingInvocationPriority {
+        timestamp: Timestamp,
+        idempotency_key: IdempotencyKey,
+        priority: InvocationPriority,
+    },
 }
 
 impl OplogEntry {
@@ -752,6 +824,32 @@ impl OplogEntry {
         }
     }
 
+    pub fn set_label(key: String, value: String) -> OplogEntry {
+        OplogEntry::SetLabel {
+            timestamp: Timestamp::now_utc(),
+            key,
+            value,
+        }
+    }
+
+    pub fn remove_label(key: String) -> OplogEntry {
+        OplogEntry::RemoveLabel {
+            timestamp: Timestamp::now_utc(),
+            key,
+        }
+    }
+
+    pub fn change_pending_invocation_priority(
+        idempotency_key: IdempotencyKey,
+        priority: InvocationPriority,
+    ) -> OplogEntry {
+        OplogEntry::ChangePendingInvocationPriority {
+            timestamp: Timestamp::now_utc(),
+            idempotency_key,
+            priority,
+        }
+    }
+
     pub fn is_end_atomic_region(&self, idx: OplogIndex) -> bool {
         matches!(self, OplogEntry::EndAtomicRegion { begin_index, .. } if *begin_index == idx)
     }
@@ -807,6 +905,9 @@ impl OplogEntry {
                 | OplogEntry::CancelPendingInvocation { .. }
                 | OplogEntry::CreateAgentInstance { .. }
                 | OplogEntry::DropAgentInstance { .. }
+                | OplogEntry::SetLabel { .. }
+                | OplogEntry::RemoveLabel { .. }
+                | OplogEntry::ChangePendingInvocationPriority { .. }
         )
     }
 
@@ -846,7 +947,10 @@ impl OplogEntry {
             | OplogEntry::SetSpanAttribute { timestamp, .. }
             | OplogEntry::ChangePersistenceLevel { timestamp, .. }
             | OplogEntry::CreateAgentInstance { timestamp, .. }
-            | OplogEntry::DropAgentInstance { timestamp, .. } => *timestamp,
+            | OplogEntry::DropAgentInstance { timestamp, .. }
+            | OplogEntry::SetLabel { timestamp, .. }
+            | OplogEntry::RemoveLabel { timestamp, .. }
+            | OplogEntry::ChangePendingInvocationPriority { timestamp, .. } => *timestamp,
         }
     }
 
@@ -989,7 +1093,7 @@ impl WorkerError {
 #[cfg(feature = "protobuf")]
 mod protobuf {
     use super::WorkerError;
-    use crate::model::oplog::{IndexedResourceKey, PersistenceLevel};
+    use crate::model::oplog::{IndexedResourceKey, InvocationPriority, PersistenceLevel};
 
     impl From<IndexedResourceKey> for golem_api_grpc::proto::golem::worker::IndexedResourceMetadata {
         fn from(value: IndexedResourceKey) -> Self {
@@ -1041,6 +1145,44 @@ mod protobuf {
         }
     }
 
+    impl From<InvocationPriority> for golem_api_grpc::proto::golem::worker::InvocationPriority {
+        fn from(value: InvocationPriority) -> Self {
+            match value {
+                InvocationPriority::Low => {
+                    golem_api_grpc::proto::golem::worker::InvocationPriority::Low
+                }
+                InvocationPriority::Normal => {
+                    golem_api_grpc::proto::golem::worker::InvocationPriority::Normal
+                }
+                InvocationPriority::High => {
+                    golem_api_grpc::proto::golem::worker::InvocationPriority::High
+                }
+                InvocationPriority::Urgent => {
+                    golem_api_grpc::proto::golem::worker::InvocationPriority::Urgent
+                }
+            }
+        }
+    }
+
+    impl From<golem_api_grpc::proto::golem::worker::InvocationPriority> for InvocationPriority {
+        fn from(value: golem_api_grpc::proto::golem::worker::InvocationPriority) -> Self {
+            match value {
+                golem_api_grpc::proto::golem::worker::InvocationPriority::Low => {
+                    InvocationPriority::Low
+                }
+                golem_api_grpc::proto::golem::worker::InvocationPriority::Normal => {
+                    InvocationPriority::Normal
+                }
+                golem_api_grpc::proto::golem::worker::InvocationPriority::High => {
+                    InvocationPriority::High
+                }
+                golem_api_grpc::proto::golem::worker::InvocationPriority::Urgent => {
+                    InvocationPriority::Urgent
+                }
+            }
+        }
+    }
+
     impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerError> for WorkerError {
         type Error = String;
 
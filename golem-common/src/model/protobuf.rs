@@ -24,7 +24,8 @@ use crate::model::{
     InitialComponentFile, InitialComponentFileKey, LogLevel, NumberOfShards, Pod, PromiseId,
     RoutingTable, RoutingTableEntry, ScanCursor, ShardId, StringFilterComparator, TargetWorkerId,
     Timestamp, WorkerCreatedAtFilter, WorkerEnvFilter, WorkerEvent, WorkerFilter, WorkerId,
-    WorkerNameFilter, WorkerNotFilter, WorkerStatus, WorkerStatusFilter, WorkerVersionFilter,
+    WorkerLabelFilter, WorkerNameFilter, WorkerNotFilter, WorkerStatus, WorkerStatusFilter,
+    WorkerVersionFilter,
 };
 use golem_api_grpc::proto::golem;
 use golem_api_grpc::proto::golem::shardmanager::{
@@ -250,6 +251,13 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerFilter> for WorkerFilte
                     filter.comparator.try_into()?,
                     filter.value,
                 )),
+                golem_api_grpc::proto::golem::worker::worker_filter::Filter::Label(filter) => {
+                    Ok(WorkerFilter::new_label(
+                        filter.name,
+                        filter.comparator.try_into()?,
+                        filter.value,
+                    ))
+                }
                 golem_api_grpc::proto::golem::worker::worker_filter::Filter::Not(filter) => {
                     let filter = *filter.filter.ok_or_else(|| "Missing filter".to_string())?;
                     Ok(WorkerFilter::new_not(filter.try_into()?))
@@ -323,6 +331,17 @@ impl From<WorkerFilter> for golem_api_grpc::proto::golem::worker::WorkerFilter {
                     value,
                 },
             ),
+            WorkerFilter::Label(WorkerLabelFilter {
+                name,
+                comparator,
+                value,
+            }) => golem_api_grpc::proto::golem::worker::worker_filter::Filter::Label(
+                golem_api_grpc::proto::golem::worker::WorkerLabelFilter {
+                    name,
+                    comparator: comparator.into(),
+                    value,
+                },
+            ),
             WorkerFilter::Status(WorkerStatusFilter { comparator, value }) => {
                 golem_api_grpc::proto::golem::worker::worker_filter::Filter::Status(
                     golem_api_grpc::proto::golem::worker::WorkerStatusFilter {
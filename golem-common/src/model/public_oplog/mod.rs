@@ -24,7 +24,8 @@ use crate::model::agent::DataValue;
 use crate::model::invocation_context::{AttributeValue, SpanId, TraceId};
 use crate::model::lucene::{LeafQuery, Query};
 use crate::model::oplog::{
-    DurableFunctionType, LogLevel, OplogIndex, PersistenceLevel, WorkerResourceId,
+    DurableFunctionType, InvocationPriority, LogLevel, OplogIndex, PersistenceLevel,
+    WorkerResourceId,
 };
 use crate::model::plugin::PluginInstallation;
 use crate::model::regions::OplogRegion;
@@ -616,6 +617,35 @@ pub struct DropAgentInstanceParameters {
     pub key: AgentInstanceKey,
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, IntoValue)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct SetLabelParameters {
+    pub timestamp: Timestamp,
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, IntoValue)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveLabelParameters {
+    pub timestamp: Timestamp,
+    pub key: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, IntoValue)]
+#[cfg_attr(feature = "poem", derive(poem_openapi::Object))]
+#[cfg_attr(feature = "poem", oai(rename_all = "camelCase"))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePendingInvocationPriorityParameters {
+    pub timestamp: Timestamp,
+    pub idempotency_key: IdempotencyKey,
+    pub priority: InvocationPriority,
+}
+
 /// A mirror of the core `OplogEntry` type, without the undefined arbitrary payloads.
 ///
 /// Instead, it encodes all payloads with wasm-rpc `Value` types. This makes this the base type
@@ -708,6 +738,12 @@ pub enum PublicOplogEntry {
     CreateAgentInstance(CreateAgentInstanceParameters),
     /// Dropped an agent instance
     DropAgentInstance(DropAgentInstanceParameters),
+    /// Set a user-defined label on the worker
+    SetLabel(SetLabelParameters),
+    /// Removed a user-defined label from the worker
+    RemoveLabel(RemoveLabelParameters),
+    /// Changed the priority of a pending invocation
+    ChangePendingInvocationPriority(ChangePendingInvocationPriorityParameters),
 }
 
 impl PublicOplogEntry {
@@ -1021,6 +1057,31 @@ impl PublicOplogEntry {
                     || Self::string_match("drop-agent-instance", &[], query_path, query)
                 // TODO: match in key and parameters
             }
+            PublicOplogEntry::SetLabel(params) => {
+                Self::string_match("setlabel", &[], query_path, query)
+                    || Self::string_match("set-label", &[], query_path, query)
+                    || Self::string_match("label", &[], query_path, query)
+                    || Self::string_match(&params.key, &[], query_path, query)
+                    || Self::string_match(&params.value, &[], query_path, query)
+            }
+            PublicOplogEntry::RemoveLabel(params) => {
+                Self::string_match("removelabel", &[], query_path, query)
+                    || Self::string_match("remove-label", &[], query_path, query)
+                    || Self::string_match("label", &[], query_path, query)
+                    || Self::string_match(&params.key, &[], query_path, query)
+            }
+            PublicOplogEntry::ChangePendingInvocationPriority(params) => {
+                Self::string_match("changependinginvocationpriority", &[], query_path, query)
+                    || Self::string_match(
+                        "change-pending-invocation-priority",
+                        &[],
+                        query_path,
+                        query,
+                    )
+                    || Self::string_match("priority", &[], query_path, query)
+                    || Self::string_match(&params.idempotency_key.value, &[], query_path, query)
+                    || Self::string_match(&params.priority.to_string(), &[], query_path, query)
+            }
         }
     }
 
@@ -15,7 +15,8 @@
 use crate::model::invocation_context::{SpanId, TraceId};
 use crate::model::oplog::{LogLevel, OplogIndex, WorkerResourceId};
 use crate::model::public_oplog::{
-    ActivatePluginParameters, CancelInvocationParameters, ChangePersistenceLevelParameters,
+    ActivatePluginParameters, CancelInvocationParameters,
+    ChangePendingInvocationPriorityParameters, ChangePersistenceLevelParameters,
     ChangeRetryPolicyParameters, CreateAgentInstanceParameters, CreateParameters,
     DeactivatePluginParameters, DescribeResourceParameters, DropAgentInstanceParameters,
     EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
@@ -25,9 +26,10 @@ use crate::model::public_oplog::{
     PendingWorkerInvocationParameters, PluginInstallationDescription, PublicAttribute,
     PublicAttributeValue, PublicDurableFunctionType, PublicExternalSpanData, PublicLocalSpanData,
     PublicOplogEntry, PublicRetryConfig, PublicSpanData, PublicUpdateDescription,
-    PublicWorkerInvocation, ResourceParameters, RevertParameters, SetSpanAttributeParameters,
-    SnapshotBasedUpdateParameters, StartSpanParameters, StringAttributeValue,
-    SuccessfulUpdateParameters, TimestampParameter, WriteRemoteBatchedParameters,
+    PublicWorkerInvocation, RemoveLabelParameters, ResourceParameters, RevertParameters,
+    SetLabelParameters, SetSpanAttributeParameters, SnapshotBasedUpdateParameters,
+    StartSpanParameters, StringAttributeValue, SuccessfulUpdateParameters, TimestampParameter,
+    WriteRemoteBatchedParameters,
 };
 use crate::model::regions::OplogRegion;
 use crate::model::{AgentInstanceKey, Empty};
@@ -519,6 +521,32 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::OplogEntry> for PublicOplogEn
                     },
                 },
             )),
+            Entry::SetLabel(set_label) => Ok(PublicOplogEntry::SetLabel(SetLabelParameters {
+                timestamp: set_label.timestamp.ok_or("Missing timestamp field")?.into(),
+                key: set_label.key,
+                value: set_label.value,
+            })),
+            Entry::RemoveLabel(remove_label) => {
+                Ok(PublicOplogEntry::RemoveLabel(RemoveLabelParameters {
+                    timestamp: remove_label
+                        .timestamp
+                        .ok_or("Missing timestamp field")?
+                        .into(),
+                    key: remove_label.key,
+                }))
+            }
+            Entry::ChangePendingInvocationPriority(change) => {
+                Ok(PublicOplogEntry::ChangePendingInvocationPriority(
+                    ChangePendingInvocationPriorityParameters {
+                        timestamp: change.timestamp.ok_or("Missing timestamp field")?.into(),
+                        priority: change.priority().into(),
+                        idempotency_key: change
+                            .idempotency_key
+                            .ok_or("Missing idempotency_key field")?
+                            .into(),
+                    },
+                ))
+            }
         }
     }
 }
@@ -932,6 +960,40 @@ impl TryFrom<PublicOplogEntry> for golem_api_grpc::proto::golem::worker::OplogEn
                     )),
                 }
             }
+            PublicOplogEntry::SetLabel(set_label) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(Entry::SetLabel(
+                        golem_api_grpc::proto::golem::worker::SetLabelParameters {
+                            timestamp: Some(set_label.timestamp.into()),
+                            key: set_label.key,
+                            value: set_label.value,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::RemoveLabel(remove_label) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(Entry::RemoveLabel(
+                        golem_api_grpc::proto::golem::worker::RemoveLabelParameters {
+                            timestamp: Some(remove_label.timestamp.into()),
+                            key: remove_label.key,
+                        },
+                    )),
+                }
+            }
+            PublicOplogEntry::ChangePendingInvocationPriority(change) => {
+                golem_api_grpc::proto::golem::worker::OplogEntry {
+                    entry: Some(Entry::ChangePendingInvocationPriority(
+                        golem_api_grpc::proto::golem::worker::ChangePendingInvocationPriorityParameters {
+                            timestamp: Some(change.timestamp.into()),
+                            idempotency_key: Some(change.idempotency_key.into()),
+                            priority: Into::<
+                                golem_api_grpc::proto::golem::worker::InvocationPriority,
+                            >::into(change.priority) as i32,
+                        },
+                    )),
+                }
+            }
         })
     }
 }
@@ -25,6 +25,9 @@ pub struct WorkerCreationRequest {
     pub env: HashMap<String, String>,
     #[cfg_attr(feature = "poem", oai(default))]
     pub wasi_config_vars: WasiConfigVars,
+    #[cfg_attr(feature = "poem", oai(default))]
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
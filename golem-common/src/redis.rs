@@ -208,6 +208,45 @@ impl RedisLabelledApi<'_> {
         )
     }
 
+    pub async fn incr_by<R, K>(&self, key: K, increment: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: AsRef<str>,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "INCRBY",
+            self.pool.incr_by(self.prefixed_key(key), increment).await,
+        )
+    }
+
+    /// Evaluates a Lua script atomically. The keys are prefixed, the same way as in other commands.
+    pub async fn eval<R, K, V>(&self, script: &str, keys: Vec<K>, args: V) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: AsRef<str>,
+        V: TryInto<MultipleValues> + Send,
+        V::Error: Into<RedisError> + Send,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "EVAL",
+            self.pool
+                .eval(
+                    script.to_string(),
+                    keys.iter()
+                        .map(|k| self.prefixed_key(k))
+                        .collect::<Vec<_>>(),
+                    args,
+                )
+                .await,
+        )
+    }
+
     pub async fn mget<R, K>(&self, keys: K) -> RedisResult<R>
     where
         R: FromRedis,
@@ -275,6 +314,23 @@ impl RedisLabelledApi<'_> {
         )
     }
 
+    pub async fn hincrby<R, K, F>(&self, key: K, field: F, increment: i64) -> RedisResult<R>
+    where
+        R: FromRedis,
+        K: AsRef<str>,
+        F: Into<RedisKey> + Send,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+        self.record(
+            start,
+            "HINCRBY",
+            self.pool
+                .hincrby(self.prefixed_key(key), field, increment)
+                .await,
+        )
+    }
+
     pub async fn hkeys<R, K>(&self, key: K) -> RedisResult<R>
     where
         R: FromRedis,
@@ -754,6 +810,41 @@ impl RedisLabelledApi<'_> {
         )
     }
 
+    /// Incrementally iterates the field names of a hash, returning the next cursor and the fields
+    pub async fn hscan_fields<K>(
+        &self,
+        key: K,
+        pattern: &str,
+        cursor: u64,
+        count: u64,
+    ) -> RedisResult<(u64, Vec<String>)>
+    where
+        K: AsRef<str>,
+    {
+        self.ensure_connected().await?;
+        let start = Instant::now();
+
+        //https://redis.io/commands/hscan/
+        let args: Vec<String> = vec![
+            self.prefixed_key(key),
+            cursor.to_string(),
+            "MATCH".to_string(),
+            pattern.to_string(),
+            "COUNT".to_string(),
+            count.to_string(),
+        ];
+
+        self.record(
+            start,
+            "HSCAN",
+            self.pool
+                .next()
+                .custom_raw(cmd!("HSCAN"), args)
+                .await
+                .and_then(|f| self.parse_hash_scan_frame(f)),
+        )
+    }
+
     pub async fn keys<K>(&self, pattern: K) -> RedisResult<Vec<String>>
     where
         K: AsRef<str>,
@@ -829,6 +920,45 @@ impl RedisLabelledApi<'_> {
             ))
         }
     }
+
+    fn parse_hash_scan_frame(&self, frame: Resp3Frame) -> RedisResult<(u64, Vec<String>)> {
+        use fred::prelude::*;
+        if let Resp3Frame::Array { mut data, .. } = frame {
+            if data.len() == 2 {
+                let cursor: u64 = data[0]
+                    .clone()
+                    .try_into()
+                    .and_then(|value: RedisValue| value.convert())?;
+
+                if let Some(Resp3Frame::Array { data, .. }) = data.pop() {
+                    // The elements are alternating field names and values
+                    let mut fields = Vec::with_capacity(data.len() / 2);
+                    for frame in data.into_iter().step_by(2) {
+                        let field: String = frame
+                            .try_into()
+                            .and_then(|value: RedisValue| value.convert())?;
+                        fields.push(field);
+                    }
+                    Ok((cursor, fields))
+                } else {
+                    Err(RedisError::new(
+                        RedisErrorKind::Protocol,
+                        "Expected second HSCAN result element to be an array.",
+                    ))
+                }
+            } else {
+                Err(RedisError::new(
+                    RedisErrorKind::Protocol,
+                    "Expected two-element bulk string array from HSCAN.",
+                ))
+            }
+        } else {
+            Err(RedisError::new(
+                RedisErrorKind::Protocol,
+                "Expected bulk string array from HSCAN.",
+            ))
+        }
+    }
 }
 
 pub struct RedisTransaction {
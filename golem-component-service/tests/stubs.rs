@@ -42,6 +42,7 @@ impl LimitService for StubLimitService {
         Ok(ResourceLimits {
             available_fuel: 1000,
             max_memory_per_worker: 1024 * 1024 * 1024, // 1 GB
+            available_storage: 1024 * 1024 * 1024,     // 1 GB
         })
     }
 }
@@ -80,6 +80,7 @@ impl DebugConfig {
             public_worker_api: self.public_worker_api,
             memory: self.memory,
             rdbms: self.rdbms,
+            outgoing_http: default_golem_config.outgoing_http,
             resource_limits: self.resource_limits,
             component_service: ComponentServiceConfig::Grpc(self.component_service),
             component_cache: self.component_cache,
@@ -49,9 +49,11 @@ use golem_worker_executor::services::oplog::{Oplog, OplogService};
 use golem_worker_executor::services::plugins::Plugins;
 use golem_worker_executor::services::projects::ProjectService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms::RdbmsService;
-use golem_worker_executor::services::resource_limits::ResourceLimits;
+use golem_worker_executor::services::resource_limits::{ResourceLimits, ResourceLimitsDisabled};
 use golem_worker_executor::services::rpc::Rpc;
+use golem_worker_executor::services::saga::SagaService;
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::worker::WorkerService;
 use golem_worker_executor::services::worker_event::WorkerEventService;
@@ -289,6 +291,10 @@ impl UpdateManagement for DebugContext {
         self.durable_ctx.end_call_snapshotting_function()
     }
 
+    async fn on_worker_update_snapshot_saved(&self) -> Result<(), WorkerExecutorError> {
+        self.durable_ctx.on_worker_update_snapshot_saved().await
+    }
+
     async fn on_worker_update_failed(
         &self,
         target_version: ComponentVersion,
@@ -597,6 +603,8 @@ impl WorkerCtx for DebugContext {
         _account_id: AccountId,
         owned_worker_id: OwnedWorkerId,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -624,6 +632,8 @@ impl WorkerCtx for DebugContext {
         let golem_ctx = DurableWorkerCtx::create(
             owned_worker_id,
             promise_service,
+            pubsub_service,
+            saga_service,
             worker_service,
             worker_enumeration_service,
             key_value_service,
@@ -644,6 +654,8 @@ impl WorkerCtx for DebugContext {
             plugins,
             worker_fork,
             project_service,
+            // Debugged workers are copies of existing ones, their files must not count against the account's storage
+            ResourceLimitsDisabled::new(),
         )
         .await?;
         Ok(Self {
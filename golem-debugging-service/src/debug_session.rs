@@ -535,6 +535,22 @@ fn get_oplog_entry_from_public_oplog_entry(
                 key: drop_agent_instance.key,
             })
         }
+        PublicOplogEntry::SetLabel(set_label) => Ok(OplogEntry::SetLabel {
+            timestamp: set_label.timestamp,
+            key: set_label.key,
+            value: set_label.value,
+        }),
+        PublicOplogEntry::RemoveLabel(remove_label) => Ok(OplogEntry::RemoveLabel {
+            timestamp: remove_label.timestamp,
+            key: remove_label.key,
+        }),
+        PublicOplogEntry::ChangePendingInvocationPriority(change) => {
+            Ok(OplogEntry::ChangePendingInvocationPriority {
+                timestamp: change.timestamp,
+                idempotency_key: change.idempotency_key,
+                priority: change.priority,
+            })
+        }
     }
 }
 
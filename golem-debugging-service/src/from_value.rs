@@ -14,7 +14,7 @@
 
 use golem_api_grpc::proto::golem::worker::UpdateMode;
 use golem_common::model::oplog::{OplogIndex, WorkerError};
-use golem_common::model::{ComponentId, ComponentVersion, PromiseId, WorkerId};
+use golem_common::model::{ComponentId, ComponentVersion, IdempotencyKey, PromiseId, WorkerId};
 use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
 use golem_wasm_rpc::Value;
 use golem_worker_executor::durable_host::http::serialized::{
@@ -300,6 +300,24 @@ impl FromValue for PromiseId {
     }
 }
 
+impl FromValue for IdempotencyKey {
+    fn from_value(value: &Value) -> Result<Self, String>
+    where
+        Self: Sized,
+    {
+        match value {
+            Value::Record(rec) => {
+                if rec.len() != 1 {
+                    Err("Failed to get IdempotencyKey from Value".to_string())
+                } else {
+                    Ok(IdempotencyKey::new(String::from_value(&rec[0])?))
+                }
+            }
+            _ => Err("Failed to get IdempotencyKey from Value".to_string()),
+        }
+    }
+}
+
 impl FromValue for InterruptKind {
     fn from_value(value: &Value) -> Result<Self, String>
     where
@@ -763,6 +781,23 @@ impl FromValue for WorkerExecutorError {
 
                     _ => Err("Failed to get GolemError. Not a Record".to_string()),
                 },
+                (26, Some(error)) => match error.deref() {
+                    Value::Record(values) => {
+                        if values.len() != 2 {
+                            return Err("Failed to get GolemError".to_string());
+                        }
+
+                        let worker_id = WorkerId::from_value(&values[0])?;
+                        let idempotency_key = IdempotencyKey::from_value(&values[1])?;
+
+                        Ok(WorkerExecutorError::invocation_not_found(
+                            worker_id,
+                            idempotency_key,
+                        ))
+                    }
+
+                    _ => Err("Failed to get GolemError. Not a Record".to_string()),
+                },
 
                 _ => Err("Failed to get GolemError. Not a Record".to_string()),
             },
@@ -48,7 +48,9 @@ use golem_worker_executor::services::oplog::OplogService;
 use golem_worker_executor::services::plugins::{Plugins, PluginsObservations};
 use golem_worker_executor::services::projects::ProjectService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
+use golem_worker_executor::services::saga::SagaService;
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::shard::ShardService;
 use golem_worker_executor::services::shard_manager::ShardManagerService;
@@ -139,6 +141,8 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         golem_config: Arc<GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -189,6 +193,8 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             key_value_service.clone(),
@@ -223,6 +229,8 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             shard_manager_service.clone(),
@@ -253,6 +261,8 @@ impl Bootstrap<DebugContext> for ServerBootstrap {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config.clone(),
             shard_service,
             key_value_service,
@@ -352,6 +362,8 @@ pub fn create_debug_wasmtime_linker(engine: &Engine) -> anyhow::Result<Linker<De
     golem_api_1_x::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
     golem_api_1_x::oplog::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
     golem_api_1_x::context::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+    golem_api_1_x::pubsub::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+    golem_api_1_x::saga::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
     golem_durability::durability::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
     golem_agent::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
     golem_wasm_rpc::golem_rpc_0_2_x::types::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
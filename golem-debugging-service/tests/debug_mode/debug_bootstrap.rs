@@ -25,9 +25,11 @@ use golem_worker_executor::services::oplog::OplogService;
 use golem_worker_executor::services::plugins::{Plugins, PluginsObservations};
 use golem_worker_executor::services::projects::ProjectService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms;
 use golem_worker_executor::services::resource_limits;
 use golem_worker_executor::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
+use golem_worker_executor::services::saga::SagaService;
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::shard::ShardService;
 use golem_worker_executor::services::shard_manager::ShardManagerService;
@@ -117,6 +119,8 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         golem_config: Arc<GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -176,6 +180,8 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             key_value_service.clone(),
@@ -208,6 +214,8 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             shard_manager_service.clone(),
@@ -238,6 +246,8 @@ impl Bootstrap<DebugContext> for TestDebuggingServerBootStrap {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config.clone(),
             shard_service,
             key_value_service,
@@ -18,8 +18,10 @@ use golem_worker_executor::services::oplog::OplogService;
 use golem_worker_executor::services::plugins::{Plugins, PluginsObservations};
 use golem_worker_executor::services::projects::ProjectService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms::RdbmsService;
 use golem_worker_executor::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
+use golem_worker_executor::services::saga::SagaService;
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::shard::ShardService;
 use golem_worker_executor::services::shard_manager::ShardManagerService;
@@ -87,6 +89,8 @@ impl Bootstrap<TestWorkerCtx> for RegularWorkerExecutorBootstrap {
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         golem_config: Arc<GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -121,6 +125,8 @@ impl Bootstrap<TestWorkerCtx> for RegularWorkerExecutorBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             key_value_service.clone(),
@@ -153,6 +159,8 @@ impl Bootstrap<TestWorkerCtx> for RegularWorkerExecutorBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             shard_manager_service.clone(),
@@ -182,6 +190,8 @@ impl Bootstrap<TestWorkerCtx> for RegularWorkerExecutorBootstrap {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config,
             shard_service,
             key_value_service,
@@ -207,6 +217,8 @@ impl Bootstrap<TestWorkerCtx> for RegularWorkerExecutorBootstrap {
         golem_api_1_x::host::add_to_linker_get_host(&mut linker, get_test_durable_ctx)?;
         golem_api_1_x::oplog::add_to_linker_get_host(&mut linker, get_test_durable_ctx)?;
         golem_api_1_x::context::add_to_linker_get_host(&mut linker, get_test_durable_ctx)?;
+        golem_api_1_x::pubsub::add_to_linker_get_host(&mut linker, get_test_durable_ctx)?;
+        golem_api_1_x::saga::add_to_linker_get_host(&mut linker, get_test_durable_ctx)?;
         golem_durability::durability::add_to_linker_get_host(&mut linker, get_test_durable_ctx)?;
         golem_wasm_rpc::golem_rpc_0_2_x::types::add_to_linker_get_host(
             &mut linker,
@@ -34,9 +34,11 @@ use golem_worker_executor::services::oplog::{Oplog, OplogService};
 use golem_worker_executor::services::plugins::Plugins;
 use golem_worker_executor::services::projects::ProjectService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::rdbms::RdbmsService;
 use golem_worker_executor::services::resource_limits::ResourceLimits;
 use golem_worker_executor::services::rpc::Rpc;
+use golem_worker_executor::services::saga::SagaService;
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::worker::WorkerService;
 use golem_worker_executor::services::worker_enumeration::WorkerEnumerationService;
@@ -72,6 +74,8 @@ impl WorkerCtx for TestWorkerCtx {
         _account_id: AccountId,
         owned_worker_id: OwnedWorkerId,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -93,12 +97,14 @@ impl WorkerCtx for TestWorkerCtx {
         file_loader: Arc<FileLoader>,
         plugins: Arc<dyn Plugins>,
         worker_fork: Arc<dyn WorkerForkService>,
-        _resource_limits: Arc<dyn ResourceLimits>,
+        resource_limits: Arc<dyn ResourceLimits>,
         project_service: Arc<dyn ProjectService>,
     ) -> Result<Self, WorkerExecutorError> {
         let durable_ctx = DurableWorkerCtx::create(
             owned_worker_id,
             promise_service,
+            pubsub_service,
+            saga_service,
             worker_service,
             worker_enumeration_service,
             key_value_service,
@@ -119,6 +125,7 @@ impl WorkerCtx for TestWorkerCtx {
             plugins,
             worker_fork,
             project_service,
+            resource_limits,
         )
         .await?;
         Ok(Self { durable_ctx })
@@ -647,6 +654,10 @@ impl UpdateManagement for TestWorkerCtx {
         self.durable_ctx.end_call_snapshotting_function()
     }
 
+    async fn on_worker_update_snapshot_saved(&self) -> Result<(), WorkerExecutorError> {
+        self.durable_ctx.on_worker_update_snapshot_saved().await
+    }
+
     async fn on_worker_update_failed(
         &self,
         target_version: ComponentVersion,
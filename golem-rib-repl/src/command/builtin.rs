@@ -1,4 +1,7 @@
-use crate::{parse_with_clap, Command, ReplContext};
+use crate::compiler::compile_rib_script;
+use crate::debugger::ReplDebugSession;
+use crate::raw::load_rib_script;
+use crate::{parse_with_clap, Command, ReplContext, RibExecutionError};
 use clap::Parser;
 use crossterm::cursor::MoveTo;
 use crossterm::{
@@ -6,8 +9,9 @@ use crossterm::{
     terminal::{Clear as TermClear, ClearType},
 };
 use golem_wasm_ast::analysis::AnalysedType;
-use rib::{CompilerOutput, ComponentDependencies, Expr, RibCompilationError};
+use rib::{CompilerOutput, ComponentDependencies, DebugStatus, Expr, RibCompilationError};
 use std::io::stdout;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(about = "Display type of a rib expression")]
@@ -154,3 +158,229 @@ impl Command for Exports {
             .print_rib_compilation_error(&error)
     }
 }
+
+#[derive(Parser, Debug)]
+#[command(
+    about = "Debug the rib script of the current session, optionally followed by a rib expression"
+)]
+pub struct DebugInput {
+    /// Worker function calls return a value generated from the return type
+    /// of the function instead of invoking the worker
+    #[arg(long)]
+    pub mock: bool,
+
+    /// Pause when the execution reaches this line. Can be repeated
+    #[arg(long = "break", value_name = "LINE")]
+    pub breakpoints: Vec<i32>,
+
+    /// Rib expression to debug after the existing rib script of the session.
+    /// Multiline rib expressions are not supported.
+    pub expr: Vec<String>,
+}
+
+impl DebugInput {
+    pub fn as_text(&self) -> String {
+        self.expr.join(" ")
+    }
+}
+
+pub struct Debug;
+
+impl Command for Debug {
+    type Input = DebugInput;
+    type Output = (DebugStatus, Option<String>);
+    type InputParseError = clap::Error;
+    type ExecutionError = RibExecutionError;
+
+    fn parse(
+        &self,
+        input: &str,
+        _repl_context: &ReplContext,
+    ) -> Result<Self::Input, Self::InputParseError> {
+        let parse_result = parse_with_clap::<DebugInput>(self.name().as_str(), input)?;
+
+        Ok(parse_result)
+    }
+
+    fn execute(
+        &self,
+        input: Self::Input,
+        repl_context: &mut ReplContext,
+    ) -> Result<Self::Output, Self::ExecutionError> {
+        let expr = input.as_text();
+        let expr = expr.strip_suffix(";").unwrap_or(expr.as_str()).trim();
+
+        let rib_script = if expr.is_empty() {
+            repl_context.get_repl_state().rib_script().as_text()
+        } else {
+            repl_context.get_new_rib_script(expr).as_text()
+        };
+
+        let repl_state = repl_context.get_repl_state();
+
+        let compiler_output = compile_rib_script(&rib_script, repl_state.clone())
+            .map_err(RibExecutionError::RibCompilationError)?;
+
+        let mut debug_session = ReplDebugSession::new(
+            &rib_script,
+            compiler_output.rib_byte_code,
+            compiler_output.source_map,
+            &input.breakpoints,
+            input.mock,
+            repl_state,
+        );
+
+        let status = debug_session
+            .status()
+            .map_err(RibExecutionError::RibRuntimeError)?;
+
+        let source_line = debug_session.source_line_of(&status);
+
+        if let DebugStatus::Paused(_) = status {
+            repl_state.start_debug_session(debug_session);
+        }
+
+        Ok((status, source_line))
+    }
+
+    fn print_output(&self, output: Self::Output, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        let (status, source_line) = output;
+        printer.print_debug_status(&status, source_line.as_deref());
+    }
+
+    fn print_input_parse_error(&self, error: Self::InputParseError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_clap_parse_error(&error);
+    }
+
+    fn print_execution_error(&self, error: Self::ExecutionError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_rib_execution_error(&error);
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Load a rib script file into the current session")]
+pub struct LoadInput {
+    /// Path to the rib script file. Imports in the file are resolved relative to the file
+    pub file: PathBuf,
+}
+
+pub struct Load;
+
+impl Command for Load {
+    type Input = LoadInput;
+    type Output = PathBuf;
+    type InputParseError = clap::Error;
+    type ExecutionError = RibExecutionError;
+
+    fn parse(
+        &self,
+        input: &str,
+        _repl_context: &ReplContext,
+    ) -> Result<Self::Input, Self::InputParseError> {
+        let parse_result = parse_with_clap::<LoadInput>(self.name().as_str(), input)?;
+
+        Ok(parse_result)
+    }
+
+    fn execute(
+        &self,
+        input: Self::Input,
+        repl_context: &mut ReplContext,
+    ) -> Result<Self::Output, Self::ExecutionError> {
+        let rib = load_rib_script(&input.file).map_err(RibExecutionError::Custom)?;
+        let rib = rib.trim();
+        let rib = rib.strip_suffix(";").unwrap_or(rib);
+
+        if rib.is_empty() {
+            return Ok(input.file);
+        }
+
+        let repl_state = repl_context.get_repl_state().clone();
+
+        repl_state.update_rib(rib);
+
+        // The loaded script is only compiled here, and gets evaluated
+        // along with the next rib expression in the session
+        match compile_rib_script(&repl_state.current_rib_program(), repl_state.clone()) {
+            Ok(compiler_output) => {
+                repl_context.update_progression(&compiler_output);
+                Ok(input.file)
+            }
+            Err(err) => {
+                repl_state.remove_last_rib_expression();
+                Err(RibExecutionError::RibCompilationError(err))
+            }
+        }
+    }
+
+    fn print_output(&self, output: Self::Output, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_custom_message(&format!("loaded {}", output.display()));
+    }
+
+    fn print_input_parse_error(&self, error: Self::InputParseError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_clap_parse_error(&error);
+    }
+
+    fn print_execution_error(&self, error: Self::ExecutionError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_rib_execution_error(&error);
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(about = "Save the rib script of the current session to a file")]
+pub struct SaveInput {
+    /// Path to the rib script file to be created or overwritten
+    pub file: PathBuf,
+}
+
+pub struct Save;
+
+impl Command for Save {
+    type Input = SaveInput;
+    type Output = PathBuf;
+    type InputParseError = clap::Error;
+    type ExecutionError = std::io::Error;
+
+    fn parse(
+        &self,
+        input: &str,
+        _repl_context: &ReplContext,
+    ) -> Result<Self::Input, Self::InputParseError> {
+        let parse_result = parse_with_clap::<SaveInput>(self.name().as_str(), input)?;
+
+        Ok(parse_result)
+    }
+
+    fn execute(
+        &self,
+        input: Self::Input,
+        repl_context: &mut ReplContext,
+    ) -> Result<Self::Output, Self::ExecutionError> {
+        let rib_script = repl_context.get_repl_state().rib_script().as_source_text();
+
+        std::fs::write(&input.file, format!("{rib_script}\n"))?;
+
+        Ok(input.file)
+    }
+
+    fn print_output(&self, output: Self::Output, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_custom_message(&format!("saved the session to {}", output.display()));
+    }
+
+    fn print_input_parse_error(&self, error: Self::InputParseError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_clap_parse_error(&error);
+    }
+
+    fn print_execution_error(&self, error: Self::ExecutionError, repl_context: &ReplContext) {
+        let printer = repl_context.get_printer();
+        printer.print_custom_error(&error.to_string());
+    }
+}
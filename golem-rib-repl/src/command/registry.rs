@@ -1,4 +1,4 @@
-use crate::command::builtin::{Clear, Exports, TypeInfo};
+use crate::command::builtin::{Clear, Debug, Exports, Load, Save, TypeInfo};
 use crate::UntypedCommand;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -14,6 +14,9 @@ impl CommandRegistry {
         registry.register(TypeInfo);
         registry.register(Clear);
         registry.register(Exports);
+        registry.register(Debug);
+        registry.register(Load);
+        registry.register(Save);
         registry
     }
 
@@ -40,11 +40,12 @@ pub fn compile_rib_script(
 
     let enums = compiler.get_enums();
 
-    let byte_code = RibByteCode::from_expr(&inferred_expr)
+    let (byte_code, source_map) = RibByteCode::from_expr_with_source_map(&inferred_expr)
         .map_err(|err| RibCompilationError::ByteCodeGenerationFail(Box::new(err)))?;
 
     Ok(ReplCompilerOutput {
         rib_byte_code: byte_code,
+        source_map,
         instance_variables,
         identifiers,
         variants,
@@ -55,6 +56,7 @@ pub fn compile_rib_script(
 #[derive(Clone)]
 pub struct ReplCompilerOutput {
     pub rib_byte_code: RibByteCode,
+    pub source_map: RibSourceMap,
     pub instance_variables: InstanceVariables,
     pub identifiers: Vec<VariableId>,
     pub variants: Vec<TypeVariant>,
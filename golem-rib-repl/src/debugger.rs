@@ -0,0 +1,190 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::invoke::ReplRibFunctionInvoke;
+use crate::repl_state::ReplState;
+use crate::value_generator::generate_value;
+use crate::worker_name_gen::DynamicWorkerGen;
+use async_trait::async_trait;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::ValueAndType;
+use rib::{
+    ComponentDependencyKey, DebugStatus, EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName,
+    InstructionId, Interpreter, RibByteCode, RibComponentFunctionInvoke, RibDebugger,
+    RibFunctionInvokeResult, RibInput, RibInterpreterResult, RibSourceMap,
+};
+use std::sync::Arc;
+
+/// An active `:debug` session in the REPL.
+/// While a session is active, every line in the REPL is a debugger command.
+pub(crate) struct ReplDebugSession {
+    debugger: RibDebugger,
+    source_lines: Vec<String>,
+}
+
+impl ReplDebugSession {
+    pub fn new(
+        rib_script: &str,
+        byte_code: RibByteCode,
+        source_map: RibSourceMap,
+        breakpoints: &[i32],
+        mock_worker_calls: bool,
+        repl_state: &Arc<ReplState>,
+    ) -> Self {
+        let worker_name_generator = Arc::new(DynamicWorkerGen::new(repl_state.clone()));
+
+        let interpreter = if mock_worker_calls {
+            Interpreter::new(
+                RibInput::default(),
+                Arc::new(MockedRibFunctionInvoke),
+                worker_name_generator,
+            )
+        } else {
+            Interpreter::new(
+                RibInput::default(),
+                Arc::new(ReplRibFunctionInvoke::new(repl_state.clone())),
+                worker_name_generator,
+            )
+        };
+
+        let mut debugger = RibDebugger::new(interpreter, byte_code, source_map);
+
+        for line in breakpoints {
+            debugger.add_breakpoint(*line);
+        }
+
+        ReplDebugSession {
+            debugger,
+            source_lines: rib_script.lines().map(|line| line.to_string()).collect(),
+        }
+    }
+
+    // The rib source of the line the debugger is paused at
+    pub fn source_line_of(&self, status: &DebugStatus) -> Option<String> {
+        match status {
+            DebugStatus::Paused(location) => location
+                .line()
+                .and_then(|line| usize::try_from(line - 1).ok())
+                .and_then(|index| self.source_lines.get(index))
+                .cloned(),
+            DebugStatus::Finished(_) => None,
+        }
+    }
+
+    pub fn status(&mut self) -> RibInterpreterResult<DebugStatus> {
+        self.debugger.status()
+    }
+
+    pub async fn run(&mut self, command: &DebugCommand) -> RibInterpreterResult<DebugOutput> {
+        match command {
+            DebugCommand::Step => self.debugger.step().await.map(DebugOutput::Status),
+            DebugCommand::Next => self.debugger.step_line().await.map(DebugOutput::Status),
+            DebugCommand::Continue => self.debugger.resume().await.map(DebugOutput::Status),
+            DebugCommand::Where => self.debugger.status().map(DebugOutput::Status),
+            DebugCommand::Break(line) => {
+                self.debugger.add_breakpoint(*line);
+                Ok(DebugOutput::Breakpoints(self.debugger.breakpoints()))
+            }
+            DebugCommand::Delete(line) => {
+                self.debugger.remove_breakpoint(*line);
+                Ok(DebugOutput::Breakpoints(self.debugger.breakpoints()))
+            }
+            DebugCommand::Stack => Ok(DebugOutput::Stack(self.debugger.stack())),
+            DebugCommand::Variables => Ok(DebugOutput::Variables(
+                self.debugger
+                    .variables()
+                    .into_iter()
+                    .map(|(variable_id, value)| (variable_id.to_string(), value))
+                    .collect(),
+            )),
+            DebugCommand::Quit => Ok(DebugOutput::Quit),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum DebugCommand {
+    Step,
+    Next,
+    Continue,
+    Where,
+    Break(i32),
+    Delete(i32),
+    Stack,
+    Variables,
+    Quit,
+}
+
+impl DebugCommand {
+    pub const HELP: &'static str = "step (s) | next (n) | continue (c) | break (b) <line> | delete (d) <line> | stack | vars | where (w) | quit (q)";
+
+    pub fn from_str(input: &str) -> Result<DebugCommand, String> {
+        let words = input.split_whitespace().collect::<Vec<_>>();
+
+        let line = || {
+            words
+                .get(1)
+                .ok_or("expecting a line number".to_string())
+                .and_then(|line| {
+                    line.parse::<i32>()
+                        .map_err(|_| format!("invalid line number `{line}`"))
+                })
+        };
+
+        match words.first().copied() {
+            // Pressing enter repeats stepping to the next line
+            None | Some("next") | Some("n") => Ok(DebugCommand::Next),
+            Some("step") | Some("s") => Ok(DebugCommand::Step),
+            Some("continue") | Some("c") => Ok(DebugCommand::Continue),
+            Some("where") | Some("w") => Ok(DebugCommand::Where),
+            Some("break") | Some("b") => line().map(DebugCommand::Break),
+            Some("delete") | Some("d") => line().map(DebugCommand::Delete),
+            Some("stack") => Ok(DebugCommand::Stack),
+            Some("vars") => Ok(DebugCommand::Variables),
+            Some("quit") | Some("q") => Ok(DebugCommand::Quit),
+            Some(other) => Err(format!(
+                "unknown debug command `{other}`. Expected one of: {}",
+                Self::HELP
+            )),
+        }
+    }
+}
+
+pub(crate) enum DebugOutput {
+    Status(DebugStatus),
+    Breakpoints(Vec<i32>),
+    Stack(Vec<String>),
+    Variables(Vec<(String, String)>),
+    Quit,
+}
+
+// Worker calls in a debug session (with `--mock`) return a value generated from
+// the return type of the function, without invoking any worker
+struct MockedRibFunctionInvoke;
+
+#[async_trait]
+impl RibComponentFunctionInvoke for MockedRibFunctionInvoke {
+    async fn invoke(
+        &self,
+        _component_dependency: ComponentDependencyKey,
+        _instruction_id: &InstructionId,
+        _worker_name: Option<EvaluatedWorkerName>,
+        _function_name: EvaluatedFqFn,
+        _args: EvaluatedFnArgs,
+        return_type: Option<AnalysedType>,
+    ) -> RibFunctionInvokeResult {
+        Ok(return_type
+            .map(|return_type| ValueAndType::new(generate_value(&return_type), return_type)))
+    }
+}
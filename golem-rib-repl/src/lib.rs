@@ -26,6 +26,7 @@ pub use rib_repl::*;
 
 mod command;
 mod compiler;
+mod debugger;
 mod dependency_manager;
 mod eval;
 mod invoke;
@@ -1,18 +1,118 @@
+use rib::{resolve_imports, FileSystemImportResolver};
+use std::path::Path;
+
 #[derive(Default, Clone)]
 pub struct RawRibScript {
-    rib_statements: Vec<String>,
+    rib_statements: Vec<RawRibStatement>,
+}
+
+/// A rib statement of the session, along with the source it was written as,
+/// which differs from the statement if it has imports in it
+#[derive(Clone)]
+struct RawRibStatement {
+    rib: String,
+    source: String,
 }
 
 impl RawRibScript {
     pub fn as_text(&self) -> String {
-        self.rib_statements.join(";\n")
+        self.rib_statements
+            .iter()
+            .map(|statement| statement.rib.as_str())
+            .collect::<Vec<_>>()
+            .join(";\n")
+    }
+
+    /// The script as it was written in the session, with all the import
+    /// statements placed first rather than the imported scripts inlined
+    pub fn as_source_text(&self) -> String {
+        let mut imports: Vec<&str> = vec![];
+        let mut statements: Vec<String> = vec![];
+
+        for statement in &self.rib_statements {
+            let (import_lines, lines): (Vec<&str>, Vec<&str>) = statement
+                .source
+                .lines()
+                .partition(|line| line.trim().starts_with("import "));
+
+            for import in import_lines {
+                let import = import.trim();
+                if !imports.contains(&import) {
+                    imports.push(import);
+                }
+            }
+
+            let rest = lines.join("\n");
+            let rest = rest.trim();
+            let rest = rest.strip_suffix(";").unwrap_or(rest);
+
+            if !rest.is_empty() {
+                statements.push(rest.to_string());
+            }
+        }
+
+        imports
+            .into_iter()
+            .map(|import| import.to_string())
+            .chain(std::iter::once(statements.join(";\n")).filter(|rest| !rest.is_empty()))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     pub fn push(&mut self, rib_statement: &str) {
-        self.rib_statements.push(rib_statement.to_string());
+        self.push_with_source(rib_statement, rib_statement);
+    }
+
+    pub fn push_with_source(&mut self, rib_statement: &str, source: &str) {
+        self.rib_statements.push(RawRibStatement {
+            rib: rib_statement.to_string(),
+            source: source.to_string(),
+        });
     }
 
     pub fn pop(&mut self) {
         self.rib_statements.pop();
     }
 }
+
+/// Reads a Rib script file, with the imports in it resolved relative to the file
+pub(crate) fn load_rib_script(path: &Path) -> Result<String, String> {
+    let path = path
+        .canonicalize()
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    // Importing the file itself will read the file and resolve its imports
+    resolve_imports(
+        &format!("import \"{file_name}\";"),
+        &FileSystemImportResolver::new(base_dir),
+    )
+    .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::RawRibScript;
+
+    #[test]
+    fn test_source_text_keeps_imports_first() {
+        let mut script = RawRibScript::default();
+
+        script.push_with_source("let x: u32 = 1;\nlet y = x", "import \"x.rib\";\nlet y = x");
+        script.push("let z = y + 1");
+        script.push_with_source("let x: u32 = 1;\nz + x", "import \"x.rib\";\nz + x");
+
+        assert_eq!(
+            script.as_source_text(),
+            "import \"x.rib\";\nlet y = x;\nlet z = y + 1;\nz + x"
+        );
+    }
+}
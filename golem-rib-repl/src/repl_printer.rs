@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ReplBootstrapError;
+use crate::{ReplBootstrapError, RibExecutionError};
 use colored::Colorize;
 use golem_wasm_ast::analysis::analysed_type::{record, str, u64};
 use golem_wasm_ast::analysis::{AnalysedResourceMode, AnalysedType, NameTypePair, TypeHandle};
@@ -79,6 +79,53 @@ pub trait ReplPrinter {
         }
     }
 
+    fn print_debug_status(&self, status: &DebugStatus, source_line: Option<&str>) {
+        match status {
+            DebugStatus::Paused(location) => {
+                let line = location
+                    .line()
+                    .map(|line| line.to_string())
+                    .unwrap_or_else(|| "?".to_string());
+
+                println!(
+                    "{} {} {}",
+                    format!("[line {line}]").magenta(),
+                    source_line.unwrap_or_default().white(),
+                    format!(
+                        "(instruction {}: {:?})",
+                        location.instruction_index, location.instruction
+                    )
+                    .truecolor(128, 128, 128)
+                );
+            }
+
+            DebugStatus::Finished(result) => {
+                println!("{}", "[debug session finished]".magenta());
+                self.print_rib_result(result);
+            }
+        }
+    }
+
+    fn print_debug_stack(&self, stack: &[String]) {
+        if stack.is_empty() {
+            println!("{}", "<empty stack>".truecolor(128, 128, 128));
+        }
+
+        for (index, value) in stack.iter().enumerate().rev() {
+            println!("{} {}", format!("[{index}]").cyan(), value.yellow());
+        }
+    }
+
+    fn print_debug_variables(&self, variables: &[(String, String)]) {
+        if variables.is_empty() {
+            println!("{}", "<no variables>".truecolor(128, 128, 128));
+        }
+
+        for (name, value) in variables {
+            println!("{} = {}", name.cyan(), value.yellow());
+        }
+    }
+
     fn print_rib_compilation_error(&self, error: &RibCompilationError) {
         print_rib_compilation_error(error);
     }
@@ -101,6 +148,20 @@ pub trait ReplPrinter {
         println!("{} {}", "[runtime error]".red(), error.to_string().white());
     }
 
+    fn print_rib_execution_error(&self, error: &RibExecutionError) {
+        match error {
+            RibExecutionError::RibRuntimeError(runtime_error) => {
+                self.print_rib_runtime_error(runtime_error);
+            }
+            RibExecutionError::RibCompilationError(compilation_error) => {
+                self.print_rib_compilation_error(compilation_error);
+            }
+            RibExecutionError::Custom(custom_error) => {
+                self.print_custom_error(custom_error);
+            }
+        }
+    }
+
     fn print_wasm_value_type(&self, analysed_type: &AnalysedType) {
         match analysed_type {
             AnalysedType::Handle(type_handle) => {
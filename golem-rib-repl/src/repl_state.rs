@@ -12,13 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::debugger::ReplDebugSession;
 use crate::worker_name_gen::ReplWorkerNameGen;
 use crate::{RawRibScript, WorkerFunctionInvoke};
 use golem_wasm_rpc::ValueAndType;
 use rib::{InstructionId, RibCompiler};
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::{Arc, RwLock, RwLockReadGuard};
+use std::sync::{Arc, Mutex, RwLock, RwLockReadGuard};
 
 pub struct ReplState {
     rib_script: RwLock<RawRibScript>,
@@ -28,6 +29,7 @@ pub struct ReplState {
     rib_compiler: RwLock<RibCompiler>,
     history_file_path: PathBuf,
     worker_name_gen: RwLock<ReplWorkerNameGen>,
+    debug_session: Mutex<Option<ReplDebugSession>>,
 }
 
 impl ReplState {
@@ -78,6 +80,21 @@ impl ReplState {
         *self.rib_script.write().unwrap() = RawRibScript::default();
         *self.invocation_results.results.write().unwrap() = HashMap::new();
         *self.last_executed_instruction.write().unwrap() = None;
+        *self.debug_session.lock().unwrap() = None;
+    }
+
+    pub fn is_debugging(&self) -> bool {
+        self.debug_session.lock().unwrap().is_some()
+    }
+
+    pub fn start_debug_session(&self, debug_session: ReplDebugSession) {
+        *self.debug_session.lock().unwrap() = Some(debug_session);
+    }
+
+    // The session is taken out while a debug command is executed (which is async),
+    // and is put back using `start_debug_session` if the session is still active
+    pub fn take_debug_session(&self) -> Option<ReplDebugSession> {
+        self.debug_session.lock().unwrap().take()
     }
 
     pub fn rib_script(&self) -> RwLockReadGuard<'_, RawRibScript> {
@@ -96,6 +113,14 @@ impl ReplState {
         self.rib_script.write().unwrap().push(rib);
     }
 
+    /// Adds a rib statement, which was resolved from `source` (Ex: with its imports inlined)
+    pub fn update_rib_with_source(&self, rib: &str, source: &str) {
+        self.rib_script
+            .write()
+            .unwrap()
+            .push_with_source(rib, source);
+    }
+
     pub fn remove_last_rib_expression(&self) {
         self.rib_script.write().unwrap().pop();
     }
@@ -115,6 +140,7 @@ impl ReplState {
             rib_compiler: RwLock::new(rib_compiler),
             history_file_path: history_file,
             worker_name_gen: RwLock::new(ReplWorkerNameGen::new()),
+            debug_session: Mutex::new(None),
         }
     }
 }
@@ -1,23 +1,24 @@
+use crate::compiler::ReplCompilerOutput;
 use crate::repl_state::ReplState;
 use crate::rib_edit::RibEdit;
 use crate::{RawRibScript, ReplPrinter};
 use rib::RibCompiler;
 use rustyline::history::DefaultHistory;
 use rustyline::Editor;
-use std::sync::RwLockReadGuard;
+use std::sync::{Arc, RwLockReadGuard};
 
 // A projection of internal repl_state that could be useful
 // for advanced customisation of REPL commands.
 pub struct ReplContext<'a> {
     printer: &'a dyn ReplPrinter,
-    repl_state: &'a ReplState,
+    repl_state: &'a Arc<ReplState>,
     editor: &'a mut Editor<RibEdit, DefaultHistory>,
 }
 
 impl<'a> ReplContext<'a> {
     pub(crate) fn new(
         printer: &'a dyn ReplPrinter,
-        repl_state: &'a ReplState,
+        repl_state: &'a Arc<ReplState>,
         editor: &'a mut Editor<RibEdit, DefaultHistory>,
     ) -> Self {
         Self {
@@ -47,6 +48,16 @@ impl<'a> ReplContext<'a> {
         result
     }
 
+    pub(crate) fn get_repl_state(&self) -> &Arc<ReplState> {
+        self.repl_state
+    }
+
+    pub(crate) fn update_progression(&mut self, compiler_output: &ReplCompilerOutput) {
+        if let Some(rib_edit) = self.editor.helper_mut() {
+            rib_edit.update_progression(compiler_output);
+        }
+    }
+
     pub fn get_rib_compiler(&self) -> RwLockReadGuard<'_, RibCompiler> {
         self.repl_state.rib_compiler()
     }
@@ -13,20 +13,25 @@
 // limitations under the License.
 
 use crate::compiler::compile_rib_script;
+use crate::debugger::{DebugCommand, DebugOutput, ReplDebugSession};
 use crate::dependency_manager::RibDependencyManager;
 use crate::eval::eval;
 use crate::invoke::WorkerFunctionInvoke;
+use crate::raw::load_rib_script;
 use crate::repl_printer::{DefaultReplResultPrinter, ReplPrinter};
 use crate::repl_state::ReplState;
 use crate::rib_context::ReplContext;
 use crate::rib_edit::RibEdit;
 use crate::{CommandRegistry, ReplBootstrapError, RibExecutionError, UntypedCommand};
 use colored::Colorize;
-use rib::{RibCompiler, RibCompilerConfig, RibResult};
+use rib::{
+    resolve_imports, DebugStatus, FileSystemImportResolver, RibCompiler, RibCompilerConfig,
+    RibResult,
+};
 use rustyline::error::ReadlineError;
 use rustyline::history::DefaultHistory;
 use rustyline::{Config, Editor};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 /// Config options:
@@ -146,7 +151,12 @@ impl RibRepl {
     /// This method is exposed for users who want to manage their own REPL loop
     /// instead of using the built-in [`Self::run`] method.
     pub fn read_line(&mut self) -> rustyline::Result<String> {
-        self.editor.readline(&self.prompt)
+        if self.repl_state.is_debugging() {
+            self.editor
+                .readline(&"(debug) ".truecolor(192, 192, 192).to_string())
+        } else {
+            self.editor.readline(&self.prompt)
+        }
     }
 
     /// Executes a single line of Rib code and returns the result.
@@ -158,6 +168,14 @@ impl RibRepl {
         &mut self,
         script_or_command: &str,
     ) -> Result<Option<RibResult>, RibExecutionError> {
+        // While a `:debug` session is active, every input is a debugger command
+        if let Some(debug_session) = self.repl_state.take_debug_session() {
+            self.execute_debug_command(debug_session, script_or_command)
+                .await;
+
+            return Ok(None);
+        }
+
         let script_or_command = CommandOrExpr::from_str(script_or_command, &self.command_registry)
             .map_err(RibExecutionError::Custom)?;
 
@@ -175,8 +193,6 @@ impl RibRepl {
                 if !script.is_empty() {
                     let rib = script.strip_suffix(";").unwrap_or(script.as_str()).trim();
 
-                    self.repl_state.update_rib(rib);
-
                     // Add every rib script into the history (in memory) and save it
                     // regardless of whether it compiles or not
                     // History is never used for any progressive compilation or interpretation
@@ -185,34 +201,71 @@ impl RibRepl {
                         .editor
                         .save_history(self.repl_state.history_file_path());
 
-                    match compile_rib_script(&self.current_rib_program(), self.repl_state.clone()) {
-                        Ok(compiler_output) => {
-                            let rib_edit = self.editor.helper_mut().unwrap();
+                    let base_dir = std::env::current_dir().unwrap_or_default();
 
-                            rib_edit.update_progression(&compiler_output);
+                    let resolved_rib =
+                        resolve_imports(rib, &FileSystemImportResolver::new(base_dir))
+                            .map_err(|err| RibExecutionError::Custom(err.to_string()))?;
 
-                            let result =
-                                eval(compiler_output.rib_byte_code, &self.repl_state).await;
+                    // The script is kept along with its import statements,
+                    // so that they are not lost when saving the session
+                    self.execute_rib(&resolved_rib, rib).await
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
 
-                            match result {
-                                Ok(result) => Ok(Some(result)),
-                                Err(err) => {
-                                    self.repl_state.remove_last_rib_expression();
+    /// Executes a Rib script file non-interactively (Ex: for smoke tests),
+    /// on top of the current state of the REPL, and returns the result of the script.
+    ///
+    /// Imports in the script are resolved relative to the script file.
+    pub async fn run_script(
+        &mut self,
+        script_file: &Path,
+    ) -> Result<Option<RibResult>, RibExecutionError> {
+        let rib = load_rib_script(script_file).map_err(RibExecutionError::Custom)?;
 
-                                    Err(RibExecutionError::RibRuntimeError(err))
-                                }
-                            }
-                        }
-                        Err(err) => {
-                            self.repl_state.remove_last_rib_expression();
+        if rib.trim().is_empty() {
+            Ok(None)
+        } else {
+            self.execute_rib(&rib, &rib).await
+        }
+    }
 
-                            Err(RibExecutionError::RibCompilationError(err))
-                        }
+    async fn execute_rib(
+        &mut self,
+        rib: &str,
+        source: &str,
+    ) -> Result<Option<RibResult>, RibExecutionError> {
+        let rib = rib.trim();
+        let rib = rib.strip_suffix(";").unwrap_or(rib);
+
+        self.repl_state.update_rib_with_source(rib, source);
+
+        match compile_rib_script(&self.current_rib_program(), self.repl_state.clone()) {
+            Ok(compiler_output) => {
+                let rib_edit = self.editor.helper_mut().unwrap();
+
+                rib_edit.update_progression(&compiler_output);
+
+                let result = eval(compiler_output.rib_byte_code, &self.repl_state).await;
+
+                match result {
+                    Ok(result) => Ok(Some(result)),
+                    Err(err) => {
+                        self.repl_state.remove_last_rib_expression();
+
+                        Err(RibExecutionError::RibRuntimeError(err))
                     }
-                } else {
-                    Ok(None)
                 }
             }
+            Err(err) => {
+                self.repl_state.remove_last_rib_expression();
+
+                Err(RibExecutionError::RibCompilationError(err))
+            }
         }
     }
 
@@ -235,17 +288,9 @@ impl RibRepl {
 
                         Ok(None) => {}
 
-                        Err(err) => match err {
-                            RibExecutionError::RibRuntimeError(runtime_error) => {
-                                self.printer.print_rib_runtime_error(&runtime_error);
-                            }
-                            RibExecutionError::RibCompilationError(runtime_error) => {
-                                self.printer.print_rib_compilation_error(&runtime_error);
-                            }
-                            RibExecutionError::Custom(custom_error) => {
-                                self.printer.print_custom_error(&custom_error);
-                            }
-                        },
+                        Err(err) => {
+                            self.printer.print_rib_execution_error(&err);
+                        }
                     }
                 }
                 Err(ReadlineError::Eof) | Err(ReadlineError::Interrupted) => break,
@@ -254,6 +299,54 @@ impl RibRepl {
         }
     }
 
+    async fn execute_debug_command(&mut self, mut debug_session: ReplDebugSession, input: &str) {
+        let command = match DebugCommand::from_str(input) {
+            Ok(command) => command,
+            Err(err) => {
+                self.printer.print_custom_error(&err);
+                self.repl_state.start_debug_session(debug_session);
+                return;
+            }
+        };
+
+        match debug_session.run(&command).await {
+            Ok(DebugOutput::Status(status)) => {
+                let source_line = debug_session.source_line_of(&status);
+                self.printer
+                    .print_debug_status(&status, source_line.as_deref());
+
+                if let DebugStatus::Paused(_) = status {
+                    self.repl_state.start_debug_session(debug_session);
+                }
+            }
+            Ok(DebugOutput::Breakpoints(breakpoints)) => {
+                let breakpoints = breakpoints
+                    .iter()
+                    .map(|line| line.to_string())
+                    .collect::<Vec<_>>();
+
+                self.printer
+                    .print_custom_message(&format!("breakpoints: [{}]", breakpoints.join(", ")));
+                self.repl_state.start_debug_session(debug_session);
+            }
+            Ok(DebugOutput::Stack(stack)) => {
+                self.printer.print_debug_stack(&stack);
+                self.repl_state.start_debug_session(debug_session);
+            }
+            Ok(DebugOutput::Variables(variables)) => {
+                self.printer.print_debug_variables(&variables);
+                self.repl_state.start_debug_session(debug_session);
+            }
+            Ok(DebugOutput::Quit) => {
+                self.printer.print_custom_message("debug session ended");
+            }
+            Err(err) => {
+                self.printer.print_rib_runtime_error(&err);
+                self.printer.print_custom_message("debug session ended");
+            }
+        }
+    }
+
     fn current_rib_program(&self) -> String {
         self.repl_state.current_rib_program()
     }
@@ -8,13 +8,90 @@ use golem_wasm_ast::analysis::analysed_type::{
 use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::ValueAndType;
 use rib::{
-    EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName, Expr, Interpreter, RibCompiler,
-    RibCompilerConfig, RibComponentFunctionInvoke, RibFunctionInvokeResult, RibInput,
+    EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName, Expr, Interpreter, RibByteCodeOptimiser,
+    RibCompiler, RibCompilerConfig, RibComponentFunctionInvoke, RibFunctionInvokeResult, RibInput,
 };
 
 #[test]
 async fn test_rib_regression() {
-    let expr = r#"
+    let expr = Expr::from_text(REGRESSION_RIB).unwrap();
+
+    let compiler = RibCompiler::new(RibCompilerConfig::new(
+        component_metadata::component_metadata(),
+        vec![],
+    ));
+
+    use std::time::Instant;
+
+    let start = Instant::now();
+
+    let compiled_expr = compiler.compile(expr).unwrap().byte_code;
+
+    let duration = start.elapsed();
+
+    println!("Compile time of the complex rib: {duration:?}");
+
+    let mut rib_executor = mock_interpreter::interpreter();
+
+    let start = Instant::now();
+
+    let result = rib_executor.run(compiled_expr).await.unwrap();
+
+    let duration = start.elapsed();
+
+    println!("Execution time of the complex rib: {duration:?}");
+
+    let actual_as_text = test_utils::convert_value_and_type_to_str(&result.get_val().unwrap());
+
+    let expected_as_text = test_utils::convert_value_and_type_to_str(&expected_value_and_type());
+
+    assert_eq!(
+        result.get_val().unwrap(),
+        expected_value_and_type(),
+        "Assertion failed! \n\n Actual value as string  : {actual_as_text} \n\n Expected value as string: {expected_as_text}\n"
+    );
+}
+
+#[test]
+async fn test_rib_regression_optimised() {
+    let expr = Expr::from_text(REGRESSION_RIB).unwrap();
+
+    let compiler = RibCompiler::new(RibCompilerConfig::new(
+        component_metadata::component_metadata(),
+        vec![],
+    ));
+
+    let compiled_expr = compiler.compile(expr).unwrap().byte_code;
+
+    let optimised_expr = RibByteCodeOptimiser::default().optimise(compiled_expr.clone());
+
+    println!(
+        "Instructions before optimisation: {}, after optimisation: {}",
+        compiled_expr.len(),
+        optimised_expr.len()
+    );
+
+    assert!(optimised_expr.len() <= compiled_expr.len());
+
+    let result = mock_interpreter::interpreter()
+        .run(compiled_expr)
+        .await
+        .unwrap();
+
+    let optimised_result = mock_interpreter::interpreter()
+        .run(optimised_expr)
+        .await
+        .unwrap();
+
+    assert_eq!(optimised_result, result);
+
+    assert_eq!(
+        optimised_result.get_val().unwrap(),
+        expected_value_and_type()
+    );
+}
+
+const REGRESSION_RIB: &str = r#"
               let worker = instance();
               let str1: string = request.body.name;
               let str2: string = request.headers.name;
@@ -471,44 +548,6 @@ async fn test_rib_regression() {
               }
         "#;
 
-    let expr = Expr::from_text(expr).unwrap();
-
-    let compiler = RibCompiler::new(RibCompilerConfig::new(
-        component_metadata::component_metadata(),
-        vec![],
-    ));
-
-    use std::time::Instant;
-
-    let start = Instant::now();
-
-    let compiled_expr = compiler.compile(expr).unwrap().byte_code;
-
-    let duration = start.elapsed();
-
-    println!("Compile time of the complex rib: {duration:?}");
-
-    let mut rib_executor = mock_interpreter::interpreter();
-
-    let start = Instant::now();
-
-    let result = rib_executor.run(compiled_expr).await.unwrap();
-
-    let duration = start.elapsed();
-
-    println!("Execution time of the complex rib: {duration:?}");
-
-    let actual_as_text = test_utils::convert_value_and_type_to_str(&result.get_val().unwrap());
-
-    let expected_as_text = test_utils::convert_value_and_type_to_str(&expected_value_and_type());
-
-    assert_eq!(
-        result.get_val().unwrap(),
-        expected_value_and_type(),
-        "Assertion failed! \n\n Actual value as string  : {actual_as_text} \n\n Expected value as string: {expected_as_text}\n"
-    );
-}
-
 fn expected_value_and_type() -> ValueAndType {
     let wasm_wave_str = r#"
           {
@@ -14,6 +14,7 @@
 
 use crate::compiler::byte_code::internal::ExprState;
 use crate::compiler::ir::RibIR;
+use crate::rib_source_span::SourceSpan;
 use crate::type_inference::TypeHint;
 use crate::{Expr, InferredExpr, InstructionId};
 use bincode::{Decode, Encode};
@@ -90,13 +91,28 @@ impl RibByteCode {
     pub fn from_expr(
         inferred_expr: &InferredExpr,
     ) -> Result<RibByteCode, RibByteCodeGenerationError> {
+        Self::from_expr_with_source_map(inferred_expr).map(|(byte_code, _)| byte_code)
+    }
+
+    // Convert expression to bytecode instructions, along with the source span
+    // of the expression each instruction is generated from
+    pub fn from_expr_with_source_map(
+        inferred_expr: &InferredExpr,
+    ) -> Result<(RibByteCode, RibSourceMap), RibByteCodeGenerationError> {
         let expr: &Expr = inferred_expr.get_expr();
         let mut instructions = Vec::new();
+        let mut source_spans = Vec::new();
         let mut stack: Vec<ExprState> = Vec::new();
+        // Source spans of the entries in the stack. An instruction pushed to the stack
+        // is attributed to the expression that pushed it
+        let mut stack_source_spans: Vec<SourceSpan> = Vec::new();
         let mut instruction_id = InstructionId::init();
         stack.push(ExprState::from_expr(expr));
+        stack_source_spans.push(expr.source_span());
 
         while let Some(remaining) = stack.pop() {
+            let source_span = stack_source_spans.pop().unwrap_or_default();
+
             match remaining {
                 ExprState::Expr(expr) => {
                     internal::process_expr(
@@ -105,18 +121,49 @@ impl RibByteCode {
                         &mut instructions,
                         &mut instruction_id,
                     )?;
+
+                    source_spans.resize(instructions.len(), expr.source_span());
+                    stack_source_spans.resize(stack.len(), expr.source_span());
                 }
 
                 ExprState::Instruction(instruction) => {
                     instructions.push(instruction);
+                    source_spans.push(source_span);
                 }
             }
         }
 
         // Use VecDeque to avoid reversal, but ok as well since this is compilation
-        Ok(RibByteCode {
+        let byte_code = RibByteCode {
             instructions: instructions.into_iter().rev().collect(),
-        })
+        };
+
+        let source_map = RibSourceMap::new(source_spans.into_iter().rev().collect());
+
+        Ok((byte_code, source_map))
+    }
+}
+
+// Source span of each instruction in the byte code, indexed by the instruction position.
+// This is used only for debugging and is never persisted along with the byte code
+#[derive(Debug, Clone, Default)]
+pub struct RibSourceMap {
+    source_spans: Vec<SourceSpan>,
+}
+
+impl RibSourceMap {
+    pub fn new(source_spans: Vec<SourceSpan>) -> Self {
+        RibSourceMap { source_spans }
+    }
+
+    pub fn get(&self, instruction_index: usize) -> Option<&SourceSpan> {
+        self.source_spans.get(instruction_index)
+    }
+
+    // Whether the instruction is generated from an expression that starts at the given line
+    pub fn starts_at_line(&self, instruction_index: usize, line: i32) -> bool {
+        self.get(instruction_index)
+            .is_some_and(|span| span.start_line() == line)
     }
 }
 
@@ -72,6 +72,7 @@ pub enum RibIR {
     SinkToList,
     Length,
     GenerateWorkerName(Option<VariableId>),
+    PushRecord(AnalysedType, Vec<String>), // Fused form of CreateAndPushRecord followed by UpdateRecord for each field
 }
 
 #[derive(Debug, Clone, PartialEq, Encode, Decode)]
@@ -170,7 +171,7 @@ mod protobuf {
         And, ConcatInstruction, CreateFunctionNameInstruction, EqualTo, GetTag, GreaterThan,
         GreaterThanOrEqualTo, InvokeFunctionInstruction, IsEmpty, JumpInstruction, LessThan,
         LessThanOrEqualTo, Negate, Or, PushListInstruction, PushNoneInstruction,
-        PushTupleInstruction, RibIr as ProtoRibIR, WitResource,
+        PushRecordInstruction, PushTupleInstruction, RibIr as ProtoRibIR, WitResource,
     };
     use golem_wasm_ast::analysis::{AnalysedType, TypeStr};
 
@@ -534,6 +535,17 @@ mod protobuf {
                 Instruction::AdvanceIterator(_) => Ok(RibIR::AdvanceIterator),
                 Instruction::SinkToList(_) => Ok(RibIR::SinkToList),
                 Instruction::PushToSink(_) => Ok(RibIR::PushToSink),
+                Instruction::PushRecord(push_record) => {
+                    let record_type = push_record
+                        .record_type
+                        .ok_or("Missing record_type".to_string())
+                        .and_then(|t| {
+                            (&t).try_into()
+                                .map_err(|_| "Failed to convert AnalysedType".to_string())
+                        })?;
+
+                    Ok(RibIR::PushRecord(record_type, push_record.field_names))
+                }
             }
         }
     }
@@ -715,6 +727,12 @@ mod protobuf {
                 RibIR::SinkToList => {
                     Instruction::SinkToList(golem_api_grpc::proto::golem::rib::SinkToList {})
                 }
+                RibIR::PushRecord(analysed_type, field_names) => {
+                    Instruction::PushRecord(PushRecordInstruction {
+                        record_type: Some((&analysed_type).into()),
+                        field_names,
+                    })
+                }
             };
 
             Ok(ProtoRibIR {
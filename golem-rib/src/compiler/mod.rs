@@ -15,6 +15,7 @@
 pub use byte_code::*;
 pub use compiler_output::*;
 pub use ir::*;
+pub use optimiser::*;
 pub use type_with_unit::*;
 pub use worker_functions_in_rib::*;
 
@@ -31,6 +32,7 @@ mod byte_code;
 mod compiler_output;
 mod desugar;
 mod ir;
+mod optimiser;
 mod type_with_unit;
 mod worker_functions_in_rib;
 
@@ -0,0 +1,555 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interpreter::evaluate_pure_instructions;
+use crate::{InstanceVariable, InstructionId, RibByteCode, RibIR, VariableId};
+use golem_wasm_rpc::{Value, ValueAndType};
+use std::collections::{HashMap, HashSet};
+
+// Upper bound on the number of times the whole set of passes is repeated.
+// Passes feed each other (inlining a let exposes constants, folding a constant condition
+// exposes dead branches, etc.), so we iterate until a fix point, but never indefinitely.
+const MAX_ITERATIONS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptimisationPass {
+    // Fuses `CreateAndPushRecord` and the `UpdateRecord` instructions following it
+    // into a single `PushRecord`
+    RecordFusion,
+    // Replaces loads of variables bound exactly once to a literal with the literal itself
+    LetInlining,
+    // Evaluates side-effect free instructions operating only on literals
+    ConstantFolding,
+    // Removes branches of a condition that is known at compile time, and any
+    // instruction that can never be reached
+    DeadBranchElimination,
+    // Retargets jumps landing on another jump, and removes jumps to the next instruction
+    JumpThreading,
+}
+
+impl OptimisationPass {
+    pub fn all() -> Vec<OptimisationPass> {
+        vec![
+            OptimisationPass::RecordFusion,
+            OptimisationPass::LetInlining,
+            OptimisationPass::ConstantFolding,
+            OptimisationPass::DeadBranchElimination,
+            OptimisationPass::JumpThreading,
+        ]
+    }
+
+    fn run(&self, instructions: Vec<RibIR>) -> Vec<RibIR> {
+        match self {
+            OptimisationPass::RecordFusion => internal::fuse_record_construction(instructions),
+            OptimisationPass::LetInlining => internal::inline_constant_lets(instructions),
+            OptimisationPass::ConstantFolding => internal::fold_constants(instructions),
+            OptimisationPass::DeadBranchElimination => {
+                internal::eliminate_dead_branches(instructions)
+            }
+            OptimisationPass::JumpThreading => internal::thread_jumps(instructions),
+        }
+    }
+}
+
+/// Optimises `RibByteCode` produced by the compiler before it is handed over to the interpreter.
+///
+/// Every pass preserves the observable behaviour of the byte code: the result (or the runtime error)
+/// of interpreting the optimised byte code is the same as that of the original byte code,
+/// including the worker functions invoked and the order in which they are invoked.
+pub struct RibByteCodeOptimiser {
+    passes: Vec<OptimisationPass>,
+}
+
+impl Default for RibByteCodeOptimiser {
+    fn default() -> Self {
+        RibByteCodeOptimiser::new(OptimisationPass::all())
+    }
+}
+
+impl RibByteCodeOptimiser {
+    pub fn new(passes: Vec<OptimisationPass>) -> Self {
+        RibByteCodeOptimiser { passes }
+    }
+
+    pub fn optimise(&self, byte_code: RibByteCode) -> RibByteCode {
+        let original = byte_code.instructions;
+        let mut instructions = original.clone();
+
+        for _ in 0..MAX_ITERATIONS {
+            let before = instructions.clone();
+
+            for pass in &self.passes {
+                instructions = pass.run(instructions);
+            }
+
+            if instructions == before {
+                break;
+            }
+        }
+
+        // The interpreter decides whether the result is unit based on the last instruction,
+        // and this is something none of the passes are allowed to change.
+        if internal::is_unit_result(&original) != internal::is_unit_result(&instructions) {
+            return RibByteCode {
+                instructions: original,
+            };
+        }
+
+        RibByteCode { instructions }
+    }
+}
+
+impl RibByteCode {
+    pub fn optimise(self) -> RibByteCode {
+        RibByteCodeOptimiser::default().optimise(self)
+    }
+}
+
+mod internal {
+    use super::*;
+
+    pub(crate) fn is_unit_result(instructions: &[RibIR]) -> bool {
+        matches!(instructions.last(), Some(RibIR::AssignVar(_)))
+    }
+
+    pub(crate) fn fuse_record_construction(instructions: Vec<RibIR>) -> Vec<RibIR> {
+        let mut result = Vec::with_capacity(instructions.len());
+        let mut iter = instructions.into_iter().peekable();
+
+        while let Some(instruction) = iter.next() {
+            match instruction {
+                RibIR::CreateAndPushRecord(analysed_type) => {
+                    let mut field_names = vec![];
+
+                    while let Some(RibIR::UpdateRecord(field_name)) =
+                        iter.next_if(|next| matches!(next, RibIR::UpdateRecord(_)))
+                    {
+                        field_names.push(field_name);
+                    }
+
+                    if field_names.is_empty() {
+                        result.push(RibIR::CreateAndPushRecord(analysed_type));
+                    } else {
+                        result.push(RibIR::PushRecord(analysed_type, field_names));
+                    }
+                }
+                instruction => result.push(instruction),
+            }
+        }
+
+        result
+    }
+
+    pub(crate) fn inline_constant_lets(instructions: Vec<RibIR>) -> Vec<RibIR> {
+        let mut assignments: HashMap<VariableId, usize> = HashMap::new();
+
+        // Variables that are looked up by other means than `LoadVar` (worker and resource instances)
+        // need to stay in the environment
+        let mut pinned: HashSet<VariableId> = HashSet::new();
+
+        for instruction in &instructions {
+            match instruction {
+                RibIR::AssignVar(variable_id) => {
+                    *assignments.entry(variable_id.clone()).or_default() += 1;
+                }
+                RibIR::InvokeFunction(_, instance_variable, _, _) => match instance_variable {
+                    InstanceVariable::WitWorker(variable_id)
+                    | InstanceVariable::WitResource(variable_id) => {
+                        pinned.insert(variable_id.clone());
+                    }
+                },
+                RibIR::GenerateWorkerName(Some(variable_id)) => {
+                    pinned.insert(variable_id.clone());
+                    pinned.insert(variable_id.as_instance_variable());
+                }
+                _ => {}
+            }
+        }
+
+        let mut constants: HashMap<VariableId, ValueAndType> = HashMap::new();
+        let mut inlined_positions: HashSet<usize> = HashSet::new();
+        let last_index = instructions.len().saturating_sub(1);
+
+        for (index, window) in instructions.windows(2).enumerate() {
+            if let [RibIR::PushLit(value), RibIR::AssignVar(variable_id)] = window {
+                if assignments.get(variable_id) == Some(&1)
+                    && !pinned.contains(variable_id)
+                    && index + 1 != last_index
+                {
+                    constants.insert(variable_id.clone(), value.clone());
+                    inlined_positions.insert(index);
+                    inlined_positions.insert(index + 1);
+                }
+            }
+        }
+
+        if constants.is_empty() {
+            return instructions;
+        }
+
+        instructions
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !inlined_positions.contains(index))
+            .map(|(_, instruction)| match instruction {
+                RibIR::LoadVar(variable_id) => match constants.get(&variable_id) {
+                    Some(value) => RibIR::PushLit(value.clone()),
+                    None => RibIR::LoadVar(variable_id),
+                },
+                instruction => instruction,
+            })
+            .collect()
+    }
+
+    pub(crate) fn fold_constants(instructions: Vec<RibIR>) -> Vec<RibIR> {
+        let mut result: Vec<RibIR> = Vec::with_capacity(instructions.len());
+
+        for instruction in instructions {
+            if let Some(arity) = foldable_arity(&instruction) {
+                if result.len() >= arity && result[result.len() - arity..].iter().all(is_constant) {
+                    let start = result.len() - arity;
+                    let mut sequence = result[start..].to_vec();
+                    sequence.push(instruction.clone());
+
+                    // Anything failing to evaluate (division by zero, out of bound index etc.)
+                    // is left as it is, so that the error is still raised at runtime
+                    if let Some(value) = evaluate_pure_instructions(&sequence) {
+                        result.truncate(start);
+                        result.push(RibIR::PushLit(value));
+                        continue;
+                    }
+                }
+            }
+
+            result.push(instruction);
+        }
+
+        result
+    }
+
+    // Number of stack values an instruction consumes, if it is side-effect free
+    // and its result depends only on these values
+    fn foldable_arity(instruction: &RibIR) -> Option<usize> {
+        match instruction {
+            RibIR::PushNone(_) | RibIR::PushEnum(_, _) => Some(0),
+            RibIR::Negate
+            | RibIR::PushSome(_)
+            | RibIR::PushOkResult(_)
+            | RibIR::PushErrResult(_)
+            | RibIR::SelectField(_)
+            | RibIR::Length
+            | RibIR::GetTag
+            | RibIR::Deconstruct => Some(1),
+            RibIR::EqualTo
+            | RibIR::GreaterThan
+            | RibIR::LessThan
+            | RibIR::GreaterThanOrEqualTo
+            | RibIR::LessThanOrEqualTo
+            | RibIR::Plus(_)
+            | RibIR::Minus(_)
+            | RibIR::Multiply(_)
+            | RibIR::Divide(_)
+            | RibIR::And
+            | RibIR::Or
+            | RibIR::SelectIndexV1 => Some(2),
+            RibIR::PushList(_, size) | RibIR::PushTuple(_, size) | RibIR::Concat(size) => {
+                Some(*size)
+            }
+            RibIR::PushRecord(_, field_names) => Some(field_names.len()),
+            _ => None,
+        }
+    }
+
+    fn is_constant(instruction: &RibIR) -> bool {
+        matches!(instruction, RibIR::PushLit(_) | RibIR::PushFlag(_))
+    }
+
+    pub(crate) fn eliminate_dead_branches(instructions: Vec<RibIR>) -> Vec<RibIR> {
+        // A conditional jump on a literal is either no jump at all, or an unconditional jump
+        let mut result: Vec<RibIR> = Vec::with_capacity(instructions.len());
+
+        for instruction in instructions {
+            if let RibIR::JumpIfFalse(label) = &instruction {
+                if let Some(RibIR::PushLit(ValueAndType {
+                    value: Value::Bool(predicate),
+                    ..
+                })) = result.last()
+                {
+                    let predicate = *predicate;
+                    let label = label.clone();
+                    result.pop();
+
+                    if !predicate {
+                        result.push(RibIR::Jump(label));
+                    }
+
+                    continue;
+                }
+            }
+
+            result.push(instruction);
+        }
+
+        // Anything after an unconditional jump or a throw is unreachable,
+        // until we find a label that is the target of some jump
+        let referenced = referenced_labels(&result);
+        let last_index = result.len().saturating_sub(1);
+
+        let mut reachable = true;
+        let mut reachable_instructions = Vec::with_capacity(result.len());
+
+        for (index, instruction) in result.into_iter().enumerate() {
+            match instruction {
+                RibIR::Label(label) => {
+                    if referenced.contains(&label) {
+                        reachable = true;
+                        reachable_instructions.push(RibIR::Label(label));
+                    } else if index == last_index {
+                        // Keeping the last instruction as it is, as the interpreter looks at it
+                        reachable_instructions.push(RibIR::Label(label));
+                    }
+                }
+                _ if !reachable => {}
+                RibIR::Jump(label) => {
+                    reachable = false;
+                    reachable_instructions.push(RibIR::Jump(label));
+                }
+                RibIR::Throw(message) => {
+                    reachable = false;
+                    reachable_instructions.push(RibIR::Throw(message));
+                }
+                instruction => reachable_instructions.push(instruction),
+            }
+        }
+
+        reachable_instructions
+    }
+
+    pub(crate) fn thread_jumps(instructions: Vec<RibIR>) -> Vec<RibIR> {
+        // Labels that are followed (possibly after other labels) by an unconditional jump
+        let mut forwards: HashMap<InstructionId, InstructionId> = HashMap::new();
+
+        for (index, instruction) in instructions.iter().enumerate() {
+            if let RibIR::Label(label) = instruction {
+                let next = instructions[index + 1..]
+                    .iter()
+                    .find(|next| !matches!(next, RibIR::Label(_)));
+
+                if let Some(RibIR::Jump(target)) = next {
+                    forwards.insert(label.clone(), target.clone());
+                }
+            }
+        }
+
+        let resolve = |label: InstructionId| -> InstructionId {
+            let mut visited = HashSet::new();
+            let mut current = label;
+
+            while let Some(next) = forwards.get(&current) {
+                if !visited.insert(current.clone()) {
+                    break;
+                }
+                current = next.clone();
+            }
+
+            current
+        };
+
+        let threaded: Vec<RibIR> = instructions
+            .into_iter()
+            .map(|instruction| match instruction {
+                RibIR::Jump(label) => RibIR::Jump(resolve(label)),
+                RibIR::JumpIfFalse(label) => RibIR::JumpIfFalse(resolve(label)),
+                instruction => instruction,
+            })
+            .collect();
+
+        // A jump to a label that follows it (possibly after other labels) is a no-op
+        let mut result = Vec::with_capacity(threaded.len());
+
+        for (index, instruction) in threaded.iter().enumerate() {
+            if let RibIR::Jump(target) = instruction {
+                let lands_on_next = threaded[index + 1..]
+                    .iter()
+                    .take_while(|next| matches!(next, RibIR::Label(_)))
+                    .any(|next| next.get_instruction_id().as_ref() == Some(target));
+
+                if lands_on_next {
+                    continue;
+                }
+            }
+
+            result.push(instruction.clone());
+        }
+
+        result
+    }
+
+    fn referenced_labels(instructions: &[RibIR]) -> HashSet<InstructionId> {
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                RibIR::Jump(label) | RibIR::JumpIfFalse(label) => Some(label.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod optimiser_tests {
+    use test_r::test;
+
+    use crate::{
+        Expr, InstructionId, Interpreter, OptimisationPass, RibByteCode, RibByteCodeOptimiser,
+        RibCompiler, RibIR, RibResult,
+    };
+    use golem_wasm_ast::analysis::analysed_type::{field, record, str};
+    use golem_wasm_rpc::IntoValueAndType;
+
+    #[test]
+    fn test_record_fusion() {
+        let byte_code = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit("bar_value".into_value_and_type()),
+                RibIR::PushLit("foo_value".into_value_and_type()),
+                RibIR::CreateAndPushRecord(record(vec![
+                    field("foo_key", str()),
+                    field("bar_key", str()),
+                ])),
+                RibIR::UpdateRecord("foo_key".to_string()),
+                RibIR::UpdateRecord("bar_key".to_string()),
+            ],
+        };
+
+        let optimiser = RibByteCodeOptimiser::new(vec![OptimisationPass::RecordFusion]);
+
+        let expected = RibByteCode {
+            instructions: vec![
+                RibIR::PushLit("bar_value".into_value_and_type()),
+                RibIR::PushLit("foo_value".into_value_and_type()),
+                RibIR::PushRecord(
+                    record(vec![field("foo_key", str()), field("bar_key", str())]),
+                    vec!["foo_key".to_string(), "bar_key".to_string()],
+                ),
+            ],
+        };
+
+        assert_eq!(optimiser.optimise(byte_code), expected);
+    }
+
+    #[test]
+    async fn test_let_inlining_and_constant_folding() {
+        let rib = r#"
+          let x: u32 = 1;
+          let y: u32 = 2;
+          x + y
+        "#;
+
+        let optimised = assert_equivalent(rib).await;
+
+        assert_eq!(
+            optimised.instructions,
+            vec![RibIR::PushLit(3u32.into_value_and_type())]
+        );
+    }
+
+    #[test]
+    async fn test_dead_branch_elimination() {
+        let rib = r#"
+          let x: u32 = 1;
+          if x > 0 then "foo" else "bar"
+        "#;
+
+        let optimised = assert_equivalent(rib).await;
+
+        assert_eq!(
+            optimised.instructions,
+            vec![
+                RibIR::PushLit("foo".into_value_and_type()),
+                RibIR::Label(InstructionId::new(2))
+            ]
+        );
+    }
+
+    #[test]
+    async fn test_constant_record_is_folded() {
+        let rib = r#"
+          let x: u32 = 1;
+          let y = if x == 1 then "foo" else "bar";
+          { a: x, b: y, c: [x, x] }
+        "#;
+
+        let optimised = assert_equivalent(rib).await;
+
+        assert_eq!(optimised.instructions.len(), 1);
+    }
+
+    #[test]
+    async fn test_division_by_zero_is_not_folded() {
+        let rib = r#"
+          let x: u32 = 1;
+          let y: u32 = 0;
+          x / y
+        "#;
+
+        let optimised = assert_equivalent(rib).await;
+
+        assert!(optimised
+            .instructions
+            .iter()
+            .any(|instruction| matches!(instruction, RibIR::Divide(_))));
+    }
+
+    #[test]
+    async fn test_unit_result_is_preserved() {
+        let rib = r#"
+          let x: u32 = 1;
+          let y: u32 = x + 1
+        "#;
+
+        let optimised = assert_equivalent(rib).await;
+
+        assert!(matches!(
+            optimised.instructions.last(),
+            Some(RibIR::AssignVar(_))
+        ));
+    }
+
+    // Compiles the rib, and asserts running the optimised byte code gives the same result
+    // as running the original one
+    async fn assert_equivalent(rib: &str) -> RibByteCode {
+        let expr = Expr::from_text(rib).unwrap();
+        let compiler = RibCompiler::default();
+        let byte_code = compiler.compile(expr).unwrap().byte_code;
+        let optimised = byte_code.clone().optimise();
+
+        assert!(optimised.len() <= byte_code.len());
+
+        let expected = run(byte_code).await;
+        let actual = run(optimised.clone()).await;
+
+        assert_eq!(actual, expected);
+
+        optimised
+    }
+
+    async fn run(byte_code: RibByteCode) -> Result<RibResult, String> {
+        let mut interpreter = Interpreter::default();
+        interpreter
+            .run(byte_code)
+            .await
+            .map_err(|err| err.to_string())
+    }
+}
@@ -146,9 +146,9 @@ impl TryFrom<&InferredType> for AnalysedTypeWithUnit {
             TypeInternal::Chr => Ok(AnalysedTypeWithUnit::analysed_type(AnalysedType::Chr(
                 TypeChr,
             ))),
-            TypeInternal::Str => Ok(AnalysedTypeWithUnit::analysed_type(AnalysedType::Str(
-                TypeStr,
-            ))),
+            TypeInternal::Str | TypeInternal::Json => Ok(AnalysedTypeWithUnit::analysed_type(
+                AnalysedType::Str(TypeStr),
+            )),
             TypeInternal::List(inferred_type) => Ok(AnalysedTypeWithUnit::analysed_type(
                 AnalysedType::List(TypeList {
                     inner: Box::new(inferred_type.try_into()?),
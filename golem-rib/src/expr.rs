@@ -1047,6 +1047,18 @@ impl Expr {
         }
     }
 
+    // A map literal such as `{"foo": 1, "bar": 2}` doesn't have a dedicated expression,
+    // and is a sequence of key-value tuples, i.e, `list<tuple<K, V>>` in WIT
+    pub fn map(entries: Vec<(Expr, Expr)>) -> Self {
+        Expr::sequence(
+            entries
+                .into_iter()
+                .map(|(key, value)| Expr::tuple(vec![key, value]))
+                .collect(),
+            None,
+        )
+    }
+
     pub fn inferred_type_mut(&mut self) -> &mut InferredType {
         match self {
             Expr::Let { inferred_type, .. }
@@ -1182,6 +1194,50 @@ impl Expr {
         Ok(())
     }
 
+    // Paths of global inputs declared as `json`, either through a let binding
+    // (`let body: json = request.body;`) or an annotation (`request.body: json`).
+    // Values of these inputs may be given as any JSON document rather than a JSON string.
+    pub fn json_typed_inputs(&self) -> Vec<String> {
+        let mut expr = self.clone();
+        let mut visitor = ExprVisitor::top_down(&mut expr);
+        let mut paths = vec![];
+
+        while let Some(expr) = visitor.pop_front() {
+            let path = match expr {
+                Expr::Let {
+                    type_annotation: Some(TypeName::Json),
+                    expr,
+                    ..
+                } => expr.global_input_path(),
+                Expr::SelectField {
+                    type_annotation: Some(TypeName::Json),
+                    ..
+                } => expr.global_input_path(),
+                _ => None,
+            };
+
+            if let Some(path) = path {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+
+        paths
+    }
+
+    fn global_input_path(&self) -> Option<String> {
+        match self {
+            Expr::Identifier { variable_id, .. } if variable_id.is_global() => {
+                Some(variable_id.name())
+            }
+            Expr::SelectField { expr, field, .. } => expr
+                .global_input_path()
+                .map(|path| format!("{path}.{field}")),
+            _ => None,
+        }
+    }
+
     pub fn set_origin(&mut self) {
         let mut visitor = ExprVisitor::bottom_up(self);
 
@@ -0,0 +1,261 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// Resolves `import "<path>";` statements in a Rib script, by inlining the Rib scripts
+/// being imported, so that shared Rib helpers (let bindings) can be reused
+/// across Rib scripts (Ex: in the REPL and in API definitions).
+///
+/// An import statement should be in a separate line. Every script is imported only once,
+/// even if it is imported from multiple scripts, and cyclic imports are rejected.
+pub fn resolve_imports(
+    rib: &str,
+    resolver: &dyn RibImportResolver,
+) -> Result<String, RibImportError> {
+    let mut state = ImportState::default();
+    resolve_imports_in(rib, None, resolver, &mut state)
+}
+
+/// Loads the Rib script corresponding to an import path
+pub trait RibImportResolver {
+    /// `importer` is the id of the script which has the import statement,
+    /// and is `None` for the root script.
+    fn resolve(&self, import_path: &str, importer: Option<&str>) -> Result<ResolvedImport, String>;
+}
+
+pub struct ResolvedImport {
+    /// Uniquely identifies the imported script (Ex: the canonical file path)
+    pub id: String,
+    pub source: String,
+}
+
+/// Resolves imports relative to the importing file, or relative to the
+/// base directory for the imports in the root script
+pub struct FileSystemImportResolver {
+    base_dir: PathBuf,
+}
+
+impl FileSystemImportResolver {
+    pub fn new(base_dir: impl AsRef<Path>) -> Self {
+        FileSystemImportResolver {
+            base_dir: base_dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl RibImportResolver for FileSystemImportResolver {
+    fn resolve(&self, import_path: &str, importer: Option<&str>) -> Result<ResolvedImport, String> {
+        let dir = importer
+            .and_then(|importer| Path::new(importer).parent())
+            .unwrap_or(self.base_dir.as_path());
+
+        let path = dir
+            .join(import_path)
+            .canonicalize()
+            .map_err(|err| err.to_string())?;
+
+        let source = std::fs::read_to_string(&path).map_err(|err| err.to_string())?;
+
+        Ok(ResolvedImport {
+            id: path.to_string_lossy().to_string(),
+            source,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RibImportError {
+    InvalidImport { statement: String },
+    ImportNotFound { import_path: String, error: String },
+    CyclicImport { import_path: String },
+}
+
+impl std::error::Error for RibImportError {}
+
+impl Display for RibImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RibImportError::InvalidImport { statement } => {
+                write!(
+                    f,
+                    "invalid import `{statement}`. Expected `import \"<path>\";`"
+                )
+            }
+            RibImportError::ImportNotFound { import_path, error } => {
+                write!(f, "failed to import `{import_path}`: {error}")
+            }
+            RibImportError::CyclicImport { import_path } => {
+                write!(f, "cyclic import of `{import_path}`")
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ImportState {
+    imported: HashSet<String>,
+    importing: Vec<String>,
+}
+
+fn resolve_imports_in(
+    rib: &str,
+    importer: Option<&str>,
+    resolver: &dyn RibImportResolver,
+    state: &mut ImportState,
+) -> Result<String, RibImportError> {
+    let mut lines = vec![];
+
+    for line in rib.lines() {
+        match parse_import(line)? {
+            Some(import_path) => {
+                let resolved = resolver.resolve(import_path, importer).map_err(|error| {
+                    RibImportError::ImportNotFound {
+                        import_path: import_path.to_string(),
+                        error,
+                    }
+                })?;
+
+                if state.importing.contains(&resolved.id) {
+                    return Err(RibImportError::CyclicImport {
+                        import_path: import_path.to_string(),
+                    });
+                }
+
+                if !state.imported.insert(resolved.id.clone()) {
+                    continue;
+                }
+
+                state.importing.push(resolved.id.clone());
+                let imported =
+                    resolve_imports_in(&resolved.source, Some(&resolved.id), resolver, state)?;
+                state.importing.pop();
+
+                let imported = imported.trim_end();
+                let imported = imported.strip_suffix(";").unwrap_or(imported);
+
+                if !imported.trim().is_empty() {
+                    lines.push(format!("{imported};"));
+                }
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+
+    Ok(lines.join("\n"))
+}
+
+fn parse_import(line: &str) -> Result<Option<&str>, RibImportError> {
+    let invalid_import = || RibImportError::InvalidImport {
+        statement: line.trim().to_string(),
+    };
+
+    match line.trim().strip_prefix("import ") {
+        Some(rest) => {
+            let rest = rest.trim();
+            let rest = rest.strip_suffix(";").unwrap_or(rest).trim_end();
+
+            rest.strip_prefix("\"")
+                .and_then(|path| path.strip_suffix("\""))
+                .filter(|path| !path.is_empty() && !path.contains('"'))
+                .map(Some)
+                .ok_or_else(invalid_import)
+        }
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{resolve_imports, Expr, ResolvedImport, RibImportError, RibImportResolver};
+    use std::collections::HashMap;
+
+    struct InMemoryImportResolver(HashMap<&'static str, &'static str>);
+
+    impl RibImportResolver for InMemoryImportResolver {
+        fn resolve(
+            &self,
+            import_path: &str,
+            _importer: Option<&str>,
+        ) -> Result<ResolvedImport, String> {
+            self.0
+                .get(import_path)
+                .map(|source| ResolvedImport {
+                    id: import_path.to_string(),
+                    source: source.to_string(),
+                })
+                .ok_or("not found".to_string())
+        }
+    }
+
+    #[test]
+    fn test_resolve_imports() {
+        let resolver = InMemoryImportResolver(HashMap::from([
+            ("math.rib", "import \"base.rib\";\nlet double = x * 2;\n"),
+            ("base.rib", "let x: u32 = 1;"),
+        ]));
+
+        let rib = "import \"math.rib\";\nimport \"base.rib\";\ndouble";
+
+        let result = resolve_imports(rib, &resolver).unwrap();
+
+        assert_eq!(result, "let x: u32 = 1;\nlet double = x * 2;\ndouble");
+        assert!(Expr::from_text(&result).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_imports_cyclic() {
+        let resolver = InMemoryImportResolver(HashMap::from([
+            ("a.rib", "import \"b.rib\";\nlet a = 1;"),
+            ("b.rib", "import \"a.rib\";\nlet b = 1;"),
+        ]));
+
+        let result = resolve_imports("import \"a.rib\";\na", &resolver);
+
+        assert_eq!(
+            result,
+            Err(RibImportError::CyclicImport {
+                import_path: "a.rib".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_imports_errors() {
+        let resolver = InMemoryImportResolver(HashMap::new());
+
+        let result = resolve_imports("import \"missing.rib\";\n1", &resolver);
+
+        assert_eq!(
+            result,
+            Err(RibImportError::ImportNotFound {
+                import_path: "missing.rib".to_string(),
+                error: "not found".to_string()
+            })
+        );
+
+        let result = resolve_imports("import missing.rib;\n1", &resolver);
+
+        assert_eq!(
+            result,
+            Err(RibImportError::InvalidImport {
+                statement: "import missing.rib;".to_string()
+            })
+        );
+    }
+}
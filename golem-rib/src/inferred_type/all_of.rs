@@ -1096,7 +1096,8 @@ pub fn get_merge_task<'a>(inferred_types: &'a [InferredType]) -> MergeTaskStack<
                     | TypeInternal::Instance { .. }
                     | TypeInternal::Unknown
                     | TypeInternal::Range { .. }
-                    | TypeInternal::Str => {
+                    | TypeInternal::Str
+                    | TypeInternal::Json => {
                         if !init {
                             final_task_stack
                                 .update(task_index, MergeTask::Complete(*task_index, inferred_type))
@@ -144,6 +144,7 @@ impl InferredType {
                 TypeInternal::Bool => Err(format!("used as {}", "bool")),
                 TypeInternal::Chr => Err(format!("used as {}", "char")),
                 TypeInternal::Str => Err(format!("used as {}", "string")),
+                TypeInternal::Json => Err(format!("used as {}", "json")),
                 TypeInternal::List(_) => Err(format!("used as {}", "list")),
                 TypeInternal::Tuple(_) => Err(format!("used as {}", "tuple")),
                 TypeInternal::Record(_) => Err(format!("used as {}", "record")),
@@ -195,6 +196,7 @@ impl InferredType {
             TypeInternal::Bool => false,
             TypeInternal::Chr => false,
             TypeInternal::Str => false,
+            TypeInternal::Json => false,
             TypeInternal::List(_) => false,
             TypeInternal::Tuple(_) => false,
             TypeInternal::Record(_) => false,
@@ -357,6 +359,13 @@ impl InferredType {
         }
     }
 
+    pub fn json() -> InferredType {
+        InferredType {
+            inner: Box::new(TypeInternal::Json),
+            origin: TypeOrigin::NoOrigin,
+        }
+    }
+
     pub fn s8() -> InferredType {
         InferredType {
             inner: Box::new(TypeInternal::S8),
@@ -512,6 +521,18 @@ impl InferredType {
         matches!(self.inner.deref(), TypeInternal::Str)
     }
 
+    pub fn is_json(&self) -> bool {
+        matches!(self.inner.deref(), TypeInternal::Json)
+    }
+
+    // An index that is neither a number nor a range is a key, looked up
+    // either in a map (`list<tuple<K, V>>`) or in a json string
+    pub fn is_lookup_key(&self) -> bool {
+        !self.is_unknown()
+            && !self.contains_only_number()
+            && !matches!(self.inner.deref(), TypeInternal::Range { .. })
+    }
+
     pub fn flatten_all_of_inferred_types(types: &Vec<InferredType>) -> Vec<InferredType> {
         flatten_all_of_list(types)
     }
@@ -31,6 +31,9 @@ pub enum TypeInternal {
     F64,
     Chr,
     Str,
+    // JSON text, which is a `string` at the WIT level. Unlike a plain `string`,
+    // keys and indices of json can be looked up, such as `body["user"]["name"]`
+    Json,
     List(InferredType),
     Tuple(Vec<InferredType>),
     Record(Vec<(String, InferredType)>),
@@ -176,6 +179,7 @@ impl Hash for TypeInternal {
                 sorted_types.hash(state);
             }
             TypeInternal::Unknown => 25.hash(state),
+            TypeInternal::Json => 26.hash(state),
         }
     }
 }
@@ -196,6 +200,7 @@ impl PartialEq for TypeInternal {
             (TypeInternal::F64, TypeInternal::F64) => true,
             (TypeInternal::Chr, TypeInternal::Chr) => true,
             (TypeInternal::Str, TypeInternal::Str) => true,
+            (TypeInternal::Json, TypeInternal::Json) => true,
             (TypeInternal::List(t1), TypeInternal::List(t2)) => t1 == t2,
             (TypeInternal::Tuple(ts1), TypeInternal::Tuple(ts2)) => ts1 == ts2,
             (TypeInternal::Record(fs1), TypeInternal::Record(fs2)) => fs1 == fs2,
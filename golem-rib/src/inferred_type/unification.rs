@@ -142,6 +142,7 @@ pub fn try_unify_type(
         TypeInternal::F64 => Ok(inferred_type.clone()),
         TypeInternal::Chr => Ok(inferred_type.clone()),
         TypeInternal::Str => Ok(inferred_type.clone()),
+        TypeInternal::Json => Ok(inferred_type.clone()),
         TypeInternal::Instance { .. } => Ok(inferred_type.clone()),
         TypeInternal::Sequence(_) => Ok(inferred_type.clone()),
     }
@@ -484,6 +485,11 @@ pub fn unify_both_inferred_types(
             unify_all_inferred_types(&unified_types)
         }
 
+        // Json is a string holding JSON text, so a string used as json is json
+        (TypeInternal::Str, TypeInternal::Json) | (TypeInternal::Json, TypeInternal::Str) => {
+            Ok(InferredType::json())
+        }
+
         (inferred_type_left, inferred_type_right) => {
             if left_inferred_type.is_number() && right_inferred_type.is_number() {
                 let eliminated = InferredType::eliminate_default(vec![
@@ -526,6 +532,7 @@ pub(crate) fn validate_unified_type(
         TypeInternal::F64 => Ok(Unified(InferredType::f64())),
         TypeInternal::Chr => Ok(Unified(InferredType::char())),
         TypeInternal::Str => Ok(Unified(InferredType::string())),
+        TypeInternal::Json => Ok(Unified(InferredType::json())),
         TypeInternal::List(inferred_type) => {
             let verified = validate_unified_type(inferred_type)?;
             Ok(Unified(InferredType::list(verified.inferred_type())))
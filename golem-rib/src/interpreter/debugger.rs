@@ -0,0 +1,280 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::interpreter::env::InterpreterEnv;
+use crate::interpreter::instruction_cursor::RibByteCodeCursor;
+use crate::interpreter::stack::InterpreterStack;
+use crate::rib_source_span::SourceSpan;
+use crate::{
+    Interpreter, RibByteCode, RibIR, RibInterpreterResult, RibResult, RibSourceMap, VariableId,
+};
+use std::collections::BTreeSet;
+
+/// Executes Rib byte code one instruction at a time, pausing at breakpoints
+/// (lines in the Rib source) so that the stack and the variables can be inspected.
+///
+/// The source map is the one generated along with the byte code
+/// (see `RibByteCode::from_expr_with_source_map`). With an empty source map,
+/// only instruction level stepping is available.
+pub struct RibDebugger {
+    interpreter: Interpreter,
+    byte_code_cursor: RibByteCodeCursor,
+    stack: InterpreterStack,
+    interpreter_env: InterpreterEnv,
+    source_map: RibSourceMap,
+    breakpoints: BTreeSet<i32>,
+    result: Option<RibResult>,
+}
+
+#[derive(Debug, Clone)]
+pub enum DebugStatus {
+    Paused(DebugLocation),
+    Finished(RibResult),
+}
+
+/// The instruction the debugger is paused at, which is yet to be executed
+#[derive(Debug, Clone)]
+pub struct DebugLocation {
+    pub instruction_index: usize,
+    pub instruction: RibIR,
+    pub source_span: Option<SourceSpan>,
+}
+
+impl DebugLocation {
+    pub fn line(&self) -> Option<i32> {
+        self.source_span.as_ref().map(|span| span.start_line())
+    }
+}
+
+impl RibDebugger {
+    pub fn new(interpreter: Interpreter, byte_code: RibByteCode, source_map: RibSourceMap) -> Self {
+        let interpreter_env = InterpreterEnv::from(&interpreter.input, &interpreter.invoke);
+
+        RibDebugger {
+            interpreter,
+            byte_code_cursor: RibByteCodeCursor::from_rib_byte_code(byte_code),
+            stack: InterpreterStack::default(),
+            interpreter_env,
+            source_map,
+            breakpoints: BTreeSet::new(),
+            result: None,
+        }
+    }
+
+    pub fn add_breakpoint(&mut self, line: i32) {
+        self.breakpoints.insert(line);
+    }
+
+    pub fn remove_breakpoint(&mut self, line: i32) -> bool {
+        self.breakpoints.remove(&line)
+    }
+
+    pub fn breakpoints(&self) -> Vec<i32> {
+        self.breakpoints.iter().cloned().collect()
+    }
+
+    pub fn status(&mut self) -> RibInterpreterResult<DebugStatus> {
+        match self.location() {
+            Some(location) => Ok(DebugStatus::Paused(location)),
+            None => self.finish().map(DebugStatus::Finished),
+        }
+    }
+
+    /// Executes the next instruction
+    pub async fn step(&mut self) -> RibInterpreterResult<DebugStatus> {
+        if let Some(instruction) = self.byte_code_cursor.get_instruction() {
+            self.interpreter
+                .execute_instruction(
+                    instruction,
+                    &mut self.byte_code_cursor,
+                    &mut self.stack,
+                    &mut self.interpreter_env,
+                )
+                .await?;
+        }
+
+        self.status()
+    }
+
+    /// Executes instructions until the next instruction belongs to a different line
+    pub async fn step_line(&mut self) -> RibInterpreterResult<DebugStatus> {
+        let current_line = self.current_line();
+
+        loop {
+            match self.step().await? {
+                DebugStatus::Paused(location)
+                    if location.line().is_none() || location.line() == current_line => {}
+                status => return Ok(status),
+            }
+        }
+    }
+
+    /// Executes instructions until a breakpoint is reached or the script is finished.
+    /// A breakpoint is hit when the execution enters a line with a breakpoint
+    pub async fn resume(&mut self) -> RibInterpreterResult<DebugStatus> {
+        let mut previous_line = self.current_line();
+
+        loop {
+            match self.step().await? {
+                DebugStatus::Paused(location) => {
+                    let line = location.line();
+
+                    if line.is_some() && line != previous_line {
+                        if line.is_some_and(|line| self.breakpoints.contains(&line)) {
+                            return Ok(DebugStatus::Paused(location));
+                        }
+
+                        previous_line = line;
+                    }
+                }
+                finished => return Ok(finished),
+            }
+        }
+    }
+
+    pub fn location(&self) -> Option<DebugLocation> {
+        let instruction_index = self.byte_code_cursor.position().index;
+
+        self.byte_code_cursor
+            .peek()
+            .map(|instruction| DebugLocation {
+                instruction_index,
+                instruction: instruction.clone(),
+                source_span: self.source_map.get(instruction_index).cloned(),
+            })
+    }
+
+    /// Values in the interpreter stack, from the bottom to the top of the stack
+    pub fn stack(&self) -> Vec<String> {
+        self.stack
+            .stack
+            .iter()
+            .map(|value| format!("{value:?}"))
+            .collect()
+    }
+
+    /// Variables (including global inputs) available in the interpreter environment so far
+    pub fn variables(&self) -> Vec<(VariableId, String)> {
+        let mut variables = self
+            .interpreter_env
+            .env
+            .iter()
+            .map(|(key, value)| (key.variable_id.clone(), format!("{value:?}")))
+            .collect::<Vec<_>>();
+
+        variables.sort_by_key(|(variable_id, _)| variable_id.to_string());
+
+        variables
+    }
+
+    fn current_line(&self) -> Option<i32> {
+        self.location().and_then(|location| location.line())
+    }
+
+    fn finish(&mut self) -> RibInterpreterResult<RibResult> {
+        match &self.result {
+            Some(result) => Ok(result.clone()),
+            None => {
+                let result = Interpreter::final_result(&self.byte_code_cursor, &mut self.stack)?;
+                self.result = Some(result.clone());
+                Ok(result)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{DebugStatus, Expr, Interpreter, RibByteCode, RibCompiler, RibDebugger, RibResult};
+    use golem_wasm_rpc::IntoValueAndType;
+
+    fn debugger(rib: &str) -> RibDebugger {
+        let expr = Expr::from_text(rib).unwrap();
+        let inferred_expr = RibCompiler::default().infer_types(expr).unwrap();
+        let (byte_code, source_map) =
+            RibByteCode::from_expr_with_source_map(&inferred_expr).unwrap();
+
+        RibDebugger::new(Interpreter::default(), byte_code, source_map)
+    }
+
+    #[test]
+    async fn test_debugger_step_line() {
+        let mut debugger = debugger("let x: u32 = 1;\nlet y: u32 = x + 2;\ny");
+
+        let status = debugger.status().unwrap();
+        assert!(matches!(status, DebugStatus::Paused(location) if location.line() == Some(1)));
+
+        let status = debugger.step_line().await.unwrap();
+        assert!(matches!(status, DebugStatus::Paused(location) if location.line() == Some(2)));
+
+        let variables = debugger
+            .variables()
+            .into_iter()
+            .map(|(variable_id, value)| (variable_id.to_string(), value))
+            .collect::<Vec<_>>();
+
+        assert_eq!(variables, vec![("x".to_string(), "1".to_string())]);
+
+        let status = debugger.step_line().await.unwrap();
+        assert!(matches!(status, DebugStatus::Paused(location) if location.line() == Some(3)));
+
+        let status = debugger.step_line().await.unwrap();
+        assert!(matches!(
+            status,
+            DebugStatus::Finished(RibResult::Val(value)) if value == 3u32.into_value_and_type()
+        ));
+    }
+
+    #[test]
+    async fn test_debugger_breakpoint() {
+        let mut debugger = debugger("let x: u32 = 1;\nlet y: u32 = x + 2;\nlet z: u32 = y * 2;\nz");
+        debugger.add_breakpoint(3);
+
+        let status = debugger.resume().await.unwrap();
+        assert!(matches!(status, DebugStatus::Paused(location) if location.line() == Some(3)));
+
+        let variables = debugger
+            .variables()
+            .into_iter()
+            .map(|(variable_id, _)| variable_id.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(variables, vec!["x".to_string(), "y".to_string()]);
+        assert!(debugger.stack().is_empty());
+
+        let status = debugger.resume().await.unwrap();
+        assert!(matches!(
+            status,
+            DebugStatus::Finished(RibResult::Val(value)) if value == 6u32.into_value_and_type()
+        ));
+    }
+
+    #[test]
+    async fn test_debugger_step() {
+        let mut debugger = debugger("1 + 2");
+
+        let status = debugger.step().await.unwrap();
+        assert!(matches!(status, DebugStatus::Paused(_)));
+        assert_eq!(debugger.stack().len(), 1);
+
+        let status = debugger.step().await.unwrap();
+        assert!(matches!(status, DebugStatus::Paused(_)));
+        assert_eq!(debugger.stack().len(), 2);
+
+        let status = debugger.step().await.unwrap();
+        assert!(matches!(status, DebugStatus::Finished(RibResult::Val(_))));
+    }
+}
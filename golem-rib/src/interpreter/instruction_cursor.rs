@@ -37,6 +37,11 @@ impl RibByteCodeCursor {
         }
     }
 
+    // The instruction that will be returned by the next `get_instruction`
+    pub fn peek(&self) -> Option<&RibIR> {
+        self.byte_code.instructions.get(self.position)
+    }
+
     pub fn get_instruction(&mut self) -> Option<RibIR> {
         if self.position < self.byte_code.instructions.len() {
             let ir = self.byte_code.instructions[self.position].clone();
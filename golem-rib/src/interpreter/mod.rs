@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use debugger::*;
 pub use env::*;
 pub use eval::*;
 pub use interpreter_input::*;
@@ -22,6 +23,7 @@ pub use rib_interpreter::*;
 pub use rib_runtime_error::*;
 pub use stack::*;
 
+mod debugger;
 mod env;
 mod eval;
 mod instruction_cursor;
@@ -15,14 +15,13 @@
 use super::interpreter_stack_value::RibInterpreterStackValue;
 use crate::interpreter::env::InterpreterEnv;
 use crate::interpreter::instruction_cursor::RibByteCodeCursor;
-use crate::interpreter::rib_runtime_error::{
-    arithmetic_error, no_result, throw_error, RibRuntimeError,
-};
+use crate::interpreter::rib_runtime_error::{no_result, throw_error, RibRuntimeError};
 use crate::interpreter::stack::InterpreterStack;
 use crate::{
     internal_corrupted_state, DefaultWorkerNameGenerator, GenerateWorkerName, RibByteCode,
     RibComponentFunctionInvoke, RibIR, RibInput, RibResult,
 };
+use golem_wasm_rpc::ValueAndType;
 use std::sync::Arc;
 
 pub struct Interpreter {
@@ -80,256 +79,228 @@ impl Interpreter {
         let mut interpreter_env = InterpreterEnv::from(&self.input, &self.invoke);
 
         while let Some(instruction) = byte_code_cursor.get_instruction() {
-            match instruction {
-                RibIR::GenerateWorkerName(instance_count) => {
-                    internal::run_generate_worker_name(
-                        instance_count,
-                        self,
-                        &mut stack,
-                        &mut interpreter_env,
-                    )?;
-                }
+            self.execute_instruction(
+                instruction,
+                &mut byte_code_cursor,
+                &mut stack,
+                &mut interpreter_env,
+            )
+            .await?;
+        }
 
-                RibIR::PushLit(val) => {
-                    stack.push_val(val);
-                }
+        Self::final_result(&byte_code_cursor, &mut stack)
+    }
 
-                RibIR::PushFlag(val) => {
-                    stack.push_val(val);
-                }
+    // Executes a single instruction that is already taken from the cursor.
+    // The cursor is moved further only if the instruction is a jump
+    pub(crate) async fn execute_instruction(
+        &mut self,
+        instruction: RibIR,
+        byte_code_cursor: &mut RibByteCodeCursor,
+        stack: &mut InterpreterStack,
+        interpreter_env: &mut InterpreterEnv,
+    ) -> RibInterpreterResult<()> {
+        match instruction {
+            RibIR::GenerateWorkerName(instance_count) => {
+                internal::run_generate_worker_name(instance_count, self, stack, interpreter_env)?;
+            }
 
-                RibIR::CreateAndPushRecord(analysed_type) => {
-                    internal::run_create_record_instruction(analysed_type, &mut stack)?;
-                }
+            RibIR::PushLit(val) => {
+                stack.push_val(val);
+            }
 
-                RibIR::UpdateRecord(field_name) => {
-                    internal::run_update_record_instruction(field_name, &mut stack)?;
-                }
+            RibIR::PushFlag(val) => {
+                stack.push_val(val);
+            }
 
-                RibIR::PushList(analysed_type, arg_size) => {
-                    internal::run_push_list_instruction(arg_size, analysed_type, &mut stack)?;
-                }
+            RibIR::CreateAndPushRecord(analysed_type) => {
+                internal::run_create_record_instruction(analysed_type, stack)?;
+            }
 
-                RibIR::EqualTo => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left == right)?;
-                }
+            RibIR::UpdateRecord(field_name) => {
+                internal::run_update_record_instruction(field_name, stack)?;
+            }
 
-                RibIR::GreaterThan => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left > right)?;
-                }
+            RibIR::PushRecord(analysed_type, field_names) => {
+                internal::run_push_record_instruction(analysed_type, field_names, stack)?;
+            }
 
-                RibIR::LessThan => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left < right)?;
-                }
+            RibIR::PushList(analysed_type, arg_size) => {
+                internal::run_push_list_instruction(arg_size, analysed_type, stack)?;
+            }
 
-                RibIR::GreaterThanOrEqualTo => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left >= right)?;
-                }
+            RibIR::EqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left == right)?;
+            }
 
-                RibIR::LessThanOrEqualTo => {
-                    internal::run_compare_instruction(&mut stack, |left, right| left <= right)?;
-                }
-                RibIR::Plus(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            let result = left + right;
-                            result.map_err(|err| arithmetic_error(err.as_str()))
-                        },
-                        &analysed_type,
-                    )?;
-                }
-                RibIR::Minus(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            let result = left - right;
-                            result.map_err(|err| arithmetic_error(err.as_str()))
-                        },
-                        &analysed_type,
-                    )?;
-                }
-                RibIR::Divide(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            if right.is_zero() {
-                                Err(arithmetic_error(
-                                    format!("division by zero. left: {left}, right: {right}")
-                                        .as_str(),
-                                ))
-                            } else {
-                                (left / right).map_err(|err| arithmetic_error(err.as_str()))
-                            }
-                        },
-                        &analysed_type,
-                    )?;
-                }
-                RibIR::Multiply(analysed_type) => {
-                    internal::run_math_instruction(
-                        &mut stack,
-                        |left, right| {
-                            let result = left * right;
-                            result.map_err(|err| arithmetic_error(err.as_str()))
-                        },
-                        &analysed_type,
-                    )?;
-                }
+            RibIR::GreaterThan => {
+                internal::run_compare_instruction(stack, |left, right| left > right)?;
+            }
 
-                RibIR::AssignVar(variable_id) => {
-                    internal::run_assign_var_instruction(
-                        variable_id,
-                        &mut stack,
-                        &mut interpreter_env,
-                    )?;
-                }
+            RibIR::LessThan => {
+                internal::run_compare_instruction(stack, |left, right| left < right)?;
+            }
 
-                RibIR::LoadVar(variable_id) => {
-                    internal::run_load_var_instruction(
-                        variable_id,
-                        &mut stack,
-                        &mut interpreter_env,
-                    )?;
-                }
+            RibIR::GreaterThanOrEqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left >= right)?;
+            }
 
-                RibIR::IsEmpty => {
-                    internal::run_is_empty_instruction(&mut stack)?;
-                }
+            RibIR::LessThanOrEqualTo => {
+                internal::run_compare_instruction(stack, |left, right| left <= right)?;
+            }
+            RibIR::Plus(analysed_type) => {
+                internal::run_math_instruction(stack, internal::plus, &analysed_type)?;
+            }
+            RibIR::Minus(analysed_type) => {
+                internal::run_math_instruction(stack, internal::minus, &analysed_type)?;
+            }
+            RibIR::Divide(analysed_type) => {
+                internal::run_math_instruction(stack, internal::divide, &analysed_type)?;
+            }
+            RibIR::Multiply(analysed_type) => {
+                internal::run_math_instruction(stack, internal::multiply, &analysed_type)?;
+            }
 
-                RibIR::JumpIfFalse(instruction_id) => {
-                    internal::run_jump_if_false_instruction(
-                        instruction_id,
-                        &mut byte_code_cursor,
-                        &mut stack,
-                    )?;
-                }
+            RibIR::AssignVar(variable_id) => {
+                internal::run_assign_var_instruction(variable_id, stack, interpreter_env)?;
+            }
 
-                RibIR::SelectField(field_name) => {
-                    internal::run_select_field_instruction(field_name, &mut stack)?;
-                }
+            RibIR::LoadVar(variable_id) => {
+                internal::run_load_var_instruction(variable_id, stack, interpreter_env)?;
+            }
 
-                RibIR::SelectIndex(index) => {
-                    internal::run_select_index_instruction(&mut stack, index)?;
-                }
+            RibIR::IsEmpty => {
+                internal::run_is_empty_instruction(stack)?;
+            }
 
-                RibIR::SelectIndexV1 => {
-                    internal::run_select_index_v1_instruction(&mut stack)?;
-                }
+            RibIR::JumpIfFalse(instruction_id) => {
+                internal::run_jump_if_false_instruction(instruction_id, byte_code_cursor, stack)?;
+            }
 
-                RibIR::CreateFunctionName(site, function_type) => {
-                    internal::run_create_function_name_instruction(
-                        site,
-                        function_type,
-                        &mut stack,
-                    )?;
-                }
+            RibIR::SelectField(field_name) => {
+                internal::run_select_field_instruction(field_name, stack)?;
+            }
+
+            RibIR::SelectIndex(index) => {
+                internal::run_select_index_instruction(stack, index)?;
+            }
 
-                RibIR::InvokeFunction(
+            RibIR::SelectIndexV1 => {
+                internal::run_select_index_v1_instruction(stack)?;
+            }
+
+            RibIR::CreateFunctionName(site, function_type) => {
+                internal::run_create_function_name_instruction(site, function_type, stack)?;
+            }
+
+            RibIR::InvokeFunction(
+                component_info,
+                instance_variable,
+                arg_size,
+                expected_result_type,
+            ) => {
+                internal::run_invoke_function_instruction(
                     component_info,
-                    instance_variable,
+                    &byte_code_cursor.position(),
                     arg_size,
+                    instance_variable,
+                    stack,
+                    interpreter_env,
                     expected_result_type,
-                ) => {
-                    internal::run_invoke_function_instruction(
-                        component_info,
-                        &byte_code_cursor.position(),
-                        arg_size,
-                        instance_variable,
-                        &mut stack,
-                        &mut interpreter_env,
-                        expected_result_type,
-                    )
-                    .await?;
-                }
+                )
+                .await?;
+            }
 
-                RibIR::PushVariant(variant_name, analysed_type) => {
-                    internal::run_variant_construction_instruction(
-                        variant_name,
-                        analysed_type,
-                        &mut stack,
-                    )
+            RibIR::PushVariant(variant_name, analysed_type) => {
+                internal::run_variant_construction_instruction(variant_name, analysed_type, stack)
                     .await?;
-                }
+            }
 
-                RibIR::PushEnum(enum_name, analysed_type) => {
-                    internal::run_push_enum_instruction(&mut stack, enum_name, analysed_type)?;
-                }
+            RibIR::PushEnum(enum_name, analysed_type) => {
+                internal::run_push_enum_instruction(stack, enum_name, analysed_type)?;
+            }
 
-                RibIR::Throw(message) => {
-                    return Err(throw_error(message.as_str()));
-                }
+            RibIR::Throw(message) => {
+                return Err(throw_error(message.as_str()));
+            }
 
-                RibIR::GetTag => {
-                    internal::run_get_tag_instruction(&mut stack)?;
-                }
+            RibIR::GetTag => {
+                internal::run_get_tag_instruction(stack)?;
+            }
 
-                RibIR::Deconstruct => {
-                    internal::run_deconstruct_instruction(&mut stack)?;
-                }
+            RibIR::Deconstruct => {
+                internal::run_deconstruct_instruction(stack)?;
+            }
 
-                RibIR::Jump(instruction_id) => {
-                    byte_code_cursor.move_to(&instruction_id).ok_or_else(|| {
-                        internal_corrupted_state!(
-                            "internal error. Failed to move to label {}",
-                            instruction_id.index
-                        )
-                    })?;
-                }
+            RibIR::Jump(instruction_id) => {
+                byte_code_cursor.move_to(&instruction_id).ok_or_else(|| {
+                    internal_corrupted_state!(
+                        "internal error. Failed to move to label {}",
+                        instruction_id.index
+                    )
+                })?;
+            }
 
-                RibIR::PushSome(analysed_type) => {
-                    internal::run_create_some_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::PushNone(analysed_type) => {
-                    internal::run_create_none_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::PushOkResult(analysed_type) => {
-                    internal::run_create_ok_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::PushErrResult(analysed_type) => {
-                    internal::run_create_err_instruction(&mut stack, analysed_type)?;
-                }
-                RibIR::Concat(arg_size) => {
-                    internal::run_concat_instruction(&mut stack, arg_size)?;
-                }
-                RibIR::PushTuple(analysed_type, arg_size) => {
-                    internal::run_push_tuple_instruction(arg_size, analysed_type, &mut stack)?;
-                }
-                RibIR::Negate => {
-                    internal::run_negate_instruction(&mut stack)?;
-                }
+            RibIR::PushSome(analysed_type) => {
+                internal::run_create_some_instruction(stack, analysed_type)?;
+            }
+            RibIR::PushNone(analysed_type) => {
+                internal::run_create_none_instruction(stack, analysed_type)?;
+            }
+            RibIR::PushOkResult(analysed_type) => {
+                internal::run_create_ok_instruction(stack, analysed_type)?;
+            }
+            RibIR::PushErrResult(analysed_type) => {
+                internal::run_create_err_instruction(stack, analysed_type)?;
+            }
+            RibIR::Concat(arg_size) => {
+                internal::run_concat_instruction(stack, arg_size)?;
+            }
+            RibIR::PushTuple(analysed_type, arg_size) => {
+                internal::run_push_tuple_instruction(arg_size, analysed_type, stack)?;
+            }
+            RibIR::Negate => {
+                internal::run_negate_instruction(stack)?;
+            }
 
-                RibIR::Label(_) => {}
+            RibIR::Label(_) => {}
 
-                RibIR::And => {
-                    internal::run_and_instruction(&mut stack)?;
-                }
+            RibIR::And => {
+                internal::run_and_instruction(stack)?;
+            }
 
-                RibIR::Or => {
-                    internal::run_or_instruction(&mut stack)?;
-                }
-                RibIR::ToIterator => {
-                    internal::run_to_iterator(&mut stack)?;
-                }
-                RibIR::CreateSink(analysed_type) => {
-                    internal::run_create_sink_instruction(&mut stack, analysed_type)?
-                }
-                RibIR::AdvanceIterator => {
-                    internal::run_advance_iterator_instruction(&mut stack)?;
-                }
-                RibIR::PushToSink => {
-                    internal::run_push_to_sink_instruction(&mut stack)?;
-                }
+            RibIR::Or => {
+                internal::run_or_instruction(stack)?;
+            }
+            RibIR::ToIterator => {
+                internal::run_to_iterator(stack)?;
+            }
+            RibIR::CreateSink(analysed_type) => {
+                internal::run_create_sink_instruction(stack, analysed_type)?
+            }
+            RibIR::AdvanceIterator => {
+                internal::run_advance_iterator_instruction(stack)?;
+            }
+            RibIR::PushToSink => {
+                internal::run_push_to_sink_instruction(stack)?;
+            }
 
-                RibIR::SinkToList => {
-                    internal::run_sink_to_list_instruction(&mut stack)?;
-                }
+            RibIR::SinkToList => {
+                internal::run_sink_to_list_instruction(stack)?;
+            }
 
-                RibIR::Length => {
-                    internal::run_length_instruction(&mut stack)?;
-                }
+            RibIR::Length => {
+                internal::run_length_instruction(stack)?;
             }
         }
 
+        Ok(())
+    }
+
+    pub(crate) fn final_result(
+        byte_code_cursor: &RibByteCodeCursor,
+        stack: &mut InterpreterStack,
+    ) -> Result<RibResult, RibRuntimeError> {
         match byte_code_cursor.last() {
             Some(RibIR::AssignVar(_)) => Ok(RibResult::Unit),
             _ => {
@@ -345,6 +316,102 @@ impl Interpreter {
     }
 }
 
+// Evaluates a straight-line sequence of side-effect free instructions, without any
+// environment or worker invocation, and returns the only value left on the stack.
+// The byte code optimiser uses this to fold constants, so that a folded value is exactly
+// what the interpreter would have produced at runtime. Any instruction that depends on
+// the environment, jumps or fails to evaluate makes the whole sequence non-foldable.
+pub(crate) fn evaluate_pure_instructions(instructions: &[RibIR]) -> Option<ValueAndType> {
+    let mut stack = InterpreterStack::default();
+
+    for instruction in instructions {
+        let result = match instruction.clone() {
+            RibIR::PushLit(val) | RibIR::PushFlag(val) => {
+                stack.push_val(val);
+                Ok(())
+            }
+            RibIR::CreateAndPushRecord(analysed_type) => {
+                internal::run_create_record_instruction(analysed_type, &mut stack)
+            }
+            RibIR::UpdateRecord(field_name) => {
+                internal::run_update_record_instruction(field_name, &mut stack)
+            }
+            RibIR::PushRecord(analysed_type, field_names) => {
+                internal::run_push_record_instruction(analysed_type, field_names, &mut stack)
+            }
+            RibIR::PushList(analysed_type, arg_size) => {
+                internal::run_push_list_instruction(arg_size, analysed_type, &mut stack)
+            }
+            RibIR::PushTuple(analysed_type, arg_size) => {
+                internal::run_push_tuple_instruction(arg_size, analysed_type, &mut stack)
+            }
+            RibIR::PushSome(analysed_type) => {
+                internal::run_create_some_instruction(&mut stack, analysed_type)
+            }
+            RibIR::PushNone(analysed_type) => {
+                internal::run_create_none_instruction(&mut stack, analysed_type)
+            }
+            RibIR::PushOkResult(analysed_type) => {
+                internal::run_create_ok_instruction(&mut stack, analysed_type)
+            }
+            RibIR::PushErrResult(analysed_type) => {
+                internal::run_create_err_instruction(&mut stack, analysed_type)
+            }
+            RibIR::PushEnum(enum_name, analysed_type) => {
+                internal::run_push_enum_instruction(&mut stack, enum_name, analysed_type)
+            }
+            RibIR::EqualTo => {
+                internal::run_compare_instruction(&mut stack, |left, right| left == right)
+            }
+            RibIR::GreaterThan => {
+                internal::run_compare_instruction(&mut stack, |left, right| left > right)
+            }
+            RibIR::LessThan => {
+                internal::run_compare_instruction(&mut stack, |left, right| left < right)
+            }
+            RibIR::GreaterThanOrEqualTo => {
+                internal::run_compare_instruction(&mut stack, |left, right| left >= right)
+            }
+            RibIR::LessThanOrEqualTo => {
+                internal::run_compare_instruction(&mut stack, |left, right| left <= right)
+            }
+            RibIR::Plus(analysed_type) => {
+                internal::run_math_instruction(&mut stack, internal::plus, &analysed_type)
+            }
+            RibIR::Minus(analysed_type) => {
+                internal::run_math_instruction(&mut stack, internal::minus, &analysed_type)
+            }
+            RibIR::Divide(analysed_type) => {
+                internal::run_math_instruction(&mut stack, internal::divide, &analysed_type)
+            }
+            RibIR::Multiply(analysed_type) => {
+                internal::run_math_instruction(&mut stack, internal::multiply, &analysed_type)
+            }
+            RibIR::And => internal::run_and_instruction(&mut stack),
+            RibIR::Or => internal::run_or_instruction(&mut stack),
+            RibIR::Negate => internal::run_negate_instruction(&mut stack),
+            RibIR::Concat(arg_size) => internal::run_concat_instruction(&mut stack, arg_size),
+            RibIR::SelectField(field_name) => {
+                internal::run_select_field_instruction(field_name, &mut stack)
+            }
+            RibIR::SelectIndexV1 => internal::run_select_index_v1_instruction(&mut stack),
+            RibIR::Length => internal::run_length_instruction(&mut stack),
+            RibIR::GetTag => internal::run_get_tag_instruction(&mut stack),
+            RibIR::Deconstruct => internal::run_deconstruct_instruction(&mut stack),
+            _ => return None,
+        };
+
+        if result.is_err() {
+            return None;
+        }
+    }
+
+    match stack.pop() {
+        Some(RibInterpreterStackValue::Val(value)) if stack.stack.is_empty() => Some(value),
+        _ => None,
+    }
+}
+
 mod internal {
     use crate::interpreter::env::{EnvironmentKey, InterpreterEnv};
     use crate::interpreter::interpreter_stack_value::RibInterpreterStackValue;
@@ -364,10 +431,10 @@ mod internal {
 
     use crate::interpreter::instruction_cursor::RibByteCodeCursor;
     use crate::interpreter::rib_runtime_error::{
-        cast_error_custom, empty_stack, exhausted_iterator, field_not_found, function_invoke_fail,
-        index_out_of_bound, infinite_computation, input_not_found, instruction_jump_error,
-        insufficient_stack_items, invalid_type_with_stack_value, type_mismatch_with_type_hint,
-        RibRuntimeError,
+        arithmetic_error, cast_error_custom, empty_stack, exhausted_iterator, field_not_found,
+        function_invoke_fail, index_out_of_bound, infinite_computation, input_not_found,
+        instruction_jump_error, insufficient_stack_items, invalid_type_with_stack_value,
+        key_not_found, throw_error, type_mismatch_with_type_hint, RibRuntimeError,
     };
     use crate::type_inference::GetTypeHint;
     use async_trait::async_trait;
@@ -818,6 +885,53 @@ mod internal {
         Ok(())
     }
 
+    pub(crate) fn run_push_record_instruction(
+        analysed_type: AnalysedType,
+        field_names: Vec<String>,
+        interpreter_stack: &mut InterpreterStack,
+    ) -> RibInterpreterResult<()> {
+        let record_type = match analysed_type {
+            AnalysedType::Record(type_record) => type_record,
+            _ => {
+                bail_corrupted_state!(
+                    "expected a record type to create a record, but obtained {}",
+                    analysed_type.get_type_hint()
+                )
+            }
+        };
+
+        // pre-initializing with () values, same as CreateAndPushRecord
+        let mut fields = vec![Value::Tuple(vec![]); record_type.fields.len()];
+
+        // Field values are in the stack in the same order UpdateRecord instructions would pop them
+        for field_name in field_names {
+            let idx = record_type
+                .fields
+                .iter()
+                .position(|pair| pair.name == field_name)
+                .ok_or_else(|| {
+                    internal_corrupted_state!(
+                        "Invalid field name {field_name}, should be one of {}",
+                        record_type
+                            .fields
+                            .iter()
+                            .map(|pair| pair.name.clone())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })?;
+
+            let value = interpreter_stack.try_pop_val()?;
+            fields[idx] = value.value;
+        }
+
+        interpreter_stack.push_val(ValueAndType {
+            value: Value::Record(fields),
+            typ: AnalysedType::Record(record_type),
+        });
+        Ok(())
+    }
+
     pub(crate) fn run_push_list_instruction(
         list_size: usize,
         analysed_type: AnalysedType,
@@ -903,6 +1017,40 @@ mod internal {
         Ok(())
     }
 
+    pub(crate) fn plus(
+        left: CoercedNumericValue,
+        right: CoercedNumericValue,
+    ) -> Result<CoercedNumericValue, RibRuntimeError> {
+        (left + right).map_err(|err| arithmetic_error(err.as_str()))
+    }
+
+    pub(crate) fn minus(
+        left: CoercedNumericValue,
+        right: CoercedNumericValue,
+    ) -> Result<CoercedNumericValue, RibRuntimeError> {
+        (left - right).map_err(|err| arithmetic_error(err.as_str()))
+    }
+
+    pub(crate) fn multiply(
+        left: CoercedNumericValue,
+        right: CoercedNumericValue,
+    ) -> Result<CoercedNumericValue, RibRuntimeError> {
+        (left * right).map_err(|err| arithmetic_error(err.as_str()))
+    }
+
+    pub(crate) fn divide(
+        left: CoercedNumericValue,
+        right: CoercedNumericValue,
+    ) -> Result<CoercedNumericValue, RibRuntimeError> {
+        if right.is_zero() {
+            Err(arithmetic_error(
+                format!("division by zero. left: {left}, right: {right}").as_str(),
+            ))
+        } else {
+            (left / right).map_err(|err| arithmetic_error(err.as_str()))
+        }
+    }
+
     pub(crate) fn run_math_instruction(
         interpreter_stack: &mut InterpreterStack,
         compare_fn: fn(
@@ -999,6 +1147,9 @@ mod internal {
                     Ok(())
                 }
 
+                // A non-numeric index is a key of a map (list of key-value tuples)
+                None => run_map_lookup(items, *typ.inner, index_value, interpreter_stack),
+
                 _ => Err(internal_corrupted_state!("failed range selection")),
             },
             RibInterpreterStackValue::Val(ValueAndType {
@@ -1030,6 +1181,10 @@ mod internal {
                     index_value,
                 )),
             },
+            RibInterpreterStackValue::Val(ValueAndType {
+                value: Value::String(json),
+                ..
+            }) => run_json_lookup(&json, index_value, interpreter_stack),
             result => Err(invalid_type_with_stack_value(
                 vec![TypeHint::List(None), TypeHint::Tuple(None)],
                 result,
@@ -1037,6 +1192,72 @@ mod internal {
         }
     }
 
+    fn run_map_lookup(
+        entries: Vec<Value>,
+        entry_type: AnalysedType,
+        key: RibInterpreterStackValue,
+        interpreter_stack: &mut InterpreterStack,
+    ) -> RibInterpreterResult<()> {
+        let key = match key {
+            RibInterpreterStackValue::Val(key) => key,
+            key => return Err(invalid_type_with_stack_value(vec![TypeHint::Str], key)),
+        };
+
+        let value_type = match entry_type {
+            AnalysedType::Tuple(tuple) if tuple.items.len() == 2 => tuple.items[1].clone(),
+            _ => {
+                bail_corrupted_state!("map lookup on a list that is not a list of key-value tuples")
+            }
+        };
+
+        let value = entries
+            .into_iter()
+            .find_map(|entry| match entry {
+                Value::Tuple(mut key_value)
+                    if key_value.len() == 2 && key_value[0] == key.value =>
+                {
+                    key_value.pop()
+                }
+                _ => None,
+            })
+            .ok_or_else(|| key_not_found(&key))?;
+
+        interpreter_stack.push_val(ValueAndType::new(value, value_type));
+        Ok(())
+    }
+
+    // Json is a string at the WIT level. A field of an object is looked up by a string key,
+    // and an element of an array by a number. Strings are returned as is,
+    // while any other json value is returned as json text
+    fn run_json_lookup(
+        json: &str,
+        key: RibInterpreterStackValue,
+        interpreter_stack: &mut InterpreterStack,
+    ) -> RibInterpreterResult<()> {
+        let parsed: serde_json::Value = serde_json::from_str(json)
+            .map_err(|err| throw_error(&format!("invalid json: {err}")))?;
+
+        let selected = match key.get_literal() {
+            Some(LiteralValue::String(field)) => parsed.get(&field),
+            Some(LiteralValue::Num(number)) => match number {
+                CoercedNumericValue::PosInt(index) => parsed.get(index as usize),
+                CoercedNumericValue::NegInt(index) if index >= 0 => parsed.get(index as usize),
+                _ => None,
+            },
+            _ => return Err(invalid_type_with_stack_value(vec![TypeHint::Str], key)),
+        };
+
+        let selected = selected.ok_or_else(|| key_not_found(&key))?;
+
+        let result = match selected {
+            serde_json::Value::String(string) => string.clone(),
+            other => other.to_string(),
+        };
+
+        interpreter_stack.push_val(result.into_value_and_type());
+        Ok(())
+    }
+
     pub(crate) fn run_select_index_instruction(
         interpreter_stack: &mut InterpreterStack,
         index: usize,
@@ -3216,6 +3437,129 @@ mod tests {
         assert_eq!(result, "index out of bound: 10 (size: 5)".to_string());
     }
 
+    #[test]
+    async fn test_interpreter_for_map_lookup() {
+        let expr = r#"
+              let headers = {"content-type": "application/json", "x-user-id": "foo"};
+              headers["x-user-id"]
+              "#;
+
+        let expr = Expr::from_text(expr).unwrap();
+
+        let compiler = RibCompiler::default();
+        let compiled = compiler.compile(expr).unwrap();
+
+        let mut interpreter = Interpreter::default();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(result.get_val().unwrap(), "foo".into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_for_map_lookup_with_type_annotation() {
+        let expr = r#"
+              let scores: map<string, u32> = {"foo": 1, "bar": 2};
+              let key = "bar";
+              scores[key] + scores["foo"]
+              "#;
+
+        let expr = Expr::from_text(expr).unwrap();
+
+        let compiler = RibCompiler::default();
+        let compiled = compiler.compile(expr).unwrap();
+
+        let mut interpreter = Interpreter::default();
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        assert_eq!(result.get_val().unwrap(), 3u32.into_value_and_type());
+    }
+
+    #[test]
+    async fn test_interpreter_for_map_lookup_key_not_found() {
+        let expr = r#"
+              let scores: map<string, u32> = {"foo": 1, "bar": 2};
+              scores["baz"]
+              "#;
+
+        let expr = Expr::from_text(expr).unwrap();
+
+        let compiler = RibCompiler::default();
+        let compiled = compiler.compile(expr).unwrap();
+
+        let mut interpreter = Interpreter::default();
+        let result = interpreter
+            .run(compiled.byte_code)
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(result, r#"key not found: "baz""#.to_string());
+    }
+
+    #[test]
+    async fn test_interpreter_for_json_lookup() {
+        let expr = r#"
+              let body: json = request.body;
+              let name = body["user"]["name"];
+              let tag = body["user"]["tags"][0];
+              let address = body["user"]["address"];
+              { name: name, first-tag: tag, address: address }
+              "#;
+
+        let mut rib_input = HashMap::new();
+
+        rib_input.insert(
+            "request".to_string(),
+            ValueAndType::new(
+                Value::Record(vec![Value::String(
+                    r#"{"user": {"name": "foo", "tags": ["bar"], "address": {"zip": 1}}}"#
+                        .to_string(),
+                )]),
+                record(vec![field("body", str())]),
+            ),
+        );
+
+        let mut interpreter =
+            test_utils::interpreter_with_noop_function_invoke(Some(RibInput::new(rib_input)));
+
+        let expr = Expr::from_text(expr).unwrap();
+
+        let compiler = RibCompiler::default();
+        let compiled = compiler.compile(expr).unwrap();
+
+        let result = interpreter.run(compiled.byte_code).await.unwrap();
+
+        let expected = ValueAndType::new(
+            Value::Record(vec![
+                Value::String("foo".to_string()),
+                Value::String("bar".to_string()),
+                Value::String(r#"{"zip":1}"#.to_string()),
+            ]),
+            record(vec![
+                field("name", str()),
+                field("first-tag", str()),
+                field("address", str()),
+            ]),
+        );
+
+        assert_eq!(result.get_val().unwrap(), expected);
+    }
+
+    #[test]
+    async fn test_interpreter_for_key_lookup_on_string() {
+        let expr = r#"
+              let body: string = "foo";
+              body["user"]
+              "#;
+
+        let expr = Expr::from_text(expr).unwrap();
+
+        let compiler = RibCompiler::default();
+        let result = compiler.compile(expr);
+
+        assert!(result.is_err());
+    }
+
     #[test]
     async fn test_interpreter_for_select_index_expr_2() {
         let expr = r#"
@@ -48,6 +48,9 @@ pub enum RibRuntimeError {
         left: Option<ValueAndType>,
         right: Option<ValueAndType>,
     },
+    KeyNotFound {
+        key: String,
+    },
     NoResult,
     ThrownError(String),
     TypeMismatch {
@@ -184,6 +187,12 @@ pub fn type_mismatch_with_type_hint(expected: Vec<TypeHint>, found: TypeHint) ->
     }
 }
 
+pub fn key_not_found<T: Display>(key: T) -> RibRuntimeError {
+    RibRuntimeError::KeyNotFound {
+        key: key.to_string(),
+    }
+}
+
 pub fn no_result() -> RibRuntimeError {
     RibRuntimeError::NoResult
 }
@@ -222,6 +231,7 @@ impl Display for RibRuntimeError {
                     "runtime type mismatch: expected {expected:?}, found {found:?}"
                 )
             }
+            RibRuntimeError::KeyNotFound { key } => write!(f, "key not found: {key}"),
             RibRuntimeError::NoResult => write!(f, "No result"),
             RibRuntimeError::InfiniteComputation { message } => {
                 write!(f, "infinite computation detected: {message}")
@@ -19,11 +19,13 @@ pub use call_type::*;
 pub use compiler::*;
 pub use expr::*;
 pub use function_name::*;
+pub use import::*;
 pub use inferred_type::*;
 pub use instance_type::*;
 pub use interpreter::*;
 pub use parser::type_name::TypeName;
 pub use registry::*;
+pub use testing::*;
 pub use text::*;
 pub use type_checker::*;
 pub use type_inference::*;
@@ -36,6 +38,7 @@ mod compiler;
 mod expr;
 mod function_name;
 mod generic_type_parameter;
+mod import;
 mod inferred_type;
 mod instance_type;
 mod interpreter;
@@ -43,6 +46,7 @@ mod parser;
 mod registry;
 mod rib_source_span;
 mod rib_type_error;
+mod testing;
 mod text;
 mod type_checker;
 mod type_inference;
@@ -161,11 +161,11 @@ mod invalid_syntax_tests {
 
     #[test]
     fn invalid_record_in_rib_program_2() {
-        let input = r#"{ "foo": "bar" }"#;
+        let input = r#"{ "foo" "bar" }"#;
         let error = Expr::from_text(input).unwrap_err();
         assert_eq!(
             error,
-            "Parse error at line: 1, column: 3\nUnexpected `\"`\nExpected letter\n"
+            "Parse error at line: 1, column: 9\nUnexpected `\"`\nExpected `:`\n"
         );
     }
 
@@ -342,6 +342,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_let_binding_with_type_name_json() {
+        let input = "let foo: json = bar";
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::let_binding(
+                "foo",
+                Expr::identifier_with_variable_id(VariableId::global("bar".to_string()), None,),
+                Some(TypeName::Json)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_json_typed_inputs() {
+        let expr = Expr::from_text(
+            r#"
+              let body: json = request.body;
+              let user: string = request.headers.user;
+              body["name"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(expr.json_typed_inputs(), vec!["request.body".to_string()]);
+
+        let expr = Expr::from_text(
+            r#"
+              let body: string = request.body;
+              body
+            "#,
+        )
+        .unwrap();
+        assert!(expr.json_typed_inputs().is_empty());
+    }
+
     #[test]
     fn test_let_binding_with_type_name_list_u8() {
         let input = "let foo: list<u8> = []";
@@ -0,0 +1,172 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::rib_expr::rib_expr;
+use crate::expr::Expr;
+use crate::parser::errors::RibParseError;
+use crate::parser::literal::literal;
+use crate::rib_source_span::GetSourcePosition;
+use combine::{
+    attempt, look_ahead,
+    parser::char::{char as char_, spaces},
+    sep_by1, ParseError, Parser,
+};
+
+// A map literal is `{"key1": value1, "key2": value2}`. Unlike records, the keys are
+// string literals, and the map is lowered to `list<tuple<string, V>>`
+pub fn map<Input>() -> impl Parser<Input, Output = Expr>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    // Looking ahead for the quote of the first key to avoid clashing with records,
+    // without consuming the input
+    attempt(
+        char_('{')
+            .skip(spaces().silent())
+            .skip(look_ahead(char_('"'))),
+    )
+    .with(sep_by1(
+        entry().skip(spaces().silent()),
+        char_(',').skip(spaces().silent()),
+    ))
+    .skip(char_('}').skip(spaces().silent()))
+    .and_then(|entries: Vec<(Expr, Expr)>| {
+        let duplicate_keys = find_duplicate_keys(&entries);
+
+        if !duplicate_keys.is_empty() {
+            Err(RibParseError::Message(format!(
+                "duplicate keys found in map: {}",
+                duplicate_keys.join(", ")
+            )))
+        } else {
+            Ok(Expr::map(entries))
+        }
+    })
+}
+
+fn entry<Input>() -> impl Parser<Input, Output = (Expr, Expr)>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    (
+        literal().skip(spaces().silent()),
+        char_(':').skip(spaces().silent()),
+        rib_expr(),
+    )
+        .map(|(key, _, value)| (key, value))
+}
+
+// Only static keys can be checked for duplicates at parse time,
+// and interpolated keys such as "${foo}" are left to the runtime
+fn find_duplicate_keys(entries: &[(Expr, Expr)]) -> Vec<String> {
+    let mut keys = std::collections::HashSet::new();
+    let mut duplicates = vec![];
+
+    for (key, _) in entries {
+        if let Expr::Literal { value, .. } = key {
+            if !keys.insert(value.clone()) {
+                duplicates.push(value.clone());
+            }
+        }
+    }
+
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use bigdecimal::BigDecimal;
+    use test_r::test;
+
+    use crate::expr::Expr;
+
+    #[test]
+    fn test_singleton_map() {
+        let input = r#"{"foo": bar}"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::map(vec![(
+                Expr::literal("foo"),
+                Expr::identifier_global("bar", None)
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        let input = r#"{ "foo": "bar", "baz-qux": {a: 1} }"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::map(vec![
+                (Expr::literal("foo"), Expr::literal("bar")),
+                (
+                    Expr::literal("baz-qux"),
+                    Expr::record(vec![("a".to_string(), Expr::number(BigDecimal::from(1)))])
+                )
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_nested_map() {
+        let input = r#"{"foo": {"bar": [1, 2]}}"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::map(vec![(
+                Expr::literal("foo"),
+                Expr::map(vec![(
+                    Expr::literal("bar"),
+                    Expr::sequence(
+                        vec![
+                            Expr::number(BigDecimal::from(1)),
+                            Expr::number(BigDecimal::from(2))
+                        ],
+                        None
+                    )
+                )])
+            )]))
+        );
+    }
+
+    #[test]
+    fn test_map_with_duplicate_keys() {
+        let input = r#"{"foo": 1, "foo": 2}"#;
+        let result = Expr::from_text(input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_map_lookup() {
+        let input = r#"headers["content-type"]"#;
+        let result = Expr::from_text(input);
+        assert_eq!(
+            result,
+            Ok(Expr::select_index(
+                Expr::identifier_global("headers", None),
+                Expr::literal("content-type")
+            ))
+        );
+    }
+}
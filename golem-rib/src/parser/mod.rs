@@ -31,6 +31,7 @@ mod let_binding;
 mod list_aggregation;
 mod list_comprehension;
 pub(crate) mod literal;
+mod map;
 mod multi_line_code_block;
 mod not;
 mod optional;
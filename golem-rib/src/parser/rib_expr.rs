@@ -26,6 +26,7 @@ use crate::parser::let_binding::let_binding;
 use crate::parser::list_aggregation::list_aggregation;
 use crate::parser::list_comprehension::list_comprehension;
 use crate::parser::literal::literal;
+use crate::parser::map::map;
 use crate::parser::multi_line_code_block::multi_line_block;
 use crate::parser::not::not;
 use crate::parser::optional::option;
@@ -118,7 +119,7 @@ where
                 let_binding(),
                 conditional(),
                 attempt(multi_line_block()),
-                flag_or_record(),
+                flag_record_or_map(),
                 tuple(),
                 boolean_literal(),
                 literal(),
@@ -160,7 +161,7 @@ where
     })
 }
 
-fn flag_or_record<Input>() -> impl Parser<Input, Output = Expr>
+fn flag_record_or_map<Input>() -> impl Parser<Input, Output = Expr>
 where
     Input: Stream<Token = char>,
     RibParseError: Into<
@@ -168,7 +169,7 @@ where
     >,
     Input::Position: GetSourcePosition,
 {
-    choice((attempt(flag()), record()))
+    choice((attempt(flag()), map(), record()))
 }
 
 // A rib rest always a start with a proper delimiter (ex: ., [, etc)
@@ -47,6 +47,10 @@ pub enum TypeName {
     F64,
     Chr,
     Str,
+    // JSON text, which is a `string` at the WIT level. Unlike `string`, keys and indices of json
+    // can be looked up, and an input bound to `json` (for example `let body: json = request.body;`)
+    // may be given as any JSON document
+    Json,
     List(Box<TypeName>),
     Tuple(Vec<TypeName>),
     Option(Box<TypeName>),
@@ -78,6 +82,7 @@ impl Display for TypeName {
             TypeName::F64 => write!(f, "f64"),
             TypeName::Chr => write!(f, "char"),
             TypeName::Str => write!(f, "string"),
+            TypeName::Json => write!(f, "json"),
             TypeName::List(inner_type) => write!(f, "list<{inner_type}>"),
             TypeName::Tuple(inner_types) => {
                 write!(f, "tuple<")?;
@@ -272,6 +277,7 @@ impl From<&TypeName> for InferredType {
             TypeName::F64 => InferredType::f64(),
             TypeName::Chr => InferredType::char(),
             TypeName::Str => InferredType::string(),
+            TypeName::Json => InferredType::json(),
             TypeName::List(inner_type) => InferredType::list(inner_type.deref().into()),
             TypeName::Tuple(inner_types) => {
                 InferredType::tuple(inner_types.iter().map(|t| t.into()).collect())
@@ -317,6 +323,7 @@ impl TryFrom<InferredType> for TypeName {
             TypeInternal::F64 => Ok(TypeName::F64),
             TypeInternal::Chr => Ok(TypeName::Chr),
             TypeInternal::Str => Ok(TypeName::Str),
+            TypeInternal::Json => Ok(TypeName::Json),
             TypeInternal::List(inferred_type) => {
                 let verified = inferred_type.clone().try_into()?;
                 Ok(TypeName::List(Box::new(verified)))
@@ -405,6 +412,9 @@ where
         attempt(string("f64").map(|_| TypeName::F64)),
         attempt(string("char").map(|_| TypeName::Chr)),
         attempt(string("string").map(|_| TypeName::Str)),
+        // `json` is a `string` holding JSON text at the WIT level, which keeps it a valid WIT type
+        // while allowing dynamic lookups such as `body["user"]["name"]`
+        attempt(string("json").map(|_| TypeName::Json)),
     ))
     .skip(spaces().silent())
 }
@@ -427,6 +437,32 @@ where
         .map(|inner_type| TypeName::List(Box::new(inner_type)))
 }
 
+// There is no map type in WIT, and hence `map<K, V>` is
+// a shorthand for `list<tuple<K, V>>`
+pub fn parse_map_type<Input>() -> impl Parser<Input, Output = TypeName>
+where
+    Input: combine::Stream<Token = char>,
+    RibParseError: Into<
+        <Input::Error as ParseError<Input::Token, Input::Range, Input::Position>>::StreamError,
+    >,
+    Input::Position: GetSourcePosition,
+{
+    string("map")
+        .skip(spaces().silent())
+        .with(between(
+            char('<').skip(spaces().silent()),
+            char('>').skip(spaces().silent()),
+            (
+                type_name().skip(spaces().silent()),
+                char(',').skip(spaces().silent()),
+                type_name().skip(spaces().silent()),
+            ),
+        ))
+        .map(|(key_type, _, value_type)| {
+            TypeName::List(Box::new(TypeName::Tuple(vec![key_type, value_type])))
+        })
+}
+
 pub fn parse_option_type<Input>() -> impl Parser<Input, Output = TypeName>
 where
     Input: combine::Stream<Token = char>,
@@ -533,6 +569,7 @@ where
     spaces().silent().with(choice((
         attempt(parse_basic_type()),
         attempt(parse_list_type()),
+        attempt(parse_map_type()),
         attempt(parse_tuple_type()),
         attempt(parse_option_type()),
         attempt(parse_result_type()),
@@ -574,6 +611,7 @@ mod protobuf {
                 TypeName::F64 => InnerTypeName::BasicType(BasicTypeName::F64 as i32),
                 TypeName::Chr => InnerTypeName::BasicType(BasicTypeName::Chr as i32),
                 TypeName::Str => InnerTypeName::BasicType(BasicTypeName::Str as i32),
+                TypeName::Json => InnerTypeName::BasicType(BasicTypeName::Json as i32),
                 TypeName::List(inner_type) => InnerTypeName::ListType(Box::new(ListType {
                     inner_type: Some(Box::new(inner_type.deref().clone().into())),
                 })),
@@ -637,6 +675,7 @@ mod protobuf {
                         Ok(BasicTypeName::F64) => Ok(TypeName::F64),
                         Ok(BasicTypeName::Chr) => Ok(TypeName::Chr),
                         Ok(BasicTypeName::Str) => Ok(TypeName::Str),
+                        Ok(BasicTypeName::Json) => Ok(TypeName::Json),
                         _ => Err(format!("Unknown basic type: {value:?}")),
                     },
                     InnerTypeName::ListType(inner_type) => {
@@ -746,6 +785,25 @@ mod type_name_tests {
         parse_and_compare("f64", TypeName::F64);
         parse_and_compare("char", TypeName::Chr);
         parse_and_compare("string", TypeName::Str);
+        parse_and_compare("json", TypeName::Json);
+    }
+
+    #[test]
+    fn test_map_type_name() {
+        parse_and_compare(
+            "map<string, u32>",
+            TypeName::List(Box::new(TypeName::Tuple(vec![
+                TypeName::Str,
+                TypeName::U32,
+            ]))),
+        );
+        parse_and_compare(
+            "map<string, list<json>>",
+            TypeName::List(Box::new(TypeName::Tuple(vec![
+                TypeName::Str,
+                TypeName::List(Box::new(TypeName::Json)),
+            ]))),
+        );
     }
 
     #[test]
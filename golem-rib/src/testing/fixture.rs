@@ -0,0 +1,261 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn, EvaluatedWorkerName, InstructionId,
+    RibComponentFunctionInvoke, RibFunctionInvokeResult,
+};
+use async_trait::async_trait;
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{parse_value_and_type, print_value_and_type, ValueAndType};
+use serde::{Deserialize, Serialize};
+
+/// A declared result of a worker function call, used instead of invoking a worker
+/// when testing Rib scripts.
+///
+/// `function` is either the fully qualified function name (Ex: `golem:it/api.{add-item}`),
+/// or a suffix of it (Ex: `api.{add-item}` or `add-item`).
+/// Arguments and results are in WAVE format, and are typed based on the function being called.
+/// If `args` is not specified, the fixture applies to any arguments, and `_` matches any argument.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RibFunctionFixture {
+    pub function: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+}
+
+impl RibFunctionFixture {
+    pub fn new(function: &str, result: Option<&str>) -> Self {
+        RibFunctionFixture {
+            function: function.to_string(),
+            worker: None,
+            args: None,
+            result: result.map(|result| result.to_string()),
+        }
+    }
+
+    pub fn with_args(mut self, args: Vec<&str>) -> Self {
+        self.args = Some(args.into_iter().map(|arg| arg.to_string()).collect());
+        self
+    }
+
+    pub fn with_worker(mut self, worker: &str) -> Self {
+        self.worker = Some(worker.to_string());
+        self
+    }
+
+    fn matches(
+        &self,
+        worker_name: Option<&EvaluatedWorkerName>,
+        function_name: &str,
+        args: &[ValueAndType],
+    ) -> bool {
+        self.matches_function(function_name)
+            && self.matches_worker(worker_name)
+            && self.matches_args(args)
+    }
+
+    fn matches_function(&self, function_name: &str) -> bool {
+        let function = self.function.as_str();
+
+        function_name == function
+            || function_name.ends_with(&format!("{{{function}}}"))
+            || [".", "/"]
+                .iter()
+                .any(|separator| function_name.ends_with(&format!("{separator}{function}")))
+    }
+
+    fn matches_worker(&self, worker_name: Option<&EvaluatedWorkerName>) -> bool {
+        match &self.worker {
+            Some(worker) => worker_name.is_some_and(|worker_name| &worker_name.0 == worker),
+            None => true,
+        }
+    }
+
+    fn matches_args(&self, args: &[ValueAndType]) -> bool {
+        match &self.args {
+            Some(patterns) => {
+                patterns.len() == args.len()
+                    && patterns.iter().zip(args).all(|(pattern, arg)| {
+                        pattern.trim() == "_"
+                            || parse_value_and_type(&arg.typ, pattern)
+                                .is_ok_and(|expected| expected.value == arg.value)
+                    })
+            }
+            None => true,
+        }
+    }
+
+    fn result(&self, return_type: Option<AnalysedType>) -> Result<Option<ValueAndType>, String> {
+        match (&self.result, return_type) {
+            (Some(result), Some(return_type)) => parse_value_and_type(&return_type, result)
+                .map(Some)
+                .map_err(|err| {
+                    format!(
+                        "invalid result `{result}` in the fixture of `{}`: {err}",
+                        self.function
+                    )
+                }),
+            (None, None) => Ok(None),
+            (Some(result), None) => Err(format!(
+                "unexpected result `{result}` in the fixture of `{}`, which returns unit",
+                self.function
+            )),
+            (None, Some(_)) => Err(format!(
+                "missing result in the fixture of `{}`",
+                self.function
+            )),
+        }
+    }
+}
+
+/// Serves worker function calls in a Rib script from fixtures,
+/// so that Rib scripts can be tested without any running workers.
+/// The first fixture matching a call is used, and a call without
+/// any matching fixture fails.
+#[derive(Debug, Clone, Default)]
+pub struct RibFunctionFixtures {
+    fixtures: Vec<RibFunctionFixture>,
+}
+
+impl RibFunctionFixtures {
+    pub fn new(fixtures: Vec<RibFunctionFixture>) -> Self {
+        RibFunctionFixtures { fixtures }
+    }
+
+    pub fn add(&mut self, fixture: RibFunctionFixture) {
+        self.fixtures.push(fixture);
+    }
+}
+
+#[async_trait]
+impl RibComponentFunctionInvoke for RibFunctionFixtures {
+    async fn invoke(
+        &self,
+        _component_dependency_key: ComponentDependencyKey,
+        _instruction_id: &InstructionId,
+        worker_name: Option<EvaluatedWorkerName>,
+        function_name: EvaluatedFqFn,
+        args: EvaluatedFnArgs,
+        return_type: Option<AnalysedType>,
+    ) -> RibFunctionInvokeResult {
+        let fixture = self
+            .fixtures
+            .iter()
+            .find(|fixture| fixture.matches(worker_name.as_ref(), &function_name.0, &args.0));
+
+        match fixture {
+            Some(fixture) => Ok(fixture.result(return_type)?),
+            None => {
+                let args = args
+                    .0
+                    .iter()
+                    .map(|arg| print_value_and_type(arg).unwrap_or_else(|_| format!("{arg:?}")))
+                    .collect::<Vec<_>>();
+
+                Err(format!(
+                    "no fixture found for the call `{}({})`",
+                    function_name.0,
+                    args.join(", ")
+                )
+                .into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::{
+        ComponentDependencyKey, EvaluatedFnArgs, EvaluatedFqFn, InstructionId,
+        RibComponentFunctionInvoke, RibFunctionFixture, RibFunctionFixtures,
+    };
+    use golem_wasm_ast::analysis::analysed_type::{str, u32};
+    use golem_wasm_rpc::IntoValueAndType;
+    use uuid::Uuid;
+
+    fn component_dependency_key() -> ComponentDependencyKey {
+        ComponentDependencyKey {
+            component_name: "shopping-cart".to_string(),
+            component_id: Uuid::nil(),
+            root_package_name: None,
+            root_package_version: None,
+        }
+    }
+
+    #[test]
+    async fn test_fixture_matching_function_and_args() {
+        let fixtures = RibFunctionFixtures::new(vec![
+            RibFunctionFixture::new("get-quantity", Some("1")).with_args(vec!["\"foo\""]),
+            RibFunctionFixture::new("api.{get-quantity}", Some("2")).with_args(vec!["_"]),
+        ]);
+
+        let result = fixtures
+            .invoke(
+                component_dependency_key(),
+                &InstructionId::init(),
+                None,
+                EvaluatedFqFn("golem:it/api.{get-quantity}".to_string()),
+                EvaluatedFnArgs(vec!["foo".into_value_and_type()]),
+                Some(u32()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, Some(1u32.into_value_and_type()));
+
+        let result = fixtures
+            .invoke(
+                component_dependency_key(),
+                &InstructionId::init(),
+                None,
+                EvaluatedFqFn("golem:it/api.{get-quantity}".to_string()),
+                EvaluatedFnArgs(vec!["bar".into_value_and_type()]),
+                Some(u32()),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, Some(2u32.into_value_and_type()));
+    }
+
+    #[test]
+    async fn test_fixture_not_found() {
+        let fixtures =
+            RibFunctionFixtures::new(vec![RibFunctionFixture::new("get-name", Some("\"a\""))]);
+
+        let result = fixtures
+            .invoke(
+                component_dependency_key(),
+                &InstructionId::init(),
+                None,
+                EvaluatedFqFn("golem:it/api.{get-quantity}".to_string()),
+                EvaluatedFnArgs(vec!["foo".into_value_and_type()]),
+                Some(str()),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "no fixture found for the call `golem:it/api.{get-quantity}(\"foo\")`"
+        );
+    }
+}
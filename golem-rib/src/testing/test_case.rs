@@ -0,0 +1,251 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    DefaultWorkerNameGenerator, Expr, Interpreter, RibCompiler, RibFunctionFixture,
+    RibFunctionFixtures, RibInput, RibResult,
+};
+use golem_wasm_rpc::{parse_value_and_type, print_value_and_type, ValueAndType};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::sync::Arc;
+
+/// A test case of a Rib script, with worker function calls served from fixtures.
+///
+/// Inputs (Ex: `request`), the expected result and the fixtures are in WAVE format,
+/// and are typed based on the Rib script. If neither `expected` nor `expectedError`
+/// is specified, the test passes as long as the script runs successfully.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RibTestCase {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mocks: Vec<RibFunctionFixture>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// A part of the expected error message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RibTestOutcome {
+    Passed,
+    Failed(String),
+}
+
+impl Display for RibTestOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RibTestOutcome::Passed => write!(f, "passed"),
+            RibTestOutcome::Failed(reason) => write!(f, "failed: {reason}"),
+        }
+    }
+}
+
+impl RibTestCase {
+    pub async fn run(&self, rib: &str, compiler: &RibCompiler) -> RibTestOutcome {
+        match self.run_rib(rib, compiler).await {
+            Ok(result) => self.check_result(result),
+            Err(RibTestError::Setup(reason)) => RibTestOutcome::Failed(reason),
+            Err(RibTestError::Runtime(error)) => self.check_error(error),
+        }
+    }
+
+    async fn run_rib(&self, rib: &str, compiler: &RibCompiler) -> Result<RibResult, RibTestError> {
+        let expr = Expr::from_text(rib)
+            .map_err(|err| RibTestError::Setup(format!("invalid rib script: {err}")))?;
+
+        let compiler_output = compiler
+            .compile(expr)
+            .map_err(|err| RibTestError::Setup(format!("rib compilation failed: {err}")))?;
+
+        let mut inputs = HashMap::new();
+
+        for (name, value) in &self.inputs {
+            let input_type = compiler_output
+                .rib_input_type_info
+                .get(name)
+                .ok_or_else(|| {
+                    RibTestError::Setup(format!("input `{name}` is not used in the rib script"))
+                })?;
+
+            let value = parse_value_and_type(input_type, value)
+                .map_err(|err| RibTestError::Setup(format!("invalid input `{name}`: {err}")))?;
+
+            inputs.insert(name.clone(), value);
+        }
+
+        let mut interpreter = Interpreter::new(
+            RibInput::new(inputs),
+            Arc::new(RibFunctionFixtures::new(self.mocks.clone())),
+            Arc::new(DefaultWorkerNameGenerator),
+        );
+
+        interpreter
+            .run(compiler_output.byte_code)
+            .await
+            .map_err(|err| RibTestError::Runtime(err.to_string()))
+    }
+
+    fn check_result(&self, result: RibResult) -> RibTestOutcome {
+        if let Some(expected_error) = &self.expected_error {
+            return RibTestOutcome::Failed(format!(
+                "expected an error containing `{expected_error}`, but the result is `{}`",
+                display_result(&result)
+            ));
+        }
+
+        let Some(expected) = &self.expected else {
+            return RibTestOutcome::Passed;
+        };
+
+        let matches = match &result {
+            RibResult::Unit => expected.trim() == "()",
+            RibResult::Val(value) => parse_value_and_type(&value.typ, expected)
+                .is_ok_and(|expected| expected.value == value.value),
+        };
+
+        if matches {
+            RibTestOutcome::Passed
+        } else {
+            RibTestOutcome::Failed(format!(
+                "expected `{expected}`, but the result is `{}`",
+                display_result(&result)
+            ))
+        }
+    }
+
+    fn check_error(&self, error: String) -> RibTestOutcome {
+        match &self.expected_error {
+            Some(expected_error) if error.contains(expected_error.as_str()) => {
+                RibTestOutcome::Passed
+            }
+            Some(expected_error) => RibTestOutcome::Failed(format!(
+                "expected an error containing `{expected_error}`, but the error is `{error}`"
+            )),
+            None => RibTestOutcome::Failed(format!("rib execution failed: {error}")),
+        }
+    }
+}
+
+enum RibTestError {
+    Setup(String),
+    Runtime(String),
+}
+
+fn display_result(result: &RibResult) -> String {
+    match result {
+        RibResult::Unit => "()".to_string(),
+        RibResult::Val(value) => display_value(value),
+    }
+}
+
+fn display_value(value: &ValueAndType) -> String {
+    print_value_and_type(value).unwrap_or_else(|_| format!("{value:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::testing::test_case::RibTestOutcome;
+    use crate::{
+        ComponentDependency, ComponentDependencyKey, RibCompiler, RibCompilerConfig,
+        RibFunctionFixture, RibTestCase,
+    };
+    use golem_wasm_ast::analysis::analysed_type::{str, u32};
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+    };
+    use std::collections::BTreeMap;
+    use uuid::Uuid;
+
+    fn compiler() -> RibCompiler {
+        let export = AnalysedExport::Function(AnalysedFunction {
+            name: "get-quantity".to_string(),
+            parameters: vec![AnalysedFunctionParameter {
+                name: "product-id".to_string(),
+                typ: str(),
+            }],
+            result: Some(AnalysedFunctionResult { typ: u32() }),
+        });
+
+        let component_dependency_key = ComponentDependencyKey {
+            component_name: "shopping-cart".to_string(),
+            component_id: Uuid::nil(),
+            root_package_name: None,
+            root_package_version: None,
+        };
+
+        RibCompiler::new(RibCompilerConfig::new(
+            vec![ComponentDependency::new(
+                component_dependency_key,
+                vec![export],
+            )],
+            vec![],
+        ))
+    }
+
+    const RIB: &str = r#"
+          let worker = instance("cart");
+          let quantity = worker.get-quantity(request.path.product-id);
+          quantity * 2
+        "#;
+
+    fn test_case(expected: Option<&str>, expected_error: Option<&str>) -> RibTestCase {
+        RibTestCase {
+            name: "quantity".to_string(),
+            inputs: BTreeMap::from([(
+                "request".to_string(),
+                "{path: {product-id: \"p1\"}}".to_string(),
+            )]),
+            mocks: vec![
+                RibFunctionFixture::new("get-quantity", Some("3")).with_args(vec!["\"p1\""])
+            ],
+            expected: expected.map(|expected| expected.to_string()),
+            expected_error: expected_error.map(|error| error.to_string()),
+        }
+    }
+
+    #[test]
+    async fn test_rib_test_case_passed() {
+        let outcome = test_case(Some("6"), None).run(RIB, &compiler()).await;
+        assert_eq!(outcome, RibTestOutcome::Passed);
+    }
+
+    #[test]
+    async fn test_rib_test_case_failed() {
+        let outcome = test_case(Some("5"), None).run(RIB, &compiler()).await;
+        assert_eq!(
+            outcome,
+            RibTestOutcome::Failed("expected `5`, but the result is `6`".to_string())
+        );
+    }
+
+    #[test]
+    async fn test_rib_test_case_expected_error() {
+        let mut test_case = test_case(None, Some("no fixture found"));
+        test_case.inputs.insert(
+            "request".to_string(),
+            "{path: {product-id: \"p2\"}}".to_string(),
+        );
+
+        let outcome = test_case.run(RIB, &compiler()).await;
+        assert_eq!(outcome, RibTestOutcome::Passed);
+    }
+}
@@ -52,7 +52,7 @@ pub fn check_invalid_worker_name(expr: &mut Expr) -> Result<(), InvalidWorkerNam
 }
 
 mod internal {
-    use crate::type_refinement::precise_types::StringType;
+    use crate::type_refinement::precise_types::{JsonType, StringType};
     use crate::type_refinement::TypeRefinement;
     use crate::{Expr, InvalidWorkerName, TypeName};
 
@@ -63,19 +63,18 @@ mod internal {
             None => {}
             Some(expr) => {
                 let inferred_type = expr.inferred_type();
-                let string_type = StringType::refine(&inferred_type);
+                // A value looked up from json, such as `body["user"]`, is a string as well
+                let is_string = StringType::refine(&inferred_type).is_some()
+                    || JsonType::refine(&inferred_type).is_some();
 
-                match string_type {
-                    Some(_) => {}
-                    None => {
-                        let type_name = TypeName::try_from(inferred_type.clone())
-                            .map(|t| t.to_string())
-                            .unwrap_or_else(|_| "unknown".to_string());
-                        return Err(InvalidWorkerName {
-                            worker_name_source_span: expr.source_span(),
-                            message: format!("expected string, found {type_name}"),
-                        });
-                    }
+                if !is_string {
+                    let type_name = TypeName::try_from(inferred_type.clone())
+                        .map(|t| t.to_string())
+                        .unwrap_or_else(|_| "unknown".to_string());
+                    return Err(InvalidWorkerName {
+                        worker_name_source_span: expr.source_span(),
+                        message: format!("expected string, found {type_name}"),
+                    });
                 }
             }
         }
@@ -278,7 +278,7 @@ impl GetTypeHint for InferredType {
             | TypeInternal::F32
             | TypeInternal::F64 => TypeHint::Number,
             TypeInternal::Chr => TypeHint::Char,
-            TypeInternal::Str => TypeHint::Str,
+            TypeInternal::Str | TypeInternal::Json => TypeHint::Str,
             TypeInternal::List(inferred_type) => {
                 TypeHint::List(Some(Box::new(inferred_type.get_type_hint())))
             }
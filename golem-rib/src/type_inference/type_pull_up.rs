@@ -15,7 +15,7 @@
 use crate::rib_source_span::SourceSpan;
 use crate::rib_type_error::RibTypeErrorInternal;
 use crate::type_inference::type_hint::TypeHint;
-use crate::type_refinement::precise_types::{ListType, RecordType};
+use crate::type_refinement::precise_types::{JsonType, ListType, RecordType, TupleType};
 use crate::type_refinement::TypeRefinement;
 use crate::FunctionName;
 use crate::{
@@ -537,6 +537,17 @@ fn get_inferred_type_of_selection_dynamic(
     let select_from_type = select_from.inferred_type();
     let select_index_type = index.inferred_type();
 
+    // Looking up a key or an index in json results in json. A plain string cannot be looked up,
+    // and is reported as not being a list below
+    if JsonType::refine(&select_from_type).is_some()
+        && !matches!(
+            select_index_type.internal_type(),
+            TypeInternal::Range { .. }
+        )
+    {
+        return Ok(InferredType::json());
+    }
+
     let refined_list = ListType::refine(&select_from_type).ok_or({
         TypeMismatchError {
             source_span: select_from.source_span(),
@@ -554,6 +565,10 @@ fn get_inferred_type_of_selection_dynamic(
 
     let list_type = refined_list.inner_type();
 
+    if select_index_type.is_lookup_key() {
+        return get_inferred_type_of_map_value(select_from, index, &list_type);
+    }
+
     if select_index_type.contains_only_number() {
         Ok(list_type)
     } else {
@@ -561,6 +576,36 @@ fn get_inferred_type_of_selection_dynamic(
     }
 }
 
+// A map is a list of key-value tuples, and looking up a key results in the value
+fn get_inferred_type_of_map_value(
+    select_from: &Expr,
+    index: &Expr,
+    entry_type: &InferredType,
+) -> Result<InferredType, RibTypeErrorInternal> {
+    let key_value_types = TupleType::refine(entry_type)
+        .map(|tuple| tuple.inner_types())
+        .filter(|types| types.len() == 2)
+        .ok_or({
+            TypeMismatchError {
+                source_span: select_from.source_span(),
+                expected_type: ExpectedType::Hint(TypeHint::List(Some(Box::new(TypeHint::Tuple(
+                    None,
+                ))))),
+                actual_type: ActualType::Inferred(select_from.inferred_type()),
+                field_path: Default::default(),
+                additional_error_detail: vec![format!(
+                    "cannot look up key {} in {} since it is not a map (list of key-value tuples)",
+                    index, select_from,
+                )],
+            }
+        })?;
+
+    Ok(key_value_types
+        .into_iter()
+        .nth(1)
+        .unwrap_or(InferredType::unknown()))
+}
+
 #[cfg(test)]
 mod type_pull_up_tests {
     use bigdecimal::BigDecimal;
@@ -609,6 +654,40 @@ mod type_pull_up_tests {
         assert_eq!(expr, expected);
     }
 
+    #[test]
+    pub fn test_pull_up_for_map_lookup() {
+        let identifier =
+            Expr::identifier_global("foo", None).merge_inferred_type(InferredType::list(
+                InferredType::tuple(vec![InferredType::string(), InferredType::u64()]),
+            ));
+        let mut expr = Expr::select_index(identifier, Expr::literal("bar"));
+        expr.pull_types_up(&ComponentDependencies::default())
+            .unwrap();
+        assert_eq!(expr.inferred_type(), InferredType::u64());
+    }
+
+    #[test]
+    pub fn test_pull_up_for_json_lookup() {
+        let identifier =
+            Expr::identifier_global("foo", None).merge_inferred_type(InferredType::json());
+        let mut expr = Expr::select_index(
+            Expr::select_index(identifier, Expr::literal("bar")),
+            Expr::number(BigDecimal::from(0)),
+        );
+        expr.pull_types_up(&ComponentDependencies::default())
+            .unwrap();
+        assert_eq!(expr.inferred_type(), InferredType::json());
+    }
+
+    #[test]
+    pub fn test_pull_up_for_key_lookup_on_string() {
+        let identifier =
+            Expr::identifier_global("foo", None).merge_inferred_type(InferredType::string());
+        let mut expr = Expr::select_index(identifier, Expr::literal("bar"));
+        let result = expr.pull_types_up(&ComponentDependencies::default());
+        assert!(result.is_err());
+    }
+
     #[test]
     pub fn test_pull_up_for_sequence() {
         let elems = vec![
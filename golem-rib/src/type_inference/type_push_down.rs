@@ -14,7 +14,7 @@
 
 use crate::rib_type_error::RibTypeErrorInternal;
 use crate::type_inference::type_push_down::internal::{
-    handle_list_comprehension, handle_list_reduce,
+    handle_list_comprehension, handle_list_reduce, is_json_lookup,
 };
 use crate::{Expr, ExprVisitor, InferredType, MatchArm, TypeInternal};
 use std::ops::Deref;
@@ -57,6 +57,16 @@ pub fn push_types_down(expr: &mut Expr) -> Result<(), RibTypeErrorInternal> {
                     TypeInternal::Range { .. } => {
                         expr.add_infer_type_mut(inferred_type.clone());
                     }
+                    // Looking up a key or an index in json doesn't tell anything more than json
+                    _ if is_json_lookup(expr) => {}
+                    // Looking up a key implies LHS is a map, i.e, list(tuple(key, field_type))
+                    _ if index_expr_type.is_lookup_key() => {
+                        let new_inferred_type = InferredType::list(InferredType::tuple(vec![
+                            index_expr_type.clone(),
+                            field_type,
+                        ]));
+                        expr.add_infer_type_mut(new_inferred_type);
+                    }
                     _ => {
                         // Similar to selectIndex
                         let new_inferred_type = InferredType::list(field_type);
@@ -261,6 +271,21 @@ mod internal {
     use std::collections::VecDeque;
     use std::ops::Deref;
 
+    // Whether the expression is json, or a chain of lookups such as `body["foo"][0]` from json,
+    // in which case the types of the intermediate lookups are yet to be pulled up
+    pub(crate) fn is_json_lookup(expr: &Expr) -> bool {
+        JsonType::refine(&expr.inferred_type()).is_some()
+            || match expr {
+                Expr::SelectIndex { expr, index, .. } => {
+                    !matches!(
+                        index.inferred_type().internal_type(),
+                        TypeInternal::Range { .. }
+                    ) && is_json_lookup(expr)
+                }
+                _ => false,
+            }
+    }
+
     pub(crate) fn handle_list_comprehension(
         variable_id: &mut VariableId,
         iterable_expr: &mut Expr,
@@ -170,6 +170,18 @@ impl TypeRefinement for StringType {
     }
 }
 
+impl TypeRefinement for JsonType {
+    fn refine(inferred_type: &InferredType) -> Option<RefinedType<Self>> {
+        internal::refine_inferred_type(inferred_type, &|inferred_type| {
+            if let TypeInternal::Json = inferred_type.internal_type() {
+                Some(JsonType)
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl TypeRefinement for NumberType {
     fn refine(inferred_type: &InferredType) -> Option<RefinedType<Self>> {
         internal::refine_inferred_type(inferred_type, &|inferred_type| match inferred_type
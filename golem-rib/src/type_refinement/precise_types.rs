@@ -38,6 +38,9 @@ pub struct VariantType(pub Vec<(String, Option<InferredType>)>);
 #[derive(Clone, PartialEq, Debug)]
 pub struct StringType;
 
+#[derive(Clone, PartialEq, Debug)]
+pub struct JsonType;
+
 #[allow(dead_code)]
 #[derive(Clone, PartialEq, Debug)]
 pub struct NumberType;
@@ -79,6 +79,7 @@ pub struct S3BlobStorageConfig {
     pub initial_component_files_bucket: String,
     pub components_bucket: String,
     pub plugin_wasm_files_bucket: String,
+    pub worker_files_bucket: String,
 }
 
 impl Default for S3BlobStorageConfig {
@@ -96,6 +97,7 @@ impl Default for S3BlobStorageConfig {
             initial_component_files_bucket: "golem-initial-component-files".to_string(),
             components_bucket: "component-store".to_string(),
             plugin_wasm_files_bucket: "golem-plugin-wasm-files".to_string(),
+            worker_files_bucket: "golem-worker-files".to_string(),
         }
     }
 }
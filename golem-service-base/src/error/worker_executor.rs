@@ -16,7 +16,7 @@ use bincode::{Decode, Encode};
 use golem_api_grpc::proto::golem;
 use golem_common::metrics::api::TraceErrorKind;
 use golem_common::model::oplog::WorkerError;
-use golem_common::model::{ComponentId, PromiseId, ShardId, WorkerId};
+use golem_common::model::{ComponentId, IdempotencyKey, PromiseId, ShardId, WorkerId};
 use golem_common::SafeDisplay;
 use golem_wasm_rpc::wasmtime::EncodingError;
 use golem_wasm_rpc_derive::IntoValue;
@@ -114,6 +114,10 @@ pub enum WorkerExecutorError {
         error: WorkerError,
         stderr: String,
     },
+    InvocationNotFound {
+        worker_id: WorkerId,
+        idempotency_key: IdempotencyKey,
+    },
 }
 
 impl WorkerExecutorError {
@@ -135,6 +139,13 @@ impl WorkerExecutorError {
         Self::WorkerNotFound { worker_id }
     }
 
+    pub fn invocation_not_found(worker_id: WorkerId, idempotency_key: IdempotencyKey) -> Self {
+        Self::InvocationNotFound {
+            worker_id,
+            idempotency_key,
+        }
+    }
+
     pub fn worker_already_exists(worker_id: WorkerId) -> Self {
         Self::WorkerAlreadyExists { worker_id }
     }
@@ -298,6 +309,15 @@ impl Display for WorkerExecutorError {
             Self::InvocationFailed { error, stderr } => {
                 write!(f, "Component trapped: {}", error.to_string(stderr))
             }
+            Self::InvocationNotFound {
+                worker_id,
+                idempotency_key,
+            } => {
+                write!(
+                    f,
+                    "Invocation {idempotency_key} not found in worker {worker_id}"
+                )
+            }
         }
     }
 }
@@ -339,6 +359,7 @@ impl Error for WorkerExecutorError {
             Self::Unknown { .. } => "Unknown error",
             Self::ShardingNotReady => "Sharding not ready",
             Self::FileSystemError { .. } => "File system error",
+            Self::InvocationNotFound { .. } => "Invocation not found",
         }
     }
 }
@@ -372,6 +393,7 @@ impl TraceErrorKind for WorkerExecutorError {
             Self::Unknown { .. } => "Unknown",
             Self::ShardingNotReady => "ShardingNotReady",
             Self::FileSystemError { .. } => "FileSystemError",
+            Self::InvocationNotFound { .. } => "InvocationNotFound",
         }
     }
 
@@ -380,6 +402,7 @@ impl TraceErrorKind for WorkerExecutorError {
             Self::WorkerAlreadyExists { .. }
             | Self::WorkerNotFound { .. }
             | Self::PromiseNotFound { .. }
+            | Self::InvocationNotFound { .. }
             | Self::PromiseDropped { .. }
             | Self::PromiseAlreadyCompleted { .. }
             | Self::Interrupted { .. }
@@ -440,6 +463,7 @@ impl From<WorkerExecutorError> for Status {
             WorkerExecutorError::WorkerNotFound { worker_id } => {
                 Self::not_found(format!("Worker not found: {worker_id}"))
             }
+            WorkerExecutorError::InvocationNotFound { .. } => Self::not_found(format!("{value}")),
             WorkerExecutorError::ParamTypeMismatch { details } => {
                 Self::invalid_argument(format!("Parameter type mismatch: {details}"))
             }
@@ -684,6 +708,19 @@ impl From<WorkerExecutorError> for golem::worker::v1::WorkerExecutionError {
                     }
                 ))
             },
+            WorkerExecutorError::InvocationNotFound {
+                worker_id,
+                idempotency_key,
+            } => Self {
+                error: Some(
+                    golem::worker::v1::worker_execution_error::Error::InvocationNotFound(
+                        golem::worker::v1::InvocationNotFound {
+                            worker_id: Some(worker_id.into()),
+                            idempotency_key: Some(idempotency_key.into()),
+                        },
+                    ),
+                ),
+            },
         }
     }
 }
@@ -871,6 +908,18 @@ impl TryFrom<golem::worker::v1::WorkerExecutionError> for WorkerExecutorError {
                 error: inner.error.ok_or("no trap_cause field")?.try_into()?,
                 stderr: inner.stderr
              }),
+            Some(golem::worker::v1::worker_execution_error::Error::InvocationNotFound(
+                invocation_not_found,
+            )) => Ok(Self::InvocationNotFound {
+                worker_id: invocation_not_found
+                    .worker_id
+                    .ok_or("Missing worker_id")?
+                    .try_into()?,
+                idempotency_key: invocation_not_found
+                    .idempotency_key
+                    .ok_or("Missing idempotency_key")?
+                    .into(),
+            }),
         }
     }
 }
@@ -0,0 +1,198 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::WorkerUpdateMode;
+use chrono::{DateTime, Utc};
+use golem_common::model::{ComponentId, ComponentVersion, WorkerFilter};
+use golem_wasm_rpc::json::OptionallyValueAndTypeJson;
+use poem_openapi::{Enum, NewType, Object, Union};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, NewType,
+)]
+pub struct BulkOperationId(pub Uuid);
+
+impl BulkOperationId {
+    pub fn new_v4() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Display for BulkOperationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for BulkOperationId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s)
+            .map(Self)
+            .map_err(|err| format!("Invalid bulk operation id '{s}': {err}"))
+    }
+}
+
+/// The operation to perform on each worker selected by a bulk operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Union)]
+#[serde(rename_all = "camelCase")]
+#[oai(discriminator_name = "type", one_of = true, rename_all = "camelCase")]
+pub enum BulkOperationKind {
+    Update(BulkUpdateOperation),
+    Interrupt(BulkInterruptOperation),
+    Resume(BulkResumeOperation),
+    Delete(BulkDeleteOperation),
+    Invoke(BulkInvokeOperation),
+}
+
+impl BulkOperationKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BulkOperationKind::Update(_) => "update",
+            BulkOperationKind::Interrupt(_) => "interrupt",
+            BulkOperationKind::Resume(_) => "resume",
+            BulkOperationKind::Delete(_) => "delete",
+            BulkOperationKind::Invoke(_) => "invoke",
+        }
+    }
+}
+
+/// Updates each worker to the given component version
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkUpdateOperation {
+    pub mode: WorkerUpdateMode,
+    pub target_version: ComponentVersion,
+}
+
+/// Interrupts each worker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkInterruptOperation {
+    /// Simulates a crash instead of stopping the workers, so they get recovered immediately
+    pub recover_immediately: Option<bool>,
+}
+
+/// Resumes each worker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkResumeOperation {
+    pub force: Option<bool>,
+}
+
+/// Deletes each worker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct BulkDeleteOperation {}
+
+/// Enqueues an invocation of the same function with the same parameters on each worker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkInvokeOperation {
+    pub function: String,
+    pub params: Vec<OptionallyValueAndTypeJson>,
+}
+
+/// Performs an operation on the workers of a component matching `filter`, or on all of them if no filter is given
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkOperationRequest {
+    pub filter: Option<WorkerFilter>,
+    pub operation: BulkOperationKind,
+    /// The maximum number of workers the operation is performed on at the same time
+    pub concurrency: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum BulkOperationStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+impl Display for BulkOperationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BulkOperationStatus::Running => write!(f, "Running"),
+            BulkOperationStatus::Completed => write!(f, "Completed"),
+            BulkOperationStatus::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+impl FromStr for BulkOperationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Running" => Ok(BulkOperationStatus::Running),
+            "Completed" => Ok(BulkOperationStatus::Completed),
+            "Failed" => Ok(BulkOperationStatus::Failed),
+            _ => Err(format!("Unknown bulk operation status: {s}")),
+        }
+    }
+}
+
+/// A bulk operation and its progress
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkOperation {
+    pub operation_id: BulkOperationId,
+    pub component_id: ComponentId,
+    pub filter: Option<WorkerFilter>,
+    pub operation: BulkOperationKind,
+    pub concurrency: u32,
+    pub status: BulkOperationStatus,
+    /// The number of selected workers. Not set until all the matching workers have been enumerated.
+    pub total: Option<u64>,
+    pub succeeded: u64,
+    pub failed: u64,
+    /// The reason the operation could not be finished, if its status is `Failed`
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// The outcome of a bulk operation on a single worker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkOperationWorkerResult {
+    pub worker_name: String,
+    /// Not set if the operation succeeded on the worker
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct BulkOperationReport {
+    pub operation: BulkOperation,
+    pub results: Vec<BulkOperationWorkerResult>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+pub struct BulkOperationsResponse {
+    pub operations: Vec<BulkOperation>,
+}
@@ -0,0 +1,179 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::worker_executor::WorkerExecutorError;
+use golem_common::model::IdempotencyKey;
+use golem_common::SafeDisplay;
+use golem_wasm_rpc::ValueAndType;
+use poem_openapi::{Enum, Object};
+use serde::{Deserialize, Serialize};
+
+/// The state of an invocation, looked up by its idempotency key
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvocationResultStatus {
+    Pending,
+    Running,
+    Completed(Option<ValueAndType>),
+    Failed(WorkerExecutorError),
+}
+
+impl InvocationResultStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            InvocationResultStatus::Completed(_) | InvocationResultStatus::Failed(_)
+        )
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::v1::InvocationResultStatus>
+    for InvocationResultStatus
+{
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::v1::InvocationResultStatus,
+    ) -> Result<Self, Self::Error> {
+        use golem_api_grpc::proto::golem::worker::v1::invocation_result_status::Status;
+
+        match value.status.ok_or("Missing field: status")? {
+            Status::Pending(_) => Ok(Self::Pending),
+            Status::Running(_) => Ok(Self::Running),
+            Status::Completed(completed) => Ok(Self::Completed(
+                completed.result.map(ValueAndType::try_from).transpose()?,
+            )),
+            Status::Failed(error) => Ok(Self::Failed(error.try_into()?)),
+        }
+    }
+}
+
+impl From<InvocationResultStatus>
+    for golem_api_grpc::proto::golem::worker::v1::InvocationResultStatus
+{
+    fn from(value: InvocationResultStatus) -> Self {
+        use golem_api_grpc::proto::golem::worker::v1::invocation_result_status::Status;
+        use golem_api_grpc::proto::golem::worker::v1::InvocationCompleted;
+
+        let status = match value {
+            InvocationResultStatus::Pending => {
+                Status::Pending(golem_api_grpc::proto::golem::common::Empty {})
+            }
+            InvocationResultStatus::Running => {
+                Status::Running(golem_api_grpc::proto::golem::common::Empty {})
+            }
+            InvocationResultStatus::Completed(result) => Status::Completed(InvocationCompleted {
+                result: result.map(|result| result.into()),
+            }),
+            InvocationResultStatus::Failed(error) => Status::Failed(error.into()),
+        };
+        Self {
+            status: Some(status),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Enum)]
+pub enum InvocationStatus {
+    /// The invocation is enqueued, but the worker has not started executing it yet
+    Pending,
+    /// The worker is executing the invocation
+    Running,
+    /// The invocation has finished successfully
+    Completed,
+    /// The invocation has failed, or was interrupted
+    Failed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct InvocationResultResponse {
+    pub idempotency_key: IdempotencyKey,
+    pub status: InvocationStatus,
+    pub result: Option<ValueAndType>,
+    /// The reason of the failure, only set if the invocation has failed
+    pub error: Option<String>,
+}
+
+impl InvocationResultResponse {
+    pub fn new(idempotency_key: IdempotencyKey, status: InvocationResultStatus) -> Self {
+        match status {
+            InvocationResultStatus::Pending => Self {
+                idempotency_key,
+                status: InvocationStatus::Pending,
+                result: None,
+                error: None,
+            },
+            InvocationResultStatus::Running => Self {
+                idempotency_key,
+                status: InvocationStatus::Running,
+                result: None,
+                error: None,
+            },
+            InvocationResultStatus::Completed(result) => Self {
+                idempotency_key,
+                status: InvocationStatus::Completed,
+                result,
+                error: None,
+            },
+            InvocationResultStatus::Failed(error) => Self {
+                idempotency_key,
+                status: InvocationStatus::Failed,
+                result: None,
+                error: Some(error.to_safe_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvocationResultResponse, InvocationResultStatus, InvocationStatus};
+    use crate::error::worker_executor::WorkerExecutorError;
+    use golem_common::model::IdempotencyKey;
+    use golem_wasm_rpc::IntoValueAndType;
+    use test_r::test;
+
+    fn roundtrip(status: InvocationResultStatus) -> InvocationResultStatus {
+        let proto: golem_api_grpc::proto::golem::worker::v1::InvocationResultStatus = status.into();
+        proto.try_into().unwrap()
+    }
+
+    #[test]
+    fn status_protobuf_roundtrip() {
+        let statuses = vec![
+            InvocationResultStatus::Pending,
+            InvocationResultStatus::Running,
+            InvocationResultStatus::Completed(None),
+            InvocationResultStatus::Completed(Some(42u32.into_value_and_type())),
+            InvocationResultStatus::Failed(WorkerExecutorError::invalid_request("failure")),
+        ];
+
+        for status in statuses {
+            assert_eq!(roundtrip(status.clone()), status);
+        }
+    }
+
+    #[test]
+    fn failed_response_has_error_but_no_result() {
+        let response = InvocationResultResponse::new(
+            IdempotencyKey::fresh(),
+            InvocationResultStatus::Failed(WorkerExecutorError::invalid_request("failure")),
+        );
+
+        assert_eq!(response.status, InvocationStatus::Failed);
+        assert_eq!(response.result, None);
+        assert!(response.error.unwrap().contains("failure"));
+    }
+}
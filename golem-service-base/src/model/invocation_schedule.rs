@@ -0,0 +1,377 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use chrono::{DateTime, Utc};
+use golem_common::model::{InvocationScheduleId, Timestamp, WorkerId};
+use golem_wasm_rpc::json::OptionallyValueAndTypeJson;
+use poem_openapi::{Object, Union};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Describes when a scheduled invocation has to be performed
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Union)]
+#[serde(rename_all = "camelCase")]
+#[oai(discriminator_name = "type", one_of = true, rename_all = "camelCase")]
+pub enum InvocationScheduleSpec {
+    Once(OnceInvocationSchedule),
+    Cron(CronInvocationSchedule),
+}
+
+impl InvocationScheduleSpec {
+    /// Gets the first time the function has to be invoked for a schedule created at `now`.
+    /// A one-shot schedule pointing to the past is invoked as soon as possible.
+    pub fn first_occurrence(&self, now: Timestamp) -> Result<Option<Timestamp>, String> {
+        match self {
+            InvocationScheduleSpec::Once(once) => Ok(Some(once.at)),
+            InvocationScheduleSpec::Cron(cron) => cron.occurrence_after(now),
+        }
+    }
+
+    /// Gets the time of the invocation following the one that was due at `previous`.
+    ///
+    /// Occurrences that were missed (for example because no executor was processing the schedule)
+    /// are not caught up with, the next occurrence is always in the future relative to `now`.
+    pub fn next_occurrence(
+        &self,
+        previous: Timestamp,
+        now: Timestamp,
+    ) -> Result<Option<Timestamp>, String> {
+        match self {
+            InvocationScheduleSpec::Once(_) => Ok(None),
+            InvocationScheduleSpec::Cron(cron) => cron.occurrence_after(previous.max(now)),
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::InvocationScheduleSpec>
+    for InvocationScheduleSpec
+{
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::InvocationScheduleSpec,
+    ) -> Result<Self, Self::Error> {
+        match value.spec {
+            Some(golem_api_grpc::proto::golem::worker::invocation_schedule_spec::Spec::Once(
+                at,
+            )) => Ok(InvocationScheduleSpec::Once(OnceInvocationSchedule {
+                at: at.into(),
+            })),
+            Some(golem_api_grpc::proto::golem::worker::invocation_schedule_spec::Spec::Cron(
+                expression,
+            )) => Ok(InvocationScheduleSpec::Cron(CronInvocationSchedule {
+                expression,
+            })),
+            None => Err("Missing field: spec".to_string()),
+        }
+    }
+}
+
+impl From<InvocationScheduleSpec> for golem_api_grpc::proto::golem::worker::InvocationScheduleSpec {
+    fn from(value: InvocationScheduleSpec) -> Self {
+        match value {
+            InvocationScheduleSpec::Once(once) => Self {
+                spec: Some(
+                    golem_api_grpc::proto::golem::worker::invocation_schedule_spec::Spec::Once(
+                        once.at.into(),
+                    ),
+                ),
+            },
+            InvocationScheduleSpec::Cron(cron) => Self {
+                spec: Some(
+                    golem_api_grpc::proto::golem::worker::invocation_schedule_spec::Spec::Cron(
+                        cron.expression,
+                    ),
+                ),
+            },
+        }
+    }
+}
+
+/// Invokes the function once, at the given point in time
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct OnceInvocationSchedule {
+    pub at: Timestamp,
+}
+
+/// Invokes the function at every point in time matching a cron expression
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct CronInvocationSchedule {
+    /// Either a standard five field cron expression (minute, hour, day of month, month, day of week,
+    /// where both 0 and 7 are Sunday), or one with an additional leading seconds field, in which case
+    /// the days of the week are numbered from 1 (Sunday) to 7 (Saturday). Evaluated in UTC.
+    pub expression: String,
+}
+
+impl CronInvocationSchedule {
+    fn parse(&self) -> Result<cron::Schedule, String> {
+        let fields = self.expression.split_whitespace().collect::<Vec<_>>();
+        let expression = if let [minute, hour, day_of_month, month, day_of_week] = fields[..] {
+            format!(
+                "0 {minute} {hour} {day_of_month} {month} {}",
+                Self::standard_day_of_week(day_of_week)
+            )
+        } else {
+            fields.join(" ")
+        };
+        cron::Schedule::from_str(&expression)
+            .map_err(|err| format!("Invalid cron expression '{}': {err}", self.expression))
+    }
+
+    /// Translates the day of week field of a standard cron expression, where Sunday is 0 or 7,
+    /// to the numbering of the `cron` crate, where Sunday is 1 and Saturday is 7.
+    /// Numeric ranges and steps are expanded to lists of days, names and wildcards are kept as they are.
+    fn standard_day_of_week(field: &str) -> String {
+        fn day(value: &str) -> Option<u32> {
+            value.parse::<u32>().ok().filter(|day| *day <= 7)
+        }
+
+        field
+            .split(',')
+            .map(|part| {
+                let (range, step) = match part.split_once('/') {
+                    Some((range, step)) => match step.parse::<usize>() {
+                        Ok(step) if step > 0 => (range, Some(step)),
+                        _ => return part.to_string(),
+                    },
+                    None => (part, None),
+                };
+                let bounds = match range.split_once('-') {
+                    Some((start, end)) => day(start).zip(day(end)),
+                    None => day(range).map(|start| match step {
+                        Some(_) => (start, 6),
+                        None => (start, start),
+                    }),
+                };
+                match bounds {
+                    Some((start, end)) if start <= end => (start..=end)
+                        .step_by(step.unwrap_or(1))
+                        .map(|day| (day % 7 + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(","),
+                    _ => part.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn occurrence_after(&self, after: Timestamp) -> Result<Option<Timestamp>, String> {
+        let schedule = self.parse()?;
+        let after = DateTime::<Utc>::from_timestamp_millis(after.to_millis() as i64)
+            .ok_or_else(|| format!("Timestamp out of range: {after}"))?;
+        Ok(schedule
+            .after(&after)
+            .next()
+            .map(|next| Timestamp::from(next.timestamp_millis() as u64)))
+    }
+}
+
+/// An invocation schedule of a worker, created through the API
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct InvocationSchedule {
+    pub schedule_id: InvocationScheduleId,
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    pub schedule: InvocationScheduleSpec,
+    pub created_at: Timestamp,
+    /// The time of the next invocation. Not set if the schedule has no more occurrences.
+    pub next_invocation_at: Option<Timestamp>,
+    /// The number of invocations performed so far
+    pub invocation_count: u64,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::InvocationSchedule> for InvocationSchedule {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::InvocationSchedule,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            schedule_id: value
+                .schedule_id
+                .ok_or("Missing field: schedule_id")?
+                .try_into()?,
+            worker_id: value
+                .worker_id
+                .ok_or("Missing field: worker_id")?
+                .try_into()?,
+            function_name: value.function_name,
+            schedule: value
+                .schedule
+                .ok_or("Missing field: schedule")?
+                .try_into()?,
+            created_at: value.created_at.ok_or("Missing field: created_at")?.into(),
+            next_invocation_at: value.next_invocation_at.map(|ts| ts.into()),
+            invocation_count: value.invocation_count,
+        })
+    }
+}
+
+impl From<InvocationSchedule> for golem_api_grpc::proto::golem::worker::InvocationSchedule {
+    fn from(value: InvocationSchedule) -> Self {
+        Self {
+            schedule_id: Some(value.schedule_id.into()),
+            worker_id: Some(value.worker_id.into()),
+            function_name: value.function_name,
+            schedule: Some(value.schedule.into()),
+            created_at: Some(value.created_at.into()),
+            next_invocation_at: value.next_invocation_at.map(|ts| ts.into()),
+            invocation_count: value.invocation_count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ScheduleInvocationRequest {
+    pub params: Vec<OptionallyValueAndTypeJson>,
+    pub schedule: InvocationScheduleSpec,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct InvocationSchedulesResponse {
+    pub schedules: Vec<InvocationSchedule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct CancelInvocationScheduleResponse {
+    pub canceled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CronInvocationSchedule, InvocationScheduleSpec, OnceInvocationSchedule};
+    use golem_common::model::Timestamp;
+    use std::str::FromStr;
+    use test_r::test;
+
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::from_str(s).unwrap()
+    }
+
+    fn cron(expression: &str) -> InvocationScheduleSpec {
+        InvocationScheduleSpec::Cron(CronInvocationSchedule {
+            expression: expression.to_string(),
+        })
+    }
+
+    #[test]
+    fn once_has_a_single_occurrence() {
+        let spec = InvocationScheduleSpec::Once(OnceInvocationSchedule {
+            at: ts("2025-03-01T10:00:00Z"),
+        });
+
+        assert_eq!(
+            spec.first_occurrence(ts("2025-03-01T09:00:00Z")),
+            Ok(Some(ts("2025-03-01T10:00:00Z")))
+        );
+        assert_eq!(
+            spec.next_occurrence(ts("2025-03-01T10:00:00Z"), ts("2025-03-01T10:00:01Z")),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn five_field_cron_expressions_start_at_whole_minutes() {
+        let spec = cron("*/15 * * * *");
+
+        assert_eq!(
+            spec.first_occurrence(ts("2025-03-01T10:07:31Z")),
+            Ok(Some(ts("2025-03-01T10:15:00Z")))
+        );
+        assert_eq!(
+            spec.next_occurrence(ts("2025-03-01T10:15:00Z"), ts("2025-03-01T10:15:01Z")),
+            Ok(Some(ts("2025-03-01T10:30:00Z")))
+        );
+    }
+
+    #[test]
+    fn six_field_cron_expressions_include_seconds() {
+        let spec = cron("30 0 12 * * *");
+
+        assert_eq!(
+            spec.first_occurrence(ts("2025-03-01T12:00:30Z")),
+            Ok(Some(ts("2025-03-02T12:00:30Z")))
+        );
+    }
+
+    #[test]
+    fn missed_cron_occurrences_are_skipped() {
+        let spec = cron("0 * * * *");
+
+        assert_eq!(
+            spec.next_occurrence(ts("2025-03-01T10:00:00Z"), ts("2025-03-01T15:20:00Z")),
+            Ok(Some(ts("2025-03-01T16:00:00Z")))
+        );
+    }
+
+    #[test]
+    fn five_field_cron_expressions_use_standard_days_of_week() {
+        // 2025-03-01 is a Saturday
+        let now = ts("2025-03-01T10:00:00Z");
+
+        assert_eq!(
+            cron("0 9 * * 1-5").first_occurrence(now),
+            Ok(Some(ts("2025-03-03T09:00:00Z")))
+        );
+        assert_eq!(
+            cron("0 9 * * 0").first_occurrence(now),
+            Ok(Some(ts("2025-03-02T09:00:00Z")))
+        );
+        assert_eq!(
+            cron("0 9 * * 7").first_occurrence(now),
+            Ok(Some(ts("2025-03-02T09:00:00Z")))
+        );
+        assert_eq!(
+            cron("0 9 * * 2,4").first_occurrence(now),
+            Ok(Some(ts("2025-03-04T09:00:00Z")))
+        );
+        assert_eq!(
+            cron("0 9 * * 1-5/2").first_occurrence(now),
+            Ok(Some(ts("2025-03-03T09:00:00Z")))
+        );
+        assert_eq!(
+            cron("0 9 * * 1-5/2").next_occurrence(ts("2025-03-03T09:00:00Z"), now),
+            Ok(Some(ts("2025-03-05T09:00:00Z")))
+        );
+        assert_eq!(
+            cron("0 9 * * MON").first_occurrence(now),
+            Ok(Some(ts("2025-03-03T09:00:00Z")))
+        );
+    }
+
+    #[test]
+    fn six_field_cron_expressions_use_the_days_of_week_of_the_cron_crate() {
+        assert_eq!(
+            cron("0 0 9 * * 1").first_occurrence(ts("2025-03-01T10:00:00Z")),
+            Ok(Some(ts("2025-03-02T09:00:00Z")))
+        );
+    }
+
+    #[test]
+    fn invalid_cron_expression_is_rejected() {
+        assert!(cron("every day")
+            .first_occurrence(ts("2025-03-01T10:00:00Z"))
+            .is_err());
+    }
+}
@@ -13,6 +13,13 @@
 // limitations under the License.
 
 pub mod auth;
+pub mod bulk_operation;
+pub mod invocation_result;
+pub mod invocation_schedule;
+pub mod pending_invocation;
+pub mod pending_promise;
+pub mod saga;
+pub mod worker_ttl;
 
 use applying::Apply;
 use bincode::{Decode, Encode};
@@ -101,6 +108,42 @@ pub struct DeactivatePluginResponse {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct RevertWorkerResponse {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ForkWorkerRequest {
+    /// Name of the new worker, created in the same component as the source worker
+    pub target_worker_name: String,
+    /// The last oplog index of the source worker to be copied to the new worker.
+    /// If not set, the whole oplog is copied.
+    pub oplog_index_cutoff: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ForkWorkerResponse {
+    pub target_worker_id: WorkerId,
+    pub oplog_index_cutoff: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct UpdateWorkerLabelsRequest {
+    /// Labels to add or overwrite
+    #[oai(default)]
+    #[serde(default)]
+    pub set: HashMap<String, String>,
+    /// Keys of labels to remove, applied after `set`
+    #[oai(default)]
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
+pub struct UpdateWorkerLabelsResponse {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize, Object)]
 pub struct CancelInvocationResponse {
     pub canceled: bool,
@@ -495,6 +538,7 @@ impl From<Component> for golem_api_grpc::proto::golem::component::Component {
 pub struct ResourceLimits {
     pub available_fuel: i64,
     pub max_memory_per_worker: i64,
+    pub available_storage: i64,
 }
 
 impl From<ResourceLimits> for golem_api_grpc::proto::golem::common::ResourceLimits {
@@ -502,6 +546,7 @@ impl From<ResourceLimits> for golem_api_grpc::proto::golem::common::ResourceLimi
         Self {
             available_fuel: value.available_fuel,
             max_memory_per_worker: value.max_memory_per_worker,
+            available_storage: value.available_storage,
         }
     }
 }
@@ -511,6 +556,7 @@ impl From<golem_api_grpc::proto::golem::common::ResourceLimits> for ResourceLimi
         Self {
             available_fuel: value.available_fuel,
             max_memory_per_worker: value.max_memory_per_worker,
+            available_storage: value.available_storage,
         }
     }
 }
@@ -0,0 +1,115 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_common::model::oplog::InvocationPriority;
+use golem_common::model::{
+    ComponentVersion, IdempotencyKey, Timestamp, TimestampedWorkerInvocation, WorkerInvocation,
+};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// An invocation waiting in the invocation queue of a worker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PendingInvocation {
+    /// Idempotency key of an exported function invocation, not set for manual update requests
+    pub idempotency_key: Option<IdempotencyKey>,
+    /// Name of the invoked function, not set for manual update requests
+    pub function_name: Option<String>,
+    /// Target component version of a manual update request
+    pub target_version: Option<ComponentVersion>,
+    pub enqueued_at: Timestamp,
+    pub priority: InvocationPriority,
+}
+
+impl PendingInvocation {
+    pub fn new(invocation: TimestampedWorkerInvocation, priority: InvocationPriority) -> Self {
+        match invocation.invocation {
+            WorkerInvocation::ExportedFunction {
+                idempotency_key,
+                full_function_name,
+                ..
+            } => Self {
+                idempotency_key: Some(idempotency_key),
+                function_name: Some(full_function_name),
+                target_version: None,
+                enqueued_at: invocation.timestamp,
+                priority,
+            },
+            WorkerInvocation::ManualUpdate { target_version } => Self {
+                idempotency_key: None,
+                function_name: None,
+                target_version: Some(target_version),
+                enqueued_at: invocation.timestamp,
+                priority,
+            },
+        }
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::PendingInvocation> for PendingInvocation {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::PendingInvocation,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            priority: value.priority().into(),
+            idempotency_key: value.idempotency_key.map(|key| key.into()),
+            function_name: value.function_name,
+            target_version: value.target_version,
+            enqueued_at: value
+                .enqueued_at
+                .ok_or("Missing field: enqueued_at")?
+                .into(),
+        })
+    }
+}
+
+impl From<PendingInvocation> for golem_api_grpc::proto::golem::worker::PendingInvocation {
+    fn from(value: PendingInvocation) -> Self {
+        Self {
+            idempotency_key: value.idempotency_key.map(|key| key.into()),
+            function_name: value.function_name,
+            target_version: value.target_version,
+            enqueued_at: Some(value.enqueued_at.into()),
+            priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(value.priority)
+                as i32,
+        }
+    }
+}
+
+/// Pending invocations of a worker, in the order they are going to be processed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+pub struct PendingInvocationsResponse {
+    pub invocations: Vec<PendingInvocation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ChangeInvocationPriorityRequest {
+    pub priority: InvocationPriority,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ChangeInvocationPriorityResponse {
+    /// False if the invocation has already started, so its priority could not be changed
+    pub changed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct PurgePendingInvocationsResponse {
+    /// Idempotency keys of the canceled invocations
+    pub canceled: Vec<IdempotencyKey>,
+}
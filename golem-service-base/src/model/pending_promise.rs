@@ -0,0 +1,56 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use golem_common::model::{PromiseId, Timestamp};
+use poem_openapi::Object;
+use serde::{Deserialize, Serialize};
+
+/// A promise created by a worker that has not been completed or deleted yet
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct PendingPromise {
+    pub promise_id: PromiseId,
+    pub created_at: Timestamp,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::PendingPromise> for PendingPromise {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::PendingPromise,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            promise_id: value
+                .promise_id
+                .ok_or("Missing field: promise_id")?
+                .try_into()?,
+            created_at: value.created_at.ok_or("Missing field: created_at")?.into(),
+        })
+    }
+}
+
+impl From<PendingPromise> for golem_api_grpc::proto::golem::worker::PendingPromise {
+    fn from(value: PendingPromise) -> Self {
+        Self {
+            promise_id: Some(value.promise_id.into()),
+            created_at: Some(value.created_at.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct PendingPromisesResponse {
+    pub promises: Vec<PendingPromise>,
+}
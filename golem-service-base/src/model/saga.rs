@@ -0,0 +1,286 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::{SagaId, Timestamp, WorkerId};
+use golem_wasm_rpc::ValueAndType;
+use golem_wasm_rpc_derive::IntoValue;
+use poem_openapi::{Enum, Object};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Enum)]
+pub enum SagaStatus {
+    /// The coordinator worker is still performing the steps of the saga
+    Running,
+    /// The saga finished successfully, its compensations are never performed
+    Completed,
+    /// The saga has been aborted and the executor is performing its compensations
+    Compensating,
+    /// All the compensations of the aborted saga have been performed
+    Compensated,
+    /// One of the compensations failed. Aborting the saga again retries it
+    CompensationFailed,
+}
+
+impl From<golem_api_grpc::proto::golem::worker::SagaStatus> for SagaStatus {
+    fn from(value: golem_api_grpc::proto::golem::worker::SagaStatus) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::worker::SagaStatus::Running => SagaStatus::Running,
+            golem_api_grpc::proto::golem::worker::SagaStatus::Completed => SagaStatus::Completed,
+            golem_api_grpc::proto::golem::worker::SagaStatus::Compensating => {
+                SagaStatus::Compensating
+            }
+            golem_api_grpc::proto::golem::worker::SagaStatus::Compensated => {
+                SagaStatus::Compensated
+            }
+            golem_api_grpc::proto::golem::worker::SagaStatus::CompensationFailed => {
+                SagaStatus::CompensationFailed
+            }
+        }
+    }
+}
+
+impl From<SagaStatus> for golem_api_grpc::proto::golem::worker::SagaStatus {
+    fn from(value: SagaStatus) -> Self {
+        match value {
+            SagaStatus::Running => Self::Running,
+            SagaStatus::Completed => Self::Completed,
+            SagaStatus::Compensating => Self::Compensating,
+            SagaStatus::Compensated => Self::Compensated,
+            SagaStatus::CompensationFailed => Self::CompensationFailed,
+        }
+    }
+}
+
+/// A compensating call registered for a step of a saga, invoked if the saga gets aborted
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize, Object, IntoValue)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SagaCompensation {
+    /// The worker the compensating function is invoked on
+    pub worker_id: WorkerId,
+    pub function_name: String,
+    pub function_params: Vec<ValueAndType>,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::SagaCompensation> for SagaCompensation {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::SagaCompensation,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            worker_id: value
+                .worker_id
+                .ok_or("Missing field: worker_id")?
+                .try_into()?,
+            function_name: value.function_name,
+            function_params: value
+                .function_params
+                .into_iter()
+                .map(|param| param.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<SagaCompensation> for golem_api_grpc::proto::golem::worker::SagaCompensation {
+    fn from(value: SagaCompensation) -> Self {
+        Self {
+            worker_id: Some(value.worker_id.into()),
+            function_name: value.function_name,
+            function_params: value
+                .function_params
+                .into_iter()
+                .map(|param| param.into())
+                .collect(),
+        }
+    }
+}
+
+/// A saga coordinated by a worker. The compensation plan is tracked by the executor instead of the
+/// coordinator's memory, so the compensations can be performed even if the coordinator worker gets
+/// reverted or fails.
+#[derive(Debug, Clone, PartialEq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct Saga {
+    pub saga_id: SagaId,
+    pub coordinator: WorkerId,
+    pub status: SagaStatus,
+    pub started_at: Timestamp,
+    /// Oplog index of the coordinator worker where the saga was started
+    pub oplog_index: OplogIndex,
+    /// The registered compensations, in the order of the steps they belong to
+    pub compensations: Vec<SagaCompensation>,
+    /// Number of compensations already performed, starting from the last registered one
+    pub compensated: u32,
+    pub abort_reason: Option<String>,
+    /// Error of the last failed compensation
+    pub error: Option<String>,
+}
+
+impl Saga {
+    pub fn new(
+        saga_id: SagaId,
+        coordinator: WorkerId,
+        started_at: Timestamp,
+        oplog_index: OplogIndex,
+    ) -> Self {
+        Self {
+            saga_id,
+            coordinator,
+            status: SagaStatus::Running,
+            started_at,
+            oplog_index,
+            compensations: Vec::new(),
+            compensated: 0,
+            abort_reason: None,
+            error: None,
+        }
+    }
+
+    /// The compensations not performed yet, together with their step index, in the order they
+    /// have to be performed
+    pub fn remaining_compensations(&self) -> impl Iterator<Item = (usize, &SagaCompensation)> {
+        let remaining = self
+            .compensations
+            .len()
+            .saturating_sub(self.compensated as usize);
+        self.compensations[..remaining].iter().enumerate().rev()
+    }
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::Saga> for Saga {
+    type Error = String;
+
+    fn try_from(value: golem_api_grpc::proto::golem::worker::Saga) -> Result<Self, Self::Error> {
+        Ok(Self {
+            status: value.status().into(),
+            saga_id: value.saga_id.ok_or("Missing field: saga_id")?.try_into()?,
+            coordinator: value
+                .coordinator
+                .ok_or("Missing field: coordinator")?
+                .try_into()?,
+            started_at: value.started_at.ok_or("Missing field: started_at")?.into(),
+            oplog_index: OplogIndex::from_u64(value.oplog_index),
+            compensations: value
+                .compensations
+                .into_iter()
+                .map(|compensation| compensation.try_into())
+                .collect::<Result<Vec<_>, _>>()?,
+            compensated: value.compensated,
+            abort_reason: value.abort_reason,
+            error: value.error,
+        })
+    }
+}
+
+impl From<Saga> for golem_api_grpc::proto::golem::worker::Saga {
+    fn from(value: Saga) -> Self {
+        let status: golem_api_grpc::proto::golem::worker::SagaStatus = value.status.into();
+        Self {
+            saga_id: Some(value.saga_id.into()),
+            coordinator: Some(value.coordinator.into()),
+            status: status as i32,
+            started_at: Some(value.started_at.into()),
+            oplog_index: value.oplog_index.into(),
+            compensations: value
+                .compensations
+                .into_iter()
+                .map(|compensation| compensation.into())
+                .collect(),
+            compensated: value.compensated,
+            abort_reason: value.abort_reason,
+            error: value.error,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+pub struct SagasResponse {
+    pub sagas: Vec<Saga>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct AbortSagaRequest {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Object)]
+pub struct AbortSagaResponse {
+    /// The state of the saga after aborting it
+    pub saga: Saga,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Saga, SagaCompensation};
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::{ComponentId, SagaId, Timestamp, WorkerId};
+    use test_r::test;
+
+    fn compensation(function_name: &str) -> SagaCompensation {
+        SagaCompensation {
+            worker_id: WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "w1".to_string(),
+            },
+            function_name: function_name.to_string(),
+            function_params: vec![],
+        }
+    }
+
+    #[test]
+    fn compensations_are_performed_in_reverse_order() {
+        let mut saga = Saga::new(
+            SagaId::new_v4(),
+            WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "coordinator".to_string(),
+            },
+            Timestamp::now_utc(),
+            OplogIndex::from_u64(10),
+        );
+        saga.compensations = vec![
+            compensation("api.{undo-a}"),
+            compensation("api.{undo-b}"),
+            compensation("api.{undo-c}"),
+        ];
+
+        let remaining = |saga: &Saga| {
+            saga.remaining_compensations()
+                .map(|(idx, compensation)| (idx, compensation.function_name.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            remaining(&saga),
+            vec![
+                (2, "api.{undo-c}".to_string()),
+                (1, "api.{undo-b}".to_string()),
+                (0, "api.{undo-a}".to_string()),
+            ]
+        );
+
+        saga.compensated = 2;
+        assert_eq!(remaining(&saga), vec![(0, "api.{undo-a}".to_string())]);
+
+        saga.compensated = 3;
+        assert!(remaining(&saga).is_empty());
+    }
+}
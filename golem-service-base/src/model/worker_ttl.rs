@@ -0,0 +1,306 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bincode::{Decode, Encode};
+use golem_common::model::{Timestamp, WorkerId, WorkerStatus};
+use poem_openapi::{Enum, Object};
+use serde::{Deserialize, Serialize};
+
+/// Time-to-live policy of workers. Workers which are idle or have exited are deleted together with
+/// their oplog once they violate any of the limits. Workers that are running or failed are never deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct WorkerTtlPolicy {
+    /// Deletes the worker once it has been idle (or exited) for this many milliseconds
+    pub max_idle_time_millis: Option<u64>,
+    /// Deletes the worker once it is older than this many milliseconds
+    pub max_age_millis: Option<u64>,
+    /// Deletes the worker as soon as it exits
+    pub delete_on_exit: bool,
+}
+
+impl WorkerTtlPolicy {
+    /// Gets when a worker in the given status has to be deleted, and why. If more than one limit
+    /// applies, the earliest one wins.
+    ///
+    /// `last_activity` is the time the worker last did anything, which for idle and exited workers
+    /// is the time they entered their current status.
+    pub fn expiration(
+        &self,
+        status: &WorkerStatus,
+        created_at: Timestamp,
+        last_activity: Timestamp,
+    ) -> Option<(Timestamp, WorkerTtlDeletionReason)> {
+        match status {
+            WorkerStatus::Exited if self.delete_on_exit => {
+                Some((last_activity, WorkerTtlDeletionReason::Exited))
+            }
+            WorkerStatus::Idle | WorkerStatus::Exited => {
+                let idle_expiration = self.max_idle_time_millis.map(|max_idle_time| {
+                    (
+                        Self::add_millis(last_activity, max_idle_time),
+                        WorkerTtlDeletionReason::MaxIdleTime,
+                    )
+                });
+                let age_expiration = self.max_age_millis.map(|max_age| {
+                    (
+                        Self::add_millis(created_at, max_age),
+                        WorkerTtlDeletionReason::MaxAge,
+                    )
+                });
+                idle_expiration
+                    .into_iter()
+                    .chain(age_expiration)
+                    .min_by_key(|(at, _)| *at)
+            }
+            _ => None,
+        }
+    }
+
+    fn add_millis(timestamp: Timestamp, millis: u64) -> Timestamp {
+        Timestamp::from(timestamp.to_millis().saturating_add(millis))
+    }
+}
+
+impl From<golem_api_grpc::proto::golem::worker::WorkerTtlPolicy> for WorkerTtlPolicy {
+    fn from(value: golem_api_grpc::proto::golem::worker::WorkerTtlPolicy) -> Self {
+        Self {
+            max_idle_time_millis: value.max_idle_time_millis,
+            max_age_millis: value.max_age_millis,
+            delete_on_exit: value.delete_on_exit,
+        }
+    }
+}
+
+impl From<WorkerTtlPolicy> for golem_api_grpc::proto::golem::worker::WorkerTtlPolicy {
+    fn from(value: WorkerTtlPolicy) -> Self {
+        Self {
+            max_idle_time_millis: value.max_idle_time_millis,
+            max_age_millis: value.max_age_millis,
+            delete_on_exit: value.delete_on_exit,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Enum)]
+pub enum WorkerTtlDeletionReason {
+    MaxIdleTime,
+    MaxAge,
+    Exited,
+}
+
+impl From<golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason>
+    for WorkerTtlDeletionReason
+{
+    fn from(value: golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason) -> Self {
+        match value {
+            golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason::MaxIdleTime => {
+                WorkerTtlDeletionReason::MaxIdleTime
+            }
+            golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason::MaxAge => {
+                WorkerTtlDeletionReason::MaxAge
+            }
+            golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason::Exited => {
+                WorkerTtlDeletionReason::Exited
+            }
+        }
+    }
+}
+
+impl From<WorkerTtlDeletionReason>
+    for golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason
+{
+    fn from(value: WorkerTtlDeletionReason) -> Self {
+        match value {
+            WorkerTtlDeletionReason::MaxIdleTime => Self::MaxIdleTime,
+            WorkerTtlDeletionReason::MaxAge => Self::MaxAge,
+            WorkerTtlDeletionReason::Exited => Self::Exited,
+        }
+    }
+}
+
+/// An entry of the audit log of workers deleted because of their time-to-live policy
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct WorkerTtlDeletion {
+    pub worker_id: WorkerId,
+    pub reason: WorkerTtlDeletionReason,
+    /// The policy the worker was deleted by
+    pub policy: WorkerTtlPolicy,
+    pub worker_created_at: Timestamp,
+    pub deleted_at: Timestamp,
+}
+
+impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerTtlDeletion> for WorkerTtlDeletion {
+    type Error = String;
+
+    fn try_from(
+        value: golem_api_grpc::proto::golem::worker::WorkerTtlDeletion,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            reason: value.reason().into(),
+            worker_id: value
+                .worker_id
+                .ok_or("Missing field: worker_id")?
+                .try_into()?,
+            policy: value.policy.ok_or("Missing field: policy")?.into(),
+            worker_created_at: value
+                .worker_created_at
+                .ok_or("Missing field: worker_created_at")?
+                .into(),
+            deleted_at: value.deleted_at.ok_or("Missing field: deleted_at")?.into(),
+        })
+    }
+}
+
+impl From<WorkerTtlDeletion> for golem_api_grpc::proto::golem::worker::WorkerTtlDeletion {
+    fn from(value: WorkerTtlDeletion) -> Self {
+        let reason: golem_api_grpc::proto::golem::worker::WorkerTtlDeletionReason =
+            value.reason.into();
+        Self {
+            worker_id: Some(value.worker_id.into()),
+            reason: reason as i32,
+            policy: Some(value.policy.into()),
+            worker_created_at: Some(value.worker_created_at.into()),
+            deleted_at: Some(value.deleted_at.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct WorkerTtlPolicyResponse {
+    /// Not set if there is no policy defined
+    pub policy: Option<WorkerTtlPolicy>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct WorkerTtlDeletionsResponse {
+    pub deletions: Vec<WorkerTtlDeletion>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WorkerTtlDeletionReason, WorkerTtlPolicy};
+    use golem_common::model::{Timestamp, WorkerStatus};
+    use std::str::FromStr;
+    use test_r::test;
+
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::from_str(s).unwrap()
+    }
+
+    const HOUR: u64 = 60 * 60 * 1000;
+
+    #[test]
+    fn running_and_failed_workers_never_expire() {
+        let policy = WorkerTtlPolicy {
+            max_idle_time_millis: Some(HOUR),
+            max_age_millis: Some(HOUR),
+            delete_on_exit: true,
+        };
+
+        for status in [WorkerStatus::Running, WorkerStatus::Failed] {
+            assert_eq!(
+                policy.expiration(
+                    &status,
+                    ts("2025-03-01T10:00:00Z"),
+                    ts("2025-03-01T10:00:00Z")
+                ),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn idle_workers_expire_at_the_earliest_limit() {
+        let policy = WorkerTtlPolicy {
+            max_idle_time_millis: Some(HOUR),
+            max_age_millis: Some(24 * HOUR),
+            delete_on_exit: false,
+        };
+
+        assert_eq!(
+            policy.expiration(
+                &WorkerStatus::Idle,
+                ts("2025-03-01T10:00:00Z"),
+                ts("2025-03-01T12:30:00Z")
+            ),
+            Some((
+                ts("2025-03-01T13:30:00Z"),
+                WorkerTtlDeletionReason::MaxIdleTime
+            ))
+        );
+        assert_eq!(
+            policy.expiration(
+                &WorkerStatus::Idle,
+                ts("2025-03-01T10:00:00Z"),
+                ts("2025-03-02T09:30:00Z")
+            ),
+            Some((ts("2025-03-02T10:00:00Z"), WorkerTtlDeletionReason::MaxAge))
+        );
+    }
+
+    #[test]
+    fn exited_workers_are_deleted_immediately_if_requested() {
+        let policy = WorkerTtlPolicy {
+            max_idle_time_millis: Some(HOUR),
+            max_age_millis: None,
+            delete_on_exit: true,
+        };
+
+        assert_eq!(
+            policy.expiration(
+                &WorkerStatus::Exited,
+                ts("2025-03-01T10:00:00Z"),
+                ts("2025-03-01T12:30:00Z")
+            ),
+            Some((ts("2025-03-01T12:30:00Z"), WorkerTtlDeletionReason::Exited))
+        );
+        assert_eq!(
+            WorkerTtlPolicy {
+                delete_on_exit: false,
+                ..policy
+            }
+            .expiration(
+                &WorkerStatus::Exited,
+                ts("2025-03-01T10:00:00Z"),
+                ts("2025-03-01T12:30:00Z")
+            ),
+            Some((
+                ts("2025-03-01T13:30:00Z"),
+                WorkerTtlDeletionReason::MaxIdleTime
+            ))
+        );
+    }
+
+    #[test]
+    fn empty_policy_never_expires() {
+        let policy = WorkerTtlPolicy {
+            max_idle_time_millis: None,
+            max_age_millis: None,
+            delete_on_exit: false,
+        };
+
+        assert_eq!(
+            policy.expiration(
+                &WorkerStatus::Exited,
+                ts("2025-03-01T10:00:00Z"),
+                ts("2025-03-01T12:30:00Z")
+            ),
+            None
+        );
+    }
+}
@@ -101,6 +101,14 @@ impl FileSystemBlobStorage {
                 result.push("plugin_wasm_files");
                 result.push(account_id.to_string());
             }
+            BlobStorageNamespace::WorkerFiles {
+                project_id,
+                worker_id,
+            } => {
+                result.push("worker_files");
+                result.push(project_id.to_string());
+                result.push(worker_id.to_string());
+            }
         }
 
         result.push(path);
@@ -372,6 +372,10 @@ pub enum BlobStorageNamespace {
     PluginWasmFiles {
         account_id: AccountId,
     },
+    WorkerFiles {
+        project_id: ProjectId,
+        worker_id: WorkerId,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
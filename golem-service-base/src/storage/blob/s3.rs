@@ -84,6 +84,7 @@ impl S3BlobStorage {
             }
             BlobStorageNamespace::Components { .. } => &self.config.components_bucket,
             BlobStorageNamespace::PluginWasmFiles { .. } => &self.config.plugin_wasm_files_bucket,
+            BlobStorageNamespace::WorkerFiles { .. } => &self.config.worker_files_bucket,
         }
     }
 
@@ -105,6 +106,10 @@ impl S3BlobStorage {
             BlobStorageNamespace::OplogPayload {
                 project_id,
                 worker_id,
+            }
+            | BlobStorageNamespace::WorkerFiles {
+                project_id,
+                worker_id,
             } => {
                 let project_id_string = project_id.to_string();
                 let worker_id_string = worker_id.to_string();
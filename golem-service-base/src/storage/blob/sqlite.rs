@@ -78,6 +78,10 @@ impl SqliteBlobStorage {
             BlobStorageNamespace::PluginWasmFiles { account_id } => {
                 format!("plugin_wasm_files-{account_id}")
             }
+            BlobStorageNamespace::WorkerFiles {
+                project_id,
+                worker_id,
+            } => format!("worker_files-{}-{}", project_id, worker_id.worker_name),
         }
     }
 
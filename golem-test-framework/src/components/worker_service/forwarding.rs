@@ -34,7 +34,8 @@ use golem_api_grpc::proto::golem::worker::v1::{
     LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse,
     ListFileSystemNodeResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
     RevertWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    UpdateWorkerLabelsRequest, UpdateWorkerLabelsResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse, WorkerError,
 };
 use golem_api_grpc::proto::golem::worker::{
     IdempotencyKey, InvocationContext, InvokeResult, InvokeResultTyped, LogEvent, TargetWorkerId,
@@ -135,10 +136,12 @@ impl WorkerService for ForwardingWorkerService {
                     args: request.args.clone(),
                     env: request.env.clone(),
                     wasi_config_vars: request.wasi_config_vars.clone(),
+                    labels: request.labels.clone(),
                     account_id: Some(account_id.into()),
                     account_limits: Some(ResourceLimits {
                         available_fuel: i64::MAX,
                         max_memory_per_worker: i64::MAX,
+                        available_storage: i64::MAX,
                     }),
                 })
                 .await;
@@ -379,8 +382,10 @@ impl WorkerService for ForwardingWorkerService {
                     account_limits: Some(ResourceLimits {
                         available_fuel: i64::MAX,
                         max_memory_per_worker: i64::MAX,
+                        available_storage: i64::MAX,
                     }),
                     context: context.clone(),
+                    priority: worker::InvocationPriority::Normal as i32,
                 })
                 .await;
 
@@ -452,8 +457,10 @@ impl WorkerService for ForwardingWorkerService {
                     account_limits: Some(ResourceLimits {
                         available_fuel: i64::MAX,
                         max_memory_per_worker: i64::MAX,
+                        available_storage: i64::MAX,
                     }),
                     context: context.clone(),
+                    priority: worker::InvocationPriority::Normal as i32,
                 })
                 .await;
 
@@ -523,8 +530,10 @@ impl WorkerService for ForwardingWorkerService {
                     account_limits: Some(ResourceLimits {
                         available_fuel: i64::MAX,
                         max_memory_per_worker: i64::MAX,
+                        available_storage: i64::MAX,
                     }),
                     context: context.clone(),
+                    priority: worker::InvocationPriority::Normal as i32,
                 })
                 .await;
 
@@ -593,6 +602,7 @@ impl WorkerService for ForwardingWorkerService {
                     account_limits: Some(ResourceLimits {
                         available_fuel: i64::MAX,
                         max_memory_per_worker: i64::MAX,
+                        available_storage: i64::MAX,
                     }),
                 })
                 .await;
@@ -895,6 +905,7 @@ impl WorkerService for ForwardingWorkerService {
                 account_limits: Some(ResourceLimits {
                     available_fuel: i64::MAX,
                     max_memory_per_worker: i64::MAX,
+                    available_storage: i64::MAX,
                 }),
                 path: request.path,
             })
@@ -954,6 +965,7 @@ impl WorkerService for ForwardingWorkerService {
                 account_limits: Some(ResourceLimits {
                     available_fuel: i64::MAX,
                     max_memory_per_worker: i64::MAX,
+                    available_storage: i64::MAX,
                 }),
                 file_path: request.file_path,
             })
@@ -1082,6 +1094,51 @@ impl WorkerService for ForwardingWorkerService {
         }
     }
 
+    async fn update_worker_labels(
+        &self,
+        token: &Uuid,
+        request: UpdateWorkerLabelsRequest,
+    ) -> crate::Result<UpdateWorkerLabelsResponse> {
+        let account_id = self.cloud_service.get_account_id(token).await?;
+        let project_id = self.cloud_service.get_default_project(token).await?;
+        let result = self
+            .worker_executor
+            .client()
+            .await?
+            .update_worker_labels(workerexecutor::v1::UpdateWorkerLabelsRequest {
+                worker_id: request.worker_id.clone(),
+                account_id: Some(account_id.into()),
+                project_id: Some(project_id.clone().into()),
+                set: request.set,
+                remove: request.remove,
+            })
+            .await;
+
+        let result = result?.into_inner();
+
+        match result.result {
+            None => Err(anyhow!(
+                "No response from golem-worker-executor update-worker-labels call"
+            )),
+            Some(workerexecutor::v1::update_worker_labels_response::Result::Success(empty)) => {
+                Ok(UpdateWorkerLabelsResponse {
+                    result: Some(worker::v1::update_worker_labels_response::Result::Success(
+                        empty,
+                    )),
+                })
+            }
+            Some(workerexecutor::v1::update_worker_labels_response::Result::Failure(error)) => {
+                Ok(UpdateWorkerLabelsResponse {
+                    result: Some(worker::v1::update_worker_labels_response::Result::Error(
+                        WorkerError {
+                            error: Some(worker::v1::worker_error::Error::InternalError(error)),
+                        },
+                    )),
+                })
+            }
+        }
+    }
+
     async fn cancel_invocation(
         &self,
         token: &Uuid,
@@ -40,18 +40,20 @@ use golem_api_grpc::proto::golem::worker::v1::{
     get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response,
     invoke_and_await_response, invoke_and_await_typed_response, invoke_response,
     launch_new_worker_response, resume_worker_response, revert_worker_response,
-    search_oplog_response, update_worker_response, CancelInvocationRequest,
-    CancelInvocationResponse, ConnectWorkerRequest, DeleteWorkerRequest, DeleteWorkerResponse,
-    ForkWorkerRequest, ForkWorkerResponse, GetFileContentsRequest, GetFileSystemNodeRequest,
-    GetFileSystemNodeResponse, GetOplogRequest, GetOplogResponse, GetOplogSuccessResponse,
-    GetWorkerMetadataRequest, GetWorkerMetadataResponse, GetWorkersMetadataRequest,
-    GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse, InterruptWorkerRequest,
-    InterruptWorkerResponse, InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse,
-    InvokeAndAwaitRequest, InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest,
-    InvokeRequest, InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
-    LaunchNewWorkerSuccessResponse, ListFileSystemNodeResponse, ResumeWorkerRequest,
-    ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest,
-    SearchOplogResponse, SearchOplogSuccessResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    search_oplog_response, update_worker_labels_response, update_worker_response,
+    CancelInvocationRequest, CancelInvocationResponse, ConnectWorkerRequest, DeleteWorkerRequest,
+    DeleteWorkerResponse, ForkWorkerRequest, ForkWorkerResponse, GetFileContentsRequest,
+    GetFileSystemNodeRequest, GetFileSystemNodeResponse, GetOplogRequest, GetOplogResponse,
+    GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
+    GetWorkersMetadataRequest, GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse,
+    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
+    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse,
+    InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse,
+    LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse,
+    ListFileSystemNodeResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
+    RevertWorkerResponse, SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse,
+    UpdateWorkerLabelsRequest, UpdateWorkerLabelsResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse,
 };
 use golem_api_grpc::proto::golem::worker::worker_filter::Filter;
 use golem_api_grpc::proto::golem::worker::{
@@ -59,8 +61,8 @@ use golem_api_grpc::proto::golem::worker::{
     FileFileSystemNode, FileSystemNode, IdempotencyKey, InvocationContext, InvokeParameters,
     InvokeResult, InvokeResultTyped, LogEvent, OplogCursor, OplogEntry, OplogEntryWithIndex,
     PendingUpdate, SuccessfulUpdate, TargetWorkerId, UpdateMode, UpdateRecord,
-    WorkerCreatedAtFilter, WorkerEnvFilter, WorkerMetadata, WorkerNameFilter, WorkerStatusFilter,
-    WorkerVersionFilter, WorkerWasiConfigVarsFilter,
+    WorkerCreatedAtFilter, WorkerEnvFilter, WorkerLabelFilter, WorkerMetadata, WorkerNameFilter,
+    WorkerStatusFilter, WorkerVersionFilter, WorkerWasiConfigVarsFilter,
 };
 use golem_client::api::ApiDefinitionClient as ApiDefinitionServiceHttpClient;
 use golem_client::api::ApiDefinitionClientLive as ApiDefinitionServiceHttpClientLive;
@@ -176,6 +178,7 @@ pub trait WorkerService: Send + Sync {
                                 .wasi_config_vars
                                 .expect("no wasi_config_vars field")
                                 .into(),
+                            labels: request.labels,
                         },
                     )
                     .await?;
@@ -350,6 +353,7 @@ pub trait WorkerService: Send + Sync {
                         &worker_id.name.unwrap(),
                         idempotency_key.map(|key| key.value).as_deref(),
                         &function,
+                        None,
                         &invoke_parameters_to_http(invoke_parameters),
                     )
                     .await?;
@@ -388,6 +392,7 @@ pub trait WorkerService: Send + Sync {
                         &request.worker_id.unwrap().name.unwrap(),
                         request.idempotency_key.map(|key| key.value).as_deref(),
                         &request.function,
+                        None,
                         &invoke_json_parameters_to_http(request.invoke_parameters),
                     )
                     .await?;
@@ -432,6 +437,7 @@ pub trait WorkerService: Send + Sync {
                         &worker_id.name.unwrap(),
                         idempotency_key.map(|key| key.value).as_deref(),
                         &function,
+                        None,
                         &invoke_parameters_to_http(invoke_parameters),
                     )
                     .await?;
@@ -481,6 +487,7 @@ pub trait WorkerService: Send + Sync {
                         &worker_id.name.unwrap(),
                         idempotency_key.map(|key| key.value).as_deref(),
                         &function,
+                        None,
                         &invoke_parameters_to_http(invoke_parameters),
                     )
                     .await?;
@@ -523,6 +530,7 @@ pub trait WorkerService: Send + Sync {
                         &request.worker_id.unwrap().name.unwrap(),
                         request.idempotency_key.map(|key| key.value).as_deref(),
                         &request.function,
+                        None,
                         &invoke_json_parameters_to_http(request.invoke_parameters),
                     )
                     .await?;
@@ -979,6 +987,46 @@ pub trait WorkerService: Send + Sync {
         }
     }
 
+    async fn update_worker_labels(
+        &self,
+        token: &Uuid,
+        request: UpdateWorkerLabelsRequest,
+    ) -> crate::Result<UpdateWorkerLabelsResponse> {
+        match self.client_protocol() {
+            GolemClientProtocol::Grpc => {
+                let mut client = self.worker_grpc_client().await;
+                let request = authorised_request(request, token);
+                Ok(client.update_worker_labels(request).await?.into_inner())
+            }
+            GolemClientProtocol::Http => {
+                let client = self.worker_http_client(token).await;
+
+                client
+                    .update_worker_labels(
+                        &request
+                            .worker_id
+                            .as_ref()
+                            .unwrap()
+                            .component_id
+                            .unwrap()
+                            .value
+                            .unwrap()
+                            .into(),
+                        &request.worker_id.unwrap().name,
+                        &golem_client::model::UpdateWorkerLabelsRequest {
+                            set: request.set,
+                            remove: request.remove,
+                        },
+                    )
+                    .await?;
+
+                Ok(UpdateWorkerLabelsResponse {
+                    result: Some(update_worker_labels_response::Result::Success(Empty {})),
+                })
+            }
+        }
+    }
+
     async fn cancel_invocation(
         &self,
         token: &Uuid,
@@ -1549,6 +1597,8 @@ fn http_worker_metadata_to_grpc(
             .into_iter()
             .map(|region| region.into())
             .collect(),
+        file_system_usage: worker_metadata.file_system_usage,
+        labels: worker_metadata.labels,
     }
 }
 
@@ -1627,6 +1677,18 @@ fn grpc_filter_to_http_filter(filter: Filter) -> Vec<String> {
                     value
                 )]
             }
+            Filter::Label(WorkerLabelFilter {
+                name,
+                comparator,
+                value,
+            }) => {
+                vec![format!(
+                    "label.{} {} {}",
+                    name,
+                    grpc_string_filter_comparator_to_http(comparator),
+                    value
+                )]
+            }
             Filter::And(and_filter) => {
                 if !allow_and {
                     panic!("'And' filters are only supported on the root level on the HTTP API")
@@ -378,6 +378,27 @@ pub fn debug_render_oplog_entry(entry: &PublicOplogEntry) -> String {
             let _ = writeln!(result, "{pad}agent type:        {}", &params.key.agent_type);
             let _ = writeln!(result, "{pad}agent id:          {}", &params.key.agent_id);
         }
+        PublicOplogEntry::SetLabel(params) => {
+            let _ = writeln!(result, "SET LABEL");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(result, "{pad}key:               {}", &params.key);
+            let _ = writeln!(result, "{pad}value:             {}", &params.value);
+        }
+        PublicOplogEntry::RemoveLabel(params) => {
+            let _ = writeln!(result, "REMOVE LABEL");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(result, "{pad}key:               {}", &params.key);
+        }
+        PublicOplogEntry::ChangePendingInvocationPriority(params) => {
+            let _ = writeln!(result, "CHANGE PENDING INVOCATION PRIORITY");
+            let _ = writeln!(result, "{pad}at:                {}", &params.timestamp);
+            let _ = writeln!(
+                result,
+                "{pad}idempotency key:   {}",
+                &params.idempotency_key,
+            );
+            let _ = writeln!(result, "{pad}priority:          {}", &params.priority);
+        }
     }
 
     result
@@ -29,13 +29,14 @@ use golem_api_grpc::proto::golem::worker::v1::{
     get_oplog_response, get_worker_metadata_response, get_workers_metadata_response,
     interrupt_worker_response, invoke_and_await_json_response, invoke_and_await_response,
     invoke_and_await_typed_response, invoke_response, launch_new_worker_response,
-    resume_worker_response, revert_worker_response, search_oplog_response, update_worker_response,
-    worker_execution_error, CancelInvocationRequest, ConnectWorkerRequest, DeleteWorkerRequest,
-    ForkWorkerRequest, ForkWorkerResponse, GetFileContentsRequest, GetFileSystemNodeRequest,
-    GetOplogRequest, GetWorkerMetadataRequest, GetWorkersMetadataRequest,
-    GetWorkersMetadataSuccessResponse, InterruptWorkerRequest, InterruptWorkerResponse,
-    InvokeAndAwaitJsonRequest, LaunchNewWorkerRequest, ResumeWorkerRequest, RevertWorkerRequest,
-    SearchOplogRequest, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
+    resume_worker_response, revert_worker_response, search_oplog_response,
+    update_worker_labels_response, update_worker_response, worker_execution_error,
+    CancelInvocationRequest, ConnectWorkerRequest, DeleteWorkerRequest, ForkWorkerRequest,
+    ForkWorkerResponse, GetFileContentsRequest, GetFileSystemNodeRequest, GetOplogRequest,
+    GetWorkerMetadataRequest, GetWorkersMetadataRequest, GetWorkersMetadataSuccessResponse,
+    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
+    LaunchNewWorkerRequest, ResumeWorkerRequest, RevertWorkerRequest, SearchOplogRequest,
+    UpdateWorkerLabelsRequest, UpdateWorkerRequest, UpdateWorkerResponse, WorkerError,
     WorkerExecutionError,
 };
 use golem_api_grpc::proto::golem::worker::{log_event, LogEvent, StdErrLog, StdOutLog, UpdateMode};
@@ -43,6 +44,7 @@ use golem_client::model::Account;
 use golem_common::model::component_metadata::{
     ComponentMetadata, DynamicLinkedInstance, RawComponentMetadata,
 };
+use golem_common::model::http_fixture::HttpFixture;
 use golem_common::model::oplog::{
     OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerResourceId,
 };
@@ -437,6 +439,7 @@ pub trait TestDsl {
     ) -> crate::Result<Vec<PublicOplogEntryWithIndex>>;
 
     async fn check_oplog_is_queryable(&self, worker_id: &WorkerId) -> crate::Result<()>;
+    async fn get_http_fixture(&self, worker_id: &WorkerId) -> crate::Result<HttpFixture>;
 
     async fn get_file_system_node(
         &self,
@@ -477,6 +480,13 @@ pub trait TestDsl {
 
     async fn revert(&self, worker_id: &WorkerId, target: RevertWorkerTarget) -> crate::Result<()>;
 
+    async fn update_worker_labels(
+        &self,
+        worker_id: &WorkerId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+    ) -> crate::Result<()>;
+
     async fn cancel_invocation(
         &self,
         worker_id: &WorkerId,
@@ -786,6 +796,7 @@ impl<Deps: TestDependencies> TestDsl for TestDependenciesDsl<Deps> {
                     args,
                     env,
                     wasi_config_vars: Some(BTreeMap::from_iter(wasi_config_vars).into()),
+                    labels: HashMap::new(),
                 },
             )
             .await?;
@@ -1612,6 +1623,12 @@ impl<Deps: TestDependencies> TestDsl for TestDependenciesDsl<Deps> {
         Ok(())
     }
 
+    async fn get_http_fixture(&self, worker_id: &WorkerId) -> crate::Result<HttpFixture> {
+        let oplog = TestDsl::get_oplog(self, worker_id, OplogIndex::INITIAL).await?;
+        HttpFixture::from_oplog(oplog.iter().map(|entry| &entry.entry))
+            .map_err(|err| anyhow!("Failed to extract HTTP fixture: {err}"))
+    }
+
     async fn get_file_system_node(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -1755,6 +1772,34 @@ impl<Deps: TestDependencies> TestDsl for TestDependenciesDsl<Deps> {
         }
     }
 
+    async fn update_worker_labels(
+        &self,
+        worker_id: &WorkerId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+    ) -> crate::Result<()> {
+        let response = self
+            .deps
+            .worker_service()
+            .update_worker_labels(
+                &self.token,
+                UpdateWorkerLabelsRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    set,
+                    remove,
+                },
+            )
+            .await?;
+
+        match response.result {
+            Some(update_worker_labels_response::Result::Success(_)) => Ok(()),
+            Some(update_worker_labels_response::Result::Error(error)) => {
+                Err(anyhow!("Failed to update worker labels: {error:?}"))
+            }
+            _ => Err(anyhow!("Failed to update worker labels: unknown error")),
+        }
+    }
+
     async fn cancel_invocation(
         &self,
         worker_id: &WorkerId,
@@ -2006,6 +2051,9 @@ pub fn worker_error_message(error: &Error) -> String {
                 worker_execution_error::Error::InvocationFailed(_) => {
                     "Invocation failed".to_string()
                 }
+                worker_execution_error::Error::InvocationNotFound(error) => {
+                    format!("Invocation not found: {:?}", error.idempotency_key)
+                }
             },
         },
     }
@@ -2185,6 +2233,8 @@ pub fn to_worker_metadata(
                 ),
                 deleted_regions: DeletedRegions::new(),
                 component_version_for_replay: metadata.component_version,
+                labels: metadata.labels.clone().into_iter().collect(),
+                invocation_priorities: HashMap::new(), // not passed through gRPC
             },
             parent: None,
         },
@@ -2365,6 +2415,7 @@ pub trait TestDslUnsafe {
     ) -> Vec<PublicOplogEntryWithIndex>;
 
     async fn check_oplog_is_queryable(&self, worker_id: &WorkerId);
+    async fn get_http_fixture(&self, worker_id: &WorkerId) -> HttpFixture;
 
     async fn get_file_system_node(
         &self,
@@ -2399,6 +2450,13 @@ pub trait TestDslUnsafe {
 
     async fn revert(&self, worker_id: &WorkerId, target: RevertWorkerTarget);
 
+    async fn update_worker_labels(
+        &self,
+        worker_id: &WorkerId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+    );
+
     async fn cancel_invocation(&self, worker_id: &WorkerId, idempotency_key: &IdempotencyKey);
     async fn try_cancel_invocation(
         &self,
@@ -2739,6 +2797,12 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .expect("Oplog check failed")
     }
 
+    async fn get_http_fixture(&self, worker_id: &WorkerId) -> HttpFixture {
+        <T as TestDsl>::get_http_fixture(self, worker_id)
+            .await
+            .expect("Failed to get HTTP fixture")
+    }
+
     async fn get_file_system_node(
         &self,
         worker_id: impl Into<TargetWorkerId> + Send + Sync,
@@ -2829,6 +2893,17 @@ impl<T: TestDsl + Sync> TestDslUnsafe for T {
             .expect("Failed to revert worker")
     }
 
+    async fn update_worker_labels(
+        &self,
+        worker_id: &WorkerId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+    ) {
+        <T as TestDsl>::update_worker_labels(self, worker_id, set, remove)
+            .await
+            .expect("Failed to update worker labels")
+    }
+
     async fn cancel_invocation(&self, worker_id: &WorkerId, idempotency_key: &IdempotencyKey) {
         <T as TestDsl>::cancel_invocation(self, worker_id, idempotency_key)
             .await
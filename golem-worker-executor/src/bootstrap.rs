@@ -26,7 +26,9 @@ use crate::services::oplog::OplogService;
 use crate::services::plugins::{Plugins, PluginsObservations};
 use crate::services::projects::ProjectService;
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc};
+use crate::services::saga::SagaService;
 use crate::services::scheduler::SchedulerService;
 use crate::services::shard::ShardService;
 use crate::services::shard_manager::ShardManagerService;
@@ -98,6 +100,8 @@ impl Bootstrap<Context> for ServerBootstrap {
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         golem_config: Arc<GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -133,6 +137,8 @@ impl Bootstrap<Context> for ServerBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             key_value_service.clone(),
@@ -165,6 +171,8 @@ impl Bootstrap<Context> for ServerBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             shard_manager_service.clone(),
@@ -195,6 +203,8 @@ impl Bootstrap<Context> for ServerBootstrap {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config.clone(),
             shard_service,
             key_value_service,
@@ -220,6 +230,8 @@ impl Bootstrap<Context> for ServerBootstrap {
         golem_api_1_x::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_api_1_x::oplog::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_api_1_x::context::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem_api_1_x::pubsub::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem_api_1_x::saga::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_durability::durability::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_agent::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem_rpc_0_2_x::types::add_to_linker_get_host(
@@ -14,3 +14,4 @@
 
 pub mod preopens;
 pub mod types;
+pub mod usage;
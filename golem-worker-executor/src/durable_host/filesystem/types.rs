@@ -54,7 +54,9 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
     ) -> Result<Resource<OutputStream>, FsError> {
         self.fail_if_read_only(&fd)?;
         self.observe_function_call("filesystem::types::descriptor", "write_via_stream");
-        HostDescriptor::write_via_stream(&mut self.as_wasi_view(), fd, offset)
+        let stream = HostDescriptor::write_via_stream(&mut self.as_wasi_view(), fd, offset)?;
+        self.register_file_output_stream(stream.rep());
+        Ok(stream)
     }
 
     fn append_via_stream(
@@ -62,7 +64,9 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
         self_: Resource<Descriptor>,
     ) -> Result<Resource<OutputStream>, FsError> {
         self.observe_function_call("filesystem::types::descriptor", "append_via_stream");
-        HostDescriptor::append_via_stream(&mut self.as_wasi_view(), self_)
+        let stream = HostDescriptor::append_via_stream(&mut self.as_wasi_view(), self_)?;
+        self.register_file_output_stream(stream.rep());
+        Ok(stream)
     }
 
     async fn advise(
@@ -105,6 +109,19 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
 
         self.observe_function_call("filesystem::types::descriptor", "set_size");
 
+        let current_size =
+            HostDescriptor::stat(&mut self.as_wasi_view(), Resource::new_borrow(fd.rep()))
+                .await?
+                .size;
+        if size > current_size
+            && !self
+                .reserve_file_system_space(size - current_size)
+                .await
+                .map_err(FsError::trap)?
+        {
+            return Err(ErrorCode::InsufficientSpace.into());
+        }
+
         HostDescriptor::set_size(&mut self.as_wasi_view(), fd, size).await
     }
 
@@ -146,6 +163,15 @@ impl<Ctx: WorkerCtx> HostDescriptor for DurableWorkerCtx<Ctx> {
         self.fail_if_read_only(&fd)?;
 
         self.observe_function_call("filesystem::types::descriptor", "write");
+
+        if !self
+            .reserve_file_system_space(buffer.len() as u64)
+            .await
+            .map_err(FsError::trap)?
+        {
+            return Err(ErrorCode::InsufficientSpace.into());
+        }
+
         HostDescriptor::write(&mut self.as_wasi_view(), fd, buffer, offset).await
     }
 
@@ -482,6 +508,10 @@ impl<Ctx: WorkerCtx> HostDirectoryEntryStream for DurableWorkerCtx<Ctx> {
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     fn filesystem_error_code(&mut self, err: Resource<Error>) -> anyhow::Result<Option<ErrorCode>> {
+        // Writes to file output streams exceeding the file system quotas fail with the error code itself
+        if let Some(error_code) = self.table().get(&err)?.downcast_ref::<ErrorCode>() {
+            return Ok(Some(*error_code));
+        }
         Host::filesystem_error_code(&mut self.as_wasi_view(), err)
     }
 
@@ -0,0 +1,433 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::resource_limits::ResourceLimits;
+use golem_common::model::AccountId;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::debug;
+
+/// Tracks the size of a worker's file system, enforcing the per-worker and per-account quotas.
+///
+/// Every write is assumed to grow the file system by its full length, so the tracked size is an
+/// upper estimate. It gets corrected by measuring the actual size of the file system whenever the
+/// estimate would exceed one of the quotas. Read-only initial files are shared between workers,
+/// so they are not counted.
+///
+/// The files in the worker's latest archive keep taking up storage after the worker gets unloaded,
+/// so they stay counted for the account until the worker is deleted.
+pub struct FileSystemUsage {
+    account_id: AccountId,
+    resource_limits: Arc<dyn ResourceLimits>,
+    max_size: u64,
+    root: PathBuf,
+    measured: u64,
+    unmeasured: u64,
+    archived: u64,
+    current: Arc<AtomicU64>,
+}
+
+impl FileSystemUsage {
+    pub fn new(
+        account_id: AccountId,
+        resource_limits: Arc<dyn ResourceLimits>,
+        max_size: u64,
+        root: PathBuf,
+        archived: u64,
+        current: Arc<AtomicU64>,
+    ) -> Self {
+        // The archived files are already counted for the account
+        Self {
+            account_id,
+            resource_limits,
+            max_size,
+            root,
+            measured: archived,
+            unmeasured: 0,
+            archived,
+            current,
+        }
+    }
+
+    /// The current estimated size of the file system
+    pub fn current(&self) -> u64 {
+        self.measured + self.unmeasured
+    }
+
+    /// Records the size of the worker's latest archive, which remains counted when the file system
+    /// gets dropped
+    pub fn set_archived(&mut self, archived: u64) {
+        self.archived = archived;
+    }
+
+    /// Accounts for writing `amount` bytes to the file system.
+    ///
+    /// Returns false if the write would exceed the worker's or the account's quota. If `enforce`
+    /// is false, the write is always accounted for and allowed.
+    pub async fn reserve(
+        &mut self,
+        amount: u64,
+        enforce: bool,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<bool, WorkerExecutorError> {
+        if !enforce {
+            self.resource_limits
+                .update_storage_usage(&self.account_id, amount as i64);
+        } else {
+            if self.current() + amount > self.max_size {
+                self.measure(read_only_paths).await?;
+                if self.current() + amount > self.max_size {
+                    debug!(
+                        "File system quota of worker exceeded: {} + {} > {}",
+                        self.current(),
+                        amount,
+                        self.max_size
+                    );
+                    return Ok(false);
+                }
+            }
+
+            if !self
+                .resource_limits
+                .reserve_storage(&self.account_id, amount)
+                .await?
+            {
+                // The estimate may include overwritten regions, giving them back before retrying
+                self.measure(read_only_paths).await?;
+                if !self
+                    .resource_limits
+                    .reserve_storage(&self.account_id, amount)
+                    .await?
+                {
+                    debug!(
+                        "Storage quota of account {} exceeded when writing {} bytes",
+                        self.account_id, amount
+                    );
+                    return Ok(false);
+                }
+            }
+        }
+
+        self.unmeasured += amount;
+        self.current.store(self.current(), Ordering::Relaxed);
+        Ok(true)
+    }
+
+    /// Measures the actual size of the file system and corrects the storage usage recorded for the account
+    pub async fn measure(
+        &mut self,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<(), WorkerExecutorError> {
+        let size = measure_directory(&self.root, read_only_paths)
+            .await
+            .map_err(|err| {
+                WorkerExecutorError::runtime(format!(
+                    "Failed to measure the size of the worker's file system: {err}"
+                ))
+            })?;
+
+        let delta = size as i64 - self.current() as i64;
+        if delta != 0 {
+            self.resource_limits
+                .update_storage_usage(&self.account_id, delta);
+        }
+        self.measured = size;
+        self.unmeasured = 0;
+        self.current.store(size, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+impl Drop for FileSystemUsage {
+    fn drop(&mut self) {
+        // The file system of the worker is deleted together with its context, but its latest
+        // archive is kept until the worker gets deleted
+        let released = self.current() as i64 - self.archived as i64;
+        if released != 0 {
+            self.resource_limits
+                .update_storage_usage(&self.account_id, -released);
+        }
+        self.current.store(self.archived, Ordering::Relaxed);
+    }
+}
+
+async fn measure_directory(
+    root: &Path,
+    read_only_paths: &HashSet<PathBuf>,
+) -> Result<u64, std::io::Error> {
+    let mut size = 0;
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut read_dir = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = read_dir.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() && !read_only_paths.contains(&entry.path()) {
+                size += entry.metadata().await?.len();
+            }
+        }
+    }
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileSystemUsage;
+    use crate::model::CurrentResourceLimits;
+    use crate::services::resource_limits::{ResourceLimits, ResourceLimitsGrpc};
+    use assert2::check;
+    use async_trait::async_trait;
+    use golem_common::model::{AccountId, RetryConfig};
+    use golem_service_base::error::worker_executor::WorkerExecutorError;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use test_r::test;
+    use uuid::Uuid;
+
+    struct StorageLimits {
+        available: Mutex<i64>,
+    }
+
+    impl StorageLimits {
+        fn new(available: i64) -> Arc<Self> {
+            Arc::new(Self {
+                available: Mutex::new(available),
+            })
+        }
+
+        fn available(&self) -> i64 {
+            *self.available.lock().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl ResourceLimits for StorageLimits {
+        async fn borrow_fuel(
+            &self,
+            _account_id: &AccountId,
+            amount: i64,
+        ) -> Result<i64, WorkerExecutorError> {
+            Ok(amount)
+        }
+
+        fn borrow_fuel_sync(&self, _account_id: &AccountId, amount: i64) -> Option<i64> {
+            Some(amount)
+        }
+
+        async fn return_fuel(
+            &self,
+            _account_id: &AccountId,
+            _remaining: i64,
+        ) -> Result<(), WorkerExecutorError> {
+            Ok(())
+        }
+
+        async fn update_last_known_limits(
+            &self,
+            _account_id: &AccountId,
+            _last_known_limits: &CurrentResourceLimits,
+        ) -> Result<(), WorkerExecutorError> {
+            Ok(())
+        }
+
+        async fn get_max_memory(
+            &self,
+            _account_id: &AccountId,
+        ) -> Result<usize, WorkerExecutorError> {
+            Ok(usize::MAX)
+        }
+
+        async fn reserve_storage(
+            &self,
+            _account_id: &AccountId,
+            amount: u64,
+        ) -> Result<bool, WorkerExecutorError> {
+            let mut available = self.available.lock().unwrap();
+            if *available >= amount as i64 {
+                *available -= amount as i64;
+                Ok(true)
+            } else {
+                Ok(false)
+            }
+        }
+
+        fn update_storage_usage(&self, _account_id: &AccountId, delta: i64) {
+            *self.available.lock().unwrap() -= delta;
+        }
+    }
+
+    fn account_id() -> AccountId {
+        AccountId {
+            value: "account".to_string(),
+        }
+    }
+
+    #[test]
+    async fn worker_quota_is_enforced_on_the_measured_size() {
+        let root = tempfile::tempdir().unwrap();
+        let limits = StorageLimits::new(i64::MAX);
+        let current = Arc::new(AtomicU64::new(0));
+        let mut usage = FileSystemUsage::new(
+            account_id(),
+            limits.clone(),
+            100,
+            root.path().to_path_buf(),
+            0,
+            current.clone(),
+        );
+
+        check!(usage.reserve(60, true, &HashSet::new()).await.unwrap());
+        std::fs::write(root.path().join("file"), [0u8; 60]).unwrap();
+
+        // Overwriting the same bytes only fits after measuring the actual size
+        check!(usage.reserve(30, true, &HashSet::new()).await.unwrap());
+        check!(current.load(Ordering::Relaxed) == 90);
+        check!(usage.reserve(30, true, &HashSet::new()).await.unwrap());
+        check!(current.load(Ordering::Relaxed) == 90);
+
+        check!(!usage.reserve(50, true, &HashSet::new()).await.unwrap());
+    }
+
+    #[test]
+    async fn account_quota_is_enforced_and_released() {
+        let root = tempfile::tempdir().unwrap();
+        let limits = StorageLimits::new(100);
+        let current = Arc::new(AtomicU64::new(0));
+        {
+            let mut usage = FileSystemUsage::new(
+                account_id(),
+                limits.clone(),
+                u64::MAX,
+                root.path().to_path_buf(),
+                0,
+                current.clone(),
+            );
+
+            check!(usage.reserve(80, true, &HashSet::new()).await.unwrap());
+            std::fs::write(root.path().join("file"), [0u8; 80]).unwrap();
+            check!(!usage.reserve(40, true, &HashSet::new()).await.unwrap());
+            check!(limits.available() == 20);
+
+            // Replayed writes are never rejected
+            check!(usage.reserve(40, false, &HashSet::new()).await.unwrap());
+            check!(limits.available() == -20);
+        }
+        check!(limits.available() == 100);
+        check!(current.load(Ordering::Relaxed) == 0);
+    }
+
+    #[test]
+    async fn archived_files_stay_counted_for_the_account() {
+        let root = tempfile::tempdir().unwrap();
+        let limits = StorageLimits::new(100);
+        let current = Arc::new(AtomicU64::new(0));
+        {
+            let mut usage = FileSystemUsage::new(
+                account_id(),
+                limits.clone(),
+                u64::MAX,
+                root.path().to_path_buf(),
+                0,
+                current.clone(),
+            );
+
+            check!(usage.reserve(80, true, &HashSet::new()).await.unwrap());
+            std::fs::write(root.path().join("file"), [0u8; 60]).unwrap();
+            usage.measure(&HashSet::new()).await.unwrap();
+            usage.set_archived(60);
+        }
+        check!(limits.available() == 40);
+        check!(current.load(Ordering::Relaxed) == 60);
+
+        // Loading the worker again does not count the restored files twice
+        let mut usage = FileSystemUsage::new(
+            account_id(),
+            limits.clone(),
+            u64::MAX,
+            root.path().to_path_buf(),
+            60,
+            current.clone(),
+        );
+        usage.measure(&HashSet::new()).await.unwrap();
+        check!(limits.available() == 40);
+        check!(usage.current() == 60);
+    }
+
+    #[test]
+    async fn read_only_files_are_not_counted() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("ro"), [0u8; 50]).unwrap();
+        std::fs::create_dir(root.path().join("dir")).unwrap();
+        std::fs::write(root.path().join("dir").join("rw"), [0u8; 10]).unwrap();
+        let read_only_paths = HashSet::from([root.path().join("ro")]);
+
+        let mut usage = FileSystemUsage::new(
+            account_id(),
+            StorageLimits::new(i64::MAX),
+            u64::MAX,
+            root.path().to_path_buf(),
+            0,
+            Arc::new(AtomicU64::new(0)),
+        );
+        usage.measure(&read_only_paths).await.unwrap();
+
+        check!(usage.current() == 10);
+    }
+
+    #[test]
+    async fn usage_recorded_before_the_limits_are_cached_is_kept() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("file"), [0u8; 50]).unwrap();
+        let limits = ResourceLimitsGrpc::new(
+            "http://localhost:9090".parse().unwrap(),
+            Uuid::new_v4(),
+            RetryConfig::default(),
+            Duration::from_secs(3600),
+        );
+        {
+            // Loading a worker measures its file system before anything fetched the account's limits
+            let mut usage = FileSystemUsage::new(
+                account_id(),
+                limits.clone(),
+                u64::MAX,
+                root.path().to_path_buf(),
+                0,
+                Arc::new(AtomicU64::new(0)),
+            );
+            usage.measure(&HashSet::new()).await.unwrap();
+            limits
+                .update_last_known_limits(
+                    &account_id(),
+                    &CurrentResourceLimits {
+                        fuel: 0,
+                        max_memory: 0,
+                        available_storage: 100,
+                    },
+                )
+                .await
+                .unwrap();
+            check!(limits.cached_available_storage(&account_id()) == Some(50));
+
+            check!(usage.reserve(20, true, &HashSet::new()).await.unwrap());
+            check!(limits.cached_available_storage(&account_id()) == Some(30));
+        }
+        check!(limits.cached_available_storage(&account_id()) == Some(100));
+    }
+}
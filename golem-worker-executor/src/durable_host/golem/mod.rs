@@ -14,4 +14,6 @@
 
 pub mod agent;
 pub mod invocation_context_api;
+pub mod pubsub;
+pub mod saga;
 pub mod v1x;
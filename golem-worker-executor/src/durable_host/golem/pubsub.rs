@@ -0,0 +1,228 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::preview2::golem_api_1_x::pubsub::Host;
+use crate::services::pubsub::delivery_idempotency_key;
+use crate::services::rpc::RpcError;
+use crate::workerctx::{InvocationManagement, WorkerCtx};
+use anyhow::anyhow;
+use golem_common::model::oplog::DurableFunctionType;
+use golem_common::model::{IdempotencyKey, OwnedWorkerId};
+use golem_wasm_ast::analysis::analysed_type;
+use golem_wasm_rpc::{Value, WitValue};
+use tracing::warn;
+use uuid::Uuid;
+use wasmtime_wasi::p2::bindings::cli::environment::Host as EnvironmentHost;
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Checks that the handler function is exported by the worker's component and accepts the topic,
+    /// the message id and the payload of the delivered messages
+    async fn validate_topic_handler(&self, handler_function_name: &str) -> Result<(), String> {
+        let function = self
+            .state
+            .component_metadata
+            .metadata
+            .find_function(handler_function_name)
+            .await?
+            .ok_or_else(|| format!("Function {handler_function_name} is not exported"))?;
+
+        let parameter_types = function
+            .analysed_export
+            .parameters
+            .iter()
+            .map(|parameter| parameter.typ.clone())
+            .collect::<Vec<_>>();
+        let expected_parameter_types = vec![
+            analysed_type::str(),
+            analysed_type::str(),
+            analysed_type::list(analysed_type::u8()),
+        ];
+
+        if parameter_types != expected_parameter_types || function.analysed_export.result.is_some()
+        {
+            Err(format!(
+                "Function {handler_function_name} must have the signature func(topic: string, message-id: string, payload: list<u8>)"
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn subscribe(
+        &mut self,
+        topic: String,
+        handler_function_name: String,
+    ) -> anyhow::Result<Result<(), String>> {
+        if let Err(err) = self.validate_topic_handler(&handler_function_name).await {
+            return Ok(Err(err));
+        }
+
+        let durability = Durability::<(), SerializableError>::new(
+            self,
+            "golem::api::pubsub",
+            "subscribe",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let result = self
+                .state
+                .pubsub_service
+                .subscribe(&self.owned_worker_id, &topic, &handler_function_name)
+                .await;
+            durability
+                .persist(self, (topic, handler_function_name), result)
+                .await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        Ok(Ok(()))
+    }
+
+    async fn unsubscribe(&mut self, topic: String) -> anyhow::Result<()> {
+        let durability = Durability::<(), SerializableError>::new(
+            self,
+            "golem::api::pubsub",
+            "unsubscribe",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let result = self
+                .state
+                .pubsub_service
+                .unsubscribe(&self.owned_worker_id, &topic)
+                .await;
+            durability.persist(self, topic, result).await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        Ok(())
+    }
+
+    async fn publish(&mut self, topic: String, payload: Vec<u8>) -> anyhow::Result<u32> {
+        // The message id is derived from the current invocation and oplog index and persisted, so when
+        // the publish gets retried after a failure the deliveries get the same idempotency keys as before
+        let durability = Durability::<(u64, u64), SerializableError>::new(
+            self,
+            "golem::api::pubsub",
+            "publish message id",
+            DurableFunctionType::ReadLocal,
+        )
+        .await?;
+        let uuid = if durability.is_live() {
+            let current_idempotency_key = match self.get_current_idempotency_key().await {
+                Some(key) => key,
+                None => IdempotencyKey::new(self.owned_worker_id.worker_id.to_string()),
+            };
+            let oplog_index = self.state.current_oplog_index().await;
+            let key = IdempotencyKey::derived(&current_idempotency_key, oplog_index);
+            let uuid = Uuid::parse_str(&key.value.to_string())?; // this is guaranteed to be a uuid
+            durability
+                .persist_serializable(self, (), Ok(uuid.as_u64_pair()))
+                .await?;
+            uuid
+        } else {
+            let (high_bits, low_bits) =
+                durability.replay::<(u64, u64), anyhow::Error>(self).await?;
+            Uuid::from_u64_pair(high_bits, low_bits)
+        };
+        let message_id = IdempotencyKey::from_uuid(uuid);
+
+        let durability = Durability::<u32, SerializableError>::new(
+            self,
+            "golem::api::pubsub",
+            "publish",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let args = self.get_arguments().await?;
+            let env = self.get_environment().await?;
+            let wasi_config_vars = self.wasi_config_vars();
+
+            let subscribers = self
+                .state
+                .pubsub_service
+                .get_subscribers(&self.owned_worker_id.project_id, &topic)
+                .await?;
+
+            let mut enqueued = 0;
+            for subscriber in subscribers {
+                let target_worker_id =
+                    OwnedWorkerId::new(&self.owned_worker_id.project_id, &subscriber.worker_id);
+                let params: Vec<WitValue> = vec![
+                    Value::String(topic.clone()).into(),
+                    Value::String(message_id.value.clone()).into(),
+                    Value::List(payload.iter().map(|byte| Value::U8(*byte)).collect()).into(),
+                ];
+                let stack = self
+                    .state
+                    .invocation_context
+                    .clone_as_inherited_stack(&self.state.current_span_id);
+
+                let result = self
+                    .rpc()
+                    .invoke(
+                        &target_worker_id,
+                        Some(delivery_idempotency_key(&message_id, &subscriber.worker_id)),
+                        subscriber.handler_function_name.clone(),
+                        params,
+                        self.created_by(),
+                        self.worker_id(),
+                        &args,
+                        &env,
+                        wasi_config_vars.clone(),
+                        stack,
+                    )
+                    .await;
+
+                match result {
+                    Ok(()) => enqueued += 1,
+                    Err(RpcError::NotFound { details }) | Err(RpcError::Denied { details }) => {
+                        // The subscriber cannot receive messages anymore, retrying would not help
+                        warn!(
+                            "Skipping delivery of message {message_id} on topic {topic} to {}: {details}",
+                            subscriber.worker_id
+                        );
+                    }
+                    Err(err) => {
+                        // Failing without persisting the result makes the worker retry the whole
+                        // publish, and the idempotency keys prevent duplicate deliveries
+                        return Err(anyhow!(
+                            "Failed to deliver message {message_id} on topic {topic} to {}: {err}",
+                            subscriber.worker_id
+                        ));
+                    }
+                }
+            }
+
+            durability
+                .persist_serializable(self, (topic, message_id), Ok(enqueued))
+                .await?;
+            Ok(enqueued)
+        } else {
+            durability.replay::<u32, anyhow::Error>(self).await
+        }
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::preview2::golem_api_1_x::saga::{Host, SagaId as BindingSagaId};
+use crate::services::saga::start_compensation;
+use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::DurableFunctionType;
+use golem_common::model::{SagaId, WorkerId};
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::saga::SagaCompensation;
+use golem_wasm_rpc::golem_rpc_0_2_x::types::{ValueAndType, WorkerId as BindingWorkerId};
+use uuid::Uuid;
+
+impl From<BindingSagaId> for SagaId {
+    fn from(value: BindingSagaId) -> Self {
+        SagaId(value.uuid.into())
+    }
+}
+
+impl From<SagaId> for BindingSagaId {
+    fn from(value: SagaId) -> Self {
+        BindingSagaId {
+            uuid: value.0.into(),
+        }
+    }
+}
+
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn begin_saga(&mut self) -> anyhow::Result<BindingSagaId> {
+        let durability = Durability::<Uuid, SerializableError>::new(
+            self,
+            "golem::api::saga",
+            "begin",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        // The saga is registered by the oplog entry of this call, the saga service collects the
+        // sagas of the worker from its oplog
+        let saga_id = if durability.is_live() {
+            let result = Ok::<Uuid, WorkerExecutorError>(Uuid::new_v4());
+            durability.persist(self, (), result).await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        Ok(SagaId(saga_id).into())
+    }
+
+    async fn register_compensation(
+        &mut self,
+        saga_id: BindingSagaId,
+        worker_id: BindingWorkerId,
+        function_name: String,
+        function_params: Vec<ValueAndType>,
+    ) -> anyhow::Result<Result<(), String>> {
+        let saga_id: SagaId = saga_id.into();
+        let compensation = SagaCompensation {
+            worker_id: WorkerId::from(worker_id),
+            function_name,
+            function_params: function_params
+                .into_iter()
+                .map(|param| param.into())
+                .collect(),
+        };
+
+        let durability = Durability::<Result<(), String>, SerializableError>::new(
+            self,
+            "golem::api::saga",
+            "register_compensation",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let saga_service = self.state.saga_service.clone();
+            let _lock = saga_service.lock(&self.owned_worker_id, &saga_id).await;
+            let result = saga_service
+                .ensure_not_aborted(&self.owned_worker_id, &saga_id)
+                .await
+                .map_err(|err| err.to_string());
+            durability
+                .persist_serializable(self, (saga_id.0, compensation), Ok(result.clone()))
+                .await?;
+            Ok(result)
+        } else {
+            durability.replay(self).await
+        }
+    }
+
+    async fn complete_saga(
+        &mut self,
+        saga_id: BindingSagaId,
+    ) -> anyhow::Result<Result<(), String>> {
+        let saga_id: SagaId = saga_id.into();
+
+        let durability = Durability::<Result<(), String>, SerializableError>::new(
+            self,
+            "golem::api::saga",
+            "complete",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let saga_service = self.state.saga_service.clone();
+            let _lock = saga_service.lock(&self.owned_worker_id, &saga_id).await;
+            let result = saga_service
+                .ensure_not_aborted(&self.owned_worker_id, &saga_id)
+                .await
+                .map_err(|err| err.to_string());
+            durability
+                .persist_serializable(self, saga_id.0, Ok(result.clone()))
+                .await?;
+            Ok(result)
+        } else {
+            durability.replay(self).await
+        }
+    }
+
+    async fn abort_saga(
+        &mut self,
+        saga_id: BindingSagaId,
+        reason: String,
+    ) -> anyhow::Result<Result<(), String>> {
+        let saga_id: SagaId = saga_id.into();
+
+        let durability = Durability::<Result<(), String>, SerializableError>::new(
+            self,
+            "golem::api::saga",
+            "abort",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let result = self
+                .state
+                .saga_service
+                .abort(&self.owned_worker_id, &saga_id, &reason)
+                .await
+                .map(|_| ())
+                .map_err(|err| err.to_string());
+            durability
+                .persist_serializable(self, (saga_id.0, reason), Ok(result.clone()))
+                .await?;
+            if result.is_ok() {
+                // Compensation runs in the background, so the coordinator can go on (or even finish)
+                // while the compensating invocations are performed
+                let retry_config = self
+                    .state
+                    .overridden_retry_policy
+                    .clone()
+                    .unwrap_or_else(|| self.state.config.retry.clone());
+                start_compensation(
+                    self.state.saga_service.clone(),
+                    self.rpc(),
+                    self.owned_worker_id.clone(),
+                    self.created_by().clone(),
+                    retry_config,
+                    saga_id,
+                );
+            }
+            Ok(result)
+        } else {
+            durability.replay(self).await
+        }
+    }
+}
@@ -20,12 +20,13 @@ use crate::model::public_oplog::{
 };
 use crate::preview2::golem_api_1_x;
 use crate::preview2::golem_api_1_x::host::{
-    ForkResult, GetWorkers, Host, HostGetWorkers, WorkerAnyFilter,
+    AwaitPromiseResult, ForkResult, GetWorkers, Host, HostGetWorkers, WorkerAnyFilter,
 };
 use crate::preview2::golem_api_1_x::oplog::{
     Host as OplogHost, HostGetOplog, HostSearchOplog, SearchOplog,
 };
 use crate::services::oplog::CommitLevel;
+use crate::services::promise::{promise_deadline, TimedAwait};
 use crate::services::{HasOplogService, HasPlugins, HasProjectService, HasWorker};
 use crate::workerctx::{InvocationManagement, StatusManagement, WorkerCtx};
 use anyhow::anyhow;
@@ -33,10 +34,11 @@ use bincode::de::Decoder;
 use bincode::enc::Encoder;
 use bincode::error::{DecodeError, EncodeError};
 use bincode::Decode;
+use chrono::{DateTime, Utc};
 use golem_common::model::oplog::{DurableFunctionType, OplogEntry};
 use golem_common::model::regions::OplogRegion;
 use golem_common::model::{ComponentId, ComponentVersion, OwnedWorkerId, ScanCursor, WorkerId};
-use golem_common::model::{IdempotencyKey, OplogIndex, PromiseId, RetryConfig};
+use golem_common::model::{IdempotencyKey, OplogIndex, PromiseId, RetryConfig, Timestamp};
 use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
 use std::time::Duration;
 use tracing::debug;
@@ -151,6 +153,82 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         }
     }
 
+    async fn await_promise_with_timeout(
+        &mut self,
+        promise_id: golem_api_1_x::host::PromiseId,
+        timeout: golem_api_1_x::host::Duration,
+    ) -> anyhow::Result<AwaitPromiseResult> {
+        self.observe_function_call("golem::api", "await_promise_with_timeout");
+        let promise_id: PromiseId = promise_id.into();
+
+        // The deadline is persisted separately, so it does not move when the worker gets suspended
+        // while waiting and replays this call after being resumed
+        let durability = Durability::<Timestamp, SerializableError>::new(
+            self,
+            "golem::api",
+            "await_promise_with_timeout deadline",
+            DurableFunctionType::ReadLocal,
+        )
+        .await?;
+
+        let deadline = if durability.is_live() {
+            let deadline = promise_deadline(Timestamp::now_utc(), Duration::from_nanos(timeout));
+            durability
+                .persist(
+                    self,
+                    (promise_id.clone(), timeout),
+                    Ok::<Timestamp, WorkerExecutorError>(deadline),
+                )
+                .await?
+        } else {
+            durability
+                .replay::<Timestamp, WorkerExecutorError>(self)
+                .await?
+        };
+
+        let durability = Durability::<Option<Vec<u8>>, SerializableError>::new(
+            self,
+            "golem::api",
+            "await_promise_with_timeout",
+            DurableFunctionType::ReadRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let result = self
+                .public_state
+                .promise_service
+                .poll(promise_id.clone())
+                .await;
+            let result = match result {
+                Ok(polled) => match TimedAwait::new(polled, deadline, Timestamp::now_utc()) {
+                    TimedAwait::Suspend { until } => {
+                        debug!(
+                            "Suspending worker until {} gets completed or {} is reached",
+                            promise_id, until
+                        );
+                        let wake_up_at =
+                            DateTime::<Utc>::from_timestamp_millis(until.to_millis() as i64)
+                                .unwrap_or_else(Utc::now);
+                        self.state.sleep_until(wake_up_at).await?;
+                        return Err(InterruptKind::Suspend.into());
+                    }
+                    TimedAwait::Completed(data) => Ok(Some(data)),
+                    TimedAwait::TimedOut => Ok(None),
+                },
+                Err(err) => Err(err),
+            };
+            durability.persist(self, promise_id, result).await
+        } else {
+            durability.replay(self).await
+        }?;
+
+        Ok(match result {
+            Some(data) => AwaitPromiseResult::Completed(data),
+            None => AwaitPromiseResult::TimedOut,
+        })
+    }
+
     async fn poll_promise(
         &mut self,
         promise_id: golem_api_1_x::host::PromiseId,
@@ -648,6 +726,58 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
         Ok(())
     }
 
+    async fn get_labels(&mut self) -> anyhow::Result<Vec<(String, String)>> {
+        let durability = Durability::<Vec<(String, String)>, SerializableError>::new(
+            self,
+            "golem::api",
+            "get_labels",
+            DurableFunctionType::ReadRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let result: Result<Vec<(String, String)>, anyhow::Error> =
+                Ok(self.get_worker_status_record().labels.into_iter().collect());
+            durability.persist(self, (), result).await
+        } else {
+            durability.replay(self).await
+        }
+    }
+
+    async fn set_label(&mut self, key: String, value: String) -> anyhow::Result<()> {
+        self.observe_function_call("golem::api", "set_label");
+
+        // Label changes are hints, so they are not replayed - the worker's status
+        // already contains the labels recorded in the oplog
+        if self.state.is_live() {
+            self.state
+                .oplog
+                .add(OplogEntry::set_label(key.clone(), value.clone()))
+                .await;
+            self.update_worker_status(move |status| {
+                status.labels.insert(key, value);
+            })
+            .await;
+        }
+        Ok(())
+    }
+
+    async fn remove_label(&mut self, key: String) -> anyhow::Result<()> {
+        self.observe_function_call("golem::api", "remove_label");
+
+        if self.state.is_live() {
+            self.state
+                .oplog
+                .add(OplogEntry::remove_label(key.clone()))
+                .await;
+            self.update_worker_status(move |status| {
+                status.labels.remove(&key);
+            })
+            .await;
+        }
+        Ok(())
+    }
+
     async fn resolve_component_id(
         &mut self,
         component_slug: String,
@@ -0,0 +1,342 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use dashmap::DashMap;
+use golem_common::model::http_fixture::{HttpFixture, HttpFixtureResponse};
+use http_body_util::{BodyExt, Full};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+use wasmtime_wasi_http::bindings::http::types::{
+    DnsErrorPayload, ErrorCode, FieldSizePayload, TlsAlertReceivedPayload,
+};
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::types::{
+    HostFutureIncomingResponse, IncomingResponse, OutgoingRequestConfig,
+};
+
+/// Loaded fixture files, shared by all the workers of the executor
+static FIXTURES: LazyLock<DashMap<PathBuf, Arc<HttpFixture>>> = LazyLock::new(DashMap::new);
+
+/// Answers the outgoing requests of a single worker from the recorded responses of a fixture
+/// file instead of sending them.
+///
+/// Every worker gets the recorded responses in their original order, independently of the
+/// requests of other workers.
+pub struct FixtureReplayer {
+    path: PathBuf,
+    served: HashMap<(String, String), usize>,
+}
+
+impl FixtureReplayer {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            served: HashMap::new(),
+        }
+    }
+
+    pub fn send_request(
+        &mut self,
+        request: &hyper::Request<HyperOutgoingBody>,
+        config: &OutgoingRequestConfig,
+    ) -> HostFutureIncomingResponse {
+        let method = request.method().as_str();
+        // Requests are recorded in the oplog without their scheme
+        let uri = format!(
+            "{}{}",
+            request.uri().authority().map(|a| a.as_str()).unwrap_or(""),
+            request
+                .uri()
+                .path_and_query()
+                .map(|p| p.as_str())
+                .unwrap_or("")
+        );
+
+        let result =
+            load(&self.path).and_then(|fixture| match self.response(&fixture, method, &uri) {
+                Some(response) => incoming_response(response, config.between_bytes_timeout),
+                None => Err(ErrorCode::InternalError(Some(format!(
+                    "No recorded response for {method} {uri} in {}",
+                    self.path.display()
+                )))),
+            });
+        HostFutureIncomingResponse::ready(Ok(result))
+    }
+
+    /// Recorded responses of the same request are served in their original order, and the last
+    /// one is repeated once all of them have been served
+    fn response<'a>(
+        &mut self,
+        fixture: &'a HttpFixture,
+        method: &str,
+        uri: &str,
+    ) -> Option<&'a HttpFixtureResponse> {
+        let matching = fixture
+            .interactions
+            .iter()
+            .filter(|interaction| {
+                interaction.request.method.eq_ignore_ascii_case(method)
+                    && interaction.request.uri == uri
+            })
+            .collect::<Vec<_>>();
+        let last = matching.len().checked_sub(1)?;
+
+        let count = self
+            .served
+            .entry((method.to_string(), uri.to_string()))
+            .or_default();
+        let interaction = matching[(*count).min(last)];
+        *count += 1;
+        Some(&interaction.response)
+    }
+}
+
+fn load(path: &Path) -> Result<Arc<HttpFixture>, ErrorCode> {
+    if let Some(fixture) = FIXTURES.get(path) {
+        return Ok(fixture.clone());
+    }
+
+    let fixture = std::fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|json| serde_json::from_str::<HttpFixture>(&json).map_err(|err| err.to_string()))
+        .map_err(|err| {
+            ErrorCode::InternalError(Some(format!(
+                "Failed to load HTTP fixtures from {}: {err}",
+                path.display()
+            )))
+        })?;
+    Ok(FIXTURES
+        .entry(path.to_path_buf())
+        .or_insert_with(|| Arc::new(fixture))
+        .clone())
+}
+
+fn incoming_response(
+    response: &HttpFixtureResponse,
+    between_bytes_timeout: Duration,
+) -> Result<IncomingResponse, ErrorCode> {
+    match response {
+        HttpFixtureResponse::Received {
+            status,
+            headers,
+            body,
+        } => {
+            let mut builder = hyper::Response::builder().status(*status);
+            for (name, value) in headers {
+                builder = builder.header(name, value);
+            }
+            let body = Full::new(Bytes::from(body.0.clone()))
+                .map_err(|never: Infallible| -> ErrorCode { match never {} })
+                .boxed();
+            let resp = builder
+                .body(body)
+                .map_err(|err| ErrorCode::InternalError(Some(err.to_string())))?;
+            Ok(IncomingResponse {
+                resp,
+                worker: None,
+                between_bytes_timeout,
+            })
+        }
+        HttpFixtureResponse::Failed { code, error } => {
+            Err(error_code(code.as_deref(), error.clone()))
+        }
+    }
+}
+
+/// Restores the error code a request failed with. Payloads are only recorded as part of the
+/// printed error, which is kept where the payload holds a message.
+fn error_code(code: Option<&str>, error: String) -> ErrorCode {
+    let field_size = || FieldSizePayload {
+        field_name: None,
+        field_size: None,
+    };
+    match code {
+        Some("dns-timeout") => ErrorCode::DnsTimeout,
+        Some("dns-error") => ErrorCode::DnsError(DnsErrorPayload {
+            rcode: None,
+            info_code: None,
+        }),
+        Some("destination-not-found") => ErrorCode::DestinationNotFound,
+        Some("destination-unavailable") => ErrorCode::DestinationUnavailable,
+        Some("destination-ip-prohibited") => ErrorCode::DestinationIpProhibited,
+        Some("destination-ip-unroutable") => ErrorCode::DestinationIpUnroutable,
+        Some("connection-refused") => ErrorCode::ConnectionRefused,
+        Some("connection-terminated") => ErrorCode::ConnectionTerminated,
+        Some("connection-timeout") => ErrorCode::ConnectionTimeout,
+        Some("connection-read-timeout") => ErrorCode::ConnectionReadTimeout,
+        Some("connection-write-timeout") => ErrorCode::ConnectionWriteTimeout,
+        Some("connection-limit-reached") => ErrorCode::ConnectionLimitReached,
+        Some("tls-protocol-error") => ErrorCode::TlsProtocolError,
+        Some("tls-certificate-error") => ErrorCode::TlsCertificateError,
+        Some("tls-alert-received") => ErrorCode::TlsAlertReceived(TlsAlertReceivedPayload {
+            alert_id: None,
+            alert_message: Some(error),
+        }),
+        Some("http-request-denied") => ErrorCode::HttpRequestDenied,
+        Some("http-request-length-required") => ErrorCode::HttpRequestLengthRequired,
+        Some("http-request-body-size") => ErrorCode::HttpRequestBodySize(None),
+        Some("http-request-method-invalid") => ErrorCode::HttpRequestMethodInvalid,
+        Some("http-request-uri-invalid") => ErrorCode::HttpRequestUriInvalid,
+        Some("http-request-uri-too-long") => ErrorCode::HttpRequestUriTooLong,
+        Some("http-request-header-section-size") => ErrorCode::HttpRequestHeaderSectionSize(None),
+        Some("http-request-header-size") => ErrorCode::HttpRequestHeaderSize(None),
+        Some("http-request-trailer-section-size") => ErrorCode::HttpRequestTrailerSectionSize(None),
+        Some("http-request-trailer-size") => ErrorCode::HttpRequestTrailerSize(field_size()),
+        Some("http-response-incomplete") => ErrorCode::HttpResponseIncomplete,
+        Some("http-response-header-section-size") => ErrorCode::HttpResponseHeaderSectionSize(None),
+        Some("http-response-header-size") => ErrorCode::HttpResponseHeaderSize(field_size()),
+        Some("http-response-body-size") => ErrorCode::HttpResponseBodySize(None),
+        Some("http-response-trailer-section-size") => {
+            ErrorCode::HttpResponseTrailerSectionSize(None)
+        }
+        Some("http-response-trailer-size") => ErrorCode::HttpResponseTrailerSize(field_size()),
+        Some("http-response-transfer-coding") => ErrorCode::HttpResponseTransferCoding(Some(error)),
+        Some("http-response-content-coding") => ErrorCode::HttpResponseContentCoding(Some(error)),
+        Some("http-response-timeout") => ErrorCode::HttpResponseTimeout,
+        Some("http-upgrade-failed") => ErrorCode::HttpUpgradeFailed,
+        Some("http-protocol-error") => ErrorCode::HttpProtocolError,
+        Some("loop-detected") => ErrorCode::LoopDetected,
+        Some("configuration-error") => ErrorCode::ConfigurationError,
+        _ => ErrorCode::InternalError(Some(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{incoming_response, FixtureReplayer};
+    use assert2::check;
+    use golem_common::model::base64::Base64;
+    use golem_common::model::http_fixture::{
+        HttpFixture, HttpFixtureRequest, HttpFixtureResponse, HttpInteraction,
+    };
+    use http_body_util::BodyExt;
+    use std::collections::BTreeMap;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use test_r::test;
+    use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+
+    fn interaction(method: &str, uri: &str, status: u16, body: &str) -> HttpInteraction {
+        HttpInteraction {
+            request: HttpFixtureRequest {
+                method: method.to_string(),
+                uri: uri.to_string(),
+                headers: BTreeMap::new(),
+            },
+            response: HttpFixtureResponse::Received {
+                status,
+                headers: BTreeMap::from([("content-type".to_string(), "text/plain".to_string())]),
+                body: Base64(body.as_bytes().to_vec()),
+            },
+        }
+    }
+
+    fn status(response: Option<&HttpFixtureResponse>) -> Option<u16> {
+        match response {
+            Some(HttpFixtureResponse::Received { status, .. }) => Some(*status),
+            _ => None,
+        }
+    }
+
+    fn fixture() -> HttpFixture {
+        HttpFixture {
+            interactions: vec![
+                interaction("GET", "example.com/a", 503, ""),
+                interaction("POST", "example.com/a", 201, ""),
+                interaction("GET", "example.com/a", 200, ""),
+            ],
+        }
+    }
+
+    #[test]
+    fn responses_are_served_in_recorded_order() {
+        let fixture = fixture();
+        let mut replayer = FixtureReplayer::new(PathBuf::from("fixtures.json"));
+
+        check!(status(replayer.response(&fixture, "GET", "example.com/a")) == Some(503));
+        check!(status(replayer.response(&fixture, "GET", "example.com/a")) == Some(200));
+        check!(status(replayer.response(&fixture, "GET", "example.com/a")) == Some(200));
+        check!(status(replayer.response(&fixture, "POST", "example.com/a")) == Some(201));
+        check!(replayer
+            .response(&fixture, "GET", "example.com/b")
+            .is_none());
+    }
+
+    #[test]
+    fn workers_are_served_independently() {
+        let fixture = fixture();
+        let mut replayer1 = FixtureReplayer::new(PathBuf::from("fixtures.json"));
+        let mut replayer2 = FixtureReplayer::new(PathBuf::from("fixtures.json"));
+
+        check!(status(replayer1.response(&fixture, "GET", "example.com/a")) == Some(503));
+        check!(status(replayer2.response(&fixture, "GET", "example.com/a")) == Some(503));
+        check!(status(replayer1.response(&fixture, "GET", "example.com/a")) == Some(200));
+        check!(status(replayer2.response(&fixture, "GET", "example.com/a")) == Some(200));
+    }
+
+    #[test]
+    fn recorded_errors_keep_their_error_code() {
+        let failed = |code: Option<&str>, error: &str| HttpFixtureResponse::Failed {
+            code: code.map(|code| code.to_string()),
+            error: error.to_string(),
+        };
+
+        let error = |response| incoming_response(&response, Duration::from_secs(1)).err();
+
+        check!(matches!(
+            error(failed(Some("connection-refused"), "connection-refused")),
+            Some(ErrorCode::ConnectionRefused)
+        ));
+        check!(matches!(
+            error(failed(
+                Some("dns-error"),
+                "dns-error({rcode: none, info-code: none})"
+            )),
+            Some(ErrorCode::DnsError(_))
+        ));
+        check!(matches!(
+            error(failed(
+                Some("internal-error"),
+                "internal-error(some(\"boom\"))"
+            )),
+            Some(ErrorCode::InternalError(_))
+        ));
+        check!(matches!(
+            error(failed(None, "pending")),
+            Some(ErrorCode::InternalError(Some(error))) if error == "pending"
+        ));
+    }
+
+    #[test]
+    async fn recorded_response_is_converted() {
+        let fixture = interaction("GET", "example.com/a", 200, "hello");
+
+        let response = incoming_response(&fixture.response, Duration::from_secs(1)).unwrap();
+
+        check!(response.resp.status() == 200);
+        check!(response.resp.headers()["content-type"] == "text/plain");
+        let body = response
+            .resp
+            .into_body()
+            .collect()
+            .await
+            .unwrap()
+            .to_bytes();
+        check!(body.as_ref() == b"hello");
+    }
+}
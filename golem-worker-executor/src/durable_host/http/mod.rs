@@ -18,8 +18,14 @@ use golem_common::model::oplog::DurableFunctionType;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use tracing::warn;
 
+/// Serving outgoing requests from recorded HTTP fixtures
+pub mod fixtures;
+
 pub mod outgoing_http;
 
+/// Retry, timeout and circuit breaker policies applied to outgoing requests
+pub mod policy;
+
 /// Serializable response data structures to be stored in the oplog
 pub mod serialized;
 
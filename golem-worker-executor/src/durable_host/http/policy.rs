@@ -0,0 +1,460 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::golem_config::{
+    CircuitBreakerConfig, OutgoingHttpPolicy, OutgoingHttpRetryConfig, OutgoingHttpTimeoutConfig,
+};
+use bytes::Bytes;
+use dashmap::DashMap;
+use golem_common::retries::get_delay;
+use http::HeaderMap;
+use http_body_util::{BodyExt, Full};
+use std::convert::Infallible;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+use wasmtime_wasi_http::body::HyperOutgoingBody;
+use wasmtime_wasi_http::types::{
+    default_send_request_handler, HostFutureIncomingResponse, IncomingResponse,
+    OutgoingRequestConfig,
+};
+
+/// Circuit breakers are shared by all the workers of the executor, keyed by the request authority
+static CIRCUIT_BREAKERS: LazyLock<DashMap<String, Arc<CircuitBreaker>>> =
+    LazyLock::new(DashMap::new);
+
+/// Sends an outgoing request in the background, applying the given host level policy.
+///
+/// Retries happen within the returned future, so only the final outcome gets recorded in the
+/// oplog, and replaying the worker returns the same response without resending the request.
+pub fn send_request(
+    request: hyper::Request<HyperOutgoingBody>,
+    config: OutgoingRequestConfig,
+    policy: OutgoingHttpPolicy,
+) -> HostFutureIncomingResponse {
+    let handle = wasmtime_wasi::runtime::spawn(async move {
+        Ok(send_request_handler(request, config, policy).await)
+    });
+    HostFutureIncomingResponse::pending(handle)
+}
+
+async fn send_request_handler(
+    request: hyper::Request<HyperOutgoingBody>,
+    config: OutgoingRequestConfig,
+    policy: OutgoingHttpPolicy,
+) -> Result<IncomingResponse, ErrorCode> {
+    let config = limit_timeouts(config, &policy.timeouts);
+    let circuit_breaker = policy.circuit_breaker.map(|circuit_breaker_config| {
+        let authority = request
+            .uri()
+            .authority()
+            .map(|authority| authority.as_str().to_lowercase())
+            .unwrap_or_default();
+        CIRCUIT_BREAKERS
+            .entry(authority)
+            .or_insert_with(|| Arc::new(CircuitBreaker::new(circuit_breaker_config)))
+            .clone()
+    });
+
+    match policy.retries {
+        Some(retries) if is_idempotent(request.method()) => {
+            send_with_retries(request, config, circuit_breaker.as_deref(), &retries).await
+        }
+        _ => send_once(request, config, circuit_breaker.as_deref()).await,
+    }
+}
+
+async fn send_with_retries(
+    request: hyper::Request<HyperOutgoingBody>,
+    config: OutgoingRequestConfig,
+    circuit_breaker: Option<&CircuitBreaker>,
+    retries: &OutgoingHttpRetryConfig,
+) -> Result<IncomingResponse, ErrorCode> {
+    // The body has to be buffered to be able to send it again
+    let (parts, body) = request.into_parts();
+    let body = body.collect().await?;
+    let trailers = body.trailers().cloned();
+    let body = body.to_bytes();
+
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let request = hyper::Request::from_parts(parts.clone(), buffered_body(&body, &trailers));
+        let result = send_once(request, copy_config(&config), circuit_breaker).await;
+
+        let retriable = match &result {
+            Ok(response) => retries
+                .retry_on_status
+                .contains(&response.resp.status().as_u16()),
+            Err(error_code) => is_transient(error_code),
+        };
+        if !retriable {
+            break result;
+        }
+        match get_delay(&retries.backoff, attempts) {
+            Some(delay) => {
+                debug!(
+                    "Outgoing request to {} failed in attempt {attempts}, retrying in {delay:?}",
+                    parts.uri
+                );
+                tokio::time::sleep(delay).await;
+            }
+            None => break result,
+        }
+    }
+}
+
+async fn send_once(
+    request: hyper::Request<HyperOutgoingBody>,
+    config: OutgoingRequestConfig,
+    circuit_breaker: Option<&CircuitBreaker>,
+) -> Result<IncomingResponse, ErrorCode> {
+    match circuit_breaker {
+        Some(circuit_breaker) => {
+            let Some(permit) = circuit_breaker.try_acquire() else {
+                return Err(ErrorCode::InternalError(Some(format!(
+                    "Circuit breaker is open for {}",
+                    request.uri().authority().map(|a| a.as_str()).unwrap_or("")
+                ))));
+            };
+            let result = default_send_request_handler(request, config).await;
+            let failed = match &result {
+                Ok(response) => response.resp.status().is_server_error(),
+                Err(error_code) => is_transient(error_code),
+            };
+            permit.record(failed);
+            result
+        }
+        None => default_send_request_handler(request, config).await,
+    }
+}
+
+fn buffered_body(body: &Bytes, trailers: &Option<HeaderMap>) -> HyperOutgoingBody {
+    let trailers = trailers.clone().map(Ok);
+    Full::new(body.clone())
+        .map_err(|never: Infallible| -> ErrorCode { match never {} })
+        .with_trailers(std::future::ready(trailers))
+        .boxed()
+}
+
+fn limit_timeouts(
+    config: OutgoingRequestConfig,
+    limits: &OutgoingHttpTimeoutConfig,
+) -> OutgoingRequestConfig {
+    fn limit(timeout: Duration, max: Option<Duration>) -> Duration {
+        max.map_or(timeout, |max| timeout.min(max))
+    }
+
+    OutgoingRequestConfig {
+        use_tls: config.use_tls,
+        connect_timeout: limit(config.connect_timeout, limits.connect_timeout),
+        first_byte_timeout: limit(config.first_byte_timeout, limits.first_byte_timeout),
+        between_bytes_timeout: limit(config.between_bytes_timeout, limits.between_bytes_timeout),
+    }
+}
+
+fn copy_config(config: &OutgoingRequestConfig) -> OutgoingRequestConfig {
+    OutgoingRequestConfig {
+        use_tls: config.use_tls,
+        connect_timeout: config.connect_timeout,
+        first_byte_timeout: config.first_byte_timeout,
+        between_bytes_timeout: config.between_bytes_timeout,
+    }
+}
+
+/// Requests which can be sent again without changing their effect, as defined by RFC 9110
+fn is_idempotent(method: &http::Method) -> bool {
+    matches!(
+        *method,
+        http::Method::GET
+            | http::Method::HEAD
+            | http::Method::OPTIONS
+            | http::Method::TRACE
+            | http::Method::PUT
+            | http::Method::DELETE
+    )
+}
+
+fn is_transient(error_code: &ErrorCode) -> bool {
+    matches!(
+        error_code,
+        ErrorCode::DnsTimeout
+            | ErrorCode::DnsError(_)
+            | ErrorCode::DestinationUnavailable
+            | ErrorCode::ConnectionRefused
+            | ErrorCode::ConnectionTerminated
+            | ErrorCode::ConnectionTimeout
+            | ErrorCode::ConnectionReadTimeout
+            | ErrorCode::ConnectionWriteTimeout
+            | ErrorCode::ConnectionLimitReached
+            | ErrorCode::HttpResponseTimeout
+    )
+}
+
+#[derive(Debug)]
+struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<CircuitState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CircuitState {
+    Closed {
+        consecutive_failures: u32,
+    },
+    Open {
+        until: Instant,
+    },
+    /// A single request is let through to probe the host
+    HalfOpen,
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CircuitState::Closed {
+                consecutive_failures: 0,
+            }),
+        }
+    }
+
+    /// Returns a permit if a request can be sent
+    fn try_acquire(&self) -> Option<CircuitBreakerPermit<'_>> {
+        let mut state = self.state.lock().unwrap();
+        let probe = match *state {
+            CircuitState::Closed { .. } => false,
+            CircuitState::Open { until } if Instant::now() >= until => {
+                *state = CircuitState::HalfOpen;
+                true
+            }
+            CircuitState::Open { .. } | CircuitState::HalfOpen => return None,
+        };
+        Some(CircuitBreakerPermit {
+            circuit_breaker: self,
+            probe,
+            recorded: false,
+        })
+    }
+
+    /// Lets the next request probe the host, when the probe ended without an outcome
+    fn release_probe(&self) {
+        let mut state = self.state.lock().unwrap();
+        if *state == CircuitState::HalfOpen {
+            *state = CircuitState::Open {
+                until: Instant::now(),
+            };
+        }
+    }
+
+    fn record(&self, failed: bool) {
+        let mut state = self.state.lock().unwrap();
+        *state = match (*state, failed) {
+            (_, false) => CircuitState::Closed {
+                consecutive_failures: 0,
+            },
+            (
+                CircuitState::Closed {
+                    consecutive_failures,
+                },
+                true,
+            ) if consecutive_failures + 1 < self.config.failure_threshold => CircuitState::Closed {
+                consecutive_failures: consecutive_failures + 1,
+            },
+            (CircuitState::Open { until }, true) => CircuitState::Open { until },
+            (_, true) => {
+                warn!(
+                    "Opening circuit breaker for {:?} after failed outgoing requests",
+                    self.config.open_duration
+                );
+                CircuitState::Open {
+                    until: Instant::now() + self.config.open_duration,
+                }
+            }
+        };
+    }
+}
+
+/// Permit of sending a single request, which has to be completed with the request's outcome.
+///
+/// The request's future can be dropped before that, for example when the worker is interrupted,
+/// in which case a half-open probe gets released so that the breaker does not stay half-open.
+struct CircuitBreakerPermit<'a> {
+    circuit_breaker: &'a CircuitBreaker,
+    probe: bool,
+    recorded: bool,
+}
+
+impl CircuitBreakerPermit<'_> {
+    fn record(mut self, failed: bool) {
+        self.recorded = true;
+        self.circuit_breaker.record(failed);
+    }
+}
+
+impl Drop for CircuitBreakerPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.circuit_breaker.release_probe();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_idempotent, is_transient, limit_timeouts, CircuitBreaker, CircuitState};
+    use crate::services::golem_config::{
+        CircuitBreakerConfig, OutgoingHttpConfig, OutgoingHttpPolicy, OutgoingHttpRetryConfig,
+        OutgoingHttpTimeoutConfig,
+    };
+    use assert2::check;
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use test_r::test;
+    use wasmtime_wasi_http::bindings::http::types::ErrorCode;
+    use wasmtime_wasi_http::types::OutgoingRequestConfig;
+
+    #[test]
+    fn policy_resolution() {
+        let retrying = OutgoingHttpPolicy {
+            retries: Some(OutgoingHttpRetryConfig::default()),
+            ..Default::default()
+        };
+        let breaking = OutgoingHttpPolicy {
+            circuit_breaker: Some(CircuitBreakerConfig::default()),
+            ..Default::default()
+        };
+        let config = OutgoingHttpConfig {
+            default: OutgoingHttpPolicy::default(),
+            hosts: HashMap::from([
+                ("api.example.com".to_string(), retrying),
+                ("*.Example.com".to_string(), breaking),
+            ]),
+            fixtures: None,
+        };
+
+        check!(config.policy("api.example.com").retries.is_some());
+        check!(config.policy("API.example.com").retries.is_some());
+        check!(config.policy("api.example.com").circuit_breaker.is_none());
+        check!(config.policy("www.example.com").circuit_breaker.is_some());
+        check!(config.policy("WWW.EXAMPLE.COM").circuit_breaker.is_some());
+        check!(config.policy("a.b.example.com").circuit_breaker.is_some());
+        check!(config.policy("example.com").circuit_breaker.is_none());
+        check!(config.policy("example.org").retries.is_none());
+    }
+
+    #[test]
+    fn timeouts_are_limited() {
+        let config = OutgoingRequestConfig {
+            use_tls: true,
+            connect_timeout: Duration::from_secs(600),
+            first_byte_timeout: Duration::from_secs(1),
+            between_bytes_timeout: Duration::from_secs(600),
+        };
+        let limits = OutgoingHttpTimeoutConfig {
+            connect_timeout: Some(Duration::from_secs(5)),
+            first_byte_timeout: Some(Duration::from_secs(10)),
+            between_bytes_timeout: None,
+        };
+
+        let config = limit_timeouts(config, &limits);
+
+        check!(config.use_tls);
+        check!(config.connect_timeout == Duration::from_secs(5));
+        check!(config.first_byte_timeout == Duration::from_secs(1));
+        check!(config.between_bytes_timeout == Duration::from_secs(600));
+    }
+
+    #[test]
+    fn only_idempotent_requests_and_transient_errors_are_retried() {
+        check!(is_idempotent(&http::Method::GET));
+        check!(is_idempotent(&http::Method::PUT));
+        check!(!is_idempotent(&http::Method::POST));
+        check!(!is_idempotent(&http::Method::PATCH));
+
+        check!(is_transient(&ErrorCode::ConnectionRefused));
+        check!(is_transient(&ErrorCode::HttpResponseTimeout));
+        check!(!is_transient(&ErrorCode::HttpRequestDenied));
+        check!(!is_transient(&ErrorCode::InternalError(None)));
+    }
+
+    #[test]
+    fn circuit_breaker_opens_after_consecutive_failures() {
+        let circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            open_duration: Duration::from_secs(60),
+        });
+
+        for _ in 0..2 {
+            circuit_breaker.try_acquire().unwrap().record(true);
+        }
+        circuit_breaker.try_acquire().unwrap().record(false);
+
+        for _ in 0..3 {
+            circuit_breaker.try_acquire().unwrap().record(true);
+        }
+        check!(circuit_breaker.try_acquire().is_none());
+    }
+
+    #[test]
+    fn circuit_breaker_probes_after_open_duration() {
+        let circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::ZERO,
+        });
+
+        circuit_breaker.try_acquire().unwrap().record(true);
+
+        // The first request after the open duration is a probe, others have to wait for it
+        let probe = circuit_breaker.try_acquire();
+        check!(probe.is_some());
+        check!(*circuit_breaker.state.lock().unwrap() == CircuitState::HalfOpen);
+        check!(circuit_breaker.try_acquire().is_none());
+
+        probe.unwrap().record(false);
+        check!(circuit_breaker.try_acquire().is_some());
+        check!(
+            *circuit_breaker.state.lock().unwrap()
+                == CircuitState::Closed {
+                    consecutive_failures: 0
+                }
+        );
+    }
+
+    #[test]
+    fn dropped_probe_lets_the_next_request_probe() {
+        let circuit_breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::ZERO,
+        });
+
+        circuit_breaker.try_acquire().unwrap().record(true);
+
+        let probe = circuit_breaker.try_acquire();
+        check!(probe.is_some());
+        check!(circuit_breaker.try_acquire().is_none());
+
+        drop(probe);
+        let probe = circuit_breaker.try_acquire();
+        check!(probe.is_some());
+        check!(*circuit_breaker.state.lock().unwrap() == CircuitState::HalfOpen);
+
+        probe.unwrap().record(true);
+        check!(matches!(
+            *circuit_breaker.state.lock().unwrap(),
+            CircuitState::Open { .. }
+        ));
+    }
+}
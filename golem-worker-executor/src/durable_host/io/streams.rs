@@ -20,11 +20,13 @@ use crate::durable_host::http::end_http_request;
 use crate::durable_host::http::serialized::SerializableHttpRequest;
 use crate::durable_host::io::{ManagedStdErr, ManagedStdOut};
 use crate::durable_host::serialized::SerializableStreamError;
+use crate::durable_host::sockets::end_tcp_connection_stream;
 use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx, HttpRequestCloseOwner};
 use crate::model::event::InternalWorkerEvent;
 use crate::workerctx::WorkerCtx;
 use golem_common::model::oplog::{DurableFunctionType, OplogIndex};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use wasmtime_wasi::p2::bindings::filesystem::types::ErrorCode;
 use wasmtime_wasi::p2::bindings::io::streams::{
     Host, HostInputStream, HostOutputStream, InputStream, OutputStream, Pollable,
 };
@@ -58,6 +60,23 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<Vec<u8>, SerializableStreamError>::new(
+                self,
+                "sockets::tcp::input_stream",
+                "read",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result = HostInputStream::read(&mut self.as_wasi_view().0, self_, len).await;
+                durability.persist(self, remote_address, result).await
+            } else {
+                durability.replay(self).await
+            }
         } else {
             self.observe_function_call("io::streams::input_stream", "read");
             HostInputStream::read(&mut self.as_wasi_view().0, self_, len).await
@@ -91,6 +110,24 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<Vec<u8>, SerializableStreamError>::new(
+                self,
+                "sockets::tcp::input_stream",
+                "blocking_read",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result =
+                    HostInputStream::blocking_read(&mut self.as_wasi_view().0, self_, len).await;
+                durability.persist(self, remote_address, result).await
+            } else {
+                durability.replay(self).await
+            }
         } else {
             self.observe_function_call("io::streams::input_stream", "blocking_read");
             HostInputStream::blocking_read(&mut self.as_wasi_view().0, self_, len).await
@@ -119,6 +156,23 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
 
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<u64, SerializableStreamError>::new(
+                self,
+                "sockets::tcp::input_stream",
+                "skip",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result = HostInputStream::skip(&mut self.as_wasi_view().0, self_, len).await;
+                durability.persist(self, remote_address, result).await
+            } else {
+                durability.replay(self).await
+            }
         } else {
             self.observe_function_call("io::streams::input_stream", "skip");
             HostInputStream::skip(&mut self.as_wasi_view().0, self_, len).await
@@ -152,6 +206,24 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
             };
             end_http_request_if_closed(self, handle, &result).await?;
             result
+        } else if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<u64, SerializableStreamError>::new(
+                self,
+                "sockets::tcp::input_stream",
+                "blocking_skip",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result =
+                    HostInputStream::blocking_skip(&mut self.as_wasi_view().0, self_, len).await;
+                durability.persist(self, remote_address, result).await
+            } else {
+                durability.replay(self).await
+            }
         } else {
             self.observe_function_call("io::streams::input_stream", "blocking_skip");
             HostInputStream::blocking_skip(&mut self.as_wasi_view().0, self_, len).await
@@ -174,6 +246,9 @@ impl<Ctx: WorkerCtx> HostInputStream for DurableWorkerCtx<Ctx> {
                 }
             }
         }
+        if is_tcp_connection_stream(self, rep.rep()) {
+            end_tcp_connection_stream(self, rep.rep()).await?;
+        }
 
         HostInputStream::drop(&mut self.as_wasi_view().0, rep).await
     }
@@ -204,7 +279,29 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         if let Some(event) = event {
             self.emit_log_event(event).await;
             Ok::<(), StreamError>(())
+        } else if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<(), SerializableStreamError>::new(
+                self,
+                "sockets::tcp::output_stream",
+                "write",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let len = contents.len() as u64;
+                let result =
+                    HostOutputStream::write(&mut self.as_wasi_view().0, self_, contents).await;
+                durability
+                    .persist(self, (remote_address, len), result)
+                    .await
+            } else {
+                durability.replay(self).await
+            }
         } else {
+            reserve_file_system_space(self, &self_, contents.len() as u64).await?;
             // Non-stdout writes are non-persistent and always executed
             HostOutputStream::write(&mut self.as_wasi_view().0, self_, contents).await
         }
@@ -241,8 +338,32 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         self_: Resource<OutputStream>,
         len: u64,
     ) -> Result<(), StreamError> {
-        self.observe_function_call("io::streams::output_stream", "write_zeroeas");
-        HostOutputStream::write_zeroes(&mut self.as_wasi_view().0, self_, len).await
+        self.observe_function_call("io::streams::output_stream", "write_zeroes");
+
+        if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<(), SerializableStreamError>::new(
+                self,
+                "sockets::tcp::output_stream",
+                "write_zeroes",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result =
+                    HostOutputStream::write_zeroes(&mut self.as_wasi_view().0, self_, len).await;
+                durability
+                    .persist(self, (remote_address, len), result)
+                    .await
+            } else {
+                durability.replay(self).await
+            }
+        } else {
+            reserve_file_system_space(self, &self_, len).await?;
+            HostOutputStream::write_zeroes(&mut self.as_wasi_view().0, self_, len).await
+        }
     }
 
     async fn blocking_write_zeroes_and_flush(
@@ -250,12 +371,24 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         self_: Resource<OutputStream>,
         len: u64,
     ) -> Result<(), StreamError> {
-        self.observe_function_call(
-            "io::streams::output_stream",
-            "blocking_write_zeroes_and_flush",
-        );
-        HostOutputStream::blocking_write_zeroes_and_flush(&mut self.as_wasi_view().0, self_, len)
+        if is_tcp_connection_stream(self, self_.rep()) {
+            let self2 = Resource::new_borrow(self_.rep());
+            self.write_zeroes(self_, len).await?;
+            self.blocking_flush(self2).await?;
+            Ok(())
+        } else {
+            self.observe_function_call(
+                "io::streams::output_stream",
+                "blocking_write_zeroes_and_flush",
+            );
+            reserve_file_system_space(self, &self_, len).await?;
+            HostOutputStream::blocking_write_zeroes_and_flush(
+                &mut self.as_wasi_view().0,
+                self_,
+                len,
+            )
             .await
+        }
     }
 
     async fn splice(
@@ -265,7 +398,31 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         len: u64,
     ) -> Result<u64, StreamError> {
         self.observe_function_call("io::streams::output_stream", "splice");
-        HostOutputStream::splice(&mut self.as_wasi_view().0, self_, src, len).await
+
+        if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<u64, SerializableStreamError>::new(
+                self,
+                "sockets::tcp::output_stream",
+                "splice",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result =
+                    HostOutputStream::splice(&mut self.as_wasi_view().0, self_, src, len).await;
+                durability
+                    .persist(self, (remote_address, len), result)
+                    .await
+            } else {
+                durability.replay(self).await
+            }
+        } else {
+            reserve_file_system_space(self, &self_, len).await?;
+            HostOutputStream::splice(&mut self.as_wasi_view().0, self_, src, len).await
+        }
     }
 
     async fn blocking_splice(
@@ -275,11 +432,42 @@ impl<Ctx: WorkerCtx> HostOutputStream for DurableWorkerCtx<Ctx> {
         len: u64,
     ) -> Result<u64, StreamError> {
         self.observe_function_call("io::streams::output_stream", "blocking_splice");
-        HostOutputStream::blocking_splice(&mut self.as_wasi_view().0, self_, src, len).await
+
+        if is_tcp_connection_stream(self, self_.rep()) {
+            let (begin_idx, remote_address) = get_tcp_connection_state(self, self_.rep())?;
+
+            let durability = Durability::<u64, SerializableStreamError>::new(
+                self,
+                "sockets::tcp::output_stream",
+                "blocking_splice",
+                DurableFunctionType::WriteRemoteBatched(Some(begin_idx)),
+            )
+            .await?;
+
+            if durability.is_live() {
+                let result =
+                    HostOutputStream::blocking_splice(&mut self.as_wasi_view().0, self_, src, len)
+                        .await;
+                durability
+                    .persist(self, (remote_address, len), result)
+                    .await
+            } else {
+                durability.replay(self).await
+            }
+        } else {
+            reserve_file_system_space(self, &self_, len).await?;
+            HostOutputStream::blocking_splice(&mut self.as_wasi_view().0, self_, src, len).await
+        }
     }
 
     async fn drop(&mut self, rep: Resource<OutputStream>) -> anyhow::Result<()> {
         self.observe_function_call("io::streams::output_stream", "drop");
+
+        if is_tcp_connection_stream(self, rep.rep()) {
+            end_tcp_connection_stream(self, rep.rep()).await?;
+        }
+        self.unregister_file_output_stream(rep.rep());
+
         HostOutputStream::drop(&mut self.as_wasi_view().0, rep).await
     }
 }
@@ -326,6 +514,46 @@ async fn end_http_request_if_closed<Ctx: WorkerCtx, T>(
     Ok(())
 }
 
+/// Accounts for writing up to `amount` bytes if the stream writes to a file, failing the operation
+/// with `insufficient-space` if it would exceed the file system quotas
+async fn reserve_file_system_space<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    stream: &Resource<OutputStream>,
+    amount: u64,
+) -> Result<(), StreamError> {
+    if ctx.is_file_output_stream(stream.rep())
+        && !ctx
+            .reserve_file_system_space(amount)
+            .await
+            .map_err(|err| StreamError::Trap(anyhow!(err)))?
+    {
+        Err(StreamError::LastOperationFailed(anyhow!(
+            ErrorCode::InsufficientSpace
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn is_tcp_connection_stream<Ctx: WorkerCtx>(ctx: &DurableWorkerCtx<Ctx>, handle: u32) -> bool {
+    ctx.state.open_tcp_connections.contains_key(&handle)
+}
+
+fn get_tcp_connection_state<Ctx: WorkerCtx>(
+    ctx: &DurableWorkerCtx<Ctx>,
+    handle: u32,
+) -> Result<(OplogIndex, String), StreamError> {
+    let connection_state = ctx.state.open_tcp_connections.get(&handle).ok_or_else(|| {
+        StreamError::Trap(anyhow!(
+            "No matching TCP connection is associated with resource handle"
+        ))
+    })?;
+    Ok((
+        connection_state.begin_index,
+        connection_state.remote_address.clone(),
+    ))
+}
+
 fn get_http_request_begin_idx<Ctx: WorkerCtx>(
     ctx: &mut DurableWorkerCtx<Ctx>,
     handle: u32,
@@ -12,31 +12,108 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use golem_common::model::oplog::DurableFunctionType;
 use wasmtime::component::Resource;
+use wasmtime_wasi::IoView;
 
-use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::durable_host::keyvalue::error::ErrorEntry;
+use crate::durable_host::keyvalue::types::BucketEntry;
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
 use crate::preview2::wasi::keyvalue::atomic::{Bucket, Error, Host, Key};
 use crate::workerctx::WorkerCtx;
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
     async fn increment(
         &mut self,
-        _bucket: Resource<Bucket>,
-        _key: Key,
-        _delta: u64,
+        bucket: Resource<Bucket>,
+        key: Key,
+        delta: u64,
     ) -> anyhow::Result<Result<u64, Resource<Error>>> {
-        self.observe_function_call("keyvalue::atomic", "increment");
-        unimplemented!("increment")
+        let project_id = self.owned_worker_id.project_id();
+        let bucket = self
+            .as_wasi_view()
+            .table()
+            .get::<BucketEntry>(&bucket)?
+            .name
+            .clone();
+
+        let durability = Durability::<u64, SerializableError>::new(
+            self,
+            "golem keyvalue::atomic",
+            "increment",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let input = (bucket.clone(), key.clone(), delta);
+            let result = self
+                .state
+                .key_value_service
+                .increment(project_id, bucket, key, delta)
+                .await;
+            durability.persist(self, input, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        match result {
+            Ok(value) => Ok(Ok(value)),
+            Err(e) => {
+                let error = self
+                    .as_wasi_view()
+                    .table()
+                    .push(ErrorEntry::new(format!("{e:?}")))?;
+                Ok(Err(error))
+            }
+        }
     }
 
     async fn compare_and_swap(
         &mut self,
-        _bucket: Resource<Bucket>,
-        _key: Key,
-        _old: u64,
-        _new: u64,
+        bucket: Resource<Bucket>,
+        key: Key,
+        old: u64,
+        new: u64,
     ) -> anyhow::Result<Result<bool, Resource<Error>>> {
-        self.observe_function_call("keyvalue::atomic", "compare_and_swap");
-        unimplemented!("compare_and_swap")
+        let project_id = self.owned_worker_id.project_id();
+        let bucket = self
+            .as_wasi_view()
+            .table()
+            .get::<BucketEntry>(&bucket)?
+            .name
+            .clone();
+
+        let durability = Durability::<bool, SerializableError>::new(
+            self,
+            "golem keyvalue::atomic",
+            "compare_and_swap",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let input = (bucket.clone(), key.clone(), old, new);
+            let result = self
+                .state
+                .key_value_service
+                .compare_and_swap(project_id, bucket, key, old, new)
+                .await;
+            durability.persist(self, input, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        match result {
+            Ok(swapped) => Ok(Ok(swapped)),
+            Err(e) => {
+                let error = self
+                    .as_wasi_view()
+                    .table()
+                    .push(ErrorEntry::new(format!("{e:?}")))?;
+                Ok(Err(error))
+            }
+        }
     }
 }
@@ -12,9 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use golem_common::model::oplog::DurableFunctionType;
 use wasmtime::component::Resource;
+use wasmtime_wasi::subscribe;
 
-use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::durable_host::keyvalue::error::ErrorEntry;
+use crate::durable_host::keyvalue::types::{IncomingValueEntry, OutgoingValueEntry};
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
 use crate::preview2::wasi::keyvalue::cache::{
     Error, FutureExistsResult, FutureGetOrSetResult, FutureGetResult, FutureResult, GetOrSetEntry,
     Host, HostFutureExistsResult, HostFutureGetOrSetResult, HostFutureGetResult, HostFutureResult,
@@ -25,148 +34,368 @@ use crate::workerctx::WorkerCtx;
 impl<Ctx: WorkerCtx> HostFutureGetResult for DurableWorkerCtx<Ctx> {
     async fn future_get_result_get(
         &mut self,
-        _self_: Resource<FutureGetResult>,
+        self_: Resource<FutureGetResult>,
     ) -> anyhow::Result<Option<Result<Option<Resource<IncomingValue>>, Resource<Error>>>> {
         self.observe_function_call("keyvalue::cache::future_get", "future_get_result_get");
-        unimplemented!("future_get_result_get")
+        let result = self.table().get(&self_)?.result.clone();
+        match result {
+            Ok(Some(value)) => {
+                let incoming_value = self.table().push(IncomingValueEntry::new(value))?;
+                Ok(Some(Ok(Some(incoming_value))))
+            }
+            Ok(None) => Ok(Some(Ok(None))),
+            Err(error) => {
+                let error = self.table().push(ErrorEntry::new(error))?;
+                Ok(Some(Err(error)))
+            }
+        }
     }
 
     async fn listen_to_future_get_result(
         &mut self,
-        _self_: Resource<FutureGetResult>,
+        self_: Resource<FutureGetResult>,
     ) -> anyhow::Result<Resource<Pollable>> {
         self.observe_function_call("keyvalue::cache::future_get", "listen_to_future_get_result");
-        unimplemented!("listen_to_future_get_result")
+        subscribe(self.table(), self_, None)
     }
 
-    async fn drop(&mut self, _rep: Resource<FutureGetResult>) -> anyhow::Result<()> {
+    async fn drop(&mut self, rep: Resource<FutureGetResult>) -> anyhow::Result<()> {
         self.observe_function_call("keyvalue::cache::future_get", "drop");
-        unimplemented!("drop")
+        self.table().delete(rep)?;
+        Ok(())
     }
 }
 
 impl<Ctx: WorkerCtx> HostFutureExistsResult for DurableWorkerCtx<Ctx> {
     async fn future_exists_result_get(
         &mut self,
-        _self_: Resource<FutureExistsResult>,
+        self_: Resource<FutureExistsResult>,
     ) -> anyhow::Result<Option<Result<bool, Resource<Error>>>> {
         self.observe_function_call("keyvalue::cache::future_exists", "future_exists_result_get");
-        unimplemented!("future_exists_result_get")
+        let result = self.table().get(&self_)?.result.clone();
+        match result {
+            Ok(exists) => Ok(Some(Ok(exists))),
+            Err(error) => {
+                let error = self.table().push(ErrorEntry::new(error))?;
+                Ok(Some(Err(error)))
+            }
+        }
     }
 
     async fn listen_to_future_exists_result(
         &mut self,
-        _self_: Resource<FutureExistsResult>,
+        self_: Resource<FutureExistsResult>,
     ) -> anyhow::Result<Resource<Pollable>> {
         self.observe_function_call(
             "keyvalue::cache::future_exists",
             "listen_to_future_exists_result",
         );
-        unimplemented!("listen_to_future_exists_result")
+        subscribe(self.table(), self_, None)
     }
 
-    async fn drop(&mut self, _rep: Resource<FutureExistsResult>) -> anyhow::Result<()> {
+    async fn drop(&mut self, rep: Resource<FutureExistsResult>) -> anyhow::Result<()> {
         self.observe_function_call("keyvalue::cache::future_exists", "drop");
-        unimplemented!("drop")
+        self.table().delete(rep)?;
+        Ok(())
     }
 }
 
 impl<Ctx: WorkerCtx> HostFutureResult for DurableWorkerCtx<Ctx> {
     async fn future_result_get(
         &mut self,
-        _self_: Resource<FutureResult>,
+        self_: Resource<FutureResult>,
     ) -> anyhow::Result<Option<Result<(), Resource<Error>>>> {
         self.observe_function_call("keyvalue::cache::future_result", "future_result_get");
-        unimplemented!("future_result_get")
+        let result = self.table().get(&self_)?.result.clone();
+        match result {
+            Ok(()) => Ok(Some(Ok(()))),
+            Err(error) => {
+                let error = self.table().push(ErrorEntry::new(error))?;
+                Ok(Some(Err(error)))
+            }
+        }
     }
 
     async fn listen_to_future_result(
         &mut self,
-        _self_: Resource<FutureResult>,
+        self_: Resource<FutureResult>,
     ) -> anyhow::Result<Resource<Pollable>> {
         self.observe_function_call("keyvalue::cache::future_result", "listen_to_future_result");
-        unimplemented!("listen_to_future_result")
+        subscribe(self.table(), self_, None)
     }
 
-    async fn drop(&mut self, _rep: Resource<FutureResult>) -> anyhow::Result<()> {
+    async fn drop(&mut self, rep: Resource<FutureResult>) -> anyhow::Result<()> {
         self.observe_function_call("keyvalue::cache::future_result", "drop");
-        unimplemented!("drop")
+        self.table().delete(rep)?;
+        Ok(())
     }
 }
 
 impl<Ctx: WorkerCtx> HostFutureGetOrSetResult for DurableWorkerCtx<Ctx> {
     async fn future_get_or_set_result_get(
         &mut self,
-        _self_: Resource<FutureGetOrSetResult>,
+        self_: Resource<FutureGetOrSetResult>,
     ) -> anyhow::Result<Option<Result<GetOrSetEntry, Resource<Error>>>> {
         self.observe_function_call(
             "keyvalue::cache::future_get_or_set",
             "future_get_or_set_result_get",
         );
-        unimplemented!("future_get_or_set_result_get")
+        let entry = self.table().get_mut(&self_)?;
+        let key = entry.key.clone();
+        // A vacancy must only be handed out once, so the result is consumed by the first call
+        let result = entry.result.take().unwrap_or_else(|| {
+            Err("The result of get-or-set has already been consumed".to_string())
+        });
+        match result {
+            Ok(Some(value)) => {
+                let incoming_value = self.table().push(IncomingValueEntry::new(value))?;
+                Ok(Some(Ok(GetOrSetEntry::Occupied(incoming_value))))
+            }
+            Ok(None) => {
+                let vacancy = self.table().push(VacancyEntry::new(key))?;
+                Ok(Some(Ok(GetOrSetEntry::Vacant(vacancy))))
+            }
+            Err(error) => {
+                let error = self.table().push(ErrorEntry::new(error))?;
+                Ok(Some(Err(error)))
+            }
+        }
     }
 
     async fn listen_to_future_get_or_set_result(
         &mut self,
-        _self_: Resource<FutureGetOrSetResult>,
+        self_: Resource<FutureGetOrSetResult>,
     ) -> anyhow::Result<Resource<Pollable>> {
         self.observe_function_call(
             "keyvalue::cache::future_get_or_set",
             "listen_to_future_get_or_set_result",
         );
-        unimplemented!("listen_to_future_get_or_set_result")
+        subscribe(self.table(), self_, None)
     }
 
-    async fn drop(&mut self, _rep: Resource<FutureGetOrSetResult>) -> anyhow::Result<()> {
+    async fn drop(&mut self, rep: Resource<FutureGetOrSetResult>) -> anyhow::Result<()> {
         self.observe_function_call("keyvalue::cache::future_get_or_set", "drop");
-        unimplemented!("drop")
+        self.table().delete(rep)?;
+        Ok(())
     }
 }
 
 impl<Ctx: WorkerCtx> HostVacancy for DurableWorkerCtx<Ctx> {
     async fn vacancy_fill(
         &mut self,
-        _self_: Resource<Vacancy>,
-        _ttl_ms: Option<u32>,
+        self_: Resource<Vacancy>,
+        ttl_ms: Option<u32>,
     ) -> anyhow::Result<Resource<OutgoingValue>> {
         self.observe_function_call("keyvalue::cache::vacancy", "vacancy_fill");
-        unimplemented!("vacancy_fill")
+        let outgoing_value = OutgoingValueEntry::new();
+        let body = outgoing_value.body.clone();
+        self.table().get_mut(&self_)?.fill = Some((body, ttl_ms));
+        let outgoing_value = self.table().push(outgoing_value)?;
+        Ok(outgoing_value)
     }
 
-    async fn drop(&mut self, _rep: Resource<Vacancy>) -> anyhow::Result<()> {
+    async fn drop(&mut self, rep: Resource<Vacancy>) -> anyhow::Result<()> {
         self.observe_function_call("keyvalue::cache::vacancy", "drop");
-        unimplemented!("drop")
+        let vacancy = self.table().delete(rep)?;
+
+        // The value written into the filled vacancy is stored when the vacancy is dropped
+        if let Some((body, ttl_ms)) = vacancy.fill {
+            let value = body.read().unwrap().clone();
+            // Errors cannot be reported from a drop - the cache is allowed to lose entries
+            let _ = self
+                .durable_cache_set("vacancy_fill", vacancy.key, value, ttl_ms)
+                .await?;
+        }
+        Ok(())
     }
 }
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
-    async fn get(&mut self, _k: Key) -> anyhow::Result<Resource<FutureGetResult>> {
-        self.observe_function_call("keyvalue::cache", "get");
-        unimplemented!("get")
+    async fn get(&mut self, k: Key) -> anyhow::Result<Resource<FutureGetResult>> {
+        let result = self.durable_cache_get("get", k).await?;
+        let future = self.table().push(FutureGetResultEntry { result })?;
+        Ok(future)
     }
 
-    async fn exists(&mut self, _k: Key) -> anyhow::Result<Resource<FutureExistsResult>> {
-        self.observe_function_call("keyvalue::cache", "exists");
-        unimplemented!("exists")
+    async fn exists(&mut self, k: Key) -> anyhow::Result<Resource<FutureExistsResult>> {
+        let result = self
+            .durable_cache_get("exists", k)
+            .await?
+            .map(|value| value.is_some());
+        let future = self.table().push(FutureExistsResultEntry { result })?;
+        Ok(future)
     }
 
     async fn set(
         &mut self,
-        _k: Key,
-        _v: Resource<OutgoingValue>,
-        _ttl_ms: Option<u32>,
+        k: Key,
+        v: Resource<OutgoingValue>,
+        ttl_ms: Option<u32>,
     ) -> anyhow::Result<Resource<FutureResult>> {
-        self.observe_function_call("keyvalue::cache", "set");
-        unimplemented!("set")
+        let value = self
+            .table()
+            .get::<OutgoingValueEntry>(&v)?
+            .body
+            .read()
+            .unwrap()
+            .clone();
+        let result = self.durable_cache_set("set", k, value, ttl_ms).await?;
+        let future = self.table().push(FutureResultEntry { result })?;
+        Ok(future)
+    }
+
+    async fn get_or_set(&mut self, k: Key) -> anyhow::Result<Resource<FutureGetOrSetResult>> {
+        let result = self.durable_cache_get("get_or_set", k.clone()).await?;
+        let future = self.table().push(FutureGetOrSetResultEntry {
+            key: k,
+            result: Some(result),
+        })?;
+        Ok(future)
+    }
+
+    async fn delete(&mut self, k: Key) -> anyhow::Result<Resource<FutureResult>> {
+        let project_id = self.owned_worker_id.project_id();
+
+        let durability = Durability::<(), SerializableError>::new(
+            self,
+            "golem keyvalue::cache",
+            "delete",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let result = self
+                .state
+                .key_value_service
+                .cache_delete(project_id, k.clone())
+                .await;
+            durability.persist(self, k, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        let future = self.table().push(FutureResultEntry {
+            result: result.map_err(|e| format!("{e:?}")),
+        })?;
+        Ok(future)
+    }
+}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Reads a cache entry, recording the result in the oplog so that the replay observes
+    /// the same cache state.
+    async fn durable_cache_get(
+        &mut self,
+        function: &'static str,
+        key: Key,
+    ) -> anyhow::Result<Result<Option<Vec<u8>>, String>> {
+        let project_id = self.owned_worker_id.project_id();
+
+        let durability = Durability::<Option<Vec<u8>>, SerializableError>::new(
+            self,
+            "golem keyvalue::cache",
+            function,
+            DurableFunctionType::ReadRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let result = self
+                .state
+                .key_value_service
+                .cache_get(project_id, key.clone())
+                .await;
+            durability.persist(self, key, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        Ok(result.map_err(|e: anyhow::Error| format!("{e:?}")))
     }
 
-    async fn get_or_set(&mut self, _k: Key) -> anyhow::Result<Resource<FutureGetOrSetResult>> {
-        self.observe_function_call("keyvalue::cache", "get_or_set");
-        unimplemented!("get_or_set")
+    async fn durable_cache_set(
+        &mut self,
+        function: &'static str,
+        key: Key,
+        value: Vec<u8>,
+        ttl_ms: Option<u32>,
+    ) -> anyhow::Result<Result<(), String>> {
+        let project_id = self.owned_worker_id.project_id();
+
+        let durability = Durability::<(), SerializableError>::new(
+            self,
+            "golem keyvalue::cache",
+            function,
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let input = (key.clone(), value.len() as u64, ttl_ms);
+            let result = self
+                .state
+                .key_value_service
+                .cache_set(
+                    project_id,
+                    key,
+                    value,
+                    ttl_ms.map(|ttl_ms| Duration::from_millis(ttl_ms as u64)),
+                )
+                .await;
+            durability.persist(self, input, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        Ok(result.map_err(|e: anyhow::Error| format!("{e:?}")))
     }
+}
+
+pub struct FutureGetResultEntry {
+    result: Result<Option<Vec<u8>>, String>,
+}
+
+#[async_trait]
+impl wasmtime_wasi::Pollable for FutureGetResultEntry {
+    async fn ready(&mut self) {}
+}
+
+pub struct FutureExistsResultEntry {
+    result: Result<bool, String>,
+}
+
+#[async_trait]
+impl wasmtime_wasi::Pollable for FutureExistsResultEntry {
+    async fn ready(&mut self) {}
+}
+
+pub struct FutureResultEntry {
+    result: Result<(), String>,
+}
+
+#[async_trait]
+impl wasmtime_wasi::Pollable for FutureResultEntry {
+    async fn ready(&mut self) {}
+}
+
+pub struct FutureGetOrSetResultEntry {
+    key: String,
+    result: Option<Result<Option<Vec<u8>>, String>>,
+}
+
+#[async_trait]
+impl wasmtime_wasi::Pollable for FutureGetOrSetResultEntry {
+    async fn ready(&mut self) {}
+}
+
+pub struct VacancyEntry {
+    key: String,
+    fill: Option<(Arc<RwLock<Vec<u8>>>, Option<u32>)>,
+}
 
-    async fn delete(&mut self, _k: Key) -> anyhow::Result<Resource<FutureResult>> {
-        self.observe_function_call("keyvalue::cache", "delete");
-        unimplemented!("delete")
+impl VacancyEntry {
+    pub fn new(key: String) -> Self {
+        Self { key, fill: None }
     }
 }
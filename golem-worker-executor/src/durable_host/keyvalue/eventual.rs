@@ -112,7 +112,7 @@ impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
             let result = self
                 .state
                 .key_value_service
-                .set(project_id, bucket, key, outgoing_value)
+                .set(project_id, bucket, key, outgoing_value, None)
                 .await;
             durability.persist(self, input, result).await
         } else {
@@ -0,0 +1,147 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use golem_common::model::oplog::DurableFunctionType;
+use wasmtime::component::Resource;
+use wasmtime_wasi::IoView;
+
+use crate::durable_host::keyvalue::error::ErrorEntry;
+use crate::durable_host::keyvalue::types::{BucketEntry, OutgoingValueEntry};
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurableWorkerCtx};
+use crate::preview2::golem::keyvalue::extensions::{
+    Bucket, Error, Host, Key, KeyPage, OutgoingValue,
+};
+use crate::storage::keyvalue;
+use crate::workerctx::WorkerCtx;
+
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {
+    async fn set_with_ttl(
+        &mut self,
+        bucket: Resource<Bucket>,
+        key: Key,
+        outgoing_value: Resource<OutgoingValue>,
+        ttl_ms: u64,
+    ) -> anyhow::Result<Result<(), Resource<Error>>> {
+        let project_id = self.owned_worker_id.project_id();
+        let bucket = self
+            .as_wasi_view()
+            .table()
+            .get::<BucketEntry>(&bucket)?
+            .name
+            .clone();
+        let outgoing_value = self
+            .as_wasi_view()
+            .table()
+            .get::<OutgoingValueEntry>(&outgoing_value)?
+            .body
+            .read()
+            .unwrap()
+            .clone();
+
+        let durability = Durability::<(), SerializableError>::new(
+            self,
+            "golem keyvalue::extensions",
+            "set_with_ttl",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let input = (
+                bucket.clone(),
+                key.clone(),
+                outgoing_value.len() as u64,
+                ttl_ms,
+            );
+            let result = self
+                .state
+                .key_value_service
+                .set(
+                    project_id,
+                    bucket,
+                    key,
+                    outgoing_value,
+                    Some(Duration::from_millis(ttl_ms)),
+                )
+                .await;
+            durability.persist(self, input, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        match result {
+            Ok(()) => Ok(Ok(())),
+            Err(e) => {
+                let error = self
+                    .as_wasi_view()
+                    .table()
+                    .push(ErrorEntry::new(format!("{e:?}")))?;
+                Ok(Err(error))
+            }
+        }
+    }
+
+    async fn list_keys(
+        &mut self,
+        bucket: Resource<Bucket>,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        limit: u32,
+    ) -> anyhow::Result<Result<KeyPage, Resource<Error>>> {
+        let project_id = self.owned_worker_id.project_id();
+        let bucket = self
+            .as_wasi_view()
+            .table()
+            .get::<BucketEntry>(&bucket)?
+            .name
+            .clone();
+
+        let durability = Durability::<keyvalue::KeyPage, SerializableError>::new(
+            self,
+            "golem keyvalue::extensions",
+            "list_keys",
+            DurableFunctionType::ReadRemote,
+        )
+        .await?;
+
+        let result = if durability.is_live() {
+            let input = (bucket.clone(), prefix.clone(), cursor.clone(), limit);
+            let result = self
+                .state
+                .key_value_service
+                .list_keys(project_id, bucket, prefix, cursor, limit as u64)
+                .await;
+            durability.persist(self, input, result).await
+        } else {
+            durability.replay(self).await
+        };
+
+        match result {
+            Ok(page) => Ok(Ok(KeyPage {
+                keys: page.keys,
+                cursor: page.cursor,
+            })),
+            Err(e) => {
+                let error = self
+                    .as_wasi_view()
+                    .table()
+                    .push(ErrorEntry::new(format!("{e:?}")))?;
+                Ok(Err(error))
+            }
+        }
+    }
+}
@@ -34,6 +34,9 @@ pub mod serialized;
 mod sockets;
 pub mod wasm_rpc;
 
+use crate::durable_host::filesystem::usage::FileSystemUsage;
+use crate::durable_host::http::fixtures::FixtureReplayer;
+use crate::durable_host::http::policy;
 use crate::durable_host::http::serialized::SerializableHttpRequest;
 use crate::durable_host::io::{ManagedStdErr, ManagedStdIn, ManagedStdOut};
 use crate::durable_host::replay_state::ReplayState;
@@ -46,15 +49,18 @@ use crate::model::{
 };
 use crate::services::blob_store::BlobStoreService;
 use crate::services::component::ComponentService;
-use crate::services::file_loader::{FileLoader, FileUseToken};
+use crate::services::file_loader::{FileLoader, FileUseToken, WorkerFilesArchive};
 use crate::services::golem_config::GolemConfig;
 use crate::services::key_value::KeyValueService;
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps, OplogService};
 use crate::services::plugins::Plugins;
 use crate::services::projects::ProjectService;
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rdbms::RdbmsService;
+use crate::services::resource_limits::ResourceLimits;
 use crate::services::rpc::Rpc;
+use crate::services::saga::{abort_running_sagas, resume_compensations, SagaService};
 use crate::services::scheduler::SchedulerService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
@@ -125,9 +131,7 @@ use wasmtime_wasi::p2::bindings::filesystem::preopens::Descriptor;
 use wasmtime_wasi::p2::{FsResult, Stderr, Stdout, WasiCtx, WasiImpl, WasiView};
 use wasmtime_wasi::{I32Exit, IoCtx, IoImpl, IoView, ResourceTable, ResourceTableError};
 use wasmtime_wasi_http::body::HyperOutgoingBody;
-use wasmtime_wasi_http::types::{
-    default_send_request, HostFutureIncomingResponse, OutgoingRequestConfig,
-};
+use wasmtime_wasi_http::types::{HostFutureIncomingResponse, OutgoingRequestConfig};
 use wasmtime_wasi_http::{HttpResult, WasiHttpCtx, WasiHttpImpl, WasiHttpView};
 
 /// Partial implementation of the WorkerCtx interfaces for adding durable execution to workers.
@@ -148,6 +152,8 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
     pub async fn create(
         owned_worker_id: OwnedWorkerId,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -168,6 +174,7 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         plugins: Arc<dyn Plugins>,
         worker_fork: Arc<dyn WorkerForkService>,
         project_service: Arc<dyn ProjectService>,
+        resource_limits: Arc<dyn ResourceLimits>,
     ) -> Result<Self, WorkerExecutorError> {
         let temp_dir = Arc::new(tempfile::Builder::new().prefix("golem").tempdir().map_err(
             |e| WorkerExecutorError::runtime(format!("Failed to create temporary directory: {e}")),
@@ -202,6 +209,45 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             &component_metadata.files,
         )
         .await?;
+        let read_only_paths = compute_read_only_paths(&files);
+
+        if let Some(skipped_history) = worker_config
+            .deleted_regions
+            .find_next_deleted_region(OplogIndex::INITIAL.next())
+            .filter(|region| region.start == OplogIndex::INITIAL.next())
+        {
+            // The history was skipped by a snapshot-based update, restoring the files archived before it
+            if file_loader
+                .restore_worker_files_for_update(
+                    &owned_worker_id,
+                    skipped_history.end,
+                    temp_dir.path(),
+                    &read_only_paths,
+                )
+                .await?
+            {
+                debug!(
+                    "Restored the file system of worker {} archived for the update at {}",
+                    owned_worker_id.worker_id, skipped_history.end
+                );
+            }
+        }
+
+        let file_system_usage_counter = invocation_queue
+            .upgrade()
+            .map(|worker| worker.file_system_usage_counter())
+            .unwrap_or_default();
+        // The latest archive of the worker has been counted for its account when it was taken
+        let archived_size = file_loader.archived_size(&owned_worker_id).await?;
+        let mut file_system_usage = FileSystemUsage::new(
+            worker_config.created_by.clone(),
+            resource_limits,
+            config.limits.max_file_system_size_per_worker,
+            temp_dir.path().to_path_buf(),
+            archived_size,
+            file_system_usage_counter,
+        );
+        file_system_usage.measure(&read_only_paths).await?;
 
         // TODO: pass config vars from component metadata
         let wasi_config_vars = effective_wasi_config_vars(
@@ -243,6 +289,8 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 oplog_service,
                 oplog,
                 promise_service,
+                pubsub_service,
+                saga_service,
                 scheduler_service,
                 worker_service,
                 worker_enumeration_service,
@@ -260,13 +308,14 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
                 component_metadata,
                 worker_config.total_linear_memory_size,
                 worker_fork,
-                RwLock::new(compute_read_only_paths(&files)),
+                RwLock::new(read_only_paths),
                 TRwLock::new(files),
                 file_loader,
                 project_service,
                 worker_config.created_by.clone(),
                 worker_config.initial_wasi_config_vars,
                 wasi_config_vars,
+                file_system_usage,
             )
             .await,
             temp_dir,
@@ -308,6 +357,32 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         }
     }
 
+    /// Accounts for writing `amount` bytes to the worker's file system. Returns false if the write
+    /// would exceed the worker's or its account's file system quota; writes are never rejected during replay.
+    async fn reserve_file_system_space(
+        &mut self,
+        amount: u64,
+    ) -> Result<bool, WorkerExecutorError> {
+        let enforce = self.state.is_live();
+        let read_only_paths = self.state.read_only_paths.read().unwrap().clone();
+        self.state
+            .file_system_usage
+            .reserve(amount, enforce, &read_only_paths)
+            .await
+    }
+
+    fn register_file_output_stream(&mut self, rep: u32) {
+        self.state.file_output_streams.insert(rep);
+    }
+
+    fn is_file_output_stream(&self, rep: u32) -> bool {
+        self.state.file_output_streams.contains(&rep)
+    }
+
+    fn unregister_file_output_stream(&mut self, rep: u32) {
+        self.state.file_output_streams.remove(&rep);
+    }
+
     fn ctx(&mut self) -> &mut WasiCtx {
         Arc::get_mut(&mut self.wasi)
             .expect("WasiCtx is shared and cannot be borrowed mutably")
@@ -374,6 +449,11 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
             .overridden_retry_config
             .clone_from(&self.state.overridden_retry_policy);
         status.pending_invocations = self.public_state.worker().pending_invocations().await;
+        status.invocation_priorities = self
+            .public_state
+            .worker()
+            .pending_invocation_priorities()
+            .await;
         status.invocation_results = self.public_state.worker().invocation_results().await;
         status.pending_updates = pending_updates;
         status
@@ -748,6 +828,69 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> DurableWorkerCtx<Ctx> {
 }
 
 impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Archives the worker's file system after the invocation completed at `oplog_index`. Failing to
+    /// do so does not fail the invocation, as replaying the oplog recreates the files anyway.
+    async fn archive_worker_files(&mut self, oplog_index: OplogIndex) {
+        let read_only_paths = self.state.read_only_paths.read().unwrap().clone();
+        match self
+            .state
+            .file_loader
+            .archive_worker_files(
+                &self.owned_worker_id,
+                oplog_index,
+                self.temp_dir.path(),
+                &read_only_paths,
+                self.state.files_archive.as_ref(),
+            )
+            .await
+        {
+            Ok(archive) => {
+                self.state
+                    .file_system_usage
+                    .set_archived(archive.as_ref().map(|archive| archive.size()).unwrap_or(0));
+                self.state.files_archive = archive;
+            }
+            Err(err) => warn!("Failed to archive the file system of the worker: {err}"),
+        }
+    }
+
+    /// Restores the file system archived after the last replayed invocation, so the worker continues
+    /// with exactly the files it had when that invocation completed, on whichever executor it was running.
+    pub async fn restore_worker_files(&mut self) -> Result<(), WorkerExecutorError> {
+        let Some(oplog_index) = self.state.replayed_invocation_completed_at.take() else {
+            return Ok(());
+        };
+
+        let read_only_paths = self.state.read_only_paths.read().unwrap().clone();
+        match self
+            .state
+            .file_loader
+            .restore_worker_files(
+                &self.owned_worker_id,
+                oplog_index,
+                self.temp_dir.path(),
+                &read_only_paths,
+            )
+            .await
+        {
+            Ok(Some(archive)) => {
+                debug!("Restored the file system archived at {oplog_index}");
+                self.state.file_system_usage.set_archived(archive.size());
+                self.state.files_archive = Some(archive);
+                self.state
+                    .file_system_usage
+                    .measure(&read_only_paths)
+                    .await?;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                // The replayed invocations have already recreated the files
+                warn!("Failed to restore the file system archived at {oplog_index}: {err}")
+            }
+        }
+        Ok(())
+    }
+
     pub async fn process_pending_replay_events(&mut self) -> Result<(), WorkerExecutorError> {
         debug!("Applying pending side effects accumulated during replay");
 
@@ -848,8 +991,15 @@ impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
         )
         .await?;
 
-        let mut read_only_paths = self.state.read_only_paths.write().unwrap();
-        *read_only_paths = compute_read_only_paths(&current_files);
+        let read_only_paths = compute_read_only_paths(&current_files);
+        drop(current_files);
+        *self.state.read_only_paths.write().unwrap() = read_only_paths.clone();
+
+        // The update may have replaced or removed files written by the worker
+        self.state
+            .file_system_usage
+            .measure(&read_only_paths)
+            .await?;
 
         // TODO: take config vars from component metadata
         let mut wasi_config_vars = self.state.wasi_config_vars.write().unwrap();
@@ -1019,6 +1169,16 @@ impl<Ctx: WorkerCtx> StatusManagement for DurableWorkerCtx<Ctx> {
                 )
                 .await;
         }
+        if status == WorkerStatus::Idle || status == WorkerStatus::Exited {
+            self.state
+                .scheduler_service
+                .schedule_ttl_check(
+                    &self.state.created_by,
+                    &self.owned_worker_id,
+                    self.public_state.oplog.current_oplog_index().await,
+                )
+                .await;
+        }
     }
 
     async fn update_pending_invocations(&self) {
@@ -1117,6 +1277,23 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
         self.store_worker_status(updated_worker_status.clone())
             .await;
 
+        if updated_worker_status == WorkerStatus::Failed {
+            // The coordinator cannot finish its sagas anymore, so the performed steps get compensated
+            if let Err(err) = abort_running_sagas(
+                self.state.saga_service.clone(),
+                self.rpc(),
+                &self.owned_worker_id,
+                self.created_by(),
+                &retry_config,
+                OplogIndex::NONE,
+                "The coordinator worker failed",
+            )
+            .await
+            {
+                warn!("Failed to abort the running sagas of the failed worker: {err}");
+            }
+        }
+
         if store_result {
             // Giving up, associating the stored result with the current and upcoming invocations
             if let Some(idempotency_key) = self.state.get_current_idempotency_key() {
@@ -1161,6 +1338,9 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                         .store_invocation_success(&idempotency_key, output.clone(), oplog_idx)
                         .await;
                 }
+
+                self.state.replayed_invocation_completed_at = None;
+                self.archive_worker_files(oplog_idx).await;
             }
         } else {
             let response = self
@@ -1177,6 +1357,8 @@ impl<Ctx: WorkerCtx> InvocationHooks for DurableWorkerCtx<Ctx> {
                         format!("{full_function_name}({function_input:?}) => {output:?}"),
                     ));
                 }
+                self.state.replayed_invocation_completed_at =
+                    Some(self.state.replay_state.last_replayed_index());
             }
         }
 
@@ -1268,6 +1450,24 @@ impl<Ctx: WorkerCtx> UpdateManagement for DurableWorkerCtx<Ctx> {
             .expect("Not in snapshotting mode");
     }
 
+    async fn on_worker_update_snapshot_saved(&self) -> Result<(), WorkerExecutorError> {
+        // The snapshot-based update is going to skip the whole history of the worker, including
+        // the file system writes which would otherwise be recreated by replaying it. The archive
+        // is associated with the index of the pending update entry, which is the end of the
+        // skipped region used when the worker gets recreated.
+        let update_index = self.public_state.oplog.current_oplog_index().await.next();
+        let read_only_paths = self.state.read_only_paths.read().unwrap().clone();
+        self.state
+            .file_loader
+            .archive_worker_files_for_update(
+                &self.owned_worker_id,
+                update_index,
+                self.temp_dir.path(),
+                &read_only_paths,
+            )
+            .await
+    }
+
     async fn on_worker_update_failed(
         &self,
         target_version: ComponentVersion,
@@ -1724,6 +1924,12 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                 match oplog_entry {
                     Err(error) => break Err(error),
                     Ok(None) => {
+                        store
+                            .as_context_mut()
+                            .data_mut()
+                            .durable_ctx_mut()
+                            .restore_worker_files()
+                            .await?;
                         store
                             .as_context_mut()
                             .data_mut()
@@ -1922,6 +2128,12 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
                     }
                 }
             } else {
+                store
+                    .as_context_mut()
+                    .data_mut()
+                    .durable_ctx_mut()
+                    .restore_worker_files()
+                    .await?;
                 store
                     .as_context_mut()
                     .data_mut()
@@ -2096,6 +2308,10 @@ impl<Ctx: WorkerCtx + DurableWorkerCtxView<Ctx>> ExternalOperations<Ctx> for Dur
             .on_shard_assignment_changed()
             .await?;
 
+        // Compensations interrupted by stopping an executor are continued by the new owner of the
+        // coordinator's shard
+        resume_compensations(this).await?;
+
         info!("Recovering workers");
 
         let workers = this.worker_service().get_running_workers_in_shards().await;
@@ -2471,10 +2687,25 @@ struct HttpRequestState {
     pub span_id: SpanId,
 }
 
+/// State associated with TCP connections opened by the guest, on top of the underlying wasi-sockets implementation
+#[derive(Debug, Clone)]
+struct TcpConnectionState {
+    /// The BeginRemoteWrite entry's index
+    pub begin_index: OplogIndex,
+    /// The remote address of the connection, to be included in the oplog
+    pub remote_address: String,
+    /// Resource id of the TCP socket the connection belongs to
+    pub socket: u32,
+    /// Whether the connection was only replayed from the oplog, without an underlying live connection
+    pub recovered: bool,
+}
+
 struct PrivateDurableWorkerState {
     oplog_service: Arc<dyn OplogService>,
     oplog: Arc<dyn Oplog>,
     promise_service: Arc<dyn PromiseService>,
+    pubsub_service: Arc<dyn PubSubService>,
+    saga_service: Arc<dyn SagaService>,
     scheduler_service: Arc<dyn SchedulerService>,
     worker_service: Arc<dyn WorkerService>,
     worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
@@ -2499,6 +2730,11 @@ struct PrivateDurableWorkerState {
     /// State of ongoing http requests, key is the resource id it is most recently associated with (one state object can belong to multiple resources, but just one at once)
     open_http_requests: HashMap<u32, HttpRequestState>,
 
+    /// State of open TCP connections, key is the resource id of one of the connection's input or output streams
+    open_tcp_connections: HashMap<u32, TcpConnectionState>,
+    /// Outcome of TCP connects not yet collected by finish-connect, key is the socket's resource id
+    pending_tcp_connects: HashMap<u32, Result<(u32, u32), SerializableError>>,
+
     snapshotting_mode: Option<PersistenceLevel>,
 
     indexed_resources: HashMap<IndexedResourceKey, WorkerResourceId>,
@@ -2524,6 +2760,18 @@ struct PrivateDurableWorkerState {
     initial_wasi_config_vars: BTreeMap<String, String>,
     /// The current config vars of the worker, taking into account component version, etc.
     wasi_config_vars: RwLock<BTreeMap<String, String>>,
+
+    file_system_usage: FileSystemUsage,
+    /// Resource ids of the output streams writing to files, which are subject to the file system quotas
+    file_output_streams: HashSet<u32>,
+
+    /// Serves the outgoing HTTP requests when the executor is configured to use fixtures
+    http_fixtures: Option<FixtureReplayer>,
+
+    /// The latest archive of the worker's file system, used to upload only the changed files
+    files_archive: Option<WorkerFilesArchive>,
+    /// Oplog index of the last invocation completed during replay, whose file archive gets restored when the replay ends
+    replayed_invocation_completed_at: Option<OplogIndex>,
 }
 
 impl PrivateDurableWorkerState {
@@ -2532,6 +2780,8 @@ impl PrivateDurableWorkerState {
         oplog_service: Arc<dyn OplogService>,
         oplog: Arc<dyn Oplog>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         scheduler_service: Arc<dyn SchedulerService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
@@ -2556,6 +2806,7 @@ impl PrivateDurableWorkerState {
         created_by: AccountId,
         initial_wasi_config_vars: BTreeMap<String, String>,
         wasi_config_vars: BTreeMap<String, String>,
+        file_system_usage: FileSystemUsage,
     ) -> Self {
         let replay_state = ReplayState::new(
             owned_worker_id.clone(),
@@ -2567,10 +2818,17 @@ impl PrivateDurableWorkerState {
         .await;
         let invocation_context = InvocationContext::new(None);
         let current_span_id = invocation_context.root.span_id().clone();
+        let http_fixtures = config
+            .outgoing_http
+            .fixtures
+            .clone()
+            .map(FixtureReplayer::new);
         Self {
             oplog_service,
             oplog: oplog.clone(),
             promise_service,
+            pubsub_service,
+            saga_service,
             scheduler_service,
             worker_service,
             worker_enumeration_service,
@@ -2590,6 +2848,8 @@ impl PrivateDurableWorkerState {
             persistence_level: PersistenceLevel::Smart,
             assume_idempotence: true,
             open_http_requests: HashMap::new(),
+            open_tcp_connections: HashMap::new(),
+            pending_tcp_connects: HashMap::new(),
             snapshotting_mode: None,
             indexed_resources: HashMap::new(),
             agent_instances: HashMap::new(),
@@ -2608,6 +2868,11 @@ impl PrivateDurableWorkerState {
             created_by,
             initial_wasi_config_vars,
             wasi_config_vars: RwLock::new(wasi_config_vars),
+            file_system_usage,
+            file_output_streams: HashSet::new(),
+            http_fixtures,
+            files_archive: None,
+            replayed_invocation_completed_at: None,
         }
     }
 
@@ -2657,22 +2922,7 @@ impl PrivateDurableWorkerState {
                         )
                         .await;
                     if end_index.is_none() {
-                        // We need to jump to the end of the oplog
-                        self.replay_state.switch_to_live().await;
-
-                        // But this is not enough, because if the retried batched write operation succeeds,
-                        // and later we replay it, we need to skip the first attempt and only replay the second.
-                        // Se we add a Jump entry to the oplog that registers a deleted region.
-                        let deleted_region = OplogRegion {
-                            start: begin_index.next(), // need to keep the BeginAtomicRegion entry
-                            end: self.replay_state.replay_target().next(), // skipping the Jump entry too
-                        };
-                        self.replay_state
-                            .add_skipped_region(deleted_region.clone())
-                            .await;
-                        self.oplog
-                            .add_and_commit(OplogEntry::jump(deleted_region))
-                            .await;
+                        self.retry_remote_write(begin_index).await;
                     }
 
                     Ok(begin_index)
@@ -2686,6 +2936,56 @@ impl PrivateDurableWorkerState {
         }
     }
 
+    /// Begins a batched remote write whose outcome can be recovered from the remote side.
+    ///
+    /// Unlike `begin_function`, when replaying a batch that has never been completed, it does not
+    /// decide whether to retry it. Instead, the returned flag is `false`, and the caller has to
+    /// either continue replaying the batch, or call `retry_remote_write`.
+    pub async fn begin_recoverable_remote_write(
+        &mut self,
+    ) -> Result<(OplogIndex, bool), WorkerExecutorError> {
+        if self.is_live() {
+            self.oplog
+                .add_and_commit(OplogEntry::begin_remote_write())
+                .await;
+            let begin_index = self.oplog.current_oplog_index().await;
+            Ok((begin_index, true))
+        } else {
+            let (begin_index, _) =
+                crate::get_oplog_entry!(self.replay_state, OplogEntry::BeginRemoteWrite)?;
+            let end_index = self
+                .replay_state
+                .lookup_oplog_entry_with_condition(
+                    begin_index,
+                    OplogEntry::is_end_remote_write,
+                    OplogEntry::no_concurrent_side_effect,
+                )
+                .await;
+            Ok((begin_index, end_index.is_some()))
+        }
+    }
+
+    /// Abandons an incomplete batched remote write found during replay, and continues in live
+    /// mode so the batch gets executed again
+    pub async fn retry_remote_write(&mut self, begin_index: OplogIndex) {
+        // We need to jump to the end of the oplog
+        self.replay_state.switch_to_live().await;
+
+        // But this is not enough, because if the retried batched write operation succeeds,
+        // and later we replay it, we need to skip the first attempt and only replay the second.
+        // Se we add a Jump entry to the oplog that registers a deleted region.
+        let deleted_region = OplogRegion {
+            start: begin_index.next(), // need to keep the BeginAtomicRegion entry
+            end: self.replay_state.replay_target().next(), // skipping the Jump entry too
+        };
+        self.replay_state
+            .add_skipped_region(deleted_region.clone())
+            .await;
+        self.oplog
+            .add_and_commit(OplogEntry::jump(deleted_region))
+            .await;
+    }
+
     pub async fn end_function(
         &mut self,
         function_type: &DurableFunctionType,
@@ -2735,7 +3035,7 @@ impl PrivateDurableWorkerState {
     pub async fn sleep_until(&self, when: DateTime<Utc>) -> Result<(), WorkerExecutorError> {
         let promise_id = self
             .promise_service
-            .create(
+            .create_internal(
                 &self.owned_worker_id.worker_id,
                 self.current_oplog_index().await,
             )
@@ -2955,7 +3255,19 @@ impl<Ctx: WorkerCtx> WasiHttpView for DurableWorkerCtxWasiHttpView<'_, Ctx> {
             // or poll the response future.
             Ok(HostFutureIncomingResponse::deferred(request, config))
         } else {
-            Ok(default_send_request(request, config))
+            match &mut self.0.state.http_fixtures {
+                Some(fixtures) => Ok(fixtures.send_request(&request, &config)),
+                None => {
+                    let host_policy = self
+                        .0
+                        .state
+                        .config
+                        .outgoing_http
+                        .policy(request.uri().host().unwrap_or_default())
+                        .clone();
+                    Ok(policy::send_request(request, config, host_policy))
+                }
+            }
         }
     }
 }
@@ -0,0 +1,431 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::rdbms::{DbTransaction, Error as RdbmsError, Rdbms, RdbmsPoolKey, RdbmsType};
+use golem_common::base_model::OplogIndex;
+use golem_common::model::WorkerId;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use uuid::Uuid;
+
+const TABLE: &str = "golem_transaction_markers";
+
+/// Commit marker of a transaction.
+///
+/// When a transaction is committed, a row identified by the worker and the oplog index of the
+/// transaction's remote write region is inserted into the marker table as part of the same
+/// transaction. If the worker crashes after the commit reached the database but before it got
+/// recorded in the oplog, the presence of this row tells the recovering executor that the
+/// transaction must not be executed again.
+///
+/// Markers are removed once the commit is recorded. Markers left behind by workers which never
+/// recovered are removed after the configured retention, when the table is first used through
+/// a connection.
+#[derive(Clone, Debug)]
+pub(super) struct TransactionMarker {
+    id: String,
+    table_created: Arc<AtomicBool>,
+    retention: Duration,
+}
+
+/// How the replay of a transaction continues
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TransactionRecovery {
+    /// The whole transaction is recorded in the oplog
+    Replay,
+    /// The transaction was committed but the worker stopped before recording the commit, so the
+    /// replay continues and the commit completes without reaching the database again
+    ReplayCommitted,
+    /// The transaction was never committed, so it has to be executed again
+    Retry,
+}
+
+impl TransactionMarker {
+    pub(super) fn new(
+        worker_id: &WorkerId,
+        begin_index: OplogIndex,
+        table_created: Arc<AtomicBool>,
+        retention: Duration,
+    ) -> Self {
+        // The id is inlined into the statements, which is safe because it is always a UUID
+        let id = Uuid::new_v5(
+            &Uuid::NAMESPACE_OID,
+            format!("{worker_id}/{begin_index}").as_bytes(),
+        )
+        .to_string();
+        Self {
+            id,
+            table_created,
+            retention,
+        }
+    }
+
+    /// Decides how to continue replaying the transaction, `completed` telling whether its
+    /// remote write region has an end in the oplog
+    pub(super) async fn recover<T: RdbmsType + 'static>(
+        &self,
+        completed: bool,
+        rdbms: &Arc<dyn Rdbms<T> + Send + Sync>,
+        pool_key: &RdbmsPoolKey,
+        worker_id: &WorkerId,
+    ) -> Result<TransactionRecovery, RdbmsError> {
+        if completed {
+            Ok(TransactionRecovery::Replay)
+        } else if self.exists(rdbms, pool_key, worker_id).await? {
+            Ok(TransactionRecovery::ReplayCommitted)
+        } else {
+            Ok(TransactionRecovery::Retry)
+        }
+    }
+
+    pub(super) async fn create_table<T: RdbmsType + 'static>(
+        &self,
+        rdbms: &Arc<dyn Rdbms<T> + Send + Sync>,
+        pool_key: &RdbmsPoolKey,
+        worker_id: &WorkerId,
+    ) -> Result<(), RdbmsError> {
+        if !self.table_created.load(Ordering::Acquire) {
+            rdbms
+                .execute(
+                    pool_key,
+                    worker_id,
+                    T::create_transaction_markers_table_statement(),
+                    vec![],
+                )
+                .await?;
+            self.remove_expired(rdbms, pool_key, worker_id).await?;
+            self.table_created.store(true, Ordering::Release);
+        }
+        Ok(())
+    }
+
+    async fn remove_expired<T: RdbmsType + 'static>(
+        &self,
+        rdbms: &Arc<dyn Rdbms<T> + Send + Sync>,
+        pool_key: &RdbmsPoolKey,
+        worker_id: &WorkerId,
+    ) -> Result<(), RdbmsError> {
+        let created_before = SystemTime::now()
+            .checked_sub(self.retention)
+            .map_or(0, epoch_millis);
+        rdbms
+            .execute(
+                pool_key,
+                worker_id,
+                &format!("DELETE FROM {TABLE} WHERE created_at < {created_before}"),
+                vec![],
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub(super) async fn insert<T: RdbmsType + 'static>(
+        &self,
+        transaction: &(dyn DbTransaction<T> + Send + Sync),
+    ) -> Result<(), RdbmsError> {
+        transaction
+            .execute(
+                &format!(
+                    "INSERT INTO {TABLE} (transaction_id, created_at) VALUES ('{}', {})",
+                    self.id,
+                    epoch_millis(SystemTime::now())
+                ),
+                vec![],
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub(super) async fn exists<T: RdbmsType + 'static>(
+        &self,
+        rdbms: &Arc<dyn Rdbms<T> + Send + Sync>,
+        pool_key: &RdbmsPoolKey,
+        worker_id: &WorkerId,
+    ) -> Result<bool, RdbmsError> {
+        self.create_table(rdbms, pool_key, worker_id).await?;
+        let result = rdbms
+            .query(
+                pool_key,
+                worker_id,
+                &format!(
+                    "SELECT transaction_id FROM {TABLE} WHERE transaction_id = '{}'",
+                    self.id
+                ),
+                vec![],
+            )
+            .await?;
+        Ok(!result.rows.is_empty())
+    }
+
+    pub(super) async fn remove<T: RdbmsType + 'static>(
+        &self,
+        rdbms: &Arc<dyn Rdbms<T> + Send + Sync>,
+        pool_key: &RdbmsPoolKey,
+        worker_id: &WorkerId,
+    ) -> Result<(), RdbmsError> {
+        rdbms
+            .execute(
+                pool_key,
+                worker_id,
+                &format!("DELETE FROM {TABLE} WHERE transaction_id = '{}'", self.id),
+                vec![],
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+fn epoch_millis(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TransactionMarker, TransactionRecovery};
+    use crate::services::golem_config::RdbmsConfig;
+    use crate::services::rdbms::sqlite::SqliteType;
+    use crate::services::rdbms::{Rdbms, RdbmsPoolKey};
+    use assert2::check;
+    use golem_common::base_model::OplogIndex;
+    use golem_common::model::{ComponentId, WorkerId};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use test_r::test;
+
+    const RETENTION: Duration = Duration::from_secs(3600);
+
+    struct TestDb {
+        _dir: tempfile::TempDir,
+        rdbms: Arc<dyn Rdbms<SqliteType> + Send + Sync>,
+        pool_key: RdbmsPoolKey,
+        worker_id: WorkerId,
+    }
+
+    impl TestDb {
+        async fn new() -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let rdbms = SqliteType::new_rdbms(RdbmsConfig::default());
+            let worker_id = new_worker_id();
+            let address = format!("sqlite://{}/test.db", dir.path().display());
+            let pool_key = rdbms.create(&address, &worker_id).await.unwrap();
+            rdbms
+                .execute(
+                    &pool_key,
+                    &worker_id,
+                    "CREATE TABLE test_users (name TEXT NOT NULL)",
+                    vec![],
+                )
+                .await
+                .unwrap();
+            Self {
+                _dir: dir,
+                rdbms,
+                pool_key,
+                worker_id,
+            }
+        }
+
+        fn marker(&self, begin_index: u64) -> TransactionMarker {
+            self.marker_with_retention(begin_index, RETENTION)
+        }
+
+        /// A marker used through a new connection, which has not created the table yet
+        fn marker_with_retention(
+            &self,
+            begin_index: u64,
+            retention: Duration,
+        ) -> TransactionMarker {
+            TransactionMarker::new(
+                &self.worker_id,
+                OplogIndex::from_u64(begin_index),
+                Arc::new(AtomicBool::new(false)),
+                retention,
+            )
+        }
+
+        async fn recover(
+            &self,
+            marker: &TransactionMarker,
+            completed: bool,
+        ) -> TransactionRecovery {
+            marker
+                .recover(completed, &self.rdbms, &self.pool_key, &self.worker_id)
+                .await
+                .unwrap()
+        }
+
+        async fn marker_exists(&self, marker: &TransactionMarker) -> bool {
+            marker
+                .exists(&self.rdbms, &self.pool_key, &self.worker_id)
+                .await
+                .unwrap()
+        }
+
+        async fn user_count(&self) -> usize {
+            self.rdbms
+                .query(
+                    &self.pool_key,
+                    &self.worker_id,
+                    "SELECT name FROM test_users",
+                    vec![],
+                )
+                .await
+                .unwrap()
+                .rows
+                .len()
+        }
+
+        /// Runs a transaction inserting a user, and stops without recording anything, as if
+        /// the worker crashed right after the commit or before it
+        async fn run_interrupted_transaction(&self, marker: &TransactionMarker, commit: bool) {
+            marker
+                .create_table(&self.rdbms, &self.pool_key, &self.worker_id)
+                .await
+                .unwrap();
+            let transaction = self
+                .rdbms
+                .begin_transaction(&self.pool_key, &self.worker_id)
+                .await
+                .unwrap();
+            transaction
+                .execute("INSERT INTO test_users (name) VALUES ('user')", vec![])
+                .await
+                .unwrap();
+            marker.insert(transaction.as_ref()).await.unwrap();
+            if commit {
+                transaction.commit().await.unwrap();
+            } else {
+                // The database rolls back the open transaction of a lost connection
+                transaction.rollback_if_open().await.unwrap();
+            }
+        }
+    }
+
+    fn new_worker_id() -> WorkerId {
+        WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn marker_id_is_deterministic() {
+        let worker_id = new_worker_id();
+        let created = Arc::new(AtomicBool::new(false));
+        let marker1 = TransactionMarker::new(
+            &worker_id,
+            OplogIndex::from_u64(5),
+            created.clone(),
+            RETENTION,
+        );
+        let marker2 = TransactionMarker::new(
+            &worker_id,
+            OplogIndex::from_u64(5),
+            created.clone(),
+            RETENTION,
+        );
+        let marker3 = TransactionMarker::new(
+            &worker_id,
+            OplogIndex::from_u64(6),
+            created.clone(),
+            RETENTION,
+        );
+        let marker4 = TransactionMarker::new(
+            &new_worker_id(),
+            OplogIndex::from_u64(5),
+            created,
+            RETENTION,
+        );
+        check!(marker1.id == marker2.id);
+        check!(marker1.id != marker3.id);
+        check!(marker1.id != marker4.id);
+    }
+
+    #[test]
+    async fn crash_between_commit_and_oplog_write_is_replayed() {
+        let db = TestDb::new().await;
+        let marker = db.marker(10);
+
+        db.run_interrupted_transaction(&marker, true).await;
+
+        check!(db.recover(&marker, false).await == TransactionRecovery::ReplayCommitted);
+        check!(db.recover(&db.marker(11), false).await == TransactionRecovery::Retry);
+        check!(db.user_count().await == 1);
+
+        // Once the replayed commit is recorded, the marker gets removed
+        marker
+            .remove(&db.rdbms, &db.pool_key, &db.worker_id)
+            .await
+            .unwrap();
+        check!(!db.marker_exists(&marker).await);
+        check!(db.user_count().await == 1);
+    }
+
+    #[test]
+    async fn crash_before_commit_is_retried() {
+        let db = TestDb::new().await;
+        let marker = db.marker(10);
+
+        db.run_interrupted_transaction(&marker, false).await;
+
+        check!(db.recover(&marker, false).await == TransactionRecovery::Retry);
+        check!(db.user_count().await == 0);
+
+        // The interrupted transaction left nothing behind, so it can be executed again
+        db.run_interrupted_transaction(&marker, true).await;
+
+        check!(db.recover(&marker, false).await == TransactionRecovery::ReplayCommitted);
+        check!(db.user_count().await == 1);
+    }
+
+    #[test]
+    async fn completed_transaction_is_replayed_without_checking_the_marker() {
+        let db = TestDb::new().await;
+        let marker = db.marker(10);
+
+        check!(db.recover(&marker, true).await == TransactionRecovery::Replay);
+        check!(!marker.table_created.load(Ordering::Acquire));
+    }
+
+    #[test]
+    async fn marker_table_is_created_when_checking() {
+        let db = TestDb::new().await;
+        let marker = db.marker(1);
+
+        check!(!db.marker_exists(&marker).await);
+    }
+
+    #[test]
+    async fn expired_markers_are_removed() {
+        let db = TestDb::new().await;
+        let marker = db.marker(1);
+        db.run_interrupted_transaction(&marker, true).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        db.marker_with_retention(2, RETENTION)
+            .create_table(&db.rdbms, &db.pool_key, &db.worker_id)
+            .await
+            .unwrap();
+        check!(db.marker_exists(&marker).await);
+
+        db.marker_with_retention(2, Duration::ZERO)
+            .create_table(&db.rdbms, &db.pool_key, &db.worker_id)
+            .await
+            .unwrap();
+        check!(!db.marker_exists(&marker).await);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::durable_host::rdbms::markers::{TransactionMarker, TransactionRecovery};
 use crate::durable_host::rdbms::serialized::RdbmsRequest;
 use crate::durable_host::serialized::SerializableError;
 use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
@@ -21,15 +22,21 @@ use crate::workerctx::WorkerCtx;
 use anyhow::anyhow;
 use golem_common::base_model::OplogIndex;
 use golem_common::model::oplog::DurableFunctionType;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use tracing::warn;
 use wasmtime::component::{Resource, ResourceTable};
 use wasmtime_wasi::IoView;
 
+mod markers;
+pub mod mssql;
 pub mod mysql;
 pub mod postgres;
 pub mod serialized;
+pub mod sqlite;
 pub mod types;
 
 fn get_db_connection_interface<T: RdbmsType>() -> String {
@@ -82,27 +89,77 @@ async fn begin_db_transaction<Ctx, T, E>(
 where
     Ctx: WorkerCtx,
     T: RdbmsType + Clone + 'static,
+    dyn RdbmsService: RdbmsTypeService<T>,
     E: From<RdbmsError>,
 {
     let interface = get_db_connection_interface::<T>();
     ctx.observe_function_call(interface.as_str(), "begin-transaction");
 
-    let begin_index = ctx
-        .begin_durable_function(&DurableFunctionType::WriteRemoteBatched(None))
-        .await?;
-
-    let pool_key = ctx
+    let connection = ctx
         .as_wasi_view()
         .table()
-        .get::<RdbmsConnection<T>>(entry)?
-        .pool_key
-        .clone();
+        .get::<RdbmsConnection<T>>(entry)?;
+    let pool_key = connection.pool_key.clone();
+    let marker_table_created = connection.marker_table_created.clone();
+
+    let (begin_index, marker) =
+        if ctx.state.config.rdbms.transaction.commit_markers && !T::is_embedded() {
+            let (begin_index, marker) =
+                begin_marked_transaction::<Ctx, T>(ctx, &pool_key, marker_table_created).await?;
+            (begin_index, Some(marker))
+        } else {
+            let begin_index = ctx
+                .begin_durable_function(&DurableFunctionType::WriteRemoteBatched(None))
+                .await?;
+            (begin_index, None)
+        };
 
-    let entry = RdbmsTransactionEntry::new(pool_key, RdbmsTransactionState::New, begin_index);
+    let entry =
+        RdbmsTransactionEntry::new(pool_key, RdbmsTransactionState::New, begin_index, marker);
     let resource = ctx.as_wasi_view().table().push(entry)?;
     Ok(Ok(resource))
 }
 
+/// Begins the remote write region of a transaction which records its commit with a marker.
+///
+/// If the region is being replayed but was never completed, the marker tells whether the
+/// transaction has to be executed again, or its commit reached the database and the replay
+/// can continue.
+async fn begin_marked_transaction<Ctx, T>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    pool_key: &RdbmsPoolKey,
+    marker_table_created: Arc<AtomicBool>,
+) -> Result<(OplogIndex, TransactionMarker), WorkerExecutorError>
+where
+    Ctx: WorkerCtx,
+    T: RdbmsType + Clone + 'static,
+    dyn RdbmsService: RdbmsTypeService<T>,
+{
+    ctx.process_pending_replay_events().await?;
+    let (begin_index, completed) = ctx.state.begin_recoverable_remote_write().await?;
+
+    let worker_id = ctx.state.owned_worker_id.worker_id.clone();
+    let marker = TransactionMarker::new(
+        &worker_id,
+        begin_index,
+        marker_table_created,
+        ctx.state.config.rdbms.transaction.commit_marker_retention,
+    );
+    let rdbms = ctx.state.rdbms_service.deref().rdbms_type_service();
+    let recovery = marker
+        .recover::<T>(completed, &rdbms, pool_key, &worker_id)
+        .await
+        .map_err(|error| {
+            WorkerExecutorError::runtime(format!(
+                "Failed to find out whether an interrupted transaction was committed: {error}"
+            ))
+        })?;
+    if recovery == TransactionRecovery::Retry {
+        ctx.state.retry_remote_write(begin_index).await;
+    }
+    Ok((begin_index, marker))
+}
+
 async fn db_connection_durable_execute<Ctx, T, P, E>(
     statement: String,
     params: Vec<P>,
@@ -129,6 +186,10 @@ where
         let (input, result) = db_connection_execute(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
+        if T::is_embedded() {
+            // the embedded database is part of the worker's file system, which is rebuilt by replay
+            let _ = db_connection_execute(statement, params, ctx, entry).await;
+        }
         durability.replay(ctx).await
     };
 
@@ -162,6 +223,9 @@ where
         let (input, result) = db_connection_query(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
+        if T::is_embedded() {
+            let _ = db_connection_query(statement, params, ctx, entry).await;
+        }
         durability.replay(ctx).await
     };
 
@@ -412,6 +476,9 @@ where
         let (input, result) = db_transaction_query(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
+        if T::is_embedded() {
+            let _ = db_transaction_query(statement, params, ctx, entry).await;
+        }
         durability.replay(ctx).await
     };
 
@@ -452,6 +519,9 @@ where
         let (input, result) = db_transaction_execute(statement, params, ctx, entry).await;
         durability.persist(ctx, input, result).await
     } else {
+        if T::is_embedded() {
+            let _ = db_transaction_execute(statement, params, ctx, entry).await;
+        }
         durability.replay(ctx).await
     };
 
@@ -526,6 +596,9 @@ where
         let result = db_transaction_rollback(ctx, entry).await;
         durability.persist(ctx, (), result).await
     } else {
+        if T::is_embedded() {
+            let _ = db_transaction_rollback(ctx, entry).await;
+        }
         durability.replay(ctx).await
     };
 
@@ -546,10 +619,14 @@ async fn db_transaction_durable_commit<Ctx, T, E>(
 where
     Ctx: WorkerCtx,
     T: RdbmsType + Clone + bincode::Encode + bincode::Decode<()> + 'static,
+    dyn RdbmsService: RdbmsTypeService<T>,
     E: From<RdbmsError>,
 {
     let interface = get_db_transaction_interface::<T>();
-    let begin_oplog_idx = ctx.table().get(entry)?.begin_index;
+    let transaction_entry = ctx.table().get(entry)?;
+    let begin_oplog_idx = transaction_entry.begin_index;
+    let pool_key = transaction_entry.pool_key.clone();
+    let marker = transaction_entry.marker.clone();
     let durability = Durability::<(), SerializableError>::new(
         ctx,
         interface.leak(),
@@ -558,10 +635,14 @@ where
     )
     .await?;
 
-    let result = if durability.is_live() {
+    let is_live = durability.is_live();
+    let result = if is_live {
         let result = db_transaction_commit(ctx, entry).await;
         durability.persist(ctx, (), result).await
     } else {
+        if T::is_embedded() {
+            let _ = db_transaction_commit(ctx, entry).await;
+        }
         durability.replay(ctx).await
     };
 
@@ -572,6 +653,15 @@ where
     )
     .await?;
 
+    // Once the commit is recorded in the oplog, its marker is no longer needed
+    if let Some(marker) = marker.filter(|_| is_live && result.is_ok()) {
+        let worker_id = ctx.state.owned_worker_id.worker_id.clone();
+        let rdbms = ctx.state.rdbms_service.deref().rdbms_type_service();
+        if let Err(error) = marker.remove(&rdbms, &pool_key, &worker_id).await {
+            warn!("Failed to remove the commit marker of transaction: {error}");
+        }
+    }
+
     Ok(result.map_err(|e| e.into()))
 }
 
@@ -607,6 +697,7 @@ where
 
 pub struct RdbmsConnection<T: RdbmsType> {
     pool_key: RdbmsPoolKey,
+    marker_table_created: Arc<AtomicBool>,
     _owner: PhantomData<T>,
 }
 
@@ -614,6 +705,7 @@ impl<T: RdbmsType> RdbmsConnection<T> {
     fn new(pool_key: RdbmsPoolKey) -> Self {
         Self {
             pool_key,
+            marker_table_created: Arc::new(AtomicBool::new(false)),
             _owner: PhantomData,
         }
     }
@@ -721,6 +813,7 @@ pub struct RdbmsTransactionEntry<T: RdbmsType + Clone + 'static> {
     pool_key: RdbmsPoolKey,
     state: RdbmsTransactionState<T>,
     begin_index: OplogIndex,
+    marker: Option<TransactionMarker>,
 }
 
 impl<T: RdbmsType + Clone + 'static> RdbmsTransactionEntry<T> {
@@ -728,11 +821,13 @@ impl<T: RdbmsType + Clone + 'static> RdbmsTransactionEntry<T> {
         pool_key: RdbmsPoolKey,
         state: RdbmsTransactionState<T>,
         begin_index: OplogIndex,
+        marker: Option<TransactionMarker>,
     ) -> Self {
         Self {
             pool_key,
             state,
             begin_index,
+            marker,
         }
     }
 
@@ -776,15 +871,16 @@ where
 
     match transaction_entry.state {
         RdbmsTransactionState::New => {
-            let transaction = ctx
-                .state
-                .rdbms_service
-                .deref()
-                .rdbms_type_service()
-                .begin_transaction(
-                    &transaction_entry.pool_key,
-                    &ctx.state.owned_worker_id.worker_id,
-                )
+            let rdbms = ctx.state.rdbms_service.deref().rdbms_type_service();
+            let worker_id = &ctx.state.owned_worker_id.worker_id;
+            if let Some(marker) = &transaction_entry.marker {
+                // Must happen outside the transaction, as some databases implicitly commit on DDL
+                marker
+                    .create_table(&rdbms, &transaction_entry.pool_key, worker_id)
+                    .await?;
+            }
+            let transaction = rdbms
+                .begin_transaction(&transaction_entry.pool_key, worker_id)
                 .await;
             match transaction {
                 Ok(transaction) => {
@@ -1011,14 +1107,17 @@ where
     Ctx: WorkerCtx,
     T: RdbmsType + Clone + 'static,
 {
-    let state = ctx
+    let entry_state = ctx
         .as_wasi_view()
         .table()
         .get::<RdbmsTransactionEntry<T>>(entry)
-        .map(|e| e.state.clone());
+        .map(|e| (e.state.clone(), e.marker.clone()));
 
-    match state {
-        Ok(RdbmsTransactionState::Open(transaction)) => {
+    match entry_state {
+        Ok((RdbmsTransactionState::Open(transaction), marker)) => {
+            if let Some(marker) = marker {
+                marker.insert(transaction.as_ref()).await?;
+            }
             transaction.commit().await?;
             ctx.as_wasi_view()
                 .table()
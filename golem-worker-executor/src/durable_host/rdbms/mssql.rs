@@ -0,0 +1,453 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::rdbms::{
+    begin_db_transaction, db_connection_drop, db_connection_durable_execute,
+    db_connection_durable_query, db_connection_durable_query_stream, db_result_stream_drop,
+    db_result_stream_durable_get_columns, db_result_stream_durable_get_next, db_transaction_drop,
+    db_transaction_durable_commit, db_transaction_durable_execute, db_transaction_durable_query,
+    db_transaction_durable_query_stream, db_transaction_durable_rollback, open_db_connection,
+    FromRdbmsValue, RdbmsConnection, RdbmsResultStreamEntry, RdbmsTransactionEntry,
+};
+use crate::durable_host::DurableWorkerCtx;
+use crate::preview2::golem::rdbms::mssql::{
+    DbColumn, DbColumnType, DbResult, DbRow, DbValue, Error, Host, HostDbConnection,
+    HostDbResultStream, HostDbTransaction,
+};
+use crate::services::rdbms::mssql::types as mssql_types;
+use crate::services::rdbms::mssql::MssqlType;
+use crate::workerctx::WorkerCtx;
+use async_trait::async_trait;
+use std::str::FromStr;
+use wasmtime::component::{Resource, ResourceTable};
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+pub type MssqlDbConnection = RdbmsConnection<MssqlType>;
+
+impl<Ctx: WorkerCtx> HostDbConnection for DurableWorkerCtx<Ctx> {
+    async fn open(
+        &mut self,
+        address: String,
+    ) -> anyhow::Result<Result<Resource<MssqlDbConnection>, Error>> {
+        open_db_connection(address, self).await
+    }
+
+    async fn query_stream(
+        &mut self,
+        self_: Resource<MssqlDbConnection>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<Resource<DbResultStreamEntry>, Error>> {
+        db_connection_durable_query_stream(statement, params, self, &self_).await
+    }
+
+    async fn query(
+        &mut self,
+        self_: Resource<MssqlDbConnection>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<DbResult, Error>> {
+        db_connection_durable_query(statement, params, self, &self_).await
+    }
+
+    async fn execute(
+        &mut self,
+        self_: Resource<MssqlDbConnection>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<u64, Error>> {
+        db_connection_durable_execute(statement, params, self, &self_).await
+    }
+
+    async fn begin_transaction(
+        &mut self,
+        self_: Resource<MssqlDbConnection>,
+    ) -> anyhow::Result<Result<Resource<DbTransactionEntry>, Error>> {
+        begin_db_transaction(self, &self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<MssqlDbConnection>) -> anyhow::Result<()> {
+        db_connection_drop(self, rep).await
+    }
+}
+
+pub type DbResultStreamEntry = RdbmsResultStreamEntry<MssqlType>;
+
+impl<Ctx: WorkerCtx> HostDbResultStream for DurableWorkerCtx<Ctx> {
+    async fn get_columns(
+        &mut self,
+        self_: Resource<DbResultStreamEntry>,
+    ) -> anyhow::Result<Vec<DbColumn>> {
+        db_result_stream_durable_get_columns(self, &self_).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<DbResultStreamEntry>,
+    ) -> anyhow::Result<Option<Vec<DbRow>>> {
+        db_result_stream_durable_get_next(self, &self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<DbResultStreamEntry>) -> anyhow::Result<()> {
+        db_result_stream_drop(self, rep).await
+    }
+}
+
+pub type DbTransactionEntry = RdbmsTransactionEntry<MssqlType>;
+
+impl<Ctx: WorkerCtx> HostDbTransaction for DurableWorkerCtx<Ctx> {
+    async fn query(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<DbResult, Error>> {
+        db_transaction_durable_query(statement, params, self, &self_).await
+    }
+
+    async fn query_stream(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<Resource<DbResultStreamEntry>, Error>> {
+        db_transaction_durable_query_stream(statement, params, self, &self_).await
+    }
+
+    async fn execute(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<u64, Error>> {
+        db_transaction_durable_execute(statement, params, self, &self_).await
+    }
+
+    async fn commit(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+    ) -> anyhow::Result<Result<(), Error>> {
+        db_transaction_durable_commit(self, &self_).await
+    }
+
+    async fn rollback(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+    ) -> anyhow::Result<Result<(), Error>> {
+        db_transaction_durable_rollback(self, &self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<DbTransactionEntry>) -> anyhow::Result<()> {
+        db_transaction_drop(self, rep).await
+    }
+}
+
+impl TryFrom<DbValue> for mssql_types::DbValue {
+    type Error = String;
+    fn try_from(value: DbValue) -> Result<Self, Self::Error> {
+        match value {
+            DbValue::Bit(v) => Ok(Self::Bit(v)),
+            DbValue::Tinyint(v) => Ok(Self::Tinyint(v)),
+            DbValue::Smallint(v) => Ok(Self::Smallint(v)),
+            DbValue::Int(v) => Ok(Self::Int(v)),
+            DbValue::Bigint(v) => Ok(Self::Bigint(v)),
+            DbValue::Real(v) => Ok(Self::Real(v)),
+            DbValue::Float(v) => Ok(Self::Float(v)),
+            DbValue::Decimal(v) => {
+                let v = bigdecimal::BigDecimal::from_str(&v).map_err(|e| e.to_string())?;
+                Ok(Self::Decimal(v))
+            }
+            DbValue::Numeric(v) => {
+                let v = bigdecimal::BigDecimal::from_str(&v).map_err(|e| e.to_string())?;
+                Ok(Self::Numeric(v))
+            }
+            DbValue::Smallmoney(v) => {
+                let v = bigdecimal::BigDecimal::from_str(&v).map_err(|e| e.to_string())?;
+                Ok(Self::Smallmoney(v))
+            }
+            DbValue::Money(v) => {
+                let v = bigdecimal::BigDecimal::from_str(&v).map_err(|e| e.to_string())?;
+                Ok(Self::Money(v))
+            }
+            DbValue::Date(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Date(value))
+            }
+            DbValue::Time(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Time(value))
+            }
+            DbValue::Smalldatetime(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Smalldatetime(value))
+            }
+            DbValue::Datetime(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Datetime(value))
+            }
+            DbValue::Datetime2(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Datetime2(value))
+            }
+            DbValue::Datetimeoffset(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Datetimeoffset(value))
+            }
+            DbValue::Fixchar(v) => Ok(Self::Fixchar(v)),
+            DbValue::Varchar(v) => Ok(Self::Varchar(v)),
+            DbValue::Text(v) => Ok(Self::Text(v)),
+            DbValue::Nchar(v) => Ok(Self::Nchar(v)),
+            DbValue::Nvarchar(v) => Ok(Self::Nvarchar(v)),
+            DbValue::Ntext(v) => Ok(Self::Ntext(v)),
+            DbValue::Binary(v) => Ok(Self::Binary(v)),
+            DbValue::Varbinary(v) => Ok(Self::Varbinary(v)),
+            DbValue::Image(v) => Ok(Self::Image(v)),
+            DbValue::Uniqueidentifier(v) => Ok(Self::Uniqueidentifier(v.into())),
+            DbValue::Xml(v) => Ok(Self::Xml(v)),
+            DbValue::Null => Ok(Self::Null),
+        }
+    }
+}
+
+impl From<mssql_types::DbValue> for DbValue {
+    fn from(value: mssql_types::DbValue) -> Self {
+        match value {
+            mssql_types::DbValue::Bit(v) => Self::Bit(v),
+            mssql_types::DbValue::Tinyint(v) => Self::Tinyint(v),
+            mssql_types::DbValue::Smallint(v) => Self::Smallint(v),
+            mssql_types::DbValue::Int(v) => Self::Int(v),
+            mssql_types::DbValue::Bigint(v) => Self::Bigint(v),
+            mssql_types::DbValue::Real(v) => Self::Real(v),
+            mssql_types::DbValue::Float(v) => Self::Float(v),
+            mssql_types::DbValue::Decimal(v) => Self::Decimal(v.to_string()),
+            mssql_types::DbValue::Numeric(v) => Self::Numeric(v.to_string()),
+            mssql_types::DbValue::Smallmoney(v) => Self::Smallmoney(v.to_string()),
+            mssql_types::DbValue::Money(v) => Self::Money(v.to_string()),
+            mssql_types::DbValue::Date(v) => Self::Date(v.into()),
+            mssql_types::DbValue::Time(v) => Self::Time(v.into()),
+            mssql_types::DbValue::Smalldatetime(v) => Self::Smalldatetime(v.into()),
+            mssql_types::DbValue::Datetime(v) => Self::Datetime(v.into()),
+            mssql_types::DbValue::Datetime2(v) => Self::Datetime2(v.into()),
+            mssql_types::DbValue::Datetimeoffset(v) => Self::Datetimeoffset(v.into()),
+            mssql_types::DbValue::Fixchar(v) => Self::Fixchar(v),
+            mssql_types::DbValue::Varchar(v) => Self::Varchar(v),
+            mssql_types::DbValue::Text(v) => Self::Text(v),
+            mssql_types::DbValue::Nchar(v) => Self::Nchar(v),
+            mssql_types::DbValue::Nvarchar(v) => Self::Nvarchar(v),
+            mssql_types::DbValue::Ntext(v) => Self::Ntext(v),
+            mssql_types::DbValue::Binary(v) => Self::Binary(v),
+            mssql_types::DbValue::Varbinary(v) => Self::Varbinary(v),
+            mssql_types::DbValue::Image(v) => Self::Image(v),
+            mssql_types::DbValue::Uniqueidentifier(v) => Self::Uniqueidentifier(v.into()),
+            mssql_types::DbValue::Xml(v) => Self::Xml(v),
+            mssql_types::DbValue::Null => Self::Null,
+        }
+    }
+}
+
+impl FromRdbmsValue<crate::services::rdbms::DbRow<mssql_types::DbValue>> for DbRow {
+    fn from(
+        value: crate::services::rdbms::DbRow<mssql_types::DbValue>,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbRow, String> {
+        Ok(value.into())
+    }
+}
+
+impl From<crate::services::rdbms::DbRow<mssql_types::DbValue>> for DbRow {
+    fn from(value: crate::services::rdbms::DbRow<mssql_types::DbValue>) -> Self {
+        Self {
+            values: value.values.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<mssql_types::DbColumnType> for DbColumnType {
+    fn from(value: mssql_types::DbColumnType) -> Self {
+        match value {
+            mssql_types::DbColumnType::Bit => Self::Bit,
+            mssql_types::DbColumnType::Tinyint => Self::Tinyint,
+            mssql_types::DbColumnType::Smallint => Self::Smallint,
+            mssql_types::DbColumnType::Int => Self::Int,
+            mssql_types::DbColumnType::Bigint => Self::Bigint,
+            mssql_types::DbColumnType::Real => Self::Real,
+            mssql_types::DbColumnType::Float => Self::Float,
+            mssql_types::DbColumnType::Decimal => Self::Decimal,
+            mssql_types::DbColumnType::Numeric => Self::Numeric,
+            mssql_types::DbColumnType::Smallmoney => Self::Smallmoney,
+            mssql_types::DbColumnType::Money => Self::Money,
+            mssql_types::DbColumnType::Date => Self::Date,
+            mssql_types::DbColumnType::Time => Self::Time,
+            mssql_types::DbColumnType::Smalldatetime => Self::Smalldatetime,
+            mssql_types::DbColumnType::Datetime => Self::Datetime,
+            mssql_types::DbColumnType::Datetime2 => Self::Datetime2,
+            mssql_types::DbColumnType::Datetimeoffset => Self::Datetimeoffset,
+            mssql_types::DbColumnType::Fixchar => Self::Fixchar,
+            mssql_types::DbColumnType::Varchar => Self::Varchar,
+            mssql_types::DbColumnType::Text => Self::Text,
+            mssql_types::DbColumnType::Nchar => Self::Nchar,
+            mssql_types::DbColumnType::Nvarchar => Self::Nvarchar,
+            mssql_types::DbColumnType::Ntext => Self::Ntext,
+            mssql_types::DbColumnType::Binary => Self::Binary,
+            mssql_types::DbColumnType::Varbinary => Self::Varbinary,
+            mssql_types::DbColumnType::Image => Self::Image,
+            mssql_types::DbColumnType::Uniqueidentifier => Self::Uniqueidentifier,
+            mssql_types::DbColumnType::Xml => Self::Xml,
+        }
+    }
+}
+
+impl From<DbColumnType> for mssql_types::DbColumnType {
+    fn from(value: DbColumnType) -> Self {
+        match value {
+            DbColumnType::Bit => Self::Bit,
+            DbColumnType::Tinyint => Self::Tinyint,
+            DbColumnType::Smallint => Self::Smallint,
+            DbColumnType::Int => Self::Int,
+            DbColumnType::Bigint => Self::Bigint,
+            DbColumnType::Real => Self::Real,
+            DbColumnType::Float => Self::Float,
+            DbColumnType::Decimal => Self::Decimal,
+            DbColumnType::Numeric => Self::Numeric,
+            DbColumnType::Smallmoney => Self::Smallmoney,
+            DbColumnType::Money => Self::Money,
+            DbColumnType::Date => Self::Date,
+            DbColumnType::Time => Self::Time,
+            DbColumnType::Smalldatetime => Self::Smalldatetime,
+            DbColumnType::Datetime => Self::Datetime,
+            DbColumnType::Datetime2 => Self::Datetime2,
+            DbColumnType::Datetimeoffset => Self::Datetimeoffset,
+            DbColumnType::Fixchar => Self::Fixchar,
+            DbColumnType::Varchar => Self::Varchar,
+            DbColumnType::Text => Self::Text,
+            DbColumnType::Nchar => Self::Nchar,
+            DbColumnType::Nvarchar => Self::Nvarchar,
+            DbColumnType::Ntext => Self::Ntext,
+            DbColumnType::Binary => Self::Binary,
+            DbColumnType::Varbinary => Self::Varbinary,
+            DbColumnType::Image => Self::Image,
+            DbColumnType::Uniqueidentifier => Self::Uniqueidentifier,
+            DbColumnType::Xml => Self::Xml,
+        }
+    }
+}
+
+impl FromRdbmsValue<crate::services::rdbms::DbResult<MssqlType>> for DbResult {
+    fn from(
+        value: crate::services::rdbms::DbResult<MssqlType>,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbResult, String> {
+        Ok(value.into())
+    }
+}
+
+impl From<crate::services::rdbms::DbResult<MssqlType>> for DbResult {
+    fn from(value: crate::services::rdbms::DbResult<MssqlType>) -> Self {
+        Self {
+            columns: value.columns.into_iter().map(|v| v.into()).collect(),
+            rows: value.rows.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl FromRdbmsValue<mssql_types::DbColumn> for DbColumn {
+    fn from(
+        value: mssql_types::DbColumn,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbColumn, String> {
+        Ok(value.into())
+    }
+}
+
+impl From<mssql_types::DbColumn> for DbColumn {
+    fn from(value: mssql_types::DbColumn) -> Self {
+        Self {
+            ordinal: value.ordinal,
+            name: value.name,
+            db_type: value.db_type.into(),
+            db_type_name: value.db_type_name,
+        }
+    }
+}
+
+impl From<crate::services::rdbms::Error> for Error {
+    fn from(value: crate::services::rdbms::Error) -> Self {
+        match value {
+            crate::services::rdbms::Error::ConnectionFailure(v) => Self::ConnectionFailure(v),
+            crate::services::rdbms::Error::QueryParameterFailure(v) => {
+                Self::QueryParameterFailure(v)
+            }
+            crate::services::rdbms::Error::QueryExecutionFailure(v) => {
+                Self::QueryExecutionFailure(v)
+            }
+            crate::services::rdbms::Error::QueryResponseFailure(v) => Self::QueryResponseFailure(v),
+            crate::services::rdbms::Error::Other(v) => Self::Other(v),
+        }
+    }
+}
+
+impl FromRdbmsValue<DbValue> for mssql_types::DbValue {
+    fn from(value: DbValue, _resource_table: &mut ResourceTable) -> Result<Self, String> {
+        value.try_into()
+    }
+}
+
+impl FromRdbmsValue<mssql_types::DbValue> for DbValue {
+    fn from(
+        value: mssql_types::DbValue,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbValue, String> {
+        Ok(value.into())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::preview2::golem::rdbms::mssql::{DbColumnType, DbValue};
+    use crate::services::rdbms::mssql::types as mssql_types;
+    use assert2::check;
+    use test_r::test;
+
+    fn check_db_value(value: mssql_types::DbValue) {
+        let wit: DbValue = value.clone().into();
+        let value2: mssql_types::DbValue = wit.try_into().unwrap();
+        check!(value2 == value);
+    }
+
+    #[test]
+    fn test_db_values_conversions() {
+        let values = mssql_types::tests::get_test_db_values();
+
+        for value in values {
+            check_db_value(value);
+        }
+    }
+
+    fn check_db_column_type(value: mssql_types::DbColumnType) {
+        let wit: DbColumnType = value.clone().into();
+        let value2: mssql_types::DbColumnType = wit.into();
+        check!(value2 == value);
+    }
+
+    #[test]
+    fn test_db_column_types_conversions() {
+        let values = mssql_types::tests::get_test_db_column_types();
+
+        for value in values {
+            check_db_column_type(value);
+        }
+    }
+}
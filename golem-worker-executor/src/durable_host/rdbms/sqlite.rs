@@ -0,0 +1,429 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::rdbms::{
+    begin_db_transaction, db_connection_drop, db_connection_durable_execute,
+    db_connection_durable_query, db_connection_durable_query_stream, db_result_stream_drop,
+    db_result_stream_durable_get_columns, db_result_stream_durable_get_next, db_transaction_drop,
+    db_transaction_durable_commit, db_transaction_durable_execute, db_transaction_durable_query,
+    db_transaction_durable_query_stream, db_transaction_durable_rollback,
+    get_db_connection_interface, open_db_connection, FromRdbmsValue, RdbmsConnection,
+    RdbmsResultStreamEntry, RdbmsTransactionEntry,
+};
+use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::preview2::golem::rdbms::sqlite::{
+    DbColumn, DbColumnType, DbResult, DbRow, DbValue, Error, Host, HostDbConnection,
+    HostDbResultStream, HostDbTransaction,
+};
+use crate::services::rdbms::sqlite::types as sqlite_types;
+use crate::services::rdbms::sqlite::{SqliteType, SQLITE};
+use crate::workerctx::WorkerCtx;
+use async_trait::async_trait;
+use std::path::{Component, Path};
+use wasmtime::component::{Resource, ResourceTable};
+
+#[async_trait]
+impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+pub type SqliteDbConnection = RdbmsConnection<SqliteType>;
+
+impl<Ctx: WorkerCtx> HostDbConnection for DurableWorkerCtx<Ctx> {
+    async fn open(
+        &mut self,
+        address: String,
+    ) -> anyhow::Result<Result<Resource<SqliteDbConnection>, Error>> {
+        match resolve_address(&address, self.temp_dir.path()).await {
+            Ok(address) => open_db_connection(address, self).await,
+            Err(error) => {
+                let interface = get_db_connection_interface::<SqliteType>();
+                self.observe_function_call(interface.as_str(), "open");
+                Ok(Err(Error::ConnectionFailure(error)))
+            }
+        }
+    }
+
+    async fn query_stream(
+        &mut self,
+        self_: Resource<SqliteDbConnection>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<Resource<DbResultStreamEntry>, Error>> {
+        db_connection_durable_query_stream(statement, params, self, &self_).await
+    }
+
+    async fn query(
+        &mut self,
+        self_: Resource<SqliteDbConnection>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<DbResult, Error>> {
+        db_connection_durable_query(statement, params, self, &self_).await
+    }
+
+    async fn execute(
+        &mut self,
+        self_: Resource<SqliteDbConnection>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<u64, Error>> {
+        db_connection_durable_execute(statement, params, self, &self_).await
+    }
+
+    async fn begin_transaction(
+        &mut self,
+        self_: Resource<SqliteDbConnection>,
+    ) -> anyhow::Result<Result<Resource<DbTransactionEntry>, Error>> {
+        begin_db_transaction(self, &self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<SqliteDbConnection>) -> anyhow::Result<()> {
+        db_connection_drop(self, rep).await
+    }
+}
+
+/// Maps a guest address like `sqlite:data/app.db?mode=rwc` to a database file
+/// in the worker's file system, creating the parent directories if needed
+async fn resolve_address(address: &str, root: &Path) -> Result<String, String> {
+    let rest = address
+        .strip_prefix(SQLITE)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| format!("Invalid address: {address}, expected {SQLITE}:<path>"))?
+        .trim_start_matches('/');
+
+    let (path, query) = match rest.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (rest, None),
+    };
+
+    let relative = Path::new(path);
+    if path.is_empty()
+        || relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return Err(format!(
+            "Invalid database path: '{path}', expected a path within the worker's file system"
+        ));
+    }
+
+    let target = root.join(relative);
+    if let Some(parent) = target.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| format!("Failed to create directory for database '{path}': {e}"))?;
+    }
+
+    let mut result = format!("{SQLITE}://{}", target.display());
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(query);
+    }
+    Ok(result)
+}
+
+pub type DbResultStreamEntry = RdbmsResultStreamEntry<SqliteType>;
+
+impl<Ctx: WorkerCtx> HostDbResultStream for DurableWorkerCtx<Ctx> {
+    async fn get_columns(
+        &mut self,
+        self_: Resource<DbResultStreamEntry>,
+    ) -> anyhow::Result<Vec<DbColumn>> {
+        db_result_stream_durable_get_columns(self, &self_).await
+    }
+
+    async fn get_next(
+        &mut self,
+        self_: Resource<DbResultStreamEntry>,
+    ) -> anyhow::Result<Option<Vec<DbRow>>> {
+        db_result_stream_durable_get_next(self, &self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<DbResultStreamEntry>) -> anyhow::Result<()> {
+        db_result_stream_drop(self, rep).await
+    }
+}
+
+pub type DbTransactionEntry = RdbmsTransactionEntry<SqliteType>;
+
+impl<Ctx: WorkerCtx> HostDbTransaction for DurableWorkerCtx<Ctx> {
+    async fn query(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<DbResult, Error>> {
+        db_transaction_durable_query(statement, params, self, &self_).await
+    }
+
+    async fn query_stream(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<Resource<DbResultStreamEntry>, Error>> {
+        db_transaction_durable_query_stream(statement, params, self, &self_).await
+    }
+
+    async fn execute(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+        statement: String,
+        params: Vec<DbValue>,
+    ) -> anyhow::Result<Result<u64, Error>> {
+        db_transaction_durable_execute(statement, params, self, &self_).await
+    }
+
+    async fn commit(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+    ) -> anyhow::Result<Result<(), Error>> {
+        db_transaction_durable_commit(self, &self_).await
+    }
+
+    async fn rollback(
+        &mut self,
+        self_: Resource<DbTransactionEntry>,
+    ) -> anyhow::Result<Result<(), Error>> {
+        db_transaction_durable_rollback(self, &self_).await
+    }
+
+    async fn drop(&mut self, rep: Resource<DbTransactionEntry>) -> anyhow::Result<()> {
+        db_transaction_drop(self, rep).await
+    }
+}
+
+impl TryFrom<DbValue> for sqlite_types::DbValue {
+    type Error = String;
+    fn try_from(value: DbValue) -> Result<Self, Self::Error> {
+        match value {
+            DbValue::Integer(v) => Ok(Self::Integer(v)),
+            DbValue::Real(v) => Ok(Self::Real(v)),
+            DbValue::Text(v) => Ok(Self::Text(v)),
+            DbValue::Blob(v) => Ok(Self::Blob(v)),
+            DbValue::Boolean(v) => Ok(Self::Boolean(v)),
+            DbValue::Date(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Date(value))
+            }
+            DbValue::Time(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Time(value))
+            }
+            DbValue::Datetime(v) => {
+                let value = v.try_into()?;
+                Ok(Self::Datetime(value))
+            }
+            DbValue::Null => Ok(Self::Null),
+        }
+    }
+}
+
+impl From<sqlite_types::DbValue> for DbValue {
+    fn from(value: sqlite_types::DbValue) -> Self {
+        match value {
+            sqlite_types::DbValue::Integer(v) => Self::Integer(v),
+            sqlite_types::DbValue::Real(v) => Self::Real(v),
+            sqlite_types::DbValue::Text(v) => Self::Text(v),
+            sqlite_types::DbValue::Blob(v) => Self::Blob(v),
+            sqlite_types::DbValue::Boolean(v) => Self::Boolean(v),
+            sqlite_types::DbValue::Date(v) => Self::Date(v.into()),
+            sqlite_types::DbValue::Time(v) => Self::Time(v.into()),
+            sqlite_types::DbValue::Datetime(v) => Self::Datetime(v.into()),
+            sqlite_types::DbValue::Null => Self::Null,
+        }
+    }
+}
+
+impl FromRdbmsValue<crate::services::rdbms::DbRow<sqlite_types::DbValue>> for DbRow {
+    fn from(
+        value: crate::services::rdbms::DbRow<sqlite_types::DbValue>,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbRow, String> {
+        Ok(value.into())
+    }
+}
+
+impl From<crate::services::rdbms::DbRow<sqlite_types::DbValue>> for DbRow {
+    fn from(value: crate::services::rdbms::DbRow<sqlite_types::DbValue>) -> Self {
+        Self {
+            values: value.values.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<sqlite_types::DbColumnType> for DbColumnType {
+    fn from(value: sqlite_types::DbColumnType) -> Self {
+        match value {
+            sqlite_types::DbColumnType::Integer => Self::Integer,
+            sqlite_types::DbColumnType::Real => Self::Real,
+            sqlite_types::DbColumnType::Text => Self::Text,
+            sqlite_types::DbColumnType::Blob => Self::Blob,
+            sqlite_types::DbColumnType::Numeric => Self::Numeric,
+            sqlite_types::DbColumnType::Boolean => Self::Boolean,
+            sqlite_types::DbColumnType::Date => Self::Date,
+            sqlite_types::DbColumnType::Time => Self::Time,
+            sqlite_types::DbColumnType::Datetime => Self::Datetime,
+            sqlite_types::DbColumnType::Null => Self::Null,
+        }
+    }
+}
+
+impl From<DbColumnType> for sqlite_types::DbColumnType {
+    fn from(value: DbColumnType) -> Self {
+        match value {
+            DbColumnType::Integer => Self::Integer,
+            DbColumnType::Real => Self::Real,
+            DbColumnType::Text => Self::Text,
+            DbColumnType::Blob => Self::Blob,
+            DbColumnType::Numeric => Self::Numeric,
+            DbColumnType::Boolean => Self::Boolean,
+            DbColumnType::Date => Self::Date,
+            DbColumnType::Time => Self::Time,
+            DbColumnType::Datetime => Self::Datetime,
+            DbColumnType::Null => Self::Null,
+        }
+    }
+}
+
+impl FromRdbmsValue<crate::services::rdbms::DbResult<SqliteType>> for DbResult {
+    fn from(
+        value: crate::services::rdbms::DbResult<SqliteType>,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbResult, String> {
+        Ok(value.into())
+    }
+}
+
+impl From<crate::services::rdbms::DbResult<SqliteType>> for DbResult {
+    fn from(value: crate::services::rdbms::DbResult<SqliteType>) -> Self {
+        Self {
+            columns: value.columns.into_iter().map(|v| v.into()).collect(),
+            rows: value.rows.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl FromRdbmsValue<sqlite_types::DbColumn> for DbColumn {
+    fn from(
+        value: sqlite_types::DbColumn,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbColumn, String> {
+        Ok(value.into())
+    }
+}
+
+impl From<sqlite_types::DbColumn> for DbColumn {
+    fn from(value: sqlite_types::DbColumn) -> Self {
+        Self {
+            ordinal: value.ordinal,
+            name: value.name,
+            db_type: value.db_type.into(),
+            db_type_name: value.db_type_name,
+        }
+    }
+}
+
+impl From<crate::services::rdbms::Error> for Error {
+    fn from(value: crate::services::rdbms::Error) -> Self {
+        match value {
+            crate::services::rdbms::Error::ConnectionFailure(v) => Self::ConnectionFailure(v),
+            crate::services::rdbms::Error::QueryParameterFailure(v) => {
+                Self::QueryParameterFailure(v)
+            }
+            crate::services::rdbms::Error::QueryExecutionFailure(v) => {
+                Self::QueryExecutionFailure(v)
+            }
+            crate::services::rdbms::Error::QueryResponseFailure(v) => Self::QueryResponseFailure(v),
+            crate::services::rdbms::Error::Other(v) => Self::Other(v),
+        }
+    }
+}
+
+impl FromRdbmsValue<DbValue> for sqlite_types::DbValue {
+    fn from(value: DbValue, _resource_table: &mut ResourceTable) -> Result<Self, String> {
+        value.try_into()
+    }
+}
+
+impl FromRdbmsValue<sqlite_types::DbValue> for DbValue {
+    fn from(
+        value: sqlite_types::DbValue,
+        _resource_table: &mut ResourceTable,
+    ) -> Result<DbValue, String> {
+        Ok(value.into())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::resolve_address;
+    use crate::preview2::golem::rdbms::sqlite::{DbColumnType, DbValue};
+    use crate::services::rdbms::sqlite::types as sqlite_types;
+    use assert2::check;
+    use test_r::test;
+
+    fn check_db_value(value: sqlite_types::DbValue) {
+        let wit: DbValue = value.clone().into();
+        let value2: sqlite_types::DbValue = wit.try_into().unwrap();
+        check!(value2 == value);
+    }
+
+    #[test]
+    fn test_db_values_conversions() {
+        let values = sqlite_types::tests::get_test_db_values();
+
+        for value in values {
+            check_db_value(value);
+        }
+    }
+
+    fn check_db_column_type(value: sqlite_types::DbColumnType) {
+        let wit: DbColumnType = value.clone().into();
+        let value2: sqlite_types::DbColumnType = wit.into();
+        check!(value2 == value);
+    }
+
+    #[test]
+    fn test_db_column_types_conversions() {
+        let values = sqlite_types::tests::get_test_db_column_types();
+
+        for value in values {
+            check_db_column_type(value);
+        }
+    }
+
+    #[test]
+    async fn test_resolve_address() {
+        let root = tempfile::tempdir().unwrap();
+        let root_path = root.path().display().to_string();
+
+        check!(
+            resolve_address("sqlite:data/app.db", root.path()).await
+                == Ok(format!("sqlite://{root_path}/data/app.db"))
+        );
+        check!(
+            resolve_address("sqlite:/app.db?mode=ro", root.path()).await
+                == Ok(format!("sqlite://{root_path}/app.db?mode=ro"))
+        );
+        check!(root.path().join("data").is_dir());
+
+        check!(resolve_address("sqlite:../app.db", root.path())
+            .await
+            .is_err());
+        check!(resolve_address("sqlite:", root.path()).await.is_err());
+        check!(resolve_address("mysql://localhost/app", root.path())
+            .await
+            .is_err());
+    }
+}
@@ -650,7 +650,7 @@ mod tests {
         SerializableStreamError,
     };
     use golem_common::model::oplog::{OplogIndex, WorkerError};
-    use golem_common::model::{ComponentId, PromiseId, ShardId, WorkerId};
+    use golem_common::model::{ComponentId, IdempotencyKey, PromiseId, ShardId, WorkerId};
     use golem_service_base::error::worker_executor::{InterruptKind, WorkerExecutorError};
     use proptest::collection::vec;
     use proptest::prelude::*;
@@ -805,6 +805,7 @@ mod tests {
             (".*", ".*").prop_map(|(path, reason)| WorkerExecutorError::InitialComponentFileDownloadFailed { path, reason }),
             (".*", ".*").prop_map(|(path, reason)| WorkerExecutorError::FileSystemError { path, reason }),
             (workererror_strat(), ".*").prop_map(|(error, stderr)| WorkerExecutorError::InvocationFailed { error, stderr }),
+            (workerid_strat(), ".*").prop_map(|(worker_id, key)| WorkerExecutorError::InvocationNotFound { worker_id, idempotency_key: IdempotencyKey::new(key) }),
         }
     }
 
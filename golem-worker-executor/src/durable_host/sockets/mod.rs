@@ -19,3 +19,123 @@ pub mod tcp;
 pub mod tcp_create_socket;
 pub mod udp;
 pub mod udp_create_socket;
+
+use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::workerctx::WorkerCtx;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use bytes::Bytes;
+use golem_common::model::oplog::DurableFunctionType;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::any::Any;
+use wasmtime_wasi::{InputStream, OutputStream, Pollable, StreamError, StreamResult};
+
+/// The write budget reported by recovered connections, matching what wasmtime reports
+/// for an idle TCP output stream
+const RECOVERED_CONNECTION_WRITE_BUDGET: usize = 1024 * 1024;
+
+/// Stands in for both streams of a TCP connection that was established before the worker got
+/// recovered.
+///
+/// While replaying, every read and write on the connection is answered from the oplog, so the
+/// underlying connection is never touched. A live connection cannot be restored transparently
+/// though, so once the replay is over any further read or write fails, and the guest has to
+/// open a new connection.
+pub struct RecoveredTcpStream;
+
+impl RecoveredTcpStream {
+    fn connection_lost() -> StreamError {
+        StreamError::LastOperationFailed(anyhow!(
+            "TCP connection was lost during worker recovery, it has to be re-established"
+        ))
+    }
+}
+
+#[async_trait]
+impl Pollable for RecoveredTcpStream {
+    async fn ready(&mut self) {}
+}
+
+#[async_trait]
+impl InputStream for RecoveredTcpStream {
+    fn read(&mut self, _size: usize) -> StreamResult<Bytes> {
+        Err(Self::connection_lost())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[async_trait]
+impl OutputStream for RecoveredTcpStream {
+    fn write(&mut self, _bytes: Bytes) -> StreamResult<()> {
+        Err(Self::connection_lost())
+    }
+
+    fn flush(&mut self) -> StreamResult<()> {
+        Ok(())
+    }
+
+    fn check_write(&mut self) -> StreamResult<usize> {
+        Ok(RECOVERED_CONNECTION_WRITE_BUDGET)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Forgets about one of the streams of an open TCP connection, and closes the connection's
+/// batched remote write once none of its streams are alive anymore
+pub(crate) async fn end_tcp_connection_stream<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    handle: u32,
+) -> Result<(), WorkerExecutorError> {
+    if let Some(state) = ctx.state.open_tcp_connections.remove(&handle) {
+        let still_open = ctx
+            .state
+            .open_tcp_connections
+            .values()
+            .any(|other| other.begin_index == state.begin_index);
+        if !still_open {
+            ctx.end_durable_function(
+                &DurableFunctionType::WriteRemoteBatched(None),
+                state.begin_index,
+                false,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::durable_host::sockets::{RecoveredTcpStream, RECOVERED_CONNECTION_WRITE_BUDGET};
+    use bytes::Bytes;
+    use test_r::test;
+    use wasmtime_wasi::{InputStream, OutputStream, StreamError};
+
+    #[test]
+    fn recovered_connection_fails_reads_and_writes() {
+        let mut stream = RecoveredTcpStream;
+
+        let read = InputStream::read(&mut stream, 16);
+        let write = OutputStream::write(&mut stream, Bytes::from_static(b"hello"));
+
+        assert!(matches!(read, Err(StreamError::LastOperationFailed(_))));
+        assert!(matches!(write, Err(StreamError::LastOperationFailed(_))));
+    }
+
+    #[test]
+    fn recovered_connection_reports_write_budget() {
+        let mut stream = RecoveredTcpStream;
+
+        assert_eq!(
+            stream.check_write().unwrap(),
+            RECOVERED_CONNECTION_WRITE_BUDGET
+        );
+        assert!(stream.flush().is_ok());
+    }
+}
@@ -12,15 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::net::SocketAddr;
 use wasmtime::component::Resource;
 
-use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::sockets::RecoveredTcpStream;
+use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx, TcpConnectionState};
 use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::DurableFunctionType;
 use wasmtime_wasi::p2::bindings::sockets::tcp::{
     Duration, Host, HostTcpSocket, InputStream, IpAddressFamily, IpSocketAddress, Network,
     OutputStream, Pollable, ShutdownType, TcpSocket,
 };
 use wasmtime_wasi::p2::SocketError;
+use wasmtime_wasi::{DynInputStream, DynOutputStream, Pollable as _};
 
 impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
     async fn start_bind(
@@ -38,14 +43,100 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         HostTcpSocket::finish_bind(&mut self.as_wasi_view(), self_)
     }
 
+    /// Connecting is performed as a single durable operation: the connection is fully
+    /// established here, and its outcome is stored until the guest collects it with
+    /// `finish_connect`. All reads and writes on the connection's streams are recorded as part
+    /// of the same batched remote write, which is closed when both streams are dropped.
+    ///
+    /// When replaying, the connection is not re-established; the recorded outcome is returned
+    /// and the streams are replaced by [`RecoveredTcpStream`], so the connection fails as soon
+    /// as it is used again after the replay.
     async fn start_connect(
         &mut self,
         self_: Resource<TcpSocket>,
         network: Resource<Network>,
         remote_address: IpSocketAddress,
     ) -> Result<(), SocketError> {
-        self.observe_function_call("sockets::tcp", "start_connect");
-        HostTcpSocket::start_connect(&mut self.as_wasi_view(), self_, network, remote_address).await
+        let handle = self_.rep();
+        let remote_address_string = SocketAddr::from(remote_address).to_string();
+
+        let begin_index = self
+            .begin_durable_function(&DurableFunctionType::WriteRemoteBatched(None))
+            .await?;
+
+        let durability = Durability::<Result<(), SerializableError>, SerializableError>::new(
+            self,
+            "sockets::tcp",
+            "connect",
+            DurableFunctionType::WriteRemoteBatched(Some(begin_index)),
+        )
+        .await?;
+
+        let (result, recovered) = if durability.is_live() {
+            let result = connect(self, self_, network, remote_address).await;
+            let serializable_result = match &result {
+                Ok(Ok(_)) => Ok(Ok(())),
+                Ok(Err(err)) => Ok(Err(SerializableError::from(err))),
+                Err(err) => Err(SerializableError::from(err)),
+            };
+            durability
+                .persist_serializable(self, remote_address_string.clone(), serializable_result)
+                .await?;
+            (result, false)
+        } else {
+            let result = match durability.replay_serializable(self).await? {
+                Ok(Ok(())) => {
+                    let input: DynInputStream = Box::new(RecoveredTcpStream);
+                    let output: DynOutputStream = Box::new(RecoveredTcpStream);
+                    let input = self.table().push_child(input, &self_)?;
+                    let output = self.table().push_child(output, &self_)?;
+                    Ok(Ok((input, output)))
+                }
+                Ok(Err(err)) => Ok(Err(SocketError::from(err))),
+                Err(err) => Err(SocketError::from(err)),
+            };
+            (result, true)
+        };
+
+        match result {
+            Ok(Ok((input, output))) => {
+                let state = TcpConnectionState {
+                    begin_index,
+                    remote_address: remote_address_string,
+                    socket: handle,
+                    recovered,
+                };
+                self.state
+                    .open_tcp_connections
+                    .insert(input.rep(), state.clone());
+                self.state.open_tcp_connections.insert(output.rep(), state);
+                self.state
+                    .pending_tcp_connects
+                    .insert(handle, Ok((input.rep(), output.rep())));
+                Ok(())
+            }
+            Ok(Err(err)) => {
+                self.end_durable_function(
+                    &DurableFunctionType::WriteRemoteBatched(None),
+                    begin_index,
+                    false,
+                )
+                .await?;
+                self.state
+                    .pending_tcp_connects
+                    .insert(handle, Err(SerializableError::from(&err)));
+                Ok(())
+            }
+            Err(err) => {
+                self.end_durable_function(
+                    &DurableFunctionType::WriteRemoteBatched(None),
+                    begin_index,
+                    false,
+                )
+                .await?;
+                Err(err)
+            }
+        }
     }
 
     fn finish_connect(
@@ -53,7 +144,11 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         self_: Resource<TcpSocket>,
     ) -> Result<(Resource<InputStream>, Resource<OutputStream>), SocketError> {
         self.observe_function_call("sockets::tcp", "finish_connect");
-        HostTcpSocket::finish_connect(&mut self.as_wasi_view(), self_)
+        match self.state.pending_tcp_connects.remove(&self_.rep()) {
+            Some(Ok((input, output))) => Ok((Resource::new_own(input), Resource::new_own(output))),
+            Some(Err(err)) => Err(SocketError::from(err)),
+            None => HostTcpSocket::finish_connect(&mut self.as_wasi_view(), self_),
+        }
     }
 
     fn start_listen(&mut self, self_: Resource<TcpSocket>) -> Result<(), SocketError> {
@@ -224,13 +319,55 @@ impl<Ctx: WorkerCtx> HostTcpSocket for DurableWorkerCtx<Ctx> {
         shutdown_type: ShutdownType,
     ) -> Result<(), SocketError> {
         self.observe_function_call("sockets::tcp", "shutdown");
+        if self.is_recovered_connection(self_.rep()) {
+            // There is no underlying connection to shut down
+            return Ok(());
+        }
         HostTcpSocket::shutdown(&mut self.as_wasi_view(), self_, shutdown_type)
     }
 
     fn drop(&mut self, rep: Resource<TcpSocket>) -> anyhow::Result<()> {
         self.observe_function_call("sockets::tcp", "drop");
+        if let Some(Ok((input, output))) = self.state.pending_tcp_connects.remove(&rep.rep()) {
+            // The guest never collected the connection's streams
+            self.table()
+                .delete(Resource::<InputStream>::new_own(input))?;
+            self.table()
+                .delete(Resource::<OutputStream>::new_own(output))?;
+        }
         HostTcpSocket::drop(&mut self.as_wasi_view(), rep)
     }
 }
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    fn is_recovered_connection(&self, socket: u32) -> bool {
+        self.state
+            .open_tcp_connections
+            .values()
+            .any(|state| state.socket == socket && state.recovered)
+    }
+}
+
+/// Starts connecting the socket and waits until the connection is established. The outer
+/// error is the one `start_connect` fails with, the inner one is what `finish_connect` fails with.
+async fn connect<Ctx: WorkerCtx>(
+    ctx: &mut DurableWorkerCtx<Ctx>,
+    socket: Resource<TcpSocket>,
+    network: Resource<Network>,
+    remote_address: IpSocketAddress,
+) -> Result<Result<(Resource<InputStream>, Resource<OutputStream>), SocketError>, SocketError> {
+    let handle = socket.rep();
+    HostTcpSocket::start_connect(&mut ctx.as_wasi_view(), socket, network, remote_address).await?;
+
+    ctx.table()
+        .get_mut(&Resource::<TcpSocket>::new_borrow(handle))?
+        .ready()
+        .await;
+
+    Ok(HostTcpSocket::finish_connect(
+        &mut ctx.as_wasi_view(),
+        Resource::new_borrow(handle),
+    ))
+}
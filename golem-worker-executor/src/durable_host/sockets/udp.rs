@@ -12,10 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use wasmtime::component::Resource;
+use std::net::SocketAddr;
+use wasmtime::component::{Linker, Resource};
+use wasmtime::StoreContextMut;
 
-use crate::durable_host::{DurabilityHost, DurableWorkerCtx};
+use crate::durable_host::serialized::SerializableError;
+use crate::durable_host::{Durability, DurabilityHost, DurableWorkerCtx};
 use crate::workerctx::WorkerCtx;
+use golem_common::model::oplog::DurableFunctionType;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use wasmtime_wasi::p2::bindings::sockets::network::ErrorCode;
 use wasmtime_wasi::p2::bindings::sockets::udp::{
     Host, HostIncomingDatagramStream, HostOutgoingDatagramStream, HostUdpSocket, IncomingDatagram,
     IncomingDatagramStream, IpAddressFamily, IpSocketAddress, Network, OutgoingDatagram,
@@ -129,6 +135,7 @@ impl<Ctx: WorkerCtx> HostUdpSocket for DurableWorkerCtx<Ctx> {
 }
 
 impl<Ctx: WorkerCtx> HostIncomingDatagramStream for DurableWorkerCtx<Ctx> {
+    // Not used by workers, as `add_durable_receive_to_linker` replaces it with `durable_receive`
     fn receive(
         &mut self,
         self_: Resource<IncomingDatagramStream>,
@@ -163,8 +170,22 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
         self_: Resource<OutgoingDatagramStream>,
         datagrams: Vec<OutgoingDatagram>,
     ) -> Result<u64, SocketError> {
-        self.observe_function_call("sockets::udp", "send");
-        HostOutgoingDatagramStream::send(&mut self.as_wasi_view(), self_, datagrams).await
+        let durability = Durability::<u64, SerializableError>::new(
+            self,
+            "sockets::udp",
+            "send",
+            DurableFunctionType::WriteRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let count = datagrams.len() as u64;
+            let result =
+                HostOutgoingDatagramStream::send(&mut self.as_wasi_view(), self_, datagrams).await;
+            durability.persist(self, count, result).await
+        } else {
+            durability.replay(self).await
+        }
     }
 
     fn subscribe(
@@ -182,3 +203,142 @@ impl<Ctx: WorkerCtx> HostOutgoingDatagramStream for DurableWorkerCtx<Ctx> {
 }
 
 impl<Ctx: WorkerCtx> Host for DurableWorkerCtx<Ctx> {}
+
+impl<Ctx: WorkerCtx> DurableWorkerCtx<Ctx> {
+    /// Receives datagrams, recording them in the oplog so the same datagrams are received
+    /// when the worker is replayed.
+    ///
+    /// `receive` is not an async host function in `wasmtime-wasi`, so this implementation is
+    /// linked in place of [`HostIncomingDatagramStream::receive`] by [`add_durable_receive_to_linker`].
+    pub async fn durable_receive(
+        &mut self,
+        self_: Resource<IncomingDatagramStream>,
+        max_results: u64,
+    ) -> Result<Vec<IncomingDatagram>, SocketError> {
+        let durability = Durability::<Vec<(Vec<u8>, String)>, SerializableError>::new(
+            self,
+            "sockets::udp",
+            "receive",
+            DurableFunctionType::ReadRemote,
+        )
+        .await?;
+
+        if durability.is_live() {
+            let result =
+                HostIncomingDatagramStream::receive(&mut self.as_wasi_view(), self_, max_results);
+            let serializable_result = match &result {
+                Ok(datagrams) => Ok(serialize_datagrams(datagrams)),
+                Err(err) => Err(SerializableError::from(err)),
+            };
+            durability
+                .persist_serializable(self, max_results, serializable_result)
+                .await?;
+            result
+        } else {
+            match durability.replay_serializable(self).await? {
+                Ok(datagrams) => deserialize_datagrams(datagrams).map_err(SocketError::from),
+                Err(err) => Err(SocketError::from(err)),
+            }
+        }
+    }
+}
+
+/// Replaces the default `incoming-datagram-stream.receive` host function with the durable
+/// [`DurableWorkerCtx::durable_receive`]
+pub fn add_durable_receive_to_linker<Ctx: WorkerCtx + Send + Sync, F>(
+    linker: &mut Linker<Ctx>,
+    get: F,
+) -> wasmtime::Result<()>
+where
+    F: for<'a> Fn(&'a mut Ctx) -> &'a mut DurableWorkerCtx<Ctx> + Send,
+    F: Copy + Send + Sync + 'static,
+{
+    linker.allow_shadowing(true);
+    linker.instance("wasi:sockets/udp@0.2.3")?.func_wrap_async(
+        "[method]incoming-datagram-stream.receive",
+        move |mut caller: StoreContextMut<'_, Ctx>,
+              (self_, max_results): (Resource<IncomingDatagramStream>, u64)| {
+            Box::new(async move {
+                let ctx = get(caller.data_mut());
+                let result: Result<Vec<IncomingDatagram>, ErrorCode> =
+                    match ctx.durable_receive(self_, max_results).await {
+                        Ok(datagrams) => Ok(datagrams),
+                        Err(err) => Err(err.downcast()?),
+                    };
+                Ok((result,))
+            })
+        },
+    )?;
+    linker.allow_shadowing(false);
+    Ok(())
+}
+
+fn serialize_datagrams(datagrams: &[IncomingDatagram]) -> Vec<(Vec<u8>, String)> {
+    datagrams
+        .iter()
+        .map(|datagram| {
+            (
+                datagram.data.clone(),
+                SocketAddr::from(datagram.remote_address).to_string(),
+            )
+        })
+        .collect()
+}
+
+fn deserialize_datagrams(
+    datagrams: Vec<(Vec<u8>, String)>,
+) -> Result<Vec<IncomingDatagram>, WorkerExecutorError> {
+    datagrams
+        .into_iter()
+        .map(|(data, remote_address)| {
+            let remote_address: SocketAddr = remote_address.parse().map_err(|err| {
+                WorkerExecutorError::runtime(format!(
+                    "Invalid recorded datagram address {remote_address}: {err}"
+                ))
+            })?;
+            Ok(IncomingDatagram {
+                data,
+                remote_address: remote_address.into(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::durable_host::sockets::udp::{deserialize_datagrams, serialize_datagrams};
+    use std::net::SocketAddr;
+    use test_r::test;
+    use wasmtime_wasi::p2::bindings::sockets::udp::IncomingDatagram;
+
+    #[test]
+    fn recorded_datagrams_are_replayed_unchanged() {
+        let datagrams = vec![
+            IncomingDatagram {
+                data: vec![1, 2, 3],
+                remote_address: "127.0.0.1:5000".parse::<SocketAddr>().unwrap().into(),
+            },
+            IncomingDatagram {
+                data: vec![],
+                remote_address: "[::1]:6000".parse::<SocketAddr>().unwrap().into(),
+            },
+        ];
+
+        let replayed = deserialize_datagrams(serialize_datagrams(&datagrams)).unwrap();
+
+        assert_eq!(replayed.len(), 2);
+        for (original, replayed) in datagrams.iter().zip(replayed.iter()) {
+            assert_eq!(original.data, replayed.data);
+            assert_eq!(
+                SocketAddr::from(original.remote_address),
+                SocketAddr::from(replayed.remote_address)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_recorded_address_fails_replay() {
+        let result = deserialize_datagrams(vec![(vec![1], "not-an-address".to_string())]);
+        assert!(result.is_err());
+    }
+}
@@ -18,6 +18,7 @@ use crate::workerctx::WorkerCtx;
 use golem_api_grpc::proto::golem::common::ResourceLimits as GrpcResourceLimits;
 use golem_common::base_model::{TargetWorkerId, WorkerId};
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::oplog::InvocationPriority;
 use golem_common::model::{AccountId, ComponentVersion, IdempotencyKey, ProjectId, WorkerMetadata};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use golem_service_base::model::Component;
@@ -49,6 +50,7 @@ pub trait GrpcInvokeRequest: CanStartWorker {
     fn idempotency_key(&self) -> Result<Option<IdempotencyKey>, WorkerExecutorError>;
     fn name(&self) -> String;
     fn invocation_context(&self) -> InvocationContextStack;
+    fn invocation_priority(&self) -> InvocationPriority;
 }
 
 trait ProtobufInvocationDetails {
@@ -284,6 +286,10 @@ impl GrpcInvokeRequest for golem_api_grpc::proto::golem::workerexecutor::v1::Inv
     fn invocation_context(&self) -> InvocationContextStack {
         from_proto_invocation_context(&self.context)
     }
+
+    fn invocation_priority(&self) -> InvocationPriority {
+        self.priority().into()
+    }
 }
 
 impl ProtobufInvocationDetails
@@ -335,6 +341,10 @@ impl GrpcInvokeRequest
     fn invocation_context(&self) -> InvocationContextStack {
         from_proto_invocation_context(&self.context)
     }
+
+    fn invocation_priority(&self) -> InvocationPriority {
+        self.priority().into()
+    }
 }
 
 impl GrpcInvokeRequest
@@ -358,6 +368,10 @@ impl GrpcInvokeRequest
     fn invocation_context(&self) -> InvocationContextStack {
         from_proto_invocation_context(&self.context)
     }
+
+    fn invocation_priority(&self) -> InvocationPriority {
+        self.priority().into()
+    }
 }
 
 impl GrpcInvokeRequest
@@ -381,6 +395,10 @@ impl GrpcInvokeRequest
     fn invocation_context(&self) -> InvocationContextStack {
         from_proto_invocation_context(&self.context)
     }
+
+    fn invocation_priority(&self) -> InvocationPriority {
+        self.priority().into()
+    }
 }
 
 /// Assumes what component version a worker will execute the next enqueued invocation with
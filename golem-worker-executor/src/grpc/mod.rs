@@ -19,15 +19,16 @@ use crate::model::event::InternalWorkerEvent;
 use crate::model::public_oplog::{
     find_component_version_at, get_public_oplog_chunk, search_public_oplog,
 };
-use crate::model::{LastError, ReadFileResult};
+use crate::model::{LastError, LookupResult, ReadFileResult};
 use crate::services::events::Event;
+use crate::services::saga::start_compensation;
 use crate::services::worker_activator::{DefaultWorkerActivator, LazyWorkerActivator};
 use crate::services::worker_event::WorkerEventReceiver;
 use crate::services::{
     All, HasActiveWorkers, HasAll, HasComponentService, HasEvents, HasOplogService, HasPlugins,
-    HasProjectService, HasPromiseService, HasRunningWorkerEnumerationService,
-    HasShardManagerService, HasShardService, HasWorkerEnumerationService, HasWorkerService,
-    UsesAllDeps,
+    HasProjectService, HasPromiseService, HasRpc, HasRunningWorkerEnumerationService,
+    HasSagaService, HasSchedulerService, HasShardManagerService, HasShardService,
+    HasWorkerEnumerationService, HasWorkerService, UsesAllDeps,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
@@ -38,16 +39,26 @@ use golem_api_grpc::proto::golem;
 use golem_api_grpc::proto::golem::worker::{Cursor, UpdateMode};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_server::WorkerExecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
-    ActivatePluginRequest, ActivatePluginResponse, CancelInvocationRequest,
-    CancelInvocationResponse, ConnectWorkerRequest, DeactivatePluginRequest,
+    AbortSagaRequest, AbortSagaResponse, ActivatePluginRequest, ActivatePluginResponse,
+    CancelInvocationRequest, CancelInvocationResponse, CancelInvocationScheduleRequest,
+    CancelInvocationScheduleResponse, ChangeInvocationPriorityRequest,
+    ChangeInvocationPriorityResponse, ConnectWorkerRequest, DeactivatePluginRequest,
     DeactivatePluginResponse, DeleteWorkerRequest, ForkWorkerRequest, ForkWorkerResponse,
     GetFileContentsRequest, GetFileContentsResponse, GetFileSystemNodeRequest,
-    GetFileSystemNodeResponse, GetOplogRequest, GetOplogResponse, GetRunningWorkersMetadataRequest,
-    GetRunningWorkersMetadataResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
+    GetFileSystemNodeResponse, GetInvocationResultRequest, GetInvocationResultResponse,
+    GetInvocationSchedulesRequest, GetInvocationSchedulesResponse, GetOplogRequest,
+    GetOplogResponse, GetPendingInvocationsRequest, GetPendingInvocationsResponse,
+    GetPendingPromisesRequest, GetPendingPromisesResponse, GetRunningWorkersMetadataRequest,
+    GetRunningWorkersMetadataResponse, GetSagasRequest, GetSagasResponse,
+    GetWorkerTtlDeletionsRequest, GetWorkerTtlDeletionsResponse, GetWorkerTtlPolicyRequest,
+    GetWorkerTtlPolicyResponse, GetWorkersMetadataRequest, GetWorkersMetadataResponse,
     InvokeAndAwaitWorkerJsonRequest, InvokeAndAwaitWorkerRequest,
     InvokeAndAwaitWorkerResponseTyped, InvokeAndAwaitWorkerSuccess, InvokeJsonWorkerRequest,
-    InvokeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse, SearchOplogRequest,
-    SearchOplogResponse, UpdateWorkerRequest, UpdateWorkerResponse,
+    InvokeWorkerResponse, PurgePendingInvocationsRequest, PurgePendingInvocationsResponse,
+    RevertWorkerRequest, RevertWorkerResponse, ScheduleInvocationRequest,
+    ScheduleInvocationResponse, SearchOplogRequest, SearchOplogResponse, SetWorkerTtlPolicyRequest,
+    SetWorkerTtlPolicyResponse, UpdateWorkerLabelsRequest, UpdateWorkerLabelsResponse,
+    UpdateWorkerRequest, UpdateWorkerResponse, WorkerTtlPolicyTarget,
 };
 use golem_common::grpc::{
     proto_account_id_string, proto_component_id_string, proto_idempotency_key_string,
@@ -55,16 +66,22 @@ use golem_common::grpc::{
     proto_worker_id_string,
 };
 use golem_common::metrics::api::record_new_grpc_api_active_stream;
-use golem_common::model::oplog::{OplogIndex, UpdateDescription};
+use golem_common::model::oplog::{InvocationPriority, OplogIndex, UpdateDescription};
 use golem_common::model::protobuf::to_protobuf_resource_description;
 use golem_common::model::{
     AccountId, ComponentFilePath, ComponentId, ComponentType, GetFileSystemNodeResult,
-    IdempotencyKey, OwnedWorkerId, PluginInstallationId, ProjectId, ScanCursor, ShardId,
+    IdempotencyKey, OwnedWorkerId, PluginInstallationId, ProjectId, SagaId, ScanCursor, ShardId,
     TimestampedWorkerInvocation, WorkerEvent, WorkerFilter, WorkerId, WorkerInvocation,
     WorkerMetadata, WorkerStatus,
 };
 use golem_common::{model as common_model, recorded_grpc_api_request};
 use golem_service_base::error::worker_executor::*;
+use golem_service_base::model::invocation_result::InvocationResultStatus;
+use golem_service_base::model::invocation_schedule::{InvocationSchedule, InvocationScheduleSpec};
+use golem_service_base::model::pending_invocation::PendingInvocation;
+use golem_service_base::model::pending_promise::PendingPromise;
+use golem_service_base::model::saga::Saga;
+use golem_service_base::model::worker_ttl::{WorkerTtlDeletion, WorkerTtlPolicy};
 use golem_wasm_rpc::protobuf::Val;
 use golem_wasm_rpc::ValueAndType;
 use std::cmp::min;
@@ -74,6 +91,7 @@ use std::marker::PhantomData;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 use tokio;
 use tokio::sync::broadcast::error::RecvError;
 use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
@@ -83,6 +101,13 @@ use tracing::{debug, info, warn, Instrument};
 use uuid::Uuid;
 use wasmtime::Error;
 
+/// Upper limit of how long a single `get_invocation_result` request may wait for the invocation
+/// to complete. Callers needing longer waits have to poll again.
+pub const MAX_INVOCATION_RESULT_WAIT: Duration = Duration::from_secs(60);
+
+/// Number of workers enumerated at once when scheduling the time-to-live checks of a component
+const TTL_SWEEP_PAGE_SIZE: u64 = 100;
+
 pub enum GrpcError<E> {
     Transport(tonic::transport::Error),
     Status(Status),
@@ -305,6 +330,10 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         )
         .await?;
 
+        if !request.labels.is_empty() {
+            worker.update_labels(request.labels, vec![]).await?;
+        }
+
         let mut subscription = self.events().subscribe();
         Worker::start_if_needed(worker.clone()).await?;
         if worker.is_loading() {
@@ -393,6 +422,20 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         Ok(success)
     }
 
+    async fn get_pending_promises_internal(
+        &self,
+        request: GetPendingPromisesRequest,
+    ) -> Result<Vec<PendingPromise>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        Ok(self
+            .promise_service()
+            .get_pending(&owned_worker_id.worker_id)
+            .await)
+    }
+
     async fn delete_worker_internal(
         &self,
         request: DeleteWorkerRequest,
@@ -402,45 +445,7 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        if let Some(metadata) =
-            Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id).await?
-        {
-            let should_interrupt = match &metadata.last_known_status.status {
-                WorkerStatus::Idle
-                | WorkerStatus::Running
-                | WorkerStatus::Suspended
-                | WorkerStatus::Retrying => true,
-                WorkerStatus::Exited | WorkerStatus::Failed | WorkerStatus::Interrupted => false,
-            };
-
-            if should_interrupt {
-                let worker = Worker::get_or_create_suspended(
-                    self,
-                    &account_id,
-                    &owned_worker_id,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                )
-                .await?;
-
-                if let Some(mut await_interrupted) =
-                    worker.set_interrupting(InterruptKind::Interrupt).await
-                {
-                    await_interrupted.recv().await.unwrap();
-                }
-
-                worker.stop().await;
-            }
-
-            Ctx::on_worker_deleted(self, &owned_worker_id.worker_id).await?;
-            self.worker_service().remove(&owned_worker_id).await;
-            self.active_workers().remove(&owned_worker_id.worker_id);
-        }
-
-        Ok(())
+        Worker::<Ctx>::delete(self, &account_id, &owned_worker_id).await
     }
 
     async fn fork_worker_internal(
@@ -599,121 +604,121 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn interrupt_worker_internal(
+    async fn get_invocation_result_internal(
         &self,
-        request: golem::workerexecutor::v1::InterruptWorkerRequest,
-    ) -> Result<(), WorkerExecutorError> {
+        request: GetInvocationResultRequest,
+    ) -> Result<InvocationResultStatus, WorkerExecutorError> {
         let owned_worker_id =
             extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
         let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id).await?;
+        let idempotency_key: IdempotencyKey = request
+            .idempotency_key
+            .ok_or(WorkerExecutorError::invalid_request(
+                "idempotency_key not found",
+            ))?
+            .into();
 
-        match metadata {
-            None => {
-                // Worker does not exist, we still check if it is in the list active workers due to some inconsistency
-                if let Some((_, worker)) = self
-                    .active_workers()
-                    .snapshot()
-                    .iter()
-                    .find(|(id, _)| id == &owned_worker_id.worker_id)
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
+            .await?
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
+
+        let status = &metadata.last_known_status;
+        let is_known = status.invocation_results.contains_key(&idempotency_key)
+            || status.current_idempotency_key.as_ref() == Some(&idempotency_key)
+            || status.pending_invocations.iter().any(|invocation| {
+                invocation.invocation.idempotency_key() == Some(&idempotency_key)
+            });
+        if !is_known {
+            return Err(WorkerExecutorError::invocation_not_found(
+                owned_worker_id.worker_id(),
+                idempotency_key,
+            ));
+        }
+
+        let worker = Worker::get_or_create_suspended(
+            self,
+            &account_id,
+            &owned_worker_id,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await?;
+
+        let wait = request
+            .wait_timeout_millis
+            .map(|millis| Duration::from_millis(millis).min(MAX_INVOCATION_RESULT_WAIT));
+        match worker.lookup_invocation(&idempotency_key, wait).await {
+            LookupResult::Complete(Ok(result)) => Ok(InvocationResultStatus::Completed(result)),
+            LookupResult::Complete(Err(err)) => Ok(InvocationResultStatus::Failed(err)),
+            LookupResult::Interrupted => Ok(InvocationResultStatus::Failed(
+                InterruptKind::Interrupt.into(),
+            )),
+            LookupResult::Pending | LookupResult::New => {
+                let metadata = worker.get_metadata()?;
+                if metadata.last_known_status.current_idempotency_key.as_ref()
+                    == Some(&idempotency_key)
                 {
-                    worker
-                        .set_interrupting(if request.recover_immediately {
-                            InterruptKind::Restart
-                        } else {
-                            InterruptKind::Interrupt
-                        })
-                        .await;
+                    Ok(InvocationResultStatus::Running)
+                } else {
+                    Ok(InvocationResultStatus::Pending)
                 }
             }
-            Some(metadata) => match &metadata.last_known_status.status {
-                WorkerStatus::Exited => {
-                    warn!("Attempted interrupting worker which already exited")
-                }
-                WorkerStatus::Idle => {
-                    warn!("Attempted interrupting worker which is idle")
-                }
-                WorkerStatus::Failed => {
-                    warn!("Attempted interrupting worker which is failed")
-                }
-                WorkerStatus::Interrupted => {
-                    warn!("Attempted interrupting worker which is already interrupted")
-                }
-                WorkerStatus::Suspended => {
-                    debug!("Marking suspended worker as interrupted");
-                    let worker = Worker::get_or_create_suspended(
-                        self,
-                        &account_id,
-                        &owned_worker_id,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                    )
-                    .await?;
-                    worker.set_interrupting(InterruptKind::Interrupt).await;
-                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
-                    self.active_workers().remove(&owned_worker_id.worker_id);
-                }
-                WorkerStatus::Retrying => {
-                    debug!("Marking worker scheduled to be retried as interrupted");
-                    let worker = Worker::get_or_create_suspended(
-                        self,
-                        &account_id,
-                        &owned_worker_id,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                    )
-                    .await?;
-                    worker.set_interrupting(InterruptKind::Interrupt).await;
-                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
-                    self.active_workers().remove(&owned_worker_id.worker_id);
-                }
-                WorkerStatus::Running => {
-                    let worker = Worker::get_or_create_suspended(
-                        self,
-                        &account_id,
-                        &owned_worker_id,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                    )
-                    .await?;
-                    worker
-                        .set_interrupting(if request.recover_immediately {
-                            InterruptKind::Restart
-                        } else {
-                            InterruptKind::Interrupt
-                        })
-                        .await;
-
-                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
-                    self.active_workers().remove(&owned_worker_id.worker_id);
-                }
-            },
         }
+    }
 
-        Ok(())
+    async fn get_pending_invocations_internal(
+        &self,
+        request: GetPendingInvocationsRequest,
+    ) -> Result<Vec<PendingInvocation>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
+            .await?
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
+
+        let status = metadata.last_known_status;
+        Ok(status
+            .pending_invocations
+            .into_iter()
+            .map(|invocation| {
+                let priority = invocation
+                    .invocation
+                    .idempotency_key()
+                    .and_then(|key| status.invocation_priorities.get(key))
+                    .copied()
+                    .unwrap_or_default();
+                PendingInvocation::new(invocation, priority)
+            })
+            .collect())
     }
 
-    async fn resume_worker_internal(
+    async fn change_invocation_priority_internal(
         &self,
-        request: golem::workerexecutor::v1::ResumeWorkerRequest,
-    ) -> Result<(), WorkerExecutorError> {
+        request: ChangeInvocationPriorityRequest,
+    ) -> Result<bool, WorkerExecutorError> {
         let owned_worker_id =
             extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
         let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let force_resume = request.force.unwrap_or(false);
+        let priority: InvocationPriority = request.priority().into();
+        let idempotency_key: IdempotencyKey = request
+            .idempotency_key
+            .ok_or(WorkerExecutorError::invalid_request(
+                "idempotency_key not found",
+            ))?
+            .into();
 
         let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
             .await?
@@ -721,166 +726,173 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                 owned_worker_id.worker_id(),
             ))?;
 
-        self.ensure_not_failed(&owned_worker_id, &metadata).await?;
-
-        match &metadata.last_known_status.status {
-            WorkerStatus::Suspended | WorkerStatus::Interrupted | WorkerStatus::Idle => {
-                info!(
-                    "Activating {:?} worker {owned_worker_id} due to explicit resume request",
-                    metadata.last_known_status.status
-                );
-                let _ = Worker::get_or_create_running(
-                    &self.services,
-                    &account_id,
-                    &owned_worker_id,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                )
-                .await?;
-                Ok(())
-            }
-            _ if force_resume => {
-                info!(
-                    "Force activating {:?} worker {owned_worker_id} due to explicit resume request",
-                    metadata.last_known_status.status
-                );
-                let _ = Worker::get_or_create_running(
-                    &self.services,
-                    &account_id,
-                    &owned_worker_id,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                )
-                .await?;
-                Ok(())
-            }
-            _ => Err(WorkerExecutorError::invalid_request(format!(
-                "Worker {worker_id} is not suspended, interrupted or idle",
-                worker_id = owned_worker_id.worker_id
-            ))),
+        if metadata
+            .last_known_status
+            .pending_invocations
+            .iter()
+            .any(|invocation| invocation.invocation.idempotency_key() == Some(&idempotency_key))
+        {
+            let worker = Worker::get_or_create_suspended(
+                self,
+                &account_id,
+                &owned_worker_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?;
+            worker
+                .change_invocation_priority(idempotency_key, priority)
+                .await
+        } else if metadata
+            .last_known_status
+            .invocation_results
+            .contains_key(&idempotency_key)
+            || metadata.last_known_status.current_idempotency_key.as_ref() == Some(&idempotency_key)
+        {
+            Ok(false)
+        } else {
+            Err(WorkerExecutorError::invocation_not_found(
+                owned_worker_id.worker_id(),
+                idempotency_key,
+            ))
         }
     }
 
-    async fn invoke_and_await_worker_internal_proto<Req: GrpcInvokeRequest>(
+    async fn purge_pending_invocations_internal(
         &self,
-        request: &Req,
-    ) -> Result<Option<Val>, WorkerExecutorError> {
-        let result = self.invoke_and_await_worker_internal_typed(request).await?;
-        let value = result
-            .map(golem_wasm_rpc::Value::try_from)
-            .transpose()
-            .map_err(|e| WorkerExecutorError::unknown(e.to_string()))?
-            .map(|value| value.into());
-        Ok(value)
-    }
+        request: PurgePendingInvocationsRequest,
+    ) -> Result<Vec<IdempotencyKey>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-    async fn invoke_and_await_worker_internal_typed<Req: GrpcInvokeRequest>(
-        &self,
-        request: &Req,
-    ) -> Result<Option<ValueAndType>, WorkerExecutorError> {
-        let full_function_name = request.name();
-
-        let worker = self.get_or_create(request).await?;
-
-        let idempotency_key = request
-            .idempotency_key()?
-            .unwrap_or(IdempotencyKey::fresh());
-
-        let params_val = request.input(&worker).await?;
-
-        let function_input = params_val
-            .into_iter()
-            .map(|val| val.clone().try_into())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|msg| WorkerExecutorError::ValueMismatch { details: msg })?;
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
+            .await?
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
 
-        let value = worker
-            .invoke_and_await(
-                idempotency_key,
-                full_function_name,
-                function_input,
-                request.invocation_context(),
+        if metadata.last_known_status.pending_invocations.is_empty() {
+            Ok(vec![])
+        } else {
+            let worker = Worker::get_or_create_suspended(
+                self,
+                &account_id,
+                &owned_worker_id,
+                None,
+                None,
+                None,
+                None,
+                None,
             )
             .await?;
-
-        Ok(value)
+            worker.purge_pending_invocations().await
+        }
     }
 
-    async fn get_or_create<Req: CanStartWorker>(
+    async fn get_sagas_internal(
         &self,
-        request: &Req,
-    ) -> Result<Arc<Worker<Ctx>>, WorkerExecutorError> {
-        let worker = self.get_or_create_pending(request).await?;
-        Worker::start_if_needed(worker.clone()).await?;
-        Ok(worker)
+        request: GetSagasRequest,
+    ) -> Result<Vec<Saga>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        if Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
+            .await?
+            .is_none()
+        {
+            return Err(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ));
+        }
+
+        self.saga_service().get_all(&owned_worker_id).await
     }
 
-    async fn get_or_create_pending<Req: CanStartWorker>(
+    async fn abort_saga_internal(
         &self,
-        request: &Req,
-    ) -> Result<Arc<Worker<Ctx>>, WorkerExecutorError> {
-        let target_worker_id = request.worker_id()?;
-        let project_id = request.project_id()?;
+        request: AbortSagaRequest,
+    ) -> Result<Saga, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let current_assignment = self.shard_service().current_assignment()?;
+        let saga_id: SagaId = request
+            .saga_id
+            .and_then(|saga_id| saga_id.try_into().ok())
+            .ok_or(WorkerExecutorError::invalid_request("Invalid saga id"))?;
+        let reason = request
+            .reason
+            .unwrap_or_else(|| format!("Aborted by {account_id}"));
+
+        let saga = self
+            .saga_service()
+            .abort(&owned_worker_id, &saga_id, &reason)
+            .await?;
 
-        let unspecified_name = target_worker_id.worker_name.is_none();
-        let worker_id = target_worker_id.into_worker_id(
-            &current_assignment.shard_ids,
-            current_assignment.number_of_shards,
+        // Compensating invocations are performed in the name of the account owning the coordinator,
+        // retrying them the same way as the coordinator's own failures
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id).await?;
+        let retry_config = metadata
+            .as_ref()
+            .and_then(|metadata| metadata.last_known_status.overridden_retry_config.clone())
+            .unwrap_or_else(|| self.services.config().retry.clone());
+        let created_by = metadata
+            .map(|metadata| metadata.created_by)
+            .unwrap_or(account_id);
+        start_compensation(
+            self.saga_service(),
+            self.rpc(),
+            owned_worker_id,
+            created_by,
+            retry_config,
+            saga_id,
         );
 
-        if unspecified_name {
-            info!(
-                worker_id = worker_id.to_string(),
-                "Generated new unique worker id"
-            );
-        }
-
-        let account_id: AccountId = request.account_id()?;
-        let owned_worker_id = OwnedWorkerId::new(&project_id, &worker_id);
-        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
-
-        let metadata = self.worker_service().get(&owned_worker_id).await;
+        Ok(saga)
+    }
 
-        if let Some(metadata) = &metadata {
-            self.ensure_not_failed(&owned_worker_id, metadata).await?;
-        }
+    async fn schedule_invocation_internal(
+        &self,
+        request: ScheduleInvocationRequest,
+    ) -> Result<InvocationSchedule, WorkerExecutorError> {
+        let schedule: InvocationScheduleSpec = request
+            .schedule
+            .ok_or(WorkerExecutorError::invalid_request("schedule not found"))?
+            .try_into()
+            .map_err(WorkerExecutorError::invalid_request)?;
 
-        if let Some(limits) = request.account_limits() {
-            Ctx::record_last_known_limits(self, &project_id, &limits.into()).await?;
+        match request.invocation {
+            Some(golem::workerexecutor::v1::schedule_invocation_request::Invocation::Invoke(
+                invoke,
+            )) => self.create_invocation_schedule(&invoke, schedule).await,
+            Some(
+                golem::workerexecutor::v1::schedule_invocation_request::Invocation::InvokeJson(
+                    invoke,
+                ),
+            ) => self.create_invocation_schedule(&invoke, schedule).await,
+            None => Err(WorkerExecutorError::invalid_request("invocation not found")),
         }
-
-        Worker::get_or_create_suspended(
-            self,
-            &account_id,
-            &owned_worker_id,
-            request.args(),
-            request.env(),
-            request.wasi_config_vars()?,
-            None,
-            request.parent(),
-        )
-        .await
     }
 
-    async fn invoke_worker_internal<Req: GrpcInvokeRequest>(
+    async fn create_invocation_schedule<Req: GrpcInvokeRequest>(
         &self,
         request: &Req,
-    ) -> Result<(), WorkerExecutorError> {
-        let full_function_name = request.name();
-
-        let worker = self.get_or_create(request).await?;
+        schedule: InvocationScheduleSpec,
+    ) -> Result<InvocationSchedule, WorkerExecutorError> {
+        let worker_id = request.worker_id()?.try_into_worker_id().ok_or(
+            WorkerExecutorError::invalid_request("Scheduled invocations require a worker name"),
+        )?;
+        let owned_worker_id = OwnedWorkerId::new(&request.project_id()?, &worker_id);
 
-        let idempotency_key = request
-            .idempotency_key()?
-            .unwrap_or(IdempotencyKey::fresh());
+        // Making sure the worker exists, and using its component metadata to interpret the input
+        let worker = self.get_or_create_pending(request).await?;
 
         let function_input = request
             .input(&worker)
@@ -890,111 +902,154 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .collect::<Result<Vec<_>, _>>()
             .map_err(|msg| WorkerExecutorError::ValueMismatch { details: msg })?;
 
-        worker
-            .invoke(
-                idempotency_key,
-                full_function_name,
+        self.scheduler_service()
+            .create_invocation_schedule(
+                &request.account_id()?,
+                &owned_worker_id,
+                request.name(),
                 function_input,
                 request.invocation_context(),
+                schedule,
             )
-            .await?;
-
-        Ok(())
+            .await
     }
 
-    async fn revoke_shards_internal(
+    async fn get_invocation_schedules_internal(
         &self,
-        request: golem::workerexecutor::v1::RevokeShardsRequest,
-    ) -> Result<(), WorkerExecutorError> {
-        let proto_shard_ids = request.shard_ids;
+        request: GetInvocationSchedulesRequest,
+    ) -> Result<Vec<InvocationSchedule>, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let shard_ids = proto_shard_ids.into_iter().map(ShardId::from).collect();
+        self.scheduler_service()
+            .get_invocation_schedules(&owned_worker_id)
+            .await
+    }
 
-        self.shard_service().revoke_shards(&shard_ids)?;
+    async fn cancel_invocation_schedule_internal(
+        &self,
+        request: CancelInvocationScheduleRequest,
+    ) -> Result<bool, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        for (worker_id, worker_details) in self.active_workers().snapshot() {
-            if self.shard_service().check_worker(&worker_id).is_err() {
-                if let Some(mut await_interrupted) = worker_details
-                    .set_interrupting(InterruptKind::Restart)
-                    .await
-                {
-                    await_interrupted.recv().await.unwrap();
-                }
-            }
-        }
+        let schedule_id = request
+            .schedule_id
+            .ok_or(WorkerExecutorError::invalid_request(
+                "schedule_id not found",
+            ))?
+            .try_into()
+            .map_err(WorkerExecutorError::invalid_request)?;
 
-        Ok(())
+        self.scheduler_service()
+            .cancel_invocation_schedule(&owned_worker_id, &schedule_id)
+            .await
     }
 
-    async fn assign_shards_internal(
+    async fn set_worker_ttl_policy_internal(
         &self,
-        request: golem::workerexecutor::v1::AssignShardsRequest,
+        request: SetWorkerTtlPolicyRequest,
     ) -> Result<(), WorkerExecutorError> {
-        let proto_shard_ids = request.shard_ids;
-
-        let shard_ids = proto_shard_ids.into_iter().map(ShardId::from).collect();
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        let policy: Option<WorkerTtlPolicy> = request.policy.map(|policy| policy.into());
 
-        self.shard_service().assign_shards(&shard_ids)?;
-        Ctx::on_shard_assignment_changed(self).await?;
+        match extract_ttl_policy_target(&request.target, &request.project_id)? {
+            TtlPolicyTarget::Component(project_id, component_id) => {
+                let has_policy = policy.is_some();
+                self.scheduler_service()
+                    .set_component_ttl_policy(&project_id, &component_id, policy)
+                    .await?;
 
-        Ok(())
-    }
+                if has_policy {
+                    self.schedule_component_ttl_checks(&project_id, &component_id)
+                        .await?;
+                }
 
-    async fn get_worker_metadata_internal(
-        &self,
-        request: golem::workerexecutor::v1::GetWorkerMetadataRequest,
-    ) -> Result<golem::worker::WorkerMetadata, WorkerExecutorError> {
-        let owned_worker_id =
-            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
-        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+                Ok(())
+            }
+            TtlPolicyTarget::Worker(owned_worker_id) => {
+                self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
-            .await?
-            .ok_or(WorkerExecutorError::worker_not_found(
-                owned_worker_id.worker_id(),
-            ))?;
+                self.scheduler_service()
+                    .set_worker_ttl_policy(&owned_worker_id, policy)
+                    .await?;
 
-        let last_error_and_retry_count = Ctx::get_last_error_and_retry_count(
-            self,
-            &owned_worker_id,
-            &metadata.last_known_status,
-        )
-        .await;
+                // Checks are only scheduled when a worker becomes idle, so an already idle worker
+                // has to be checked explicitly for the new policy to take effect
+                if self.worker_service().get(&owned_worker_id).await.is_some() {
+                    let last_oplog_index =
+                        self.oplog_service().get_last_index(&owned_worker_id).await;
+                    self.scheduler_service()
+                        .schedule_ttl_check(&account_id, &owned_worker_id, last_oplog_index)
+                        .await;
+                }
 
-        Self::create_proto_metadata(metadata, last_error_and_retry_count)
+                Ok(())
+            }
+        }
     }
 
-    async fn get_running_workers_metadata_internal(
+    /// Checks are only scheduled when a worker becomes idle, so the already existing workers of a
+    /// component have to be checked explicitly for a new component policy to take effect. The
+    /// checks get performed by the executors owning the workers.
+    async fn schedule_component_ttl_checks(
         &self,
-        request: GetRunningWorkersMetadataRequest,
-    ) -> Result<Vec<golem::worker::WorkerMetadata>, WorkerExecutorError> {
-        let component_id: ComponentId = request
-            .component_id
-            .and_then(|t| t.try_into().ok())
-            .ok_or(WorkerExecutorError::invalid_request("Invalid component id"))?;
-
-        let filter: Option<WorkerFilter> = match request.filter {
-            Some(f) => Some(f.try_into().map_err(WorkerExecutorError::invalid_request)?),
-            _ => None,
-        };
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+    ) -> Result<(), WorkerExecutorError> {
+        let mut cursor = ScanCursor::default();
+        loop {
+            let (next_cursor, workers) = self
+                .worker_enumeration_service()
+                .get(
+                    project_id,
+                    component_id,
+                    None,
+                    cursor,
+                    TTL_SWEEP_PAGE_SIZE,
+                    false,
+                )
+                .await?;
 
-        let workers = self
-            .running_worker_enumeration_service()
-            .get(&component_id, filter)
-            .await?;
+            for worker in workers {
+                let owned_worker_id = worker.owned_worker_id();
+                let last_oplog_index = self.oplog_service().get_last_index(&owned_worker_id).await;
+                self.scheduler_service()
+                    .schedule_ttl_check(&worker.created_by, &owned_worker_id, last_oplog_index)
+                    .await;
+            }
 
-        let result: Vec<golem::worker::WorkerMetadata> = workers
-            .into_iter()
-            .map(|worker_metadata| Self::create_proto_metadata(worker_metadata, None))
-            .collect::<Result<Vec<_>, _>>()?;
+            match next_cursor {
+                Some(next_cursor) => cursor = next_cursor,
+                None => break Ok(()),
+            }
+        }
+    }
 
-        Ok(result)
+    async fn get_worker_ttl_policy_internal(
+        &self,
+        request: GetWorkerTtlPolicyRequest,
+    ) -> Result<Option<WorkerTtlPolicy>, WorkerExecutorError> {
+        match extract_ttl_policy_target(&request.target, &request.project_id)? {
+            TtlPolicyTarget::Component(project_id, component_id) => {
+                self.scheduler_service()
+                    .get_component_ttl_policy(&project_id, &component_id)
+                    .await
+            }
+            TtlPolicyTarget::Worker(owned_worker_id) => {
+                self.scheduler_service()
+                    .get_worker_ttl_policy(&owned_worker_id)
+                    .await
+            }
+        }
     }
 
-    async fn get_workers_metadata_internal(
+    async fn get_worker_ttl_deletions_internal(
         &self,
-        request: GetWorkersMetadataRequest,
-    ) -> Result<(Option<Cursor>, Vec<golem::worker::WorkerMetadata>), WorkerExecutorError> {
+        request: GetWorkerTtlDeletionsRequest,
+    ) -> Result<Vec<WorkerTtlDeletion>, WorkerExecutorError> {
         let component_id: ComponentId = request
             .component_id
             .and_then(|t| t.try_into().ok())
@@ -1005,584 +1060,586 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             .and_then(|t| t.try_into().ok())
             .ok_or(WorkerExecutorError::invalid_request("Invalid project id"))?;
 
-        let filter: Option<WorkerFilter> = match request.filter {
-            Some(f) => Some(f.try_into().map_err(WorkerExecutorError::invalid_request)?),
-            _ => None,
-        };
+        self.scheduler_service()
+            .get_ttl_deletions(&project_id, &component_id)
+            .await
+    }
 
-        let (new_cursor, workers) = self
-            .worker_enumeration_service()
-            .get(
-                &project_id,
-                &component_id,
-                filter,
-                request
-                    .cursor
-                    .map(|cursor| ScanCursor {
-                        cursor: cursor.cursor,
-                        layer: cursor.layer as usize,
-                    })
-                    .unwrap_or_default(),
-                request.count,
-                request.precise,
-            )
-            .instrument(info_span!("enumerate_workers"))
-            .await?;
+    async fn interrupt_worker_internal(
+        &self,
+        request: golem::workerexecutor::v1::InterruptWorkerRequest,
+    ) -> Result<(), WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let mut result = Vec::new();
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id).await?;
 
-        for worker_metadata in workers {
-            let last_error_and_retry_count = Ctx::get_last_error_and_retry_count(
-                self,
-                &worker_metadata.owned_worker_id(),
-                &worker_metadata.last_known_status,
-            )
-            .await;
-            let metadata =
-                Self::create_proto_metadata(worker_metadata, last_error_and_retry_count)?;
-            result.push(metadata);
+        match metadata {
+            None => {
+                // Worker does not exist, we still check if it is in the list active workers due to some inconsistency
+                if let Some((_, worker)) = self
+                    .active_workers()
+                    .snapshot()
+                    .iter()
+                    .find(|(id, _)| id == &owned_worker_id.worker_id)
+                {
+                    worker
+                        .set_interrupting(if request.recover_immediately {
+                            InterruptKind::Restart
+                        } else {
+                            InterruptKind::Interrupt
+                        })
+                        .await;
+                }
+            }
+            Some(metadata) => match &metadata.last_known_status.status {
+                WorkerStatus::Exited => {
+                    warn!("Attempted interrupting worker which already exited")
+                }
+                WorkerStatus::Idle => {
+                    warn!("Attempted interrupting worker which is idle")
+                }
+                WorkerStatus::Failed => {
+                    warn!("Attempted interrupting worker which is failed")
+                }
+                WorkerStatus::Interrupted => {
+                    warn!("Attempted interrupting worker which is already interrupted")
+                }
+                WorkerStatus::Suspended => {
+                    debug!("Marking suspended worker as interrupted");
+                    let worker = Worker::get_or_create_suspended(
+                        self,
+                        &account_id,
+                        &owned_worker_id,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .await?;
+                    worker.set_interrupting(InterruptKind::Interrupt).await;
+                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
+                    self.active_workers().remove(&owned_worker_id.worker_id);
+                }
+                WorkerStatus::Retrying => {
+                    debug!("Marking worker scheduled to be retried as interrupted");
+                    let worker = Worker::get_or_create_suspended(
+                        self,
+                        &account_id,
+                        &owned_worker_id,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .await?;
+                    worker.set_interrupting(InterruptKind::Interrupt).await;
+                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
+                    self.active_workers().remove(&owned_worker_id.worker_id);
+                }
+                WorkerStatus::Running => {
+                    let worker = Worker::get_or_create_suspended(
+                        self,
+                        &account_id,
+                        &owned_worker_id,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                    .await?;
+                    worker
+                        .set_interrupting(if request.recover_immediately {
+                            InterruptKind::Restart
+                        } else {
+                            InterruptKind::Interrupt
+                        })
+                        .await;
+
+                    // Explicitly drop from the active worker cache - this will drop websocket connections etc.
+                    self.active_workers().remove(&owned_worker_id.worker_id);
+                }
+            },
         }
 
-        Ok((
-            new_cursor.map(|cursor| Cursor {
-                layer: cursor.layer as u64,
-                cursor: cursor.cursor,
-            }),
-            result,
-        ))
+        Ok(())
     }
 
-    async fn update_worker_internal(
+    async fn resume_worker_internal(
         &self,
-        request: UpdateWorkerRequest,
+        request: golem::workerexecutor::v1::ResumeWorkerRequest,
     ) -> Result<(), WorkerExecutorError> {
         let owned_worker_id =
             extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
         let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let mut metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
+        let force_resume = request.force.unwrap_or(false);
+
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
             .await?
             .ok_or(WorkerExecutorError::worker_not_found(
                 owned_worker_id.worker_id(),
             ))?;
 
-        if metadata.last_known_status.component_version == request.target_version {
-            return Err(WorkerExecutorError::invalid_request(
-                "Worker is already at the target version",
-            ));
-        }
+        self.ensure_not_failed(&owned_worker_id, &metadata).await?;
 
-        let component_metadata = self
-            .component_service()
-            .get_metadata(
-                &owned_worker_id.project_id,
-                &owned_worker_id.worker_id.component_id,
-                Some(metadata.last_known_status.component_version),
-            )
-            .await?;
-        if component_metadata.component_type == ComponentType::Ephemeral {
-            return Err(WorkerExecutorError::invalid_request(
-                "Ephemeral workers cannot be updated",
-            ));
+        match &metadata.last_known_status.status {
+            WorkerStatus::Suspended | WorkerStatus::Interrupted | WorkerStatus::Idle => {
+                info!(
+                    "Activating {:?} worker {owned_worker_id} due to explicit resume request",
+                    metadata.last_known_status.status
+                );
+                let _ = Worker::get_or_create_running(
+                    &self.services,
+                    &account_id,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                Ok(())
+            }
+            _ if force_resume => {
+                info!(
+                    "Force activating {:?} worker {owned_worker_id} due to explicit resume request",
+                    metadata.last_known_status.status
+                );
+                let _ = Worker::get_or_create_running(
+                    &self.services,
+                    &account_id,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                Ok(())
+            }
+            _ => Err(WorkerExecutorError::invalid_request(format!(
+                "Worker {worker_id} is not suspended, interrupted or idle",
+                worker_id = owned_worker_id.worker_id
+            ))),
         }
+    }
 
-        match request.mode() {
-            UpdateMode::Automatic => {
-                let update_description = UpdateDescription::Automatic {
-                    target_version: request.target_version,
-                };
+    async fn invoke_and_await_worker_internal_proto<Req: GrpcInvokeRequest>(
+        &self,
+        request: &Req,
+    ) -> Result<Option<Val>, WorkerExecutorError> {
+        let result = self.invoke_and_await_worker_internal_typed(request).await?;
+        let value = result
+            .map(golem_wasm_rpc::Value::try_from)
+            .transpose()
+            .map_err(|e| WorkerExecutorError::unknown(e.to_string()))?
+            .map(|value| value.into());
+        Ok(value)
+    }
 
-                if metadata
-                    .last_known_status
-                    .pending_updates
-                    .iter()
-                    .any(|update| update.description == update_description)
-                {
-                    return Err(WorkerExecutorError::invalid_request(
-                        "The same update is already in progress",
-                    ));
-                }
+    async fn invoke_and_await_worker_internal_typed<Req: GrpcInvokeRequest>(
+        &self,
+        request: &Req,
+    ) -> Result<Option<ValueAndType>, WorkerExecutorError> {
+        let full_function_name = request.name();
 
-                match &metadata.last_known_status.status {
-                    WorkerStatus::Exited => {
-                        warn!("Attempted updating worker which already exited")
-                    }
-                    WorkerStatus::Interrupted
-                    | WorkerStatus::Suspended
-                    | WorkerStatus::Retrying
-                    | WorkerStatus::Failed => {
-                        // The worker is not active.
-                        //
-                        // We start activating it but block on a signal.
-                        // This way we eliminate the race condition of activating the worker, but have
-                        // time to inject the pending update oplog entry so the at the time the worker
-                        // really gets activated it is going to see it and perform the update.
+        let worker = self.get_or_create(request).await?;
 
-                        debug!("Activating worker for update",);
-                        let worker = Worker::get_or_create_suspended(
-                            self,
-                            &account_id,
-                            &owned_worker_id,
-                            None,
-                            None,
-                            None,
-                            Some(metadata.last_known_status.component_version),
-                            None,
-                        )
-                        .await?;
+        let idempotency_key = request
+            .idempotency_key()?
+            .unwrap_or(IdempotencyKey::fresh());
 
-                        debug!("Enqueuing update");
-                        worker.enqueue_update(update_description.clone()).await;
+        let params_val = request.input(&worker).await?;
 
-                        if metadata.last_known_status.status == WorkerStatus::Failed {
-                            // If the worker was previously in a permanently failed state,
-                            // we reset this state to Retrying, so we can fix the failure cause
-                            // with an update.
-                            metadata.last_known_status.status = WorkerStatus::Retrying;
-                        }
-                        let mut skipped_regions =
-                            metadata.last_known_status.skipped_regions.clone();
-                        let (pending_updates, temporary_skipped_regions) =
-                            worker.pending_updates().await;
-                        skipped_regions.set_override(temporary_skipped_regions);
-                        metadata.last_known_status.pending_updates = pending_updates;
-                        metadata.last_known_status.skipped_regions = skipped_regions;
-                        worker.update_status(metadata.last_known_status).await;
+        let function_input = params_val
+            .into_iter()
+            .map(|val| val.clone().try_into())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|msg| WorkerExecutorError::ValueMismatch { details: msg })?;
 
-                        debug!("Resuming initialization to perform the update",);
-                        Worker::start_if_needed(worker.clone()).await?;
-                    }
-                    WorkerStatus::Running | WorkerStatus::Idle => {
-                        // If the worker is already running we need to write to its oplog the
-                        // update attempt, and then interrupt it and have it immediately restarting
-                        // to begin the update.
-                        let worker = Worker::get_or_create_suspended(
-                            self,
-                            &account_id,
-                            &owned_worker_id,
-                            None,
-                            None,
-                            None,
-                            None,
-                            None,
-                        )
-                        .await?;
+        let value = worker
+            .invoke_and_await(
+                idempotency_key,
+                full_function_name,
+                function_input,
+                request.invocation_context(),
+                request.invocation_priority(),
+            )
+            .await?;
 
-                        worker.enqueue_update(update_description.clone()).await;
+        Ok(value)
+    }
 
-                        debug!("Enqueued update for running worker");
+    async fn get_or_create<Req: CanStartWorker>(
+        &self,
+        request: &Req,
+    ) -> Result<Arc<Worker<Ctx>>, WorkerExecutorError> {
+        let worker = self.get_or_create_pending(request).await?;
+        Worker::start_if_needed(worker.clone()).await?;
+        Ok(worker)
+    }
 
-                        worker.set_interrupting(InterruptKind::Restart).await;
+    async fn get_or_create_pending<Req: CanStartWorker>(
+        &self,
+        request: &Req,
+    ) -> Result<Arc<Worker<Ctx>>, WorkerExecutorError> {
+        let target_worker_id = request.worker_id()?;
+        let project_id = request.project_id()?;
 
-                        debug!("Interrupted running worker for update");
-                    }
-                }
-            }
+        let current_assignment = self.shard_service().current_assignment()?;
 
-            UpdateMode::Manual => {
-                if metadata.last_known_status.pending_invocations.iter().any(|invocation|
-                    matches!(invocation, TimestampedWorkerInvocation { invocation: WorkerInvocation::ManualUpdate { target_version, .. }, ..} if *target_version == request.target_version)
-                ) {
-                    return Err(WorkerExecutorError::invalid_request(
-                        "The same update is already in progress",
-                    ));
-                }
+        let unspecified_name = target_worker_id.worker_name.is_none();
+        let worker_id = target_worker_id.into_worker_id(
+            &current_assignment.shard_ids,
+            current_assignment.number_of_shards,
+        );
 
-                // For manual update we need to invoke the worker to save the custom snapshot.
-                // This is in a race condition with other worker invocations, so the whole update
-                // process need to be initiated through the worker's invocation queue.
+        if unspecified_name {
+            info!(
+                worker_id = worker_id.to_string(),
+                "Generated new unique worker id"
+            );
+        }
 
-                let worker = Worker::get_or_create_suspended(
-                    self,
-                    &account_id,
-                    &owned_worker_id,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                )
-                .await?;
-                worker.enqueue_manual_update(request.target_version).await;
+        let account_id: AccountId = request.account_id()?;
+        let owned_worker_id = OwnedWorkerId::new(&project_id, &worker_id);
+        self.ensure_worker_belongs_to_this_executor(&worker_id)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+
+        if let Some(metadata) = &metadata {
+            self.ensure_not_failed(&owned_worker_id, metadata).await?;
+        }
+
+        if let Some(limits) = request.account_limits() {
+            Ctx::record_last_known_limits(self, &project_id, &limits.into()).await?;
+        }
+
+        Worker::get_or_create_suspended(
+            self,
+            &account_id,
+            &owned_worker_id,
+            request.args(),
+            request.env(),
+            request.wasi_config_vars()?,
+            None,
+            request.parent(),
+        )
+        .await
+    }
+
+    async fn invoke_worker_internal<Req: GrpcInvokeRequest>(
+        &self,
+        request: &Req,
+    ) -> Result<(), WorkerExecutorError> {
+        let full_function_name = request.name();
+
+        let worker = self.get_or_create(request).await?;
+
+        let idempotency_key = request
+            .idempotency_key()?
+            .unwrap_or(IdempotencyKey::fresh());
+
+        let function_input = request
+            .input(&worker)
+            .await?
+            .iter()
+            .map(|val| val.clone().try_into())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|msg| WorkerExecutorError::ValueMismatch { details: msg })?;
+
+        worker
+            .invoke(
+                idempotency_key,
+                full_function_name,
+                function_input,
+                request.invocation_context(),
+                request.invocation_priority(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn revoke_shards_internal(
+        &self,
+        request: golem::workerexecutor::v1::RevokeShardsRequest,
+    ) -> Result<(), WorkerExecutorError> {
+        let proto_shard_ids = request.shard_ids;
+
+        let shard_ids = proto_shard_ids.into_iter().map(ShardId::from).collect();
+
+        self.shard_service().revoke_shards(&shard_ids)?;
+
+        for (worker_id, worker_details) in self.active_workers().snapshot() {
+            if self.shard_service().check_worker(&worker_id).is_err() {
+                if let Some(mut await_interrupted) = worker_details
+                    .set_interrupting(InterruptKind::Restart)
+                    .await
+                {
+                    await_interrupted.recv().await.unwrap();
+                }
             }
         }
 
         Ok(())
     }
 
-    async fn connect_worker_internal(
+    async fn assign_shards_internal(
         &self,
-        request: ConnectWorkerRequest,
-    ) -> ResponseResult<<Self as WorkerExecutor>::ConnectWorkerStream> {
+        request: golem::workerexecutor::v1::AssignShardsRequest,
+    ) -> Result<(), WorkerExecutorError> {
+        let proto_shard_ids = request.shard_ids;
+
+        let shard_ids = proto_shard_ids.into_iter().map(ShardId::from).collect();
+
+        self.shard_service().assign_shards(&shard_ids)?;
+        Ctx::on_shard_assignment_changed(self).await?;
+
+        Ok(())
+    }
+
+    async fn get_worker_metadata_internal(
+        &self,
+        request: golem::workerexecutor::v1::GetWorkerMetadataRequest,
+    ) -> Result<golem::worker::WorkerMetadata, WorkerExecutorError> {
         let owned_worker_id =
             extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
-        let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let metadata = Worker::<Ctx>::get_latest_metadata(self, &owned_worker_id)
+        let metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
             .await?
             .ok_or(WorkerExecutorError::worker_not_found(
                 owned_worker_id.worker_id(),
             ))?;
 
-        self.ensure_not_failed(&owned_worker_id, &metadata).await?;
+        let last_error_and_retry_count = Ctx::get_last_error_and_retry_count(
+            self,
+            &owned_worker_id,
+            &metadata.last_known_status,
+        )
+        .await;
 
-        if metadata.last_known_status.status != WorkerStatus::Interrupted {
-            let event_service = Worker::get_or_create_suspended(
-                self,
-                &account_id,
-                &owned_worker_id,
-                None,
-                None,
-                None,
-                None,
-                None,
-            )
-            .await?
-            .event_service();
+        let file_system_usage = self.file_system_usage(&owned_worker_id).await;
 
-            let receiver = event_service.receiver();
+        Self::create_proto_metadata(metadata, last_error_and_retry_count, file_system_usage)
+    }
 
-            info!("Client connected");
-            record_new_grpc_api_active_stream();
+    async fn get_running_workers_metadata_internal(
+        &self,
+        request: GetRunningWorkersMetadataRequest,
+    ) -> Result<Vec<golem::worker::WorkerMetadata>, WorkerExecutorError> {
+        let component_id: ComponentId = request
+            .component_id
+            .and_then(|t| t.try_into().ok())
+            .ok_or(WorkerExecutorError::invalid_request("Invalid component id"))?;
 
-            Ok(Response::new(WorkerEventStream::new(receiver)))
-        } else {
-            // We don't want 'connect' to resume interrupted workers
-            Err(WorkerExecutorError::Interrupted {
-                kind: InterruptKind::Interrupt,
-            }
-            .into())
+        let filter: Option<WorkerFilter> = match request.filter {
+            Some(f) => Some(f.try_into().map_err(WorkerExecutorError::invalid_request)?),
+            _ => None,
+        };
+
+        let workers = self
+            .running_worker_enumeration_service()
+            .get(&component_id, filter)
+            .await?;
+
+        let mut result = Vec::new();
+        for worker_metadata in workers {
+            let file_system_usage = self
+                .file_system_usage(&worker_metadata.owned_worker_id())
+                .await;
+            result.push(Self::create_proto_metadata(
+                worker_metadata,
+                None,
+                file_system_usage,
+            )?);
         }
+
+        Ok(result)
     }
 
-    async fn get_oplog_internal(
+    async fn get_workers_metadata_internal(
         &self,
-        request: GetOplogRequest,
-    ) -> Result<GetOplogResponse, WorkerExecutorError> {
-        let owned_worker_id =
-            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
-        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+        request: GetWorkersMetadataRequest,
+    ) -> Result<(Option<Cursor>, Vec<golem::worker::WorkerMetadata>), WorkerExecutorError> {
+        let component_id: ComponentId = request
+            .component_id
+            .and_then(|t| t.try_into().ok())
+            .ok_or(WorkerExecutorError::invalid_request("Invalid component id"))?;
 
-        let chunk = match request.cursor {
-            Some(cursor) => get_public_oplog_chunk(
-                self.component_service(),
-                self.oplog_service(),
-                self.plugins(),
-                self.project_service(),
-                &owned_worker_id,
-                cursor.current_component_version,
-                OplogIndex::from_u64(cursor.next_oplog_index),
-                min(
-                    request.count as usize,
-                    self.services.config().limits.max_oplog_query_pages_size,
-                ),
-            )
-            .await
-            .map_err(WorkerExecutorError::unknown)?,
-            None => {
-                let start = OplogIndex::from_u64(request.from_oplog_index);
-                let initial_component_version =
-                    find_component_version_at(self.oplog_service(), &owned_worker_id, start)
-                        .await?;
+        let project_id: ProjectId = request
+            .project_id
+            .and_then(|t| t.try_into().ok())
+            .ok_or(WorkerExecutorError::invalid_request("Invalid project id"))?;
 
-                get_public_oplog_chunk(
-                    self.component_service(),
-                    self.oplog_service(),
-                    self.plugins(),
-                    self.project_service(),
-                    &owned_worker_id,
-                    initial_component_version,
-                    start,
-                    min(
-                        request.count as usize,
-                        self.services.config().limits.max_oplog_query_pages_size,
-                    ),
-                )
-                .await
-                .map_err(WorkerExecutorError::unknown)?
-            }
+        let filter: Option<WorkerFilter> = match request.filter {
+            Some(f) => Some(f.try_into().map_err(WorkerExecutorError::invalid_request)?),
+            _ => None,
         };
 
-        let next = if chunk.entries.is_empty() {
-            None
-        } else {
-            Some(golem::worker::OplogCursor {
-                next_oplog_index: chunk.next_oplog_index.into(),
-                current_component_version: chunk.current_component_version,
-            })
-        };
+        let (new_cursor, workers) = self
+            .worker_enumeration_service()
+            .get(
+                &project_id,
+                &component_id,
+                filter,
+                request
+                    .cursor
+                    .map(|cursor| ScanCursor {
+                        cursor: cursor.cursor,
+                        layer: cursor.layer as usize,
+                    })
+                    .unwrap_or_default(),
+                request.count,
+                request.precise,
+            )
+            .instrument(info_span!("enumerate_workers"))
+            .await?;
 
-        Ok(GetOplogResponse {
-            result: Some(
-                golem::workerexecutor::v1::get_oplog_response::Result::Success(
-                    golem::workerexecutor::v1::GetOplogSuccessResponse {
-                        entries: chunk
-                            .entries
-                            .into_iter()
-                            .map(|entry| entry.try_into())
-                            .collect::<Result<Vec<_>, _>>()
-                            .map_err(WorkerExecutorError::unknown)?,
-                        next,
-                        first_index_in_chunk: chunk.first_index_in_chunk.into(),
-                        last_index: chunk.last_index.into(),
-                    },
-                ),
-            ),
-        })
+        let mut result = Vec::new();
+
+        for worker_metadata in workers {
+            let last_error_and_retry_count = Ctx::get_last_error_and_retry_count(
+                self,
+                &worker_metadata.owned_worker_id(),
+                &worker_metadata.last_known_status,
+            )
+            .await;
+            let file_system_usage = self
+                .file_system_usage(&worker_metadata.owned_worker_id())
+                .await;
+            let metadata = Self::create_proto_metadata(
+                worker_metadata,
+                last_error_and_retry_count,
+                file_system_usage,
+            )?;
+            result.push(metadata);
+        }
+
+        Ok((
+            new_cursor.map(|cursor| Cursor {
+                layer: cursor.layer as u64,
+                cursor: cursor.cursor,
+            }),
+            result,
+        ))
     }
 
-    async fn search_oplog_internal(
+    async fn update_worker_internal(
         &self,
-        request: SearchOplogRequest,
-    ) -> Result<SearchOplogResponse, WorkerExecutorError> {
+        request: UpdateWorkerRequest,
+    ) -> Result<(), WorkerExecutorError> {
         let owned_worker_id =
             extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let chunk = match request.cursor {
-            Some(cursor) => search_public_oplog(
-                self.component_service(),
-                self.oplog_service(),
-                self.plugins(),
-                self.project_service(),
-                &owned_worker_id,
-                cursor.current_component_version,
-                OplogIndex::from_u64(cursor.next_oplog_index),
-                min(
-                    request.count as usize,
-                    self.services.config().limits.max_oplog_query_pages_size,
-                ),
-                &request.query,
-            )
-            .await
-            .map_err(WorkerExecutorError::unknown)?,
-            None => {
-                let start = OplogIndex::INITIAL;
-                let initial_component_version =
-                    find_component_version_at(self.oplog_service(), &owned_worker_id, start)
-                        .await?;
-                search_public_oplog(
-                    self.component_service(),
-                    self.oplog_service(),
-                    self.plugins(),
-                    self.project_service(),
-                    &owned_worker_id,
-                    initial_component_version,
-                    start,
-                    min(
-                        request.count as usize,
-                        self.services.config().limits.max_oplog_query_pages_size,
-                    ),
-                    &request.query,
-                )
-                .await
-                .map_err(WorkerExecutorError::unknown)?
-            }
-        };
+        let mut metadata = Worker::<Ctx>::get_latest_metadata(&self.services, &owned_worker_id)
+            .await?
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
 
-        let next = if chunk.entries.is_empty() {
-            None
-        } else {
-            Some(golem::worker::OplogCursor {
-                next_oplog_index: chunk.next_oplog_index.into(),
-                current_component_version: chunk.current_component_version,
-            })
-        };
+        if metadata.last_known_status.component_version == request.target_version {
+            return Err(WorkerExecutorError::invalid_request(
+                "Worker is already at the target version",
+            ));
+        }
 
-        Ok(SearchOplogResponse {
-            result: Some(
-                golem::workerexecutor::v1::search_oplog_response::Result::Success(
-                    golem::workerexecutor::v1::SearchOplogSuccessResponse {
-                        entries: chunk
-                            .entries
-                            .into_iter()
-                            .map(|(idx, entry)| {
-                                entry.try_into().map(|entry: golem::worker::OplogEntry| {
-                                    golem::worker::OplogEntryWithIndex {
-                                        oplog_index: idx.into(),
-                                        entry: Some(entry),
-                                    }
-                                })
-                            })
-                            .collect::<Result<Vec<_>, _>>()
-                            .map_err(WorkerExecutorError::unknown)?,
-                        next,
-                        last_index: chunk.last_index.into(),
-                    },
-                ),
-            ),
-        })
-    }
+        let component_metadata = self
+            .component_service()
+            .get_metadata(
+                &owned_worker_id.project_id,
+                &owned_worker_id.worker_id.component_id,
+                Some(metadata.last_known_status.component_version),
+            )
+            .await?;
+        if component_metadata.component_type == ComponentType::Ephemeral {
+            return Err(WorkerExecutorError::invalid_request(
+                "Ephemeral workers cannot be updated",
+            ));
+        }
 
-    async fn get_file_system_node_internal(
-        &self,
-        request: GetFileSystemNodeRequest,
-    ) -> Result<GetFileSystemNodeResponse, WorkerExecutorError> {
-        let path = ComponentFilePath::from_abs_str(&request.path)
-            .map_err(|e| WorkerExecutorError::invalid_request(format!("Invalid path: {e}")))?;
+        match request.mode() {
+            UpdateMode::Automatic => {
+                let update_description = UpdateDescription::Automatic {
+                    target_version: request.target_version,
+                };
 
-        let worker = self.get_or_create(&request).await?;
+                if metadata
+                    .last_known_status
+                    .pending_updates
+                    .iter()
+                    .any(|update| update.description == update_description)
+                {
+                    return Err(WorkerExecutorError::invalid_request(
+                        "The same update is already in progress",
+                    ));
+                }
 
-        let result = worker.get_file_system_node(path).await?;
+                match &metadata.last_known_status.status {
+                    WorkerStatus::Exited => {
+                        warn!("Attempted updating worker which already exited")
+                    }
+                    WorkerStatus::Interrupted
+                    | WorkerStatus::Suspended
+                    | WorkerStatus::Retrying
+                    | WorkerStatus::Failed => {
+                        // The worker is not active.
+                        //
+                        // We start activating it but block on a signal.
+                        // This way we eliminate the race condition of activating the worker, but have
+                        // time to inject the pending update oplog entry so the at the time the worker
+                        // really gets activated it is going to see it and perform the update.
 
-        let response = match result {
-            GetFileSystemNodeResult::Ok(entries) => GetFileSystemNodeResponse {
-                result: Some(
-                    golem::workerexecutor::v1::get_file_system_node_response::Result::DirSuccess(
-                        golem::workerexecutor::v1::ListDirectorySuccessResponse {
-                            nodes: entries.into_iter().map(|entry| entry.into()).collect(),
-                        },
-                    ),
-                ),
-            },
-            GetFileSystemNodeResult::NotFound => GetFileSystemNodeResponse {
-                result: Some(
-                    golem::workerexecutor::v1::get_file_system_node_response::Result::NotFound(
-                        golem::common::Empty {},
-                    ),
-                ),
-            },
-            GetFileSystemNodeResult::File(file_node) => GetFileSystemNodeResponse {
-                result: Some(
-                    golem::workerexecutor::v1::get_file_system_node_response::Result::FileSuccess(
-                        golem::workerexecutor::v1::ListFileDataSuccessResponse {
-                            file: Some(file_node.into()),
-                        },
-                    ),
-                ),
-            },
-        };
-
-        Ok(response)
-    }
-
-    async fn get_file_contents_internal(
-        &self,
-        request: GetFileContentsRequest,
-    ) -> Result<<Self as WorkerExecutor>::GetFileContentsStream, WorkerExecutorError> {
-        let path = ComponentFilePath::from_abs_str(&request.file_path)
-            .map_err(|e| WorkerExecutorError::invalid_request(format!("Invalid path: {e}")))?;
-
-        let worker = self.get_or_create(&request).await?;
-
-        let result = worker.read_file(path).await?;
-
-        let response: <Self as WorkerExecutor>::GetFileContentsStream = match result {
-            ReadFileResult::NotFound => {
-                let header = golem::workerexecutor::v1::GetFileContentsResponseHeader {
-                    result: Some(golem::workerexecutor::v1::get_file_contents_response_header::Result::NotFound(golem::common::Empty {})),
-                };
-                let header_chunk = GetFileContentsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_file_contents_response::Result::Header(
-                            header,
-                        ),
-                    ),
-                };
-                Box::pin(tokio_stream::iter(vec![Ok(header_chunk)]))
-            }
-            ReadFileResult::NotAFile => {
-                let header = golem::workerexecutor::v1::GetFileContentsResponseHeader {
-                    result: Some(golem::workerexecutor::v1::get_file_contents_response_header::Result::NotAFile(golem::common::Empty {})),
-                };
-                let header_chunk = GetFileContentsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_file_contents_response::Result::Header(
-                            header,
-                        ),
-                    ),
-                };
-                Box::pin(tokio_stream::iter(vec![Ok(header_chunk)]))
-            }
-            ReadFileResult::Ok(stream) => {
-                let header = golem::workerexecutor::v1::GetFileContentsResponseHeader {
-                    result: Some(golem::workerexecutor::v1::get_file_contents_response_header::Result::Success(golem::common::Empty {})),
-                };
-                let header_chunk = GetFileContentsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_file_contents_response::Result::Header(
-                            header,
-                        ),
-                    ),
-                };
-                let header_stream = tokio_stream::iter(vec![Ok(header_chunk)]);
-
-                let content_stream = stream
-                    .map(|item| {
-                        let transformed = match item {
-                            Ok(data) => {
-                                GetFileContentsResponse {
-                                    result: Some(
-                                        golem::workerexecutor::v1::get_file_contents_response::Result::Success(data.into())
-                                    )
-                                }
-                            }
-                            Err(e) => {
-                                GetFileContentsResponse {
-                                    result: Some(
-                                        golem::workerexecutor::v1::get_file_contents_response::Result::Failure(e.into())
-                                    )
-                                }
-                            }
-                        };
-                        Ok(transformed)
-                    });
-                Box::pin(header_stream.chain(content_stream))
-            }
-        };
-        Ok(response)
-    }
-
-    async fn activate_plugin_internal(
-        &self,
-        request: ActivatePluginRequest,
-    ) -> Result<(), WorkerExecutorError> {
-        let owned_worker_id =
-            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
-        let account_id = extract_account_id(&request, |r| &r.account_id)?;
-        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
-
-        let plugin_installation_id =
-            request
-                .installation_id
-                .ok_or(WorkerExecutorError::invalid_request(
-                    "installation_id not found",
-                ))?;
-        let plugin_installation_id: PluginInstallationId = plugin_installation_id
-            .try_into()
-            .map_err(WorkerExecutorError::invalid_request)?;
-
-        let metadata = self.worker_service().get(&owned_worker_id).await;
-        let worker_status =
-            Ctx::compute_latest_worker_status(self, &owned_worker_id, &metadata).await?;
-
-        match metadata {
-            Some(metadata) => {
-                // Worker exists
-
-                if worker_status
-                    .active_plugins
-                    .contains(&plugin_installation_id)
-                {
-                    warn!("Plugin is already activated");
-                    Ok(())
-                } else {
-                    let component_metadata = self
-                        .component_service()
-                        .get_metadata(
-                            &owned_worker_id.project_id,
-                            &owned_worker_id.worker_id.component_id,
+                        debug!("Activating worker for update",);
+                        let worker = Worker::get_or_create_suspended(
+                            self,
+                            &account_id,
+                            &owned_worker_id,
+                            None,
+                            None,
+                            None,
                             Some(metadata.last_known_status.component_version),
+                            None,
                         )
                         .await?;
 
-                    if component_metadata
-                        .installed_plugins
-                        .iter()
-                        .any(|installation| installation.id == plugin_installation_id)
-                    {
+                        debug!("Enqueuing update");
+                        worker.enqueue_update(update_description.clone()).await;
+
+                        if metadata.last_known_status.status == WorkerStatus::Failed {
+                            // If the worker was previously in a permanently failed state,
+                            // we reset this state to Retrying, so we can fix the failure cause
+                            // with an update.
+                            metadata.last_known_status.status = WorkerStatus::Retrying;
+                        }
+                        let mut skipped_regions =
+                            metadata.last_known_status.skipped_regions.clone();
+                        let (pending_updates, temporary_skipped_regions) =
+                            worker.pending_updates().await;
+                        skipped_regions.set_override(temporary_skipped_regions);
+                        metadata.last_known_status.pending_updates = pending_updates;
+                        metadata.last_known_status.skipped_regions = skipped_regions;
+                        worker.update_status(metadata.last_known_status).await;
+
+                        debug!("Resuming initialization to perform the update",);
+                        Worker::start_if_needed(worker.clone()).await?;
+                    }
+                    WorkerStatus::Running | WorkerStatus::Idle => {
+                        // If the worker is already running we need to write to its oplog the
+                        // update attempt, and then interrupt it and have it immediately restarting
+                        // to begin the update.
                         let worker = Worker::get_or_create_suspended(
                             self,
                             &account_id,
@@ -1594,234 +1651,1243 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
                             None,
                         )
                         .await?;
-                        worker.activate_plugin(plugin_installation_id).await?;
-                        Ok(())
-                    } else {
-                        Err(WorkerExecutorError::invalid_request(
-                            "Plugin installation does not belong to this worker's component",
-                        ))
+
+                        worker.enqueue_update(update_description.clone()).await;
+
+                        debug!("Enqueued update for running worker");
+
+                        worker.set_interrupting(InterruptKind::Restart).await;
+
+                        debug!("Interrupted running worker for update");
                     }
                 }
             }
-            None => Err(WorkerExecutorError::worker_not_found(
-                owned_worker_id.worker_id(),
-            )),
+
+            UpdateMode::Manual => {
+                if metadata.last_known_status.pending_invocations.iter().any(|invocation|
+                    matches!(invocation, TimestampedWorkerInvocation { invocation: WorkerInvocation::ManualUpdate { target_version, .. }, ..} if *target_version == request.target_version)
+                ) {
+                    return Err(WorkerExecutorError::invalid_request(
+                        "The same update is already in progress",
+                    ));
+                }
+
+                // For manual update we need to invoke the worker to save the custom snapshot.
+                // This is in a race condition with other worker invocations, so the whole update
+                // process need to be initiated through the worker's invocation queue.
+
+                let worker = Worker::get_or_create_suspended(
+                    self,
+                    &account_id,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                worker.enqueue_manual_update(request.target_version).await;
+            }
         }
+
+        Ok(())
     }
 
-    async fn deactivate_plugin_internal(
+    async fn connect_worker_internal(
         &self,
-        request: DeactivatePluginRequest,
-    ) -> Result<(), WorkerExecutorError> {
+        request: ConnectWorkerRequest,
+    ) -> ResponseResult<<Self as WorkerExecutor>::ConnectWorkerStream> {
         let owned_worker_id =
             extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
         let account_id = extract_account_id(&request, |r| &r.account_id)?;
         self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
 
-        let plugin_installation_id =
-            request
-                .installation_id
-                .ok_or(WorkerExecutorError::invalid_request(
-                    "installation_id not found",
-                ))?;
-        let plugin_installation_id: PluginInstallationId = plugin_installation_id
-            .try_into()
-            .map_err(WorkerExecutorError::invalid_request)?;
+        let metadata = Worker::<Ctx>::get_latest_metadata(self, &owned_worker_id)
+            .await?
+            .ok_or(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            ))?;
 
-        let metadata = self.worker_service().get(&owned_worker_id).await;
-        let worker_status =
-            Ctx::compute_latest_worker_status(self, &owned_worker_id, &metadata).await?;
+        self.ensure_not_failed(&owned_worker_id, &metadata).await?;
 
-        match metadata {
-            Some(metadata) => {
-                // Worker exists
+        if metadata.last_known_status.status != WorkerStatus::Interrupted {
+            let event_service = Worker::get_or_create_suspended(
+                self,
+                &account_id,
+                &owned_worker_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await?
+            .event_service();
 
-                if !worker_status
-                    .active_plugins
-                    .contains(&plugin_installation_id)
-                {
-                    warn!("Plugin is already deactivated");
-                    Ok(())
-                } else {
-                    let component_metadata = self
-                        .component_service()
-                        .get_metadata(
-                            &owned_worker_id.project_id,
-                            &owned_worker_id.worker_id.component_id,
-                            Some(metadata.last_known_status.component_version),
-                        )
+            let receiver = event_service.receiver();
+
+            info!("Client connected");
+            record_new_grpc_api_active_stream();
+
+            Ok(Response::new(WorkerEventStream::new(receiver)))
+        } else {
+            // We don't want 'connect' to resume interrupted workers
+            Err(WorkerExecutorError::Interrupted {
+                kind: InterruptKind::Interrupt,
+            }
+            .into())
+        }
+    }
+
+    async fn get_oplog_internal(
+        &self,
+        request: GetOplogRequest,
+    ) -> Result<GetOplogResponse, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let chunk = match request.cursor {
+            Some(cursor) => get_public_oplog_chunk(
+                self.component_service(),
+                self.oplog_service(),
+                self.plugins(),
+                self.project_service(),
+                &owned_worker_id,
+                cursor.current_component_version,
+                OplogIndex::from_u64(cursor.next_oplog_index),
+                min(
+                    request.count as usize,
+                    self.services.config().limits.max_oplog_query_pages_size,
+                ),
+            )
+            .await
+            .map_err(WorkerExecutorError::unknown)?,
+            None => {
+                let start = OplogIndex::from_u64(request.from_oplog_index);
+                let initial_component_version =
+                    find_component_version_at(self.oplog_service(), &owned_worker_id, start)
                         .await?;
 
-                    if component_metadata
-                        .installed_plugins
-                        .iter()
-                        .any(|installation| installation.id == plugin_installation_id)
-                    {
-                        let worker = Worker::get_or_create_suspended(
-                            self,
-                            &account_id,
-                            &owned_worker_id,
-                            None,
-                            None,
-                            None,
-                            None,
-                            None,
-                        )
+                get_public_oplog_chunk(
+                    self.component_service(),
+                    self.oplog_service(),
+                    self.plugins(),
+                    self.project_service(),
+                    &owned_worker_id,
+                    initial_component_version,
+                    start,
+                    min(
+                        request.count as usize,
+                        self.services.config().limits.max_oplog_query_pages_size,
+                    ),
+                )
+                .await
+                .map_err(WorkerExecutorError::unknown)?
+            }
+        };
+
+        let next = if chunk.entries.is_empty() {
+            None
+        } else {
+            Some(golem::worker::OplogCursor {
+                next_oplog_index: chunk.next_oplog_index.into(),
+                current_component_version: chunk.current_component_version,
+            })
+        };
+
+        Ok(GetOplogResponse {
+            result: Some(
+                golem::workerexecutor::v1::get_oplog_response::Result::Success(
+                    golem::workerexecutor::v1::GetOplogSuccessResponse {
+                        entries: chunk
+                            .entries
+                            .into_iter()
+                            .map(|entry| entry.try_into())
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(WorkerExecutorError::unknown)?,
+                        next,
+                        first_index_in_chunk: chunk.first_index_in_chunk.into(),
+                        last_index: chunk.last_index.into(),
+                    },
+                ),
+            ),
+        })
+    }
+
+    async fn search_oplog_internal(
+        &self,
+        request: SearchOplogRequest,
+    ) -> Result<SearchOplogResponse, WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let chunk = match request.cursor {
+            Some(cursor) => search_public_oplog(
+                self.component_service(),
+                self.oplog_service(),
+                self.plugins(),
+                self.project_service(),
+                &owned_worker_id,
+                cursor.current_component_version,
+                OplogIndex::from_u64(cursor.next_oplog_index),
+                min(
+                    request.count as usize,
+                    self.services.config().limits.max_oplog_query_pages_size,
+                ),
+                &request.query,
+            )
+            .await
+            .map_err(WorkerExecutorError::unknown)?,
+            None => {
+                let start = OplogIndex::INITIAL;
+                let initial_component_version =
+                    find_component_version_at(self.oplog_service(), &owned_worker_id, start)
                         .await?;
-                        worker.deactivate_plugin(plugin_installation_id).await?;
-                        Ok(())
-                    } else {
-                        Err(WorkerExecutorError::invalid_request(
-                            "Plugin installation does not belong to this worker's component",
-                        ))
-                    }
-                }
+                search_public_oplog(
+                    self.component_service(),
+                    self.oplog_service(),
+                    self.plugins(),
+                    self.project_service(),
+                    &owned_worker_id,
+                    initial_component_version,
+                    start,
+                    min(
+                        request.count as usize,
+                        self.services.config().limits.max_oplog_query_pages_size,
+                    ),
+                    &request.query,
+                )
+                .await
+                .map_err(WorkerExecutorError::unknown)?
             }
-            None => Err(WorkerExecutorError::worker_not_found(
-                owned_worker_id.worker_id(),
-            )),
+        };
+
+        let next = if chunk.entries.is_empty() {
+            None
+        } else {
+            Some(golem::worker::OplogCursor {
+                next_oplog_index: chunk.next_oplog_index.into(),
+                current_component_version: chunk.current_component_version,
+            })
+        };
+
+        Ok(SearchOplogResponse {
+            result: Some(
+                golem::workerexecutor::v1::search_oplog_response::Result::Success(
+                    golem::workerexecutor::v1::SearchOplogSuccessResponse {
+                        entries: chunk
+                            .entries
+                            .into_iter()
+                            .map(|(idx, entry)| {
+                                entry.try_into().map(|entry: golem::worker::OplogEntry| {
+                                    golem::worker::OplogEntryWithIndex {
+                                        oplog_index: idx.into(),
+                                        entry: Some(entry),
+                                    }
+                                })
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                            .map_err(WorkerExecutorError::unknown)?,
+                        next,
+                        last_index: chunk.last_index.into(),
+                    },
+                ),
+            ),
+        })
+    }
+
+    async fn get_file_system_node_internal(
+        &self,
+        request: GetFileSystemNodeRequest,
+    ) -> Result<GetFileSystemNodeResponse, WorkerExecutorError> {
+        let path = ComponentFilePath::from_abs_str(&request.path)
+            .map_err(|e| WorkerExecutorError::invalid_request(format!("Invalid path: {e}")))?;
+
+        let worker = self.get_or_create(&request).await?;
+
+        let result = worker.get_file_system_node(path).await?;
+
+        let response = match result {
+            GetFileSystemNodeResult::Ok(entries) => GetFileSystemNodeResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_file_system_node_response::Result::DirSuccess(
+                        golem::workerexecutor::v1::ListDirectorySuccessResponse {
+                            nodes: entries.into_iter().map(|entry| entry.into()).collect(),
+                        },
+                    ),
+                ),
+            },
+            GetFileSystemNodeResult::NotFound => GetFileSystemNodeResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_file_system_node_response::Result::NotFound(
+                        golem::common::Empty {},
+                    ),
+                ),
+            },
+            GetFileSystemNodeResult::File(file_node) => GetFileSystemNodeResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_file_system_node_response::Result::FileSuccess(
+                        golem::workerexecutor::v1::ListFileDataSuccessResponse {
+                            file: Some(file_node.into()),
+                        },
+                    ),
+                ),
+            },
+        };
+
+        Ok(response)
+    }
+
+    async fn get_file_contents_internal(
+        &self,
+        request: GetFileContentsRequest,
+    ) -> Result<<Self as WorkerExecutor>::GetFileContentsStream, WorkerExecutorError> {
+        let path = ComponentFilePath::from_abs_str(&request.file_path)
+            .map_err(|e| WorkerExecutorError::invalid_request(format!("Invalid path: {e}")))?;
+
+        let worker = self.get_or_create(&request).await?;
+
+        let result = worker.read_file(path).await?;
+
+        let response: <Self as WorkerExecutor>::GetFileContentsStream = match result {
+            ReadFileResult::NotFound => {
+                let header = golem::workerexecutor::v1::GetFileContentsResponseHeader {
+                    result: Some(golem::workerexecutor::v1::get_file_contents_response_header::Result::NotFound(golem::common::Empty {})),
+                };
+                let header_chunk = GetFileContentsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_file_contents_response::Result::Header(
+                            header,
+                        ),
+                    ),
+                };
+                Box::pin(tokio_stream::iter(vec![Ok(header_chunk)]))
+            }
+            ReadFileResult::NotAFile => {
+                let header = golem::workerexecutor::v1::GetFileContentsResponseHeader {
+                    result: Some(golem::workerexecutor::v1::get_file_contents_response_header::Result::NotAFile(golem::common::Empty {})),
+                };
+                let header_chunk = GetFileContentsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_file_contents_response::Result::Header(
+                            header,
+                        ),
+                    ),
+                };
+                Box::pin(tokio_stream::iter(vec![Ok(header_chunk)]))
+            }
+            ReadFileResult::Ok(stream) => {
+                let header = golem::workerexecutor::v1::GetFileContentsResponseHeader {
+                    result: Some(golem::workerexecutor::v1::get_file_contents_response_header::Result::Success(golem::common::Empty {})),
+                };
+                let header_chunk = GetFileContentsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_file_contents_response::Result::Header(
+                            header,
+                        ),
+                    ),
+                };
+                let header_stream = tokio_stream::iter(vec![Ok(header_chunk)]);
+
+                let content_stream = stream
+                    .map(|item| {
+                        let transformed = match item {
+                            Ok(data) => {
+                                GetFileContentsResponse {
+                                    result: Some(
+                                        golem::workerexecutor::v1::get_file_contents_response::Result::Success(data.into())
+                                    )
+                                }
+                            }
+                            Err(e) => {
+                                GetFileContentsResponse {
+                                    result: Some(
+                                        golem::workerexecutor::v1::get_file_contents_response::Result::Failure(e.into())
+                                    )
+                                }
+                            }
+                        };
+                        Ok(transformed)
+                    });
+                Box::pin(header_stream.chain(content_stream))
+            }
+        };
+        Ok(response)
+    }
+
+    async fn activate_plugin_internal(
+        &self,
+        request: ActivatePluginRequest,
+    ) -> Result<(), WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let plugin_installation_id =
+            request
+                .installation_id
+                .ok_or(WorkerExecutorError::invalid_request(
+                    "installation_id not found",
+                ))?;
+        let plugin_installation_id: PluginInstallationId = plugin_installation_id
+            .try_into()
+            .map_err(WorkerExecutorError::invalid_request)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        let worker_status =
+            Ctx::compute_latest_worker_status(self, &owned_worker_id, &metadata).await?;
+
+        match metadata {
+            Some(metadata) => {
+                // Worker exists
+
+                if worker_status
+                    .active_plugins
+                    .contains(&plugin_installation_id)
+                {
+                    warn!("Plugin is already activated");
+                    Ok(())
+                } else {
+                    let component_metadata = self
+                        .component_service()
+                        .get_metadata(
+                            &owned_worker_id.project_id,
+                            &owned_worker_id.worker_id.component_id,
+                            Some(metadata.last_known_status.component_version),
+                        )
+                        .await?;
+
+                    if component_metadata
+                        .installed_plugins
+                        .iter()
+                        .any(|installation| installation.id == plugin_installation_id)
+                    {
+                        let worker = Worker::get_or_create_suspended(
+                            self,
+                            &account_id,
+                            &owned_worker_id,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                        )
+                        .await?;
+                        worker.activate_plugin(plugin_installation_id).await?;
+                        Ok(())
+                    } else {
+                        Err(WorkerExecutorError::invalid_request(
+                            "Plugin installation does not belong to this worker's component",
+                        ))
+                    }
+                }
+            }
+            None => Err(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            )),
+        }
+    }
+
+    async fn deactivate_plugin_internal(
+        &self,
+        request: DeactivatePluginRequest,
+    ) -> Result<(), WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let plugin_installation_id =
+            request
+                .installation_id
+                .ok_or(WorkerExecutorError::invalid_request(
+                    "installation_id not found",
+                ))?;
+        let plugin_installation_id: PluginInstallationId = plugin_installation_id
+            .try_into()
+            .map_err(WorkerExecutorError::invalid_request)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        let worker_status =
+            Ctx::compute_latest_worker_status(self, &owned_worker_id, &metadata).await?;
+
+        match metadata {
+            Some(metadata) => {
+                // Worker exists
+
+                if !worker_status
+                    .active_plugins
+                    .contains(&plugin_installation_id)
+                {
+                    warn!("Plugin is already deactivated");
+                    Ok(())
+                } else {
+                    let component_metadata = self
+                        .component_service()
+                        .get_metadata(
+                            &owned_worker_id.project_id,
+                            &owned_worker_id.worker_id.component_id,
+                            Some(metadata.last_known_status.component_version),
+                        )
+                        .await?;
+
+                    if component_metadata
+                        .installed_plugins
+                        .iter()
+                        .any(|installation| installation.id == plugin_installation_id)
+                    {
+                        let worker = Worker::get_or_create_suspended(
+                            self,
+                            &account_id,
+                            &owned_worker_id,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                        )
+                        .await?;
+                        worker.deactivate_plugin(plugin_installation_id).await?;
+                        Ok(())
+                    } else {
+                        Err(WorkerExecutorError::invalid_request(
+                            "Plugin installation does not belong to this worker's component",
+                        ))
+                    }
+                }
+            }
+            None => Err(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            )),
+        }
+    }
+
+    async fn update_worker_labels_internal(
+        &self,
+        request: UpdateWorkerLabelsRequest,
+    ) -> Result<(), WorkerExecutorError> {
+        let owned_worker_id =
+            extract_owned_worker_id(&request, |r| &r.worker_id, |r| &r.project_id)?;
+        let account_id = extract_account_id(&request, |r| &r.account_id)?;
+        self.ensure_worker_belongs_to_this_executor(&owned_worker_id)?;
+
+        let metadata = self.worker_service().get(&owned_worker_id).await;
+        match metadata {
+            Some(_) => {
+                let worker = Worker::get_or_create_suspended(
+                    self,
+                    &account_id,
+                    &owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+                worker.update_labels(request.set, request.remove).await?;
+                Ok(())
+            }
+            None => Err(WorkerExecutorError::worker_not_found(
+                owned_worker_id.worker_id(),
+            )),
+        }
+    }
+
+    /// The file system usage of a worker is only known while it is loaded in memory
+    async fn file_system_usage(&self, owned_worker_id: &OwnedWorkerId) -> u64 {
+        self.active_workers()
+            .try_get(owned_worker_id)
+            .await
+            .map(|worker| worker.file_system_usage())
+            .unwrap_or_default()
+    }
+
+    fn create_proto_metadata(
+        metadata: WorkerMetadata,
+        last_error_and_retry_count: Option<LastError>,
+        file_system_usage: u64,
+    ) -> Result<golem::worker::WorkerMetadata, WorkerExecutorError> {
+        let mut updates = Vec::new();
+
+        let latest_status = metadata.last_known_status;
+        for pending_invocation in &latest_status.pending_invocations {
+            if let TimestampedWorkerInvocation {
+                timestamp,
+                invocation: WorkerInvocation::ManualUpdate { target_version },
+            } = pending_invocation
+            {
+                updates.push(golem::worker::UpdateRecord {
+                    timestamp: Some((*timestamp).into()),
+                    target_version: *target_version,
+                    update: Some(golem::worker::update_record::Update::Pending(
+                        golem::worker::PendingUpdate {},
+                    )),
+                });
+            }
+        }
+        for pending_update in &latest_status.pending_updates {
+            updates.push(golem::worker::UpdateRecord {
+                timestamp: Some(pending_update.timestamp.into()),
+                target_version: *pending_update.description.target_version(),
+                update: Some(golem::worker::update_record::Update::Pending(
+                    golem::worker::PendingUpdate {},
+                )),
+            });
+        }
+        for successful_update in &latest_status.successful_updates {
+            updates.push(golem::worker::UpdateRecord {
+                timestamp: Some(successful_update.timestamp.into()),
+                target_version: successful_update.target_version,
+                update: Some(golem::worker::update_record::Update::Successful(
+                    golem::worker::SuccessfulUpdate {},
+                )),
+            });
+        }
+        for failed_update in &latest_status.failed_updates {
+            updates.push(golem::worker::UpdateRecord {
+                timestamp: Some(failed_update.timestamp.into()),
+                target_version: failed_update.target_version,
+                update: Some(golem::worker::update_record::Update::Failed(
+                    golem::worker::FailedUpdate {
+                        details: failed_update.details.clone(),
+                    },
+                )),
+            });
+        }
+        updates.sort_by_key(|record| {
+            record.timestamp.as_ref().unwrap().seconds * 1_000_000_000
+                + record.timestamp.as_ref().unwrap().nanos as i64
+        });
+
+        let mut owned_resources = Vec::new();
+        for (resource_id, resource) in latest_status.owned_resources {
+            owned_resources.push(
+                to_protobuf_resource_description(resource_id, resource).map_err(|err| {
+                    WorkerExecutorError::unknown(format!("Failed to create worker metadata: {err}"))
+                })?,
+            );
+        }
+
+        let active_plugins = latest_status.active_plugins;
+
+        Ok(golem::worker::WorkerMetadata {
+            worker_id: Some(metadata.worker_id.into()),
+            project_id: Some(metadata.project_id.into()),
+            args: metadata.args.clone(),
+            env: HashMap::from_iter(metadata.env.iter().cloned()),
+            created_by: Some(metadata.created_by.into()),
+            wasi_config_vars: Some(metadata.wasi_config_vars.into()),
+            component_version: latest_status.component_version,
+            status: Into::<golem::worker::WorkerStatus>::into(latest_status.status.clone()).into(),
+            retry_count: last_error_and_retry_count
+                .as_ref()
+                .map(|last_error| last_error.retry_count)
+                .unwrap_or_default(),
+
+            pending_invocation_count: latest_status.pending_invocations.len() as u64,
+            updates,
+            created_at: Some(metadata.created_at.into()),
+            last_error: last_error_and_retry_count
+                .map(|last_error| last_error.error.to_string(&last_error.stderr)),
+            component_size: latest_status.component_size,
+            total_linear_memory_size: latest_status.total_linear_memory_size,
+            owned_resources,
+            active_plugins: active_plugins.into_iter().map(|id| id.into()).collect(),
+            skipped_regions: latest_status
+                .skipped_regions
+                .into_regions()
+                .map(|region| region.into())
+                .collect(),
+            deleted_regions: latest_status
+                .deleted_regions
+                .into_regions()
+                .map(|region| region.into())
+                .collect(),
+            file_system_usage,
+            labels: latest_status.labels.into_iter().collect(),
+        })
+    }
+}
+
+impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static> UsesAllDeps
+    for WorkerExecutorImpl<Ctx, Svcs>
+{
+    type Ctx = Ctx;
+
+    fn all(&self) -> &All<Ctx> {
+        self.services.all()
+    }
+}
+
+#[tonic::async_trait]
+impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static>
+    WorkerExecutor for WorkerExecutorImpl<Ctx, Svcs>
+{
+    async fn create_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::CreateWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::CreateWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "create_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            component_version = request.component_version,
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self
+            .create_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::CreateWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::create_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::CreateWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::create_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_and_await_worker(
+        &self,
+        request: Request<InvokeAndAwaitWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_and_await_worker",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self.invoke_and_await_worker_internal_proto(&request).instrument(record.span.clone()).await {
+            Ok(output) => {
+                let result = InvokeAndAwaitWorkerSuccess { output };
+
+                record.succeed(Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Success(result),
+                        ),
+                    },
+                )))
+            }
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_and_await_worker_typed(
+        &self,
+        request: Request<InvokeAndAwaitWorkerRequest>,
+    ) -> Result<Response<InvokeAndAwaitWorkerResponseTyped>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_and_await_worker_typed",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self.invoke_and_await_worker_internal_typed(&request).instrument(record.span.clone()).await {
+            Ok(value_and_type) => {
+                let result = golem::workerexecutor::v1::InvokeAndAwaitWorkerSuccessTyped {
+                    output: value_and_type.map(|vnt| vnt.into())
+                };
+
+                record.succeed(Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Success(result),
+                        ),
+                    },
+                )))
+            }
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::InvokeWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::InvokeWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_worker",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            function = request.name,
+            account_id = proto_account_id_string(&request.account_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        match self
+            .invoke_worker_internal(&request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::InvokeWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::invoke_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_and_await_worker_json(
+        &self,
+        request: Request<InvokeAndAwaitWorkerJsonRequest>,
+    ) -> Result<Response<InvokeAndAwaitWorkerResponseTyped>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_and_await_worker_json",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            account_id = proto_account_id_string(&request.account_id),
+        );
+
+        match self.invoke_and_await_worker_internal_typed(&request).instrument(record.span.clone()).await {
+            Ok(value_and_type) => {
+                let result = golem::workerexecutor::v1::InvokeAndAwaitWorkerSuccessTyped {
+                    output: value_and_type.map(|vnt| vnt.into())
+                };
+
+                record.succeed(Ok(Response::new(
+                    InvokeAndAwaitWorkerResponseTyped {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Success(result),
+                        ),
+                    },
+                )))
+            }
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn invoke_worker_json(
+        &self,
+        request: Request<InvokeJsonWorkerRequest>,
+    ) -> Result<Response<InvokeWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "invoke_worker_json",
+            worker_id = proto_target_worker_id_string(&request.worker_id),
+            function = request.name,
+            account_id = proto_account_id_string(&request.account_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        match self
+            .invoke_worker_internal(&request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::InvokeWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::invoke_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InvokeWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::invoke_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    type ConnectWorkerStream = ResponseStream;
+
+    async fn connect_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::ConnectWorkerRequest>,
+    ) -> ResponseResult<Self::ConnectWorkerStream> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "connect_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            account_id = proto_account_id_string(&request.account_id)
+        );
+
+        self.connect_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+    }
+
+    async fn delete_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::DeleteWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::DeleteWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "delete_worker",
+            worker_id = proto_worker_id_string(&request.worker_id)
+        );
+
+        match self
+            .delete_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::DeleteWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::delete_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::DeleteWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::delete_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn complete_promise(
+        &self,
+        request: Request<golem::workerexecutor::v1::CompletePromiseRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::CompletePromiseResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "complete_promise",
+            promise_id = proto_promise_id_string(&request.promise_id)
+        );
+
+        match self
+            .complete_promise_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(success) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::CompletePromiseResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::complete_promise_response::Result::Success(
+                            success,
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::CompletePromiseResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::complete_promise_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
+
+    async fn get_pending_promises(
+        &self,
+        request: Request<GetPendingPromisesRequest>,
+    ) -> Result<Response<GetPendingPromisesResponse>, Status> {
+        let request = request.into_inner();
+
+        let record = recorded_grpc_api_request!(
+            "get_pending_promises",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .get_pending_promises_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(promises) => record.succeed(Ok(Response::new(GetPendingPromisesResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_pending_promises_response::Result::Success(
+                        golem::workerexecutor::v1::GetPendingPromisesSuccessResponse {
+                            promises: promises.into_iter().map(|p| p.into()).collect(),
+                        },
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(GetPendingPromisesResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_pending_promises_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
         }
     }
 
-    fn create_proto_metadata(
-        metadata: WorkerMetadata,
-        last_error_and_retry_count: Option<LastError>,
-    ) -> Result<golem::worker::WorkerMetadata, WorkerExecutorError> {
-        let mut updates = Vec::new();
+    async fn interrupt_worker(
+        &self,
+        request: Request<golem::workerexecutor::v1::InterruptWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::InterruptWorkerResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "interrupt_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
 
-        let latest_status = metadata.last_known_status;
-        for pending_invocation in &latest_status.pending_invocations {
-            if let TimestampedWorkerInvocation {
-                timestamp,
-                invocation: WorkerInvocation::ManualUpdate { target_version },
-            } = pending_invocation
-            {
-                updates.push(golem::worker::UpdateRecord {
-                    timestamp: Some((*timestamp).into()),
-                    target_version: *target_version,
-                    update: Some(golem::worker::update_record::Update::Pending(
-                        golem::worker::PendingUpdate {},
-                    )),
-                });
-            }
-        }
-        for pending_update in &latest_status.pending_updates {
-            updates.push(golem::worker::UpdateRecord {
-                timestamp: Some(pending_update.timestamp.into()),
-                target_version: *pending_update.description.target_version(),
-                update: Some(golem::worker::update_record::Update::Pending(
-                    golem::worker::PendingUpdate {},
-                )),
-            });
-        }
-        for successful_update in &latest_status.successful_updates {
-            updates.push(golem::worker::UpdateRecord {
-                timestamp: Some(successful_update.timestamp.into()),
-                target_version: successful_update.target_version,
-                update: Some(golem::worker::update_record::Update::Successful(
-                    golem::worker::SuccessfulUpdate {},
-                )),
-            });
-        }
-        for failed_update in &latest_status.failed_updates {
-            updates.push(golem::worker::UpdateRecord {
-                timestamp: Some(failed_update.timestamp.into()),
-                target_version: failed_update.target_version,
-                update: Some(golem::worker::update_record::Update::Failed(
-                    golem::worker::FailedUpdate {
-                        details: failed_update.details.clone(),
+        match self
+            .interrupt_worker_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::InterruptWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::interrupt_worker_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::InterruptWorkerResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::interrupt_worker_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
                     },
                 )),
-            });
+                &err,
+            ),
         }
-        updates.sort_by_key(|record| {
-            record.timestamp.as_ref().unwrap().seconds * 1_000_000_000
-                + record.timestamp.as_ref().unwrap().nanos as i64
-        });
+    }
 
-        let mut owned_resources = Vec::new();
-        for (resource_id, resource) in latest_status.owned_resources {
-            owned_resources.push(
-                to_protobuf_resource_description(resource_id, resource).map_err(|err| {
-                    WorkerExecutorError::unknown(format!("Failed to create worker metadata: {err}"))
-                })?,
-            );
-        }
+    async fn revoke_shards(
+        &self,
+        request: Request<golem::workerexecutor::v1::RevokeShardsRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::RevokeShardsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("revoke_shards",);
 
-        let active_plugins = latest_status.active_plugins;
+        match self
+            .revoke_shards_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::RevokeShardsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::revoke_shards_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::RevokeShardsResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::revoke_shards_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
+    }
 
-        Ok(golem::worker::WorkerMetadata {
-            worker_id: Some(metadata.worker_id.into()),
-            project_id: Some(metadata.project_id.into()),
-            args: metadata.args.clone(),
-            env: HashMap::from_iter(metadata.env.iter().cloned()),
-            created_by: Some(metadata.created_by.into()),
-            wasi_config_vars: Some(metadata.wasi_config_vars.into()),
-            component_version: latest_status.component_version,
-            status: Into::<golem::worker::WorkerStatus>::into(latest_status.status.clone()).into(),
-            retry_count: last_error_and_retry_count
-                .as_ref()
-                .map(|last_error| last_error.retry_count)
-                .unwrap_or_default(),
+    async fn assign_shards(
+        &self,
+        request: Request<golem::workerexecutor::v1::AssignShardsRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::AssignShardsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!("assign_shards",);
 
-            pending_invocation_count: latest_status.pending_invocations.len() as u64,
-            updates,
-            created_at: Some(metadata.created_at.into()),
-            last_error: last_error_and_retry_count
-                .map(|last_error| last_error.error.to_string(&last_error.stderr)),
-            component_size: latest_status.component_size,
-            total_linear_memory_size: latest_status.total_linear_memory_size,
-            owned_resources,
-            active_plugins: active_plugins.into_iter().map(|id| id.into()).collect(),
-            skipped_regions: latest_status
-                .skipped_regions
-                .into_regions()
-                .map(|region| region.into())
-                .collect(),
-            deleted_regions: latest_status
-                .deleted_regions
-                .into_regions()
-                .map(|region| region.into())
-                .collect(),
-        })
+        match self
+            .assign_shards_internal(request)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(_) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::AssignShardsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::assign_shards_response::Result::Success(
+                            golem::common::Empty {},
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::AssignShardsResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::assign_shards_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
     }
-}
 
-impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static> UsesAllDeps
-    for WorkerExecutorImpl<Ctx, Svcs>
-{
-    type Ctx = Ctx;
+    async fn get_worker_metadata(
+        &self,
+        request: Request<golem::workerexecutor::v1::GetWorkerMetadataRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::GetWorkerMetadataResponse>, Status> {
+        let request = request.into_inner();
 
-    fn all(&self) -> &All<Ctx> {
-        self.services.all()
+        let record = recorded_grpc_api_request!(
+            "get_worker_metadata",
+            worker_id = proto_worker_id_string(&request.worker_id)
+        );
+
+        let result = self
+            .get_worker_metadata_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(result) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Success(
+                            result,
+                        ),
+                    ),
+                },
+            ))),
+            Err(err @ WorkerExecutorError::WorkerNotFound { .. }) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                },
+            ))),
+            Err(err) => record.fail(
+                Ok(Response::new(
+                    golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                        result: Some(
+                            golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
+                                err.clone().into(),
+                            ),
+                        ),
+                    },
+                )),
+                &err,
+            ),
+        }
     }
-}
 
-#[tonic::async_trait]
-impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync + 'static>
-    WorkerExecutor for WorkerExecutorImpl<Ctx, Svcs>
-{
-    async fn create_worker(
+    async fn resume_worker(
         &self,
-        request: Request<golem::workerexecutor::v1::CreateWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::CreateWorkerResponse>, Status> {
+        request: Request<golem::workerexecutor::v1::ResumeWorkerRequest>,
+    ) -> Result<Response<golem::workerexecutor::v1::ResumeWorkerResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "create_worker",
+            "resume_worker",
             worker_id = proto_worker_id_string(&request.worker_id),
-            component_version = request.component_version,
-            account_id = proto_account_id_string(&request.account_id),
         );
 
         match self
-            .create_worker_internal(request)
+            .resume_worker_internal(request)
             .instrument(record.span.clone())
             .await
         {
             Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::CreateWorkerResponse {
+                golem::workerexecutor::v1::ResumeWorkerResponse {
                     result: Some(
-                        golem::workerexecutor::v1::create_worker_response::Result::Success(
+                        golem::workerexecutor::v1::resume_worker_response::Result::Success(
                             golem::common::Empty {},
                         ),
                     ),
@@ -1829,9 +2895,9 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             ))),
             Err(err) => record.fail(
                 Ok(Response::new(
-                    golem::workerexecutor::v1::CreateWorkerResponse {
+                    golem::workerexecutor::v1::ResumeWorkerResponse {
                         result: Some(
-                            golem::workerexecutor::v1::create_worker_response::Result::Failure(
+                            golem::workerexecutor::v1::resume_worker_response::Result::Failure(
                                 err.clone().into(),
                             ),
                         ),
@@ -1842,35 +2908,37 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn invoke_and_await_worker(
+    async fn get_running_workers_metadata(
         &self,
-        request: Request<InvokeAndAwaitWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse>, Status> {
+        request: Request<GetRunningWorkersMetadataRequest>,
+    ) -> Result<Response<GetRunningWorkersMetadataResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_and_await_worker",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
-            account_id = proto_account_id_string(&request.account_id),
+            "get_running_workers_metadata",
+            component_id = proto_component_id_string(&request.component_id),
         );
 
-        match self.invoke_and_await_worker_internal_proto(&request).instrument(record.span.clone()).await {
-            Ok(output) => {
-                let result = InvokeAndAwaitWorkerSuccess { output };
-
-                record.succeed(Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Success(result),
+        let result = self
+            .get_running_workers_metadata_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(workers) => record.succeed(Ok(Response::new(
+                golem::workerexecutor::v1::GetRunningWorkersMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Success(
+                            golem::workerexecutor::v1::GetRunningWorkersMetadataSuccessResponse {
+                                workers
+                            }
                         ),
-                    },
-                )))
-            }
+                    ),
+                },
+            ))),
             Err(err) => record.fail(
                 Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponse {
+                    GetRunningWorkersMetadataResponse {
                         result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response::Result::Failure(
+                            golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Failure(
                                 err.clone().into(),
                             ),
                         ),
@@ -1881,539 +2949,472 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn invoke_and_await_worker_typed(
+    async fn get_workers_metadata(
         &self,
-        request: Request<InvokeAndAwaitWorkerRequest>,
-    ) -> Result<Response<InvokeAndAwaitWorkerResponseTyped>, Status> {
+        request: Request<GetWorkersMetadataRequest>,
+    ) -> Result<Response<GetWorkersMetadataResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_and_await_worker_typed",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
-            account_id = proto_account_id_string(&request.account_id),
+            "get_workers_metadata",
+            component_id = proto_component_id_string(&request.component_id),
         );
 
-        match self.invoke_and_await_worker_internal_typed(&request).instrument(record.span.clone()).await {
-            Ok(value_and_type) => {
-                let result = golem::workerexecutor::v1::InvokeAndAwaitWorkerSuccessTyped {
-                    output: value_and_type.map(|vnt| vnt.into())
-                };
-
-                record.succeed(Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Success(result),
+        let result = self
+            .get_workers_metadata_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok((cursor, workers)) => {
+                record.succeed(Ok(Response::new(GetWorkersMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Success(
+                            golem::workerexecutor::v1::GetWorkersMetadataSuccessResponse {
+                                workers,
+                                cursor,
+                            },
                         ),
-                    },
-                )))
+                    ),
+                })))
             }
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetWorkersMetadataResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn invoke_worker(
+    async fn update_worker(
         &self,
-        request: Request<golem::workerexecutor::v1::InvokeWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::InvokeWorkerResponse>, Status> {
+        request: Request<UpdateWorkerRequest>,
+    ) -> Result<Response<UpdateWorkerResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_worker",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            function = request.name,
-            account_id = proto_account_id_string(&request.account_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            "update_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            target_version = request.target_version,
         );
 
         match self
-            .invoke_worker_internal(&request)
+            .update_worker_internal(request)
             .instrument(record.span.clone())
             .await
         {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::InvokeWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::invoke_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            Ok(_) => record.succeed(Ok(Response::new(UpdateWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::update_worker_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(UpdateWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::update_worker_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn invoke_and_await_worker_json(
+    async fn get_oplog(
         &self,
-        request: Request<InvokeAndAwaitWorkerJsonRequest>,
-    ) -> Result<Response<InvokeAndAwaitWorkerResponseTyped>, Status> {
+        request: Request<GetOplogRequest>,
+    ) -> Result<Response<GetOplogResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_and_await_worker_json",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
-            account_id = proto_account_id_string(&request.account_id),
+            "get_oplog",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self.invoke_and_await_worker_internal_typed(&request).instrument(record.span.clone()).await {
-            Ok(value_and_type) => {
-                let result = golem::workerexecutor::v1::InvokeAndAwaitWorkerSuccessTyped {
-                    output: value_and_type.map(|vnt| vnt.into())
-                };
-
-                record.succeed(Ok(Response::new(
-                    InvokeAndAwaitWorkerResponseTyped {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Success(result),
-                        ),
-                    },
-                )))
-            }
+        let result = self
+            .get_oplog_internal(request)
+            .instrument(record.span.clone())
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeAndAwaitWorkerResponseTyped {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_and_await_worker_response_typed::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetOplogResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_oplog_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn invoke_worker_json(
+    async fn search_oplog(
         &self,
-        request: Request<InvokeJsonWorkerRequest>,
-    ) -> Result<Response<InvokeWorkerResponse>, Status> {
+        request: Request<SearchOplogRequest>,
+    ) -> Result<Response<SearchOplogResponse>, Status> {
         let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "invoke_worker_json",
-            worker_id = proto_target_worker_id_string(&request.worker_id),
-            function = request.name,
-            account_id = proto_account_id_string(&request.account_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            "search_oplog",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self
-            .invoke_worker_internal(&request)
+        let result = self
+            .search_oplog_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::InvokeWorkerResponse {
+            .await;
+        match result {
+            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Err(err) => record.fail(
+                Ok(Response::new(SearchOplogResponse {
                     result: Some(
-                        golem::workerexecutor::v1::invoke_worker_response::Result::Success(
-                            golem::common::Empty {},
+                        golem::workerexecutor::v1::search_oplog_response::Result::Failure(
+                            err.clone().into(),
                         ),
                     ),
-                },
-            ))),
-            Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InvokeWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::invoke_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
-                        ),
-                    },
-                )),
+                })),
                 &err,
             ),
         }
     }
 
-    type ConnectWorkerStream = ResponseStream;
-
-    async fn connect_worker(
+    async fn fork_worker(
         &self,
-        request: Request<golem::workerexecutor::v1::ConnectWorkerRequest>,
-    ) -> ResponseResult<Self::ConnectWorkerStream> {
+        request: Request<ForkWorkerRequest>,
+    ) -> Result<Response<ForkWorkerResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!(
-            "connect_worker",
-            worker_id = proto_worker_id_string(&request.worker_id),
-            account_id = proto_account_id_string(&request.account_id)
-        );
-
-        self.connect_worker_internal(request)
-            .instrument(record.span.clone())
-            .await
-    }
 
-    async fn delete_worker(
-        &self,
-        request: Request<golem::workerexecutor::v1::DeleteWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::DeleteWorkerResponse>, Status> {
-        let request = request.into_inner();
         let record = recorded_grpc_api_request!(
-            "delete_worker",
-            worker_id = proto_worker_id_string(&request.worker_id)
+            "fork_worker",
+            source_worker_id = proto_worker_id_string(&request.source_worker_id),
+            target_worker_id = proto_worker_id_string(&request.target_worker_id),
         );
 
-        match self
-            .delete_worker_internal(request)
+        let result = self
+            .fork_worker_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::DeleteWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::delete_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(ForkWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::fork_worker_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::DeleteWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::delete_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(ForkWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::fork_worker_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn complete_promise(
+    async fn revert_worker(
         &self,
-        request: Request<golem::workerexecutor::v1::CompletePromiseRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::CompletePromiseResponse>, Status> {
+        request: Request<RevertWorkerRequest>,
+    ) -> Result<Response<RevertWorkerResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "complete_promise",
-            promise_id = proto_promise_id_string(&request.promise_id)
+            "revert_worker",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self
-            .complete_promise_internal(request)
+        let result = self
+            .revert_worker_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(success) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::CompletePromiseResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::complete_promise_response::Result::Success(
-                            success,
-                        ),
+            .await;
+
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(RevertWorkerResponse {
+                result: Some(
+                    golem::workerexecutor::v1::revert_worker_response::Result::Success(
+                        golem::common::Empty {},
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::CompletePromiseResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::complete_promise_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(RevertWorkerResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::revert_worker_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn interrupt_worker(
+    async fn cancel_invocation(
         &self,
-        request: Request<golem::workerexecutor::v1::InterruptWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::InterruptWorkerResponse>, Status> {
+        request: Request<CancelInvocationRequest>,
+    ) -> Result<Response<CancelInvocationResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "interrupt_worker",
+            "cancel_invocation",
             worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
         );
 
-        match self
-            .interrupt_worker_internal(request)
+        let result = self
+            .cancel_invocation_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::InterruptWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::interrupt_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+
+        match result {
+            Ok(canceled) => record.succeed(Ok(Response::new(CancelInvocationResponse {
+                result: Some(
+                    golem::workerexecutor::v1::cancel_invocation_response::Result::Success(
+                        canceled,
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::InterruptWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::interrupt_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(CancelInvocationResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::cancel_invocation_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn revoke_shards(
+    async fn get_invocation_result(
         &self,
-        request: Request<golem::workerexecutor::v1::RevokeShardsRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::RevokeShardsResponse>, Status> {
+        request: Request<GetInvocationResultRequest>,
+    ) -> Result<Response<GetInvocationResultResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!("revoke_shards",);
 
-        match self
-            .revoke_shards_internal(request)
+        let record = recorded_grpc_api_request!(
+            "get_invocation_result",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        let result = self
+            .get_invocation_result_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::RevokeShardsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::revoke_shards_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+
+        match result {
+            Ok(status) => record.succeed(Ok(Response::new(GetInvocationResultResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_invocation_result_response::Result::Success(
+                        status.into(),
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::RevokeShardsResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::revoke_shards_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetInvocationResultResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_invocation_result_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn assign_shards(
+    async fn get_pending_invocations(
         &self,
-        request: Request<golem::workerexecutor::v1::AssignShardsRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::AssignShardsResponse>, Status> {
+        request: Request<GetPendingInvocationsRequest>,
+    ) -> Result<Response<GetPendingInvocationsResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!("assign_shards",);
 
-        match self
-            .assign_shards_internal(request)
+        let record = recorded_grpc_api_request!(
+            "get_pending_invocations",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let result = self
+            .get_pending_invocations_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::AssignShardsResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::assign_shards_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+
+        match result {
+            Ok(invocations) => record.succeed(Ok(Response::new(GetPendingInvocationsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_pending_invocations_response::Result::Success(
+                        golem::workerexecutor::v1::GetPendingInvocationsSuccessResponse {
+                            invocations: invocations.into_iter().map(|i| i.into()).collect(),
+                        },
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::AssignShardsResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::assign_shards_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetPendingInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_pending_invocations_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn get_worker_metadata(
+    async fn change_invocation_priority(
         &self,
-        request: Request<golem::workerexecutor::v1::GetWorkerMetadataRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::GetWorkerMetadataResponse>, Status> {
+        request: Request<ChangeInvocationPriorityRequest>,
+    ) -> Result<Response<ChangeInvocationPriorityResponse>, Status> {
         let request = request.into_inner();
 
         let record = recorded_grpc_api_request!(
-            "get_worker_metadata",
-            worker_id = proto_worker_id_string(&request.worker_id)
+            "change_invocation_priority",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
         );
 
         let result = self
-            .get_worker_metadata_internal(request)
+            .change_invocation_priority_internal(request)
             .instrument(record.span.clone())
             .await;
+
         match result {
-            Ok(result) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::GetWorkerMetadataResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Success(
-                            result,
-                        ),
+            Ok(changed) => record.succeed(Ok(Response::new(ChangeInvocationPriorityResponse {
+                result: Some(
+                    golem::workerexecutor::v1::change_invocation_priority_response::Result::Success(
+                        changed,
                     ),
-                },
-            ))),
-            Err(err @ WorkerExecutorError::WorkerNotFound { .. }) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::GetWorkerMetadataResponse {
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(ChangeInvocationPriorityResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
+                        golem::workerexecutor::v1::change_invocation_priority_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
-                },
-            ))),
-            Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::GetWorkerMetadataResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::get_worker_metadata_response::Result::Failure(
-                                err.clone().into(),
-                            ),
-                        ),
-                    },
-                )),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn resume_worker(
+    async fn purge_pending_invocations(
         &self,
-        request: Request<golem::workerexecutor::v1::ResumeWorkerRequest>,
-    ) -> Result<Response<golem::workerexecutor::v1::ResumeWorkerResponse>, Status> {
+        request: Request<PurgePendingInvocationsRequest>,
+    ) -> Result<Response<PurgePendingInvocationsResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "resume_worker",
+            "purge_pending_invocations",
             worker_id = proto_worker_id_string(&request.worker_id),
         );
 
-        match self
-            .resume_worker_internal(request)
+        let result = self
+            .purge_pending_invocations_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::ResumeWorkerResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::resume_worker_response::Result::Success(
-                            golem::common::Empty {},
-                        ),
+            .await;
+
+        match result {
+            Ok(canceled) => record.succeed(Ok(Response::new(PurgePendingInvocationsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::purge_pending_invocations_response::Result::Success(
+                        golem::workerexecutor::v1::PurgePendingInvocationsSuccessResponse {
+                            canceled: canceled.into_iter().map(|key| key.into()).collect(),
+                        },
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    golem::workerexecutor::v1::ResumeWorkerResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::resume_worker_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(PurgePendingInvocationsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::purge_pending_invocations_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn get_running_workers_metadata(
+    async fn get_sagas(
         &self,
-        request: Request<GetRunningWorkersMetadataRequest>,
-    ) -> Result<Response<GetRunningWorkersMetadataResponse>, Status> {
+        request: Request<GetSagasRequest>,
+    ) -> Result<Response<GetSagasResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "get_running_workers_metadata",
-            component_id = proto_component_id_string(&request.component_id),
+            "get_sagas",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .get_running_workers_metadata_internal(request)
+            .get_sagas_internal(request)
             .instrument(record.span.clone())
             .await;
+
         match result {
-            Ok(workers) => record.succeed(Ok(Response::new(
-                golem::workerexecutor::v1::GetRunningWorkersMetadataResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Success(
-                            golem::workerexecutor::v1::GetRunningWorkersMetadataSuccessResponse {
-                                workers
-                            }
-                        ),
+            Ok(sagas) => record.succeed(Ok(Response::new(GetSagasResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_sagas_response::Result::Success(
+                        golem::workerexecutor::v1::GetSagasSuccessResponse {
+                            sagas: sagas.into_iter().map(|saga| saga.into()).collect(),
+                        },
                     ),
-                },
-            ))),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(
-                    GetRunningWorkersMetadataResponse {
-                        result: Some(
-                            golem::workerexecutor::v1::get_running_workers_metadata_response::Result::Failure(
-                                err.clone().into(),
-                            ),
+                Ok(Response::new(GetSagasResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::get_sagas_response::Result::Failure(
+                            err.clone().into(),
                         ),
-                    },
-                )),
+                    ),
+                })),
                 &err,
             ),
         }
     }
 
-    async fn get_workers_metadata(
+    async fn abort_saga(
         &self,
-        request: Request<GetWorkersMetadataRequest>,
-    ) -> Result<Response<GetWorkersMetadataResponse>, Status> {
+        request: Request<AbortSagaRequest>,
+    ) -> Result<Response<AbortSagaResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "get_workers_metadata",
-            component_id = proto_component_id_string(&request.component_id),
+            "abort_saga",
+            worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .get_workers_metadata_internal(request)
+            .abort_saga_internal(request)
             .instrument(record.span.clone())
             .await;
+
         match result {
-            Ok((cursor, workers)) => {
-                record.succeed(Ok(Response::new(GetWorkersMetadataResponse {
-                    result: Some(
-                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Success(
-                            golem::workerexecutor::v1::GetWorkersMetadataSuccessResponse {
-                                workers,
-                                cursor,
-                            },
-                        ),
-                    ),
-                })))
-            }
+            Ok(saga) => record.succeed(Ok(Response::new(AbortSagaResponse {
+                result: Some(
+                    golem::workerexecutor::v1::abort_saga_response::Result::Success(saga.into()),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(GetWorkersMetadataResponse {
+                Ok(Response::new(AbortSagaResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_workers_metadata_response::Result::Failure(
+                        golem::workerexecutor::v1::abort_saga_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2423,33 +3424,42 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn update_worker(
+    async fn schedule_invocation(
         &self,
-        request: Request<UpdateWorkerRequest>,
-    ) -> Result<Response<UpdateWorkerResponse>, Status> {
+        request: Request<ScheduleInvocationRequest>,
+    ) -> Result<Response<ScheduleInvocationResponse>, Status> {
         let request = request.into_inner();
-        let record = recorded_grpc_api_request!(
-            "update_worker",
-            worker_id = proto_worker_id_string(&request.worker_id),
-            target_version = request.target_version,
-        );
 
-        match self
-            .update_worker_internal(request)
+        let worker_id = match &request.invocation {
+            Some(golem::workerexecutor::v1::schedule_invocation_request::Invocation::Invoke(
+                invoke,
+            )) => proto_target_worker_id_string(&invoke.worker_id),
+            Some(
+                golem::workerexecutor::v1::schedule_invocation_request::Invocation::InvokeJson(
+                    invoke,
+                ),
+            ) => proto_target_worker_id_string(&invoke.worker_id),
+            None => None,
+        };
+        let record = recorded_grpc_api_request!("schedule_invocation", worker_id = worker_id);
+
+        let result = self
+            .schedule_invocation_internal(request)
             .instrument(record.span.clone())
-            .await
-        {
-            Ok(_) => record.succeed(Ok(Response::new(UpdateWorkerResponse {
+            .await;
+
+        match result {
+            Ok(schedule) => record.succeed(Ok(Response::new(ScheduleInvocationResponse {
                 result: Some(
-                    golem::workerexecutor::v1::update_worker_response::Result::Success(
-                        golem::common::Empty {},
+                    golem::workerexecutor::v1::schedule_invocation_response::Result::Success(
+                        schedule.into(),
                     ),
                 ),
             }))),
             Err(err) => record.fail(
-                Ok(Response::new(UpdateWorkerResponse {
+                Ok(Response::new(ScheduleInvocationResponse {
                     result: Some(
-                        golem::workerexecutor::v1::update_worker_response::Result::Failure(
+                        golem::workerexecutor::v1::schedule_invocation_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2459,26 +3469,36 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn get_oplog(
+    async fn get_invocation_schedules(
         &self,
-        request: Request<GetOplogRequest>,
-    ) -> Result<Response<GetOplogResponse>, Status> {
+        request: Request<GetInvocationSchedulesRequest>,
+    ) -> Result<Response<GetInvocationSchedulesResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "get_oplog",
+            "get_invocation_schedules",
             worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .get_oplog_internal(request)
+            .get_invocation_schedules_internal(request)
             .instrument(record.span.clone())
             .await;
+
         match result {
-            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Ok(schedules) => record.succeed(Ok(Response::new(GetInvocationSchedulesResponse {
+                result: Some(
+                    golem::workerexecutor::v1::get_invocation_schedules_response::Result::Success(
+                        golem::workerexecutor::v1::GetInvocationSchedulesSuccessResponse {
+                            schedules: schedules.into_iter().map(|s| s.into()).collect(),
+                        },
+                    ),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(GetOplogResponse {
+                Ok(Response::new(GetInvocationSchedulesResponse {
                     result: Some(
-                        golem::workerexecutor::v1::get_oplog_response::Result::Failure(
+                        golem::workerexecutor::v1::get_invocation_schedules_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2488,26 +3508,34 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn search_oplog(
+    async fn cancel_invocation_schedule(
         &self,
-        request: Request<SearchOplogRequest>,
-    ) -> Result<Response<SearchOplogResponse>, Status> {
+        request: Request<CancelInvocationScheduleRequest>,
+    ) -> Result<Response<CancelInvocationScheduleResponse>, Status> {
         let request = request.into_inner();
+
         let record = recorded_grpc_api_request!(
-            "search_oplog",
+            "cancel_invocation_schedule",
             worker_id = proto_worker_id_string(&request.worker_id),
         );
 
         let result = self
-            .search_oplog_internal(request)
+            .cancel_invocation_schedule_internal(request)
             .instrument(record.span.clone())
             .await;
+
         match result {
-            Ok(response) => record.succeed(Ok(Response::new(response))),
+            Ok(canceled) => record.succeed(Ok(Response::new(CancelInvocationScheduleResponse {
+                result: Some(
+                    golem::workerexecutor::v1::cancel_invocation_schedule_response::Result::Success(
+                        canceled,
+                    ),
+                ),
+            }))),
             Err(err) => record.fail(
-                Ok(Response::new(SearchOplogResponse {
+                Ok(Response::new(CancelInvocationScheduleResponse {
                     result: Some(
-                        golem::workerexecutor::v1::search_oplog_response::Result::Failure(
+                        golem::workerexecutor::v1::cancel_invocation_schedule_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2517,35 +3545,34 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn fork_worker(
+    async fn set_worker_ttl_policy(
         &self,
-        request: Request<ForkWorkerRequest>,
-    ) -> Result<Response<ForkWorkerResponse>, Status> {
+        request: Request<SetWorkerTtlPolicyRequest>,
+    ) -> Result<Response<SetWorkerTtlPolicyResponse>, Status> {
         let request = request.into_inner();
 
         let record = recorded_grpc_api_request!(
-            "fork_worker",
-            source_worker_id = proto_worker_id_string(&request.source_worker_id),
-            target_worker_id = proto_worker_id_string(&request.target_worker_id),
+            "set_worker_ttl_policy",
+            target = proto_ttl_policy_target_string(&request.target),
         );
 
         let result = self
-            .fork_worker_internal(request)
+            .set_worker_ttl_policy_internal(request)
             .instrument(record.span.clone())
             .await;
 
         match result {
-            Ok(_) => record.succeed(Ok(Response::new(ForkWorkerResponse {
+            Ok(()) => record.succeed(Ok(Response::new(SetWorkerTtlPolicyResponse {
                 result: Some(
-                    golem::workerexecutor::v1::fork_worker_response::Result::Success(
+                    golem::workerexecutor::v1::set_worker_ttl_policy_response::Result::Success(
                         golem::common::Empty {},
                     ),
                 ),
             }))),
             Err(err) => record.fail(
-                Ok(Response::new(ForkWorkerResponse {
+                Ok(Response::new(SetWorkerTtlPolicyResponse {
                     result: Some(
-                        golem::workerexecutor::v1::fork_worker_response::Result::Failure(
+                        golem::workerexecutor::v1::set_worker_ttl_policy_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2555,34 +3582,36 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn revert_worker(
+    async fn get_worker_ttl_policy(
         &self,
-        request: Request<RevertWorkerRequest>,
-    ) -> Result<Response<RevertWorkerResponse>, Status> {
+        request: Request<GetWorkerTtlPolicyRequest>,
+    ) -> Result<Response<GetWorkerTtlPolicyResponse>, Status> {
         let request = request.into_inner();
 
         let record = recorded_grpc_api_request!(
-            "revert_worker",
-            worker_id = proto_worker_id_string(&request.worker_id),
+            "get_worker_ttl_policy",
+            target = proto_ttl_policy_target_string(&request.target),
         );
 
         let result = self
-            .revert_worker_internal(request)
+            .get_worker_ttl_policy_internal(request)
             .instrument(record.span.clone())
             .await;
 
         match result {
-            Ok(_) => record.succeed(Ok(Response::new(RevertWorkerResponse {
+            Ok(policy) => record.succeed(Ok(Response::new(GetWorkerTtlPolicyResponse {
                 result: Some(
-                    golem::workerexecutor::v1::revert_worker_response::Result::Success(
-                        golem::common::Empty {},
+                    golem::workerexecutor::v1::get_worker_ttl_policy_response::Result::Success(
+                        golem::workerexecutor::v1::GetWorkerTtlPolicySuccessResponse {
+                            policy: policy.map(|policy| policy.into()),
+                        },
                     ),
                 ),
             }))),
             Err(err) => record.fail(
-                Ok(Response::new(RevertWorkerResponse {
+                Ok(Response::new(GetWorkerTtlPolicyResponse {
                     result: Some(
-                        golem::workerexecutor::v1::revert_worker_response::Result::Failure(
+                        golem::workerexecutor::v1::get_worker_ttl_policy_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2592,35 +3621,36 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
         }
     }
 
-    async fn cancel_invocation(
+    async fn get_worker_ttl_deletions(
         &self,
-        request: Request<CancelInvocationRequest>,
-    ) -> Result<Response<CancelInvocationResponse>, Status> {
+        request: Request<GetWorkerTtlDeletionsRequest>,
+    ) -> Result<Response<GetWorkerTtlDeletionsResponse>, Status> {
         let request = request.into_inner();
 
         let record = recorded_grpc_api_request!(
-            "cancel_invocation",
-            worker_id = proto_worker_id_string(&request.worker_id),
-            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+            "get_worker_ttl_deletions",
+            component_id = proto_component_id_string(&request.component_id),
         );
 
         let result = self
-            .cancel_invocation_internal(request)
+            .get_worker_ttl_deletions_internal(request)
             .instrument(record.span.clone())
             .await;
 
         match result {
-            Ok(canceled) => record.succeed(Ok(Response::new(CancelInvocationResponse {
+            Ok(deletions) => record.succeed(Ok(Response::new(GetWorkerTtlDeletionsResponse {
                 result: Some(
-                    golem::workerexecutor::v1::cancel_invocation_response::Result::Success(
-                        canceled,
+                    golem::workerexecutor::v1::get_worker_ttl_deletions_response::Result::Success(
+                        golem::workerexecutor::v1::GetWorkerTtlDeletionsSuccessResponse {
+                            deletions: deletions.into_iter().map(|d| d.into()).collect(),
+                        },
                     ),
                 ),
             }))),
             Err(err) => record.fail(
-                Ok(Response::new(CancelInvocationResponse {
+                Ok(Response::new(GetWorkerTtlDeletionsResponse {
                     result: Some(
-                        golem::workerexecutor::v1::cancel_invocation_response::Result::Failure(
+                        golem::workerexecutor::v1::get_worker_ttl_deletions_response::Result::Failure(
                             err.clone().into(),
                         ),
                     ),
@@ -2772,6 +3802,42 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + UsesAllDeps<Ctx = Ctx> + Send + Sync +
             ),
         }
     }
+
+    async fn update_worker_labels(
+        &self,
+        request: Request<UpdateWorkerLabelsRequest>,
+    ) -> Result<Response<UpdateWorkerLabelsResponse>, Status> {
+        let request = request.into_inner();
+        let record = recorded_grpc_api_request!(
+            "update_worker_labels",
+            worker_id = proto_worker_id_string(&request.worker_id)
+        );
+
+        let result = self
+            .update_worker_labels_internal(request)
+            .instrument(record.span.clone())
+            .await;
+
+        match result {
+            Ok(_) => record.succeed(Ok(Response::new(UpdateWorkerLabelsResponse {
+                result: Some(
+                    golem::workerexecutor::v1::update_worker_labels_response::Result::Success(
+                        golem::common::Empty {},
+                    ),
+                ),
+            }))),
+            Err(err) => record.fail(
+                Ok(Response::new(UpdateWorkerLabelsResponse {
+                    result: Some(
+                        golem::workerexecutor::v1::update_worker_labels_response::Result::Failure(
+                            err.clone().into(),
+                        ),
+                    ),
+                })),
+                &err,
+            ),
+        }
+    }
 }
 
 pub fn authorised_grpc_request<T>(request: T, access_token: &Uuid) -> Request<T> {
@@ -2856,3 +3922,51 @@ fn extract_account_id<T>(
         .ok_or(WorkerExecutorError::invalid_request("account_id not found"))?;
     Ok(account_id.clone().into())
 }
+
+enum TtlPolicyTarget {
+    Component(ProjectId, ComponentId),
+    Worker(OwnedWorkerId),
+}
+
+fn extract_ttl_policy_target(
+    target: &Option<WorkerTtlPolicyTarget>,
+    project_id: &Option<golem::common::ProjectId>,
+) -> Result<TtlPolicyTarget, WorkerExecutorError> {
+    let project_id: ProjectId = (*project_id)
+        .ok_or(WorkerExecutorError::invalid_request("project_id not found"))?
+        .try_into()
+        .map_err(WorkerExecutorError::invalid_request)?;
+
+    match target.as_ref().and_then(|target| target.target.clone()) {
+        Some(golem::workerexecutor::v1::worker_ttl_policy_target::Target::ComponentId(
+            component_id,
+        )) => Ok(TtlPolicyTarget::Component(
+            project_id,
+            component_id
+                .try_into()
+                .map_err(WorkerExecutorError::invalid_request)?,
+        )),
+        Some(golem::workerexecutor::v1::worker_ttl_policy_target::Target::WorkerId(worker_id)) => {
+            let worker_id: WorkerId = worker_id
+                .try_into()
+                .map_err(WorkerExecutorError::invalid_request)?;
+            Ok(TtlPolicyTarget::Worker(OwnedWorkerId::new(
+                &project_id,
+                &worker_id,
+            )))
+        }
+        None => Err(WorkerExecutorError::invalid_request("target not found")),
+    }
+}
+
+fn proto_ttl_policy_target_string(target: &Option<WorkerTtlPolicyTarget>) -> Option<String> {
+    match target.as_ref().and_then(|target| target.target.clone()) {
+        Some(golem::workerexecutor::v1::worker_ttl_policy_target::Target::ComponentId(
+            component_id,
+        )) => proto_component_id_string(&Some(component_id)),
+        Some(golem::workerexecutor::v1::worker_ttl_policy_target::Target::WorkerId(worker_id)) => {
+            proto_worker_id_string(&Some(worker_id))
+        }
+        None => None,
+    }
+}
@@ -46,6 +46,8 @@ use crate::services::oplog::{
 use crate::services::plugins::{Plugins, PluginsObservations};
 use crate::services::projects::ProjectService;
 use crate::services::promise::{DefaultPromiseService, PromiseService};
+use crate::services::pubsub::{DefaultPubSubService, PubSubService};
+use crate::services::saga::{DefaultSagaService, SagaService};
 use crate::services::scheduler::{SchedulerService, SchedulerServiceDefault};
 use crate::services::shard::{ShardService, ShardServiceDefault};
 use crate::services::shard_manager::ShardManagerService;
@@ -189,6 +191,8 @@ pub trait Bootstrap<Ctx: WorkerCtx> {
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         golem_config: Arc<GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -396,7 +400,10 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
 
     let initial_files_service = Arc::new(InitialComponentFilesService::new(blob_storage.clone()));
 
-    let file_loader = Arc::new(FileLoader::new(initial_files_service.clone())?);
+    let file_loader = Arc::new(FileLoader::new(
+        initial_files_service.clone(),
+        blob_storage.clone(),
+    )?);
     let (plugins, plugins_observations) = bootstrap.create_plugins(&golem_config);
 
     let project_service = services::projects::configured(&golem_config.project_service);
@@ -411,6 +418,8 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
     let golem_config = Arc::new(golem_config.clone());
     let promise_service: Arc<dyn PromiseService> =
         Arc::new(DefaultPromiseService::new(key_value_storage.clone()));
+    let pubsub_service: Arc<dyn PubSubService> =
+        Arc::new(DefaultPubSubService::new(key_value_storage.clone()));
     let shard_service = Arc::new(ShardServiceDefault::new());
 
     let mut oplog_archives: Vec<Arc<dyn OplogArchiveService>> = Vec::new();
@@ -518,6 +527,12 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
         project_service.clone(),
     ));
 
+    let saga_service: Arc<dyn SagaService> = Arc::new(DefaultSagaService::new(
+        key_value_storage.clone(),
+        oplog_service.clone(),
+        shard_service.clone(),
+    ));
+
     let worker_service = Arc::new(DefaultWorkerService::new(
         key_value_storage.clone(),
         shard_service.clone(),
@@ -538,6 +553,7 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
         oplog_service.clone(),
         worker_service.clone(),
         golem_config.scheduler.refresh_interval,
+        golem_config.scheduler.ttl_deletion_log_retention,
     );
 
     let all = bootstrap
@@ -552,6 +568,8 @@ pub async fn create_worker_executor_impl<Ctx: WorkerCtx, A: Bootstrap<Ctx> + ?Si
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config.clone(),
             shard_service,
             key_value_service,
@@ -107,6 +107,7 @@ pub mod events {
 }
 
 pub mod workers {
+    use golem_common::model::WorkerId;
     use lazy_static::lazy_static;
     use prometheus::*;
 
@@ -117,6 +118,17 @@ pub mod workers {
             &["api"]
         )
         .unwrap();
+        static ref WORKER_TTL_DELETIONS_TOTAL: Counter = register_counter!(
+            "worker_ttl_deletions_total",
+            "Number of workers deleted because of their time-to-live policy"
+        )
+        .unwrap();
+        static ref WORKER_INVOCATION_QUEUE_DEPTH: IntGaugeVec = register_int_gauge_vec!(
+            "worker_invocation_queue_depth",
+            "Number of pending invocations in the invocation queue of a worker loaded in memory",
+            &["worker_id"]
+        )
+        .unwrap();
     }
 
     pub fn record_worker_call(api_name: &'static str) {
@@ -124,6 +136,20 @@ pub mod workers {
             .with_label_values(&[api_name])
             .inc();
     }
+
+    pub fn record_worker_ttl_deletion() {
+        WORKER_TTL_DELETIONS_TOTAL.inc();
+    }
+
+    pub fn record_invocation_queue_depth(worker_id: &WorkerId, depth: usize) {
+        WORKER_INVOCATION_QUEUE_DEPTH
+            .with_label_values(&[&worker_id.to_string()])
+            .set(depth as i64);
+    }
+
+    pub fn remove_invocation_queue_depth(worker_id: &WorkerId) {
+        let _ = WORKER_INVOCATION_QUEUE_DEPTH.remove_label_values(&[&worker_id.to_string()]);
+    }
 }
 
 pub mod promises {
@@ -110,6 +110,8 @@ pub struct CurrentResourceLimits {
     pub fuel: i64,
     /// The maximum amount of memory that can be used by the worker
     pub max_memory: usize,
+    /// The storage available for the file systems of the account's workers
+    pub available_storage: i64,
 }
 
 impl From<golem_api_grpc::proto::golem::common::ResourceLimits> for CurrentResourceLimits {
@@ -117,6 +119,7 @@ impl From<golem_api_grpc::proto::golem::common::ResourceLimits> for CurrentResou
         Self {
             fuel: value.available_fuel,
             max_memory: value.max_memory_per_worker as usize,
+            available_storage: value.available_storage,
         }
     }
 }
@@ -30,10 +30,14 @@ use crate::services::component::ComponentService;
 use crate::services::oplog::OplogService;
 use crate::services::plugins::Plugins;
 use crate::services::projects::ProjectService;
+use crate::services::rdbms::mssql::types as mssql_types;
+use crate::services::rdbms::mssql::MssqlType;
 use crate::services::rdbms::mysql::types as mysql_types;
 use crate::services::rdbms::mysql::MysqlType;
 use crate::services::rdbms::postgres::types as postgres_types;
 use crate::services::rdbms::postgres::PostgresType;
+use crate::services::rdbms::sqlite::types as sqlite_types;
+use crate::services::rdbms::sqlite::SqliteType;
 use crate::services::rdbms::RdbmsIntoValueAndType;
 use crate::services::rpc::RpcError;
 use async_trait::async_trait;
@@ -43,7 +47,8 @@ use golem_common::model::exports::find_resource_site;
 use golem_common::model::lucene::Query;
 use golem_common::model::oplog::{OplogEntry, OplogIndex, SpanData, UpdateDescription};
 use golem_common::model::public_oplog::{
-    ActivatePluginParameters, CancelInvocationParameters, ChangePersistenceLevelParameters,
+    ActivatePluginParameters, CancelInvocationParameters,
+    ChangePendingInvocationPriorityParameters, ChangePersistenceLevelParameters,
     ChangeRetryPolicyParameters, CreateAgentInstanceParameters, CreateParameters,
     DeactivatePluginParameters, DescribeResourceParameters, DropAgentInstanceParameters,
     EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
@@ -52,15 +57,18 @@ use golem_common::model::public_oplog::{
     LogParameters, ManualUpdateParameters, PendingUpdateParameters,
     PendingWorkerInvocationParameters, PluginInstallationDescription, PublicAttribute,
     PublicExternalSpanData, PublicLocalSpanData, PublicOplogEntry, PublicSpanData,
-    PublicUpdateDescription, PublicWorkerInvocation, ResourceParameters, RevertParameters,
-    SetSpanAttributeParameters, SnapshotBasedUpdateParameters, StartSpanParameters,
-    SuccessfulUpdateParameters, TimestampParameter,
+    PublicUpdateDescription, PublicWorkerInvocation, RemoveLabelParameters, ResourceParameters,
+    RevertParameters, SetLabelParameters, SetSpanAttributeParameters,
+    SnapshotBasedUpdateParameters, StartSpanParameters, SuccessfulUpdateParameters,
+    TimestampParameter,
 };
 use golem_common::model::{
-    ComponentId, ComponentVersion, Empty, OwnedWorkerId, PromiseId, WorkerId, WorkerInvocation,
+    ComponentId, ComponentVersion, Empty, IdempotencyKey, OwnedWorkerId, PromiseId, Timestamp,
+    WorkerId, WorkerInvocation,
 };
 use golem_common::serialization::try_deserialize as core_try_deserialize;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::saga::SagaCompensation;
 use golem_service_base::model::RevertWorkerTarget;
 use golem_wasm_ast::analysis::analysed_type::{
     case, field, list, option, record, result, result_err, str, u64, unit_case, variant,
@@ -809,6 +817,32 @@ impl PublicOplogEntryOps for PublicOplogEntry {
             OplogEntry::DropAgentInstance { timestamp, key } => Ok(
                 PublicOplogEntry::DropAgentInstance(DropAgentInstanceParameters { timestamp, key }),
             ),
+            OplogEntry::SetLabel {
+                timestamp,
+                key,
+                value,
+            } => Ok(PublicOplogEntry::SetLabel(SetLabelParameters {
+                timestamp,
+                key,
+                value,
+            })),
+            OplogEntry::RemoveLabel { timestamp, key } => {
+                Ok(PublicOplogEntry::RemoveLabel(RemoveLabelParameters {
+                    timestamp,
+                    key,
+                }))
+            }
+            OplogEntry::ChangePendingInvocationPriority {
+                timestamp,
+                idempotency_key,
+                priority,
+            } => Ok(PublicOplogEntry::ChangePendingInvocationPriority(
+                ChangePendingInvocationPriorityParameters {
+                    timestamp,
+                    idempotency_key,
+                    priority,
+                },
+            )),
         }
     }
 }
@@ -1131,6 +1165,39 @@ fn encode_host_function_request_as_value(
             let payload: PromiseId = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem::api::await_promise_with_timeout deadline" => {
+            let payload: (PromiseId, u64) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::await_promise_with_timeout" => {
+            let payload: PromiseId = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::pubsub::subscribe" => {
+            let payload: (String, String) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::pubsub::unsubscribe" => {
+            let payload: String = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::pubsub::publish" => {
+            let payload: (String, IdempotencyKey) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::saga::begin" => no_payload(),
+        "golem::api::saga::register_compensation" => {
+            let payload: (Uuid, SagaCompensation) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::saga::complete" => {
+            let payload: Uuid = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::saga::abort" => {
+            let payload: (Uuid, String) = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "golem::api::resolve_component_id" => {
             let payload: String = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
@@ -1139,6 +1206,7 @@ fn encode_host_function_request_as_value(
             let payload: (String, String) = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem::api::get_labels" => no_payload(),
         "rdbms::mysql::db-connection::query"
         | "rdbms::mysql::db-connection::execute"
         | "rdbms::mysql::db-connection::query-stream"
@@ -1165,6 +1233,32 @@ fn encode_host_function_request_as_value(
         | "rdbms::postgres::db-transaction::commit"
         | "rdbms::postgres::db-result-stream::get-columns"
         | "rdbms::postgres::db-result-stream::get-next" => no_payload(),
+        "rdbms::sqlite::db-connection::query"
+        | "rdbms::sqlite::db-connection::execute"
+        | "rdbms::sqlite::db-connection::query-stream"
+        | "rdbms::sqlite::db-transaction::query"
+        | "rdbms::sqlite::db-transaction::execute"
+        | "rdbms::sqlite::db-transaction::query-stream" => {
+            let payload: Option<RdbmsRequest<SqliteType>> = try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::sqlite::db-transaction::rollback"
+        | "rdbms::sqlite::db-transaction::commit"
+        | "rdbms::sqlite::db-result-stream::get-columns"
+        | "rdbms::sqlite::db-result-stream::get-next" => no_payload(),
+        "rdbms::mssql::db-connection::query"
+        | "rdbms::mssql::db-connection::execute"
+        | "rdbms::mssql::db-connection::query-stream"
+        | "rdbms::mssql::db-transaction::query"
+        | "rdbms::mssql::db-transaction::execute"
+        | "rdbms::mssql::db-transaction::query-stream" => {
+            let payload: Option<RdbmsRequest<MssqlType>> = try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::mssql::db-transaction::rollback"
+        | "rdbms::mssql::db-transaction::commit"
+        | "rdbms::mssql::db-result-stream::get-columns"
+        | "rdbms::mssql::db-result-stream::get-next" => no_payload(),
         _ => {
             // For everything else we assume that payload is a serialized ValueAndType
             let payload: ValueAndType = try_deserialize(bytes)?;
@@ -1525,6 +1619,32 @@ fn encode_host_function_response_as_value(
             let payload: Result<Option<Vec<u8>>, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem::api::await_promise_with_timeout deadline" => {
+            let payload: Result<Timestamp, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::await_promise_with_timeout" => {
+            let payload: Result<Option<Vec<u8>>, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::pubsub::subscribe" | "golem::api::pubsub::unsubscribe" => {
+            let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::pubsub::publish" => {
+            let payload: Result<u32, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::saga::begin" => {
+            let payload: Result<Uuid, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "golem::api::saga::register_compensation"
+        | "golem::api::saga::complete"
+        | "golem::api::saga::abort" => {
+            let payload: Result<Result<(), String>, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "golem::api::resolve_component_id" => {
             let payload: Result<Option<ComponentId>, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
@@ -1533,6 +1653,10 @@ fn encode_host_function_response_as_value(
             let payload: Result<Option<WorkerId>, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
         }
+        "golem::api::get_labels" => {
+            let payload: Result<Vec<(String, String)>, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
         "rdbms::mysql::db-connection::execute" | "rdbms::mysql::db-transaction::execute" => {
             let payload: Result<u64, SerializableError> = try_deserialize(bytes)?;
             Ok(payload.into_value_and_type())
@@ -1595,6 +1719,68 @@ fn encode_host_function_response_as_value(
             > = try_deserialize(bytes)?;
             Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
         }
+        "rdbms::sqlite::db-connection::execute" | "rdbms::sqlite::db-transaction::execute" => {
+            let payload: Result<u64, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "rdbms::sqlite::db-connection::query" | "rdbms::sqlite::db-transaction::query" => {
+            let payload: Result<crate::services::rdbms::DbResult<SqliteType>, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::sqlite::db-connection::query-stream"
+        | "rdbms::sqlite::db-transaction::query-stream" => {
+            let payload: Result<RdbmsRequest<SqliteType>, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::sqlite::db-transaction::rollback" | "rdbms::sqlite::db-transaction::commit" => {
+            let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "rdbms::sqlite::db-result-stream::get-columns" => {
+            let payload: Result<Vec<sqlite_types::DbColumn>, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::sqlite::db-result-stream::get-next" => {
+            let payload: Result<
+                Option<Vec<crate::services::rdbms::DbRow<sqlite_types::DbValue>>>,
+                SerializableError,
+            > = try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::mssql::db-connection::execute" | "rdbms::mssql::db-transaction::execute" => {
+            let payload: Result<u64, SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "rdbms::mssql::db-connection::query" | "rdbms::mssql::db-transaction::query" => {
+            let payload: Result<crate::services::rdbms::DbResult<MssqlType>, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::mssql::db-connection::query-stream"
+        | "rdbms::mssql::db-transaction::query-stream" => {
+            let payload: Result<RdbmsRequest<MssqlType>, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::mssql::db-transaction::rollback" | "rdbms::mssql::db-transaction::commit" => {
+            let payload: Result<(), SerializableError> = try_deserialize(bytes)?;
+            Ok(payload.into_value_and_type())
+        }
+        "rdbms::mssql::db-result-stream::get-columns" => {
+            let payload: Result<Vec<mssql_types::DbColumn>, SerializableError> =
+                try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
+        "rdbms::mssql::db-result-stream::get-next" => {
+            let payload: Result<
+                Option<Vec<crate::services::rdbms::DbRow<mssql_types::DbValue>>>,
+                SerializableError,
+            > = try_deserialize(bytes)?;
+            Ok(RdbmsIntoValueAndType::into_value_and_type(payload))
+        }
         _ => {
             // For everything else we assume that payload is a serialized ValueAndType
             let payload: ValueAndType = try_deserialize(bytes)?;
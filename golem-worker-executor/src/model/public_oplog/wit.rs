@@ -17,7 +17,8 @@ use crate::preview2::golem_api_1_x::oplog;
 use crate::preview2::wasi::clocks::wall_clock::Datetime;
 use golem_common::base_model::ProjectId;
 use golem_common::model::public_oplog::{
-    ActivatePluginParameters, CancelInvocationParameters, ChangePersistenceLevelParameters,
+    ActivatePluginParameters, CancelInvocationParameters,
+    ChangePendingInvocationPriorityParameters, ChangePersistenceLevelParameters,
     ChangeRetryPolicyParameters, CreateAgentInstanceParameters, CreateParameters,
     DeactivatePluginParameters, DescribeResourceParameters, DropAgentInstanceParameters,
     EndRegionParameters, ErrorParameters, ExportedFunctionCompletedParameters,
@@ -26,9 +27,10 @@ use golem_common::model::public_oplog::{
     LogParameters, ManualUpdateParameters, PendingUpdateParameters,
     PendingWorkerInvocationParameters, PluginInstallationDescription, PublicAttributeValue,
     PublicDurableFunctionType, PublicRetryConfig, PublicSpanData, PublicWorkerInvocation,
-    ResourceParameters, RevertParameters, SetSpanAttributeParameters,
-    SnapshotBasedUpdateParameters, StartSpanParameters, StringAttributeValue,
-    SuccessfulUpdateParameters, TimestampParameter, WriteRemoteBatchedParameters,
+    RemoveLabelParameters, ResourceParameters, RevertParameters, SetLabelParameters,
+    SetSpanAttributeParameters, SnapshotBasedUpdateParameters, StartSpanParameters,
+    StringAttributeValue, SuccessfulUpdateParameters, TimestampParameter,
+    WriteRemoteBatchedParameters,
 };
 use golem_common::model::Timestamp;
 use golem_wasm_rpc::WitValue;
@@ -343,6 +345,43 @@ impl From<PublicOplogEntry> for oplog::OplogEntry {
                     message: format!("Key: {key:?}"),
                 })
             }
+            PublicOplogEntry::SetLabel(SetLabelParameters {
+                timestamp,
+                key,
+                value,
+            }) => {
+                // TODO: add this to WIT - until then we temporarily represent with a log entry
+                Self::Log(oplog::LogParameters {
+                    timestamp: timestamp.into(),
+                    level: golem_common::model::oplog::LogLevel::Info.into(),
+                    context: "SetLabel".to_string(),
+                    message: format!("{key}={value}"),
+                })
+            }
+            PublicOplogEntry::RemoveLabel(RemoveLabelParameters { timestamp, key }) => {
+                // TODO: add this to WIT - until then we temporarily represent with a log entry
+                Self::Log(oplog::LogParameters {
+                    timestamp: timestamp.into(),
+                    level: golem_common::model::oplog::LogLevel::Info.into(),
+                    context: "RemoveLabel".to_string(),
+                    message: key,
+                })
+            }
+            PublicOplogEntry::ChangePendingInvocationPriority(
+                ChangePendingInvocationPriorityParameters {
+                    timestamp,
+                    idempotency_key,
+                    priority,
+                },
+            ) => {
+                // TODO: add this to WIT - until then we temporarily represent with a log entry
+                Self::Log(oplog::LogParameters {
+                    timestamp: timestamp.into(),
+                    level: golem_common::model::oplog::LogLevel::Info.into(),
+                    context: "ChangePendingInvocationPriority".to_string(),
+                    message: format!("{idempotency_key}={priority}"),
+                })
+            }
         }
     }
 }
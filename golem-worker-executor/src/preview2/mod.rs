@@ -29,6 +29,11 @@ wasmtime::component::bindgen!({
         "wasi:keyvalue/types/bucket": super::durable_host::keyvalue::types::BucketEntry,
         "wasi:keyvalue/types/incoming-value": super::durable_host::keyvalue::types::IncomingValueEntry,
         "wasi:keyvalue/types/outgoing-value": super::durable_host::keyvalue::types::OutgoingValueEntry,
+        "wasi:keyvalue/cache/future-get-result": super::durable_host::keyvalue::caching::FutureGetResultEntry,
+        "wasi:keyvalue/cache/future-exists-result": super::durable_host::keyvalue::caching::FutureExistsResultEntry,
+        "wasi:keyvalue/cache/future-result": super::durable_host::keyvalue::caching::FutureResultEntry,
+        "wasi:keyvalue/cache/future-get-or-set-result": super::durable_host::keyvalue::caching::FutureGetOrSetResultEntry,
+        "wasi:keyvalue/cache/vacancy": super::durable_host::keyvalue::caching::VacancyEntry,
         "golem:api/context/span": super::durable_host::golem::invocation_context_api::SpanEntry,
         "golem:api/context/invocation-context": super::durable_host::golem::invocation_context_api::InvocationContextEntry,
         "golem:api/host/get-workers": super::durable_host::golem::v1x::GetWorkersEntry,
@@ -46,6 +51,12 @@ wasmtime::component::bindgen!({
         "golem:rdbms/postgres/db-transaction": super::durable_host::rdbms::postgres::DbTransactionEntry,
         "golem:rdbms/postgres/lazy-db-column-type": super::durable_host::rdbms::postgres::LazyDbColumnTypeEntry,
         "golem:rdbms/postgres/lazy-db-value": super::durable_host::rdbms::postgres::LazyDbValueEntry,
+        "golem:rdbms/sqlite/db-connection": super::durable_host::rdbms::sqlite::SqliteDbConnection,
+        "golem:rdbms/sqlite/db-result-stream": super::durable_host::rdbms::sqlite::DbResultStreamEntry,
+        "golem:rdbms/sqlite/db-transaction": super::durable_host::rdbms::sqlite::DbTransactionEntry,
+        "golem:rdbms/mssql/db-connection": super::durable_host::rdbms::mssql::MssqlDbConnection,
+        "golem:rdbms/mssql/db-result-stream": super::durable_host::rdbms::mssql::DbResultStreamEntry,
+        "golem:rdbms/mssql/db-transaction": super::durable_host::rdbms::mssql::DbTransactionEntry,
     },
 });
 
@@ -14,18 +14,26 @@
 
 use anyhow::anyhow;
 use async_lock::Mutex;
+use bincode::{Decode, Encode};
+use bytes::Bytes;
 use futures::TryStreamExt;
-use golem_common::model::{InitialComponentFileKey, ProjectId};
+use golem_common::model::oplog::OplogIndex;
+use golem_common::model::{InitialComponentFileKey, OwnedWorkerId, ProjectId};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::replayable_stream::ContentHash;
 use golem_service_base::service::initial_component_files::InitialComponentFilesService;
-use std::collections::HashMap;
+use golem_service_base::storage::blob::{
+    BlobStorage, BlobStorageLabelledApi, BlobStorageNamespace, ExistsResult,
+};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::atomic::AtomicU64;
 use std::sync::Weak;
+use std::time::UNIX_EPOCH;
 use std::{path::PathBuf, sync::Arc};
 use tempfile::TempDir;
 use tokio::io::AsyncWriteExt;
-use tracing::debug;
+use tracing::{debug, warn};
 
 // Opaque token for read-only files. This is used to ensure that the file is not deleted while it is in use.
 // Make sure to not drop this token until you are done with the file.
@@ -37,8 +45,13 @@ pub struct FileUseToken {
 ///
 /// This will hardlink to a temporary directory to avoid copying files between workers. Beware
 /// that hardlinking is only possible within the same filesystem.
+///
+/// It is also responsible for archiving the files written by a worker into blob storage, so they
+/// can be restored on any executor when the worker gets recovered, or when a snapshot-based update
+/// skips the history that created them.
 pub struct FileLoader {
     initial_component_files_service: Arc<InitialComponentFilesService>,
+    blob_storage: Arc<dyn BlobStorage + Send + Sync>,
     cache_dir: TempDir,
     // Note: The cache is shared between accounts. One account no accessing data from another account
     // is implicitly done by the key being a hash of the content.
@@ -52,6 +65,7 @@ pub struct FileLoader {
 impl FileLoader {
     pub fn new(
         initial_component_files_service: Arc<InitialComponentFilesService>,
+        blob_storage: Arc<dyn BlobStorage + Send + Sync>,
     ) -> Result<Self, anyhow::Error> {
         let cache_dir = tempfile::Builder::new()
             .prefix("golem-initial-component-files")
@@ -59,6 +73,7 @@ impl FileLoader {
 
         Ok(Self {
             initial_component_files_service,
+            blob_storage,
             cache: Mutex::new(HashMap::new()),
             cache_dir,
             item_counter: AtomicU64::new(0),
@@ -100,6 +115,531 @@ impl FileLoader {
             })
     }
 
+    /// Archives the writable part of a worker's filesystem after the invocation which completed at
+    /// `oplog_index`, so it can be restored when the worker gets recovered on any executor.
+    ///
+    /// The contents of the files are stored individually, addressed by their hash, so only the files
+    /// which changed since the `previous` archive are uploaded. All the other invocation archives of
+    /// the worker are pruned once the new one is stored.
+    /// Returns `None` if the worker did not write any files.
+    pub async fn archive_worker_files(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        oplog_index: OplogIndex,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+        previous: Option<&WorkerFilesArchive>,
+    ) -> Result<Option<WorkerFilesArchive>, WorkerExecutorError> {
+        let archive = self
+            .collect_worker_files(
+                owned_worker_id,
+                oplog_index,
+                root,
+                read_only_paths,
+                previous,
+            )
+            .await?;
+
+        let previously_empty = match previous {
+            Some(previous) => previous.entries.is_empty(),
+            None => true,
+        };
+        if archive.entries.is_empty() && previously_empty {
+            return Ok(None);
+        }
+
+        self.store_archive(owned_worker_id, ArchiveKind::Invocation, &archive)
+            .await?;
+
+        // Pruning only affects storage usage, the new archive is already stored
+        if let Err(err) = self
+            .prune_invocation_archives(owned_worker_id, &archive, previous)
+            .await
+        {
+            warn!(
+                "Failed to prune the file archives of {}: {err}",
+                owned_worker_id.worker_id
+            );
+        }
+
+        Ok(Some(archive))
+    }
+
+    /// The size of the files in the latest invocation archive of a worker, which is the storage its
+    /// file system keeps taking up while the worker is not loaded
+    pub async fn archived_size(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<u64, WorkerExecutorError> {
+        let latest = self
+            .archives(owned_worker_id, ArchiveKind::Invocation)
+            .await?
+            .into_iter()
+            .max();
+
+        match latest {
+            Some(oplog_index) => Ok(self
+                .load_archive(owned_worker_id, ArchiveKind::Invocation, oplog_index)
+                .await?
+                .map(|archive| archive.size())
+                .unwrap_or_default()),
+            None => Ok(0),
+        }
+    }
+
+    /// Archives the writable part of a worker's filesystem before a snapshot-based update, which is
+    /// going to skip the history that produced these files. The archive is associated with the oplog
+    /// index of the update and is kept until the worker gets deleted.
+    pub async fn archive_worker_files_for_update(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        update_index: OplogIndex,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<(), WorkerExecutorError> {
+        let archive = self
+            .collect_worker_files(owned_worker_id, update_index, root, read_only_paths, None)
+            .await?;
+        self.store_archive(owned_worker_id, ArchiveKind::Update, &archive)
+            .await
+    }
+
+    /// Restores the archive taken after the invocation which completed at `oplog_index`, replacing
+    /// the writable part of the worker's filesystem.
+    ///
+    /// Read-only initial files are never overwritten or removed.
+    /// Returns `None` if there is no such archive.
+    pub async fn restore_worker_files(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        oplog_index: OplogIndex,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<Option<WorkerFilesArchive>, WorkerExecutorError> {
+        match self
+            .load_archive(owned_worker_id, ArchiveKind::Invocation, oplog_index)
+            .await?
+        {
+            Some(archive) => {
+                self.restore_archive(owned_worker_id, &archive, root, read_only_paths)
+                    .await?;
+                Ok(Some(archive))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Restores the archive taken before the snapshot-based update at `update_index`.
+    ///
+    /// Read-only initial files are never overwritten or removed.
+    /// Returns false if there is no such archive.
+    pub async fn restore_worker_files_for_update(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        update_index: OplogIndex,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<bool, WorkerExecutorError> {
+        match self
+            .load_archive(owned_worker_id, ArchiveKind::Update, update_index)
+            .await?
+        {
+            Some(archive) => {
+                self.restore_archive(owned_worker_id, &archive, root, read_only_paths)
+                    .await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Copies the file archives of a worker taken at or before `until`, together with the files they
+    /// refer to, to another worker. Used when forking workers.
+    pub async fn copy_worker_files(
+        &self,
+        source: &OwnedWorkerId,
+        target: &OwnedWorkerId,
+        until: OplogIndex,
+    ) -> Result<(), WorkerExecutorError> {
+        let blob_storage = self.blob_storage.with("file_loader", "copy_worker_files");
+        let mut copied = HashSet::new();
+
+        for kind in [ArchiveKind::Invocation, ArchiveKind::Update] {
+            for oplog_index in self.archives(source, kind).await? {
+                if oplog_index > until {
+                    continue;
+                }
+                let Some(archive) = self.load_archive(source, kind, oplog_index).await? else {
+                    continue;
+                };
+
+                for hash in archive.hashes() {
+                    if copied.insert(hash.clone()) {
+                        let path = Self::file_path(&hash);
+                        let data = blob_storage
+                            .get_raw(Self::worker_files_namespace(source), &path)
+                            .await
+                            .map_err(|e| {
+                                WorkerExecutorError::runtime(format!(
+                                    "Failed to read archived worker file: {e}"
+                                ))
+                            })?;
+                        if let Some(data) = data {
+                            blob_storage
+                                .put_raw(Self::worker_files_namespace(target), &path, &data)
+                                .await
+                                .map_err(|e| {
+                                    WorkerExecutorError::runtime(format!(
+                                        "Failed to store archived worker file: {e}"
+                                    ))
+                                })?;
+                        }
+                    }
+                }
+
+                self.store_archive(target, kind, &archive).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Deletes all the file archives of a worker, and the files they refer to
+    pub async fn delete_worker_files(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError> {
+        let blob_storage = self.blob_storage.with("file_loader", "delete_worker_files");
+        for dir in [ARCHIVES_DIR, UPDATES_DIR, FILES_DIR] {
+            blob_storage
+                .delete_dir(
+                    Self::worker_files_namespace(owned_worker_id),
+                    Path::new(dir),
+                )
+                .await
+                .map_err(|e| {
+                    WorkerExecutorError::runtime(format!("Failed to delete worker files: {e}"))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Collects the entries of the worker's filesystem, uploading the contents of the files which
+    /// are not part of the `previous` archive. Files with the same size and modification time as in
+    /// the previous archive are not read again.
+    async fn collect_worker_files(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        oplog_index: OplogIndex,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+        previous: Option<&WorkerFilesArchive>,
+    ) -> Result<WorkerFilesArchive, WorkerExecutorError> {
+        let blob_storage = self
+            .blob_storage
+            .with("file_loader", "archive_worker_files");
+        let namespace = Self::worker_files_namespace(owned_worker_id);
+        let previous_files = previous
+            .map(|previous| previous.files())
+            .unwrap_or_default();
+        let mut stored = previous
+            .map(|previous| previous.hashes())
+            .unwrap_or_default();
+
+        let collect = async {
+            let mut entries = Vec::new();
+            let mut pending = vec![root.to_path_buf()];
+
+            while let Some(dir) = pending.pop() {
+                let mut read_dir = tokio::fs::read_dir(&dir).await?;
+                while let Some(entry) = read_dir.next_entry().await? {
+                    let path = entry.path();
+                    let relative = WorkerFilesArchive::relative_path(root, &path)?;
+                    let file_type = entry.file_type().await?;
+                    if file_type.is_dir() {
+                        entries.push(WorkerFilesArchiveEntry::Directory { path: relative });
+                        pending.push(path);
+                    } else if file_type.is_file() && !read_only_paths.contains(&path) {
+                        let metadata = entry.metadata().await?;
+                        let size = metadata.len();
+                        let modified_at = metadata
+                            .modified()
+                            .ok()
+                            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                            .map(|modified| modified.as_nanos());
+
+                        let unchanged = match previous_files.get(relative.as_str()) {
+                            Some(WorkerFilesArchiveEntry::File {
+                                hash,
+                                size: previous_size,
+                                modified_at: previous_modified_at,
+                                ..
+                            }) if modified_at.is_some()
+                                && *previous_size == size
+                                && *previous_modified_at == modified_at =>
+                            {
+                                Some(hash.clone())
+                            }
+                            _ => None,
+                        };
+
+                        let hash = match unchanged {
+                            Some(hash) => hash,
+                            None => {
+                                let content = Bytes::from(tokio::fs::read(&path).await?);
+                                let hash = content.content_hash().await?;
+                                if stored.insert(hash.clone()) {
+                                    blob_storage
+                                        .put_raw(
+                                            namespace.clone(),
+                                            &Self::file_path(&hash),
+                                            &content,
+                                        )
+                                        .await
+                                        .map_err(|e| anyhow!(e))?;
+                                }
+                                hash
+                            }
+                        };
+
+                        entries.push(WorkerFilesArchiveEntry::File {
+                            path: relative,
+                            hash,
+                            size,
+                            modified_at,
+                        });
+                    }
+                }
+            }
+
+            entries.sort_by(|a, b| a.path().cmp(b.path()));
+            Ok::<_, anyhow::Error>(entries)
+        };
+
+        let entries = collect.await.map_err(|e| {
+            WorkerExecutorError::runtime(format!("Failed to archive worker files: {e}"))
+        })?;
+
+        debug!(
+            "Collected {} worker file system entries of {} at oplog index {}",
+            entries.len(),
+            owned_worker_id.worker_id,
+            oplog_index
+        );
+
+        Ok(WorkerFilesArchive {
+            oplog_index,
+            entries,
+        })
+    }
+
+    async fn restore_archive(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        archive: &WorkerFilesArchive,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<(), WorkerExecutorError> {
+        debug!(
+            "Restoring {} worker file system entries of {} archived at oplog index {}",
+            archive.entries.len(),
+            owned_worker_id.worker_id,
+            archive.oplog_index
+        );
+
+        let blob_storage = self
+            .blob_storage
+            .with("file_loader", "restore_worker_files");
+        let namespace = Self::worker_files_namespace(owned_worker_id);
+
+        let restore = async {
+            for entry in &archive.entries {
+                let path = root.join(entry.path());
+                if !path.starts_with(root) || entry.path().split('/').any(|part| part == "..") {
+                    return Err(anyhow!("Invalid path in archive: {}", entry.path()));
+                }
+
+                match entry {
+                    WorkerFilesArchiveEntry::Directory { .. } => {
+                        tokio::fs::create_dir_all(&path).await?;
+                    }
+                    WorkerFilesArchiveEntry::File { hash, .. } => {
+                        if read_only_paths.contains(&path) {
+                            debug!("Not restoring read-only file {}", path.display());
+                            continue;
+                        }
+                        let content = blob_storage
+                            .get_raw(namespace.clone(), &Self::file_path(hash))
+                            .await
+                            .map_err(|e| anyhow!(e))?
+                            .ok_or_else(|| {
+                                anyhow!("Missing content of archived file {}", entry.path())
+                            })?;
+                        if let Some(parent) = path.parent() {
+                            tokio::fs::create_dir_all(parent).await?;
+                        }
+                        tokio::fs::write(&path, content).await?;
+                    }
+                }
+            }
+            archive.remove_unarchived(root, read_only_paths).await
+        };
+
+        restore.await.map_err(|e| {
+            WorkerExecutorError::runtime(format!("Failed to restore worker files: {e}"))
+        })
+    }
+
+    /// Removes all the invocation archives of a worker except `current`, and the archived files
+    /// which are no longer referred to by any of the remaining archives
+    async fn prune_invocation_archives(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        current: &WorkerFilesArchive,
+        previous: Option<&WorkerFilesArchive>,
+    ) -> Result<(), WorkerExecutorError> {
+        let mut stale_archives = Vec::new();
+        let mut stale_files = HashSet::new();
+        match previous {
+            Some(previous) => {
+                if previous.oplog_index != current.oplog_index {
+                    stale_archives.push(previous.oplog_index);
+                    stale_files.extend(previous.hashes());
+                }
+            }
+            None => {
+                // Not knowing the previous archive, for example right after a recovery
+                for oplog_index in self
+                    .archives(owned_worker_id, ArchiveKind::Invocation)
+                    .await?
+                {
+                    if oplog_index != current.oplog_index {
+                        if let Some(archive) = self
+                            .load_archive(owned_worker_id, ArchiveKind::Invocation, oplog_index)
+                            .await?
+                        {
+                            stale_files.extend(archive.hashes());
+                        }
+                        stale_archives.push(oplog_index);
+                    }
+                }
+            }
+        }
+
+        if stale_archives.is_empty() {
+            return Ok(());
+        }
+
+        for hash in current.hashes() {
+            stale_files.remove(&hash);
+        }
+        if !stale_files.is_empty() {
+            for oplog_index in self.archives(owned_worker_id, ArchiveKind::Update).await? {
+                if let Some(archive) = self
+                    .load_archive(owned_worker_id, ArchiveKind::Update, oplog_index)
+                    .await?
+                {
+                    for hash in archive.hashes() {
+                        stale_files.remove(&hash);
+                    }
+                }
+            }
+        }
+
+        let paths = stale_archives
+            .into_iter()
+            .map(|oplog_index| Self::archive_path(ArchiveKind::Invocation, oplog_index))
+            .chain(stale_files.iter().map(|hash| Self::file_path(hash)))
+            .collect::<Vec<_>>();
+        self.blob_storage
+            .with("file_loader", "prune_worker_files")
+            .delete_many(Self::worker_files_namespace(owned_worker_id), &paths)
+            .await
+            .map_err(|e| {
+                WorkerExecutorError::runtime(format!("Failed to delete worker files: {e}"))
+            })
+    }
+
+    async fn store_archive(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        kind: ArchiveKind,
+        archive: &WorkerFilesArchive,
+    ) -> Result<(), WorkerExecutorError> {
+        self.blob_storage
+            .with("file_loader", "store_worker_files_archive")
+            .put(
+                Self::worker_files_namespace(owned_worker_id),
+                &Self::archive_path(kind, archive.oplog_index),
+                archive,
+            )
+            .await
+            .map_err(|e| {
+                WorkerExecutorError::runtime(format!("Failed to store worker files archive: {e}"))
+            })
+    }
+
+    async fn load_archive(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        kind: ArchiveKind,
+        oplog_index: OplogIndex,
+    ) -> Result<Option<WorkerFilesArchive>, WorkerExecutorError> {
+        self.blob_storage
+            .with("file_loader", "load_worker_files_archive")
+            .get(
+                Self::worker_files_namespace(owned_worker_id),
+                &Self::archive_path(kind, oplog_index),
+            )
+            .await
+            .map_err(|e| {
+                WorkerExecutorError::runtime(format!("Failed to read worker files archive: {e}"))
+            })
+    }
+
+    async fn archives(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        kind: ArchiveKind,
+    ) -> Result<Vec<OplogIndex>, WorkerExecutorError> {
+        let blob_storage = self.blob_storage.with("file_loader", "list_archives");
+        let namespace = Self::worker_files_namespace(owned_worker_id);
+        let dir = Path::new(kind.dir());
+        let exists = blob_storage
+            .exists(namespace.clone(), dir)
+            .await
+            .map_err(|e| {
+                WorkerExecutorError::runtime(format!("Failed to check worker files archives: {e}"))
+            })?;
+        if exists != ExistsResult::Directory {
+            return Ok(Vec::new());
+        }
+
+        let paths = blob_storage.list_dir(namespace, dir).await.map_err(|e| {
+            WorkerExecutorError::runtime(format!("Failed to list worker files archives: {e}"))
+        })?;
+
+        Ok(paths
+            .iter()
+            .filter_map(|path| path.file_name()?.to_str()?.parse::<u64>().ok())
+            .map(OplogIndex::from_u64)
+            .collect())
+    }
+
+    fn worker_files_namespace(owned_worker_id: &OwnedWorkerId) -> BlobStorageNamespace {
+        BlobStorageNamespace::WorkerFiles {
+            project_id: owned_worker_id.project_id(),
+            worker_id: owned_worker_id.worker_id(),
+        }
+    }
+
+    fn archive_path(kind: ArchiveKind, oplog_index: OplogIndex) -> PathBuf {
+        Path::new(kind.dir()).join(oplog_index.to_string())
+    }
+
+    fn file_path(hash: &str) -> PathBuf {
+        Path::new(FILES_DIR).join(hash)
+    }
+
     async fn get_read_only_to_impl(
         &self,
         project_id: &ProjectId,
@@ -285,6 +825,139 @@ impl FileLoader {
     }
 }
 
+const ARCHIVES_DIR: &str = "archives";
+const UPDATES_DIR: &str = "updates";
+const FILES_DIR: &str = "files";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    /// Taken after a successful invocation, only the latest one is kept
+    Invocation,
+    /// Taken before a snapshot-based update, kept until the worker is deleted
+    Update,
+}
+
+impl ArchiveKind {
+    fn dir(&self) -> &'static str {
+        match self {
+            ArchiveKind::Invocation => ARCHIVES_DIR,
+            ArchiveKind::Update => UPDATES_DIR,
+        }
+    }
+}
+
+/// Manifest of the writable part of a worker's filesystem at a given oplog index.
+/// The contents of the files are stored separately, addressed by their hash.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct WorkerFilesArchive {
+    oplog_index: OplogIndex,
+    entries: Vec<WorkerFilesArchiveEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+enum WorkerFilesArchiveEntry {
+    Directory {
+        path: String,
+    },
+    File {
+        path: String,
+        hash: String,
+        size: u64,
+        modified_at: Option<u128>,
+    },
+}
+
+impl WorkerFilesArchive {
+    /// The total size of the archived files
+    pub fn size(&self) -> u64 {
+        self.entries
+            .iter()
+            .map(|entry| match entry {
+                WorkerFilesArchiveEntry::File { size, .. } => *size,
+                WorkerFilesArchiveEntry::Directory { .. } => 0,
+            })
+            .sum()
+    }
+
+    fn files(&self) -> HashMap<&str, &WorkerFilesArchiveEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry, WorkerFilesArchiveEntry::File { .. }))
+            .map(|entry| (entry.path(), entry))
+            .collect()
+    }
+
+    fn hashes(&self) -> HashSet<String> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                WorkerFilesArchiveEntry::File { hash, .. } => Some(hash.clone()),
+                WorkerFilesArchiveEntry::Directory { .. } => None,
+            })
+            .collect()
+    }
+
+    /// Removes the writable files and the directories which are not part of the archive
+    async fn remove_unarchived(
+        &self,
+        root: &Path,
+        read_only_paths: &HashSet<PathBuf>,
+    ) -> Result<(), anyhow::Error> {
+        let archived = self
+            .entries
+            .iter()
+            .map(|entry| entry.path())
+            .collect::<HashSet<_>>();
+        let mut directories = Vec::new();
+        let mut pending = vec![root.to_path_buf()];
+
+        while let Some(dir) = pending.pop() {
+            let mut read_dir = tokio::fs::read_dir(&dir).await?;
+            while let Some(entry) = read_dir.next_entry().await? {
+                let path = entry.path();
+                let relative = Self::relative_path(root, &path)?;
+                if entry.file_type().await?.is_dir() {
+                    pending.push(path.clone());
+                    if !archived.contains(relative.as_str()) {
+                        directories.push(path);
+                    }
+                } else if !archived.contains(relative.as_str()) && !read_only_paths.contains(&path)
+                {
+                    tokio::fs::remove_file(&path).await?;
+                }
+            }
+        }
+
+        // Directories are visited before their children. The ones still containing read-only files are kept.
+        for dir in directories.into_iter().rev() {
+            let _ = tokio::fs::remove_dir(&dir).await;
+        }
+        Ok(())
+    }
+
+    fn relative_path(root: &Path, path: &Path) -> Result<String, anyhow::Error> {
+        let relative = path.strip_prefix(root)?;
+        let parts = relative
+            .components()
+            .map(|c| {
+                c.as_os_str()
+                    .to_str()
+                    .ok_or_else(|| anyhow!("Non UTF-8 path: {}", path.display()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(parts.join("/"))
+    }
+}
+
+impl WorkerFilesArchiveEntry {
+    fn path(&self) -> &str {
+        match self {
+            WorkerFilesArchiveEntry::Directory { path } => path,
+            WorkerFilesArchiveEntry::File { path, .. } => path,
+        }
+    }
+}
+
 // Scary type, let's break it down:
 // Outer Mutex: This is the lock that protects the cache from concurrent access.
 // HashMap: The cache itself, mapping keys to weak references to the cache entries.
@@ -309,3 +982,359 @@ impl Drop for InitializedCacheEntry {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ArchiveKind, FileLoader, FILES_DIR};
+    use assert2::check;
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::{ComponentId, OwnedWorkerId, ProjectId, WorkerId};
+    use golem_service_base::service::initial_component_files::InitialComponentFilesService;
+    use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
+    use std::collections::HashSet;
+    use std::path::Path;
+    use std::sync::Arc;
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn file_loader() -> FileLoader {
+        let blob_storage = Arc::new(InMemoryBlobStorage::new());
+        FileLoader::new(
+            Arc::new(InitialComponentFilesService::new(blob_storage.clone())),
+            blob_storage,
+        )
+        .unwrap()
+    }
+
+    fn owned_worker_id(name: &str) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            &ProjectId::new_v4(),
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: name.to_string(),
+            },
+        )
+    }
+
+    async fn stored_files(file_loader: &FileLoader, worker: &OwnedWorkerId) -> usize {
+        file_loader
+            .blob_storage
+            .with("test", "stored_files")
+            .list_dir(
+                FileLoader::worker_files_namespace(worker),
+                Path::new(FILES_DIR),
+            )
+            .await
+            .unwrap()
+            .len()
+    }
+
+    #[test]
+    async fn archived_files_are_restored() {
+        let file_loader = file_loader();
+        let worker = owned_worker_id("worker");
+        let oplog_index = OplogIndex::from_u64(10);
+
+        let source = tempfile::tempdir().unwrap();
+        tokio::fs::create_dir_all(source.path().join("data/empty"))
+            .await
+            .unwrap();
+        tokio::fs::write(source.path().join("data/state.json"), b"{}")
+            .await
+            .unwrap();
+        tokio::fs::write(source.path().join("initial.txt"), b"read-only")
+            .await
+            .unwrap();
+        let read_only_paths = HashSet::from([source.path().join("initial.txt")]);
+
+        let archive = file_loader
+            .archive_worker_files(&worker, oplog_index, source.path(), &read_only_paths, None)
+            .await
+            .unwrap();
+        check!(archive.is_some());
+
+        let target = tempfile::tempdir().unwrap();
+        tokio::fs::create_dir_all(target.path().join("stale"))
+            .await
+            .unwrap();
+        tokio::fs::write(target.path().join("stale/file.txt"), b"stale")
+            .await
+            .unwrap();
+        tokio::fs::write(target.path().join("initial.txt"), b"read-only")
+            .await
+            .unwrap();
+        let target_read_only_paths = HashSet::from([target.path().join("initial.txt")]);
+
+        let restored = file_loader
+            .restore_worker_files(&worker, oplog_index, target.path(), &target_read_only_paths)
+            .await
+            .unwrap();
+
+        check!(restored == archive);
+        check!(
+            tokio::fs::read(target.path().join("data/state.json"))
+                .await
+                .unwrap()
+                == b"{}"
+        );
+        check!(target.path().join("data/empty").is_dir());
+        check!(!target.path().join("stale").exists());
+        check!(target.path().join("initial.txt").exists());
+    }
+
+    #[test]
+    async fn archived_size_is_the_size_of_the_latest_archive() {
+        let file_loader = file_loader();
+        let worker = owned_worker_id("worker");
+        check!(file_loader.archived_size(&worker).await.unwrap() == 0);
+
+        let source = tempfile::tempdir().unwrap();
+        tokio::fs::write(source.path().join("a.txt"), b"aaa")
+            .await
+            .unwrap();
+        let first = file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(10),
+                source.path(),
+                &HashSet::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        tokio::fs::write(source.path().join("b.txt"), b"bb")
+            .await
+            .unwrap();
+        file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(20),
+                source.path(),
+                &HashSet::new(),
+                first.as_ref(),
+            )
+            .await
+            .unwrap();
+
+        check!(file_loader.archived_size(&worker).await.unwrap() == 5);
+    }
+
+    #[test]
+    async fn only_the_archive_of_the_given_invocation_is_restored() {
+        let file_loader = file_loader();
+        let worker = owned_worker_id("worker");
+
+        let source = tempfile::tempdir().unwrap();
+        tokio::fs::write(source.path().join("a.txt"), b"a")
+            .await
+            .unwrap();
+        file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(10),
+                source.path(),
+                &HashSet::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let target = tempfile::tempdir().unwrap();
+        let restored = file_loader
+            .restore_worker_files(
+                &worker,
+                OplogIndex::from_u64(20),
+                target.path(),
+                &HashSet::new(),
+            )
+            .await
+            .unwrap();
+
+        check!(restored.is_none());
+        check!(!target.path().join("a.txt").exists());
+    }
+
+    #[test]
+    async fn nothing_is_archived_without_written_files() {
+        let file_loader = file_loader();
+        let worker = owned_worker_id("worker");
+
+        let source = tempfile::tempdir().unwrap();
+        let archive = file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(10),
+                source.path(),
+                &HashSet::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        check!(archive.is_none());
+        check!(file_loader
+            .archives(&worker, ArchiveKind::Invocation)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    async fn only_changed_files_are_stored_and_previous_archives_are_pruned() {
+        let file_loader = file_loader();
+        let worker = owned_worker_id("worker");
+
+        let source = tempfile::tempdir().unwrap();
+        tokio::fs::write(source.path().join("a.txt"), b"a")
+            .await
+            .unwrap();
+        tokio::fs::write(source.path().join("b.txt"), b"b")
+            .await
+            .unwrap();
+        let first = file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(10),
+                source.path(),
+                &HashSet::new(),
+                None,
+            )
+            .await
+            .unwrap();
+        check!(stored_files(&file_loader, &worker).await == 2);
+
+        tokio::fs::write(source.path().join("b.txt"), b"changed")
+            .await
+            .unwrap();
+        let second = file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(20),
+                source.path(),
+                &HashSet::new(),
+                first.as_ref(),
+            )
+            .await
+            .unwrap();
+
+        check!(
+            file_loader
+                .archives(&worker, ArchiveKind::Invocation)
+                .await
+                .unwrap()
+                == vec![OplogIndex::from_u64(20)]
+        );
+        check!(stored_files(&file_loader, &worker).await == 2);
+
+        let target = tempfile::tempdir().unwrap();
+        let restored = file_loader
+            .restore_worker_files(
+                &worker,
+                OplogIndex::from_u64(20),
+                target.path(),
+                &HashSet::new(),
+            )
+            .await
+            .unwrap();
+        check!(restored == second);
+        check!(tokio::fs::read(target.path().join("b.txt")).await.unwrap() == b"changed");
+    }
+
+    #[test]
+    async fn files_of_update_archives_are_kept_when_pruning() {
+        let file_loader = file_loader();
+        let worker = owned_worker_id("worker");
+        let update_index = OplogIndex::from_u64(5);
+
+        let source = tempfile::tempdir().unwrap();
+        tokio::fs::write(source.path().join("a.txt"), b"a")
+            .await
+            .unwrap();
+        file_loader
+            .archive_worker_files_for_update(&worker, update_index, source.path(), &HashSet::new())
+            .await
+            .unwrap();
+        let first = file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(10),
+                source.path(),
+                &HashSet::new(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        tokio::fs::remove_file(source.path().join("a.txt"))
+            .await
+            .unwrap();
+        file_loader
+            .archive_worker_files(
+                &worker,
+                OplogIndex::from_u64(20),
+                source.path(),
+                &HashSet::new(),
+                first.as_ref(),
+            )
+            .await
+            .unwrap();
+
+        let target = tempfile::tempdir().unwrap();
+        let restored = file_loader
+            .restore_worker_files_for_update(&worker, update_index, target.path(), &HashSet::new())
+            .await
+            .unwrap();
+
+        check!(restored);
+        check!(tokio::fs::read(target.path().join("a.txt")).await.unwrap() == b"a");
+    }
+
+    #[test]
+    async fn archives_are_copied_up_to_the_cut_off_and_deleted() {
+        let file_loader = file_loader();
+        let source_worker = owned_worker_id("source");
+        let target_worker = owned_worker_id("target");
+
+        let source = tempfile::tempdir().unwrap();
+        tokio::fs::write(source.path().join("a.txt"), b"a")
+            .await
+            .unwrap();
+        for idx in [10, 20] {
+            file_loader
+                .archive_worker_files_for_update(
+                    &source_worker,
+                    OplogIndex::from_u64(idx),
+                    source.path(),
+                    &HashSet::new(),
+                )
+                .await
+                .unwrap();
+        }
+
+        file_loader
+            .copy_worker_files(&source_worker, &target_worker, OplogIndex::from_u64(15))
+            .await
+            .unwrap();
+
+        check!(
+            file_loader
+                .archives(&target_worker, ArchiveKind::Update)
+                .await
+                .unwrap()
+                == vec![OplogIndex::from_u64(10)]
+        );
+        check!(stored_files(&file_loader, &target_worker).await == 1);
+
+        file_loader
+            .delete_worker_files(&source_worker)
+            .await
+            .unwrap();
+        check!(file_loader
+            .archives(&source_worker, ArchiveKind::Update)
+            .await
+            .unwrap()
+            .is_empty());
+        check!(stored_files(&file_loader, &source_worker).await == 0);
+    }
+}
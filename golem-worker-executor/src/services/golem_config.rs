@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -49,6 +50,7 @@ pub struct GolemConfig {
     pub public_worker_api: WorkerServiceGrpcConfig,
     pub memory: MemoryConfig,
     pub rdbms: RdbmsConfig,
+    pub outgoing_http: OutgoingHttpConfig,
     pub resource_limits: ResourceLimitsConfig,
     pub component_service: ComponentServiceConfig,
     pub component_cache: ComponentCacheConfig,
@@ -64,6 +66,7 @@ pub struct Limits {
     pub max_active_workers: usize,
     pub invocation_result_broadcast_capacity: usize,
     pub max_concurrent_streams: u32,
+    pub max_file_system_size_per_worker: u64,
     pub event_broadcast_capacity: usize,
     pub event_history_size: usize,
     pub fuel_to_borrow: i64,
@@ -222,6 +225,9 @@ pub struct ActiveWorkersConfig {
 pub struct SchedulerConfig {
     #[serde(with = "humantime_serde")]
     pub refresh_interval: Duration,
+    /// How long the audit log keeps the workers deleted by their time-to-live policy
+    #[serde(with = "humantime_serde")]
+    pub ttl_deletion_log_retention: Duration,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -300,6 +306,18 @@ impl MemoryConfig {
 pub struct RdbmsConfig {
     pub pool: RdbmsPoolConfig,
     pub query: RdbmsQueryConfig,
+    pub transaction: RdbmsTransactionConfig,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct RdbmsTransactionConfig {
+    /// Writes a marker row as part of every committed transaction, so that after a crash the
+    /// executor can find out whether a commit which is missing from the oplog reached the database
+    pub commit_markers: bool,
+    /// How long commit markers are kept. Must be longer than a crashed worker may wait for its
+    /// recovery, as the marker of an interrupted transaction is needed until then.
+    #[serde(with = "humantime_serde")]
+    pub commit_marker_retention: Duration,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -316,6 +334,90 @@ pub struct RdbmsPoolConfig {
     pub eviction_period: Duration,
 }
 
+/// Host level policies applied to the outgoing HTTP requests of workers
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OutgoingHttpConfig {
+    /// Policy of the hosts not listed in `hosts`
+    pub default: OutgoingHttpPolicy,
+    /// Policies of specific hosts. A key is either a host name, or a `*.` prefixed domain matching
+    /// all of its subdomains. Keys are matched ignoring case.
+    pub hosts: HashMap<String, OutgoingHttpPolicy>,
+    /// When set, outgoing requests are not sent but answered from the recorded responses of
+    /// this HTTP fixture file
+    pub fixtures: Option<PathBuf>,
+}
+
+impl OutgoingHttpConfig {
+    pub fn policy(&self, host: &str) -> &OutgoingHttpPolicy {
+        let host = host.to_lowercase();
+        self.host_policy(&host)
+            .or_else(|| {
+                host.match_indices('.')
+                    .find_map(|(idx, _)| self.host_policy(&format!("*{}", &host[idx..])))
+            })
+            .unwrap_or(&self.default)
+    }
+
+    fn host_policy(&self, key: &str) -> Option<&OutgoingHttpPolicy> {
+        self.hosts
+            .iter()
+            .find_map(|(host, policy)| host.eq_ignore_ascii_case(key).then_some(policy))
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct OutgoingHttpPolicy {
+    /// Retries of idempotent requests failing with a transient error
+    pub retries: Option<OutgoingHttpRetryConfig>,
+    /// Upper limits of the timeouts requested by the workers
+    pub timeouts: OutgoingHttpTimeoutConfig,
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OutgoingHttpRetryConfig {
+    pub backoff: RetryConfig,
+    /// Response status codes which are retried the same way as connection failures
+    pub retry_on_status: Vec<u16>,
+}
+
+impl Default for OutgoingHttpRetryConfig {
+    fn default() -> Self {
+        Self {
+            backoff: RetryConfig::default(),
+            retry_on_status: vec![502, 503, 504],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct OutgoingHttpTimeoutConfig {
+    #[serde(default, with = "humantime_serde")]
+    pub connect_timeout: Option<Duration>,
+    #[serde(default, with = "humantime_serde")]
+    pub first_byte_timeout: Option<Duration>,
+    #[serde(default, with = "humantime_serde")]
+    pub between_bytes_timeout: Option<Duration>,
+}
+
+/// Stops sending requests to a host for `open_duration` after `failure_threshold` consecutive
+/// requests failed, then lets a single request through to find out if the host has recovered
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: u32,
+    #[serde(with = "humantime_serde")]
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(tag = "type", content = "config")]
 pub enum ResourceLimitsConfig {
@@ -445,6 +547,7 @@ impl Default for GolemConfig {
             public_worker_api: WorkerServiceGrpcConfig::default(),
             memory: MemoryConfig::default(),
             rdbms: RdbmsConfig::default(),
+            outgoing_http: OutgoingHttpConfig::default(),
             resource_limits: ResourceLimitsConfig::default(),
             component_service: ComponentServiceConfig::default(),
             component_cache: ComponentCacheConfig::default(),
@@ -497,6 +600,7 @@ impl Default for Limits {
             max_active_workers: 1024,
             invocation_result_broadcast_capacity: 100000,
             max_concurrent_streams: 1024,
+            max_file_system_size_per_worker: 1024 * 1024 * 1024,
             event_broadcast_capacity: 1024,
             event_history_size: 128,
             fuel_to_borrow: 10000,
@@ -593,6 +697,7 @@ impl Default for SchedulerConfig {
     fn default() -> Self {
         Self {
             refresh_interval: Duration::from_secs(2),
+            ttl_deletion_log_retention: Duration::from_secs(30 * 24 * 60 * 60),
         }
     }
 }
@@ -651,6 +756,15 @@ impl Default for RdbmsQueryConfig {
     }
 }
 
+impl Default for RdbmsTransactionConfig {
+    fn default() -> Self {
+        Self {
+            commit_markers: true,
+            commit_marker_retention: Duration::from_secs(7 * 24 * 60 * 60),
+        }
+    }
+}
+
 impl Default for RdbmsPoolConfig {
     fn default() -> Self {
         Self {
@@ -13,15 +13,18 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use bincode::{Decode, Encode};
 use bytes::Bytes;
 
-use golem_common::model::ProjectId;
+use golem_common::cache::{BackgroundEvictionMode, Cache, FullCacheEvictionMode, SimpleCache};
+use golem_common::model::{ProjectId, Timestamp};
 
 use crate::storage::keyvalue::{
-    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+    KeyPage, KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
 };
 
 /// Service implementing a persistent key-value store
@@ -57,6 +60,16 @@ pub trait KeyValueService: Send + Sync {
 
     async fn get_keys(&self, project_id: ProjectId, bucket: String) -> anyhow::Result<Vec<String>>;
 
+    /// Lists a page of the bucket's keys, see [`KeyValueStorage::list_keys`]
+    async fn list_keys(
+        &self,
+        project_id: ProjectId,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> anyhow::Result<KeyPage>;
+
     async fn get_many(
         &self,
         project_id: ProjectId,
@@ -64,12 +77,14 @@ pub trait KeyValueService: Send + Sync {
         keys: Vec<String>,
     ) -> anyhow::Result<Vec<Option<Vec<u8>>>>;
 
+    /// Sets the value of the key, optionally expiring it after `ttl`
     async fn set(
         &self,
         project_id: ProjectId,
         bucket: String,
         key: String,
         outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()>;
 
     async fn set_many(
@@ -78,16 +93,130 @@ pub trait KeyValueService: Send + Sync {
         bucket: String,
         key_values: Vec<(String, Vec<u8>)>,
     ) -> anyhow::Result<()>;
+
+    async fn increment(
+        &self,
+        project_id: ProjectId,
+        bucket: String,
+        key: String,
+        delta: u64,
+    ) -> anyhow::Result<u64>;
+
+    async fn compare_and_swap(
+        &self,
+        project_id: ProjectId,
+        bucket: String,
+        key: String,
+        old: u64,
+        new: u64,
+    ) -> anyhow::Result<bool>;
+
+    /// Gets a value from the project's `wasi:keyvalue/cache`, returning `None` if it is missing or expired
+    async fn cache_get(
+        &self,
+        project_id: ProjectId,
+        key: String,
+    ) -> anyhow::Result<Option<Vec<u8>>>;
+
+    /// Sets a value in the project's `wasi:keyvalue/cache`, optionally expiring after `ttl`
+    async fn cache_set(
+        &self,
+        project_id: ProjectId,
+        key: String,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()>;
+
+    async fn cache_delete(&self, project_id: ProjectId, key: String) -> anyhow::Result<()>;
 }
 
+/// An entry of the `wasi:keyvalue/cache`, stored both in the local cache and in the key-value storage
+#[derive(Clone, Debug, Encode, Decode)]
+struct CachedValue {
+    value: Vec<u8>,
+    /// Expiration time in milliseconds since the epoch
+    expires_at: Option<u64>,
+}
+
+impl CachedValue {
+    fn new(value: Vec<u8>, ttl: Option<Duration>) -> Self {
+        Self {
+            value,
+            expires_at: ttl.map(|ttl| Timestamp::now_utc().to_millis() + ttl.as_millis() as u64),
+        }
+    }
+
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// A `wasi:keyvalue/cache` entry cached in the executor's memory
 #[derive(Clone, Debug)]
+struct LocallyCachedValue {
+    cached: CachedValue,
+    /// Time in milliseconds since the epoch after which the entry is reloaded from the storage
+    valid_until: u64,
+}
+
+/// Maximum number of `wasi:keyvalue/cache` entries kept in the executor's memory
+const LOCAL_CACHE_CAPACITY: usize = 10000;
+
+/// Maximum time a locally cached entry is used without reloading it from the storage.
+/// Entries may be changed or deleted by workers running on other executors, so this
+/// bounds how long such changes remain invisible.
+const LOCAL_CACHE_MAX_AGE: Duration = Duration::from_secs(5);
+
+#[derive(Clone)]
 pub struct DefaultKeyValueService {
     key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+    local_cache: Cache<(ProjectId, String), (), LocallyCachedValue, ()>,
+    local_cache_max_age: Duration,
 }
 
 impl DefaultKeyValueService {
     pub fn new(key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>) -> Self {
-        Self { key_value_storage }
+        Self::with_local_cache(key_value_storage, LOCAL_CACHE_CAPACITY, LOCAL_CACHE_MAX_AGE)
+    }
+
+    pub fn with_local_cache(
+        key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+        local_cache_capacity: usize,
+        local_cache_max_age: Duration,
+    ) -> Self {
+        Self {
+            key_value_storage,
+            local_cache: Cache::new(
+                Some(local_cache_capacity),
+                FullCacheEvictionMode::LeastRecentlyUsed(1),
+                BackgroundEvictionMode::OlderThan {
+                    ttl: local_cache_max_age,
+                    period: Duration::from_secs(60),
+                },
+                "key_value_cache",
+            ),
+            local_cache_max_age,
+        }
+    }
+
+    async fn cache_locally(&self, project_id: ProjectId, key: String, cached: CachedValue) {
+        let now = Timestamp::now_utc().to_millis();
+        let max_valid_until = now + self.local_cache_max_age.as_millis() as u64;
+        let valid_until = cached.expires_at.map_or(max_valid_until, |expires_at| {
+            expires_at.min(max_valid_until)
+        });
+
+        let local_key = (project_id, key);
+        self.local_cache.remove(&local_key);
+        let _ = self
+            .local_cache
+            .get_or_insert_simple(&local_key, async || {
+                Ok(LocallyCachedValue {
+                    cached,
+                    valid_until,
+                })
+            })
+            .await;
     }
 }
 
@@ -174,6 +303,28 @@ impl KeyValueService for DefaultKeyValueService {
         Ok(keys)
     }
 
+    async fn list_keys(
+        &self,
+        project_id: ProjectId,
+        bucket: String,
+        prefix: Option<String>,
+        cursor: Option<String>,
+        limit: u64,
+    ) -> anyhow::Result<KeyPage> {
+        let page = self
+            .key_value_storage
+            .with("key_value", "list_keys")
+            .list_keys(
+                KeyValueStorageNamespace::UserDefined { project_id, bucket },
+                prefix.as_deref(),
+                cursor.as_deref(),
+                limit,
+            )
+            .await
+            .map_err(|err| anyhow!(err))?;
+        Ok(page)
+    }
+
     async fn get_many(
         &self,
         project_id: ProjectId,
@@ -201,16 +352,22 @@ impl KeyValueService for DefaultKeyValueService {
         bucket: String,
         key: String,
         outgoing_value: Vec<u8>,
+        ttl: Option<Duration>,
     ) -> anyhow::Result<()> {
-        self.key_value_storage
-            .with_entity("key_value", "set", "custom")
-            .set_raw(
-                KeyValueStorageNamespace::UserDefined { project_id, bucket },
-                &key,
-                &outgoing_value,
-            )
-            .await
-            .map_err(|err| anyhow!(err))?;
+        let storage = self
+            .key_value_storage
+            .with_entity("key_value", "set", "custom");
+        let namespace = KeyValueStorageNamespace::UserDefined { project_id, bucket };
+        match ttl {
+            Some(ttl) => storage
+                .set_raw_with_ttl(namespace, &key, &outgoing_value, ttl)
+                .await
+                .map_err(|err| anyhow!(err))?,
+            None => storage
+                .set_raw(namespace, &key, &outgoing_value)
+                .await
+                .map_err(|err| anyhow!(err))?,
+        }
         Ok(())
     }
 
@@ -234,4 +391,235 @@ impl KeyValueService for DefaultKeyValueService {
             .map_err(|err| anyhow!(err))?;
         Ok(())
     }
+
+    async fn increment(
+        &self,
+        project_id: ProjectId,
+        bucket: String,
+        key: String,
+        delta: u64,
+    ) -> anyhow::Result<u64> {
+        let value = self
+            .key_value_storage
+            .with("key_value", "increment")
+            .increment(
+                KeyValueStorageNamespace::UserDefined { project_id, bucket },
+                &key,
+                delta,
+            )
+            .await
+            .map_err(|err| anyhow!(err))?;
+        Ok(value)
+    }
+
+    async fn compare_and_swap(
+        &self,
+        project_id: ProjectId,
+        bucket: String,
+        key: String,
+        old: u64,
+        new: u64,
+    ) -> anyhow::Result<bool> {
+        let swapped = self
+            .key_value_storage
+            .with("key_value", "compare_and_swap")
+            .compare_and_swap(
+                KeyValueStorageNamespace::UserDefined { project_id, bucket },
+                &key,
+                old,
+                new,
+            )
+            .await
+            .map_err(|err| anyhow!(err))?;
+        Ok(swapped)
+    }
+
+    async fn cache_get(
+        &self,
+        project_id: ProjectId,
+        key: String,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let now = Timestamp::now_utc().to_millis();
+        let local_key = (project_id.clone(), key);
+
+        if let Some(local) = self.local_cache.try_get(&local_key) {
+            if local.valid_until > now {
+                return Ok(Some(local.cached.value));
+            }
+            self.local_cache.remove(&local_key);
+        }
+
+        let (project_id, key) = local_key;
+        let cached: Option<CachedValue> = self
+            .key_value_storage
+            .with_entity("key_value", "cache_get", "cached_value")
+            .get(
+                KeyValueStorageNamespace::Cache {
+                    project_id: project_id.clone(),
+                },
+                &key,
+            )
+            .await
+            .map_err(|err| anyhow!(err))?;
+
+        match cached {
+            Some(cached) if !cached.is_expired(now) => {
+                let value = cached.value.clone();
+                self.cache_locally(project_id, key, cached).await;
+                Ok(Some(value))
+            }
+            Some(_) => {
+                self.key_value_storage
+                    .with("key_value", "cache_get")
+                    .del(KeyValueStorageNamespace::Cache { project_id }, &key)
+                    .await
+                    .map_err(|err| anyhow!(err))?;
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn cache_set(
+        &self,
+        project_id: ProjectId,
+        key: String,
+        value: Vec<u8>,
+        ttl: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        let cached = CachedValue::new(value, ttl);
+        let storage = self
+            .key_value_storage
+            .with_entity("key_value", "cache_set", "cached_value");
+        let namespace = KeyValueStorageNamespace::Cache {
+            project_id: project_id.clone(),
+        };
+        match ttl {
+            Some(ttl) => storage
+                .set_with_ttl(namespace, &key, &cached, ttl)
+                .await
+                .map_err(|err| anyhow!(err))?,
+            None => storage
+                .set(namespace, &key, &cached)
+                .await
+                .map_err(|err| anyhow!(err))?,
+        }
+
+        self.cache_locally(project_id, key, cached).await;
+        Ok(())
+    }
+
+    async fn cache_delete(&self, project_id: ProjectId, key: String) -> anyhow::Result<()> {
+        self.local_cache.remove(&(project_id.clone(), key.clone()));
+        self.key_value_storage
+            .with("key_value", "cache_delete")
+            .del(KeyValueStorageNamespace::Cache { project_id }, &key)
+            .await
+            .map_err(|err| anyhow!(err))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::key_value::{DefaultKeyValueService, KeyValueService};
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use golem_common::model::ProjectId;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use test_r::test;
+
+    #[test]
+    async fn cache_set_and_delete_update_the_local_cache() {
+        let svc = DefaultKeyValueService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let project_id = ProjectId::new_v4();
+
+        svc.cache_set(project_id.clone(), "key".to_string(), vec![1], None)
+            .await
+            .unwrap();
+        svc.cache_set(project_id.clone(), "key".to_string(), vec![2], None)
+            .await
+            .unwrap();
+        let value = svc
+            .cache_get(project_id.clone(), "key".to_string())
+            .await
+            .unwrap();
+        assert_eq!(value, Some(vec![2]));
+
+        svc.cache_delete(project_id.clone(), "key".to_string())
+            .await
+            .unwrap();
+        let value = svc.cache_get(project_id, "key".to_string()).await.unwrap();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    async fn changes_from_other_executors_are_visible_after_max_age() {
+        let storage = Arc::new(InMemoryKeyValueStorage::new());
+        let svc1 = DefaultKeyValueService::with_local_cache(
+            storage.clone(),
+            16,
+            Duration::from_millis(200),
+        );
+        let svc2 =
+            DefaultKeyValueService::with_local_cache(storage, 16, Duration::from_millis(200));
+        let project_id = ProjectId::new_v4();
+
+        svc1.cache_set(project_id.clone(), "key".to_string(), vec![1], None)
+            .await
+            .unwrap();
+        svc2.cache_delete(project_id.clone(), "key".to_string())
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+
+        let value = svc1.cache_get(project_id, "key".to_string()).await.unwrap();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    async fn expired_entries_are_not_returned() {
+        let svc = DefaultKeyValueService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let project_id = ProjectId::new_v4();
+
+        svc.cache_set(
+            project_id.clone(),
+            "key".to_string(),
+            vec![1],
+            Some(Duration::from_millis(100)),
+        )
+        .await
+        .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let value = svc.cache_get(project_id, "key".to_string()).await.unwrap();
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    async fn local_cache_is_bounded() {
+        let svc = DefaultKeyValueService::with_local_cache(
+            Arc::new(InMemoryKeyValueStorage::new()),
+            2,
+            Duration::from_secs(60),
+        );
+        let project_id = ProjectId::new_v4();
+
+        for i in 0..5u8 {
+            svc.cache_set(project_id.clone(), format!("key-{i}"), vec![i], None)
+                .await
+                .unwrap();
+        }
+
+        assert!(svc.local_cache.iter().count() <= 2);
+        for i in 0..5u8 {
+            let value = svc
+                .cache_get(project_id.clone(), format!("key-{i}"))
+                .await
+                .unwrap();
+            assert_eq!(value, Some(vec![i]));
+        }
+    }
 }
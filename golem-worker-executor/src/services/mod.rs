@@ -33,9 +33,11 @@ pub mod oplog;
 pub mod plugins;
 pub mod projects;
 pub mod promise;
+pub mod pubsub;
 pub mod rdbms;
 pub mod resource_limits;
 pub mod rpc;
+pub mod saga;
 pub mod scheduler;
 pub mod shard;
 pub mod shard_manager;
@@ -104,6 +106,14 @@ pub trait HasPromiseService {
     fn promise_service(&self) -> Arc<dyn promise::PromiseService>;
 }
 
+pub trait HasPubSubService {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService>;
+}
+
+pub trait HasSagaService {
+    fn saga_service(&self) -> Arc<dyn saga::SagaService>;
+}
+
 pub trait HasWasmtimeEngine<Ctx> {
     fn engine(&self) -> Arc<wasmtime::Engine>;
     fn linker(&self) -> Arc<wasmtime::component::Linker<Ctx>>;
@@ -188,6 +198,8 @@ pub trait HasAll<Ctx: WorkerCtx>:
     + HasWorkerEnumerationService
     + HasRunningWorkerEnumerationService
     + HasPromiseService
+    + HasPubSubService
+    + HasSagaService
     + HasWasmtimeEngine<Ctx>
     + HasKeyValueService
     + HasBlobStoreService
@@ -221,6 +233,8 @@ impl<
             + HasWorkerEnumerationService
             + HasRunningWorkerEnumerationService
             + HasPromiseService
+            + HasPubSubService
+            + HasSagaService
             + HasWasmtimeEngine<Ctx>
             + HasKeyValueService
             + HasBlobStoreService
@@ -260,6 +274,8 @@ pub struct All<Ctx: WorkerCtx> {
     running_worker_enumeration_service:
         Arc<dyn worker_enumeration::RunningWorkerEnumerationService>,
     promise_service: Arc<dyn promise::PromiseService>,
+    pubsub_service: Arc<dyn pubsub::PubSubService>,
+    saga_service: Arc<dyn saga::SagaService>,
     golem_config: Arc<golem_config::GolemConfig>,
     shard_service: Arc<dyn shard::ShardService>,
     key_value_service: Arc<dyn key_value::KeyValueService>,
@@ -293,6 +309,8 @@ impl<Ctx: WorkerCtx> Clone for All<Ctx> {
             worker_enumeration_service: self.worker_enumeration_service.clone(),
             running_worker_enumeration_service: self.running_worker_enumeration_service.clone(),
             promise_service: self.promise_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            saga_service: self.saga_service.clone(),
             golem_config: self.golem_config.clone(),
             shard_service: self.shard_service.clone(),
             key_value_service: self.key_value_service.clone(),
@@ -330,6 +348,8 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             dyn worker_enumeration::RunningWorkerEnumerationService,
         >,
         promise_service: Arc<dyn promise::PromiseService>,
+        pubsub_service: Arc<dyn pubsub::PubSubService>,
+        saga_service: Arc<dyn saga::SagaService>,
         golem_config: Arc<golem_config::GolemConfig>,
         shard_service: Arc<dyn shard::ShardService>,
         key_value_service: Arc<dyn key_value::KeyValueService>,
@@ -360,6 +380,8 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config,
             shard_service,
             key_value_service,
@@ -393,6 +415,8 @@ impl<Ctx: WorkerCtx> All<Ctx> {
             this.worker_enumeration_service(),
             this.running_worker_enumeration_service(),
             this.promise_service(),
+            this.pubsub_service(),
+            this.saga_service(),
             this.config(),
             this.shard_service(),
             this.key_value_service(),
@@ -490,6 +514,18 @@ impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasPromiseService for T {
     }
 }
 
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasPubSubService for T {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService> {
+        self.all().pubsub_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasSagaService for T {
+    fn saga_service(&self) -> Arc<dyn saga::SagaService> {
+        self.all().saga_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx, T: UsesAllDeps<Ctx = Ctx>> HasWasmtimeEngine<Ctx> for T {
     fn engine(&self) -> Arc<wasmtime::Engine> {
         self.all().engine.clone()
@@ -29,7 +29,7 @@ use async_mutex::Mutex;
 use async_trait::async_trait;
 use bytes::Bytes;
 use golem_common::model::invocation_context::InvocationContextStack;
-use golem_common::model::oplog::{OplogEntry, OplogIndex, OplogPayload};
+use golem_common::model::oplog::{InvocationPriority, OplogEntry, OplogIndex, OplogPayload};
 use golem_common::model::plugin::{
     OplogProcessorDefinition, PluginDefinition, PluginTypeSpecificDefinition,
 };
@@ -261,6 +261,7 @@ impl<Ctx: WorkerCtx> OplogProcessorPlugin for PerExecutorOplogProcessorPlugin<Ct
                 function_name,
                 function_input,
                 InvocationContextStack::fresh(),
+                InvocationPriority::Normal,
             )
             .await?;
 
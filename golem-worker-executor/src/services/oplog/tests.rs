@@ -347,6 +347,28 @@ pub fn rounded(entry: OplogEntry) -> OplogEntry {
             timestamp: rounded_ts(timestamp),
             key,
         },
+        OplogEntry::SetLabel {
+            timestamp,
+            key,
+            value,
+        } => OplogEntry::SetLabel {
+            timestamp: rounded_ts(timestamp),
+            key,
+            value,
+        },
+        OplogEntry::RemoveLabel { timestamp, key } => OplogEntry::RemoveLabel {
+            timestamp: rounded_ts(timestamp),
+            key,
+        },
+        OplogEntry::ChangePendingInvocationPriority {
+            timestamp,
+            idempotency_key,
+            priority,
+        } => OplogEntry::ChangePendingInvocationPriority {
+            timestamp: rounded_ts(timestamp),
+            idempotency_key,
+            priority,
+        },
     }
 }
 
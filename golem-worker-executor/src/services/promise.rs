@@ -21,20 +21,27 @@ use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use dashmap::DashMap;
 use golem_common::model::oplog::OplogIndex;
-use golem_common::model::{PromiseId, WorkerId};
+use golem_common::model::{PromiseId, Timestamp, WorkerId};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::pending_promise::PendingPromise;
 #[cfg(test)]
 use std::collections::HashSet;
 use std::ops::DerefMut;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::oneshot;
 use tracing::debug;
 
 /// Service implementing creation, completion and polling of promises
 #[async_trait]
 pub trait PromiseService: Send + Sync {
+    /// Creates a promise on behalf of the worker, which is listed by `get_pending` until it gets completed or deleted
     async fn create(&self, worker_id: &WorkerId, oplog_idx: OplogIndex) -> PromiseId;
 
+    /// Creates a promise used by the executor itself, for example to wake up a sleeping worker.
+    /// These promises are not listed by `get_pending`.
+    async fn create_internal(&self, worker_id: &WorkerId, oplog_idx: OplogIndex) -> PromiseId;
+
     async fn wait_for(&self, promise_id: PromiseId) -> Result<Vec<u8>, WorkerExecutorError>;
 
     async fn poll(&self, promise_id: PromiseId) -> Result<Option<Vec<u8>>, WorkerExecutorError>;
@@ -46,6 +53,12 @@ pub trait PromiseService: Send + Sync {
     ) -> Result<bool, WorkerExecutorError>;
 
     async fn delete(&self, promise_id: PromiseId);
+
+    /// Gets the promises created by the worker which are not completed or deleted yet, oldest first
+    async fn get_pending(&self, worker_id: &WorkerId) -> Vec<PendingPromise>;
+
+    /// Deletes all the pending promises of the worker
+    async fn delete_all_pending(&self, worker_id: &WorkerId);
 }
 
 #[derive(Clone, Debug)]
@@ -77,6 +90,22 @@ impl DefaultPromiseService {
         }
     }
 
+    async fn remove_from_pending(&self, promise_id: &PromiseId) {
+        self.key_value_storage
+            .with_entity("promise", "remove_from_pending", "promise")
+            .remove_from_sorted_set(
+                KeyValueStorageNamespace::Promise,
+                &get_pending_promises_redis_key(&promise_id.worker_id),
+                promise_id,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!(
+                    "failed to remove promise {promise_id} from the pending promises in Redis: {err}"
+                )
+            });
+    }
+
     async fn exists(&self, promise_id: &PromiseId) -> bool {
         self.key_value_storage
             .with("promise", "complete")
@@ -94,6 +123,25 @@ impl DefaultPromiseService {
 #[async_trait]
 impl PromiseService for DefaultPromiseService {
     async fn create(&self, worker_id: &WorkerId, oplog_idx: OplogIndex) -> PromiseId {
+        let promise_id = self.create_internal(worker_id, oplog_idx).await;
+
+        self.key_value_storage
+            .with_entity("promise", "create", "promise")
+            .add_to_sorted_set(
+                KeyValueStorageNamespace::Promise,
+                &get_pending_promises_redis_key(worker_id),
+                Timestamp::now_utc().to_millis() as f64,
+                &promise_id,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to add promise {promise_id} to the pending promises in Redis: {err}")
+            });
+
+        promise_id
+    }
+
+    async fn create_internal(&self, worker_id: &WorkerId, oplog_idx: OplogIndex) -> PromiseId {
         let promise_id = PromiseId {
             worker_id: worker_id.clone(),
             oplog_idx,
@@ -209,6 +257,8 @@ impl PromiseService for DefaultPromiseService {
         if !self.exists(&promise_id).await {
             Err(WorkerExecutorError::PromiseNotFound { promise_id })
         } else if written {
+            self.remove_from_pending(&promise_id).await;
+
             let complete = PromiseState::Complete(data.clone());
             self.insert_if_empty(promise_id.clone(), complete);
             let entry = self.promises.get(&promise_id).unwrap_or_else(|| {
@@ -249,6 +299,42 @@ impl PromiseService for DefaultPromiseService {
             .unwrap_or_else(|err| {
                 panic!("failed to delete promise {promise_id} from Redis: {err}")
             });
+        self.remove_from_pending(&promise_id).await;
+    }
+
+    async fn get_pending(&self, worker_id: &WorkerId) -> Vec<PendingPromise> {
+        self.key_value_storage
+            .with_entity("promise", "get_pending", "promise")
+            .get_sorted_set::<PromiseId>(
+                KeyValueStorageNamespace::Promise,
+                &get_pending_promises_redis_key(worker_id),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to get the pending promises of {worker_id} from Redis: {err}")
+            })
+            .into_iter()
+            .map(|(score, promise_id)| PendingPromise {
+                promise_id,
+                created_at: Timestamp::from(score as u64),
+            })
+            .collect()
+    }
+
+    async fn delete_all_pending(&self, worker_id: &WorkerId) {
+        for pending in self.get_pending(worker_id).await {
+            self.delete(pending.promise_id).await;
+        }
+        self.key_value_storage
+            .with("promise", "delete_all_pending")
+            .del(
+                KeyValueStorageNamespace::Promise,
+                &get_pending_promises_redis_key(worker_id),
+            )
+            .await
+            .unwrap_or_else(|err| {
+                panic!("failed to delete the pending promises of {worker_id} from Redis: {err}")
+            });
     }
 }
 
@@ -260,6 +346,10 @@ fn get_promise_result_redis_key(promise_id: &PromiseId) -> String {
     format!("{}:completed", promise_id.to_redis_key())
 }
 
+fn get_pending_promises_redis_key(worker_id: &WorkerId) -> String {
+    format!("{}:pending", worker_id.to_redis_key())
+}
+
 #[derive(Debug)]
 enum PromiseState {
     Pending(
@@ -275,6 +365,35 @@ pub enum RedisPromiseState {
     Complete(Vec<u8>),
 }
 
+/// The point in time until a worker awaits a promise, calculated once when it starts awaiting
+/// it. The deadline gets persisted, so resuming or replaying the worker does not move it.
+pub fn promise_deadline(awaiting_since: Timestamp, timeout: Duration) -> Timestamp {
+    Timestamp::from(awaiting_since.to_millis() + timeout.as_millis() as u64)
+}
+
+/// The outcome of awaiting a promise until a deadline, given the result of polling it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimedAwait {
+    Completed(Vec<u8>),
+    TimedOut,
+    /// The worker has to be suspended until the promise gets completed, or the deadline is reached
+    Suspend {
+        until: Timestamp,
+    },
+}
+
+impl TimedAwait {
+    /// A promise found completed wins over the deadline, even if the deadline has passed
+    /// while the worker was suspended
+    pub fn new(polled: Option<Vec<u8>>, deadline: Timestamp, now: Timestamp) -> Self {
+        match polled {
+            Some(data) => TimedAwait::Completed(data),
+            None if now < deadline => TimedAwait::Suspend { until: deadline },
+            None => TimedAwait::TimedOut,
+        }
+    }
+}
+
 #[cfg(test)]
 pub struct PromiseServiceMock {
     completed: Arc<Mutex<HashSet<PromiseId>>>,
@@ -307,6 +426,10 @@ impl PromiseService for PromiseServiceMock {
         unimplemented!()
     }
 
+    async fn create_internal(&self, _worker_id: &WorkerId, _oplog_idx: OplogIndex) -> PromiseId {
+        unimplemented!()
+    }
+
     async fn wait_for(&self, _promise_id: PromiseId) -> Result<Vec<u8>, WorkerExecutorError> {
         unimplemented!()
     }
@@ -327,4 +450,187 @@ impl PromiseService for PromiseServiceMock {
     async fn delete(&self, _promise_id: PromiseId) {
         unimplemented!()
     }
+
+    async fn get_pending(&self, _worker_id: &WorkerId) -> Vec<PendingPromise> {
+        unimplemented!()
+    }
+
+    async fn delete_all_pending(&self, _worker_id: &WorkerId) {
+        unimplemented!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::services::promise::{
+        promise_deadline, DefaultPromiseService, PromiseService, TimedAwait,
+    };
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use golem_common::model::oplog::OplogIndex;
+    use golem_common::model::{ComponentId, PromiseId, Timestamp, WorkerId};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn new_worker_id() -> WorkerId {
+        WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "worker".to_string(),
+        }
+    }
+
+    async fn pending_ids(
+        promise_service: &DefaultPromiseService,
+        worker_id: &WorkerId,
+    ) -> Vec<PromiseId> {
+        promise_service
+            .get_pending(worker_id)
+            .await
+            .into_iter()
+            .map(|pending| pending.promise_id)
+            .collect()
+    }
+
+    #[test]
+    async fn promises_are_pending_oldest_first_until_completed() {
+        let promise_service = DefaultPromiseService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let worker_id = new_worker_id();
+
+        let p1 = promise_service
+            .create(&worker_id, OplogIndex::from_u64(10))
+            .await;
+        let p2 = promise_service
+            .create(&worker_id, OplogIndex::from_u64(20))
+            .await;
+        assert_eq!(
+            pending_ids(&promise_service, &worker_id).await,
+            vec![p1.clone(), p2.clone()]
+        );
+
+        assert!(promise_service.complete(p1, vec![1, 2, 3]).await.unwrap());
+        assert_eq!(pending_ids(&promise_service, &worker_id).await, vec![p2]);
+    }
+
+    #[test]
+    async fn deleted_promises_are_not_pending() {
+        let promise_service = DefaultPromiseService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let worker_id = new_worker_id();
+
+        let promise_id = promise_service
+            .create(&worker_id, OplogIndex::from_u64(10))
+            .await;
+        promise_service.delete(promise_id).await;
+
+        assert!(promise_service.get_pending(&worker_id).await.is_empty());
+    }
+
+    #[test]
+    async fn internal_promises_are_not_pending() {
+        let promise_service = DefaultPromiseService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let worker_id = new_worker_id();
+
+        promise_service
+            .create_internal(&worker_id, OplogIndex::from_u64(10))
+            .await;
+
+        assert!(promise_service.get_pending(&worker_id).await.is_empty());
+    }
+
+    #[test]
+    async fn deleting_all_pending_promises_keeps_the_ones_of_other_workers() {
+        let promise_service = DefaultPromiseService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let worker_id = new_worker_id();
+        let other_worker_id = new_worker_id();
+
+        let promise_id = promise_service
+            .create(&worker_id, OplogIndex::from_u64(10))
+            .await;
+        let other_promise_id = promise_service
+            .create(&other_worker_id, OplogIndex::from_u64(10))
+            .await;
+        promise_service.delete_all_pending(&worker_id).await;
+
+        assert!(promise_service.get_pending(&worker_id).await.is_empty());
+        assert!(promise_service.poll(promise_id).await.is_err());
+        assert_eq!(
+            pending_ids(&promise_service, &other_worker_id).await,
+            vec![other_promise_id]
+        );
+    }
+
+    #[test]
+    fn timed_await_suspends_until_the_deadline() {
+        let awaiting_since = Timestamp::from(1_000);
+        let deadline = promise_deadline(awaiting_since, Duration::from_secs(5));
+
+        assert_eq!(deadline, Timestamp::from(6_000));
+        assert_eq!(
+            TimedAwait::new(None, deadline, awaiting_since),
+            TimedAwait::Suspend { until: deadline }
+        );
+        assert_eq!(
+            TimedAwait::new(None, deadline, Timestamp::from(5_999)),
+            TimedAwait::Suspend { until: deadline }
+        );
+    }
+
+    #[test]
+    fn timed_await_times_out_at_the_deadline() {
+        let deadline = promise_deadline(Timestamp::from(1_000), Duration::from_secs(5));
+
+        assert_eq!(
+            TimedAwait::new(None, deadline, deadline),
+            TimedAwait::TimedOut
+        );
+        assert_eq!(
+            TimedAwait::new(None, deadline, Timestamp::from(60_000)),
+            TimedAwait::TimedOut
+        );
+    }
+
+    #[test]
+    async fn timed_await_completes_with_a_promise_completed_while_suspended() {
+        let promise_service = DefaultPromiseService::new(Arc::new(InMemoryKeyValueStorage::new()));
+        let promise_id = promise_service
+            .create(&new_worker_id(), OplogIndex::from_u64(10))
+            .await;
+        let deadline = promise_deadline(Timestamp::from(1_000), Duration::from_secs(5));
+
+        let polled = promise_service.poll(promise_id.clone()).await.unwrap();
+        assert_eq!(
+            TimedAwait::new(polled, deadline, Timestamp::from(1_000)),
+            TimedAwait::Suspend { until: deadline }
+        );
+
+        promise_service
+            .complete(promise_id.clone(), vec![42])
+            .await
+            .unwrap();
+
+        // The worker may only be resumed after the deadline, but the completion still wins
+        let polled = promise_service.poll(promise_id).await.unwrap();
+        assert_eq!(
+            TimedAwait::new(polled, deadline, Timestamp::from(7_000)),
+            TimedAwait::Completed(vec![42])
+        );
+    }
+
+    #[test]
+    fn timed_await_resumed_after_the_deadline_uses_the_persisted_deadline() {
+        let timeout = Duration::from_secs(5);
+        let persisted_deadline = promise_deadline(Timestamp::from(1_000), timeout);
+        let resumed_at = Timestamp::from(6_500);
+
+        // Resuming the worker replays the deadline instead of starting the timeout again,
+        // so it times out exactly once the original deadline is reached
+        assert_eq!(
+            TimedAwait::new(None, persisted_deadline, resumed_at),
+            TimedAwait::TimedOut
+        );
+        assert_ne!(
+            TimedAwait::new(None, promise_deadline(resumed_at, timeout), resumed_at),
+            TimedAwait::TimedOut
+        );
+    }
 }
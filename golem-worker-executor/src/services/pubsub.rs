@@ -0,0 +1,586 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::services::oplog::Oplog;
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use crate::worker::status::calculate_deleted_regions;
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_common::model::regions::DeletedRegions;
+use golem_common::model::{IdempotencyKey, OwnedWorkerId, ProjectId, WorkerId};
+use golem_common::serialization::deserialize;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// A worker subscribed to a topic, and the exported function handling the topic's messages
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TopicSubscriber {
+    pub worker_id: WorkerId,
+    pub handler_function_name: String,
+}
+
+/// A topic a worker is subscribed to
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct TopicSubscription {
+    pub topic: String,
+    pub handler_function_name: String,
+}
+
+/// Service keeping track of the topic subscriptions of workers.
+///
+/// Topics are scoped to projects. The messages are not stored by this service, publishing
+/// enqueues an invocation of the handler function for each subscriber.
+#[async_trait]
+pub trait PubSubService: Send + Sync {
+    /// Subscribes the worker to the topic, replacing the handler function of an existing subscription
+    async fn subscribe(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+        handler_function_name: &str,
+    ) -> Result<(), WorkerExecutorError>;
+
+    async fn unsubscribe(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+    ) -> Result<(), WorkerExecutorError>;
+
+    /// Removes all the subscriptions of the worker, used when the worker gets deleted
+    async fn unsubscribe_all(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError>;
+
+    async fn get_subscribers(
+        &self,
+        project_id: &ProjectId,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscriber>, WorkerExecutorError>;
+
+    async fn get_subscriptions(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<TopicSubscription>, WorkerExecutorError>;
+}
+
+pub struct DefaultPubSubService {
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+}
+
+impl DefaultPubSubService {
+    pub fn new(key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>) -> Self {
+        Self { key_value_storage }
+    }
+
+    async fn remove_subscription(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        subscription: TopicSubscription,
+    ) -> Result<(), WorkerExecutorError> {
+        let subscriber = TopicSubscriber {
+            worker_id: owned_worker_id.worker_id(),
+            handler_function_name: subscription.handler_function_name.clone(),
+        };
+        self.key_value_storage
+            .with_entity("pubsub", "unsubscribe", "topic_subscriber")
+            .remove_from_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::topic_key(&owned_worker_id.project_id, &subscription.topic),
+                &subscriber,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        self.key_value_storage
+            .with_entity("pubsub", "unsubscribe", "topic_subscription")
+            .remove_from_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::worker_key(owned_worker_id),
+                &subscription,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    fn topic_key(project_id: &ProjectId, topic: &str) -> String {
+        format!("pubsub:topic:{project_id}/{topic}")
+    }
+
+    fn worker_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!("pubsub:worker:{owned_worker_id}")
+    }
+}
+
+#[async_trait]
+impl PubSubService for DefaultPubSubService {
+    async fn subscribe(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+        handler_function_name: &str,
+    ) -> Result<(), WorkerExecutorError> {
+        self.unsubscribe(owned_worker_id, topic).await?;
+
+        let subscriber = TopicSubscriber {
+            worker_id: owned_worker_id.worker_id(),
+            handler_function_name: handler_function_name.to_string(),
+        };
+        let subscription = TopicSubscription {
+            topic: topic.to_string(),
+            handler_function_name: handler_function_name.to_string(),
+        };
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "topic_subscriber")
+            .add_to_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::topic_key(&owned_worker_id.project_id, topic),
+                &subscriber,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        self.key_value_storage
+            .with_entity("pubsub", "subscribe", "topic_subscription")
+            .add_to_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::worker_key(owned_worker_id),
+                &subscription,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn unsubscribe(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        topic: &str,
+    ) -> Result<(), WorkerExecutorError> {
+        for subscription in self.get_subscriptions(owned_worker_id).await? {
+            if subscription.topic == topic {
+                self.remove_subscription(owned_worker_id, subscription)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn unsubscribe_all(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError> {
+        for subscription in self.get_subscriptions(owned_worker_id).await? {
+            self.remove_subscription(owned_worker_id, subscription)
+                .await?;
+        }
+        self.key_value_storage
+            .with("pubsub", "unsubscribe_all")
+            .del(
+                KeyValueStorageNamespace::PubSub,
+                &Self::worker_key(owned_worker_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn get_subscribers(
+        &self,
+        project_id: &ProjectId,
+        topic: &str,
+    ) -> Result<Vec<TopicSubscriber>, WorkerExecutorError> {
+        self.key_value_storage
+            .with_entity("pubsub", "get_subscribers", "topic_subscriber")
+            .members_of_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::topic_key(project_id, topic),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn get_subscriptions(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<TopicSubscription>, WorkerExecutorError> {
+        self.key_value_storage
+            .with_entity("pubsub", "get_subscriptions", "topic_subscription")
+            .members_of_set(
+                KeyValueStorageNamespace::PubSub,
+                &Self::worker_key(owned_worker_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+}
+
+/// Idempotency key of the invocation delivering a message to one of the subscribers of its topic.
+/// It only depends on the message id and the subscriber, so delivering the same message again
+/// does not enqueue a second invocation.
+pub fn delivery_idempotency_key(
+    message_id: &IdempotencyKey,
+    worker_id: &WorkerId,
+) -> IdempotencyKey {
+    let namespace = Uuid::new_v5(&Uuid::NAMESPACE_OID, message_id.value.as_bytes());
+    IdempotencyKey::from_uuid(Uuid::new_v5(&namespace, worker_id.to_string().as_bytes()))
+}
+
+const SUBSCRIBE_FUNCTION_NAME: &str = "golem::api::pubsub::subscribe";
+const UNSUBSCRIBE_FUNCTION_NAME: &str = "golem::api::pubsub::unsubscribe";
+
+/// Rebuilds the subscriptions of a worker from the subscribe and unsubscribe calls recorded in the
+/// given entries of its oplog, ignoring the calls in reverted regions.
+///
+/// Used after reverting a worker, as the reverted calls are not going to be replayed, and after
+/// forking one, as the forked worker inherits the subscriptions made before the cut-off point.
+pub async fn restore_subscriptions(
+    pubsub_service: Arc<dyn PubSubService>,
+    oplog: Arc<dyn Oplog>,
+    owned_worker_id: &OwnedWorkerId,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> Result<(), WorkerExecutorError> {
+    let deleted_regions = calculate_deleted_regions(DeletedRegions::new(), entries);
+
+    let mut subscriptions = BTreeMap::new();
+    for (idx, entry) in entries {
+        if deleted_regions.is_in_deleted_region(*idx) {
+            continue;
+        }
+
+        if let OplogEntry::ImportedFunctionInvoked {
+            function_name,
+            request,
+            response,
+            ..
+        } = entry
+        {
+            if function_name == SUBSCRIBE_FUNCTION_NAME
+                || function_name == UNSUBSCRIBE_FUNCTION_NAME
+            {
+                let request = oplog
+                    .download_payload(request)
+                    .await
+                    .map_err(WorkerExecutorError::runtime)?;
+                let response = oplog
+                    .download_payload(response)
+                    .await
+                    .map_err(WorkerExecutorError::runtime)?;
+                apply_recorded_call(&mut subscriptions, function_name, &request, &response)
+                    .map_err(WorkerExecutorError::runtime)?;
+            }
+        }
+    }
+
+    pubsub_service.unsubscribe_all(owned_worker_id).await?;
+    for (topic, handler_function_name) in subscriptions {
+        pubsub_service
+            .subscribe(owned_worker_id, &topic, &handler_function_name)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Applies a recorded subscribe or unsubscribe call to the subscriptions (topic to handler function
+/// name), unless the call failed
+fn apply_recorded_call(
+    subscriptions: &mut BTreeMap<String, String>,
+    function_name: &str,
+    request: &[u8],
+    response: &[u8],
+) -> Result<(), String> {
+    let response: Result<(), SerializableError> = deserialize(response)?;
+    if response.is_err() {
+        return Ok(());
+    }
+
+    if function_name == SUBSCRIBE_FUNCTION_NAME {
+        let (topic, handler_function_name): (String, String) = deserialize(request)?;
+        subscriptions.insert(topic, handler_function_name);
+    } else {
+        let topic: String = deserialize(request)?;
+        subscriptions.remove(&topic);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::durable_host::serialized::SerializableError;
+    use crate::services::pubsub::{
+        apply_recorded_call, delivery_idempotency_key, DefaultPubSubService, PubSubService,
+        TopicSubscriber, TopicSubscription, SUBSCRIBE_FUNCTION_NAME, UNSUBSCRIBE_FUNCTION_NAME,
+    };
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use golem_common::model::{ComponentId, IdempotencyKey, OwnedWorkerId, ProjectId, WorkerId};
+    use golem_common::serialization::serialize;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    fn pubsub_service() -> DefaultPubSubService {
+        DefaultPubSubService::new(Arc::new(InMemoryKeyValueStorage::new()))
+    }
+
+    fn owned_worker_id(project_id: &ProjectId, name: &str) -> OwnedWorkerId {
+        OwnedWorkerId::new(
+            project_id,
+            &WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: name.to_string(),
+            },
+        )
+    }
+
+    fn subscriber(owned_worker_id: &OwnedWorkerId, handler_function_name: &str) -> TopicSubscriber {
+        TopicSubscriber {
+            worker_id: owned_worker_id.worker_id(),
+            handler_function_name: handler_function_name.to_string(),
+        }
+    }
+
+    fn recorded_subscribe(
+        subscriptions: &mut BTreeMap<String, String>,
+        topic: &str,
+        handler_function_name: &str,
+        response: Result<(), SerializableError>,
+    ) {
+        apply_recorded_call(
+            subscriptions,
+            SUBSCRIBE_FUNCTION_NAME,
+            &serialize(&(topic.to_string(), handler_function_name.to_string())).unwrap(),
+            &serialize(&response).unwrap(),
+        )
+        .unwrap();
+    }
+
+    fn recorded_unsubscribe(subscriptions: &mut BTreeMap<String, String>, topic: &str) {
+        let response: Result<(), SerializableError> = Ok(());
+        apply_recorded_call(
+            subscriptions,
+            UNSUBSCRIBE_FUNCTION_NAME,
+            &serialize(&topic.to_string()).unwrap(),
+            &serialize(&response).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    async fn subscribers_are_listed_per_topic() {
+        let pubsub = pubsub_service();
+        let project_id = ProjectId::new_v4();
+        let w1 = owned_worker_id(&project_id, "w1");
+        let w2 = owned_worker_id(&project_id, "w2");
+
+        pubsub
+            .subscribe(&w1, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w2, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w2, "payments", "api.{on-payment}")
+            .await
+            .unwrap();
+
+        let mut subscribers = pubsub.get_subscribers(&project_id, "orders").await.unwrap();
+        subscribers.sort_by_key(|subscriber| subscriber.worker_id.worker_name.clone());
+        assert_eq!(
+            subscribers,
+            vec![
+                subscriber(&w1, "api.{on-order}"),
+                subscriber(&w2, "api.{on-order}")
+            ]
+        );
+        assert_eq!(
+            pubsub
+                .get_subscribers(&project_id, "payments")
+                .await
+                .unwrap(),
+            vec![subscriber(&w2, "api.{on-payment}")]
+        );
+    }
+
+    #[test]
+    async fn topics_are_scoped_to_projects() {
+        let pubsub = pubsub_service();
+        let project_id = ProjectId::new_v4();
+        let w1 = owned_worker_id(&project_id, "w1");
+
+        pubsub
+            .subscribe(&w1, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+
+        assert!(pubsub
+            .get_subscribers(&ProjectId::new_v4(), "orders")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    async fn subscribing_again_replaces_the_handler() {
+        let pubsub = pubsub_service();
+        let project_id = ProjectId::new_v4();
+        let w1 = owned_worker_id(&project_id, "w1");
+
+        pubsub
+            .subscribe(&w1, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w1, "orders", "api.{handle-order}")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pubsub.get_subscribers(&project_id, "orders").await.unwrap(),
+            vec![subscriber(&w1, "api.{handle-order}")]
+        );
+        assert_eq!(
+            pubsub.get_subscriptions(&w1).await.unwrap(),
+            vec![TopicSubscription {
+                topic: "orders".to_string(),
+                handler_function_name: "api.{handle-order}".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    async fn unsubscribe_only_removes_the_given_topic() {
+        let pubsub = pubsub_service();
+        let project_id = ProjectId::new_v4();
+        let w1 = owned_worker_id(&project_id, "w1");
+        let w2 = owned_worker_id(&project_id, "w2");
+
+        pubsub
+            .subscribe(&w1, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w1, "payments", "api.{on-payment}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w2, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+
+        pubsub.unsubscribe(&w1, "orders").await.unwrap();
+
+        assert_eq!(
+            pubsub.get_subscribers(&project_id, "orders").await.unwrap(),
+            vec![subscriber(&w2, "api.{on-order}")]
+        );
+        assert_eq!(
+            pubsub.get_subscriptions(&w1).await.unwrap(),
+            vec![TopicSubscription {
+                topic: "payments".to_string(),
+                handler_function_name: "api.{on-payment}".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    async fn unsubscribe_all_removes_every_subscription_of_the_worker() {
+        let pubsub = pubsub_service();
+        let project_id = ProjectId::new_v4();
+        let w1 = owned_worker_id(&project_id, "w1");
+        let w2 = owned_worker_id(&project_id, "w2");
+
+        pubsub
+            .subscribe(&w1, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w1, "payments", "api.{on-payment}")
+            .await
+            .unwrap();
+        pubsub
+            .subscribe(&w2, "orders", "api.{on-order}")
+            .await
+            .unwrap();
+
+        pubsub.unsubscribe_all(&w1).await.unwrap();
+
+        assert_eq!(
+            pubsub.get_subscribers(&project_id, "orders").await.unwrap(),
+            vec![subscriber(&w2, "api.{on-order}")]
+        );
+        assert!(pubsub
+            .get_subscribers(&project_id, "payments")
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(pubsub.get_subscriptions(&w1).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn recorded_calls_are_applied_in_order() {
+        let mut subscriptions = BTreeMap::new();
+
+        recorded_subscribe(&mut subscriptions, "orders", "api.{on-order}", Ok(()));
+        recorded_subscribe(&mut subscriptions, "payments", "api.{on-payment}", Ok(()));
+        recorded_subscribe(&mut subscriptions, "orders", "api.{handle-order}", Ok(()));
+        recorded_unsubscribe(&mut subscriptions, "payments");
+
+        assert_eq!(
+            subscriptions,
+            BTreeMap::from([("orders".to_string(), "api.{handle-order}".to_string())])
+        );
+    }
+
+    #[test]
+    fn failed_recorded_calls_are_ignored() {
+        let mut subscriptions = BTreeMap::new();
+
+        recorded_subscribe(
+            &mut subscriptions,
+            "orders",
+            "api.{on-order}",
+            Err(SerializableError::Generic {
+                message: "failed".to_string(),
+            }),
+        );
+
+        assert!(subscriptions.is_empty());
+    }
+
+    #[test]
+    fn delivery_idempotency_keys_are_deterministic_per_subscriber() {
+        let project_id = ProjectId::new_v4();
+        let w1 = owned_worker_id(&project_id, "w1").worker_id();
+        let w2 = owned_worker_id(&project_id, "w2").worker_id();
+        let message_id = IdempotencyKey::fresh();
+
+        assert_eq!(
+            delivery_idempotency_key(&message_id, &w1),
+            delivery_idempotency_key(&message_id, &w1)
+        );
+        assert_ne!(
+            delivery_idempotency_key(&message_id, &w1),
+            delivery_idempotency_key(&message_id, &w2)
+        );
+        assert_ne!(
+            delivery_idempotency_key(&message_id, &w1),
+            delivery_idempotency_key(&IdempotencyKey::fresh(), &w1)
+        );
+    }
+}
@@ -13,13 +13,17 @@
 // limitations under the License.
 
 pub(crate) mod metrics;
+pub mod mssql;
 pub mod mysql;
 pub mod postgres;
+pub mod sqlite;
 pub(crate) mod sqlx_common;
 
 use crate::services::golem_config::RdbmsConfig;
+use crate::services::rdbms::mssql::MssqlType;
 use crate::services::rdbms::mysql::MysqlType;
 use crate::services::rdbms::postgres::PostgresType;
+use crate::services::rdbms::sqlite::SqliteType;
 use async_trait::async_trait;
 use bincode::{BorrowDecode, Decode, Encode};
 use golem_common::model::WorkerId;
@@ -56,6 +60,17 @@ pub trait RdbmsType: Debug + Display + Default + Send {
         + Encode
         + RdbmsIntoValueAndType
         + 'static;
+
+    /// Embedded databases store their data in the worker's own file system, which is rebuilt
+    /// by replaying the worker, so their statements have to be re-executed during replay too.
+    fn is_embedded() -> bool {
+        false
+    }
+
+    /// Statement creating the table of transaction commit markers, unless it already exists
+    fn create_transaction_markers_table_statement() -> &'static str {
+        "CREATE TABLE IF NOT EXISTS golem_transaction_markers (transaction_id CHAR(36) PRIMARY KEY, created_at BIGINT NOT NULL)"
+    }
 }
 
 #[derive(Clone)]
@@ -148,6 +163,8 @@ pub trait Rdbms<T: RdbmsType> {
 pub trait RdbmsService: Send + Sync {
     fn mysql(&self) -> Arc<dyn Rdbms<MysqlType> + Send + Sync>;
     fn postgres(&self) -> Arc<dyn Rdbms<PostgresType> + Send + Sync>;
+    fn sqlite(&self) -> Arc<dyn Rdbms<SqliteType> + Send + Sync>;
+    fn mssql(&self) -> Arc<dyn Rdbms<MssqlType> + Send + Sync>;
 }
 
 pub trait RdbmsTypeService<T: RdbmsType> {
@@ -166,10 +183,24 @@ impl RdbmsTypeService<PostgresType> for dyn RdbmsService {
     }
 }
 
+impl RdbmsTypeService<SqliteType> for dyn RdbmsService {
+    fn rdbms_type_service(&self) -> Arc<dyn Rdbms<SqliteType> + Send + Sync> {
+        self.sqlite()
+    }
+}
+
+impl RdbmsTypeService<MssqlType> for dyn RdbmsService {
+    fn rdbms_type_service(&self) -> Arc<dyn Rdbms<MssqlType> + Send + Sync> {
+        self.mssql()
+    }
+}
+
 #[derive(Clone)]
 pub struct RdbmsServiceDefault {
     mysql: Arc<dyn Rdbms<MysqlType> + Send + Sync>,
     postgres: Arc<dyn Rdbms<PostgresType> + Send + Sync>,
+    sqlite: Arc<dyn Rdbms<SqliteType> + Send + Sync>,
+    mssql: Arc<dyn Rdbms<MssqlType> + Send + Sync>,
 }
 
 impl RdbmsServiceDefault {
@@ -177,6 +208,8 @@ impl RdbmsServiceDefault {
         Self {
             mysql: MysqlType::new_rdbms(config),
             postgres: PostgresType::new_rdbms(config),
+            sqlite: SqliteType::new_rdbms(config),
+            mssql: MssqlType::new_rdbms(config),
         }
     }
 }
@@ -195,6 +228,14 @@ impl RdbmsService for RdbmsServiceDefault {
     fn postgres(&self) -> Arc<dyn Rdbms<PostgresType> + Send + Sync> {
         self.postgres.clone()
     }
+
+    fn sqlite(&self) -> Arc<dyn Rdbms<SqliteType> + Send + Sync> {
+        self.sqlite.clone()
+    }
+
+    fn mssql(&self) -> Arc<dyn Rdbms<MssqlType> + Send + Sync> {
+        self.mssql.clone()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Encode, Decode, IntoValue)]
@@ -0,0 +1,48 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod tiberius_rdbms;
+pub mod types;
+
+use crate::services::golem_config::RdbmsConfig;
+use crate::services::rdbms::{Rdbms, RdbmsType};
+use bincode::{Decode, Encode};
+use std::fmt::Display;
+use std::sync::Arc;
+
+pub(crate) const MSSQL: &str = "mssql";
+
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct MssqlType;
+
+impl MssqlType {
+    pub fn new_rdbms(config: RdbmsConfig) -> Arc<dyn Rdbms<MssqlType> + Send + Sync> {
+        tiberius_rdbms::new(config)
+    }
+}
+
+impl RdbmsType for MssqlType {
+    type DbColumn = types::DbColumn;
+    type DbValue = types::DbValue;
+
+    fn create_transaction_markers_table_statement() -> &'static str {
+        "IF OBJECT_ID(N'golem_transaction_markers', N'U') IS NULL CREATE TABLE golem_transaction_markers (transaction_id CHAR(36) PRIMARY KEY, created_at BIGINT NOT NULL)"
+    }
+}
+
+impl Display for MssqlType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{MSSQL}")
+    }
+}
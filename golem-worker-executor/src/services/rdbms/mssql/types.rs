@@ -0,0 +1,357 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::rdbms::{AnalysedTypeMerger, RdbmsIntoValueAndType};
+use bigdecimal::BigDecimal;
+use bincode::{Decode, Encode};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{IntoValue, IntoValueAndType, ValueAndType};
+use golem_wasm_rpc_derive::IntoValue;
+use std::fmt::Display;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, IntoValue)]
+pub enum DbColumnType {
+    Bit,
+    Tinyint,
+    Smallint,
+    Int,
+    Bigint,
+    Real,
+    Float,
+    Decimal,
+    Numeric,
+    Smallmoney,
+    Money,
+    Date,
+    Time,
+    Smalldatetime,
+    Datetime,
+    Datetime2,
+    Datetimeoffset,
+    Fixchar,
+    Varchar,
+    Text,
+    Nchar,
+    Nvarchar,
+    Ntext,
+    Binary,
+    Varbinary,
+    Image,
+    Uniqueidentifier,
+    Xml,
+}
+
+impl Display for DbColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbColumnType::Bit => write!(f, "bit"),
+            DbColumnType::Tinyint => write!(f, "tinyint"),
+            DbColumnType::Smallint => write!(f, "smallint"),
+            DbColumnType::Int => write!(f, "int"),
+            DbColumnType::Bigint => write!(f, "bigint"),
+            DbColumnType::Real => write!(f, "real"),
+            DbColumnType::Float => write!(f, "float"),
+            DbColumnType::Decimal => write!(f, "decimal"),
+            DbColumnType::Numeric => write!(f, "numeric"),
+            DbColumnType::Smallmoney => write!(f, "smallmoney"),
+            DbColumnType::Money => write!(f, "money"),
+            DbColumnType::Date => write!(f, "date"),
+            DbColumnType::Time => write!(f, "time"),
+            DbColumnType::Smalldatetime => write!(f, "smalldatetime"),
+            DbColumnType::Datetime => write!(f, "datetime"),
+            DbColumnType::Datetime2 => write!(f, "datetime2"),
+            DbColumnType::Datetimeoffset => write!(f, "datetimeoffset"),
+            DbColumnType::Fixchar => write!(f, "char"),
+            DbColumnType::Varchar => write!(f, "varchar"),
+            DbColumnType::Text => write!(f, "text"),
+            DbColumnType::Nchar => write!(f, "nchar"),
+            DbColumnType::Nvarchar => write!(f, "nvarchar"),
+            DbColumnType::Ntext => write!(f, "ntext"),
+            DbColumnType::Binary => write!(f, "binary"),
+            DbColumnType::Varbinary => write!(f, "varbinary"),
+            DbColumnType::Image => write!(f, "image"),
+            DbColumnType::Uniqueidentifier => write!(f, "uniqueidentifier"),
+            DbColumnType::Xml => write!(f, "xml"),
+        }
+    }
+}
+
+impl RdbmsIntoValueAndType for DbColumnType {
+    fn into_value_and_type(self) -> ValueAndType {
+        IntoValueAndType::into_value_and_type(self)
+    }
+
+    fn get_base_type() -> AnalysedType {
+        DbColumnType::get_type()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode, IntoValue)]
+pub enum DbValue {
+    Bit(bool),
+    Tinyint(u8),
+    Smallint(i16),
+    Int(i32),
+    Bigint(i64),
+    Real(f32),
+    Float(f64),
+    Decimal(#[bincode(with_serde)] BigDecimal),
+    Numeric(#[bincode(with_serde)] BigDecimal),
+    Smallmoney(#[bincode(with_serde)] BigDecimal),
+    Money(#[bincode(with_serde)] BigDecimal),
+    Date(#[bincode(with_serde)] chrono::NaiveDate),
+    Time(#[bincode(with_serde)] chrono::NaiveTime),
+    Smalldatetime(#[bincode(with_serde)] chrono::NaiveDateTime),
+    Datetime(#[bincode(with_serde)] chrono::NaiveDateTime),
+    Datetime2(#[bincode(with_serde)] chrono::NaiveDateTime),
+    Datetimeoffset(#[bincode(with_serde)] chrono::DateTime<chrono::Utc>),
+    Fixchar(String),
+    Varchar(String),
+    Text(String),
+    Nchar(String),
+    Nvarchar(String),
+    Ntext(String),
+    Binary(Vec<u8>),
+    Varbinary(Vec<u8>),
+    Image(Vec<u8>),
+    Uniqueidentifier(#[bincode(with_serde)] Uuid),
+    Xml(String),
+    Null,
+}
+
+impl Display for DbValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbValue::Bit(v) => write!(f, "{v}"),
+            DbValue::Tinyint(v) => write!(f, "{v}"),
+            DbValue::Smallint(v) => write!(f, "{v}"),
+            DbValue::Int(v) => write!(f, "{v}"),
+            DbValue::Bigint(v) => write!(f, "{v}"),
+            DbValue::Real(v) => write!(f, "{v}"),
+            DbValue::Float(v) => write!(f, "{v}"),
+            DbValue::Decimal(v) => write!(f, "{v}"),
+            DbValue::Numeric(v) => write!(f, "{v}"),
+            DbValue::Smallmoney(v) => write!(f, "{v}"),
+            DbValue::Money(v) => write!(f, "{v}"),
+            DbValue::Date(v) => write!(f, "{v}"),
+            DbValue::Time(v) => write!(f, "{v}"),
+            DbValue::Smalldatetime(v) => write!(f, "{v}"),
+            DbValue::Datetime(v) => write!(f, "{v}"),
+            DbValue::Datetime2(v) => write!(f, "{v}"),
+            DbValue::Datetimeoffset(v) => write!(f, "{v}"),
+            DbValue::Fixchar(v) => write!(f, "{v}"),
+            DbValue::Varchar(v) => write!(f, "{v}"),
+            DbValue::Text(v) => write!(f, "{v}"),
+            DbValue::Nchar(v) => write!(f, "{v}"),
+            DbValue::Nvarchar(v) => write!(f, "{v}"),
+            DbValue::Ntext(v) => write!(f, "{v}"),
+            DbValue::Binary(v) => write!(f, "{v:?}"),
+            DbValue::Varbinary(v) => write!(f, "{v:?}"),
+            DbValue::Image(v) => write!(f, "{v:?}"),
+            DbValue::Uniqueidentifier(v) => write!(f, "{v}"),
+            DbValue::Xml(v) => write!(f, "{v}"),
+            DbValue::Null => write!(f, "NULL"),
+        }
+    }
+}
+
+impl RdbmsIntoValueAndType for DbValue {
+    fn into_value_and_type(self) -> ValueAndType {
+        IntoValueAndType::into_value_and_type(self)
+    }
+
+    fn get_base_type() -> AnalysedType {
+        DbValue::get_type()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, IntoValue)]
+pub struct DbColumn {
+    pub ordinal: u64,
+    pub name: String,
+    pub db_type: DbColumnType,
+    pub db_type_name: String,
+}
+
+impl RdbmsIntoValueAndType for DbColumn {
+    fn into_value_and_type(self) -> ValueAndType {
+        IntoValueAndType::into_value_and_type(self)
+    }
+
+    fn get_base_type() -> AnalysedType {
+        DbColumn::get_type()
+    }
+}
+
+impl AnalysedTypeMerger for DbColumnType {
+    fn merge_types(first: AnalysedType, _second: AnalysedType) -> AnalysedType {
+        // same types are expected
+        first
+    }
+}
+
+impl AnalysedTypeMerger for DbValue {
+    fn merge_types(first: AnalysedType, _second: AnalysedType) -> AnalysedType {
+        // same types are expected
+        first
+    }
+}
+
+impl AnalysedTypeMerger for DbColumn {
+    fn merge_types(first: AnalysedType, _second: AnalysedType) -> AnalysedType {
+        // same types are expected
+        first
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::services::rdbms::mssql::{types as mssql_types, MssqlType};
+    use crate::services::rdbms::{DbResult, DbRow, RdbmsIntoValueAndType};
+    use assert2::check;
+    use bigdecimal::BigDecimal;
+    use bincode::{Decode, Encode};
+    use golem_common::serialization::{serialize, try_deserialize};
+    use std::str::FromStr;
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn check_bincode<T: Encode + Decode<()> + PartialEq>(value: T) {
+        let bin_value = serialize(&value).unwrap().to_vec();
+        let value2: Option<T> = try_deserialize(bin_value.as_slice()).ok().flatten();
+        check!(value2.unwrap() == value);
+    }
+
+    fn check_type_and_value<T: RdbmsIntoValueAndType>(value: T) {
+        let value_and_type = value.into_value_and_type();
+        let value_and_type_json = serde_json::to_string(&value_and_type);
+        check!(value_and_type_json.is_ok());
+    }
+
+    #[test]
+    fn test_db_values_conversions() {
+        for value in get_test_db_values() {
+            check_bincode(value.clone());
+            check_type_and_value(value);
+        }
+    }
+
+    #[test]
+    fn test_db_column_types_conversions() {
+        for value in get_test_db_column_types() {
+            check_bincode(value.clone());
+            check_type_and_value(value);
+        }
+    }
+
+    #[test]
+    fn test_db_result_conversions() {
+        let value = DbResult::<MssqlType>::new(
+            get_test_db_columns(),
+            vec![DbRow {
+                values: get_test_db_values(),
+            }],
+        );
+
+        check_bincode(value.clone());
+        check_type_and_value(value);
+    }
+
+    pub(crate) fn get_test_db_columns() -> Vec<mssql_types::DbColumn> {
+        let types = get_test_db_column_types();
+        let mut columns: Vec<mssql_types::DbColumn> = Vec::with_capacity(types.len());
+
+        for (i, ct) in types.iter().enumerate() {
+            let c = mssql_types::DbColumn {
+                ordinal: i as u64,
+                name: format!("column-{i}"),
+                db_type: ct.clone(),
+                db_type_name: ct.to_string(),
+            };
+            columns.push(c);
+        }
+        columns
+    }
+
+    pub(crate) fn get_test_db_column_types() -> Vec<mssql_types::DbColumnType> {
+        vec![
+            mssql_types::DbColumnType::Bit,
+            mssql_types::DbColumnType::Tinyint,
+            mssql_types::DbColumnType::Smallint,
+            mssql_types::DbColumnType::Int,
+            mssql_types::DbColumnType::Bigint,
+            mssql_types::DbColumnType::Real,
+            mssql_types::DbColumnType::Float,
+            mssql_types::DbColumnType::Decimal,
+            mssql_types::DbColumnType::Numeric,
+            mssql_types::DbColumnType::Smallmoney,
+            mssql_types::DbColumnType::Money,
+            mssql_types::DbColumnType::Date,
+            mssql_types::DbColumnType::Time,
+            mssql_types::DbColumnType::Smalldatetime,
+            mssql_types::DbColumnType::Datetime,
+            mssql_types::DbColumnType::Datetime2,
+            mssql_types::DbColumnType::Datetimeoffset,
+            mssql_types::DbColumnType::Fixchar,
+            mssql_types::DbColumnType::Varchar,
+            mssql_types::DbColumnType::Text,
+            mssql_types::DbColumnType::Nchar,
+            mssql_types::DbColumnType::Nvarchar,
+            mssql_types::DbColumnType::Ntext,
+            mssql_types::DbColumnType::Binary,
+            mssql_types::DbColumnType::Varbinary,
+            mssql_types::DbColumnType::Image,
+            mssql_types::DbColumnType::Uniqueidentifier,
+            mssql_types::DbColumnType::Xml,
+        ]
+    }
+
+    pub(crate) fn get_test_db_values() -> Vec<mssql_types::DbValue> {
+        let datetime = chrono::NaiveDateTime::new(
+            chrono::NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            chrono::NaiveTime::from_hms_opt(10, 20, 0).unwrap(),
+        );
+        vec![
+            mssql_types::DbValue::Bit(true),
+            mssql_types::DbValue::Tinyint(1),
+            mssql_types::DbValue::Smallint(2),
+            mssql_types::DbValue::Int(3),
+            mssql_types::DbValue::Bigint(4),
+            mssql_types::DbValue::Real(5.0),
+            mssql_types::DbValue::Float(6.0),
+            mssql_types::DbValue::Decimal(BigDecimal::from_str("70.00").unwrap()),
+            mssql_types::DbValue::Numeric(BigDecimal::from_str("80.50").unwrap()),
+            mssql_types::DbValue::Smallmoney(BigDecimal::from_str("9.9900").unwrap()),
+            mssql_types::DbValue::Money(BigDecimal::from_str("1000.1234").unwrap()),
+            mssql_types::DbValue::Date(chrono::NaiveDate::from_ymd_opt(2030, 10, 12).unwrap()),
+            mssql_types::DbValue::Time(chrono::NaiveTime::from_hms_opt(1, 20, 30).unwrap()),
+            mssql_types::DbValue::Smalldatetime(datetime),
+            mssql_types::DbValue::Datetime(datetime),
+            mssql_types::DbValue::Datetime2(datetime),
+            mssql_types::DbValue::Datetimeoffset(datetime.and_utc()),
+            mssql_types::DbValue::Fixchar("0123456789".to_string()),
+            mssql_types::DbValue::Varchar(format!("name-{}", Uuid::new_v4())),
+            mssql_types::DbValue::Text("text".to_string()),
+            mssql_types::DbValue::Nchar("nchar".to_string()),
+            mssql_types::DbValue::Nvarchar("nvarchar ✓".to_string()),
+            mssql_types::DbValue::Ntext("ntext".to_string()),
+            mssql_types::DbValue::Binary(vec![66, 105, 110, 97, 114, 121]),
+            mssql_types::DbValue::Varbinary("Varbinary".as_bytes().to_vec()),
+            mssql_types::DbValue::Image("Image".as_bytes().to_vec()),
+            mssql_types::DbValue::Uniqueidentifier(Uuid::new_v4()),
+            mssql_types::DbValue::Xml("<root><id>100</id></root>".to_string()),
+        ]
+    }
+}
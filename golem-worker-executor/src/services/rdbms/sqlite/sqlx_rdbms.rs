@@ -0,0 +1,263 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::golem_config::{RdbmsConfig, RdbmsPoolConfig};
+use crate::services::rdbms::sqlite::types::{DbColumn, DbColumnType, DbValue};
+use crate::services::rdbms::sqlite::{SqliteType, SQLITE};
+use crate::services::rdbms::sqlx_common::{
+    create_db_result, PoolCreator, QueryExecutor, QueryParamsBinder, SqlxDbResultStream, SqlxRdbms,
+};
+use crate::services::rdbms::{DbResult, DbResultStream, DbRow, Error, Rdbms, RdbmsPoolKey};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use sqlx::{Column, ConnectOptions, Pool, Row, TypeInfo, ValueRef};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How long a connection waits for a lock held by another connection of the same database
+const BUSY_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(crate) fn new(config: RdbmsConfig) -> Arc<dyn Rdbms<SqliteType> + Send + Sync> {
+    let sqlx: SqlxRdbms<SqliteType, sqlx::sqlite::Sqlite> = SqlxRdbms::new(config);
+    Arc::new(sqlx)
+}
+
+#[async_trait]
+impl PoolCreator<sqlx::Sqlite> for RdbmsPoolKey {
+    async fn create_pool(&self, config: &RdbmsPoolConfig) -> Result<Pool<sqlx::Sqlite>, Error> {
+        if self.address.scheme() != SQLITE {
+            Err(Error::ConnectionFailure(format!(
+                "scheme '{}' in url is invalid",
+                self.address.scheme()
+            )))?
+        }
+        let options = sqlx::sqlite::SqliteConnectOptions::from_url(&self.address)
+            .map_err(Error::connection_failure)?
+            .create_if_missing(true)
+            .busy_timeout(BUSY_TIMEOUT);
+        sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(config.max_connections)
+            .connect_with(options)
+            .await
+            .map_err(Error::connection_failure)
+    }
+}
+
+#[async_trait]
+impl QueryExecutor<SqliteType, sqlx::Sqlite> for SqliteType {
+    async fn execute<'c, E>(
+        statement: &str,
+        params: Vec<DbValue>,
+        executor: E,
+    ) -> Result<u64, Error>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let query: sqlx::query::Query<sqlx::Sqlite, sqlx::sqlite::SqliteArguments> =
+            sqlx::query(statement).bind_params(params)?;
+
+        let result = query
+            .execute(executor)
+            .await
+            .map_err(Error::query_execution_failure)?;
+        Ok(result.rows_affected())
+    }
+
+    async fn query<'c, E>(
+        statement: &str,
+        params: Vec<DbValue>,
+        executor: E,
+    ) -> Result<DbResult<SqliteType>, Error>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let query: sqlx::query::Query<sqlx::Sqlite, sqlx::sqlite::SqliteArguments> =
+            sqlx::query(statement).bind_params(params)?;
+
+        let result = query
+            .fetch_all(executor)
+            .await
+            .map_err(Error::query_execution_failure)?;
+        create_db_result::<SqliteType, sqlx::Sqlite>(result)
+    }
+
+    async fn query_stream<'c, E>(
+        statement: &str,
+        params: Vec<DbValue>,
+        batch: usize,
+        executor: E,
+    ) -> Result<Arc<dyn DbResultStream<SqliteType> + Send + Sync + 'c>, Error>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let query: sqlx::query::Query<sqlx::Sqlite, sqlx::sqlite::SqliteArguments> =
+            sqlx::query(statement.to_string().leak()).bind_params(params)?;
+
+        let stream: BoxStream<Result<sqlx::sqlite::SqliteRow, sqlx::Error>> = query.fetch(executor);
+
+        let response: SqlxDbResultStream<'c, SqliteType, sqlx::sqlite::Sqlite> =
+            SqlxDbResultStream::create(stream, batch).await?;
+        Ok(Arc::new(response))
+    }
+}
+
+impl<'q> QueryParamsBinder<'q, SqliteType, sqlx::Sqlite>
+    for sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>
+{
+    fn bind_params(
+        mut self,
+        params: Vec<DbValue>,
+    ) -> Result<sqlx::query::Query<'q, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'q>>, Error>
+    {
+        for param in params {
+            self = bind_value(self, param);
+        }
+        Ok(self)
+    }
+}
+
+fn bind_value(
+    query: sqlx::query::Query<sqlx::Sqlite, sqlx::sqlite::SqliteArguments>,
+    value: DbValue,
+) -> sqlx::query::Query<sqlx::Sqlite, sqlx::sqlite::SqliteArguments> {
+    match value {
+        DbValue::Integer(v) => query.bind(v),
+        DbValue::Real(v) => query.bind(v),
+        DbValue::Text(v) => query.bind(v),
+        DbValue::Blob(v) => query.bind(v),
+        DbValue::Boolean(v) => query.bind(v),
+        DbValue::Date(v) => query.bind(v),
+        DbValue::Time(v) => query.bind(v),
+        DbValue::Datetime(v) => query.bind(v),
+        DbValue::Null => query.bind(None::<String>),
+    }
+}
+
+impl TryFrom<&sqlx::sqlite::SqliteRow> for DbRow<DbValue> {
+    type Error = String;
+
+    fn try_from(value: &sqlx::sqlite::SqliteRow) -> Result<Self, Self::Error> {
+        let count = value.len();
+        let mut values = Vec::with_capacity(count);
+        for index in 0..count {
+            values.push(get_db_value(index, value)?);
+        }
+        Ok(DbRow { values })
+    }
+}
+
+/// SQLite is dynamically typed, so the value is decoded based on its storage class. The declared
+/// column type is only used to decode booleans, dates and times, which SQLite stores as integers
+/// or text.
+fn get_db_value(index: usize, row: &sqlx::sqlite::SqliteRow) -> Result<DbValue, String> {
+    let raw_value = row.try_get_raw(index).map_err(|e| e.to_string())?;
+    if raw_value.is_null() {
+        return Ok(DbValue::Null);
+    }
+    let storage_type: DbColumnType = (&*raw_value.type_info()).try_into()?;
+    let declared_type: DbColumnType = row.columns()[index].type_info().try_into()?;
+
+    let value = match (declared_type, storage_type) {
+        (DbColumnType::Boolean, DbColumnType::Integer) => {
+            let v: bool = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Boolean(v)
+        }
+        (DbColumnType::Date, DbColumnType::Text) => {
+            let v: chrono::NaiveDate = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Date(v)
+        }
+        (DbColumnType::Time, DbColumnType::Text) => {
+            let v: chrono::NaiveTime = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Time(v)
+        }
+        (
+            DbColumnType::Datetime,
+            DbColumnType::Text | DbColumnType::Integer | DbColumnType::Real,
+        ) => {
+            let v: chrono::NaiveDateTime = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Datetime(v)
+        }
+        (_, DbColumnType::Integer) => {
+            let v: i64 = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Integer(v)
+        }
+        (_, DbColumnType::Real) => {
+            let v: f64 = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Real(v)
+        }
+        (_, DbColumnType::Text) => {
+            let v: String = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Text(v)
+        }
+        (_, DbColumnType::Blob) => {
+            let v: Vec<u8> = row.try_get(index).map_err(|e| e.to_string())?;
+            DbValue::Blob(v)
+        }
+        (_, storage_type) => Err(format!("Value type '{storage_type}' is not supported"))?,
+    };
+    Ok(value)
+}
+
+impl TryFrom<&sqlx::sqlite::SqliteColumn> for DbColumn {
+    type Error = String;
+
+    fn try_from(value: &sqlx::sqlite::SqliteColumn) -> Result<Self, Self::Error> {
+        let ordinal = value.ordinal() as u64;
+        let db_type: DbColumnType = value.type_info().try_into()?;
+        let db_type_name = value.type_info().name().to_string();
+        let name = value.name().to_string();
+        Ok(DbColumn {
+            ordinal,
+            name,
+            db_type,
+            db_type_name,
+        })
+    }
+}
+
+impl TryFrom<&sqlx::sqlite::SqliteTypeInfo> for DbColumnType {
+    type Error = String;
+
+    fn try_from(value: &sqlx::sqlite::SqliteTypeInfo) -> Result<Self, Self::Error> {
+        let type_name = value.name();
+
+        match type_name {
+            sqlite_type_name::INTEGER => Ok(DbColumnType::Integer),
+            sqlite_type_name::REAL => Ok(DbColumnType::Real),
+            sqlite_type_name::TEXT => Ok(DbColumnType::Text),
+            sqlite_type_name::BLOB => Ok(DbColumnType::Blob),
+            sqlite_type_name::NUMERIC => Ok(DbColumnType::Numeric),
+            sqlite_type_name::BOOLEAN => Ok(DbColumnType::Boolean),
+            sqlite_type_name::DATE => Ok(DbColumnType::Date),
+            sqlite_type_name::TIME => Ok(DbColumnType::Time),
+            sqlite_type_name::DATETIME => Ok(DbColumnType::Datetime),
+            sqlite_type_name::NULL => Ok(DbColumnType::Null),
+            _ => Err(format!("Column type '{type_name}' is not supported"))?,
+        }
+    }
+}
+
+/// sqlx_sqlite::type_info::DataType is not publicly accessible.
+pub(crate) mod sqlite_type_name {
+    pub(crate) const NULL: &str = "NULL";
+    pub(crate) const TEXT: &str = "TEXT";
+    pub(crate) const REAL: &str = "REAL";
+    pub(crate) const BLOB: &str = "BLOB";
+    pub(crate) const INTEGER: &str = "INTEGER";
+    pub(crate) const NUMERIC: &str = "NUMERIC";
+    pub(crate) const BOOLEAN: &str = "BOOLEAN";
+    pub(crate) const DATE: &str = "DATE";
+    pub(crate) const TIME: &str = "TIME";
+    pub(crate) const DATETIME: &str = "DATETIME";
+}
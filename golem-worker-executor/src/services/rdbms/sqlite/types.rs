@@ -0,0 +1,241 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::services::rdbms::{AnalysedTypeMerger, RdbmsIntoValueAndType};
+use bincode::{Decode, Encode};
+use golem_wasm_ast::analysis::AnalysedType;
+use golem_wasm_rpc::{IntoValue, IntoValueAndType, ValueAndType};
+use golem_wasm_rpc_derive::IntoValue;
+use std::fmt::Display;
+
+/// SQLite column types: the storage classes, plus the declared types sqlx knows how to decode
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, IntoValue)]
+pub enum DbColumnType {
+    Integer,
+    Real,
+    Text,
+    Blob,
+    Numeric,
+    Boolean,
+    Date,
+    Time,
+    Datetime,
+    /// Used for columns without a declared type, like expressions
+    Null,
+}
+
+impl Display for DbColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbColumnType::Integer => write!(f, "integer"),
+            DbColumnType::Real => write!(f, "real"),
+            DbColumnType::Text => write!(f, "text"),
+            DbColumnType::Blob => write!(f, "blob"),
+            DbColumnType::Numeric => write!(f, "numeric"),
+            DbColumnType::Boolean => write!(f, "boolean"),
+            DbColumnType::Date => write!(f, "date"),
+            DbColumnType::Time => write!(f, "time"),
+            DbColumnType::Datetime => write!(f, "datetime"),
+            DbColumnType::Null => write!(f, "null"),
+        }
+    }
+}
+
+impl RdbmsIntoValueAndType for DbColumnType {
+    fn into_value_and_type(self) -> ValueAndType {
+        IntoValueAndType::into_value_and_type(self)
+    }
+
+    fn get_base_type() -> AnalysedType {
+        DbColumnType::get_type()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Encode, Decode, IntoValue)]
+pub enum DbValue {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+    Blob(Vec<u8>),
+    Boolean(bool),
+    Date(#[bincode(with_serde)] chrono::NaiveDate),
+    Time(#[bincode(with_serde)] chrono::NaiveTime),
+    Datetime(#[bincode(with_serde)] chrono::NaiveDateTime),
+    Null,
+}
+
+impl Display for DbValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbValue::Integer(v) => write!(f, "{v}"),
+            DbValue::Real(v) => write!(f, "{v}"),
+            DbValue::Text(v) => write!(f, "{v}"),
+            DbValue::Blob(v) => write!(f, "{v:?}"),
+            DbValue::Boolean(v) => write!(f, "{v}"),
+            DbValue::Date(v) => write!(f, "{v}"),
+            DbValue::Time(v) => write!(f, "{v}"),
+            DbValue::Datetime(v) => write!(f, "{v}"),
+            DbValue::Null => write!(f, "NULL"),
+        }
+    }
+}
+
+impl RdbmsIntoValueAndType for DbValue {
+    fn into_value_and_type(self) -> ValueAndType {
+        IntoValueAndType::into_value_and_type(self)
+    }
+
+    fn get_base_type() -> AnalysedType {
+        DbValue::get_type()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode, IntoValue)]
+pub struct DbColumn {
+    pub ordinal: u64,
+    pub name: String,
+    pub db_type: DbColumnType,
+    pub db_type_name: String,
+}
+
+impl RdbmsIntoValueAndType for DbColumn {
+    fn into_value_and_type(self) -> ValueAndType {
+        IntoValueAndType::into_value_and_type(self)
+    }
+
+    fn get_base_type() -> AnalysedType {
+        DbColumn::get_type()
+    }
+}
+
+impl AnalysedTypeMerger for DbColumnType {
+    fn merge_types(first: AnalysedType, _second: AnalysedType) -> AnalysedType {
+        // same types are expected
+        first
+    }
+}
+
+impl AnalysedTypeMerger for DbValue {
+    fn merge_types(first: AnalysedType, _second: AnalysedType) -> AnalysedType {
+        // same types are expected
+        first
+    }
+}
+
+impl AnalysedTypeMerger for DbColumn {
+    fn merge_types(first: AnalysedType, _second: AnalysedType) -> AnalysedType {
+        // same types are expected
+        first
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use crate::services::rdbms::sqlite::{types as sqlite_types, SqliteType};
+    use crate::services::rdbms::{DbResult, DbRow, RdbmsIntoValueAndType};
+    use assert2::check;
+    use bincode::{Decode, Encode};
+    use golem_common::serialization::{serialize, try_deserialize};
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn check_bincode<T: Encode + Decode<()> + PartialEq>(value: T) {
+        let bin_value = serialize(&value).unwrap().to_vec();
+        let value2: Option<T> = try_deserialize(bin_value.as_slice()).ok().flatten();
+        check!(value2.unwrap() == value);
+    }
+
+    fn check_type_and_value<T: RdbmsIntoValueAndType>(value: T) {
+        let value_and_type = value.into_value_and_type();
+        let value_and_type_json = serde_json::to_string(&value_and_type);
+        check!(value_and_type_json.is_ok());
+    }
+
+    #[test]
+    fn test_db_values_conversions() {
+        for value in get_test_db_values() {
+            check_bincode(value.clone());
+            check_type_and_value(value);
+        }
+    }
+
+    #[test]
+    fn test_db_column_types_conversions() {
+        for value in get_test_db_column_types() {
+            check_bincode(value.clone());
+            check_type_and_value(value);
+        }
+    }
+
+    #[test]
+    fn test_db_result_conversions() {
+        let value = DbResult::<SqliteType>::new(
+            get_test_db_columns(),
+            vec![DbRow {
+                values: get_test_db_values(),
+            }],
+        );
+
+        check_bincode(value.clone());
+        check_type_and_value(value);
+    }
+
+    pub(crate) fn get_test_db_columns() -> Vec<sqlite_types::DbColumn> {
+        let types = get_test_db_column_types();
+        let mut columns: Vec<sqlite_types::DbColumn> = Vec::with_capacity(types.len());
+
+        for (i, ct) in types.iter().enumerate() {
+            let c = sqlite_types::DbColumn {
+                ordinal: i as u64,
+                name: format!("column-{i}"),
+                db_type: ct.clone(),
+                db_type_name: ct.to_string(),
+            };
+            columns.push(c);
+        }
+        columns
+    }
+
+    pub(crate) fn get_test_db_column_types() -> Vec<sqlite_types::DbColumnType> {
+        vec![
+            sqlite_types::DbColumnType::Integer,
+            sqlite_types::DbColumnType::Real,
+            sqlite_types::DbColumnType::Text,
+            sqlite_types::DbColumnType::Blob,
+            sqlite_types::DbColumnType::Numeric,
+            sqlite_types::DbColumnType::Boolean,
+            sqlite_types::DbColumnType::Date,
+            sqlite_types::DbColumnType::Time,
+            sqlite_types::DbColumnType::Datetime,
+            sqlite_types::DbColumnType::Null,
+        ]
+    }
+
+    pub(crate) fn get_test_db_values() -> Vec<sqlite_types::DbValue> {
+        vec![
+            sqlite_types::DbValue::Integer(1),
+            sqlite_types::DbValue::Real(2.5),
+            sqlite_types::DbValue::Text(format!("name-{}", Uuid::new_v4())),
+            sqlite_types::DbValue::Blob("Blob".as_bytes().to_vec()),
+            sqlite_types::DbValue::Boolean(true),
+            sqlite_types::DbValue::Date(chrono::NaiveDate::from_ymd_opt(2030, 10, 12).unwrap()),
+            sqlite_types::DbValue::Time(chrono::NaiveTime::from_hms_opt(1, 20, 30).unwrap()),
+            sqlite_types::DbValue::Datetime(chrono::NaiveDateTime::new(
+                chrono::NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(10, 20, 30).unwrap(),
+            )),
+            sqlite_types::DbValue::Null,
+        ]
+    }
+}
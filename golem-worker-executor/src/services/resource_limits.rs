@@ -75,12 +75,27 @@ pub trait ResourceLimits: Send + Sync {
     ) -> Result<(), WorkerExecutorError>;
 
     async fn get_max_memory(&self, account_id: &AccountId) -> Result<usize, WorkerExecutorError>;
+
+    /// Tries to reserve storage for a worker's file system. Returns false if the account does not
+    /// have enough storage available.
+    async fn reserve_storage(
+        &self,
+        account_id: &AccountId,
+        amount: u64,
+    ) -> Result<bool, WorkerExecutorError>;
+
+    /// Unconditionally records a change in the storage used by the file systems of an account's workers.
+    ///
+    /// Negative values release previously reserved storage. Positive values are used for growth
+    /// which cannot be rejected, such as file system writes performed while replaying a worker.
+    fn update_storage_usage(&self, account_id: &AccountId, delta: i64);
 }
 
 #[derive(Debug, Clone)]
 pub struct CurrentResourceLimitsEntry {
     limits: CurrentResourceLimits,
     delta: i64,
+    storage_delta: i64,
 }
 
 /// The default ResourceLimits implementation
@@ -92,6 +107,8 @@ pub struct ResourceLimitsGrpc {
     access_token: Uuid,
     retry_config: RetryConfig,
     current_limits: DashMap<AccountId, CurrentResourceLimitsEntry>,
+    /// Storage usage changes of accounts whose limits are not cached yet
+    pending_storage_updates: DashMap<AccountId, i64>,
     background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
 }
 
@@ -99,9 +116,14 @@ impl ResourceLimitsGrpc {
     async fn send_batch_updates(
         &self,
         updates: HashMap<AccountId, i64>,
+        storage_updates: HashMap<AccountId, i64>,
     ) -> Result<(), WorkerExecutorError> {
         let body = BatchUpdateResourceLimits {
             updates: updates.into_iter().map(|(k, v)| (k.value, v)).collect(),
+            storage_updates: storage_updates
+                .into_iter()
+                .map(|(k, v)| (k.value, v))
+                .collect(),
         };
         with_retries(
             "resource_limits",
@@ -198,6 +220,42 @@ impl ResourceLimitsGrpc {
         updates
     }
 
+    /// Takes all recorded storage usage updates and resets them to 0
+    fn take_all_storage_updates(&self) -> HashMap<AccountId, i64> {
+        let mut updates = HashMap::new();
+        for mut entry in self.current_limits.iter_mut() {
+            if entry.storage_delta != 0 {
+                updates.insert(entry.key().clone(), entry.storage_delta);
+                entry.storage_delta = 0;
+            }
+        }
+        let pending_accounts = self
+            .pending_storage_updates
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+        for account_id in pending_accounts {
+            if let Some((account_id, delta)) = self.pending_storage_updates.remove(&account_id) {
+                if delta != 0 {
+                    *updates.entry(account_id).or_default() += delta;
+                }
+            }
+        }
+        updates
+    }
+
+    async fn ensure_limits_cached(
+        &self,
+        account_id: &AccountId,
+    ) -> Result<(), WorkerExecutorError> {
+        if !self.current_limits.contains_key(account_id) {
+            let fetched_limits = self.fetch_resource_limits(account_id).await?;
+            self.update_last_known_limits(account_id, &fetched_limits)
+                .await?;
+        }
+        Ok(())
+    }
+
     pub fn new(
         endpoint: Uri,
         access_token: Uuid,
@@ -209,6 +267,7 @@ impl ResourceLimitsGrpc {
             access_token,
             retry_config,
             current_limits: DashMap::new(),
+            pending_storage_updates: DashMap::new(),
             background_handle: Arc::new(Mutex::new(None)),
         };
         let svc = Arc::new(svc);
@@ -217,14 +276,21 @@ impl ResourceLimitsGrpc {
             loop {
                 tokio::time::sleep(batch_update_interval).await;
                 let updates = svc_clone.take_all_fuel_updates();
-                if !updates.is_empty() {
-                    let r = svc_clone.send_batch_updates(updates.clone()).await;
+                let storage_updates = svc_clone.take_all_storage_updates();
+                if !updates.is_empty() || !storage_updates.is_empty() {
+                    let r = svc_clone
+                        .send_batch_updates(updates.clone(), storage_updates.clone())
+                        .await;
                     if let Err(err) = r {
                         error!("Failed to send batched resource usage updates: {}", err);
                         error!(
                             "The following fuel consumption records were lost: {:?}",
                             updates
                         );
+                        error!(
+                            "The following storage usage records were lost: {:?}",
+                            storage_updates
+                        );
                     }
                 }
             }
@@ -306,10 +372,24 @@ impl ResourceLimits for ResourceLimitsGrpc {
             .and_modify(|entry| {
                 entry.limits.fuel = last_known_limits.fuel + entry.delta;
                 entry.limits.max_memory = last_known_limits.max_memory;
+                entry.limits.available_storage =
+                    last_known_limits.available_storage - entry.storage_delta;
             })
-            .or_insert(CurrentResourceLimitsEntry {
-                limits: last_known_limits.clone(),
-                delta: 0,
+            .or_insert_with(|| {
+                // Storage usage changes recorded before the limits got cached are not part of the last known limits yet
+                let storage_delta = self
+                    .pending_storage_updates
+                    .remove(account_id)
+                    .map(|(_, delta)| delta)
+                    .unwrap_or_default();
+                CurrentResourceLimitsEntry {
+                    limits: CurrentResourceLimits {
+                        available_storage: last_known_limits.available_storage - storage_delta,
+                        ..last_known_limits.clone()
+                    },
+                    delta: 0,
+                    storage_delta,
+                }
             });
         Ok(())
     }
@@ -329,6 +409,52 @@ impl ResourceLimits for ResourceLimitsGrpc {
             }
         }
     }
+
+    async fn reserve_storage(
+        &self,
+        account_id: &AccountId,
+        amount: u64,
+    ) -> Result<bool, WorkerExecutorError> {
+        self.ensure_limits_cached(account_id).await?;
+
+        let amount = amount as i64;
+        let mut reserved = false;
+        self.current_limits
+            .entry(account_id.clone())
+            .and_modify(|entry| {
+                if entry.limits.available_storage >= amount {
+                    entry.limits.available_storage -= amount;
+                    entry.storage_delta += amount;
+                    reserved = true;
+                }
+            });
+        Ok(reserved)
+    }
+
+    fn update_storage_usage(&self, account_id: &AccountId, delta: i64) {
+        match self.current_limits.get_mut(account_id) {
+            Some(mut entry) => {
+                entry.limits.available_storage -= delta;
+                entry.storage_delta += delta;
+            }
+            None => {
+                // Kept until the limits get cached, or reported with the next batch update
+                *self
+                    .pending_storage_updates
+                    .entry(account_id.clone())
+                    .or_default() += delta;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+impl ResourceLimitsGrpc {
+    pub fn cached_available_storage(&self, account_id: &AccountId) -> Option<i64> {
+        self.current_limits
+            .get(account_id)
+            .map(|entry| entry.limits.available_storage)
+    }
 }
 
 fn authorised_request<T>(request: T, access_token: &Uuid) -> Request<T> {
@@ -340,7 +466,7 @@ fn authorised_request<T>(request: T, access_token: &Uuid) -> Request<T> {
     req
 }
 
-struct ResourceLimitsDisabled;
+pub struct ResourceLimitsDisabled;
 
 impl ResourceLimitsDisabled {
     pub fn new() -> Arc<Self> {
@@ -381,6 +507,16 @@ impl ResourceLimits for ResourceLimitsDisabled {
     async fn get_max_memory(&self, _account_id: &AccountId) -> Result<usize, WorkerExecutorError> {
         Ok(usize::MAX)
     }
+
+    async fn reserve_storage(
+        &self,
+        _account_id: &AccountId,
+        _amount: u64,
+    ) -> Result<bool, WorkerExecutorError> {
+        Ok(true)
+    }
+
+    fn update_storage_usage(&self, _account_id: &AccountId, _delta: i64) {}
 }
 
 pub fn configured(config: &ResourceLimitsConfig) -> Arc<dyn ResourceLimits + Send + Sync> {
@@ -449,4 +585,77 @@ impl ResourceLimits for ResourceLimitsMock {
     async fn get_max_memory(&self, _account_id: &AccountId) -> Result<usize, WorkerExecutorError> {
         unimplemented!()
     }
+
+    async fn reserve_storage(
+        &self,
+        _account_id: &AccountId,
+        _amount: u64,
+    ) -> Result<bool, WorkerExecutorError> {
+        unimplemented!()
+    }
+
+    fn update_storage_usage(&self, _account_id: &AccountId, _delta: i64) {
+        unimplemented!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ResourceLimits, ResourceLimitsGrpc};
+    use crate::model::CurrentResourceLimits;
+    use assert2::check;
+    use golem_common::model::{AccountId, RetryConfig};
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use test_r::test;
+    use uuid::Uuid;
+
+    #[test]
+    async fn storage_usage_of_uncached_accounts_is_applied_when_cached() {
+        let limits = ResourceLimitsGrpc::new(
+            "http://localhost:9090".parse().unwrap(),
+            Uuid::new_v4(),
+            RetryConfig::default(),
+            Duration::from_secs(3600),
+        );
+        let account_id = AccountId {
+            value: "account".to_string(),
+        };
+
+        limits.update_storage_usage(&account_id, 30);
+        limits
+            .update_last_known_limits(
+                &account_id,
+                &CurrentResourceLimits {
+                    fuel: 0,
+                    max_memory: 0,
+                    available_storage: 100,
+                },
+            )
+            .await
+            .unwrap();
+
+        check!(limits.cached_available_storage(&account_id) == Some(70));
+        check!(limits.take_all_storage_updates() == HashMap::from([(account_id, 30)]));
+    }
+
+    #[test]
+    async fn storage_usage_of_uncached_accounts_is_reported() {
+        let limits = ResourceLimitsGrpc::new(
+            "http://localhost:9090".parse().unwrap(),
+            Uuid::new_v4(),
+            RetryConfig::default(),
+            Duration::from_secs(3600),
+        );
+        let account_id = AccountId {
+            value: "account".to_string(),
+        };
+
+        limits.update_storage_usage(&account_id, 30);
+        limits.update_storage_usage(&account_id, -10);
+
+        check!(limits.cached_available_storage(&account_id).is_none());
+        check!(limits.take_all_storage_updates() == HashMap::from([(account_id, 20)]));
+        check!(limits.take_all_storage_updates().is_empty());
+    }
 }
@@ -25,20 +25,21 @@ use crate::services::resource_limits::ResourceLimits;
 use crate::services::shard::ShardService;
 use crate::services::worker_proxy::{WorkerProxy, WorkerProxyError};
 use crate::services::{
-    active_workers, blob_store, component, golem_config, key_value, oplog, promise, rdbms,
-    scheduler, shard_manager, worker, worker_activator, worker_enumeration, worker_fork,
+    active_workers, blob_store, component, golem_config, key_value, oplog, promise, pubsub, rdbms,
+    saga, scheduler, shard_manager, worker, worker_activator, worker_enumeration, worker_fork,
     HasActiveWorkers, HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps,
     HasFileLoader, HasKeyValueService, HasOplogProcessorPlugin, HasOplogService, HasPlugins,
-    HasProjectService, HasPromiseService, HasRdbmsService, HasResourceLimits, HasRpc,
-    HasRunningWorkerEnumerationService, HasSchedulerService, HasShardManagerService,
-    HasShardService, HasWasmtimeEngine, HasWorkerActivator, HasWorkerEnumerationService,
-    HasWorkerForkService, HasWorkerProxy, HasWorkerService,
+    HasProjectService, HasPromiseService, HasPubSubService, HasRdbmsService, HasResourceLimits,
+    HasRpc, HasRunningWorkerEnumerationService, HasSagaService, HasSchedulerService,
+    HasShardManagerService, HasShardService, HasWasmtimeEngine, HasWorkerActivator,
+    HasWorkerEnumerationService, HasWorkerForkService, HasWorkerProxy, HasWorkerService,
 };
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
 use bincode::{Decode, Encode};
 use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::oplog::InvocationPriority;
 use golem_common::model::{AccountId, IdempotencyKey, OwnedWorkerId, TargetWorkerId, WorkerId};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
 use golem_wasm_rpc::{ValueAndType, WitValue};
@@ -298,6 +299,8 @@ pub struct DirectWorkerInvocationRpc<Ctx: WorkerCtx> {
     running_worker_enumeration_service:
         Arc<dyn worker_enumeration::RunningWorkerEnumerationService>,
     promise_service: Arc<dyn promise::PromiseService>,
+    pubsub_service: Arc<dyn pubsub::PubSubService>,
+    saga_service: Arc<dyn saga::SagaService>,
     golem_config: Arc<golem_config::GolemConfig>,
     shard_service: Arc<dyn ShardService>,
     key_value_service: Arc<dyn key_value::KeyValueService>,
@@ -330,6 +333,8 @@ impl<Ctx: WorkerCtx> Clone for DirectWorkerInvocationRpc<Ctx> {
             worker_enumeration_service: self.worker_enumeration_service.clone(),
             running_worker_enumeration_service: self.running_worker_enumeration_service.clone(),
             promise_service: self.promise_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            saga_service: self.saga_service.clone(),
             golem_config: self.golem_config.clone(),
             shard_service: self.shard_service.clone(),
             key_value_service: self.key_value_service.clone(),
@@ -399,6 +404,18 @@ impl<Ctx: WorkerCtx> HasPromiseService for DirectWorkerInvocationRpc<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasPubSubService for DirectWorkerInvocationRpc<Ctx> {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService> {
+        self.pubsub_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasSagaService for DirectWorkerInvocationRpc<Ctx> {
+    fn saga_service(&self) -> Arc<dyn saga::SagaService> {
+        self.saga_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasWasmtimeEngine<Ctx> for DirectWorkerInvocationRpc<Ctx> {
     fn engine(&self) -> Arc<wasmtime::Engine> {
         self.engine.clone()
@@ -532,6 +549,8 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             dyn worker_enumeration::RunningWorkerEnumerationService,
         >,
         promise_service: Arc<dyn promise::PromiseService>,
+        pubsub_service: Arc<dyn pubsub::PubSubService>,
+        saga_service: Arc<dyn saga::SagaService>,
         golem_config: Arc<golem_config::GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         shard_manager_service: Arc<dyn shard_manager::ShardManagerService>,
@@ -562,6 +581,8 @@ impl<Ctx: WorkerCtx> DirectWorkerInvocationRpc<Ctx> {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config,
             shard_service,
             key_value_service,
@@ -705,7 +726,13 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
             .await?;
 
             let result_value = worker
-                .invoke_and_await(idempotency_key, function_name, input_values, self_stack)
+                .invoke_and_await(
+                    idempotency_key,
+                    function_name,
+                    input_values,
+                    self_stack,
+                    InvocationPriority::Normal,
+                )
                 .await?;
 
             Ok(result_value)
@@ -770,7 +797,13 @@ impl<Ctx: WorkerCtx> Rpc for DirectWorkerInvocationRpc<Ctx> {
             .await?;
 
             worker
-                .invoke(idempotency_key, function_name, input_values, self_stack)
+                .invoke(
+                    idempotency_key,
+                    function_name,
+                    input_values,
+                    self_stack,
+                    InvocationPriority::Normal,
+                )
                 .await?;
             Ok(())
         } else {
@@ -0,0 +1,1353 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::durable_host::serialized::SerializableError;
+use crate::services::oplog::OplogService;
+use crate::services::rpc::Rpc;
+use crate::services::shard::ShardService;
+use crate::services::{HasAll, HasConfig, HasRpc, HasSagaService};
+use crate::storage::keyvalue::{
+    KeyValueStorage, KeyValueStorageLabelledApi, KeyValueStorageNamespace,
+};
+use crate::worker::Worker;
+use crate::workerctx::WorkerCtx;
+use async_trait::async_trait;
+use bincode::{Decode, Encode};
+use dashmap::DashMap;
+use golem_common::model::invocation_context::InvocationContextStack;
+use golem_common::model::oplog::{OplogEntry, OplogIndex};
+use golem_common::model::{
+    AccountId, IdempotencyKey, OwnedWorkerId, RetryConfig, SagaId, Timestamp, WorkerId,
+};
+use golem_common::retries::RetryState;
+use golem_common::serialization::deserialize;
+use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::saga::{Saga, SagaCompensation, SagaStatus};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use tracing::{debug, span, warn, Instrument, Level};
+use uuid::Uuid;
+
+pub const BEGIN_FUNCTION_NAME: &str = "golem::api::saga::begin";
+pub const REGISTER_COMPENSATION_FUNCTION_NAME: &str = "golem::api::saga::register_compensation";
+pub const COMPLETE_FUNCTION_NAME: &str = "golem::api::saga::complete";
+
+/// Number of oplog entries read at once when collecting the sagas of a coordinator
+const OPLOG_READ_PAGE_SIZE: u64 = 1024;
+
+/// Service tracking the sagas coordinated by workers.
+///
+/// Starting a saga, registering its compensations and completing it is recorded in the oplog of the
+/// coordinator, and the sagas are collected from there, including the reverted regions of the oplog.
+/// This way reverting the coordinator does not lose the compensation plan. Only the progress of the
+/// compensation of aborted sagas is stored by the service itself. Performing the compensations is
+/// done by `start_compensation`.
+#[async_trait]
+pub trait SagaService: Send + Sync {
+    /// Gets exclusive access to the saga until the returned lock is dropped. Registering a
+    /// compensation or completing the saga has to happen while holding it, so it cannot race with
+    /// aborting the saga.
+    async fn lock(&self, owned_worker_id: &OwnedWorkerId, saga_id: &SagaId) -> SagaLock;
+
+    /// Fails if the saga has been aborted, so it cannot get new compensations or be completed anymore
+    async fn ensure_not_aborted(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Result<(), WorkerExecutorError>;
+
+    /// Marks the saga as compensating and returns its new state. Aborting a saga which is already
+    /// compensating is allowed, and aborting a saga whose compensation failed makes it compensating again.
+    async fn abort(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        reason: &str,
+    ) -> Result<Saga, WorkerExecutorError>;
+
+    async fn get(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Result<Option<Saga>, WorkerExecutorError>;
+
+    /// Gets all the sagas coordinated by the worker, oldest first
+    async fn get_all(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<Saga>, WorkerExecutorError>;
+
+    /// Records that the compensation of the given step has been performed. Recording the same
+    /// step again has no effect.
+    async fn compensation_performed(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        step: usize,
+    ) -> Result<(), WorkerExecutorError>;
+
+    async fn compensation_failed(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        error: String,
+    ) -> Result<(), WorkerExecutorError>;
+
+    /// Gets the compensating sagas whose coordinator belongs to the shards assigned to this executor
+    async fn get_compensating(&self) -> Result<Vec<(OwnedWorkerId, SagaId)>, WorkerExecutorError>;
+
+    /// Whether the coordinator belongs to the shards assigned to this executor, so this executor is
+    /// the one responsible for compensating its sagas
+    fn is_coordinated_here(&self, owned_worker_id: &OwnedWorkerId) -> bool;
+
+    /// Gets exclusive access to performing the compensations of the saga, or `None` if they are
+    /// already being performed by this executor
+    fn try_lock_compensation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Option<SagaLock>;
+
+    /// Removes the state of all the sagas coordinated by the worker
+    async fn delete_all(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), WorkerExecutorError>;
+}
+
+type SagaKey = (OwnedWorkerId, SagaId);
+type SagaLocks = Arc<DashMap<SagaKey, Arc<Mutex<()>>>>;
+
+/// Exclusive access to a saga, released when dropped
+pub struct SagaLock {
+    locks: SagaLocks,
+    key: SagaKey,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl SagaLock {
+    async fn acquire(locks: &SagaLocks, key: SagaKey) -> Self {
+        let lock = locks.entry(key.clone()).or_default().clone();
+        let guard = lock.lock_owned().await;
+        Self {
+            locks: locks.clone(),
+            key,
+            guard: Some(guard),
+        }
+    }
+
+    fn try_acquire(locks: &SagaLocks, key: SagaKey) -> Option<Self> {
+        let lock = locks.entry(key.clone()).or_default().clone();
+        let guard = lock.try_lock_owned().ok()?;
+        Some(Self {
+            locks: locks.clone(),
+            key,
+            guard: Some(guard),
+        })
+    }
+}
+
+impl Drop for SagaLock {
+    fn drop(&mut self) {
+        self.guard.take();
+        // Only the sagas somebody is waiting for are kept in the map
+        self.locks
+            .remove_if(&self.key, |_, lock| Arc::strong_count(lock) == 1);
+    }
+}
+
+/// The progress of the compensation of an aborted saga
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct AbortedSaga {
+    status: SagaStatus,
+    compensated: u32,
+    abort_reason: String,
+    error: Option<String>,
+}
+
+impl AbortedSaga {
+    fn apply_to(self, saga: &mut Saga) {
+        saga.status = self.status;
+        saga.compensated = self.compensated;
+        saga.abort_reason = Some(self.abort_reason);
+        saga.error = self.error;
+    }
+}
+
+pub struct DefaultSagaService {
+    key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+    oplog_service: Arc<dyn OplogService>,
+    shard_service: Arc<dyn ShardService>,
+    locks: SagaLocks,
+    compensation_locks: SagaLocks,
+}
+
+impl DefaultSagaService {
+    /// Key of the set of all the compensating sagas, used for resuming their compensation
+    const COMPENSATING_KEY: &'static str = "saga:compensating";
+
+    pub fn new(
+        key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+        oplog_service: Arc<dyn OplogService>,
+        shard_service: Arc<dyn ShardService>,
+    ) -> Self {
+        Self {
+            key_value_storage,
+            oplog_service,
+            shard_service,
+            locks: Arc::new(DashMap::new()),
+            compensation_locks: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Collects the sagas of the coordinator from its whole oplog
+    async fn read_sagas(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<BTreeMap<SagaId, Saga>, WorkerExecutorError> {
+        let last_index = self.oplog_service.get_last_index(owned_worker_id).await;
+        let mut sagas = BTreeMap::new();
+        let mut idx = OplogIndex::INITIAL;
+        while idx <= last_index {
+            let entries = self
+                .oplog_service
+                .read(owned_worker_id, idx, OPLOG_READ_PAGE_SIZE)
+                .await;
+            for (idx, entry) in &entries {
+                if let OplogEntry::ImportedFunctionInvoked {
+                    timestamp,
+                    function_name,
+                    request,
+                    response,
+                    ..
+                } = entry
+                {
+                    if function_name == BEGIN_FUNCTION_NAME
+                        || function_name == REGISTER_COMPENSATION_FUNCTION_NAME
+                        || function_name == COMPLETE_FUNCTION_NAME
+                    {
+                        let request = self
+                            .oplog_service
+                            .download_payload(owned_worker_id, request)
+                            .await
+                            .map_err(WorkerExecutorError::runtime)?;
+                        let response = self
+                            .oplog_service
+                            .download_payload(owned_worker_id, response)
+                            .await
+                            .map_err(WorkerExecutorError::runtime)?;
+                        apply_recorded_call(
+                            &mut sagas,
+                            &owned_worker_id.worker_id,
+                            *idx,
+                            *timestamp,
+                            function_name,
+                            &request,
+                            &response,
+                        )
+                        .map_err(WorkerExecutorError::runtime)?;
+                    }
+                }
+            }
+            idx = OplogIndex::from_u64(u64::from(idx) + OPLOG_READ_PAGE_SIZE);
+        }
+        Ok(sagas)
+    }
+
+    async fn get_aborted(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Result<Option<AbortedSaga>, WorkerExecutorError> {
+        self.key_value_storage
+            .with_entity("saga", "get_aborted", "aborted_saga")
+            .get(
+                KeyValueStorageNamespace::Saga,
+                &Self::saga_key(owned_worker_id, saga_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    /// Updates the state of an aborted saga, keeping the set of compensating sagas in sync with it
+    async fn set_aborted(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        aborted: &AbortedSaga,
+    ) -> Result<(), WorkerExecutorError> {
+        self.key_value_storage
+            .with_entity("saga", "set_aborted", "aborted_saga")
+            .set(
+                KeyValueStorageNamespace::Saga,
+                &Self::saga_key(owned_worker_id, saga_id),
+                aborted,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        self.key_value_storage
+            .with_entity("saga", "set_aborted", "saga_id")
+            .add_to_set(
+                KeyValueStorageNamespace::Saga,
+                &Self::worker_key(owned_worker_id),
+                saga_id,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+
+        let compensating =
+            self.key_value_storage
+                .with_entity("saga", "set_aborted", "compensating_saga");
+        let key = (owned_worker_id.clone(), saga_id.clone());
+        if aborted.status == SagaStatus::Compensating {
+            compensating
+                .add_to_set(KeyValueStorageNamespace::Saga, Self::COMPENSATING_KEY, &key)
+                .await
+        } else {
+            compensating
+                .remove_from_set(KeyValueStorageNamespace::Saga, Self::COMPENSATING_KEY, &key)
+                .await
+        }
+        .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn update_aborted(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        f: impl FnOnce(&Saga, &mut AbortedSaga) + Send,
+    ) -> Result<(), WorkerExecutorError> {
+        let _lock =
+            SagaLock::acquire(&self.locks, (owned_worker_id.clone(), saga_id.clone())).await;
+        let saga = self
+            .get(owned_worker_id, saga_id)
+            .await?
+            .ok_or_else(|| saga_not_found(saga_id))?;
+        let mut aborted = self
+            .get_aborted(owned_worker_id, saga_id)
+            .await?
+            .ok_or_else(|| {
+                WorkerExecutorError::invalid_request(format!("Saga {saga_id} is not aborted"))
+            })?;
+        f(&saga, &mut aborted);
+        self.set_aborted(owned_worker_id, saga_id, &aborted).await
+    }
+
+    fn saga_key(owned_worker_id: &OwnedWorkerId, saga_id: &SagaId) -> String {
+        format!("saga:{owned_worker_id}/{saga_id}")
+    }
+
+    fn worker_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!("saga:worker:{owned_worker_id}")
+    }
+}
+
+#[async_trait]
+impl SagaService for DefaultSagaService {
+    async fn lock(&self, owned_worker_id: &OwnedWorkerId, saga_id: &SagaId) -> SagaLock {
+        SagaLock::acquire(&self.locks, (owned_worker_id.clone(), saga_id.clone())).await
+    }
+
+    async fn ensure_not_aborted(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Result<(), WorkerExecutorError> {
+        if self.get_aborted(owned_worker_id, saga_id).await?.is_some() {
+            Err(WorkerExecutorError::invalid_request(format!(
+                "Saga {saga_id} is not running"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn abort(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        reason: &str,
+    ) -> Result<Saga, WorkerExecutorError> {
+        let _lock =
+            SagaLock::acquire(&self.locks, (owned_worker_id.clone(), saga_id.clone())).await;
+        let mut saga = self
+            .get(owned_worker_id, saga_id)
+            .await?
+            .ok_or_else(|| saga_not_found(saga_id))?;
+
+        let aborted = match saga.status {
+            SagaStatus::Running => AbortedSaga {
+                status: if saga.remaining_compensations().next().is_some() {
+                    SagaStatus::Compensating
+                } else {
+                    SagaStatus::Compensated
+                },
+                compensated: 0,
+                abort_reason: reason.to_string(),
+                error: None,
+            },
+            SagaStatus::CompensationFailed | SagaStatus::Compensating => AbortedSaga {
+                status: SagaStatus::Compensating,
+                compensated: saga.compensated,
+                abort_reason: saga.abort_reason.clone().unwrap_or_default(),
+                error: None,
+            },
+            SagaStatus::Completed | SagaStatus::Compensated => {
+                return Err(WorkerExecutorError::invalid_request(format!(
+                    "Saga {saga_id} has already finished"
+                )))
+            }
+        };
+        self.set_aborted(owned_worker_id, saga_id, &aborted).await?;
+        aborted.apply_to(&mut saga);
+        Ok(saga)
+    }
+
+    async fn get(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Result<Option<Saga>, WorkerExecutorError> {
+        let Some(mut saga) = self.read_sagas(owned_worker_id).await?.remove(saga_id) else {
+            return Ok(None);
+        };
+        if let Some(aborted) = self.get_aborted(owned_worker_id, saga_id).await? {
+            aborted.apply_to(&mut saga);
+        }
+        Ok(Some(saga))
+    }
+
+    async fn get_all(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<Saga>, WorkerExecutorError> {
+        let mut sagas: Vec<Saga> = self
+            .read_sagas(owned_worker_id)
+            .await?
+            .into_values()
+            .collect();
+        let aborted: Vec<Option<AbortedSaga>> = self
+            .key_value_storage
+            .with_entity("saga", "get_all", "aborted_saga")
+            .get_many(
+                KeyValueStorageNamespace::Saga,
+                sagas
+                    .iter()
+                    .map(|saga| Self::saga_key(owned_worker_id, &saga.saga_id))
+                    .collect(),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        for (saga, aborted) in sagas.iter_mut().zip(aborted) {
+            if let Some(aborted) = aborted {
+                aborted.apply_to(saga);
+            }
+        }
+        sagas.sort_by_key(|saga| saga.oplog_index);
+        Ok(sagas)
+    }
+
+    async fn compensation_performed(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        step: usize,
+    ) -> Result<(), WorkerExecutorError> {
+        self.update_aborted(owned_worker_id, saga_id, |saga, aborted| {
+            let remaining = saga
+                .compensations
+                .len()
+                .saturating_sub(aborted.compensated as usize);
+            if remaining > 0 && remaining - 1 == step {
+                aborted.compensated += 1;
+                if remaining == 1 {
+                    aborted.status = SagaStatus::Compensated;
+                }
+            }
+        })
+        .await
+    }
+
+    async fn compensation_failed(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+        error: String,
+    ) -> Result<(), WorkerExecutorError> {
+        self.update_aborted(owned_worker_id, saga_id, |_, aborted| {
+            aborted.status = SagaStatus::CompensationFailed;
+            aborted.error = Some(error);
+        })
+        .await
+    }
+
+    async fn get_compensating(&self) -> Result<Vec<(OwnedWorkerId, SagaId)>, WorkerExecutorError> {
+        let compensating: Vec<(OwnedWorkerId, SagaId)> = self
+            .key_value_storage
+            .with_entity("saga", "get_compensating", "compensating_saga")
+            .members_of_set(KeyValueStorageNamespace::Saga, Self::COMPENSATING_KEY)
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        Ok(compensating
+            .into_iter()
+            .filter(|(owned_worker_id, _)| self.is_coordinated_here(owned_worker_id))
+            .collect())
+    }
+
+    fn is_coordinated_here(&self, owned_worker_id: &OwnedWorkerId) -> bool {
+        self.shard_service
+            .check_worker(&owned_worker_id.worker_id)
+            .is_ok()
+    }
+
+    fn try_lock_compensation(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        saga_id: &SagaId,
+    ) -> Option<SagaLock> {
+        SagaLock::try_acquire(
+            &self.compensation_locks,
+            (owned_worker_id.clone(), saga_id.clone()),
+        )
+    }
+
+    async fn delete_all(&self, owned_worker_id: &OwnedWorkerId) -> Result<(), WorkerExecutorError> {
+        let saga_ids: Vec<SagaId> = self
+            .key_value_storage
+            .with_entity("saga", "delete_all", "saga_id")
+            .members_of_set(
+                KeyValueStorageNamespace::Saga,
+                &Self::worker_key(owned_worker_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        for saga_id in &saga_ids {
+            self.key_value_storage
+                .with_entity("saga", "delete_all", "compensating_saga")
+                .remove_from_set(
+                    KeyValueStorageNamespace::Saga,
+                    Self::COMPENSATING_KEY,
+                    &(owned_worker_id.clone(), saga_id.clone()),
+                )
+                .await
+                .map_err(WorkerExecutorError::runtime)?;
+        }
+        let mut keys: Vec<String> = saga_ids
+            .iter()
+            .map(|saga_id| Self::saga_key(owned_worker_id, saga_id))
+            .collect();
+        keys.push(Self::worker_key(owned_worker_id));
+        self.key_value_storage
+            .with("saga", "delete_all")
+            .del_many(KeyValueStorageNamespace::Saga, keys)
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+}
+
+fn saga_not_found(saga_id: &SagaId) -> WorkerExecutorError {
+    WorkerExecutorError::invalid_request(format!("Saga {saga_id} not found"))
+}
+
+/// Applies a recorded saga call of the coordinator to its sagas, unless the call failed
+fn apply_recorded_call(
+    sagas: &mut BTreeMap<SagaId, Saga>,
+    coordinator: &WorkerId,
+    oplog_index: OplogIndex,
+    timestamp: Timestamp,
+    function_name: &str,
+    request: &[u8],
+    response: &[u8],
+) -> Result<(), String> {
+    if function_name == BEGIN_FUNCTION_NAME {
+        let response: Result<Uuid, SerializableError> = deserialize(response)?;
+        if let Ok(saga_id) = response {
+            let saga_id = SagaId(saga_id);
+            sagas.insert(
+                saga_id.clone(),
+                Saga::new(saga_id, coordinator.clone(), timestamp, oplog_index),
+            );
+        }
+    } else {
+        let response: Result<Result<(), String>, SerializableError> = deserialize(response)?;
+        if !matches!(response, Ok(Ok(()))) {
+            return Ok(());
+        }
+
+        if function_name == REGISTER_COMPENSATION_FUNCTION_NAME {
+            let (saga_id, compensation): (Uuid, SagaCompensation) = deserialize(request)?;
+            if let Some(saga) = sagas.get_mut(&SagaId(saga_id)) {
+                saga.compensations.push(compensation);
+            }
+        } else {
+            let saga_id: Uuid = deserialize(request)?;
+            if let Some(saga) = sagas.get_mut(&SagaId(saga_id)) {
+                saga.status = SagaStatus::Completed;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Idempotency key of the invocation compensating a step of a saga. Performing the compensation
+/// again, for example after the executor got restarted, does not invoke the function twice.
+pub fn compensation_idempotency_key(saga_id: &SagaId, step: usize) -> IdempotencyKey {
+    IdempotencyKey::from_uuid(Uuid::new_v5(
+        &saga_id.0,
+        format!("compensation-{step}").as_bytes(),
+    ))
+}
+
+/// Performs the remaining compensations of an aborted saga in the background, starting from the
+/// last registered one. Each compensation is awaited before starting the previous one. A failing
+/// compensation is retried according to the given retry config, and if it keeps failing the process
+/// stops, leaving the saga in the `CompensationFailed` status.
+///
+/// The saga stays in the `Compensating` status until it is finished, so if the executor stops in the
+/// meantime, the compensation is resumed by `resume_compensations` of the executor the coordinator
+/// gets assigned to.
+pub fn start_compensation(
+    saga_service: Arc<dyn SagaService>,
+    rpc: Arc<dyn Rpc>,
+    owned_worker_id: OwnedWorkerId,
+    created_by: AccountId,
+    retry_config: RetryConfig,
+    saga_id: SagaId,
+) {
+    let span = span!(
+        Level::INFO,
+        "saga-compensation",
+        worker_id = owned_worker_id.worker_id.to_string(),
+        saga_id = saga_id.to_string(),
+    );
+    tokio::spawn(
+        async move {
+            loop {
+                let Some(lock) = saga_service.try_lock_compensation(&owned_worker_id, &saga_id)
+                else {
+                    debug!("Saga {saga_id} is already being compensated");
+                    break;
+                };
+                let failed = match compensate(
+                    saga_service.as_ref(),
+                    rpc.as_ref(),
+                    &owned_worker_id,
+                    &created_by,
+                    &retry_config,
+                    &saga_id,
+                )
+                .await
+                {
+                    Ok(failed) => failed,
+                    Err(err) => {
+                        warn!("Failed to compensate saga {saga_id}: {err}");
+                        break;
+                    }
+                };
+                drop(lock);
+
+                // The saga may have been aborted again after its compensation failed, but before
+                // the lock got released, so nobody else could start compensating it
+                let compensating = matches!(
+                    saga_service.get(&owned_worker_id, &saga_id).await,
+                    Ok(Some(saga)) if saga.status == SagaStatus::Compensating
+                );
+                if !failed || !compensating || !saga_service.is_coordinated_here(&owned_worker_id) {
+                    break;
+                }
+            }
+        }
+        .instrument(span),
+    );
+}
+
+/// Aborts the running sagas of the worker which were started at or after the given oplog index, and
+/// starts compensating them. Used when the coordinator fails permanently, or when the part of its
+/// oplog which started the sagas gets reverted.
+pub async fn abort_running_sagas(
+    saga_service: Arc<dyn SagaService>,
+    rpc: Arc<dyn Rpc>,
+    owned_worker_id: &OwnedWorkerId,
+    created_by: &AccountId,
+    retry_config: &RetryConfig,
+    started_at_or_after: OplogIndex,
+    reason: &str,
+) -> Result<(), WorkerExecutorError> {
+    let sagas = saga_service.get_all(owned_worker_id).await?;
+    for saga in sagas {
+        if saga.status == SagaStatus::Running && saga.oplog_index >= started_at_or_after {
+            debug!("Aborting saga {}: {reason}", saga.saga_id);
+            saga_service
+                .abort(owned_worker_id, &saga.saga_id, reason)
+                .await?;
+            start_compensation(
+                saga_service.clone(),
+                rpc.clone(),
+                owned_worker_id.clone(),
+                created_by.clone(),
+                retry_config.clone(),
+                saga.saga_id,
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Resumes compensating the sagas which were left in the `Compensating` status by a stopped
+/// executor, or by the executor previously owning the shard of their coordinator. Called on startup
+/// and whenever the shard assignment changes.
+pub async fn resume_compensations<Ctx: WorkerCtx, T: HasAll<Ctx> + Send + Sync>(
+    this: &T,
+) -> Result<(), WorkerExecutorError> {
+    for (owned_worker_id, saga_id) in this.saga_service().get_compensating().await? {
+        let Some(metadata) = Worker::<Ctx>::get_latest_metadata(this, &owned_worker_id).await?
+        else {
+            continue;
+        };
+        debug!("Resuming the compensation of saga {saga_id} of worker {owned_worker_id}");
+        let retry_config = metadata
+            .last_known_status
+            .overridden_retry_config
+            .unwrap_or_else(|| this.config().retry.clone());
+        start_compensation(
+            this.saga_service(),
+            this.rpc(),
+            owned_worker_id,
+            metadata.created_by,
+            retry_config,
+            saga_id,
+        );
+    }
+    Ok(())
+}
+
+/// Performs the remaining compensations of the saga, returning whether one of them failed
+async fn compensate(
+    saga_service: &dyn SagaService,
+    rpc: &dyn Rpc,
+    owned_worker_id: &OwnedWorkerId,
+    created_by: &AccountId,
+    retry_config: &RetryConfig,
+    saga_id: &SagaId,
+) -> Result<bool, WorkerExecutorError> {
+    let Some(saga) = saga_service.get(owned_worker_id, saga_id).await? else {
+        return Ok(false);
+    };
+    if saga.status != SagaStatus::Compensating {
+        return Ok(false);
+    }
+
+    for (step, compensation) in saga.remaining_compensations() {
+        if !saga_service.is_coordinated_here(owned_worker_id) {
+            debug!("The coordinator of saga {saga_id} got assigned to another executor");
+            break;
+        }
+
+        debug!(
+            "Compensating step {step} of saga {saga_id} by invoking {} on {}",
+            compensation.function_name, compensation.worker_id
+        );
+        let target_worker_id =
+            OwnedWorkerId::new(&owned_worker_id.project_id, &compensation.worker_id);
+        let mut retry_state = RetryState::new(retry_config);
+        let result = loop {
+            retry_state.start_attempt();
+            let result = rpc
+                .invoke_and_await(
+                    &target_worker_id,
+                    Some(compensation_idempotency_key(saga_id, step)),
+                    compensation.function_name.clone(),
+                    compensation
+                        .function_params
+                        .iter()
+                        .map(|param| param.value.clone().into())
+                        .collect(),
+                    created_by,
+                    &owned_worker_id.worker_id,
+                    &[],
+                    &[],
+                    BTreeMap::new(),
+                    InvocationContextStack::fresh(),
+                )
+                .await;
+            match result {
+                Ok(_) => break Ok(()),
+                Err(err) => {
+                    warn!("Compensation of step {step} of saga {saga_id} failed: {err}");
+                    if !retry_state.failed_attempt().await {
+                        break Err(err);
+                    }
+                }
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                saga_service
+                    .compensation_performed(owned_worker_id, saga_id, step)
+                    .await?
+            }
+            Err(err) => {
+                saga_service
+                    .compensation_failed(
+                        owned_worker_id,
+                        saga_id,
+                        format!(
+                            "Compensation of step {step} ({} on {}) failed: {err}",
+                            compensation.function_name, compensation.worker_id
+                        ),
+                    )
+                    .await?;
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_r::test;
+
+    use crate::durable_host::serialized::SerializableError;
+    use crate::model::ExecutionStatus;
+    use crate::services::oplog::{CommitLevel, Oplog, OplogOps, OplogService, PrimaryOplogService};
+    use crate::services::saga::{
+        apply_recorded_call, compensation_idempotency_key, DefaultSagaService, SagaService,
+        BEGIN_FUNCTION_NAME, COMPLETE_FUNCTION_NAME, REGISTER_COMPENSATION_FUNCTION_NAME,
+    };
+    use crate::services::shard::{ShardService, ShardServiceDefault};
+    use crate::storage::indexed::memory::InMemoryIndexedStorage;
+    use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
+    use crate::storage::keyvalue::KeyValueStorage;
+    use golem_common::model::oplog::{DurableFunctionType, OplogIndex};
+    use golem_common::model::{
+        AccountId, ComponentId, ComponentType, OwnedWorkerId, ProjectId, SagaId, ShardId,
+        Timestamp, WorkerId, WorkerMetadata, WorkerStatusRecord,
+    };
+    use golem_common::serialization::serialize;
+    use golem_service_base::model::saga::{SagaCompensation, SagaStatus};
+    use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
+    use std::collections::{BTreeMap, HashSet};
+    use std::sync::{Arc, RwLock};
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    type SagaCallResult = Result<Result<(), String>, SerializableError>;
+
+    /// A coordinator worker recording its saga calls to its oplog the same way the host functions do
+    struct Coordinator {
+        owned_worker_id: OwnedWorkerId,
+        oplog: Arc<dyn Oplog>,
+        sagas: DefaultSagaService,
+    }
+
+    impl Coordinator {
+        async fn new() -> Self {
+            let shard_service = Arc::new(ShardServiceDefault::new());
+            shard_service.register(1, &HashSet::from([ShardId::new(0)]));
+            Self::with_services(
+                Arc::new(InMemoryKeyValueStorage::new()),
+                in_memory_oplog_service().await,
+                shard_service,
+                "coordinator",
+            )
+            .await
+        }
+
+        async fn with_services(
+            key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
+            oplog_service: Arc<dyn OplogService>,
+            shard_service: Arc<dyn ShardService>,
+            worker_name: &str,
+        ) -> Self {
+            let project_id = ProjectId::new_v4();
+            let worker_id = WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: worker_name.to_string(),
+            };
+            let owned_worker_id = OwnedWorkerId::new(&project_id, &worker_id);
+            let oplog = oplog_service
+                .open(
+                    &owned_worker_id,
+                    oplog_service.get_last_index(&owned_worker_id).await,
+                    WorkerMetadata::default(
+                        worker_id,
+                        AccountId {
+                            value: "user1".to_string(),
+                        },
+                        project_id,
+                    ),
+                    Arc::new(RwLock::new(ExecutionStatus::Suspended {
+                        last_known_status: WorkerStatusRecord::default(),
+                        component_type: ComponentType::Durable,
+                        timestamp: Timestamp::now_utc(),
+                    })),
+                )
+                .await;
+            Self {
+                owned_worker_id,
+                oplog,
+                sagas: DefaultSagaService::new(key_value_storage, oplog_service, shard_service),
+            }
+        }
+
+        async fn record<I: bincode::Encode + Sync, O: bincode::Encode + Sync>(
+            &self,
+            function_name: &str,
+            request: &I,
+            response: &O,
+        ) {
+            self.oplog
+                .add_imported_function_invoked(
+                    function_name.to_string(),
+                    request,
+                    response,
+                    DurableFunctionType::WriteRemote,
+                )
+                .await
+                .unwrap();
+            self.oplog.commit(CommitLevel::Always).await;
+        }
+
+        async fn begin(&self) -> SagaId {
+            let saga_id = SagaId::new_v4();
+            self.record(
+                BEGIN_FUNCTION_NAME,
+                &(),
+                &Ok::<Uuid, SerializableError>(saga_id.0),
+            )
+            .await;
+            saga_id
+        }
+
+        async fn register(&self, saga_id: &SagaId, function_name: &str) {
+            let response: SagaCallResult = Ok(Ok(()));
+            self.record(
+                REGISTER_COMPENSATION_FUNCTION_NAME,
+                &(saga_id.0, compensation(function_name)),
+                &response,
+            )
+            .await;
+        }
+
+        async fn complete(&self, saga_id: &SagaId) {
+            let response: SagaCallResult = Ok(Ok(()));
+            self.record(COMPLETE_FUNCTION_NAME, &saga_id.0, &response)
+                .await;
+        }
+
+        async fn abort(&self, saga_id: &SagaId) -> SagaId {
+            self.sagas
+                .abort(&self.owned_worker_id, saga_id, "test")
+                .await
+                .unwrap()
+                .saga_id
+        }
+
+        async fn status(&self, saga_id: &SagaId) -> SagaStatus {
+            self.sagas
+                .get(&self.owned_worker_id, saga_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .status
+        }
+
+        async fn compensating(&self) -> Vec<SagaId> {
+            self.sagas
+                .get_compensating()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|(_, saga_id)| saga_id)
+                .collect()
+        }
+    }
+
+    async fn in_memory_oplog_service() -> Arc<dyn OplogService> {
+        Arc::new(
+            PrimaryOplogService::new(
+                Arc::new(InMemoryIndexedStorage::new()),
+                Arc::new(InMemoryBlobStorage::new()),
+                1,
+                100,
+            )
+            .await,
+        )
+    }
+
+    fn compensation(function_name: &str) -> SagaCompensation {
+        SagaCompensation {
+            worker_id: WorkerId {
+                component_id: ComponentId::new_v4(),
+                worker_name: "participant".to_string(),
+            },
+            function_name: function_name.to_string(),
+            function_params: vec![],
+        }
+    }
+
+    #[test]
+    async fn sagas_are_collected_from_the_oplog() {
+        let coordinator = Coordinator::new().await;
+
+        let first = coordinator.begin().await;
+        coordinator.register(&first, "api.{release}").await;
+        let second = coordinator.begin().await;
+        coordinator.register(&first, "api.{refund}").await;
+        coordinator.complete(&second).await;
+
+        let sagas = coordinator
+            .sagas
+            .get_all(&coordinator.owned_worker_id)
+            .await
+            .unwrap();
+
+        let summary: Vec<_> = sagas
+            .iter()
+            .map(|saga| {
+                (
+                    saga.saga_id.clone(),
+                    saga.status,
+                    saga.oplog_index,
+                    saga.compensations
+                        .iter()
+                        .map(|compensation| compensation.function_name.as_str())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    first,
+                    SagaStatus::Running,
+                    OplogIndex::from_u64(1),
+                    vec!["api.{release}", "api.{refund}"]
+                ),
+                (
+                    second,
+                    SagaStatus::Completed,
+                    OplogIndex::from_u64(3),
+                    vec![]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn failed_saga_calls_are_ignored() {
+        let coordinator = WorkerId {
+            component_id: ComponentId::new_v4(),
+            worker_name: "coordinator".to_string(),
+        };
+        let saga_id = SagaId::new_v4();
+        let succeeded: SagaCallResult = Ok(Ok(()));
+        let rejected: SagaCallResult = Ok(Err("Saga is not running".to_string()));
+        let failed: SagaCallResult = Err(SerializableError::Generic {
+            message: "failure".to_string(),
+        });
+        let begin = serialize(&()).unwrap();
+        let begin_response = serialize(&Ok::<Uuid, SerializableError>(saga_id.0)).unwrap();
+        let register = serialize(&(saga_id.0, compensation("api.{release}"))).unwrap();
+        let complete = serialize(&saga_id.0).unwrap();
+
+        let mut sagas = BTreeMap::new();
+        for (function_name, request, response) in [
+            (BEGIN_FUNCTION_NAME, &begin, begin_response),
+            (
+                REGISTER_COMPENSATION_FUNCTION_NAME,
+                &register,
+                serialize(&succeeded).unwrap(),
+            ),
+            (
+                REGISTER_COMPENSATION_FUNCTION_NAME,
+                &register,
+                serialize(&rejected).unwrap(),
+            ),
+            (
+                REGISTER_COMPENSATION_FUNCTION_NAME,
+                &register,
+                serialize(&failed).unwrap(),
+            ),
+            (
+                COMPLETE_FUNCTION_NAME,
+                &complete,
+                serialize(&failed).unwrap(),
+            ),
+        ] {
+            apply_recorded_call(
+                &mut sagas,
+                &coordinator,
+                OplogIndex::INITIAL,
+                Timestamp::now_utc(),
+                function_name,
+                request,
+                &response,
+            )
+            .unwrap();
+        }
+
+        let saga = &sagas[&saga_id];
+        assert_eq!(saga.compensations.len(), 1);
+        assert_eq!(saga.status, SagaStatus::Running);
+    }
+
+    #[test]
+    async fn aborted_saga_gets_compensated() {
+        let coordinator = Coordinator::new().await;
+        let saga_id = coordinator.begin().await;
+        coordinator.register(&saga_id, "api.{release}").await;
+
+        let saga = coordinator
+            .sagas
+            .abort(&coordinator.owned_worker_id, &saga_id, "test")
+            .await
+            .unwrap();
+
+        assert_eq!(saga.status, SagaStatus::Compensating);
+        assert_eq!(saga.abort_reason, Some("test".to_string()));
+        assert_eq!(coordinator.compensating().await, vec![saga_id.clone()]);
+        assert!(coordinator
+            .sagas
+            .ensure_not_aborted(&coordinator.owned_worker_id, &saga_id)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    async fn aborted_saga_without_compensations_is_compensated_immediately() {
+        let coordinator = Coordinator::new().await;
+        let saga_id = coordinator.begin().await;
+
+        coordinator.abort(&saga_id).await;
+
+        assert_eq!(coordinator.status(&saga_id).await, SagaStatus::Compensated);
+        assert!(coordinator.compensating().await.is_empty());
+    }
+
+    #[test]
+    async fn finished_sagas_cannot_be_aborted() {
+        let coordinator = Coordinator::new().await;
+        let completed = coordinator.begin().await;
+        coordinator.register(&completed, "api.{release}").await;
+        coordinator.complete(&completed).await;
+        let compensated = coordinator.begin().await;
+        coordinator.abort(&compensated).await;
+
+        for saga_id in [completed, compensated, SagaId::new_v4()] {
+            assert!(coordinator
+                .sagas
+                .abort(&coordinator.owned_worker_id, &saga_id, "again")
+                .await
+                .is_err());
+        }
+    }
+
+    #[test]
+    async fn compensations_are_recorded_in_reverse_order() {
+        let coordinator = Coordinator::new().await;
+        let saga_id = coordinator.begin().await;
+        coordinator.register(&saga_id, "api.{release}").await;
+        coordinator.register(&saga_id, "api.{refund}").await;
+        coordinator.abort(&saga_id).await;
+
+        // Recording a step out of order, or recording the same step twice has no effect
+        for step in [0, 1, 1] {
+            coordinator
+                .sagas
+                .compensation_performed(&coordinator.owned_worker_id, &saga_id, step)
+                .await
+                .unwrap();
+        }
+        let saga = coordinator
+            .sagas
+            .get(&coordinator.owned_worker_id, &saga_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saga.compensated, 1);
+        assert_eq!(saga.status, SagaStatus::Compensating);
+
+        coordinator
+            .sagas
+            .compensation_performed(&coordinator.owned_worker_id, &saga_id, 0)
+            .await
+            .unwrap();
+        assert_eq!(coordinator.status(&saga_id).await, SagaStatus::Compensated);
+        assert!(coordinator.compensating().await.is_empty());
+    }
+
+    #[test]
+    async fn failed_compensation_is_resumed_by_aborting_again() {
+        let coordinator = Coordinator::new().await;
+        let saga_id = coordinator.begin().await;
+        coordinator.register(&saga_id, "api.{release}").await;
+        coordinator.abort(&saga_id).await;
+
+        coordinator
+            .sagas
+            .compensation_failed(&coordinator.owned_worker_id, &saga_id, "boom".to_string())
+            .await
+            .unwrap();
+        let saga = coordinator
+            .sagas
+            .get(&coordinator.owned_worker_id, &saga_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(saga.status, SagaStatus::CompensationFailed);
+        assert_eq!(saga.error, Some("boom".to_string()));
+        assert!(coordinator.compensating().await.is_empty());
+
+        let saga = coordinator
+            .sagas
+            .abort(&coordinator.owned_worker_id, &saga_id, "retry")
+            .await
+            .unwrap();
+        assert_eq!(saga.status, SagaStatus::Compensating);
+        assert_eq!(saga.abort_reason, Some("test".to_string()));
+        assert_eq!(saga.error, None);
+        assert_eq!(coordinator.compensating().await, vec![saga_id]);
+    }
+
+    #[test]
+    async fn only_sagas_coordinated_in_assigned_shards_are_resumed() {
+        let key_value_storage = Arc::new(InMemoryKeyValueStorage::new());
+        let oplog_service = in_memory_oplog_service().await;
+        let shard_service = Arc::new(ShardServiceDefault::new());
+        shard_service.register(2, &HashSet::from([ShardId::new(0)]));
+
+        let mut assigned = None;
+        let mut not_assigned = None;
+        for idx in 0.. {
+            let coordinator = Coordinator::with_services(
+                key_value_storage.clone(),
+                oplog_service.clone(),
+                shard_service.clone(),
+                &format!("coordinator-{idx}"),
+            )
+            .await;
+            if coordinator
+                .sagas
+                .is_coordinated_here(&coordinator.owned_worker_id)
+            {
+                assigned.get_or_insert(coordinator);
+            } else {
+                not_assigned.get_or_insert(coordinator);
+            }
+            if assigned.is_some() && not_assigned.is_some() {
+                break;
+            }
+        }
+        let assigned = assigned.unwrap();
+        let not_assigned = not_assigned.unwrap();
+
+        let mut saga_ids = Vec::new();
+        for coordinator in [&assigned, &not_assigned] {
+            let saga_id = coordinator.begin().await;
+            coordinator.register(&saga_id, "api.{release}").await;
+            saga_ids.push(coordinator.abort(&saga_id).await);
+        }
+
+        assert_eq!(assigned.compensating().await, vec![saga_ids[0].clone()]);
+    }
+
+    #[test]
+    async fn deleting_the_coordinator_removes_its_aborted_sagas() {
+        let coordinator = Coordinator::new().await;
+        let saga_id = coordinator.begin().await;
+        coordinator.register(&saga_id, "api.{release}").await;
+        coordinator.abort(&saga_id).await;
+
+        coordinator
+            .sagas
+            .delete_all(&coordinator.owned_worker_id)
+            .await
+            .unwrap();
+
+        assert!(coordinator.compensating().await.is_empty());
+        assert!(coordinator
+            .sagas
+            .ensure_not_aborted(&coordinator.owned_worker_id, &saga_id)
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    async fn sagas_are_locked_individually() {
+        let coordinator = Coordinator::new().await;
+        let first = SagaId::new_v4();
+        let second = SagaId::new_v4();
+
+        let lock = coordinator
+            .sagas
+            .lock(&coordinator.owned_worker_id, &first)
+            .await;
+        assert!(tokio::time::timeout(
+            Duration::from_millis(100),
+            coordinator.sagas.lock(&coordinator.owned_worker_id, &first)
+        )
+        .await
+        .is_err());
+        let other = coordinator
+            .sagas
+            .lock(&coordinator.owned_worker_id, &second)
+            .await;
+
+        drop(lock);
+        drop(other);
+        assert!(coordinator.sagas.locks.is_empty());
+    }
+
+    #[test]
+    async fn saga_is_compensated_by_one_task_at_a_time() {
+        let coordinator = Coordinator::new().await;
+        let saga_id = SagaId::new_v4();
+
+        let lock = coordinator
+            .sagas
+            .try_lock_compensation(&coordinator.owned_worker_id, &saga_id)
+            .unwrap();
+        assert!(coordinator
+            .sagas
+            .try_lock_compensation(&coordinator.owned_worker_id, &saga_id)
+            .is_none());
+
+        drop(lock);
+        assert!(coordinator
+            .sagas
+            .try_lock_compensation(&coordinator.owned_worker_id, &saga_id)
+            .is_some());
+    }
+
+    #[test]
+    fn compensation_idempotency_keys_are_deterministic_per_step() {
+        let saga_id = SagaId::new_v4();
+
+        assert_eq!(
+            compensation_idempotency_key(&saga_id, 0),
+            compensation_idempotency_key(&saga_id, 0)
+        );
+        assert_ne!(
+            compensation_idempotency_key(&saga_id, 0),
+            compensation_idempotency_key(&saga_id, 1)
+        );
+        assert_ne!(
+            compensation_idempotency_key(&saga_id, 0),
+            compensation_idempotency_key(&SagaId::new_v4(), 0)
+        );
+    }
+}
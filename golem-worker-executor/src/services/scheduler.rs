@@ -14,6 +14,7 @@
 
 use crate::metrics::oplog::record_scheduled_archive;
 use crate::metrics::promises::record_scheduled_promise_completed;
+use crate::metrics::workers::record_worker_ttl_deletion;
 use crate::services::oplog::{MultiLayerOplog, Oplog, OplogService};
 use crate::services::promise::PromiseService;
 use crate::services::shard::ShardService;
@@ -26,23 +27,110 @@ use crate::storage::keyvalue::{
 use crate::worker::Worker;
 use crate::workerctx::WorkerCtx;
 use async_trait::async_trait;
+use bincode::{Decode, Encode};
 use chrono::{DateTime, TimeZone, Utc};
+use dashmap::DashMap;
 use golem_common::model::invocation_context::InvocationContextStack;
-use golem_common::model::{AccountId, IdempotencyKey, OwnedWorkerId, ScheduleId, ScheduledAction};
+use golem_common::model::oplog::{InvocationPriority, OplogIndex};
+use golem_common::model::{
+    AccountId, ComponentId, IdempotencyKey, InvocationScheduleId, OwnedWorkerId, ProjectId,
+    ScheduleId, ScheduledAction, Timestamp,
+};
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::invocation_schedule::{InvocationSchedule, InvocationScheduleSpec};
+use golem_service_base::model::worker_ttl::{WorkerTtlDeletion, WorkerTtlPolicy};
 use golem_wasm_rpc::Value;
 use std::ops::{Add, Deref};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use tokio::sync::OwnedMutexGuard;
 use tokio::task::JoinHandle;
 use tokio::time::Instant;
 use tracing::{error, info, span, warn, Instrument, Level};
+use uuid::Uuid;
 
 #[async_trait]
 pub trait SchedulerService: Send + Sync {
     async fn schedule(&self, time: DateTime<Utc>, action: ScheduledAction) -> ScheduleId;
 
     async fn cancel(&self, id: ScheduleId);
+
+    /// Creates a one-shot or recurring invocation schedule for a worker, and schedules its first
+    /// occurrence. Recurring schedules are re-armed every time an occurrence is performed.
+    async fn create_invocation_schedule(
+        &self,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        function_name: String,
+        function_input: Vec<Value>,
+        invocation_context: InvocationContextStack,
+        spec: InvocationScheduleSpec,
+    ) -> Result<InvocationSchedule, WorkerExecutorError>;
+
+    async fn get_invocation_schedules(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<InvocationSchedule>, WorkerExecutorError>;
+
+    /// Cancels an invocation schedule, returning false if it does not exist (anymore)
+    async fn cancel_invocation_schedule(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        schedule_id: &InvocationScheduleId,
+    ) -> Result<bool, WorkerExecutorError>;
+
+    /// Sets the time-to-live policy of all the workers of a component, or removes it if `policy` is `None`
+    async fn set_component_ttl_policy(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+        policy: Option<WorkerTtlPolicy>,
+    ) -> Result<(), WorkerExecutorError>;
+
+    async fn get_component_ttl_policy(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+    ) -> Result<Option<WorkerTtlPolicy>, WorkerExecutorError>;
+
+    /// Sets the time-to-live policy of a single worker, overriding the policy of its component,
+    /// or removes it if `policy` is `None`
+    async fn set_worker_ttl_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        policy: Option<WorkerTtlPolicy>,
+    ) -> Result<(), WorkerExecutorError>;
+
+    async fn get_worker_ttl_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<WorkerTtlPolicy>, WorkerExecutorError>;
+
+    /// Schedules checking the time-to-live of a worker which became idle or exited at
+    /// `last_oplog_index`. Does nothing if neither the worker nor its component has a policy.
+    async fn schedule_ttl_check(
+        &self,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+    );
+
+    /// Gets the audit log of the workers of a component deleted by their time-to-live policy, latest first
+    async fn get_ttl_deletions(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+    ) -> Result<Vec<WorkerTtlDeletion>, WorkerExecutorError>;
+}
+
+/// Everything needed to perform the occurrences of an invocation schedule, stored
+/// separately from the scheduled actions so the schedule can be listed and updated.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+struct InvocationScheduleRecord {
+    account_id: AccountId,
+    schedule: InvocationSchedule,
+    function_input: Vec<Value>,
+    invocation_context: InvocationContextStack,
 }
 
 /// A lighter trait than `WorkerActivator` that only provides the required functionality
@@ -66,6 +154,12 @@ pub trait SchedulerWorkerAccess {
         function_input: Vec<Value>,
         invocation_context: InvocationContextStack,
     ) -> Result<(), WorkerExecutorError>;
+
+    async fn delete_worker(
+        &self,
+        created_by: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError>;
 }
 
 #[async_trait]
@@ -106,6 +200,7 @@ impl<Ctx: WorkerCtx> SchedulerWorkerAccess for Arc<dyn WorkerActivator<Ctx>> {
                 full_function_name,
                 function_input,
                 invocation_context,
+                InvocationPriority::Normal,
             )
             .await?;
 
@@ -113,17 +208,60 @@ impl<Ctx: WorkerCtx> SchedulerWorkerAccess for Arc<dyn WorkerActivator<Ctx>> {
 
         Ok(())
     }
+
+    async fn delete_worker(
+        &self,
+        created_by: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError> {
+        self.deref()
+            .delete_worker(created_by, owned_worker_id)
+            .await
+    }
+}
+
+type InvocationScheduleKey = (OwnedWorkerId, InvocationScheduleId);
+type InvocationScheduleLocks = Arc<DashMap<InvocationScheduleKey, Arc<tokio::sync::Mutex<()>>>>;
+
+/// Exclusive access to an invocation schedule, released when dropped
+struct InvocationScheduleLock {
+    locks: InvocationScheduleLocks,
+    key: InvocationScheduleKey,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl InvocationScheduleLock {
+    async fn acquire(locks: &InvocationScheduleLocks, key: InvocationScheduleKey) -> Self {
+        let lock = locks.entry(key.clone()).or_default().clone();
+        let guard = lock.lock_owned().await;
+        Self {
+            locks: locks.clone(),
+            key,
+            guard: Some(guard),
+        }
+    }
+}
+
+impl Drop for InvocationScheduleLock {
+    fn drop(&mut self) {
+        self.guard.take();
+        // Only the schedules somebody is waiting for are kept in the map
+        self.locks
+            .remove_if(&self.key, |_, lock| Arc::strong_count(lock) == 1);
+    }
 }
 
 #[derive(Clone)]
 pub struct SchedulerServiceDefault {
     key_value_storage: Arc<dyn KeyValueStorage + Send + Sync>,
     background_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
+    invocation_schedule_locks: InvocationScheduleLocks,
     shard_service: Arc<dyn ShardService>,
     promise_service: Arc<dyn PromiseService>,
     worker_access: Arc<dyn SchedulerWorkerAccess + Send + Sync>,
     oplog_service: Arc<dyn OplogService>,
     worker_service: Arc<dyn WorkerService>,
+    ttl_deletion_log_retention: Duration,
 }
 
 impl SchedulerServiceDefault {
@@ -135,15 +273,18 @@ impl SchedulerServiceDefault {
         oplog_service: Arc<dyn OplogService>,
         worker_service: Arc<dyn WorkerService>,
         process_interval: Duration,
+        ttl_deletion_log_retention: Duration,
     ) -> Arc<Self> {
         let svc = Self {
             key_value_storage,
             background_handle: Arc::new(Mutex::new(None)),
+            invocation_schedule_locks: Arc::new(DashMap::new()),
             shard_service,
             promise_service,
             oplog_service,
             worker_service,
             worker_access,
+            ttl_deletion_log_retention,
         };
         let svc = Arc::new(svc);
         let background_handle = {
@@ -346,6 +487,69 @@ impl SchedulerServiceDefault {
                         );
                     };
                 }
+                ScheduledAction::InvokeScheduled {
+                    account_id,
+                    owned_worker_id,
+                    schedule_id,
+                    scheduled_at,
+                } => {
+                    // Canceling the schedule waits for the occurrence to be processed, so it
+                    // cannot get re-armed after the cancellation
+                    let _lock = InvocationScheduleLock::acquire(
+                        &self.invocation_schedule_locks,
+                        (owned_worker_id.clone(), schedule_id.clone()),
+                    )
+                    .await;
+
+                    // A missing record means the schedule was canceled
+                    match self
+                        .get_invocation_schedule_record(&owned_worker_id, &schedule_id)
+                        .await
+                    {
+                        Ok(Some(record)) => {
+                            if let Err(e) = self
+                                .perform_scheduled_invocation(
+                                    &account_id,
+                                    &owned_worker_id,
+                                    record,
+                                    scheduled_at,
+                                    now,
+                                )
+                                .await
+                            {
+                                error!(
+                                    worker_id = owned_worker_id.to_string(),
+                                    schedule_id = schedule_id.to_string(),
+                                    "Failed to re-arm invocation schedule: {e}"
+                                );
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => {
+                            error!(
+                                worker_id = owned_worker_id.to_string(),
+                                schedule_id = schedule_id.to_string(),
+                                "Failed to load invocation schedule: {e}"
+                            );
+                        }
+                    }
+                }
+                ScheduledAction::CheckWorkerTtl {
+                    account_id,
+                    owned_worker_id,
+                    last_oplog_index,
+                    ..
+                } => {
+                    if let Err(e) = self
+                        .check_worker_ttl(&account_id, &owned_worker_id, last_oplog_index, now)
+                        .await
+                    {
+                        error!(
+                            worker_id = owned_worker_id.to_string(),
+                            "Failed to check the time-to-live of worker: {e}"
+                        );
+                    }
+                }
             }
 
             // We are completely done with the action, purge it from the queue
@@ -358,8 +562,313 @@ impl SchedulerServiceDefault {
         Ok(())
     }
 
+    /// Enqueues the invocation of a schedule's occurrence and re-arms the schedule if it has
+    /// further occurrences. The next occurrence gets scheduled before the current one is removed
+    /// from the queue, so a crash in between can only lead to performing the same occurrence
+    /// again - which is deduplicated by its idempotency key.
+    async fn perform_scheduled_invocation(
+        &self,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        mut record: InvocationScheduleRecord,
+        scheduled_at: Timestamp,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let schedule_id = record.schedule.schedule_id.clone();
+        let idempotency_key = IdempotencyKey::from_uuid(Uuid::new_v5(
+            &schedule_id.0,
+            &scheduled_at.to_millis().to_be_bytes(),
+        ));
+
+        // TODO: Same as for ScheduledAction::Invoke, failing to enqueue the invocation is only logged
+        let result = self
+            .worker_access
+            .enqueue_invocation(
+                account_id,
+                owned_worker_id,
+                idempotency_key,
+                record.schedule.function_name.clone(),
+                record.function_input.clone(),
+                record.invocation_context.clone(),
+            )
+            .await;
+
+        if let Err(e) = result {
+            error!(
+                worker_id = owned_worker_id.to_string(),
+                schedule_id = schedule_id.to_string(),
+                full_function_name = record.schedule.function_name,
+                "Failed to invoke worker with scheduled invocation: {e}"
+            );
+        }
+
+        record.schedule.invocation_count += 1;
+        let next = record
+            .schedule
+            .schedule
+            .next_occurrence(scheduled_at, Self::to_timestamp(now))
+            .unwrap_or_else(|err| {
+                error!(
+                    worker_id = owned_worker_id.to_string(),
+                    schedule_id = schedule_id.to_string(),
+                    "Failed to calculate the next occurrence of the invocation schedule: {err}"
+                );
+                None
+            });
+        record.schedule.next_invocation_at = next;
+
+        match next {
+            Some(next) => {
+                self.key_value_storage
+                    .with_entity("scheduler", "process", "invocation_schedule")
+                    .set(
+                        KeyValueStorageNamespace::Schedule,
+                        &Self::invocation_schedule_key(owned_worker_id, &schedule_id),
+                        &record,
+                    )
+                    .await?;
+
+                self.schedule(
+                    Self::to_date_time(next),
+                    ScheduledAction::InvokeScheduled {
+                        account_id: account_id.clone(),
+                        owned_worker_id: owned_worker_id.clone(),
+                        schedule_id,
+                        scheduled_at: next,
+                    },
+                )
+                .await;
+            }
+            None => {
+                self.delete_invocation_schedule_record(owned_worker_id, &schedule_id)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_invocation_schedule_record(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        schedule_id: &InvocationScheduleId,
+    ) -> Result<Option<InvocationScheduleRecord>, String> {
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "get_invocation_schedule",
+                "invocation_schedule",
+            )
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::invocation_schedule_key(owned_worker_id, schedule_id),
+            )
+            .await
+    }
+
+    async fn delete_invocation_schedule_record(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        schedule_id: &InvocationScheduleId,
+    ) -> Result<(), String> {
+        self.key_value_storage
+            .with("scheduler", "delete_invocation_schedule")
+            .del(
+                KeyValueStorageNamespace::Schedule,
+                &Self::invocation_schedule_key(owned_worker_id, schedule_id),
+            )
+            .await?;
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "delete_invocation_schedule",
+                "invocation_schedule_id",
+            )
+            .remove_from_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::invocation_schedules_key(owned_worker_id),
+                schedule_id,
+            )
+            .await
+    }
+
+    /// Deletes the worker if it violates its time-to-live policy, otherwise schedules the next
+    /// check for the time it will. Deletion requires the worker to be unchanged since the check was
+    /// scheduled, so a worker which got invoked in the meantime is only checked again once it
+    /// becomes idle again.
+    async fn check_worker_ttl(
+        &self,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        if !self.oplog_service.exists(owned_worker_id).await
+            || self.oplog_service.get_last_index(owned_worker_id).await != last_oplog_index
+        {
+            return Ok(());
+        }
+
+        let Some(metadata) = self.worker_service.get(owned_worker_id).await else {
+            return Ok(());
+        };
+        let Some(policy) = self.get_effective_ttl_policy(owned_worker_id).await? else {
+            return Ok(());
+        };
+
+        let last_activity = self
+            .oplog_service
+            .read(owned_worker_id, last_oplog_index, 1)
+            .await
+            .into_values()
+            .next()
+            .map(|entry| entry.timestamp())
+            .unwrap_or(metadata.created_at);
+
+        match policy.expiration(
+            &metadata.last_known_status.status,
+            metadata.created_at,
+            last_activity,
+        ) {
+            Some((at, reason)) if at <= Self::to_timestamp(now) => {
+                self.worker_access
+                    .delete_worker(account_id, owned_worker_id)
+                    .await
+                    .map_err(|err| err.to_string())?;
+
+                info!(
+                    worker_id = owned_worker_id.to_string(),
+                    reason = ?reason,
+                    "Deleted worker because of its time-to-live policy"
+                );
+                record_worker_ttl_deletion();
+
+                self.add_ttl_deletion(
+                    &owned_worker_id.project_id,
+                    WorkerTtlDeletion {
+                        worker_id: owned_worker_id.worker_id(),
+                        reason,
+                        policy,
+                        worker_created_at: metadata.created_at,
+                        deleted_at: Self::to_timestamp(now),
+                    },
+                )
+                .await
+            }
+            Some((at, _)) => {
+                self.schedule(
+                    Self::to_date_time(at),
+                    ScheduledAction::CheckWorkerTtl {
+                        account_id: account_id.clone(),
+                        owned_worker_id: owned_worker_id.clone(),
+                        last_oplog_index,
+                        scheduled_at: at,
+                    },
+                )
+                .await;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// The worker's own time-to-live policy, or its component's if it does not have one
+    async fn get_effective_ttl_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<WorkerTtlPolicy>, String> {
+        match self
+            .key_value_storage
+            .with_entity("scheduler", "get_ttl_policy", "worker_ttl_policy")
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::worker_ttl_policy_key(owned_worker_id),
+            )
+            .await?
+        {
+            Some(policy) => Ok(Some(policy)),
+            None => {
+                self.key_value_storage
+                    .with_entity("scheduler", "get_ttl_policy", "worker_ttl_policy")
+                    .get(
+                        KeyValueStorageNamespace::Schedule,
+                        &Self::component_ttl_policy_key(
+                            &owned_worker_id.project_id,
+                            &owned_worker_id.worker_id.component_id,
+                        ),
+                    )
+                    .await
+            }
+        }
+    }
+
+    /// Adds an entry to the audit log of deleted workers, dropping the entries older than the
+    /// configured retention period
+    async fn add_ttl_deletion(
+        &self,
+        project_id: &ProjectId,
+        deletion: WorkerTtlDeletion,
+    ) -> Result<(), String> {
+        let key = Self::ttl_deletions_key(project_id, &deletion.worker_id.component_id);
+        let deleted_at = deletion.deleted_at.to_millis();
+
+        self.key_value_storage
+            .with_entity("scheduler", "add_ttl_deletion", "worker_ttl_deletion")
+            .add_to_sorted_set(
+                KeyValueStorageNamespace::Schedule,
+                &key,
+                deleted_at as f64,
+                &deletion,
+            )
+            .await?;
+
+        let cutoff = deleted_at.saturating_sub(self.ttl_deletion_log_retention.as_millis() as u64);
+        let expired: Vec<(f64, WorkerTtlDeletion)> = self
+            .key_value_storage
+            .with_entity("scheduler", "add_ttl_deletion", "worker_ttl_deletion")
+            .query_sorted_set(KeyValueStorageNamespace::Schedule, &key, 0.0, cutoff as f64)
+            .await?;
+        for (_, expired) in expired {
+            self.key_value_storage
+                .with_entity("scheduler", "add_ttl_deletion", "worker_ttl_deletion")
+                .remove_from_sorted_set(KeyValueStorageNamespace::Schedule, &key, &expired)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn set_ttl_policy(
+        &self,
+        key: &str,
+        policy: Option<WorkerTtlPolicy>,
+    ) -> Result<(), String> {
+        match policy {
+            Some(policy) => {
+                self.key_value_storage
+                    .with_entity("scheduler", "set_ttl_policy", "worker_ttl_policy")
+                    .set(KeyValueStorageNamespace::Schedule, key, &policy)
+                    .await
+            }
+            None => {
+                self.key_value_storage
+                    .with("scheduler", "set_ttl_policy")
+                    .del(KeyValueStorageNamespace::Schedule, key)
+                    .await
+            }
+        }
+    }
+
     const HOUR_IN_MILLIS: i64 = 1000 * 60 * 60;
 
+    fn to_timestamp(time: DateTime<Utc>) -> Timestamp {
+        Timestamp::from(time.timestamp_millis() as u64)
+    }
+
+    fn to_date_time(timestamp: Timestamp) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(timestamp.to_millis() as i64).unwrap_or_else(Utc::now)
+    }
+
     fn split_time<Tz: TimeZone>(time: DateTime<Tz>) -> (i64, f64) {
         let millis = time.timestamp_millis();
         let hours_since_epoch = millis / Self::HOUR_IN_MILLIS;
@@ -374,6 +883,29 @@ impl SchedulerServiceDefault {
     fn schedule_key_from_timestamp(timestamp: i64) -> String {
         format!("worker:schedule:{timestamp}")
     }
+
+    fn invocation_schedule_key(
+        owned_worker_id: &OwnedWorkerId,
+        schedule_id: &InvocationScheduleId,
+    ) -> String {
+        format!("worker:invocation-schedule:{owned_worker_id}:{schedule_id}")
+    }
+
+    fn invocation_schedules_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!("worker:invocation-schedules:{owned_worker_id}")
+    }
+
+    fn component_ttl_policy_key(project_id: &ProjectId, component_id: &ComponentId) -> String {
+        format!("worker:ttl-policy:component:{project_id}/{component_id}")
+    }
+
+    fn worker_ttl_policy_key(owned_worker_id: &OwnedWorkerId) -> String {
+        format!("worker:ttl-policy:worker:{owned_worker_id}")
+    }
+
+    fn ttl_deletions_key(project_id: &ProjectId, component_id: &ComponentId) -> String {
+        format!("worker:ttl-deletions:{project_id}/{component_id}")
+    }
 }
 
 impl Drop for SchedulerServiceDefault {
@@ -426,6 +958,261 @@ impl SchedulerService for SchedulerServiceDefault {
                 )
             });
     }
+
+    async fn create_invocation_schedule(
+        &self,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        function_name: String,
+        function_input: Vec<Value>,
+        invocation_context: InvocationContextStack,
+        spec: InvocationScheduleSpec,
+    ) -> Result<InvocationSchedule, WorkerExecutorError> {
+        let now = Timestamp::now_utc();
+        let first = spec
+            .first_occurrence(now)
+            .map_err(WorkerExecutorError::invalid_request)?
+            .ok_or(WorkerExecutorError::invalid_request(
+                "The invocation schedule has no future occurrences",
+            ))?;
+
+        let schedule = InvocationSchedule {
+            schedule_id: InvocationScheduleId::new_v4(),
+            worker_id: owned_worker_id.worker_id(),
+            function_name,
+            schedule: spec,
+            created_at: now,
+            next_invocation_at: Some(first),
+            invocation_count: 0,
+        };
+        let record = InvocationScheduleRecord {
+            account_id: account_id.clone(),
+            schedule: schedule.clone(),
+            function_input,
+            invocation_context,
+        };
+
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "create_invocation_schedule",
+                "invocation_schedule",
+            )
+            .set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::invocation_schedule_key(owned_worker_id, &schedule.schedule_id),
+                &record,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+        self.key_value_storage
+            .with_entity(
+                "scheduler",
+                "create_invocation_schedule",
+                "invocation_schedule_id",
+            )
+            .add_to_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::invocation_schedules_key(owned_worker_id),
+                &schedule.schedule_id,
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+
+        self.schedule(
+            Self::to_date_time(first),
+            ScheduledAction::InvokeScheduled {
+                account_id: account_id.clone(),
+                owned_worker_id: owned_worker_id.clone(),
+                schedule_id: schedule.schedule_id.clone(),
+                scheduled_at: first,
+            },
+        )
+        .await;
+
+        Ok(schedule)
+    }
+
+    async fn get_invocation_schedules(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Vec<InvocationSchedule>, WorkerExecutorError> {
+        let schedule_ids: Vec<InvocationScheduleId> = self
+            .key_value_storage
+            .with_entity(
+                "scheduler",
+                "get_invocation_schedules",
+                "invocation_schedule_id",
+            )
+            .members_of_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::invocation_schedules_key(owned_worker_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+
+        let mut schedules = Vec::new();
+        for schedule_id in schedule_ids {
+            if let Some(record) = self
+                .get_invocation_schedule_record(owned_worker_id, &schedule_id)
+                .await
+                .map_err(WorkerExecutorError::runtime)?
+            {
+                schedules.push(record.schedule);
+            }
+        }
+        schedules.sort_by_key(|schedule| schedule.created_at);
+
+        Ok(schedules)
+    }
+
+    async fn cancel_invocation_schedule(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        schedule_id: &InvocationScheduleId,
+    ) -> Result<bool, WorkerExecutorError> {
+        let _lock = InvocationScheduleLock::acquire(
+            &self.invocation_schedule_locks,
+            (owned_worker_id.clone(), schedule_id.clone()),
+        )
+        .await;
+
+        match self
+            .get_invocation_schedule_record(owned_worker_id, schedule_id)
+            .await
+            .map_err(WorkerExecutorError::runtime)?
+        {
+            Some(record) => {
+                self.delete_invocation_schedule_record(owned_worker_id, schedule_id)
+                    .await
+                    .map_err(WorkerExecutorError::runtime)?;
+
+                // Processing an occurrence without a record is a no-op, removing it is just cleanup
+                if let Some(next) = record.schedule.next_invocation_at {
+                    let (hours_since_epoch, _) = Self::split_time(Self::to_date_time(next));
+                    self.cancel(ScheduleId {
+                        timestamp: hours_since_epoch,
+                        action: ScheduledAction::InvokeScheduled {
+                            account_id: record.account_id,
+                            owned_worker_id: owned_worker_id.clone(),
+                            schedule_id: schedule_id.clone(),
+                            scheduled_at: next,
+                        },
+                    })
+                    .await;
+                }
+
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn set_component_ttl_policy(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+        policy: Option<WorkerTtlPolicy>,
+    ) -> Result<(), WorkerExecutorError> {
+        self.set_ttl_policy(
+            &Self::component_ttl_policy_key(project_id, component_id),
+            policy,
+        )
+        .await
+        .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn get_component_ttl_policy(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+    ) -> Result<Option<WorkerTtlPolicy>, WorkerExecutorError> {
+        self.key_value_storage
+            .with_entity("scheduler", "get_ttl_policy", "worker_ttl_policy")
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::component_ttl_policy_key(project_id, component_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn set_worker_ttl_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+        policy: Option<WorkerTtlPolicy>,
+    ) -> Result<(), WorkerExecutorError> {
+        self.set_ttl_policy(&Self::worker_ttl_policy_key(owned_worker_id), policy)
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn get_worker_ttl_policy(
+        &self,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<Option<WorkerTtlPolicy>, WorkerExecutorError> {
+        self.key_value_storage
+            .with_entity("scheduler", "get_ttl_policy", "worker_ttl_policy")
+            .get(
+                KeyValueStorageNamespace::Schedule,
+                &Self::worker_ttl_policy_key(owned_worker_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)
+    }
+
+    async fn schedule_ttl_check(
+        &self,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+        last_oplog_index: OplogIndex,
+    ) {
+        match self.get_effective_ttl_policy(owned_worker_id).await {
+            Ok(Some(_)) => {
+                // The exact time of the deletion is calculated when the check is performed
+                let now = Utc::now();
+                self.schedule(
+                    now,
+                    ScheduledAction::CheckWorkerTtl {
+                        account_id: account_id.clone(),
+                        owned_worker_id: owned_worker_id.clone(),
+                        last_oplog_index,
+                        scheduled_at: Self::to_timestamp(now),
+                    },
+                )
+                .await;
+            }
+            Ok(None) => {}
+            Err(e) => {
+                error!(
+                    worker_id = owned_worker_id.to_string(),
+                    "Failed to load the time-to-live policy of worker: {e}"
+                );
+            }
+        }
+    }
+
+    async fn get_ttl_deletions(
+        &self,
+        project_id: &ProjectId,
+        component_id: &ComponentId,
+    ) -> Result<Vec<WorkerTtlDeletion>, WorkerExecutorError> {
+        let deletions: Vec<(f64, WorkerTtlDeletion)> = self
+            .key_value_storage
+            .with_entity("scheduler", "get_ttl_deletions", "worker_ttl_deletion")
+            .get_sorted_set(
+                KeyValueStorageNamespace::Schedule,
+                &Self::ttl_deletions_key(project_id, component_id),
+            )
+            .await
+            .map_err(WorkerExecutorError::runtime)?;
+
+        Ok(deletions
+            .into_iter()
+            .rev()
+            .map(|(_, deletion)| deletion)
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -442,25 +1229,109 @@ mod tests {
     use crate::storage::keyvalue::memory::InMemoryKeyValueStorage;
     use async_trait::async_trait;
     use bincode::Encode;
-    use chrono::DateTime;
+    use chrono::{DateTime, Utc};
     use golem_common::model::invocation_context::InvocationContextStack;
     use golem_common::model::oplog::OplogIndex;
     use golem_common::model::{
         AccountId, ComponentId, IdempotencyKey, OwnedWorkerId, ProjectId, PromiseId,
-        ScheduledAction, ShardId, WorkerId,
+        ScheduledAction, ShardId, Timestamp, WorkerId,
     };
     use golem_service_base::error::worker_executor::WorkerExecutorError;
+    use golem_service_base::model::invocation_schedule::{
+        CronInvocationSchedule, InvocationScheduleSpec,
+    };
+    use golem_service_base::model::worker_ttl::WorkerTtlPolicy;
     use golem_service_base::storage::blob::memory::InMemoryBlobStorage;
     use golem_wasm_rpc::Value;
     use std::collections::{HashMap, HashSet};
     use std::str::FromStr;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use test_r::test;
     use uuid::Uuid;
 
     struct SchedulerWorkerAccessMock;
 
+    #[derive(Default)]
+    struct RecordingSchedulerWorkerAccess {
+        invocations: Mutex<Vec<(IdempotencyKey, String)>>,
+    }
+
+    #[async_trait]
+    impl SchedulerWorkerAccess for RecordingSchedulerWorkerAccess {
+        async fn activate_worker(&self, _created_by: &AccountId, _owned_worker_id: &OwnedWorkerId) {
+        }
+        async fn open_oplog(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Arc<dyn Oplog>, WorkerExecutorError> {
+            unimplemented!()
+        }
+        async fn enqueue_invocation(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+            idempotency_key: IdempotencyKey,
+            full_function_name: String,
+            _function_input: Vec<Value>,
+            _invocation_context: InvocationContextStack,
+        ) -> Result<(), WorkerExecutorError> {
+            self.invocations
+                .lock()
+                .unwrap()
+                .push((idempotency_key, full_function_name));
+            Ok(())
+        }
+        async fn delete_worker(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<(), WorkerExecutorError> {
+            unimplemented!()
+        }
+    }
+
+    /// Blocks enqueueing invocations until released
+    #[derive(Default)]
+    struct BlockingSchedulerWorkerAccess {
+        entered: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+    }
+
+    #[async_trait]
+    impl SchedulerWorkerAccess for BlockingSchedulerWorkerAccess {
+        async fn activate_worker(&self, _created_by: &AccountId, _owned_worker_id: &OwnedWorkerId) {
+        }
+        async fn open_oplog(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<Arc<dyn Oplog>, WorkerExecutorError> {
+            unimplemented!()
+        }
+        async fn enqueue_invocation(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+            _idempotency_key: IdempotencyKey,
+            _full_function_name: String,
+            _function_input: Vec<Value>,
+            _invocation_context: InvocationContextStack,
+        ) -> Result<(), WorkerExecutorError> {
+            self.entered.notify_one();
+            self.release.notified().await;
+            Ok(())
+        }
+        async fn delete_worker(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<(), WorkerExecutorError> {
+            unimplemented!()
+        }
+    }
+
     #[async_trait]
     impl SchedulerWorkerAccess for SchedulerWorkerAccessMock {
         async fn activate_worker(&self, _created_by: &AccountId, _owned_worker_id: &OwnedWorkerId) {
@@ -483,6 +1354,13 @@ mod tests {
         ) -> Result<(), WorkerExecutorError> {
             unimplemented!()
         }
+        async fn delete_worker(
+            &self,
+            _created_by: &AccountId,
+            _owned_worker_id: &OwnedWorkerId,
+        ) -> Result<(), WorkerExecutorError> {
+            unimplemented!()
+        }
     }
 
     fn serialized_bytes<T: Encode>(entry: &T) -> Vec<u8> {
@@ -581,6 +1459,7 @@ mod tests {
             oplog_service,
             worker_service,
             Duration::from_secs(1000), // not testing process() here
+            Duration::from_secs(3600),
         );
 
         let account_id = AccountId {
@@ -712,6 +1591,7 @@ mod tests {
             oplog_service,
             worker_service,
             Duration::from_secs(1000), // not testing process() here
+            Duration::from_secs(3600),
         );
 
         let account_id = AccountId {
@@ -825,6 +1705,7 @@ mod tests {
             oplog_service,
             worker_service,
             Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
         );
 
         let account_id = AccountId {
@@ -943,6 +1824,7 @@ mod tests {
             oplog_service,
             worker_service,
             Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
         );
 
         let account_id = AccountId {
@@ -1058,6 +1940,7 @@ mod tests {
             oplog_service,
             worker_service,
             Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
         );
 
         let account_id = AccountId {
@@ -1180,6 +2063,7 @@ mod tests {
             oplog_service,
             worker_service,
             Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
         );
 
         let account_id = AccountId {
@@ -1241,4 +2125,295 @@ mod tests {
         assert!(completed_promises.contains(&p3));
         assert!(completed_promises.contains(&p2));
     }
+
+    #[test]
+    pub async fn invocation_schedules_are_rearmed_until_canceled() {
+        let owned_worker_id = OwnedWorkerId::new(
+            &ProjectId::new_v4(),
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "inst1".to_string(),
+            },
+        );
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_access = Arc::new(RecordingSchedulerWorkerAccess::default());
+        let oplog_service = create_oplog_service_mock().await;
+        let golem_config = Arc::new(GolemConfig::default());
+        let worker_service = create_worker_service_mock(
+            kvs.clone(),
+            shard_service.clone(),
+            oplog_service.clone(),
+            golem_config,
+        );
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_access.clone(),
+            oplog_service,
+            worker_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
+        );
+
+        let account_id = AccountId {
+            value: "test_account".to_string(),
+        };
+
+        let schedule = svc
+            .create_invocation_schedule(
+                &account_id,
+                &owned_worker_id,
+                "run".to_string(),
+                vec![Value::U32(1)],
+                InvocationContextStack::fresh(),
+                InvocationScheduleSpec::Cron(CronInvocationSchedule {
+                    expression: "0 * * * *".to_string(),
+                }),
+            )
+            .await
+            .unwrap();
+        let first = schedule.next_invocation_at.unwrap();
+
+        svc.process(Utc::now() + chrono::Duration::hours(1))
+            .await
+            .unwrap();
+
+        let invocations = worker_access.invocations.lock().unwrap().clone();
+        assert_eq!(invocations.len(), 1);
+        assert_eq!(invocations[0].1, "run");
+
+        let schedules = svc
+            .get_invocation_schedules(&owned_worker_id)
+            .await
+            .unwrap();
+        assert_eq!(schedules.len(), 1);
+        assert_eq!(schedules[0].invocation_count, 1);
+        assert_eq!(
+            schedules[0].next_invocation_at,
+            Some(Timestamp::from(first.to_millis() + 60 * 60 * 1000))
+        );
+
+        assert!(svc
+            .cancel_invocation_schedule(&owned_worker_id, &schedule.schedule_id)
+            .await
+            .unwrap());
+        assert!(!svc
+            .cancel_invocation_schedule(&owned_worker_id, &schedule.schedule_id)
+            .await
+            .unwrap());
+        assert!(svc
+            .get_invocation_schedules(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(kvs
+            .sorted_sets()
+            .iter()
+            .all(|entry| entry.value().is_empty()));
+    }
+
+    #[test]
+    pub async fn invocation_schedules_canceled_while_processed_are_not_rearmed() {
+        let owned_worker_id = OwnedWorkerId::new(
+            &ProjectId::new_v4(),
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "inst1".to_string(),
+            },
+        );
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_access = Arc::new(BlockingSchedulerWorkerAccess::default());
+        let oplog_service = create_oplog_service_mock().await;
+        let golem_config = Arc::new(GolemConfig::default());
+        let worker_service = create_worker_service_mock(
+            kvs.clone(),
+            shard_service.clone(),
+            oplog_service.clone(),
+            golem_config,
+        );
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_access.clone(),
+            oplog_service,
+            worker_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
+        );
+
+        let account_id = AccountId {
+            value: "test_account".to_string(),
+        };
+
+        let schedule = svc
+            .create_invocation_schedule(
+                &account_id,
+                &owned_worker_id,
+                "run".to_string(),
+                vec![Value::U32(1)],
+                InvocationContextStack::fresh(),
+                InvocationScheduleSpec::Cron(CronInvocationSchedule {
+                    expression: "0 * * * *".to_string(),
+                }),
+            )
+            .await
+            .unwrap();
+
+        let process = {
+            let svc = svc.clone();
+            tokio::spawn(async move { svc.process(Utc::now() + chrono::Duration::hours(1)).await })
+        };
+        worker_access.entered.notified().await;
+
+        let cancel = {
+            let svc = svc.clone();
+            let owned_worker_id = owned_worker_id.clone();
+            let schedule_id = schedule.schedule_id.clone();
+            tokio::spawn(async move {
+                svc.cancel_invocation_schedule(&owned_worker_id, &schedule_id)
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!cancel.is_finished());
+
+        worker_access.release.notify_one();
+        process.await.unwrap().unwrap();
+        assert!(cancel.await.unwrap().unwrap());
+
+        assert!(svc
+            .get_invocation_schedules(&owned_worker_id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(kvs
+            .sorted_sets()
+            .iter()
+            .all(|entry| entry.value().is_empty()));
+    }
+
+    #[test]
+    pub async fn ttl_checks_are_only_scheduled_for_workers_with_a_policy() {
+        let owned_worker_id = OwnedWorkerId::new(
+            &ProjectId::new_v4(),
+            &WorkerId {
+                component_id: ComponentId(Uuid::new_v4()),
+                worker_name: "inst1".to_string(),
+            },
+        );
+
+        let kvs = Arc::new(InMemoryKeyValueStorage::new());
+
+        let shard_service = create_shard_service_mock();
+        let promise_service = create_promise_service_mock();
+        let worker_access = create_worker_access_mock();
+        let oplog_service = create_oplog_service_mock().await;
+        let golem_config = Arc::new(GolemConfig::default());
+        let worker_service = create_worker_service_mock(
+            kvs.clone(),
+            shard_service.clone(),
+            oplog_service.clone(),
+            golem_config,
+        );
+
+        let svc = SchedulerServiceDefault::new(
+            kvs.clone(),
+            shard_service,
+            promise_service,
+            worker_access,
+            oplog_service,
+            worker_service,
+            Duration::from_secs(1000), // explicitly calling process for testing
+            Duration::from_secs(3600),
+        );
+
+        let account_id = AccountId {
+            value: "test_account".to_string(),
+        };
+        let component_policy = WorkerTtlPolicy {
+            max_idle_time_millis: Some(60 * 1000),
+            max_age_millis: None,
+            delete_on_exit: true,
+        };
+        let worker_policy = WorkerTtlPolicy {
+            max_idle_time_millis: None,
+            max_age_millis: Some(60 * 60 * 1000),
+            delete_on_exit: false,
+        };
+
+        svc.schedule_ttl_check(&account_id, &owned_worker_id, OplogIndex::from_u64(10))
+            .await;
+        assert!(kvs
+            .sorted_sets()
+            .iter()
+            .all(|entry| entry.value().is_empty()));
+
+        svc.set_component_ttl_policy(
+            &owned_worker_id.project_id,
+            &owned_worker_id.worker_id.component_id,
+            Some(component_policy.clone()),
+        )
+        .await
+        .unwrap();
+        svc.set_worker_ttl_policy(&owned_worker_id, Some(worker_policy.clone()))
+            .await
+            .unwrap();
+        assert_eq!(
+            svc.get_worker_ttl_policy(&owned_worker_id).await.unwrap(),
+            Some(worker_policy)
+        );
+
+        svc.set_worker_ttl_policy(&owned_worker_id, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            svc.get_worker_ttl_policy(&owned_worker_id).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            svc.get_component_ttl_policy(
+                &owned_worker_id.project_id,
+                &owned_worker_id.worker_id.component_id
+            )
+            .await
+            .unwrap(),
+            Some(component_policy)
+        );
+
+        svc.schedule_ttl_check(&account_id, &owned_worker_id, OplogIndex::from_u64(10))
+            .await;
+        let scheduled: usize = kvs
+            .sorted_sets()
+            .iter()
+            .map(|entry| entry.value().len())
+            .sum();
+        assert_eq!(scheduled, 1);
+
+        // The worker does not exist, so the check is a no-op that gets removed from the queue
+        svc.process(Utc::now()).await.unwrap();
+        assert!(kvs
+            .sorted_sets()
+            .iter()
+            .all(|entry| entry.value().is_empty()));
+        assert!(svc
+            .get_ttl_deletions(
+                &owned_worker_id.project_id,
+                &owned_worker_id.worker_id.component_id
+            )
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
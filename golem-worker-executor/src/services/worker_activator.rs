@@ -52,6 +52,13 @@ pub trait WorkerActivator<Ctx: WorkerCtx>: Send + Sync {
         component_version: Option<u64>,
         parent: Option<WorkerId>,
     ) -> Result<Arc<Worker<Ctx>>, WorkerExecutorError>;
+
+    /// Deletes a worker, interrupting it first if it is running
+    async fn delete_worker(
+        &self,
+        created_by: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError>;
 }
 
 pub struct LazyWorkerActivator<Ctx: WorkerCtx> {
@@ -151,6 +158,24 @@ impl<Ctx: WorkerCtx> WorkerActivator<Ctx> for LazyWorkerActivator<Ctx> {
             )),
         }
     }
+
+    async fn delete_worker(
+        &self,
+        created_by: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError> {
+        let maybe_worker_activator = self.worker_activator.lock().unwrap().clone();
+        match maybe_worker_activator {
+            Some(worker_activator) => {
+                worker_activator
+                    .delete_worker(created_by, owned_worker_id)
+                    .await
+            }
+            None => Err(WorkerExecutorError::runtime(
+                "WorkerActivator is disabled, not deleting instance",
+            )),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -242,4 +267,12 @@ impl<Ctx: WorkerCtx, Svcs: HasAll<Ctx> + Send + Sync + 'static> WorkerActivator<
         )
         .await
     }
+
+    async fn delete_worker(
+        &self,
+        created_by: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError> {
+        Worker::delete(&self.all, created_by, owned_worker_id).await
+    }
 }
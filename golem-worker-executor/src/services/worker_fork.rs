@@ -22,18 +22,20 @@ use crate::services::oplog::plugin::OplogProcessorPlugin;
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
 use crate::services::plugins::Plugins;
 use crate::services::projects::ProjectService;
+use crate::services::pubsub::restore_subscriptions;
 use crate::services::resource_limits::ResourceLimits;
 use crate::services::rpc::Rpc;
 use crate::services::shard::ShardService;
 use crate::services::worker_proxy::WorkerProxy;
 use crate::services::{
-    active_workers, blob_store, component, golem_config, key_value, oplog, promise, scheduler,
-    shard_manager, worker, worker_activator, worker_enumeration, HasActiveWorkers,
+    active_workers, blob_store, component, golem_config, key_value, oplog, promise, pubsub, saga,
+    scheduler, shard_manager, worker, worker_activator, worker_enumeration, HasActiveWorkers,
     HasBlobStoreService, HasComponentService, HasConfig, HasEvents, HasExtraDeps, HasFileLoader,
     HasKeyValueService, HasOplogProcessorPlugin, HasOplogService, HasPlugins, HasProjectService,
-    HasPromiseService, HasResourceLimits, HasRpc, HasRunningWorkerEnumerationService,
-    HasSchedulerService, HasShardManagerService, HasShardService, HasWasmtimeEngine,
-    HasWorkerActivator, HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
+    HasPromiseService, HasPubSubService, HasResourceLimits, HasRpc,
+    HasRunningWorkerEnumerationService, HasSagaService, HasSchedulerService,
+    HasShardManagerService, HasShardService, HasWasmtimeEngine, HasWorkerActivator,
+    HasWorkerEnumerationService, HasWorkerProxy, HasWorkerService,
 };
 use crate::services::{rdbms, HasOplog, HasRdbmsService, HasWorkerForkService};
 use crate::worker::Worker;
@@ -44,6 +46,7 @@ use golem_common::model::{AccountId, ProjectId, Timestamp, WorkerMetadata, Worke
 use golem_common::model::{OwnedWorkerId, WorkerId};
 use golem_common::serialization::serialize;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::sync::RwLock;
 use tokio::runtime::Handle;
@@ -81,6 +84,8 @@ pub struct DefaultWorkerFork<Ctx: WorkerCtx> {
     pub running_worker_enumeration_service:
         Arc<dyn worker_enumeration::RunningWorkerEnumerationService>,
     pub promise_service: Arc<dyn promise::PromiseService>,
+    pub pubsub_service: Arc<dyn pubsub::PubSubService>,
+    pub saga_service: Arc<dyn saga::SagaService>,
     pub golem_config: Arc<golem_config::GolemConfig>,
     pub shard_service: Arc<dyn ShardService>,
     pub key_value_service: Arc<dyn key_value::KeyValueService>,
@@ -148,6 +153,18 @@ impl<Ctx: WorkerCtx> HasPromiseService for DefaultWorkerFork<Ctx> {
     }
 }
 
+impl<Ctx: WorkerCtx> HasPubSubService for DefaultWorkerFork<Ctx> {
+    fn pubsub_service(&self) -> Arc<dyn pubsub::PubSubService> {
+        self.pubsub_service.clone()
+    }
+}
+
+impl<Ctx: WorkerCtx> HasSagaService for DefaultWorkerFork<Ctx> {
+    fn saga_service(&self) -> Arc<dyn saga::SagaService> {
+        self.saga_service.clone()
+    }
+}
+
 impl<Ctx: WorkerCtx> HasWasmtimeEngine<Ctx> for DefaultWorkerFork<Ctx> {
     fn engine(&self) -> Arc<wasmtime::Engine> {
         self.engine.clone()
@@ -279,6 +296,8 @@ impl<Ctx: WorkerCtx> Clone for DefaultWorkerFork<Ctx> {
             worker_enumeration_service: self.worker_enumeration_service.clone(),
             running_worker_enumeration_service: self.running_worker_enumeration_service.clone(),
             promise_service: self.promise_service.clone(),
+            pubsub_service: self.pubsub_service.clone(),
+            saga_service: self.saga_service.clone(),
             golem_config: self.golem_config.clone(),
             shard_service: self.shard_service.clone(),
             key_value_service: self.key_value_service.clone(),
@@ -315,6 +334,8 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
             dyn worker_enumeration::RunningWorkerEnumerationService,
         >,
         promise_service: Arc<dyn promise::PromiseService>,
+        pubsub_service: Arc<dyn pubsub::PubSubService>,
+        saga_service: Arc<dyn saga::SagaService>,
         golem_config: Arc<golem_config::GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn key_value::KeyValueService>,
@@ -344,6 +365,8 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config,
             shard_service,
             key_value_service,
@@ -454,6 +477,13 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
 
         source_oplog.commit(CommitLevel::Always).await;
 
+        let last_oplog_index = source_oplog.current_oplog_index().await;
+        if oplog_index_cut_off > last_oplog_index {
+            return Err(WorkerExecutorError::invalid_request(format!(
+                "oplog_index_cut_off must not be greater than the last oplog index of the source worker ({last_oplog_index})"
+            )));
+        }
+
         let initial_oplog_entry = source_oplog.read(OplogIndex::INITIAL).await;
 
         // Update the oplog initial entry with the new worker
@@ -479,11 +509,39 @@ impl<Ctx: WorkerCtx> DefaultWorkerFork<Ctx> {
 
         let oplog_range = OplogIndexRange::new(OplogIndex::INITIAL.next(), oplog_index_cut_off);
 
+        let mut copied_entries = BTreeMap::new();
         for oplog_index in oplog_range {
             let entry = source_oplog.read(oplog_index).await;
             new_oplog.add(entry.clone()).await;
+            copied_entries.insert(oplog_index, entry);
         }
 
+        // Files archived by snapshot-based updates within the copied range are needed to recreate the target
+        self.file_loader
+            .copy_worker_files(
+                &owned_source_worker_id,
+                &owned_target_worker_id,
+                oplog_index_cut_off,
+            )
+            .await?;
+
+        // The copied archive of the files is counted for the account of the target worker
+        let archived_size = self
+            .file_loader
+            .archived_size(&owned_target_worker_id)
+            .await?;
+        self.resource_limits
+            .update_storage_usage(fork_account_id, archived_size as i64);
+
+        // The target inherits the topic subscriptions of the source made before the cut-off point
+        restore_subscriptions(
+            self.pubsub_service(),
+            source_oplog.clone(),
+            &owned_target_worker_id,
+            &copied_entries,
+        )
+        .await?;
+
         Ok(new_oplog)
     }
 }
@@ -12,15 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+use crate::storage::keyvalue::{
+    decode_counter, encode_counter, KeyPage, KeyValueStorage, KeyValueStorageNamespace,
+};
 use async_trait::async_trait;
 use bytes::Bytes;
 use dashmap::mapref::entry::Entry;
 use dashmap::{DashMap, DashSet};
+use golem_common::model::Timestamp;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct InMemoryKeyValueStorage {
     kvs: DashMap<String, Vec<u8>>,
+    /// Expiration times of the keys in `kvs`, in milliseconds since the epoch
+    expirations: DashMap<String, u64>,
     sets: DashMap<String, DashSet<Vec<u8>>>,
     sorted_sets: DashMap<String, Vec<(f64, Vec<u8>)>>,
 }
@@ -35,6 +41,7 @@ impl InMemoryKeyValueStorage {
     pub fn new() -> Self {
         Self {
             kvs: DashMap::new(),
+            expirations: DashMap::new(),
             sets: DashMap::new(),
             sorted_sets: DashMap::new(),
         }
@@ -55,6 +62,20 @@ impl InMemoryKeyValueStorage {
     fn composite_key(namespace: &KeyValueStorageNamespace, key: &str) -> String {
         format!("{namespace:?}/{key}")
     }
+
+    fn is_expired(&self, composite_key: &str, now: u64) -> bool {
+        self.expirations
+            .get(composite_key)
+            .is_some_and(|expires_at| *expires_at <= now)
+    }
+
+    /// Removes the entry if it has expired
+    fn purge_if_expired(&self, composite_key: &str) {
+        if self.is_expired(composite_key, Timestamp::now_utc().to_millis()) {
+            self.expirations.remove(composite_key);
+            self.kvs.remove(composite_key);
+        }
+    }
 }
 
 #[async_trait]
@@ -68,8 +89,28 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         key: &str,
         value: &[u8],
     ) -> Result<(), String> {
-        self.kvs
-            .insert(Self::composite_key(&namespace, key), value.to_vec());
+        let composite_key = Self::composite_key(&namespace, key);
+        self.expirations.remove(&composite_key);
+        self.kvs.insert(composite_key, value.to_vec());
+        Ok(())
+    }
+
+    async fn set_with_ttl(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+        ttl: Duration,
+    ) -> Result<(), String> {
+        let composite_key = Self::composite_key(&namespace, key);
+        self.expirations.insert(
+            composite_key.clone(),
+            Timestamp::now_utc().to_millis() + ttl.as_millis() as u64,
+        );
+        self.kvs.insert(composite_key, value.to_vec());
         Ok(())
     }
 
@@ -82,8 +123,9 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         pairs: &[(&str, &[u8])],
     ) -> Result<(), String> {
         for (key, value) in pairs {
-            self.kvs
-                .insert(Self::composite_key(&namespace, key), value.to_vec());
+            let composite_key = Self::composite_key(&namespace, key);
+            self.expirations.remove(&composite_key);
+            self.kvs.insert(composite_key, value.to_vec());
         }
         Ok(())
     }
@@ -97,7 +139,9 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         key: &str,
         value: &[u8],
     ) -> Result<bool, String> {
-        match self.kvs.entry(Self::composite_key(&namespace, key)) {
+        let composite_key = Self::composite_key(&namespace, key);
+        self.purge_if_expired(&composite_key);
+        match self.kvs.entry(composite_key) {
             Entry::Occupied(_) => Ok(false),
             Entry::Vacant(entry) => {
                 entry.insert(value.to_vec());
@@ -114,7 +158,9 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<Option<Bytes>, String> {
-        match self.kvs.get(&Self::composite_key(&namespace, key)) {
+        let composite_key = Self::composite_key(&namespace, key);
+        self.purge_if_expired(&composite_key);
+        match self.kvs.get(&composite_key) {
             Some(value) => Ok(Some(Bytes::from(value.value().clone()))),
             None => Ok(None),
         }
@@ -145,7 +191,9 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<(), String> {
-        self.kvs.remove(&Self::composite_key(&namespace, key));
+        let composite_key = Self::composite_key(&namespace, key);
+        self.expirations.remove(&composite_key);
+        self.kvs.remove(&composite_key);
         Ok(())
     }
 
@@ -170,7 +218,9 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<bool, String> {
-        Ok(self.kvs.contains_key(&Self::composite_key(&namespace, key)))
+        let composite_key = Self::composite_key(&namespace, key);
+        self.purge_if_expired(&composite_key);
+        Ok(self.kvs.contains_key(&composite_key))
     }
 
     async fn keys(
@@ -180,11 +230,12 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
         namespace: KeyValueStorageNamespace,
     ) -> Result<Vec<String>, String> {
         let prefix = Self::composite_key(&namespace, "");
+        let now = Timestamp::now_utc().to_millis();
         Ok(self
             .kvs
             .iter()
             .filter_map(|item| {
-                if item.key().starts_with(&prefix) {
+                if item.key().starts_with(&prefix) && !self.is_expired(item.key(), now) {
                     Some(item.key()[prefix.len()..].to_string())
                 } else {
                     None
@@ -193,6 +244,91 @@ impl KeyValueStorage for InMemoryKeyValueStorage {
             .collect())
     }
 
+    async fn list_keys(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        prefix: Option<&str>,
+        cursor: Option<&str>,
+        limit: u64,
+    ) -> Result<KeyPage, String> {
+        let prefix = Self::composite_key(&namespace, prefix.unwrap_or_default());
+        let namespace_prefix_len = Self::composite_key(&namespace, "").len();
+        let now = Timestamp::now_utc().to_millis();
+
+        let mut keys: Vec<String> = self
+            .kvs
+            .iter()
+            .filter(|item| item.key().starts_with(&prefix) && !self.is_expired(item.key(), now))
+            .map(|item| item.key()[namespace_prefix_len..].to_string())
+            .filter(|key| cursor.is_none_or(|cursor| key.as_str() > cursor))
+            .collect();
+        keys.sort();
+
+        let limit = limit as usize;
+        if keys.len() > limit {
+            keys.truncate(limit);
+            let cursor = keys.last().cloned();
+            Ok(KeyPage { keys, cursor })
+        } else {
+            Ok(KeyPage { keys, cursor: None })
+        }
+    }
+
+    async fn increment(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        delta: u64,
+    ) -> Result<u64, String> {
+        let composite_key = Self::composite_key(&namespace, key);
+        self.purge_if_expired(&composite_key);
+        let mut entry = self
+            .kvs
+            .entry(composite_key)
+            .or_insert_with(|| encode_counter(0));
+        let current = decode_counter(Some(entry.value()))?;
+        let new = current
+            .checked_add(delta)
+            .ok_or_else(|| "Counter overflow".to_string())?;
+        *entry.value_mut() = encode_counter(new);
+        Ok(new)
+    }
+
+    async fn compare_and_swap(
+        &self,
+        _svc_name: &'static str,
+        _api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        old: u64,
+        new: u64,
+    ) -> Result<bool, String> {
+        let composite_key = Self::composite_key(&namespace, key);
+        self.purge_if_expired(&composite_key);
+        match self.kvs.entry(composite_key) {
+            Entry::Occupied(mut entry) => {
+                if decode_counter(Some(entry.get()))? == old {
+                    entry.insert(encode_counter(new));
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+            Entry::Vacant(entry) => {
+                if old == 0 {
+                    entry.insert(encode_counter(new));
+                    Ok(true)
+                } else {
+                    Ok(false)
+                }
+            }
+        }
+    }
+
     async fn add_to_set(
         &self,
         _svc_name: &'static str,
@@ -22,6 +22,7 @@ use bytes::Bytes;
 use golem_common::base_model::ProjectId;
 use golem_common::serialization::{deserialize, serialize};
 use std::fmt::Debug;
+use std::time::Duration;
 
 #[async_trait]
 pub trait KeyValueStorage: Debug {
@@ -35,6 +36,19 @@ pub trait KeyValueStorage: Debug {
         value: &[u8],
     ) -> Result<(), String>;
 
+    /// Sets the value of `key`, expiring it after `ttl`. Setting the key again without a TTL removes
+    /// the expiration.
+    async fn set_with_ttl(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+        ttl: Duration,
+    ) -> Result<(), String>;
+
     async fn set_many(
         &self,
         svc_name: &'static str,
@@ -103,6 +117,43 @@ pub trait KeyValueStorage: Debug {
         namespace: KeyValueStorageNamespace,
     ) -> Result<Vec<String>, String>;
 
+    /// Lists a page of keys in the namespace, optionally only the ones starting with `prefix`.
+    ///
+    /// The `cursor` is either `None` for the first page or the cursor returned with the previous
+    /// page. Its format is specific to the storage implementation, and `limit` may be treated as a hint.
+    async fn list_keys(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        prefix: Option<&str>,
+        cursor: Option<&str>,
+        limit: u64,
+    ) -> Result<KeyPage, String>;
+
+    /// Atomically increments the counter stored at `key` by `delta`, treating a missing key as 0,
+    /// and returns the new value. Counters are stored as decimal ASCII strings.
+    async fn increment(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        delta: u64,
+    ) -> Result<u64, String>;
+
+    /// Atomically replaces the counter stored at `key` with `new` if its current value is `old`,
+    /// treating a missing key as 0. Returns whether the swap happened.
+    async fn compare_and_swap(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        old: u64,
+        new: u64,
+    ) -> Result<bool, String>;
+
     async fn add_to_set(
         &self,
         svc_name: &'static str,
@@ -253,6 +304,48 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledKeyValueStorage<'a, S> {
             .keys(self.svc_name, self.api_name, namespace)
             .await
     }
+
+    pub async fn list_keys(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        prefix: Option<&str>,
+        cursor: Option<&str>,
+        limit: u64,
+    ) -> Result<KeyPage, String> {
+        self.storage
+            .list_keys(
+                self.svc_name,
+                self.api_name,
+                namespace,
+                prefix,
+                cursor,
+                limit,
+            )
+            .await
+    }
+
+    pub async fn increment(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        delta: u64,
+    ) -> Result<u64, String> {
+        self.storage
+            .increment(self.svc_name, self.api_name, namespace, key, delta)
+            .await
+    }
+
+    pub async fn compare_and_swap(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        old: u64,
+        new: u64,
+    ) -> Result<bool, String> {
+        self.storage
+            .compare_and_swap(self.svc_name, self.api_name, namespace, key, old, new)
+            .await
+    }
 }
 
 pub struct LabelledEntityKeyValueStorage<'a, S: KeyValueStorage + ?Sized> {
@@ -297,6 +390,28 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledEntityKeyValueStorage<'a, S> {
             .await
     }
 
+    pub async fn set_with_ttl<V: Encode>(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &V,
+        ttl: Duration,
+    ) -> Result<(), String> {
+        let serialized = serialize(value)?;
+
+        self.storage
+            .set_with_ttl(
+                self.svc_name,
+                self.api_name,
+                self.entity_name,
+                namespace,
+                key,
+                &serialized,
+                ttl,
+            )
+            .await
+    }
+
     pub async fn set_raw(
         &self,
         namespace: KeyValueStorageNamespace,
@@ -315,6 +430,26 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledEntityKeyValueStorage<'a, S> {
             .await
     }
 
+    pub async fn set_raw_with_ttl(
+        &self,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+        ttl: Duration,
+    ) -> Result<(), String> {
+        self.storage
+            .set_with_ttl(
+                self.svc_name,
+                self.api_name,
+                self.entity_name,
+                namespace,
+                key,
+                value,
+                ttl,
+            )
+            .await
+    }
+
     pub async fn set_if_not_exists<V: Encode>(
         &self,
         namespace: KeyValueStorageNamespace,
@@ -619,13 +754,45 @@ impl<'a, S: ?Sized + KeyValueStorage> LabelledEntityKeyValueStorage<'a, S> {
     }
 }
 
+/// A page of keys returned by [`KeyValueStorage::list_keys`]
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct KeyPage {
+    pub keys: Vec<String>,
+    /// Cursor of the next page, `None` if there are no more keys
+    pub cursor: Option<String>,
+}
+
+/// Decodes a counter value used by the atomic operations, treating a missing value as 0.
+pub(crate) fn decode_counter(value: Option<&[u8]>) -> Result<u64, String> {
+    match value {
+        None => Ok(0),
+        Some(bytes) => std::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .ok_or_else(|| "The stored value is not a valid unsigned 64-bit counter".to_string()),
+    }
+}
+
+/// Encodes a counter value used by the atomic operations
+pub(crate) fn encode_counter(value: u64) -> Vec<u8> {
+    value.to_string().into_bytes()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum KeyValueStorageNamespace {
     Worker,
     Promise,
     Schedule,
+    /// Topic subscriptions of workers
+    PubSub,
+    /// Compensation progress of the aborted sagas coordinated by workers
+    Saga,
     UserDefined {
         project_id: ProjectId,
         bucket: String,
     },
+    /// Backing store of the project's `wasi:keyvalue/cache` entries
+    Cache {
+        project_id: ProjectId,
+    },
 }
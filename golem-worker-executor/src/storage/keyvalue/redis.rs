@@ -14,22 +14,151 @@
 
 use async_trait::async_trait;
 use bytes::Bytes;
-use fred::types::SetOptions;
+use dashmap::mapref::entry::Entry;
+use dashmap::DashMap;
+use fred::types::{Expiration, RedisValue, SetOptions};
 use golem_common::metrics::redis::{record_redis_deserialized_size, record_redis_serialized_size};
+use golem_common::model::Timestamp;
 use golem_common::redis::RedisPool;
 use std::collections::HashMap;
-use tracing::debug;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn, Instrument};
 
-use crate::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+use crate::storage::keyvalue::{KeyPage, KeyValueStorage, KeyValueStorageNamespace};
+
+/// Atomically swaps a counter stored in a hash field, treating a missing field as 0
+const HASH_COMPARE_AND_SWAP_SCRIPT: &str = r#"
+local current = redis.call('HGET', KEYS[1], ARGV[1]) or '0'
+if current == ARGV[2] then
+    redis.call('HSET', KEYS[1], ARGV[1], ARGV[3])
+    return 1
+else
+    return 0
+end
+"#;
+
+/// Atomically swaps a counter stored in a plain key, treating a missing key as 0
+const COMPARE_AND_SWAP_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1]) or '0'
+if current == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[2])
+    return 1
+else
+    return 0
+end
+"#;
+
+/// Sets a hash field and records its expiration time
+const SET_WITH_TTL_SCRIPT: &str = r#"
+redis.call('HSET', KEYS[1], ARGV[1], ARGV[2])
+redis.call('ZADD', KEYS[2], ARGV[3], ARGV[1])
+return 1
+"#;
+
+/// Sets hash fields given as field-value pairs, removing their expiration times
+const SET_WITHOUT_TTL_SCRIPT: &str = r#"
+for i = 1, #ARGV, 2 do
+    redis.call('HSET', KEYS[1], ARGV[i], ARGV[i + 1])
+    redis.call('ZREM', KEYS[2], ARGV[i])
+end
+return 1
+"#;
+
+/// Deletes hash fields together with their expiration times
+const DELETE_SCRIPT: &str = r#"
+for i = 1, #ARGV do
+    redis.call('HDEL', KEYS[1], ARGV[i])
+    redis.call('ZREM', KEYS[2], ARGV[i])
+end
+return 1
+"#;
+
+/// Gets hash fields, returning nil for the fields expired at `ARGV[1]`
+const GET_UNEXPIRED_SCRIPT: &str = r#"
+local result = {}
+for i = 2, #ARGV do
+    local expires_at = redis.call('ZSCORE', KEYS[2], ARGV[i])
+    if expires_at and tonumber(expires_at) <= tonumber(ARGV[1]) then
+        result[i - 1] = false
+    else
+        result[i - 1] = redis.call('HGET', KEYS[1], ARGV[i])
+    end
+end
+return result
+"#;
+
+/// Returns the given hash fields which exist and are not expired at `ARGV[1]`
+const EXISTING_UNEXPIRED_SCRIPT: &str = r#"
+local result = {}
+for i = 2, #ARGV do
+    local expires_at = redis.call('ZSCORE', KEYS[2], ARGV[i])
+    if not (expires_at and tonumber(expires_at) <= tonumber(ARGV[1]))
+        and redis.call('HEXISTS', KEYS[1], ARGV[i]) == 1 then
+        table.insert(result, ARGV[i])
+    end
+end
+return result
+"#;
+
+/// Deletes the hash field `ARGV[1]` if it expired at `ARGV[2]`, to be used before updating it
+const DELETE_IF_EXPIRED: &str = r#"
+local expires_at = redis.call('ZSCORE', KEYS[2], ARGV[1])
+if expires_at and tonumber(expires_at) <= tonumber(ARGV[2]) then
+    redis.call('HDEL', KEYS[1], ARGV[1])
+    redis.call('ZREM', KEYS[2], ARGV[1])
+end
+"#;
+
+/// Sets a hash field if it does not exist or expired
+const EXPIRING_SET_IF_NOT_EXISTS_SCRIPT: &str = r#"
+return redis.call('HSETNX', KEYS[1], ARGV[1], ARGV[3])
+"#;
+
+/// Increments a hash field, treating an expired field as 0
+const EXPIRING_INCREMENT_SCRIPT: &str = r#"
+return redis.call('HINCRBY', KEYS[1], ARGV[1], ARGV[3])
+"#;
+
+/// Atomically swaps a counter stored in a hash field, treating a missing or expired field as 0
+const EXPIRING_COMPARE_AND_SWAP_SCRIPT: &str = r#"
+local current = redis.call('HGET', KEYS[1], ARGV[1]) or '0'
+if current == ARGV[3] then
+    redis.call('HSET', KEYS[1], ARGV[1], ARGV[4])
+    return 1
+else
+    return 0
+end
+"#;
+
+/// Deletes at most `ARGV[2]` of the hash fields expired at `ARGV[1]`
+const PURGE_EXPIRED_SCRIPT: &str = r#"
+local expired = redis.call('ZRANGEBYSCORE', KEYS[2], '-inf', ARGV[1], 'LIMIT', 0, tonumber(ARGV[2]))
+if #expired > 0 then
+    redis.call('HDEL', KEYS[1], unpack(expired))
+    redis.call('ZREM', KEYS[2], unpack(expired))
+end
+return #expired
+"#;
+
+/// Maximum number of expired fields deleted by a single purge
+const PURGE_BATCH_SIZE: u64 = 1000;
+
+/// Minimum time between two purges of the same namespace
+const PURGE_INTERVAL: Duration = Duration::from_secs(10);
 
 #[derive(Debug)]
 pub struct RedisKeyValueStorage {
     redis: RedisPool,
+    last_purges: Arc<DashMap<String, Instant>>,
 }
 
 impl RedisKeyValueStorage {
     pub fn new(redis: RedisPool) -> Self {
-        Self { redis }
+        Self {
+            redis,
+            last_purges: Arc::new(DashMap::new()),
+        }
     }
 
     fn use_hash(namespace: &KeyValueStorageNamespace) -> Option<String> {
@@ -37,11 +166,142 @@ impl RedisKeyValueStorage {
             KeyValueStorageNamespace::Worker => None,
             KeyValueStorageNamespace::Promise => Some("promises".to_string()),
             KeyValueStorageNamespace::Schedule => None,
+            KeyValueStorageNamespace::PubSub => None,
+            KeyValueStorageNamespace::Saga => None,
             KeyValueStorageNamespace::UserDefined { project_id, bucket } => {
                 Some(format!("user-defined:{project_id}:{bucket}"))
             }
+            KeyValueStorageNamespace::Cache { project_id } => Some(format!("cache:{project_id}")),
+        }
+    }
+
+    /// Redis (before 7.4) cannot expire individual hash fields, so for the namespaces supporting
+    /// expiring keys the expiration times are kept in a sorted set. Reads skip the expired fields,
+    /// and the expired fields are deleted in bounded batches in the background.
+    fn expirations_key(namespace: &KeyValueStorageNamespace) -> Option<String> {
+        match namespace {
+            KeyValueStorageNamespace::UserDefined { .. }
+            | KeyValueStorageNamespace::Cache { .. } => {
+                Self::use_hash(namespace).map(|ns| format!("expirations:{ns}"))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the hash and the expirations sorted set of namespaces supporting expiring keys
+    fn expiring_hash(namespace: &KeyValueStorageNamespace) -> Option<(String, String)> {
+        Self::use_hash(namespace).zip(Self::expirations_key(namespace))
+    }
+
+    /// Deletes a batch of expired fields of the namespace in the background,
+    /// at most once per `PURGE_INTERVAL`
+    fn purge_expired_in_background(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: &KeyValueStorageNamespace,
+    ) {
+        if let Some((ns, expirations)) = Self::expiring_hash(namespace) {
+            let now = Instant::now();
+            let due = match self.last_purges.entry(ns.clone()) {
+                Entry::Occupied(mut entry) => {
+                    if now.duration_since(*entry.get()) >= PURGE_INTERVAL {
+                        entry.insert(now);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(now);
+                    true
+                }
+            };
+
+            if due {
+                let redis = self.redis.clone();
+                tokio::spawn(
+                    async move {
+                        let result: Result<i64, _> = redis
+                            .with(svc_name, api_name)
+                            .eval(
+                                PURGE_EXPIRED_SCRIPT,
+                                vec![ns, expirations],
+                                vec![
+                                    Timestamp::now_utc().to_millis().to_string(),
+                                    PURGE_BATCH_SIZE.to_string(),
+                                ],
+                            )
+                            .await;
+                        if let Err(err) = result {
+                            warn!("Failed to purge expired keys: {err}");
+                        }
+                    }
+                    .in_current_span(),
+                );
+            }
         }
     }
+
+    /// Runs `script` on an expiring hash field after deleting it if it expired.
+    /// The arguments of `script` start from `ARGV[3]`.
+    async fn eval_on_unexpired<R: fred::types::FromRedis>(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        (ns, expirations): (String, String),
+        script: &str,
+        key: &str,
+        args: Vec<RedisValue>,
+    ) -> Result<R, String> {
+        let mut all_args = vec![
+            RedisValue::from(key),
+            RedisValue::from(Timestamp::now_utc().to_millis().to_string()),
+        ];
+        all_args.extend(args);
+        self.redis
+            .with(svc_name, api_name)
+            .eval(
+                &format!("{DELETE_IF_EXPIRED}{script}"),
+                vec![ns, expirations],
+                all_args,
+            )
+            .await
+            .map_err(|redis_err| redis_err.to_string())
+    }
+
+    /// Returns the given fields of an expiring hash which exist and are not expired
+    async fn existing_unexpired(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        ns: String,
+        expirations: String,
+        keys: Vec<String>,
+    ) -> Result<Vec<String>, String> {
+        if keys.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut args = vec![Timestamp::now_utc().to_millis().to_string()];
+        args.extend(keys);
+        self.redis
+            .with(svc_name, api_name)
+            .eval(EXISTING_UNEXPIRED_SCRIPT, vec![ns, expirations], args)
+            .await
+            .map_err(|redis_err| redis_err.to_string())
+    }
+}
+
+/// Escapes the glob-style pattern characters used by `SCAN` and `HSCAN`
+fn escape_pattern(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
 }
 
 #[async_trait]
@@ -57,11 +317,28 @@ impl KeyValueStorage for RedisKeyValueStorage {
     ) -> Result<(), String> {
         record_redis_serialized_size(svc_name, entity_name, value.len());
 
+        if let Some((ns, expirations)) = Self::expiring_hash(&namespace) {
+            let _: i64 = self
+                .redis
+                .with(svc_name, api_name)
+                .eval(
+                    SET_WITHOUT_TTL_SCRIPT,
+                    vec![ns, expirations],
+                    vec![
+                        RedisValue::from(key),
+                        RedisValue::Bytes(Bytes::copy_from_slice(value)),
+                    ],
+                )
+                .await
+                .map_err(|redis_err| redis_err.to_string())?;
+            return Ok(());
+        }
+
         match Self::use_hash(&namespace) {
             Some(ns) => self
                 .redis
                 .with(svc_name, api_name)
-                .hset(ns, (key, value))
+                .hset::<(), _, _>(ns, (key, value))
                 .await
                 .map_err(|redis_err| redis_err.to_string()),
             None => self
@@ -73,6 +350,50 @@ impl KeyValueStorage for RedisKeyValueStorage {
         }
     }
 
+    async fn set_with_ttl(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+        ttl: Duration,
+    ) -> Result<(), String> {
+        record_redis_serialized_size(svc_name, entity_name, value.len());
+
+        let ttl_ms = ttl.as_millis() as i64;
+        match Self::use_hash(&namespace) {
+            Some(ns) => {
+                let expirations = Self::expirations_key(&namespace).ok_or_else(|| {
+                    format!("Expiring keys are not supported in namespace {namespace:?}")
+                })?;
+                let expires_at = Timestamp::now_utc().to_millis() as i64 + ttl_ms;
+                let _: i64 = self
+                    .redis
+                    .with(svc_name, api_name)
+                    .eval(
+                        SET_WITH_TTL_SCRIPT,
+                        vec![ns, expirations],
+                        vec![
+                            RedisValue::from(key),
+                            RedisValue::Bytes(Bytes::copy_from_slice(value)),
+                            RedisValue::from(expires_at),
+                        ],
+                    )
+                    .await
+                    .map_err(|redis_err| redis_err.to_string())?;
+                Ok(())
+            }
+            None => self
+                .redis
+                .with(svc_name, api_name)
+                .set(key, value, Some(Expiration::PX(ttl_ms)), None, false)
+                .await
+                .map_err(|redis_err| redis_err.to_string()),
+        }
+    }
+
     async fn set_many(
         &self,
         svc_name: &'static str,
@@ -86,11 +407,33 @@ impl KeyValueStorage for RedisKeyValueStorage {
             map.insert(*k, *v);
             record_redis_serialized_size(svc_name, entity_name, v.len());
         }
+        if let Some((ns, expirations)) = Self::expiring_hash(&namespace) {
+            if map.is_empty() {
+                return Ok(());
+            }
+            let args: Vec<RedisValue> = map
+                .into_iter()
+                .flat_map(|(key, value)| {
+                    [
+                        RedisValue::from(key),
+                        RedisValue::Bytes(Bytes::copy_from_slice(value)),
+                    ]
+                })
+                .collect();
+            let _: i64 = self
+                .redis
+                .with(svc_name, api_name)
+                .eval(SET_WITHOUT_TTL_SCRIPT, vec![ns, expirations], args)
+                .await
+                .map_err(|redis_err| redis_err.to_string())?;
+            return Ok(());
+        }
+
         match Self::use_hash(&namespace) {
             Some(ns) => self
                 .redis
                 .with(svc_name, api_name)
-                .hmset(ns, map)
+                .hmset::<(), _, _>(ns, map)
                 .await
                 .map_err(|redis_err| redis_err.to_string()),
             None => self
@@ -113,6 +456,20 @@ impl KeyValueStorage for RedisKeyValueStorage {
     ) -> Result<bool, String> {
         record_redis_serialized_size(svc_name, entity_name, value.len());
 
+        if let Some((ns, expirations)) = Self::expiring_hash(&namespace) {
+            let result: bool = self
+                .eval_on_unexpired(
+                    svc_name,
+                    api_name,
+                    (ns, expirations),
+                    EXPIRING_SET_IF_NOT_EXISTS_SCRIPT,
+                    key,
+                    vec![RedisValue::Bytes(Bytes::copy_from_slice(value))],
+                )
+                .await?;
+            return Ok(result);
+        }
+
         match Self::use_hash(&namespace) {
             Some(ns) => {
                 let result: bool = self
@@ -148,6 +505,17 @@ impl KeyValueStorage for RedisKeyValueStorage {
         key: &str,
     ) -> Result<Option<Bytes>, String> {
         let serialized: Option<Bytes> = match Self::use_hash(&namespace) {
+            Some(_) if Self::expirations_key(&namespace).is_some() => self
+                .get_many(
+                    svc_name,
+                    api_name,
+                    entity_name,
+                    namespace,
+                    vec![key.to_string()],
+                )
+                .await?
+                .pop()
+                .flatten(),
             Some(ns) => self
                 .redis
                 .with(svc_name, api_name)
@@ -162,11 +530,12 @@ impl KeyValueStorage for RedisKeyValueStorage {
                 .map_err(|redis_err| redis_err.to_string())?,
         };
 
-        if let Some(serialized) = serialized {
-            record_redis_deserialized_size(svc_name, entity_name, serialized.len());
-            Ok(Some(serialized))
-        } else {
-            Ok(None)
+        match serialized {
+            Some(serialized) if Self::expirations_key(&namespace).is_none() => {
+                record_redis_deserialized_size(svc_name, entity_name, serialized.len());
+                Ok(Some(serialized))
+            }
+            serialized => Ok(serialized),
         }
     }
 
@@ -179,12 +548,24 @@ impl KeyValueStorage for RedisKeyValueStorage {
         keys: Vec<String>,
     ) -> Result<Vec<Option<Bytes>>, String> {
         let serialized: Vec<Option<Bytes>> = match Self::use_hash(&namespace) {
-            Some(ns) => self
-                .redis
-                .with(svc_name, api_name)
-                .hmget(ns, keys)
-                .await
-                .map_err(|redis_err| redis_err.to_string())?,
+            Some(ns) => match Self::expirations_key(&namespace) {
+                Some(expirations) => {
+                    self.purge_expired_in_background(svc_name, api_name, &namespace);
+                    let mut args = vec![Timestamp::now_utc().to_millis().to_string()];
+                    args.extend(keys);
+                    self.redis
+                        .with(svc_name, api_name)
+                        .eval(GET_UNEXPIRED_SCRIPT, vec![ns, expirations], args)
+                        .await
+                        .map_err(|redis_err| redis_err.to_string())?
+                }
+                None => self
+                    .redis
+                    .with(svc_name, api_name)
+                    .hmget(ns, keys)
+                    .await
+                    .map_err(|redis_err| redis_err.to_string())?,
+            },
             None => self
                 .redis
                 .with(svc_name, api_name)
@@ -207,11 +588,17 @@ impl KeyValueStorage for RedisKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<(), String> {
+        if Self::expirations_key(&namespace).is_some() {
+            return self
+                .del_many(svc_name, api_name, namespace, vec![key.to_string()])
+                .await;
+        }
+
         match Self::use_hash(&namespace) {
             Some(ns) => self
                 .redis
                 .with(svc_name, api_name)
-                .hdel(ns, key)
+                .hdel::<(), _, _>(ns, key)
                 .await
                 .map_err(|redis_err| redis_err.to_string()),
             None => self
@@ -230,11 +617,24 @@ impl KeyValueStorage for RedisKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         keys: Vec<String>,
     ) -> Result<(), String> {
+        if let Some((ns, expirations)) = Self::expiring_hash(&namespace) {
+            if keys.is_empty() {
+                return Ok(());
+            }
+            let _: i64 = self
+                .redis
+                .with(svc_name, api_name)
+                .eval(DELETE_SCRIPT, vec![ns, expirations], keys)
+                .await
+                .map_err(|redis_err| redis_err.to_string())?;
+            return Ok(());
+        }
+
         match Self::use_hash(&namespace) {
             Some(ns) => self
                 .redis
                 .with(svc_name, api_name)
-                .hdel(ns, keys)
+                .hdel::<(), _, _>(ns, keys)
                 .await
                 .map_err(|redis_err| redis_err.to_string()),
             None => self
@@ -253,6 +653,14 @@ impl KeyValueStorage for RedisKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<bool, String> {
+        if let Some((ns, expirations)) = Self::expiring_hash(&namespace) {
+            self.purge_expired_in_background(svc_name, api_name, &namespace);
+            let existing = self
+                .existing_unexpired(svc_name, api_name, ns, expirations, vec![key.to_string()])
+                .await?;
+            return Ok(!existing.is_empty());
+        }
+
         match Self::use_hash(&namespace) {
             Some(ns) => self
                 .redis
@@ -276,19 +684,169 @@ impl KeyValueStorage for RedisKeyValueStorage {
         namespace: KeyValueStorageNamespace,
     ) -> Result<Vec<String>, String> {
         match Self::use_hash(&namespace) {
-            Some(ns) => self
+            Some(ns) => {
+                let keys: Vec<String> = self
+                    .redis
+                    .with(svc_name, api_name)
+                    .hkeys(&ns)
+                    .await
+                    .map_err(|redis_err| redis_err.to_string())?;
+                match Self::expirations_key(&namespace) {
+                    Some(expirations) => {
+                        self.purge_expired_in_background(svc_name, api_name, &namespace);
+                        self.existing_unexpired(svc_name, api_name, ns, expirations, keys)
+                            .await
+                    }
+                    None => Ok(keys),
+                }
+            }
+            None => self
                 .redis
                 .with(svc_name, api_name)
-                .hkeys(ns)
+                .keys("*".to_string())
                 .await
                 .map_err(|redis_err| redis_err.to_string()),
+        }
+    }
+
+    async fn list_keys(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        prefix: Option<&str>,
+        cursor: Option<&str>,
+        limit: u64,
+    ) -> Result<KeyPage, String> {
+        let cursor = match cursor {
+            Some(cursor) => cursor
+                .parse::<u64>()
+                .map_err(|_| format!("Invalid cursor: {cursor}"))?,
+            None => 0,
+        };
+        let pattern = format!("{}*", escape_pattern(prefix.unwrap_or_default()));
+
+        // SCAN and HSCAN treat the limit as a hint, and may return keys in any order
+        let (next_cursor, keys) = match Self::use_hash(&namespace) {
+            Some(ns) => {
+                let (next_cursor, keys) = self
+                    .redis
+                    .with(svc_name, api_name)
+                    .hscan_fields(&ns, &pattern, cursor, limit)
+                    .await
+                    .map_err(|redis_err| redis_err.to_string())?;
+                match Self::expirations_key(&namespace) {
+                    Some(expirations) => {
+                        self.purge_expired_in_background(svc_name, api_name, &namespace);
+                        let keys = self
+                            .existing_unexpired(svc_name, api_name, ns, expirations, keys)
+                            .await?;
+                        (next_cursor, keys)
+                    }
+                    None => (next_cursor, keys),
+                }
+            }
             None => self
                 .redis
                 .with(svc_name, api_name)
-                .keys("*".to_string())
+                .scan(pattern, cursor, limit)
                 .await
-                .map_err(|redis_err| redis_err.to_string()),
-        }
+                .map_err(|redis_err| redis_err.to_string())?,
+        };
+
+        Ok(KeyPage {
+            keys,
+            cursor: (next_cursor != 0).then(|| next_cursor.to_string()),
+        })
+    }
+
+    async fn increment(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        delta: u64,
+    ) -> Result<u64, String> {
+        let delta = i64::try_from(delta).map_err(|_| "Counter overflow".to_string())?;
+        let result: i64 = match Self::use_hash(&namespace) {
+            Some(ns) => match Self::expirations_key(&namespace) {
+                Some(expirations) => {
+                    self.eval_on_unexpired(
+                        svc_name,
+                        api_name,
+                        (ns, expirations),
+                        EXPIRING_INCREMENT_SCRIPT,
+                        key,
+                        vec![RedisValue::from(delta)],
+                    )
+                    .await?
+                }
+                None => self
+                    .redis
+                    .with(svc_name, api_name)
+                    .hincrby(ns, key, delta)
+                    .await
+                    .map_err(|redis_err| redis_err.to_string())?,
+            },
+            None => self
+                .redis
+                .with(svc_name, api_name)
+                .incr_by(key, delta)
+                .await
+                .map_err(|redis_err| redis_err.to_string())?,
+        };
+        u64::try_from(result).map_err(|_| "Counter overflow".to_string())
+    }
+
+    async fn compare_and_swap(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        old: u64,
+        new: u64,
+    ) -> Result<bool, String> {
+        let result: i64 = match Self::use_hash(&namespace) {
+            Some(ns) => match Self::expirations_key(&namespace) {
+                Some(expirations) => {
+                    self.eval_on_unexpired(
+                        svc_name,
+                        api_name,
+                        (ns, expirations),
+                        EXPIRING_COMPARE_AND_SWAP_SCRIPT,
+                        key,
+                        vec![
+                            RedisValue::from(old.to_string()),
+                            RedisValue::from(new.to_string()),
+                        ],
+                    )
+                    .await?
+                }
+                None => self
+                    .redis
+                    .with(svc_name, api_name)
+                    .eval(
+                        HASH_COMPARE_AND_SWAP_SCRIPT,
+                        vec![ns],
+                        vec![key.to_string(), old.to_string(), new.to_string()],
+                    )
+                    .await
+                    .map_err(|redis_err| redis_err.to_string())?,
+            },
+            None => self
+                .redis
+                .with(svc_name, api_name)
+                .eval(
+                    COMPARE_AND_SWAP_SCRIPT,
+                    vec![key],
+                    vec![old.to_string(), new.to_string()],
+                )
+                .await
+                .map_err(|redis_err| redis_err.to_string())?,
+        };
+        Ok(result == 1)
     }
 
     async fn add_to_set(
@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::storage::keyvalue::{KeyValueStorage, KeyValueStorageNamespace};
+use crate::storage::keyvalue::{
+    decode_counter, encode_counter, KeyPage, KeyValueStorage, KeyValueStorageNamespace,
+};
 use async_trait::async_trait;
 use bytes::Bytes;
+use golem_common::model::Timestamp;
 use golem_common::SafeDisplay;
 use golem_service_base::db::sqlite::SqlitePool;
 use golem_service_base::db::DBValue;
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct SqliteKeyValueStorage {
@@ -48,6 +52,22 @@ impl SqliteKeyValueStorage {
         .await
         .map_err(|err| err.to_safe_string())?;
 
+        let has_expires_at: Option<(i32,)> = pool
+            .fetch_optional_as(sqlx::query_as(
+                "SELECT 1 FROM pragma_table_info('kv_storage') WHERE name = 'expires_at';",
+            ))
+            .await
+            .map_err(|err| err.to_safe_string())?;
+        if has_expires_at.is_none() {
+            pool.execute(sqlx::query(
+                r#"
+                    ALTER TABLE kv_storage ADD COLUMN expires_at INTEGER; -- Expiration time in milliseconds since the epoch, NULL if the key never expires
+                    "#,
+            ))
+            .await
+            .map_err(|err| err.to_safe_string())?;
+        }
+
         pool.execute(sqlx::query(
             r#"
                   CREATE TABLE IF NOT EXISTS set_storage (
@@ -96,9 +116,12 @@ impl SqliteKeyValueStorage {
             KeyValueStorageNamespace::Worker => "worker".to_string(),
             KeyValueStorageNamespace::Promise => "promise".to_string(),
             KeyValueStorageNamespace::Schedule => "schedule".to_string(),
+            KeyValueStorageNamespace::PubSub => "pubsub".to_string(),
+            KeyValueStorageNamespace::Saga => "saga".to_string(),
             KeyValueStorageNamespace::UserDefined { project_id, bucket } => {
                 format!("user-defined:{project_id}:{bucket}")
             }
+            KeyValueStorageNamespace::Cache { project_id } => format!("cache:{project_id}"),
         }
     }
 }
@@ -129,6 +152,33 @@ impl KeyValueStorage for SqliteKeyValueStorage {
             .map_err(|err| err.to_safe_string())
     }
 
+    async fn set_with_ttl(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        _entity_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        value: &[u8],
+        ttl: Duration,
+    ) -> Result<(), String> {
+        let expires_at = now_millis() + ttl.as_millis() as i64;
+        let query = sqlx::query(
+            "INSERT OR REPLACE INTO kv_storage (key, value, namespace, expires_at) VALUES (?, ?, ?, ?);",
+        )
+        .bind(key)
+        .bind(value)
+        .bind(Self::namespace(namespace))
+        .bind(expires_at);
+
+        self.pool
+            .with_rw(svc_name, api_name)
+            .execute(query)
+            .await
+            .map(|_| ())
+            .map_err(|err| err.to_safe_string())
+    }
+
     async fn set_many(
         &self,
         svc_name: &'static str,
@@ -168,16 +218,22 @@ impl KeyValueStorage for SqliteKeyValueStorage {
         let existing: Option<(i32,)> = api
             .fetch_optional_as(
                 sqlx::query_as::<_, (i32,)>(
-                    "SELECT 1 FROM kv_storage WHERE key = ? AND namespace = ?",
+                    "SELECT 1 FROM kv_storage WHERE key = ? AND namespace = ? AND (expires_at IS NULL OR expires_at > ?)",
                 )
                 .bind(key)
-                .bind(Self::namespace(namespace.clone())),
+                .bind(Self::namespace(namespace.clone()))
+                .bind(now_millis()),
             )
             .await
             .map_err(|err| err.to_safe_string())?;
 
+        if existing.is_some() {
+            return Ok(false);
+        }
+
+        // Replacing, as the key may still be present with an expired value
         let query = sqlx::query(
-            "INSERT OR IGNORE INTO kv_storage (key, value, namespace) VALUES (?, ?, ?);",
+            "INSERT OR REPLACE INTO kv_storage (key, value, namespace) VALUES (?, ?, ?);",
         )
         .bind(key)
         .bind(value)
@@ -185,7 +241,7 @@ impl KeyValueStorage for SqliteKeyValueStorage {
 
         api.execute(query)
             .await
-            .map(|_| existing.is_none())
+            .map(|_| true)
             .map_err(|err| err.to_safe_string())
     }
 
@@ -197,9 +253,12 @@ impl KeyValueStorage for SqliteKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<Option<Bytes>, String> {
-        let query = sqlx::query_as("SELECT value FROM kv_storage WHERE key = ? AND namespace = ?;")
-            .bind(key)
-            .bind(Self::namespace(namespace));
+        let query = sqlx::query_as(
+            "SELECT value FROM kv_storage WHERE key = ? AND namespace = ? AND (expires_at IS NULL OR expires_at > ?);",
+        )
+        .bind(key)
+        .bind(Self::namespace(namespace))
+        .bind(now_millis());
 
         self.pool
             .with_ro(svc_name, api_name)
@@ -220,14 +279,14 @@ impl KeyValueStorage for SqliteKeyValueStorage {
         let placeholders = keys.iter().map(|_| "?").collect::<Vec<_>>().join(",");
 
         let statement = format!(
-            "SELECT key, value FROM kv_storage WHERE key IN ({placeholders}) AND namespace = ?;"
+            "SELECT key, value FROM kv_storage WHERE key IN ({placeholders}) AND namespace = ? AND (expires_at IS NULL OR expires_at > ?);"
         );
         let mut query = sqlx::query_as(&statement);
 
         for key in &keys {
             query = query.bind(key);
         }
-        query = query.bind(Self::namespace(namespace));
+        query = query.bind(Self::namespace(namespace)).bind(now_millis());
 
         let results: Vec<DBKeyValue> = self
             .pool
@@ -295,9 +354,12 @@ impl KeyValueStorage for SqliteKeyValueStorage {
         namespace: KeyValueStorageNamespace,
         key: &str,
     ) -> Result<bool, String> {
-        let query = sqlx::query("SELECT 1 FROM kv_storage WHERE key = ? AND namespace = ?")
-            .bind(key)
-            .bind(Self::namespace(namespace));
+        let query = sqlx::query(
+            "SELECT 1 FROM kv_storage WHERE key = ? AND namespace = ? AND (expires_at IS NULL OR expires_at > ?)",
+        )
+        .bind(key)
+        .bind(Self::namespace(namespace))
+        .bind(now_millis());
 
         self.pool
             .with_ro(svc_name, api_name)
@@ -313,8 +375,11 @@ impl KeyValueStorage for SqliteKeyValueStorage {
         api_name: &'static str,
         namespace: KeyValueStorageNamespace,
     ) -> Result<Vec<String>, String> {
-        let query = sqlx::query_as("SELECT key FROM kv_storage WHERE namespace = ?;")
-            .bind(Self::namespace(namespace));
+        let query = sqlx::query_as(
+            "SELECT key FROM kv_storage WHERE namespace = ? AND (expires_at IS NULL OR expires_at > ?);",
+        )
+        .bind(Self::namespace(namespace))
+        .bind(now_millis());
 
         self.pool
             .with_ro(svc_name, api_name)
@@ -324,6 +389,139 @@ impl KeyValueStorage for SqliteKeyValueStorage {
             .map_err(|err| err.to_safe_string())
     }
 
+    async fn list_keys(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        prefix: Option<&str>,
+        cursor: Option<&str>,
+        limit: u64,
+    ) -> Result<KeyPage, String> {
+        let prefix = prefix.unwrap_or_default();
+        // Fetching one more key than the limit to know whether there is a next page
+        let query = sqlx::query_as(
+            r#"
+                SELECT key FROM kv_storage
+                WHERE namespace = ?
+                  AND substr(key, 1, length(?)) = ?
+                  AND (? IS NULL OR key > ?)
+                  AND (expires_at IS NULL OR expires_at > ?)
+                ORDER BY key
+                LIMIT ?;
+            "#,
+        )
+        .bind(Self::namespace(namespace))
+        .bind(prefix)
+        .bind(prefix)
+        .bind(cursor)
+        .bind(cursor)
+        .bind(now_millis())
+        .bind(limit.saturating_add(1) as i64);
+
+        let mut keys = self
+            .pool
+            .with_ro(svc_name, api_name)
+            .fetch_all::<(String,), _>(query)
+            .await
+            .map(|vec| vec.into_iter().map(|k| k.0).collect::<Vec<String>>())
+            .map_err(|err| err.to_safe_string())?;
+
+        if keys.len() as u64 > limit {
+            keys.truncate(limit as usize);
+            let cursor = keys.last().cloned();
+            Ok(KeyPage { keys, cursor })
+        } else {
+            Ok(KeyPage { keys, cursor: None })
+        }
+    }
+
+    async fn increment(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        delta: u64,
+    ) -> Result<u64, String> {
+        let api = self.pool.with_rw(svc_name, api_name);
+        let mut tx = api.begin().await.map_err(|err| err.to_safe_string())?;
+
+        let existing = tx
+            .fetch_optional_as::<DBValue, _>(
+                sqlx::query_as(
+                    "SELECT value FROM kv_storage WHERE key = ? AND namespace = ? AND (expires_at IS NULL OR expires_at > ?);",
+                )
+                .bind(key)
+                .bind(Self::namespace(namespace.clone()))
+                .bind(now_millis()),
+            )
+            .await
+            .map_err(|err| err.to_safe_string())?
+            .map(|value| value.into_bytes());
+        let new = decode_counter(existing.as_deref())?
+            .checked_add(delta)
+            .ok_or_else(|| "Counter overflow".to_string())?;
+
+        tx.execute(
+            sqlx::query(
+                "INSERT OR REPLACE INTO kv_storage (key, value, namespace) VALUES (?, ?, ?);",
+            )
+            .bind(key)
+            .bind(encode_counter(new))
+            .bind(Self::namespace(namespace)),
+        )
+        .await
+        .map_err(|err| err.to_safe_string())?;
+
+        api.commit(tx).await.map_err(|err| err.to_safe_string())?;
+        Ok(new)
+    }
+
+    async fn compare_and_swap(
+        &self,
+        svc_name: &'static str,
+        api_name: &'static str,
+        namespace: KeyValueStorageNamespace,
+        key: &str,
+        old: u64,
+        new: u64,
+    ) -> Result<bool, String> {
+        let api = self.pool.with_rw(svc_name, api_name);
+        let mut tx = api.begin().await.map_err(|err| err.to_safe_string())?;
+
+        let existing = tx
+            .fetch_optional_as::<DBValue, _>(
+                sqlx::query_as(
+                    "SELECT value FROM kv_storage WHERE key = ? AND namespace = ? AND (expires_at IS NULL OR expires_at > ?);",
+                )
+                .bind(key)
+                .bind(Self::namespace(namespace.clone()))
+                .bind(now_millis()),
+            )
+            .await
+            .map_err(|err| err.to_safe_string())?
+            .map(|value| value.into_bytes());
+
+        if decode_counter(existing.as_deref())? != old {
+            return Ok(false);
+        }
+
+        tx.execute(
+            sqlx::query(
+                "INSERT OR REPLACE INTO kv_storage (key, value, namespace) VALUES (?, ?, ?);",
+            )
+            .bind(key)
+            .bind(encode_counter(new))
+            .bind(Self::namespace(namespace)),
+        )
+        .await
+        .map_err(|err| err.to_safe_string())?;
+
+        api.commit(tx).await.map_err(|err| err.to_safe_string())?;
+        Ok(true)
+    }
+
     async fn add_to_set(
         &self,
         svc_name: &'static str,
@@ -527,3 +725,7 @@ impl DBScoreValue {
         (self.score, Bytes::from(self.value))
     }
 }
+
+fn now_millis() -> i64 {
+    Timestamp::now_utc().to_millis() as i64
+}
@@ -82,6 +82,7 @@ where
         get,
     )?;
     wasmtime_wasi::p2::bindings::sockets::udp::add_to_linker_get_host(&mut linker, get)?;
+    crate::durable_host::sockets::udp::add_durable_receive_to_linker(&mut linker, get)?;
     wasmtime_wasi::p2::bindings::sockets::udp_create_socket::add_to_linker_get_host(
         &mut linker,
         get,
@@ -102,11 +103,14 @@ where
     crate::preview2::wasi::keyvalue::eventual_batch::add_to_linker_get_host(&mut linker, get)?;
     crate::preview2::wasi::keyvalue::types::add_to_linker_get_host(&mut linker, get)?;
     crate::preview2::wasi::keyvalue::wasi_keyvalue_error::add_to_linker_get_host(&mut linker, get)?;
+    crate::preview2::golem::keyvalue::extensions::add_to_linker_get_host(&mut linker, get)?;
     crate::preview2::wasi::logging::logging::add_to_linker_get_host(&mut linker, get)?;
     crate::preview2::wasi::config::store::add_to_linker_get_host(&mut linker, get)?;
 
     crate::preview2::golem::rdbms::mysql::add_to_linker_get_host(&mut linker, get)?;
     crate::preview2::golem::rdbms::postgres::add_to_linker_get_host(&mut linker, get)?;
+    crate::preview2::golem::rdbms::sqlite::add_to_linker_get_host(&mut linker, get)?;
+    crate::preview2::golem::rdbms::mssql::add_to_linker_get_host(&mut linker, get)?;
 
     Ok(linker)
 }
@@ -265,12 +265,12 @@ impl<Ctx: WorkerCtx> InnerInvocationLoop<'_, Ctx> {
             self.waiting_for_command.store(false, Ordering::Release);
             let outcome = match cmd {
                 WorkerCommand::Invocation => {
-                    let message = self
-                        .active
-                        .write()
-                        .await
-                        .pop_front()
-                        .expect("Message should be present");
+                    let message = {
+                        let mut active = self.active.write().await;
+                        let message = active.pop_front().expect("Message should be present");
+                        Worker::<Ctx>::record_queue_depth(&self.owned_worker_id, &active);
+                        message
+                    };
 
                     self.invocation(message).await
                 }
@@ -370,6 +370,7 @@ impl<Ctx: WorkerCtx> Invocation<'_, Ctx> {
             QueuedWorkerInvocation::External {
                 invocation,
                 canceled,
+                ..
             } => {
                 if !canceled {
                     self.external_invocation(invocation).await
@@ -742,6 +743,22 @@ impl<Ctx: WorkerCtx> Invocation<'_, Ctx> {
                                 .await
                             {
                                 Ok(update_description) => {
+                                    if let Err(error) = self
+                                        .store
+                                        .data()
+                                        .on_worker_update_snapshot_saved()
+                                        .await
+                                    {
+                                        return self
+                                            .fail_update(
+                                                target_version,
+                                                format!(
+                                                    "failed to archive the worker's files for manual update: {error}"
+                                                ),
+                                            )
+                                            .await;
+                                    }
+
                                     // Enqueue the update
                                     self.parent.enqueue_update(update_description).await;
 
@@ -18,21 +18,24 @@ pub mod status;
 
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::mem;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::durable_host::recover_stderr_logs;
+use crate::metrics::workers::{record_invocation_queue_depth, remove_invocation_queue_depth};
 use crate::model::{ExecutionStatus, LookupResult, ReadFileResult, TrapType, WorkerConfig};
 use crate::services::events::{Event, EventsSubscription};
 use crate::services::oplog::{CommitLevel, Oplog, OplogOps};
+use crate::services::pubsub::restore_subscriptions;
+use crate::services::saga::abort_running_sagas;
 use crate::services::worker_event::{WorkerEventService, WorkerEventServiceDefault};
 use crate::services::{
     All, HasActiveWorkers, HasAll, HasBlobStoreService, HasComponentService, HasConfig, HasEvents,
     HasExtraDeps, HasFileLoader, HasKeyValueService, HasOplog, HasOplogService, HasPlugins,
-    HasProjectService, HasPromiseService, HasRdbmsService, HasResourceLimits, HasRpc,
-    HasSchedulerService, HasWasmtimeEngine, HasWorkerEnumerationService, HasWorkerForkService,
-    HasWorkerProxy, HasWorkerService, UsesAllDeps,
+    HasProjectService, HasPromiseService, HasPubSubService, HasRdbmsService, HasResourceLimits,
+    HasRpc, HasSagaService, HasSchedulerService, HasWasmtimeEngine, HasWorkerEnumerationService,
+    HasWorkerForkService, HasWorkerProxy, HasWorkerService, UsesAllDeps,
 };
 use crate::worker::invocation_loop::InvocationLoop;
 use crate::worker::status::calculate_last_known_status;
@@ -41,14 +44,16 @@ use anyhow::anyhow;
 use futures::channel::oneshot;
 use golem_common::model::invocation_context::InvocationContextStack;
 use golem_common::model::oplog::{
-    OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription, WorkerError,
+    InvocationPriority, OplogEntry, OplogIndex, TimestampedUpdateDescription, UpdateDescription,
+    WorkerError,
 };
 use golem_common::model::regions::{DeletedRegions, DeletedRegionsBuilder, OplogRegion};
 use golem_common::model::{AccountId, RetryConfig};
 use golem_common::model::{ComponentFilePath, ComponentType, PluginInstallationId};
 use golem_common::model::{
     ComponentVersion, GetFileSystemNodeResult, IdempotencyKey, OwnedWorkerId, Timestamp,
-    TimestampedWorkerInvocation, WorkerId, WorkerInvocation, WorkerMetadata, WorkerStatusRecord,
+    TimestampedWorkerInvocation, WorkerId, WorkerInvocation, WorkerMetadata, WorkerStatus,
+    WorkerStatusRecord,
 };
 use golem_service_base::error::worker_executor::{
     InterruptKind, WorkerExecutorError, WorkerOutOfMemory,
@@ -96,6 +101,9 @@ pub struct Worker<Ctx: WorkerCtx> {
 
     instance: Arc<Mutex<WorkerInstance>>,
     oom_retry_config: RetryConfig,
+
+    /// Size of the worker's file system in bytes, kept up to date by its instance while it is loaded
+    file_system_usage: Arc<AtomicU64>,
 }
 
 impl<Ctx: WorkerCtx> HasOplog for Worker<Ctx> {
@@ -193,6 +201,92 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Deletes a worker together with its oplog, files and invocation schedules, interrupting it
+    /// first if it is currently running. Does nothing if the worker does not exist.
+    pub async fn delete<T>(
+        deps: &T,
+        account_id: &AccountId,
+        owned_worker_id: &OwnedWorkerId,
+    ) -> Result<(), WorkerExecutorError>
+    where
+        T: HasAll<Ctx> + Clone + Send + Sync + 'static,
+    {
+        if let Some(metadata) = Self::get_latest_metadata(deps, owned_worker_id).await? {
+            let should_interrupt = match &metadata.last_known_status.status {
+                WorkerStatus::Idle
+                | WorkerStatus::Running
+                | WorkerStatus::Suspended
+                | WorkerStatus::Retrying => true,
+                WorkerStatus::Exited | WorkerStatus::Failed | WorkerStatus::Interrupted => false,
+            };
+
+            if should_interrupt {
+                let worker = Self::get_or_create_suspended(
+                    deps,
+                    account_id,
+                    owned_worker_id,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+
+                if let Some(mut await_interrupted) =
+                    worker.set_interrupting(InterruptKind::Interrupt).await
+                {
+                    await_interrupted.recv().await.unwrap();
+                }
+
+                worker.stop().await;
+            }
+
+            for schedule in deps
+                .scheduler_service()
+                .get_invocation_schedules(owned_worker_id)
+                .await?
+            {
+                deps.scheduler_service()
+                    .cancel_invocation_schedule(owned_worker_id, &schedule.schedule_id)
+                    .await?;
+            }
+            deps.scheduler_service()
+                .set_worker_ttl_policy(owned_worker_id, None)
+                .await?;
+            deps.promise_service()
+                .delete_all_pending(&owned_worker_id.worker_id)
+                .await;
+            deps.pubsub_service()
+                .unsubscribe_all(owned_worker_id)
+                .await?;
+            deps.saga_service().delete_all(owned_worker_id).await?;
+
+            Ctx::on_worker_deleted(deps, &owned_worker_id.worker_id).await?;
+            deps.worker_service().remove(owned_worker_id).await;
+            deps.active_workers().remove(&owned_worker_id.worker_id);
+            // The archived files stay counted for the account until the worker gets deleted
+            match deps.file_loader().archived_size(owned_worker_id).await {
+                Ok(archived_size) => deps
+                    .resource_limits()
+                    .update_storage_usage(&metadata.created_by, -(archived_size as i64)),
+                Err(err) => warn!(
+                    "Failed to get the size of the archived files of worker {owned_worker_id}: {err}"
+                ),
+            }
+            // The archived files are not needed by anything else, failing to remove them must not fail the deletion
+            if let Err(err) = deps
+                .file_loader()
+                .delete_worker_files(owned_worker_id)
+                .await
+            {
+                warn!("Failed to delete the archived files of worker {owned_worker_id}: {err}");
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn new<T: HasAll<Ctx>>(
         deps: &T,
         account_id: &AccountId,
@@ -252,6 +346,8 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             .collect::<Vec<_>>();
         let initial_invocation_results =
             worker_metadata.last_known_status.invocation_results.clone();
+        let initial_invocation_priorities =
+            &worker_metadata.last_known_status.invocation_priorities;
 
         let queue = Arc::new(RwLock::new(VecDeque::from_iter(
             initial_pending_invocations
@@ -259,6 +355,12 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 .map(|inv| QueuedWorkerInvocation::External {
                     invocation: inv.clone(),
                     canceled: false,
+                    priority: inv
+                        .invocation
+                        .idempotency_key()
+                        .and_then(|key| initial_invocation_priorities.get(key))
+                        .copied()
+                        .unwrap_or_default(),
                 }),
         )));
         let pending_updates = Arc::new(RwLock::new(VecDeque::from_iter(
@@ -295,6 +397,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             initial_worker_metadata: worker_metadata,
             worker_estimate_coefficient: deps.config().memory.worker_estimate_coefficient,
             oom_retry_config: deps.config().memory.oom_retry_config.clone(),
+            file_system_usage: Arc::new(AtomicU64::new(0)),
         })
     }
 
@@ -409,6 +512,15 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         self.worker_event_service.clone()
     }
 
+    /// Gets the current size of the worker's file system, which is 0 if it is not loaded
+    pub fn file_system_usage(&self) -> u64 {
+        self.file_system_usage.load(Ordering::Relaxed)
+    }
+
+    pub fn file_system_usage_counter(&self) -> Arc<AtomicU64> {
+        self.file_system_usage.clone()
+    }
+
     pub fn is_loading(&self) -> bool {
         matches!(
             &*self.execution_status.read().unwrap(),
@@ -520,6 +632,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         full_function_name: String,
         function_input: Vec<Value>,
         invocation_context: InvocationContextStack,
+        priority: InvocationPriority,
     ) -> Result<ResultOrSubscription, WorkerExecutorError> {
         let output = self.lookup_invocation_result(&idempotency_key).await;
 
@@ -539,6 +652,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     full_function_name,
                     function_input,
                     invocation_context,
+                    priority,
                 )
                 .await;
                 Ok(ResultOrSubscription::Pending(subscription))
@@ -555,6 +669,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         full_function_name: String,
         function_input: Vec<Value>,
         invocation_context: InvocationContextStack,
+        priority: InvocationPriority,
     ) -> Result<Option<ValueAndType>, WorkerExecutorError> {
         match self
             .invoke(
@@ -562,6 +677,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 full_function_name,
                 function_input,
                 invocation_context,
+                priority,
             )
             .await?
         {
@@ -593,6 +709,32 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         }
     }
 
+    /// Looks up the result of an invocation by its idempotency key, without invoking anything.
+    ///
+    /// If `wait` is set and the invocation is still pending, waits at most that long for
+    /// it to finish before returning the latest known state.
+    pub async fn lookup_invocation(
+        &self,
+        idempotency_key: &IdempotencyKey,
+        wait: Option<Duration>,
+    ) -> LookupResult {
+        let subscription = self.events().subscribe();
+        match (self.lookup_invocation_result(idempotency_key).await, wait) {
+            (LookupResult::Pending, Some(wait)) => {
+                match tokio::time::timeout(
+                    wait,
+                    self.wait_for_invocation_result(idempotency_key, subscription),
+                )
+                .await
+                {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(_)) | Err(_) => self.lookup_invocation_result(idempotency_key).await,
+                }
+            }
+            (result, _) => result,
+        }
+    }
+
     /// Enqueue attempting an update.
     ///
     /// The update itself is not performed by the invocation queue's processing loop,
@@ -631,16 +773,15 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     timestamp: entry.timestamp(),
                     invocation,
                 };
-                self.queue
-                    .write()
-                    .await
-                    .push_back(QueuedWorkerInvocation::External {
-                        invocation: timestamped_invocation,
-                        canceled: false,
-                    });
+                QueuedWorkerInvocation::enqueue_external(
+                    &mut *self.queue.write().await,
+                    timestamped_invocation,
+                    InvocationPriority::Normal,
+                );
                 self.oplog.add_and_commit(entry).await;
             }
         }
+        Self::record_queue_depth(&self.owned_worker_id, &*self.queue.read().await);
         self.update_metadata()
             .await
             .expect("update_metadata failed");
@@ -655,6 +796,38 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             .collect()
     }
 
+    /// Gets the pending invocations in the order they are going to be processed, together with their priority
+    pub async fn pending_invocations_with_priority(
+        &self,
+    ) -> Vec<(TimestampedWorkerInvocation, InvocationPriority)> {
+        self.queue
+            .read()
+            .await
+            .iter()
+            .filter_map(|inv| {
+                inv.as_external_active()
+                    .map(|invocation| (invocation.clone(), inv.priority()))
+            })
+            .collect()
+    }
+
+    /// Gets the priorities of the pending invocations which are not `InvocationPriority::Normal`
+    pub async fn pending_invocation_priorities(
+        &self,
+    ) -> HashMap<IdempotencyKey, InvocationPriority> {
+        self.queue
+            .read()
+            .await
+            .iter()
+            .filter(|inv| inv.priority() != InvocationPriority::Normal)
+            .filter_map(|inv| {
+                inv.as_external_active()
+                    .and_then(|invocation| invocation.invocation.idempotency_key())
+                    .map(|key| (key.clone(), inv.priority()))
+            })
+            .collect()
+    }
+
     pub async fn pending_updates(
         &self,
     ) -> (VecDeque<TimestampedUpdateDescription>, DeletedRegions) {
@@ -848,6 +1021,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         full_function_name: String,
         function_input: Vec<Value>,
         invocation_context: InvocationContextStack,
+        priority: InvocationPriority,
     ) {
         match &*self.instance.lock().await {
             WorkerInstance::Running(running) => {
@@ -857,6 +1031,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         full_function_name,
                         function_input,
                         invocation_context,
+                        priority,
                     )
                     .await;
             }
@@ -868,21 +1043,22 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                     function_input,
                     invocation_context,
                 };
-                let entry = OplogEntry::pending_worker_invocation(invocation.clone());
+                let entries = pending_invocation_entries(&invocation, priority);
                 let timestamped_invocation = TimestampedWorkerInvocation {
-                    timestamp: entry.timestamp(),
+                    timestamp: entries[0].timestamp(),
                     invocation,
                 };
-                self.queue
-                    .write()
-                    .await
-                    .push_back(QueuedWorkerInvocation::External {
-                        invocation: timestamped_invocation,
-                        canceled: false,
-                    });
-                self.oplog.add_and_commit(entry).await;
+                QueuedWorkerInvocation::enqueue_external(
+                    &mut *self.queue.write().await,
+                    timestamped_invocation,
+                    priority,
+                );
+                for entry in entries {
+                    self.oplog.add_and_commit(entry).await;
+                }
             }
         }
+        Self::record_queue_depth(&self.owned_worker_id, &*self.queue.read().await);
 
         self.update_metadata()
             .await
@@ -959,6 +1135,23 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
         Ok(())
     }
 
+    /// Sets the given labels, then removes the labels with the keys listed in `remove`.
+    pub async fn update_labels(
+        &self,
+        set: impl IntoIterator<Item = (String, String)>,
+        remove: Vec<String>,
+    ) -> Result<(), WorkerExecutorError> {
+        for (key, value) in set {
+            self.oplog.add(OplogEntry::set_label(key, value)).await;
+        }
+        for key in remove {
+            self.oplog.add(OplogEntry::remove_label(key)).await;
+        }
+        self.oplog.commit(CommitLevel::Always).await;
+        self.update_metadata().await?;
+        Ok(())
+    }
+
     /// Reverts the worker to a previous state, selected by either the last oplog index to keep
     /// or the number of invocations to drop.
     ///
@@ -1004,10 +1197,102 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
             .add_and_commit(OplogEntry::cancel_pending_invocation(idempotency_key))
             .await;
         self.update_metadata().await?;
+        Self::record_queue_depth(&self.owned_worker_id, &queue);
 
         Ok(())
     }
 
+    /// Changes the priority of a pending invocation, moving it behind all the other pending
+    /// invocations of the same or higher priority.
+    ///
+    /// Returns false if the invocation is not pending (anymore).
+    pub async fn change_invocation_priority(
+        &self,
+        idempotency_key: IdempotencyKey,
+        priority: InvocationPriority,
+    ) -> Result<bool, WorkerExecutorError> {
+        let mut queue = self.queue.write().await;
+        let Some(position) = queue.iter().position(|item| {
+            item.as_external_active().is_some() && item.matches_idempotency_key(&idempotency_key)
+        }) else {
+            return Ok(false);
+        };
+
+        let mut item = queue
+            .remove(position)
+            .expect("Position of the queued invocation must be valid");
+        if let QueuedWorkerInvocation::External {
+            priority: item_priority,
+            ..
+        } = &mut item
+        {
+            *item_priority = priority;
+        }
+        let index = priority_insertion_index(queue.iter().map(|item| item.priority()), priority);
+        queue.insert(index, item);
+
+        self.oplog
+            .add_and_commit(OplogEntry::change_pending_invocation_priority(
+                idempotency_key,
+                priority,
+            ))
+            .await;
+        self.update_metadata().await?;
+
+        Ok(true)
+    }
+
+    /// Cancels all the pending invocations of exported functions which have not started yet.
+    ///
+    /// Returns the idempotency keys of the canceled invocations.
+    pub async fn purge_pending_invocations(
+        &self,
+    ) -> Result<Vec<IdempotencyKey>, WorkerExecutorError> {
+        let mut queue = self.queue.write().await;
+        let mut canceled_keys = Vec::new();
+        for item in queue.iter_mut() {
+            if let QueuedWorkerInvocation::External {
+                invocation,
+                canceled,
+                ..
+            } = item
+            {
+                if let (false, Some(idempotency_key)) =
+                    (*canceled, invocation.invocation.idempotency_key())
+                {
+                    *canceled = true;
+                    canceled_keys.push(idempotency_key.clone());
+                }
+            }
+        }
+
+        if !canceled_keys.is_empty() {
+            for idempotency_key in &canceled_keys {
+                self.oplog
+                    .add(OplogEntry::cancel_pending_invocation(
+                        idempotency_key.clone(),
+                    ))
+                    .await;
+            }
+            self.oplog.commit(CommitLevel::Always).await;
+            self.update_metadata().await?;
+        }
+        Self::record_queue_depth(&self.owned_worker_id, &queue);
+
+        Ok(canceled_keys)
+    }
+
+    pub(crate) fn record_queue_depth(
+        owned_worker_id: &OwnedWorkerId,
+        queue: &VecDeque<QueuedWorkerInvocation>,
+    ) {
+        let depth = queue
+            .iter()
+            .filter(|item| item.as_external_active().is_some())
+            .count();
+        record_invocation_queue_depth(&owned_worker_id.worker_id, depth);
+    }
+
     /// Starting from the end of the oplog, find the Nth ExportedFunctionInvoked entry's index.
     async fn find_nth_invocation_from_end(&self, n: usize) -> Option<OplogIndex> {
         let mut current = self.oplog.current_oplog_index().await;
@@ -1064,6 +1349,47 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                 .await;
             self.oplog.add_and_commit(OplogEntry::revert(region)).await;
 
+            // Sagas started in the reverted region are never going to be finished by the worker
+            let retry_config = metadata
+                .last_known_status
+                .overridden_retry_config
+                .clone()
+                .unwrap_or_else(|| self.config().retry.clone());
+            if let Err(err) = abort_running_sagas(
+                self.saga_service(),
+                self.rpc(),
+                &self.owned_worker_id,
+                &metadata.created_by,
+                &retry_config,
+                region_start,
+                "The coordinator worker got reverted to before the saga started",
+            )
+            .await
+            {
+                warn!("Failed to abort the sagas started in the reverted oplog region: {err}");
+            }
+
+            // The subscriptions made in the reverted region are not going to be replayed
+            let entries = self
+                .oplog_service()
+                .read_prefix(
+                    &self.owned_worker_id,
+                    self.oplog.current_oplog_index().await,
+                )
+                .await;
+            if let Err(err) = restore_subscriptions(
+                self.pubsub_service(),
+                self.oplog.clone(),
+                &self.owned_worker_id,
+                &entries,
+            )
+            .await
+            {
+                warn!(
+                    "Failed to restore the topic subscriptions after reverting the worker: {err}"
+                );
+            }
+
             // Recalculating the status from the whole oplog, because the newly deleted region may contain things like worker updates.
             let recalculated_status =
                 calculate_last_known_status(self, &self.owned_worker_id, &None).await?;
@@ -1225,6 +1551,7 @@ impl<Ctx: WorkerCtx> Worker<Ctx> {
                         QueuedWorkerInvocation::External {
                             invocation: inner,
                             canceled,
+                            ..
                         } => {
                             if !canceled {
                                 if let Some(idempotency_key) = inner.invocation.idempotency_key() {
@@ -1458,6 +1785,12 @@ impl WaitingWorker {
     }
 }
 
+impl<Ctx: WorkerCtx> Drop for Worker<Ctx> {
+    fn drop(&mut self) {
+        remove_invocation_queue_depth(&self.owned_worker_id.worker_id);
+    }
+}
+
 impl Drop for WaitingWorker {
     fn drop(&mut self) {
         if let Some(handle) = self.handle.take() {
@@ -1546,6 +1879,7 @@ impl RunningWorker {
         full_function_name: String,
         function_input: Vec<Value>,
         invocation_context: InvocationContextStack,
+        priority: InvocationPriority,
     ) {
         let invocation = WorkerInvocation::ExportedFunction {
             idempotency_key,
@@ -1553,32 +1887,37 @@ impl RunningWorker {
             function_input,
             invocation_context,
         };
-        self.enqueue_worker_invocation(invocation).await;
+        self.enqueue_worker_invocation(invocation, priority).await;
     }
 
     pub async fn enqueue_manual_update(&self, target_version: ComponentVersion) {
         let invocation = WorkerInvocation::ManualUpdate { target_version };
-        self.enqueue_worker_invocation(invocation).await;
+        self.enqueue_worker_invocation(invocation, InvocationPriority::Normal)
+            .await;
     }
 
-    async fn enqueue_worker_invocation(&self, invocation: WorkerInvocation) {
-        let entry = OplogEntry::pending_worker_invocation(invocation.clone());
+    async fn enqueue_worker_invocation(
+        &self,
+        invocation: WorkerInvocation,
+        priority: InvocationPriority,
+    ) {
+        let entries = pending_invocation_entries(&invocation, priority);
         let timestamped_invocation = TimestampedWorkerInvocation {
-            timestamp: entry.timestamp(),
+            timestamp: entries[0].timestamp(),
             invocation,
         };
         if self.execution_status.read().unwrap().is_running() {
             debug!("Worker is busy, persisting pending invocation",);
             // The worker is currently busy, so we write the pending worker invocation to the oplog
-            self.oplog.add_and_commit(entry).await;
+            for entry in entries {
+                self.oplog.add_and_commit(entry).await;
+            }
         }
-        self.queue
-            .write()
-            .await
-            .push_back(QueuedWorkerInvocation::External {
-                invocation: timestamped_invocation,
-                canceled: false,
-            });
+        QueuedWorkerInvocation::enqueue_external(
+            &mut *self.queue.write().await,
+            timestamped_invocation,
+            priority,
+        );
         self.sender.send(WorkerCommand::Invocation).unwrap()
     }
 
@@ -1684,6 +2023,8 @@ impl RunningWorker {
             worker_metadata.created_by,
             OwnedWorkerId::new(&worker_metadata.project_id, &worker_metadata.worker_id),
             parent.promise_service(),
+            parent.pubsub_service(),
+            parent.saga_service(),
             parent.worker_service(),
             parent.worker_enumeration_service(),
             parent.key_value_service(),
@@ -1890,6 +2231,7 @@ pub enum QueuedWorkerInvocation {
     External {
         invocation: TimestampedWorkerInvocation,
         canceled: bool,
+        priority: InvocationPriority,
     },
     GetFileSystemNode {
         path: ComponentFilePath,
@@ -1909,16 +2251,44 @@ pub enum QueuedWorkerInvocation {
 }
 
 impl QueuedWorkerInvocation {
+    /// Adds a new external invocation with the given priority to the queue, keeping the queue
+    /// ordered by priority
+    fn enqueue_external(
+        queue: &mut VecDeque<Self>,
+        invocation: TimestampedWorkerInvocation,
+        priority: InvocationPriority,
+    ) {
+        let index = priority_insertion_index(queue.iter().map(|item| item.priority()), priority);
+        queue.insert(
+            index,
+            Self::External {
+                invocation,
+                canceled: false,
+                priority,
+            },
+        );
+    }
+
     fn as_external_active(&self) -> Option<&TimestampedWorkerInvocation> {
         match self {
             Self::External {
                 invocation,
                 canceled: false,
+                ..
             } => Some(invocation),
             _ => None,
         }
     }
 
+    /// The priority of the queued item. Items used for concurrency control are always
+    /// processed with normal priority.
+    fn priority(&self) -> InvocationPriority {
+        match self {
+            Self::External { priority, .. } => *priority,
+            _ => InvocationPriority::Normal,
+        }
+    }
+
     fn matches_idempotency_key(&self, idempotency_key: &IdempotencyKey) -> bool {
         match self {
             Self::External { invocation, .. } => {
@@ -1929,6 +2299,35 @@ impl QueuedWorkerInvocation {
     }
 }
 
+/// The oplog entries persisting a pending invocation. Invocations enqueued with a priority other
+/// than normal are followed by a priority change, so the priority survives the recovery of the worker.
+pub(crate) fn pending_invocation_entries(
+    invocation: &WorkerInvocation,
+    priority: InvocationPriority,
+) -> Vec<OplogEntry> {
+    let mut entries = vec![OplogEntry::pending_worker_invocation(invocation.clone())];
+    if priority != InvocationPriority::Normal {
+        if let Some(idempotency_key) = invocation.idempotency_key() {
+            entries.push(OplogEntry::change_pending_invocation_priority(
+                idempotency_key.clone(),
+                priority,
+            ));
+        }
+    }
+    entries
+}
+
+/// Returns the index where an item with the given priority has to be inserted into a queue
+/// ordered by descending priority, so it gets after all the items with the same or higher priority.
+pub(crate) fn priority_insertion_index(
+    priorities: impl ExactSizeIterator<Item = InvocationPriority>,
+    priority: InvocationPriority,
+) -> usize {
+    let len = priorities.len();
+    let mut priorities = priorities;
+    priorities.position(|p| p < priority).unwrap_or(len)
+}
+
 pub enum ResultOrSubscription {
     Finished(Result<Option<ValueAndType>, WorkerExecutorError>),
     Pending(EventsSubscription),
@@ -1,8 +1,10 @@
 use crate::services::{HasConfig, HasOplogService};
-use crate::worker::is_worker_error_retriable;
+use crate::worker::{is_worker_error_retriable, priority_insertion_index};
 use async_recursion::async_recursion;
 use golem_common::base_model::{OplogIndex, PluginInstallationId};
-use golem_common::model::oplog::{OplogEntry, TimestampedUpdateDescription, UpdateDescription};
+use golem_common::model::oplog::{
+    InvocationPriority, OplogEntry, TimestampedUpdateDescription, UpdateDescription,
+};
 use golem_common::model::regions::{DeletedRegions, DeletedRegionsBuilder, OplogRegion};
 use golem_common::model::{
     AgentInstanceDescription, ExportedResourceInstanceDescription, ExportedResourceInstanceKey,
@@ -73,8 +75,9 @@ where
                 &new_entries,
             );
 
-            let pending_invocations = calculate_pending_invocations(
+            let (pending_invocations, invocation_priorities) = calculate_pending_invocations(
                 last_known.pending_invocations,
+                last_known.invocation_priorities,
                 &deleted_regions,
                 &new_entries,
             );
@@ -115,6 +118,8 @@ where
             let active_plugins =
                 calculate_active_plugins(active_plugins, &deleted_regions, &new_entries);
 
+            let labels = calculate_labels(last_known.labels, &deleted_regions, &new_entries);
+
             let result = WorkerStatusRecord {
                 oplog_idx: last_oplog_index,
                 status,
@@ -133,6 +138,8 @@ where
                 active_plugins,
                 deleted_regions,
                 component_version_for_replay,
+                labels,
+                invocation_priorities,
             };
             Ok(result)
         }
@@ -254,12 +261,15 @@ fn calculate_latest_worker_status(
             }
             OplogEntry::CreateAgentInstance { .. } => {}
             OplogEntry::DropAgentInstance { .. } => {}
+            OplogEntry::SetLabel { .. } => {}
+            OplogEntry::RemoveLabel { .. } => {}
+            OplogEntry::ChangePendingInvocationPriority { .. } => {}
         }
     }
     result
 }
 
-fn calculate_deleted_regions(
+pub(crate) fn calculate_deleted_regions(
     initial_deleted: DeletedRegions,
     entries: &BTreeMap<OplogIndex, OplogEntry>,
 ) -> DeletedRegions {
@@ -358,10 +368,15 @@ fn calculate_overridden_retry_policy(
 
 fn calculate_pending_invocations(
     initial: Vec<TimestampedWorkerInvocation>,
+    initial_priorities: HashMap<IdempotencyKey, InvocationPriority>,
     deleted_regions: &DeletedRegions,
     entries: &BTreeMap<OplogIndex, OplogEntry>,
-) -> Vec<TimestampedWorkerInvocation> {
+) -> (
+    Vec<TimestampedWorkerInvocation>,
+    HashMap<IdempotencyKey, InvocationPriority>,
+) {
     let mut result = initial;
+    let mut priorities = initial_priorities;
     for (idx, entry) in entries {
         // Skipping entries in deleted regions (by revert) but not by skipped regions (by jumps and updates)
         if deleted_regions.is_in_deleted_region(*idx) {
@@ -374,10 +389,14 @@ fn calculate_pending_invocations(
                 invocation,
                 ..
             } => {
-                result.push(TimestampedWorkerInvocation {
-                    timestamp: *timestamp,
-                    invocation: invocation.clone(),
-                });
+                insert_by_priority(
+                    &mut result,
+                    &priorities,
+                    TimestampedWorkerInvocation {
+                        timestamp: *timestamp,
+                        invocation: invocation.clone(),
+                    },
+                );
             }
             OplogEntry::ExportedFunctionInvoked {
                 idempotency_key, ..
@@ -393,6 +412,7 @@ fn calculate_pending_invocations(
                     } => key != idempotency_key,
                     _ => true,
                 });
+                priorities.remove(idempotency_key);
             }
             OplogEntry::PendingUpdate {
                 description: UpdateDescription::SnapshotBased { target_version, .. },
@@ -422,11 +442,51 @@ fn calculate_pending_invocations(
                     } => key != idempotency_key,
                     _ => true,
                 });
+                priorities.remove(idempotency_key);
+            }
+            OplogEntry::ChangePendingInvocationPriority {
+                idempotency_key,
+                priority,
+                ..
+            } => {
+                if let Some(position) = result.iter().position(|invocation| {
+                    invocation.invocation.is_idempotency_key(idempotency_key)
+                }) {
+                    let invocation = result.remove(position);
+                    if *priority == InvocationPriority::Normal {
+                        priorities.remove(idempotency_key);
+                    } else {
+                        priorities.insert(idempotency_key.clone(), *priority);
+                    }
+                    insert_by_priority(&mut result, &priorities, invocation);
+                }
             }
             _ => {}
         }
     }
-    result
+    (result, priorities)
+}
+
+/// Inserts a pending invocation to the list ordered by priority, after all the invocations
+/// with the same or higher priority - the same way the worker's invocation queue does it
+fn insert_by_priority(
+    pending_invocations: &mut Vec<TimestampedWorkerInvocation>,
+    priorities: &HashMap<IdempotencyKey, InvocationPriority>,
+    invocation: TimestampedWorkerInvocation,
+) {
+    let priority_of = |invocation: &TimestampedWorkerInvocation| {
+        invocation
+            .invocation
+            .idempotency_key()
+            .and_then(|key| priorities.get(key))
+            .copied()
+            .unwrap_or_default()
+    };
+    let index = priority_insertion_index(
+        pending_invocations.iter().map(&priority_of),
+        priority_of(&invocation),
+    );
+    pending_invocations.insert(index, invocation);
 }
 
 fn calculate_update_fields(
@@ -705,6 +765,31 @@ fn calculate_active_plugins(
     result
 }
 
+fn calculate_labels(
+    initial: BTreeMap<String, String>,
+    deleted_regions: &DeletedRegions,
+    entries: &BTreeMap<OplogIndex, OplogEntry>,
+) -> BTreeMap<String, String> {
+    let mut result = initial;
+    for (idx, entry) in entries {
+        // Skipping entries in deleted regions as they are not applied during replay
+        if deleted_regions.is_in_deleted_region(*idx) {
+            continue;
+        }
+
+        match entry {
+            OplogEntry::SetLabel { key, value, .. } => {
+                result.insert(key.clone(), value.clone());
+            }
+            OplogEntry::RemoveLabel { key, .. } => {
+                result.remove(key);
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::ExecutionStatus;
@@ -712,6 +797,7 @@ mod test {
     use crate::services::oplog::tests::rounded;
     use crate::services::oplog::{Oplog, OplogService};
     use crate::services::{HasConfig, HasOplogService};
+    use crate::worker::pending_invocation_entries;
     use crate::worker::status::calculate_last_known_status;
     use async_trait::async_trait;
     use bincode::Encode;
@@ -719,8 +805,8 @@ mod test {
     use golem_common::base_model::OplogIndex;
     use golem_common::model::invocation_context::{InvocationContextStack, TraceId};
     use golem_common::model::oplog::{
-        DurableFunctionType, OplogEntry, OplogPayload, TimestampedUpdateDescription,
-        UpdateDescription,
+        DurableFunctionType, InvocationPriority, OplogEntry, OplogPayload,
+        TimestampedUpdateDescription, UpdateDescription,
     };
     use golem_common::model::regions::{DeletedRegions, OplogRegion};
     use golem_common::model::{
@@ -1020,6 +1106,88 @@ mod test {
         run_test_case(test_case).await;
     }
 
+    #[test]
+    async fn pending_invocation_priorities() {
+        let keys = (0..4).map(|_| IdempotencyKey::fresh()).collect::<Vec<_>>();
+        let invocation = |idx: usize| WorkerInvocation::ExportedFunction {
+            idempotency_key: keys[idx].clone(),
+            full_function_name: format!("f{idx}"),
+            function_input: vec![],
+            invocation_context: InvocationContextStack::fresh(),
+        };
+
+        let test_case = TestCase::builder(0)
+            .pending_invocation(invocation(0))
+            .pending_invocation(invocation(1))
+            .pending_invocation(invocation(2))
+            .change_pending_invocation_priority(keys[2].clone(), InvocationPriority::Urgent)
+            .change_pending_invocation_priority(keys[0].clone(), InvocationPriority::Low)
+            .pending_invocation(invocation(3))
+            .change_pending_invocation_priority(keys[1].clone(), InvocationPriority::Normal)
+            .cancel_pending_invocation(keys[2].clone())
+            .build();
+
+        run_test_case(test_case).await;
+    }
+
+    #[test]
+    async fn pending_invocations_enqueued_with_priority() {
+        let keys = (0..3).map(|_| IdempotencyKey::fresh()).collect::<Vec<_>>();
+        let invocation = |idx: usize| WorkerInvocation::ExportedFunction {
+            idempotency_key: keys[idx].clone(),
+            full_function_name: format!("f{idx}"),
+            function_input: vec![],
+            invocation_context: InvocationContextStack::fresh(),
+        };
+
+        let test_case = TestCase::builder(0)
+            .enqueued_invocation(invocation(0), InvocationPriority::Low)
+            .enqueued_invocation(invocation(1), InvocationPriority::Normal)
+            .enqueued_invocation(invocation(2), InvocationPriority::Urgent)
+            .build();
+
+        run_test_case(test_case).await;
+    }
+
+    #[test]
+    async fn pending_invocation_priorities_with_revert() {
+        let k1 = IdempotencyKey::fresh();
+        let k2 = IdempotencyKey::fresh();
+
+        let test_case = TestCase::builder(0)
+            .pending_invocation(WorkerInvocation::ExportedFunction {
+                idempotency_key: k1.clone(),
+                full_function_name: "a".to_string(),
+                function_input: vec![],
+                invocation_context: InvocationContextStack::fresh(),
+            })
+            .pending_invocation(WorkerInvocation::ExportedFunction {
+                idempotency_key: k2.clone(),
+                full_function_name: "b".to_string(),
+                function_input: vec![],
+                invocation_context: InvocationContextStack::fresh(),
+            })
+            .change_pending_invocation_priority(k2, InvocationPriority::High)
+            .revert(OplogIndex::from_u64(3))
+            .build();
+
+        run_test_case(test_case).await;
+    }
+
+    #[test]
+    async fn labels_with_revert() {
+        let test_case = TestCase::builder(0)
+            .set_label("team", "payments")
+            .set_label("env", "staging")
+            .set_label("env", "production")
+            .remove_label("team")
+            .set_label("owner", "alice")
+            .revert(OplogIndex::from_u64(4))
+            .build();
+
+        run_test_case(test_case).await;
+    }
+
     struct TestCaseBuilder {
         entries: Vec<TestEntry>,
         previous_status_record: WorkerStatusRecord,
@@ -1159,6 +1327,28 @@ mod test {
             )
         }
 
+        pub fn set_label(self, key: &str, value: &str) -> Self {
+            let (key, value) = (key.to_string(), value.to_string());
+            self.add(
+                rounded(OplogEntry::set_label(key.clone(), value.clone())),
+                move |mut status| {
+                    status.labels.insert(key, value);
+                    status
+                },
+            )
+        }
+
+        pub fn remove_label(self, key: &str) -> Self {
+            let key = key.to_string();
+            self.add(
+                rounded(OplogEntry::remove_label(key.clone())),
+                move |mut status| {
+                    status.labels.remove(&key);
+                    status
+                },
+            )
+        }
+
         pub fn jump(self, target: OplogIndex) -> Self {
             let current = OplogIndex::from_u64(self.entries.len() as u64 + 1);
             let region = OplogRegion {
@@ -1192,6 +1382,8 @@ mod test {
                 .clone();
             self.add(OplogEntry::revert(region.clone()), move |mut status| {
                 status.active_plugins = old_status.active_plugins;
+                status.labels = old_status.labels;
+                status.invocation_priorities = old_status.invocation_priorities;
 
                 status.skipped_regions = old_status.skipped_regions;
                 status.skipped_regions.add(region.clone());
@@ -1217,16 +1409,90 @@ mod test {
         pub fn pending_invocation(self, invocation: WorkerInvocation) -> Self {
             let entry = rounded(OplogEntry::pending_worker_invocation(invocation.clone()));
             self.add(entry.clone(), move |mut status| {
-                status
+                // New invocations have normal priority, so they go before the low priority ones
+                let index = status
                     .pending_invocations
-                    .push(TimestampedWorkerInvocation {
+                    .iter()
+                    .position(|pending| {
+                        pending.invocation.idempotency_key().is_some_and(|key| {
+                            status.invocation_priorities.get(key) == Some(&InvocationPriority::Low)
+                        })
+                    })
+                    .unwrap_or(status.pending_invocations.len());
+                status.pending_invocations.insert(
+                    index,
+                    TimestampedWorkerInvocation {
                         timestamp: entry.timestamp(),
                         invocation,
-                    });
+                    },
+                );
+                status
+            })
+        }
+
+        pub fn change_pending_invocation_priority(
+            self,
+            idempotency_key: IdempotencyKey,
+            priority: InvocationPriority,
+        ) -> Self {
+            let entry = rounded(OplogEntry::change_pending_invocation_priority(
+                idempotency_key.clone(),
+                priority,
+            ));
+            self.add(entry, move |mut status| {
+                let position = status
+                    .pending_invocations
+                    .iter()
+                    .position(|pending| pending.invocation.is_idempotency_key(&idempotency_key))
+                    .unwrap();
+                let invocation = status.pending_invocations.remove(position);
+                if priority == InvocationPriority::Normal {
+                    status.invocation_priorities.remove(&idempotency_key);
+                } else {
+                    status
+                        .invocation_priorities
+                        .insert(idempotency_key.clone(), priority);
+                }
+                let priorities = status.invocation_priorities.clone();
+                let index = status
+                    .pending_invocations
+                    .iter()
+                    .position(|pending| {
+                        let pending_priority = pending
+                            .invocation
+                            .idempotency_key()
+                            .and_then(|key| priorities.get(key))
+                            .copied()
+                            .unwrap_or(InvocationPriority::Normal);
+                        pending_priority < priority
+                    })
+                    .unwrap_or(status.pending_invocations.len());
+                status.pending_invocations.insert(index, invocation);
                 status
             })
         }
 
+        /// Adds the same entries as the worker does when enqueueing an invocation while it is busy
+        pub fn enqueued_invocation(
+            self,
+            invocation: WorkerInvocation,
+            priority: InvocationPriority,
+        ) -> Self {
+            let entries = pending_invocation_entries(&invocation, priority);
+            let mut builder = self.pending_invocation(invocation);
+            for entry in entries.into_iter().skip(1) {
+                if let OplogEntry::ChangePendingInvocationPriority {
+                    idempotency_key,
+                    priority,
+                    ..
+                } = entry
+                {
+                    builder = builder.change_pending_invocation_priority(idempotency_key, priority);
+                }
+            }
+            builder
+        }
+
         pub fn cancel_pending_invocation(self, idempotency_key: IdempotencyKey) -> Self {
             let entry = rounded(OplogEntry::cancel_pending_invocation(
                 idempotency_key.clone(),
@@ -1245,6 +1511,7 @@ mod test {
                         } => key != &idempotency_key,
                         _ => true,
                     });
+                status.invocation_priorities.remove(&idempotency_key);
                 status
             })
         }
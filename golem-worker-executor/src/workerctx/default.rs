@@ -28,9 +28,11 @@ use crate::services::oplog::{Oplog, OplogService};
 use crate::services::plugins::Plugins;
 use crate::services::projects::ProjectService;
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rdbms::RdbmsService;
 use crate::services::resource_limits::ResourceLimits;
 use crate::services::rpc::Rpc;
+use crate::services::saga::SagaService;
 use crate::services::scheduler::SchedulerService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
@@ -415,6 +417,10 @@ impl UpdateManagement for Context {
         self.durable_ctx.end_call_snapshotting_function()
     }
 
+    async fn on_worker_update_snapshot_saved(&self) -> Result<(), WorkerExecutorError> {
+        self.durable_ctx.on_worker_update_snapshot_saved().await
+    }
+
     async fn on_worker_update_failed(
         &self,
         target_version: ComponentVersion,
@@ -677,6 +683,8 @@ impl WorkerCtx for Context {
         account_id: AccountId,
         owned_worker_id: OwnedWorkerId,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -704,6 +712,8 @@ impl WorkerCtx for Context {
         let golem_ctx = DurableWorkerCtx::create(
             owned_worker_id.clone(),
             promise_service,
+            pubsub_service,
+            saga_service,
             worker_service,
             worker_enumeration_service,
             key_value_service,
@@ -724,6 +734,7 @@ impl WorkerCtx for Context {
             plugins,
             worker_fork,
             project_service,
+            resource_limits.clone(),
         )
         .await?;
         Ok(Self::new(golem_ctx, config, account_id, resource_limits))
@@ -27,9 +27,11 @@ use crate::services::oplog::{Oplog, OplogService};
 use crate::services::plugins::Plugins;
 use crate::services::projects::ProjectService;
 use crate::services::promise::PromiseService;
+use crate::services::pubsub::PubSubService;
 use crate::services::rdbms::RdbmsService;
 use crate::services::resource_limits::ResourceLimits;
 use crate::services::rpc::Rpc;
+use crate::services::saga::SagaService;
 use crate::services::scheduler::SchedulerService;
 use crate::services::worker::WorkerService;
 use crate::services::worker_event::WorkerEventService;
@@ -97,6 +99,8 @@ pub trait WorkerCtx:
     /// - `component_metadata`: Metadata associated with the worker's component
     /// - `initial_component_metadata`: Metadata associated with the worker's component at the start of replay. Might be same or earlier than component_metadata
     /// - `promise_service`: The service for managing promises
+    /// - `pubsub_service`: The service keeping track of the topic subscriptions of workers
+    /// - `saga_service`: The service tracking the sagas coordinated by workers and compensating the aborted ones
     /// - `worker_service`: The service for managing workers
     /// - `key_value_service`: The service for storing key-value pairs
     /// - `blob_store_service`: The service for storing arbitrary blobs
@@ -117,6 +121,8 @@ pub trait WorkerCtx:
         account_id: AccountId,
         owned_worker_id: OwnedWorkerId,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn worker_enumeration::WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -322,6 +328,11 @@ pub trait UpdateManagement {
     /// Marks the end of a snapshot function call. This can be used to re-enable persistence
     fn end_call_snapshotting_function(&mut self);
 
+    /// Called after the save-snapshot function of a snapshot-based update returned, before the
+    /// update gets enqueued. Anything that has to survive skipping the worker's history must be
+    /// persisted here.
+    async fn on_worker_update_snapshot_saved(&self) -> Result<(), WorkerExecutorError>;
+
     /// Called when an update attempt has failed
     async fn on_worker_update_failed(
         &self,
@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::common::{start, TestContext};
+use crate::common::{start, TestContext, TestWorkerExecutor};
 use crate::compatibility::worker_recovery::save_recovery_golden_file;
 use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
 use assert2::check;
 use axum::routing::get;
 use axum::Router;
-use golem_api_grpc::proto::golem::worker::v1::{worker_execution_error, ComponentParseFailed};
-use golem_api_grpc::proto::golem::workerexecutor::v1::CompletePromiseRequest;
+use golem_api_grpc::proto::golem::worker::v1::{
+    invocation_result_status, worker_execution_error, ComponentParseFailed, InvocationResultStatus,
+    WorkerExecutionError,
+};
+use golem_api_grpc::proto::golem::workerexecutor::v1::{
+    get_invocation_result_response, CompletePromiseRequest, GetInvocationResultRequest,
+};
 use golem_common::model::component_metadata::{
     DynamicLinkedInstance, DynamicLinkedWasmRpc, WasmRpcTarget,
 };
@@ -29,7 +34,7 @@ use golem_common::model::{
     IdempotencyKey, PromiseId, ScanCursor, StringFilterComparator, TargetWorkerId, Timestamp,
     WorkerFilter, WorkerId, WorkerMetadata, WorkerResourceDescription, WorkerStatus,
 };
-use golem_test_framework::config::TestDependencies;
+use golem_test_framework::config::{TestDependencies, TestDependenciesDsl};
 use golem_test_framework::dsl::{
     drain_connection, is_worker_execution_error, stdout_event_matching, stdout_events,
     worker_error_logs, worker_error_message, TestDslUnsafe,
@@ -39,7 +44,7 @@ use golem_wasm_ast::analysis::{analysed_type, AnalysedType, TypeStr};
 use golem_wasm_rpc::{IntoValue, Record};
 use golem_wasm_rpc::{IntoValueAndType, Value, ValueAndType};
 use redis::Commands;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::io::Write;
 use std::path::Path;
@@ -1377,6 +1382,87 @@ async fn get_workers(
     get_check(&component_id, None, 0, &executor).await;
 }
 
+#[test]
+#[tracing::instrument]
+#[timeout(120_000)]
+async fn get_workers_by_label(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("option-service").store().await;
+
+    let worker_id1 = executor
+        .start_worker(&component_id, "get-workers-by-label-1")
+        .await;
+    let worker_id2 = executor
+        .start_worker(&component_id, "get-workers-by-label-2")
+        .await;
+
+    executor
+        .update_worker_labels(
+            &worker_id1,
+            HashMap::from([
+                ("team".to_string(), "payments".to_string()),
+                ("tier".to_string(), "gold".to_string()),
+            ]),
+            vec![],
+        )
+        .await;
+    executor
+        .update_worker_labels(
+            &worker_id2,
+            HashMap::from([("team".to_string(), "search".to_string())]),
+            vec![],
+        )
+        .await;
+    executor
+        .update_worker_labels(&worker_id1, HashMap::new(), vec!["tier".to_string()])
+        .await;
+
+    let (metadata1, _) = executor.get_worker_metadata(&worker_id1).await.unwrap();
+
+    let (_, payments) = executor
+        .get_workers_metadata(
+            &component_id,
+            Some(WorkerFilter::new_label(
+                "team".to_string(),
+                StringFilterComparator::Equal,
+                "payments".to_string(),
+            )),
+            ScanCursor::default(),
+            10,
+            true,
+        )
+        .await;
+    let (_, gold) = executor
+        .get_workers_metadata(
+            &component_id,
+            Some(WorkerFilter::new_label(
+                "tier".to_string(),
+                StringFilterComparator::Equal,
+                "gold".to_string(),
+            )),
+            ScanCursor::default(),
+            10,
+            true,
+        )
+        .await;
+
+    drop(executor);
+
+    check!(
+        metadata1.last_known_status.labels
+            == BTreeMap::from([("team".to_string(), "payments".to_string())])
+    );
+    check!(payments.len() == 1);
+    check!(payments[0].0.worker_id == worker_id1);
+    check!(gold.is_empty());
+}
+
 #[test]
 #[tracing::instrument]
 #[timeout(120_000)]
@@ -3336,6 +3422,119 @@ async fn cancelling_pending_invocations(
     executor.check_oplog_is_queryable(&worker_id).await;
 }
 
+#[test]
+#[tracing::instrument]
+#[timeout(120_000)]
+async fn getting_invocation_results(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("counters").store().await;
+    let worker_id = executor
+        .start_worker(&component_id, "get-invocation-results")
+        .await;
+
+    let completed_key = IdempotencyKey::fresh();
+    let blocked_key = IdempotencyKey::fresh();
+    let unknown_key = IdempotencyKey::fresh();
+
+    let _ = executor
+        .invoke_and_await_with_key(
+            &worker_id,
+            &completed_key,
+            "rpc:counters-exports/api.{counter(\"counter1\").inc-by}",
+            vec![5u64.into_value_and_type()],
+        )
+        .await
+        .unwrap();
+
+    let promise_id = executor
+        .invoke_and_await(
+            &worker_id,
+            "rpc:counters-exports/api.{counter(\"counter1\").create-promise}",
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    executor
+        .invoke_with_key(
+            &worker_id,
+            &blocked_key,
+            "rpc:counters-exports/api.{counter(\"counter1\").block-on-promise}",
+            vec![ValueAndType {
+                value: promise_id[0].clone(),
+                typ: PromiseId::get_type(),
+            }],
+        )
+        .await
+        .unwrap();
+
+    let completed = get_invocation_result(&executor, &worker_id, &completed_key, None).await;
+    let wait_started = std::time::Instant::now();
+    let blocked = get_invocation_result(&executor, &worker_id, &blocked_key, Some(500)).await;
+    let blocked_wait = wait_started.elapsed();
+    let unknown = get_invocation_result(&executor, &worker_id, &unknown_key, Some(500)).await;
+
+    check!(matches!(
+        completed,
+        Some(get_invocation_result_response::Result::Success(
+            InvocationResultStatus {
+                status: Some(invocation_result_status::Status::Completed(_))
+            }
+        ))
+    ));
+    check!(matches!(
+        blocked,
+        Some(get_invocation_result_response::Result::Success(
+            InvocationResultStatus {
+                status: Some(
+                    invocation_result_status::Status::Running(_)
+                        | invocation_result_status::Status::Pending(_)
+                )
+            }
+        ))
+    ));
+    check!(blocked_wait >= Duration::from_millis(500));
+    check!(matches!(
+        unknown,
+        Some(get_invocation_result_response::Result::Failure(
+            WorkerExecutionError {
+                error: Some(worker_execution_error::Error::InvocationNotFound(_))
+            }
+        ))
+    ));
+
+    executor.check_oplog_is_queryable(&worker_id).await;
+}
+
+async fn get_invocation_result(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    worker_id: &WorkerId,
+    idempotency_key: &IdempotencyKey,
+    wait_timeout_millis: Option<u64>,
+) -> Option<get_invocation_result_response::Result> {
+    executor
+        .deps
+        .client()
+        .await
+        .expect("Failed to get client")
+        .get_invocation_result(GetInvocationResultRequest {
+            worker_id: Some(worker_id.clone().into()),
+            idempotency_key: Some(idempotency_key.clone().into()),
+            account_id: Some(executor.account_id.clone().into()),
+            project_id: Some(executor.default_project_id.clone().into()),
+            wait_timeout_millis,
+        })
+        .await
+        .expect("Failed to get invocation result")
+        .into_inner()
+        .result
+}
+
 /// Test resolving a component_id from the name.
 #[test]
 #[tracing::instrument]
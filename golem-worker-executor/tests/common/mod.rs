@@ -61,8 +61,10 @@ use golem_worker_executor::services::oplog::{Oplog, OplogService};
 use golem_worker_executor::services::plugins::{Plugins, PluginsObservations};
 use golem_worker_executor::services::projects::ProjectService;
 use golem_worker_executor::services::promise::PromiseService;
+use golem_worker_executor::services::pubsub::PubSubService;
 use golem_worker_executor::services::resource_limits::ResourceLimits;
 use golem_worker_executor::services::rpc::{DirectWorkerInvocationRpc, RemoteInvocationRpc, Rpc};
+use golem_worker_executor::services::saga::SagaService;
 use golem_worker_executor::services::scheduler::SchedulerService;
 use golem_worker_executor::services::shard::ShardService;
 use golem_worker_executor::services::shard_manager::ShardManagerService;
@@ -638,6 +640,10 @@ impl UpdateManagement for TestWorkerCtx {
         self.durable_ctx.end_call_snapshotting_function()
     }
 
+    async fn on_worker_update_snapshot_saved(&self) -> Result<(), WorkerExecutorError> {
+        self.durable_ctx.on_worker_update_snapshot_saved().await
+    }
+
     async fn on_worker_update_failed(
         &self,
         target_version: ComponentVersion,
@@ -672,6 +678,8 @@ impl WorkerCtx for TestWorkerCtx {
         _account_id: AccountId,
         owned_worker_id: OwnedWorkerId,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         worker_service: Arc<dyn WorkerService>,
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -693,12 +701,14 @@ impl WorkerCtx for TestWorkerCtx {
         file_loader: Arc<FileLoader>,
         plugins: Arc<dyn Plugins>,
         worker_fork: Arc<dyn WorkerForkService>,
-        _resource_limits: Arc<dyn ResourceLimits>,
+        resource_limits: Arc<dyn ResourceLimits>,
         project_service: Arc<dyn ProjectService>,
     ) -> Result<Self, WorkerExecutorError> {
         let durable_ctx = DurableWorkerCtx::create(
             owned_worker_id,
             promise_service,
+            pubsub_service,
+            saga_service,
             worker_service,
             worker_enumeration_service,
             key_value_service,
@@ -719,6 +729,7 @@ impl WorkerCtx for TestWorkerCtx {
             plugins,
             worker_fork,
             project_service,
+            resource_limits,
         )
         .await?;
         Ok(Self { durable_ctx })
@@ -1036,6 +1047,8 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         worker_enumeration_service: Arc<dyn WorkerEnumerationService>,
         running_worker_enumeration_service: Arc<dyn RunningWorkerEnumerationService>,
         promise_service: Arc<dyn PromiseService>,
+        pubsub_service: Arc<dyn PubSubService>,
+        saga_service: Arc<dyn SagaService>,
         golem_config: Arc<GolemConfig>,
         shard_service: Arc<dyn ShardService>,
         key_value_service: Arc<dyn KeyValueService>,
@@ -1068,6 +1081,8 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             key_value_service.clone(),
@@ -1100,6 +1115,8 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             worker_enumeration_service.clone(),
             running_worker_enumeration_service.clone(),
             promise_service.clone(),
+            pubsub_service.clone(),
+            saga_service.clone(),
             golem_config.clone(),
             shard_service.clone(),
             shard_manager_service.clone(),
@@ -1129,6 +1146,8 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
             worker_enumeration_service,
             running_worker_enumeration_service,
             promise_service,
+            pubsub_service,
+            saga_service,
             golem_config,
             shard_service,
             key_value_service,
@@ -1154,6 +1173,8 @@ impl Bootstrap<TestWorkerCtx> for ServerBootstrap {
         golem_api_1_x::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_api_1_x::oplog::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_api_1_x::context::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem_api_1_x::pubsub::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
+        golem_api_1_x::saga::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         durability::durability::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_agent::host::add_to_linker_get_host(&mut linker, get_durable_ctx)?;
         golem_wasm_rpc::golem_rpc_0_2_x::types::add_to_linker_get_host(
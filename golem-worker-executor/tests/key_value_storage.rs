@@ -26,6 +26,7 @@ use golem_worker_executor::storage::keyvalue::{KeyValueStorage, KeyValueStorageN
 use sqlx::sqlite::SqlitePoolOptions;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
+use std::time::Duration;
 use test_r::{define_matrix_dimension, inherit_test_dep, test, test_dep};
 use uuid::Uuid;
 
@@ -486,6 +487,237 @@ async fn keys(
     assert_eq!(keys3, vec![key2.to_string()]);
 }
 
+#[test]
+#[tracing::instrument]
+async fn set_with_ttl(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let key1 = "key1";
+    let key2 = "key2";
+    let value = "value".as_bytes();
+
+    kvs.set_with_ttl(
+        "test",
+        "api",
+        "entity",
+        ns.clone(),
+        key1,
+        value,
+        Duration::from_millis(500),
+    )
+    .await
+    .unwrap();
+    kvs.set_with_ttl(
+        "test",
+        "api",
+        "entity",
+        ns.clone(),
+        key2,
+        value,
+        Duration::from_millis(500),
+    )
+    .await
+    .unwrap();
+    kvs.set("test", "api", "entity", ns.clone(), key2, value)
+        .await
+        .unwrap();
+
+    let result1 = kvs
+        .get("test", "api", "entity", ns.clone(), key1)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    let result2 = kvs
+        .get("test", "api", "entity", ns.clone(), key1)
+        .await
+        .unwrap();
+    let result3 = kvs.exists("test", "api", ns.clone(), key1).await.unwrap();
+    let result4 = kvs.get("test", "api", "entity", ns, key2).await.unwrap();
+    assert_eq!(result1, Some(value.into()));
+    assert_eq!(result2, None);
+    assert!(!result3);
+    assert_eq!(result4, Some(value.into()));
+}
+
+#[test]
+#[tracing::instrument]
+async fn expired_keys_are_skipped(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let value = "value".as_bytes();
+    kvs.set_with_ttl(
+        "test",
+        "api",
+        "entity",
+        ns.clone(),
+        "expiring-expired",
+        value,
+        Duration::from_millis(200),
+    )
+    .await
+    .unwrap();
+    kvs.set_with_ttl(
+        "test",
+        "api",
+        "entity",
+        ns.clone(),
+        "expiring-counter",
+        "5".as_bytes(),
+        Duration::from_millis(200),
+    )
+    .await
+    .unwrap();
+    kvs.set("test", "api", "entity", ns.clone(), "expiring-kept", value)
+        .await
+        .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    let page = kvs
+        .list_keys("test", "api", ns.clone(), Some("expiring-"), None, 100)
+        .await
+        .unwrap();
+    let values = kvs
+        .get_many(
+            "test",
+            "api",
+            "entity",
+            ns.clone(),
+            vec!["expiring-expired".to_string(), "expiring-kept".to_string()],
+        )
+        .await
+        .unwrap();
+    let counter = kvs
+        .increment("test", "api", ns.clone(), "expiring-counter", 1)
+        .await
+        .unwrap();
+    let inserted = kvs
+        .set_if_not_exists("test", "api", "entity", ns, "expiring-expired", value)
+        .await
+        .unwrap();
+
+    assert_eq!(page.keys, vec!["expiring-kept"]);
+    assert_eq!(values, vec![None, Some(value.into())]);
+    assert_eq!(counter, 1);
+    assert!(inserted);
+}
+
+#[test]
+#[tracing::instrument]
+async fn list_keys(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let value = "value".as_bytes();
+    for key in ["a1", "a2", "a3", "a4", "a5", "b1"] {
+        kvs.set("test", "api", "entity", ns.clone(), key, value)
+            .await
+            .unwrap();
+    }
+
+    let mut keys = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = kvs
+            .list_keys("test", "api", ns.clone(), Some("a"), cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        keys.extend(page.keys);
+        match page.cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    keys.sort();
+    keys.dedup();
+
+    assert_eq!(keys, vec!["a1", "a2", "a3", "a4", "a5"]);
+}
+
+#[test]
+#[tracing::instrument]
+async fn increment(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let key = "counter";
+
+    let result1 = kvs
+        .increment("test", "api", ns.clone(), key, 5)
+        .await
+        .unwrap();
+    let result2 = kvs
+        .increment("test", "api", ns.clone(), key, 3)
+        .await
+        .unwrap();
+    let result3 = kvs
+        .increment("test", "api", nss.ns2.clone(), key, 1)
+        .await
+        .unwrap();
+    let result4 = kvs.get("test", "api", "entity", ns, key).await.unwrap();
+    assert_eq!(result1, 5);
+    assert_eq!(result2, 8);
+    assert_eq!(result3, 1);
+    assert_eq!(result4, Some("8".as_bytes().into()));
+}
+
+#[test]
+#[tracing::instrument]
+async fn compare_and_swap(
+    _deps: &WorkerExecutorTestDependencies,
+    #[dimension(kvs)] kvs: &Arc<dyn GetKeyValueStorage + Send + Sync>,
+    #[dimension(nss)] nss: &Namespaces,
+) {
+    let kvs = kvs.get_key_value_storage().await;
+    let ns = nss.ns.clone();
+
+    let key = "counter";
+
+    let result1 = kvs
+        .compare_and_swap("test", "api", ns.clone(), key, 1, 2)
+        .await
+        .unwrap();
+    let result2 = kvs
+        .compare_and_swap("test", "api", ns.clone(), key, 0, 10)
+        .await
+        .unwrap();
+    let result3 = kvs
+        .compare_and_swap("test", "api", ns.clone(), key, 0, 20)
+        .await
+        .unwrap();
+    let result4 = kvs
+        .compare_and_swap("test", "api", ns.clone(), key, 10, 20)
+        .await
+        .unwrap();
+    let result5 = kvs
+        .increment("test", "api", ns.clone(), key, 1)
+        .await
+        .unwrap();
+    assert!(!result1);
+    assert!(result2);
+    assert!(!result3);
+    assert!(result4);
+    assert_eq!(result5, 21);
+}
+
 #[test]
 #[tracing::instrument]
 async fn sets(
@@ -54,11 +54,13 @@ pub mod key_value_storage;
 pub mod keyvalue;
 pub mod measure_test_component_mem;
 pub mod observability;
+pub mod pubsub;
 pub mod rdbms;
 pub mod rdbms_service;
 pub mod revert;
 pub mod rust_rpc;
 pub mod rust_rpc_stubless;
+pub mod saga;
 pub mod scalability;
 pub mod transactions;
 pub mod ts_rpc1_stubless;
@@ -78,6 +80,8 @@ tag_suite!(transactions, group2);
 tag_suite!(wasi, group2);
 tag_suite!(revert, group2);
 tag_suite!(durability, group2);
+tag_suite!(pubsub, group2);
+tag_suite!(saga, group2);
 
 tag_suite!(scalability, group3);
 tag_suite!(hot_update, group3);
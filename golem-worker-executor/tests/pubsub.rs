@@ -0,0 +1,254 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::{start, TestContext, TestWorkerExecutor};
+use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use golem_common::model::{IdempotencyKey, WorkerId};
+use golem_service_base::model::{RevertLastInvocations, RevertWorkerTarget};
+use golem_test_framework::config::TestDependenciesDsl;
+use golem_test_framework::dsl::TestDslUnsafe;
+use golem_wasm_rpc::{IntoValueAndType, Value};
+use golem_worker_executor::services::pubsub::delivery_idempotency_key;
+use test_r::{inherit_test_dep, test};
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(Tracing);
+
+#[test]
+#[tracing::instrument]
+async fn publish_delivers_to_subscribers(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("pubsub").store().await;
+    let subscriber1 = executor.start_worker(&component_id, "subscriber-1").await;
+    let subscriber2 = executor.start_worker(&component_id, "subscriber-2").await;
+    let publisher = executor.start_worker(&component_id, "publisher").await;
+
+    subscribe(&executor, &subscriber1, "orders").await;
+    subscribe(&executor, &subscriber2, "orders").await;
+
+    let enqueued1 = publish(&executor, &publisher, "orders", vec![1, 2, 3]).await;
+
+    executor
+        .invoke_and_await(
+            &subscriber2,
+            "golem:it/api.{unsubscribe}",
+            vec!["orders".into_value_and_type()],
+        )
+        .await
+        .unwrap();
+
+    let enqueued2 = publish(&executor, &publisher, "orders", vec![4, 5]).await;
+    let enqueued3 = publish(&executor, &publisher, "payments", vec![6]).await;
+
+    let received1 = get_received(&executor, &subscriber1).await;
+    let received2 = get_received(&executor, &subscriber2).await;
+
+    executor.check_oplog_is_queryable(&publisher).await;
+
+    drop(executor);
+
+    assert_eq!(enqueued1, 2);
+    assert_eq!(enqueued2, 1);
+    assert_eq!(enqueued3, 0);
+    assert_eq!(
+        received1
+            .iter()
+            .map(|(topic, _, payload)| (topic.as_str(), payload.clone()))
+            .collect::<Vec<_>>(),
+        vec![("orders", vec![1, 2, 3]), ("orders", vec![4, 5])]
+    );
+    assert_ne!(received1[0].1, received1[1].1);
+    assert_eq!(
+        received2
+            .iter()
+            .map(|(topic, _, payload)| (topic.as_str(), payload.clone()))
+            .collect::<Vec<_>>(),
+        vec![("orders", vec![1, 2, 3])]
+    );
+    assert_eq!(received1[0].1, received2[0].1);
+}
+
+#[test]
+#[tracing::instrument]
+async fn redelivered_messages_are_handled_once(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("pubsub").store().await;
+    let subscriber = executor.start_worker(&component_id, "subscriber").await;
+    let publisher = executor.start_worker(&component_id, "publisher").await;
+
+    subscribe(&executor, &subscriber, "orders").await;
+    let enqueued = publish(&executor, &publisher, "orders", vec![1, 2, 3]).await;
+    let received1 = get_received(&executor, &subscriber).await;
+
+    // Recovering the publisher replays the publish without delivering the message again
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let publisher_received = get_received(&executor, &publisher).await;
+
+    // Delivering the same message again, as a retried publish would, does not invoke the handler again
+    let message_id = IdempotencyKey::new(received1[0].1.clone());
+    executor
+        .invoke_and_await_with_key(
+            &subscriber,
+            &delivery_idempotency_key(&message_id, &subscriber),
+            "golem:it/api.{on-message}",
+            vec![
+                "orders".into_value_and_type(),
+                message_id.value.clone().into_value_and_type(),
+                vec![1u8, 2, 3].into_value_and_type(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let received2 = get_received(&executor, &subscriber).await;
+
+    executor.check_oplog_is_queryable(&publisher).await;
+
+    drop(executor);
+
+    assert_eq!(enqueued, 1);
+    assert!(publisher_received.is_empty());
+    assert_eq!(received1.len(), 1);
+    assert_eq!(received2, received1);
+}
+
+#[test]
+#[tracing::instrument]
+async fn reverted_subscriptions_are_removed(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("pubsub").store().await;
+    let subscriber = executor.start_worker(&component_id, "subscriber").await;
+    let publisher = executor.start_worker(&component_id, "publisher").await;
+
+    subscribe(&executor, &subscriber, "orders").await;
+    subscribe(&executor, &subscriber, "payments").await;
+
+    executor
+        .revert(
+            &subscriber,
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations: 1,
+            }),
+        )
+        .await;
+
+    let enqueued1 = publish(&executor, &publisher, "orders", vec![1]).await;
+    let enqueued2 = publish(&executor, &publisher, "payments", vec![2]).await;
+
+    let received = get_received(&executor, &subscriber).await;
+
+    executor.check_oplog_is_queryable(&subscriber).await;
+
+    drop(executor);
+
+    assert_eq!(enqueued1, 1);
+    assert_eq!(enqueued2, 0);
+    assert_eq!(
+        received
+            .iter()
+            .map(|(topic, _, payload)| (topic.as_str(), payload.clone()))
+            .collect::<Vec<_>>(),
+        vec![("orders", vec![1])]
+    );
+}
+
+async fn subscribe(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    worker_id: &WorkerId,
+    topic: &str,
+) {
+    let result = executor
+        .invoke_and_await(
+            worker_id,
+            "golem:it/api.{subscribe}",
+            vec![topic.into_value_and_type()],
+        )
+        .await
+        .unwrap();
+    assert_eq!(result, vec![Value::Result(Ok(None))]);
+}
+
+async fn publish(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    worker_id: &WorkerId,
+    topic: &str,
+    payload: Vec<u8>,
+) -> u32 {
+    let result = executor
+        .invoke_and_await(
+            worker_id,
+            "golem:it/api.{publish}",
+            vec![topic.into_value_and_type(), payload.into_value_and_type()],
+        )
+        .await
+        .unwrap();
+    match result.as_slice() {
+        [Value::U32(enqueued)] => *enqueued,
+        _ => panic!("Unexpected result of publish: {result:?}"),
+    }
+}
+
+async fn get_received(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    worker_id: &WorkerId,
+) -> Vec<(String, String, Vec<u8>)> {
+    let result = executor
+        .invoke_and_await(worker_id, "golem:it/api.{get-received}", vec![])
+        .await
+        .unwrap();
+    match result.as_slice() {
+        [Value::List(messages)] => messages
+            .iter()
+            .map(|message| match message {
+                Value::Tuple(fields) => match fields.as_slice() {
+                    [Value::String(topic), Value::String(message_id), Value::List(payload)] => (
+                        topic.clone(),
+                        message_id.clone(),
+                        payload
+                            .iter()
+                            .map(|byte| match byte {
+                                Value::U8(byte) => *byte,
+                                _ => panic!("Unexpected payload byte: {byte:?}"),
+                            })
+                            .collect(),
+                    ),
+                    _ => panic!("Unexpected message: {message:?}"),
+                },
+                _ => panic!("Unexpected message: {message:?}"),
+            })
+            .collect(),
+        _ => panic!("Unexpected result of get-received: {result:?}"),
+    }
+}
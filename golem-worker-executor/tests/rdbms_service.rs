@@ -21,6 +21,7 @@ use golem_test_framework::components::rdb::docker_postgres::DockerPostgresRdb;
 use golem_worker_executor::services::golem_config::{RdbmsConfig, RdbmsPoolConfig};
 use golem_worker_executor::services::rdbms::mysql::{types as mysql_types, MysqlType};
 use golem_worker_executor::services::rdbms::postgres::{types as postgres_types, PostgresType};
+use golem_worker_executor::services::rdbms::sqlite::{types as sqlite_types, SqliteType};
 use golem_worker_executor::services::rdbms::{DbResult, DbRow, Error};
 use golem_worker_executor::services::rdbms::{Rdbms, RdbmsServiceDefault, RdbmsType};
 use golem_worker_executor::services::rdbms::{RdbmsPoolKey, RdbmsService};
@@ -48,6 +49,23 @@ async fn mysql() -> DockerMysqlRdb {
     DockerMysqlRdb::new(&unique_network_id).await
 }
 
+struct SqliteDb {
+    dir: tempfile::TempDir,
+}
+
+impl SqliteDb {
+    fn connection_string(&self, db_name: &str) -> String {
+        format!("sqlite://{}/{db_name}.db", self.dir.path().display())
+    }
+}
+
+#[test_dep]
+fn sqlite() -> SqliteDb {
+    SqliteDb {
+        dir: tempfile::tempdir().expect("Failed to create temporary directory"),
+    }
+}
+
 #[test_dep]
 fn rdbms_service() -> RdbmsServiceDefault {
     RdbmsServiceDefault::new(RdbmsConfig {
@@ -2435,6 +2453,255 @@ async fn mysql_create_insert_select_test(
     .await;
 }
 
+#[test]
+async fn sqlite_transaction_tests(sqlite: &SqliteDb, rdbms_service: &RdbmsServiceDefault) {
+    let db_address = sqlite.connection_string("transaction_tests");
+    let rdbms = rdbms_service.sqlite();
+
+    let create_table_statement = r#"
+            CREATE TABLE IF NOT EXISTS test_users
+            (
+                user_id             TEXT NOT NULL,
+                name                TEXT NOT NULL,
+                created_on          DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                PRIMARY KEY (user_id)
+            );
+        "#;
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(
+            vec![StatementTest::execute_test(
+                create_table_statement,
+                vec![],
+                None,
+            )],
+            None,
+        ),
+    )
+    .await;
+
+    let insert_statement = r#"
+            INSERT INTO test_users
+            (user_id, name)
+            VALUES
+            (?, ?)
+        "#;
+
+    let count = 60;
+
+    let mut rows: Vec<DbRow<sqlite_types::DbValue>> = Vec::with_capacity(count);
+
+    let mut statements: Vec<StatementTest<SqliteType>> = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let params: Vec<sqlite_types::DbValue> = vec![
+            sqlite_types::DbValue::Text(format!("{i:03}")),
+            sqlite_types::DbValue::Text(format!("name-{i:03}")),
+        ];
+
+        statements.push(StatementTest::execute_test(
+            insert_statement,
+            params.clone(),
+            Some(1),
+        ));
+
+        rows.push(DbRow { values: params });
+    }
+
+    let select_statement_test = StatementTest::query_test(
+        "SELECT user_id, name FROM test_users ORDER BY user_id ASC",
+        vec![],
+        None,
+        Some(rows.clone()),
+    );
+
+    statements.push(select_statement_test.clone());
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(statements, Some(TransactionEnd::Commit)),
+    )
+    .await;
+
+    let delete_statement_test = StatementTest::execute_test("DELETE FROM test_users", vec![], None);
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(
+            vec![delete_statement_test.clone()],
+            Some(TransactionEnd::Rollback),
+        ),
+    )
+    .await;
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(
+            vec![select_statement_test.with_query_stream_expected(None, Some(rows.clone()))],
+            Some(TransactionEnd::Commit),
+        ),
+    )
+    .await;
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(vec![delete_statement_test], Some(TransactionEnd::Commit)),
+    )
+    .await;
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(
+            vec![select_statement_test.with_query_expected(Some(vec![]), Some(vec![]))],
+            Some(TransactionEnd::None),
+        ),
+    )
+    .await;
+}
+
+#[test]
+async fn sqlite_create_insert_select_test(sqlite: &SqliteDb, rdbms_service: &RdbmsServiceDefault) {
+    let db_address = sqlite.connection_string("create_insert_select_test");
+    let rdbms = rdbms_service.sqlite();
+    let create_table_statement = r#"
+            CREATE TABLE IF NOT EXISTS data_types
+            (
+              id TEXT PRIMARY KEY,
+              integer_col INTEGER,
+              real_col REAL,
+              text_col TEXT,
+              blob_col BLOB,
+              boolean_col BOOLEAN,
+              date_col DATE,
+              time_col TIME,
+              datetime_col DATETIME
+            );
+        "#;
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(
+            vec![StatementTest::execute_test(
+                create_table_statement,
+                vec![],
+                None,
+            )],
+            None,
+        ),
+    )
+    .await;
+
+    let insert_statement = r#"
+            INSERT INTO data_types (
+              id, integer_col, real_col, text_col, blob_col,
+              boolean_col, date_col, time_col, datetime_col
+            ) VALUES (
+              ?, ?, ?, ?, ?,
+              ?, ?, ?, ?
+            );
+        "#;
+
+    let count = 4;
+
+    let mut rows: Vec<DbRow<sqlite_types::DbValue>> = Vec::with_capacity(count);
+    let mut statements: Vec<StatementTest<SqliteType>> = Vec::with_capacity(count);
+    for i in 0..count {
+        let mut params: Vec<sqlite_types::DbValue> =
+            vec![sqlite_types::DbValue::Text(format!("{i:03}"))];
+
+        if i % 2 == 0 {
+            params.append(&mut vec![
+                sqlite_types::DbValue::Integer(i as i64 * 10),
+                sqlite_types::DbValue::Real(1.5),
+                sqlite_types::DbValue::Text(format!("name-{}", Uuid::new_v4())),
+                sqlite_types::DbValue::Blob("Blob".as_bytes().to_vec()),
+                sqlite_types::DbValue::Boolean(true),
+                sqlite_types::DbValue::Date(
+                    chrono::NaiveDate::from_ymd_opt(2030 + i as i32, 10, 12).unwrap(),
+                ),
+                sqlite_types::DbValue::Time(chrono::NaiveTime::from_hms_opt(1, 20, 30).unwrap()),
+                sqlite_types::DbValue::Datetime(chrono::NaiveDateTime::new(
+                    chrono::NaiveDate::from_ymd_opt(2023 + i as i32, 1, 1).unwrap(),
+                    chrono::NaiveTime::from_hms_opt(10, 20, 30).unwrap(),
+                )),
+            ]);
+        } else {
+            for _ in 0..8 {
+                params.push(sqlite_types::DbValue::Null);
+            }
+        };
+
+        statements.push(StatementTest::execute_test(
+            insert_statement,
+            params.clone(),
+            Some(1),
+        ));
+
+        rows.push(DbRow { values: params });
+    }
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(statements, Some(TransactionEnd::Commit)),
+    )
+    .await;
+
+    let expected_columns: Vec<sqlite_types::DbColumn> = [
+        ("id", sqlite_types::DbColumnType::Text),
+        ("integer_col", sqlite_types::DbColumnType::Integer),
+        ("real_col", sqlite_types::DbColumnType::Real),
+        ("text_col", sqlite_types::DbColumnType::Text),
+        ("blob_col", sqlite_types::DbColumnType::Blob),
+        ("boolean_col", sqlite_types::DbColumnType::Boolean),
+        ("date_col", sqlite_types::DbColumnType::Date),
+        ("time_col", sqlite_types::DbColumnType::Time),
+        ("datetime_col", sqlite_types::DbColumnType::Datetime),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(ordinal, (name, db_type))| sqlite_types::DbColumn {
+        name: name.to_string(),
+        ordinal: ordinal as u64,
+        db_type_name: db_type.to_string().to_uppercase(),
+        db_type,
+    })
+    .collect();
+
+    let select_statement = r#"
+            SELECT
+              id, integer_col, real_col, text_col, blob_col,
+              boolean_col, date_col, time_col, datetime_col
+           FROM data_types ORDER BY id ASC;
+        "#;
+
+    rdbms_test(
+        rdbms.clone(),
+        &db_address,
+        RdbmsTest::new(
+            vec![
+                StatementTest::query_stream_test(
+                    select_statement,
+                    vec![],
+                    Some(expected_columns),
+                    Some(rows),
+                ),
+                StatementTest::execute_test("DELETE FROM data_types;", vec![], None),
+            ],
+            None,
+        ),
+    )
+    .await;
+}
+
 async fn execute_rdbms_test<T: RdbmsType + Clone + 'static>(
     rdbms: Arc<dyn Rdbms<T> + Send + Sync>,
     pool_key: &RdbmsPoolKey,
@@ -2750,6 +3017,50 @@ async fn mysql_connection_err_test(rdbms_service: &RdbmsServiceDefault) {
     .await;
 }
 
+#[test]
+async fn sqlite_query_err_test(sqlite: &SqliteDb, rdbms_service: &RdbmsServiceDefault) {
+    let db_address = sqlite.connection_string("query_err_test");
+    let rdbms = rdbms_service.sqlite();
+
+    rdbms_query_err_test(
+        rdbms.clone(),
+        &db_address,
+        "SELECT * FROM xxx",
+        vec![],
+        Error::QueryExecutionFailure(
+            "error returned from database: (code: 1) no such table: xxx".to_string(),
+        ),
+    )
+    .await;
+}
+
+#[test]
+async fn sqlite_execute_err_test(sqlite: &SqliteDb, rdbms_service: &RdbmsServiceDefault) {
+    let db_address = sqlite.connection_string("execute_err_test");
+    let rdbms = rdbms_service.sqlite();
+
+    rdbms_execute_err_test(
+        rdbms.clone(),
+        &db_address,
+        "SELECT * FROM xxx",
+        vec![],
+        Error::QueryExecutionFailure(
+            "error returned from database: (code: 1) no such table: xxx".to_string(),
+        ),
+    )
+    .await;
+}
+
+#[test]
+async fn sqlite_connection_err_test(rdbms_service: &RdbmsServiceDefault) {
+    rdbms_connection_err_test(
+        rdbms_service.sqlite(),
+        "sqlit:///tmp/test.db",
+        Error::ConnectionFailure("scheme 'sqlit' in url is invalid".to_string()),
+    )
+    .await;
+}
+
 async fn rdbms_connection_err_test<T: RdbmsType>(
     rdbms: Arc<dyn Rdbms<T> + Send + Sync>,
     db_address: &str,
@@ -2893,6 +3204,25 @@ async fn postgres_par_test(postgres: &DockerPostgresRdb, rdbms_service: &RdbmsSe
     .await
 }
 
+#[test]
+async fn sqlite_par_test(sqlite: &SqliteDb, rdbms_service: &RdbmsServiceDefault) {
+    let rdbms = rdbms_service.sqlite();
+    let db_addresses = (0..4)
+        .map(|i| sqlite.connection_string(&format!("par_test_{i}")))
+        .collect();
+
+    rdbms_par_test(
+        rdbms,
+        db_addresses,
+        3,
+        RdbmsTest::new(
+            vec![StatementTest::execute_test("SELECT 1", vec![], Some(0))],
+            None,
+        ),
+    )
+    .await
+}
+
 async fn create_test_databases<T: RdbmsType + Clone + 'static>(
     rdbms: Arc<dyn Rdbms<T> + Send + Sync>,
     db_address: &str,
@@ -0,0 +1,283 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::common::{start, TestContext, TestWorkerExecutor};
+use crate::{LastUniqueId, Tracing, WorkerExecutorTestDependencies};
+use golem_common::model::WorkerId;
+use golem_service_base::model::{RevertLastInvocations, RevertWorkerTarget};
+use golem_test_framework::config::TestDependenciesDsl;
+use golem_test_framework::dsl::TestDslUnsafe;
+use golem_wasm_ast::analysis::analysed_type;
+use golem_wasm_rpc::{IntoValueAndType, Value, ValueAndType};
+use std::time::{Duration, Instant};
+use test_r::{inherit_test_dep, test};
+
+inherit_test_dep!(WorkerExecutorTestDependencies);
+inherit_test_dep!(LastUniqueId);
+inherit_test_dep!(Tracing);
+
+#[derive(Clone, Copy)]
+enum Outcome {
+    Complete = 0,
+    Abort = 1,
+    Fail = 2,
+    LeaveRunning = 3,
+}
+
+#[test]
+#[tracing::instrument]
+async fn aborted_saga_is_compensated_in_reverse_order(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("saga").store().await;
+    let coordinator = executor.start_worker(&component_id, "coordinator").await;
+    let participant = executor.start_worker(&component_id, "participant").await;
+
+    let result = run_saga(&executor, &coordinator, &["a", "b"], Outcome::Abort).await;
+    let log = wait_for_log(&executor, &participant, 4).await;
+
+    executor.check_oplog_is_queryable(&coordinator).await;
+
+    drop(executor);
+
+    assert_eq!(result, vec![Value::Result(Ok(None))]);
+    assert_eq!(
+        log,
+        vec!["reserve a", "reserve b", "release b", "release a"]
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn completed_saga_is_not_compensated(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("saga").store().await;
+    let coordinator = executor.start_worker(&component_id, "coordinator").await;
+    let participant = executor.start_worker(&component_id, "participant").await;
+
+    let result = run_saga(&executor, &coordinator, &["a", "b"], Outcome::Complete).await;
+    // Reverting the coordinator does not abort the sagas it finished
+    executor
+        .revert(
+            &coordinator,
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations: 1,
+            }),
+        )
+        .await;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let log = get_log(&executor, &participant).await;
+
+    drop(executor);
+
+    assert_eq!(result, vec![Value::Result(Ok(None))]);
+    assert_eq!(log, vec!["reserve a", "reserve b"]);
+}
+
+#[test]
+#[tracing::instrument]
+async fn sagas_of_failed_coordinator_are_compensated(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("saga").store().await;
+    let coordinator = executor.start_worker(&component_id, "coordinator").await;
+    let participant = executor.start_worker(&component_id, "participant").await;
+
+    let result = executor
+        .invoke_and_await(
+            &coordinator,
+            "golem:it/api.{run-saga}",
+            run_saga_params(&participant, &["a", "b"], Outcome::Fail),
+        )
+        .await;
+    let log = wait_for_log(&executor, &participant, 4).await;
+
+    drop(executor);
+
+    assert!(result.is_err());
+    assert_eq!(
+        log,
+        vec!["reserve a", "reserve b", "release b", "release a"]
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn sagas_started_in_reverted_region_are_compensated(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("saga").store().await;
+    let coordinator = executor.start_worker(&component_id, "coordinator").await;
+    let participant = executor.start_worker(&component_id, "participant").await;
+
+    let result = run_saga(&executor, &coordinator, &["a", "b"], Outcome::LeaveRunning).await;
+    executor
+        .revert(
+            &coordinator,
+            RevertWorkerTarget::RevertLastInvocations(RevertLastInvocations {
+                number_of_invocations: 1,
+            }),
+        )
+        .await;
+    let log = wait_for_log(&executor, &participant, 4).await;
+
+    executor.check_oplog_is_queryable(&coordinator).await;
+
+    drop(executor);
+
+    assert_eq!(result, vec![Value::Result(Ok(None))]);
+    assert_eq!(
+        log,
+        vec!["reserve a", "reserve b", "release b", "release a"]
+    );
+}
+
+#[test]
+#[tracing::instrument]
+async fn compensation_is_finished_once_after_restart(
+    last_unique_id: &LastUniqueId,
+    deps: &WorkerExecutorTestDependencies,
+    _tracing: &Tracing,
+) {
+    let context = TestContext::new(last_unique_id);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let component_id = executor.component("saga").store().await;
+    let coordinator = executor.start_worker(&component_id, "coordinator").await;
+    let participant = executor.start_worker(&component_id, "participant").await;
+
+    let items = ["a", "b", "c", "d", "e"];
+    let result = run_saga(&executor, &coordinator, &items, Outcome::Abort).await;
+
+    // Stopping the executor while it may still be compensating, the compensation is resumed on startup
+    drop(executor);
+    let executor = start(deps, &context).await.unwrap().into_admin().await;
+
+    let log = wait_for_log(&executor, &participant, 2 * items.len()).await;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    let final_log = get_log(&executor, &participant).await;
+
+    drop(executor);
+
+    assert_eq!(result, vec![Value::Result(Ok(None))]);
+    assert_eq!(
+        log,
+        vec![
+            "reserve a",
+            "reserve b",
+            "reserve c",
+            "reserve d",
+            "reserve e",
+            "release e",
+            "release d",
+            "release c",
+            "release b",
+            "release a"
+        ]
+    );
+    assert_eq!(final_log, log);
+}
+
+fn run_saga_params(participant: &WorkerId, items: &[&str], outcome: Outcome) -> Vec<ValueAndType> {
+    vec![
+        participant.worker_name.clone().into_value_and_type(),
+        items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>()
+            .into_value_and_type(),
+        ValueAndType::new(
+            Value::Enum(outcome as u32),
+            analysed_type::r#enum(&["complete", "abort", "fail", "leave-running"]),
+        ),
+    ]
+}
+
+async fn run_saga(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    coordinator: &WorkerId,
+    items: &[&str],
+    outcome: Outcome,
+) -> Vec<Value> {
+    let participant = WorkerId {
+        component_id: coordinator.component_id.clone(),
+        worker_name: "participant".to_string(),
+    };
+    executor
+        .invoke_and_await(
+            coordinator,
+            "golem:it/api.{run-saga}",
+            run_saga_params(&participant, items, outcome),
+        )
+        .await
+        .unwrap()
+}
+
+async fn get_log(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    worker_id: &WorkerId,
+) -> Vec<String> {
+    let result = executor
+        .invoke_and_await(worker_id, "golem:it/api.{get-log}", vec![])
+        .await
+        .unwrap();
+    match result.as_slice() {
+        [Value::List(entries)] => entries
+            .iter()
+            .map(|entry| match entry {
+                Value::String(entry) => entry.clone(),
+                _ => panic!("Unexpected log entry: {entry:?}"),
+            })
+            .collect(),
+        _ => panic!("Unexpected result of get-log: {result:?}"),
+    }
+}
+
+/// Compensations are performed in the background, so the participant's log is polled until it has
+/// the expected number of entries
+async fn wait_for_log(
+    executor: &TestDependenciesDsl<TestWorkerExecutor>,
+    worker_id: &WorkerId,
+    expected_entries: usize,
+) -> Vec<String> {
+    let started = Instant::now();
+    loop {
+        let log = get_log(executor, worker_id).await;
+        if log.len() >= expected_entries || started.elapsed() > Duration::from_secs(30) {
+            break log;
+        }
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+}
@@ -0,0 +1,28 @@
+CREATE TABLE bulk_operations
+(
+    namespace    text      NOT NULL,
+    id           UUID      NOT NULL,
+    component_id UUID      NOT NULL,
+    operation    text      NOT NULL,
+    filter       text,
+    concurrency  integer   NOT NULL,
+    status       text      NOT NULL,
+    total        bigint,
+    succeeded    bigint    NOT NULL DEFAULT 0,
+    failed       bigint    NOT NULL DEFAULT 0,
+    error        text,
+    created_at   timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    finished_at  timestamp,
+    heartbeat_at timestamp NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE INDEX bulk_operations_component_idx ON bulk_operations (namespace, component_id);
+
+CREATE TABLE bulk_operation_results
+(
+    operation_id UUID NOT NULL REFERENCES bulk_operations (id) ON DELETE CASCADE,
+    worker_name  text NOT NULL,
+    error        text,
+    PRIMARY KEY (operation_id, worker_name)
+);
@@ -21,7 +21,9 @@ use crate::service::component::ComponentServiceError;
 use crate::service::gateway::api_definition::ApiDefinitionError as BaseApiDefinitionError;
 use crate::service::gateway::api_deployment::ApiDeploymentError;
 use crate::service::gateway::security_scheme::SecuritySchemeServiceError as BaseSecuritySchemeServiceError;
-use crate::service::worker::{CallWorkerExecutorError, WorkerServiceError};
+use crate::service::worker::{
+    BulkOperationServiceError, CallWorkerExecutorError, WorkerServiceError,
+};
 use golem_api_grpc::proto::golem::project::v1::project_error::Error;
 use golem_common::metrics::api::TraceErrorKind;
 use golem_common::model::error::ErrorBody;
@@ -166,6 +168,17 @@ impl From<WorkerServiceError> for ApiEndpointError {
     }
 }
 
+impl From<BulkOperationServiceError> for ApiEndpointError {
+    fn from(error: BulkOperationServiceError) -> Self {
+        match error {
+            BulkOperationServiceError::NotFound(_) => Self::not_found(error),
+            BulkOperationServiceError::BadRequest(_) => Self::bad_request(error),
+            BulkOperationServiceError::InternalRepoError(_)
+            | BulkOperationServiceError::Internal(_) => Self::internal(error),
+        }
+    }
+}
+
 impl From<ComponentServiceError> for ApiEndpointError {
     fn from(error: ComponentServiceError) -> Self {
         match error {
@@ -204,7 +217,8 @@ impl From<CallWorkerExecutorError> for ApiEndpointError {
 impl From<WorkerExecutorError> for ApiEndpointError {
     fn from(error: WorkerExecutorError) -> Self {
         match error {
-            WorkerExecutorError::WorkerNotFound { .. } => Self::not_found(error),
+            WorkerExecutorError::WorkerNotFound { .. }
+            | WorkerExecutorError::InvocationNotFound { .. } => Self::not_found(error),
             WorkerExecutorError::InvocationFailed { error, stderr } => {
                 Self::InternalError(Json(ErrorBodyWithOptionalWorkerError {
                     error: "Invocation Failed".to_string(),
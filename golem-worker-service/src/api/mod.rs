@@ -51,6 +51,7 @@ pub fn make_open_api_service(services: &Services) -> OpenApiService<Apis, ()> {
                 services.component_service.clone(),
                 services.worker_service.clone(),
                 services.worker_auth_service.clone(),
+                services.bulk_operation_service.clone(),
             ),
             ApiDefinitionApi::new(
                 services.definition_service.clone(),
@@ -17,22 +17,40 @@ use crate::model;
 use crate::service::auth::AuthService;
 use crate::service::component::ComponentService;
 use crate::service::worker::{proxy_worker_connection, InvocationParameters};
-use crate::service::worker::{ConnectWorkerStream, WorkerService};
+use crate::service::worker::{BulkOperationService, ConnectWorkerStream, WorkerService};
 use futures::StreamExt;
 use futures::TryStreamExt;
 use golem_common::model::auth::AuthCtx;
 use golem_common::model::auth::{ProjectAction, TokenSecret};
 use golem_common::model::error::{ErrorBody, ErrorsBody};
-use golem_common::model::oplog::OplogIndex;
+use golem_common::model::oplog::{InvocationPriority, OplogIndex};
 use golem_common::model::public_oplog::OplogCursor;
 use golem_common::model::worker::WorkerCreationRequest;
 use golem_common::model::{
-    ComponentFilePath, ComponentId, IdempotencyKey, PluginInstallationId, ScanCursor,
-    TargetWorkerId, WorkerFilter, WorkerId,
+    ComponentFilePath, ComponentId, IdempotencyKey, InvocationScheduleId, PluginInstallationId,
+    SagaId, ScanCursor, TargetWorkerId, WorkerFilter, WorkerId,
 };
 use golem_common::recorded_http_api_request;
 use golem_service_base::api_tags::ApiTags;
 use golem_service_base::model::auth::{GolemSecurityScheme, WrappedGolemSecuritySchema};
+use golem_service_base::model::bulk_operation::{
+    BulkOperation, BulkOperationId, BulkOperationKind, BulkOperationReport, BulkOperationRequest,
+    BulkOperationsResponse,
+};
+use golem_service_base::model::invocation_result::InvocationResultResponse;
+use golem_service_base::model::invocation_schedule::{
+    CancelInvocationScheduleResponse, InvocationSchedule, InvocationSchedulesResponse,
+    ScheduleInvocationRequest,
+};
+use golem_service_base::model::pending_invocation::{
+    ChangeInvocationPriorityRequest, ChangeInvocationPriorityResponse, PendingInvocationsResponse,
+    PurgePendingInvocationsResponse,
+};
+use golem_service_base::model::pending_promise::PendingPromisesResponse;
+use golem_service_base::model::saga::{AbortSagaRequest, AbortSagaResponse, SagasResponse};
+use golem_service_base::model::worker_ttl::{
+    WorkerTtlDeletionsResponse, WorkerTtlPolicy, WorkerTtlPolicyResponse,
+};
 use golem_service_base::model::*;
 use poem::web::websocket::{BoxWebSocketUpgraded, WebSocket};
 use poem::Body;
@@ -54,6 +72,7 @@ pub struct WorkerApi {
     component_service: Arc<dyn ComponentService>,
     worker_service: Arc<dyn WorkerService>,
     worker_auth_service: Arc<dyn AuthService>,
+    bulk_operation_service: Arc<dyn BulkOperationService>,
 }
 
 #[OpenApi(prefix_path = "/v1/components", tag = ApiTags::Worker)]
@@ -62,11 +81,13 @@ impl WorkerApi {
         component_service: Arc<dyn ComponentService>,
         worker_service: Arc<dyn WorkerService>,
         auth_service: Arc<dyn AuthService>,
+        bulk_operation_service: Arc<dyn BulkOperationService>,
     ) -> Self {
         Self {
             component_service,
             worker_service,
             worker_auth_service: auth_service,
+            bulk_operation_service,
         }
     }
 
@@ -129,6 +150,7 @@ impl WorkerApi {
             args,
             env,
             wasi_config_vars,
+            labels,
         } = request;
 
         let worker_id = validated_worker_id(component_id, name)?;
@@ -146,6 +168,7 @@ impl WorkerApi {
                 args,
                 env,
                 wasi_config_vars.into(),
+                labels,
                 namespace,
             )
             .await?;
@@ -211,6 +234,8 @@ impl WorkerApi {
         worker_name: Path<String>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
         function: Query<String>,
+        /// priority of the invocation in the worker's queue of pending invocations, normal by default
+        priority: Query<Option<InvocationPriority>>,
         params: Json<InvokeParameters>,
         token: GolemSecurityScheme,
     ) -> Result<Json<InvokeResult>> {
@@ -228,6 +253,7 @@ impl WorkerApi {
                 worker_id.into_target_worker_id(),
                 idempotency_key.0,
                 function.0,
+                priority.0.unwrap_or_default(),
                 params.0,
                 token,
             )
@@ -242,6 +268,7 @@ impl WorkerApi {
         target_worker_id: TargetWorkerId,
         idempotency_key: Option<IdempotencyKey>,
         function: String,
+        priority: InvocationPriority,
         params: InvokeParameters,
         token: GolemSecurityScheme,
     ) -> Result<Json<InvokeResult>> {
@@ -268,6 +295,7 @@ impl WorkerApi {
                     function,
                     vals,
                     None,
+                    priority,
                     namespace,
                 )
             }
@@ -278,6 +306,7 @@ impl WorkerApi {
                     function,
                     jsons,
                     None,
+                    priority,
                     namespace,
                 )
             }
@@ -301,6 +330,8 @@ impl WorkerApi {
         component_id: Path<ComponentId>,
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
         function: Query<String>,
+        /// priority of the invocation in the worker's queue of pending invocations, normal by default
+        priority: Query<Option<InvocationPriority>>,
         params: Json<InvokeParameters>,
         token: GolemSecurityScheme,
     ) -> Result<Json<InvokeResult>> {
@@ -318,6 +349,7 @@ impl WorkerApi {
                 target_worker_id,
                 idempotency_key.0,
                 function.0,
+                priority.0.unwrap_or_default(),
                 params.0,
                 token,
             )
@@ -342,6 +374,8 @@ impl WorkerApi {
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
         /// name of the exported function to be invoked
         function: Query<String>,
+        /// priority of the invocation in the worker's queue of pending invocations, normal by default
+        priority: Query<Option<InvocationPriority>>,
         params: Json<InvokeParameters>,
         token: GolemSecurityScheme,
     ) -> Result<Json<InvokeResponse>> {
@@ -359,6 +393,7 @@ impl WorkerApi {
                 worker_id.into_target_worker_id(),
                 idempotency_key.0,
                 function.0,
+                priority.0.unwrap_or_default(),
                 params.0,
                 token,
             )
@@ -373,6 +408,7 @@ impl WorkerApi {
         target_worker_id: TargetWorkerId,
         idempotency_key: Option<IdempotencyKey>,
         function: String,
+        priority: InvocationPriority,
         params: InvokeParameters,
         token: GolemSecurityScheme,
     ) -> Result<Json<InvokeResponse>> {
@@ -397,6 +433,7 @@ impl WorkerApi {
                 function,
                 vals,
                 None,
+                priority,
                 namespace,
             ),
             InvocationParameters::RawJsonStrings(jsons) => self.worker_service.invoke_json(
@@ -405,6 +442,7 @@ impl WorkerApi {
                 function,
                 jsons,
                 None,
+                priority,
                 namespace,
             ),
         }
@@ -427,6 +465,8 @@ impl WorkerApi {
         #[oai(name = "Idempotency-Key")] idempotency_key: Header<Option<IdempotencyKey>>,
         /// name of the exported function to be invoked
         function: Query<String>,
+        /// priority of the invocation in the worker's queue of pending invocations, normal by default
+        priority: Query<Option<InvocationPriority>>,
         params: Json<InvokeParameters>,
         token: GolemSecurityScheme,
     ) -> Result<Json<InvokeResponse>> {
@@ -444,6 +484,7 @@ impl WorkerApi {
                 target_worker_id,
                 idempotency_key.0,
                 function.0,
+                priority.0.unwrap_or_default(),
                 params.0,
                 token,
             )
@@ -504,6 +545,52 @@ impl WorkerApi {
         Ok(Json(response))
     }
 
+    /// Get the pending promises of a worker
+    ///
+    /// Lists the promises created by the worker that have not been completed or deleted yet, oldest first.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/promises",
+        method = "get",
+        operation_id = "get_pending_promises"
+    )]
+    async fn get_pending_promises(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<PendingPromisesResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("get_pending_promises", worker_id = worker_id.to_string());
+
+        let response = self
+            .get_pending_promises_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_pending_promises_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<PendingPromisesResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let promises = self
+            .worker_service
+            .get_pending_promises(&worker_id, namespace)
+            .await?;
+
+        Ok(Json(PendingPromisesResponse { promises }))
+    }
+
     /// Interrupt a worker
     ///
     /// Interrupts the execution of a worker.
@@ -1164,6 +1251,54 @@ impl WorkerApi {
         Ok(Json(DeactivatePluginResponse {}))
     }
 
+    /// Update the labels of a worker
+    ///
+    /// Sets the given labels, overwriting existing values with the same key, and then removes
+    /// the labels listed in `remove`.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/labels",
+        method = "post",
+        operation_id = "update_worker_labels"
+    )]
+    async fn update_worker_labels(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<UpdateWorkerLabelsRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<UpdateWorkerLabelsResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("update_worker_labels", worker_id = worker_id.to_string());
+
+        let response = self
+            .update_worker_labels_internal(worker_id, request.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn update_worker_labels_internal(
+        &self,
+        worker_id: WorkerId,
+        request: UpdateWorkerLabelsRequest,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<UpdateWorkerLabelsResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        self.worker_service
+            .update_labels(&worker_id, request.set, request.remove, namespace)
+            .await?;
+
+        Ok(Json(UpdateWorkerLabelsResponse {}))
+    }
+
     /// Revert a worker
     ///
     /// Reverts a worker by undoing either the last few invocations or the last few recorded oplog entries.
@@ -1211,6 +1346,95 @@ impl WorkerApi {
         Ok(Json(RevertWorkerResponse {}))
     }
 
+    /// Fork a worker
+    ///
+    /// Creates a new worker in the same component, with a copy of the source worker's oplog up to the given
+    /// oplog index, or the whole oplog if no index is given. The new worker continues independently from the
+    /// source worker, which is not affected by the fork.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/fork",
+        method = "post",
+        operation_id = "fork_worker"
+    )]
+    async fn fork_worker(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        request: Json<ForkWorkerRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ForkWorkerResponse>> {
+        let source_worker_id = validated_worker_id(component_id.0.clone(), worker_name.0)?;
+        let target_worker_id =
+            validated_worker_id(component_id.0, request.0.target_worker_name.clone())?;
+
+        let record = recorded_http_api_request!(
+            "fork_worker",
+            source_worker_id = source_worker_id.to_string(),
+            target_worker_id = target_worker_id.to_string(),
+        );
+
+        let response = self
+            .fork_worker_internal(
+                source_worker_id,
+                target_worker_id,
+                request.0.oplog_index_cutoff,
+                token,
+            )
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn fork_worker_internal(
+        &self,
+        source_worker_id: WorkerId,
+        target_worker_id: WorkerId,
+        oplog_index_cutoff: Option<u64>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ForkWorkerResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(
+                &source_worker_id.component_id,
+                ProjectAction::UpdateWorker,
+                &auth,
+            )
+            .await?;
+
+        let oplog_index_cutoff = match oplog_index_cutoff {
+            Some(oplog_index_cutoff) => OplogIndex::from_u64(oplog_index_cutoff),
+            None => {
+                let oplog = self
+                    .worker_service
+                    .get_oplog(
+                        &source_worker_id,
+                        OplogIndex::INITIAL,
+                        None,
+                        1,
+                        namespace.clone(),
+                    )
+                    .await?;
+                OplogIndex::from_u64(oplog.last_index)
+            }
+        };
+
+        self.worker_service
+            .fork_worker(
+                &source_worker_id,
+                &target_worker_id,
+                oplog_index_cutoff,
+                namespace,
+            )
+            .await?;
+
+        Ok(Json(ForkWorkerResponse {
+            target_worker_id,
+            oplog_index_cutoff: oplog_index_cutoff.into(),
+        }))
+    }
+
     /// Cancels a pending invocation if it has not started yet
     ///
     /// The invocation to be cancelled is identified by the idempotency key passed to the invoke API.
@@ -1262,6 +1486,920 @@ impl WorkerApi {
         Ok(Json(CancelInvocationResponse { canceled }))
     }
 
+    /// Get the result of an invocation
+    ///
+    /// Looks up an invocation by the idempotency key passed to the invoke API, and returns whether it
+    /// is still pending, running, or has completed or failed, together with its result if available.
+    /// When `wait-timeout-ms` is set and the invocation has not finished yet, the request waits at most
+    /// that long (but no longer than 60 seconds) for it to finish. Unknown invocations are reported
+    /// as not found.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocations/:idempotency_key",
+        method = "get",
+        operation_id = "get_invocation_result"
+    )]
+    async fn get_invocation_result(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        idempotency_key: Path<IdempotencyKey>,
+        #[oai(name = "wait-timeout-ms")] wait_timeout_ms: Query<Option<u64>>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationResultResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_invocation_result",
+            worker_id = worker_id.to_string(),
+            idempotency_key = idempotency_key.0.to_string(),
+        );
+
+        let response = self
+            .get_invocation_result_internal(worker_id, idempotency_key.0, wait_timeout_ms.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_invocation_result_internal(
+        &self,
+        worker_id: WorkerId,
+        idempotency_key: IdempotencyKey,
+        wait_timeout_ms: Option<u64>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationResultResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let status = self
+            .worker_service
+            .get_invocation_result(&worker_id, &idempotency_key, wait_timeout_ms, namespace)
+            .await?;
+
+        Ok(Json(InvocationResultResponse::new(idempotency_key, status)))
+    }
+
+    /// Get the pending invocations of a worker
+    ///
+    /// Returns the invocations which are waiting in the worker's invocation queue, in the order they
+    /// are going to be processed. Invocations with higher priority are processed first, invocations
+    /// with the same priority are processed in the order they were enqueued.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocations",
+        method = "get",
+        operation_id = "get_pending_invocations"
+    )]
+    async fn get_pending_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<PendingInvocationsResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_pending_invocations",
+            worker_id = worker_id.to_string(),
+        );
+
+        let response = self
+            .get_pending_invocations_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_pending_invocations_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<PendingInvocationsResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let invocations = self
+            .worker_service
+            .get_pending_invocations(&worker_id, namespace)
+            .await?;
+
+        Ok(Json(PendingInvocationsResponse { invocations }))
+    }
+
+    /// Change the priority of a pending invocation
+    ///
+    /// Moves the invocation identified by the idempotency key passed to the invoke API behind all the
+    /// other pending invocations with the same or higher priority. Returns `changed: false` if the
+    /// invocation has already started.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocations/:idempotency_key/priority",
+        method = "put",
+        operation_id = "change_invocation_priority"
+    )]
+    async fn change_invocation_priority(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        idempotency_key: Path<IdempotencyKey>,
+        request: Json<ChangeInvocationPriorityRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ChangeInvocationPriorityResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "change_invocation_priority",
+            worker_id = worker_id.to_string(),
+            idempotency_key = idempotency_key.0.to_string(),
+            priority = request.0.priority.to_string(),
+        );
+
+        let response = self
+            .change_invocation_priority_internal(worker_id, idempotency_key.0, request.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn change_invocation_priority_internal(
+        &self,
+        worker_id: WorkerId,
+        idempotency_key: IdempotencyKey,
+        request: ChangeInvocationPriorityRequest,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<ChangeInvocationPriorityResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let changed = self
+            .worker_service
+            .change_invocation_priority(&worker_id, &idempotency_key, request.priority, namespace)
+            .await?;
+
+        Ok(Json(ChangeInvocationPriorityResponse { changed }))
+    }
+
+    /// Purge the pending invocations of a worker
+    ///
+    /// Cancels all the invocations waiting in the worker's invocation queue which have not started yet.
+    /// Pending manual update requests are kept.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/invocations",
+        method = "delete",
+        operation_id = "purge_pending_invocations"
+    )]
+    async fn purge_pending_invocations(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<PurgePendingInvocationsResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "purge_pending_invocations",
+            worker_id = worker_id.to_string(),
+        );
+
+        let response = self
+            .purge_pending_invocations_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn purge_pending_invocations_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<PurgePendingInvocationsResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let canceled = self
+            .worker_service
+            .purge_pending_invocations(&worker_id, namespace)
+            .await?;
+
+        Ok(Json(PurgePendingInvocationsResponse { canceled }))
+    }
+
+    /// Get the sagas coordinated by a worker
+    ///
+    /// Returns all the sagas started by the worker through the saga host API, oldest first, together with
+    /// their registered compensations and the progress of their compensation.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/sagas",
+        method = "get",
+        operation_id = "get_sagas"
+    )]
+    async fn get_sagas(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<SagasResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!("get_sagas", worker_id = worker_id.to_string());
+
+        let response = self
+            .get_sagas_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_sagas_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<SagasResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let sagas = self.worker_service.get_sagas(&worker_id, namespace).await?;
+
+        Ok(Json(SagasResponse { sagas }))
+    }
+
+    /// Abort a saga coordinated by a worker
+    ///
+    /// Aborts a running saga, and performs its registered compensations in the background in reverse
+    /// order. Aborting a saga whose compensation failed retries the compensation from the failed step.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/sagas/:saga_id/abort",
+        method = "post",
+        operation_id = "abort_saga"
+    )]
+    async fn abort_saga(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        saga_id: Path<SagaId>,
+        request: Json<AbortSagaRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<AbortSagaResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "abort_saga",
+            worker_id = worker_id.to_string(),
+            saga_id = saga_id.0.to_string(),
+        );
+
+        let response = self
+            .abort_saga_internal(worker_id, saga_id.0, request.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn abort_saga_internal(
+        &self,
+        worker_id: WorkerId,
+        saga_id: SagaId,
+        request: AbortSagaRequest,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<AbortSagaResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let saga = self
+            .worker_service
+            .abort_saga(&worker_id, &saga_id, request.reason, namespace)
+            .await?;
+
+        Ok(Json(AbortSagaResponse { saga }))
+    }
+
+    /// Schedule an invocation
+    ///
+    /// Schedules an invocation of a worker function, either once at a given point in time,
+    /// or repeatedly at every point in time matching a cron expression (evaluated in UTC).
+    /// Every occurrence is enqueued the same way as invocations made through the invoke endpoint.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules",
+        method = "post",
+        operation_id = "schedule_invocation"
+    )]
+    async fn schedule_invocation(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        /// name of the exported function to be invoked
+        function: Query<String>,
+        request: Json<ScheduleInvocationRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationSchedule>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "schedule_invocation",
+            worker_id = worker_id.to_string(),
+            function = function.0
+        );
+
+        let response = self
+            .schedule_invocation_internal(worker_id, function.0, request.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn schedule_invocation_internal(
+        &self,
+        worker_id: WorkerId,
+        function: String,
+        request: ScheduleInvocationRequest,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationSchedule>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let params =
+            InvocationParameters::from_optionally_type_annotated_value_jsons(request.params)
+                .map_err(|errors| ApiEndpointError::BadRequest(Json(ErrorsBody { errors })))?;
+
+        let schedule = match params {
+            InvocationParameters::TypedProtoVals(vals) => {
+                self.worker_service
+                    .validate_and_schedule_invocation(
+                        &worker_id,
+                        function,
+                        vals,
+                        request.schedule,
+                        None,
+                        namespace,
+                    )
+                    .await?
+            }
+            InvocationParameters::RawJsonStrings(jsons) => {
+                self.worker_service
+                    .schedule_invocation_json(
+                        &worker_id,
+                        function,
+                        jsons,
+                        request.schedule,
+                        None,
+                        namespace,
+                    )
+                    .await?
+            }
+        };
+
+        Ok(Json(schedule))
+    }
+
+    /// Get the invocation schedules of a worker
+    ///
+    /// Lists the one-shot schedules that have not been performed yet, and all recurring schedules.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules",
+        method = "get",
+        operation_id = "get_invocation_schedules"
+    )]
+    async fn get_invocation_schedules(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationSchedulesResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "get_invocation_schedules",
+            worker_id = worker_id.to_string()
+        );
+
+        let response = self
+            .get_invocation_schedules_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_invocation_schedules_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<InvocationSchedulesResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let schedules = self
+            .worker_service
+            .get_invocation_schedules(&worker_id, namespace)
+            .await?;
+
+        Ok(Json(InvocationSchedulesResponse { schedules }))
+    }
+
+    /// Cancel an invocation schedule
+    ///
+    /// No further invocations are made by the schedule. Invocations that are already enqueued are not affected.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/schedules/:schedule_id",
+        method = "delete",
+        operation_id = "cancel_invocation_schedule"
+    )]
+    async fn cancel_invocation_schedule(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        schedule_id: Path<InvocationScheduleId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<CancelInvocationScheduleResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "cancel_invocation_schedule",
+            worker_id = worker_id.to_string(),
+            schedule_id = schedule_id.0.to_string(),
+        );
+
+        let response = self
+            .cancel_invocation_schedule_internal(worker_id, schedule_id.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn cancel_invocation_schedule_internal(
+        &self,
+        worker_id: WorkerId,
+        schedule_id: InvocationScheduleId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<CancelInvocationScheduleResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let canceled = self
+            .worker_service
+            .cancel_invocation_schedule(&worker_id, &schedule_id, namespace)
+            .await?;
+
+        Ok(Json(CancelInvocationScheduleResponse { canceled }))
+    }
+
+    /// Set the time-to-live policy of a component's workers
+    ///
+    /// Workers of the component that are idle or have exited get deleted together with their oplog once they violate the policy.
+    /// The policy applies to workers the next time they become idle or exit. Policies set on individual workers take precedence.
+    #[oai(
+        path = "/:component_id/ttl-policy",
+        method = "put",
+        operation_id = "set_component_ttl_policy"
+    )]
+    async fn set_component_ttl_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        policy: Json<WorkerTtlPolicy>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let record = recorded_http_api_request!(
+            "set_component_ttl_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .set_component_ttl_policy_internal(component_id.0, Some(policy.0), token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    /// Get the time-to-live policy of a component's workers
+    #[oai(
+        path = "/:component_id/ttl-policy",
+        method = "get",
+        operation_id = "get_component_ttl_policy"
+    )]
+    async fn get_component_ttl_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let record = recorded_http_api_request!(
+            "get_component_ttl_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .get_component_ttl_policy_internal(component_id.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_component_ttl_policy_internal(
+        &self,
+        component_id: ComponentId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let policy = self
+            .worker_service
+            .get_component_ttl_policy(&component_id, namespace)
+            .await?;
+
+        Ok(Json(WorkerTtlPolicyResponse { policy }))
+    }
+
+    /// Remove the time-to-live policy of a component's workers
+    #[oai(
+        path = "/:component_id/ttl-policy",
+        method = "delete",
+        operation_id = "delete_component_ttl_policy"
+    )]
+    async fn delete_component_ttl_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let record = recorded_http_api_request!(
+            "delete_component_ttl_policy",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .set_component_ttl_policy_internal(component_id.0, None, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn set_component_ttl_policy_internal(
+        &self,
+        component_id: ComponentId,
+        policy: Option<WorkerTtlPolicy>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        self.worker_service
+            .set_component_ttl_policy(&component_id, policy.clone(), namespace)
+            .await?;
+
+        Ok(Json(WorkerTtlPolicyResponse { policy }))
+    }
+
+    /// Set the time-to-live policy of a worker
+    ///
+    /// Overrides the policy of the worker's component. The worker gets deleted together with its oplog once it is idle or has exited and violates the policy.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/ttl-policy",
+        method = "put",
+        operation_id = "set_worker_ttl_policy"
+    )]
+    async fn set_worker_ttl_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        policy: Json<WorkerTtlPolicy>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("set_worker_ttl_policy", worker_id = worker_id.to_string());
+
+        let response = self
+            .set_worker_ttl_policy_internal(worker_id, Some(policy.0), token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    /// Get the time-to-live policy of a worker
+    ///
+    /// Only returns the policy set on the worker itself, not the one inherited from its component.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/ttl-policy",
+        method = "get",
+        operation_id = "get_worker_ttl_policy"
+    )]
+    async fn get_worker_ttl_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record =
+            recorded_http_api_request!("get_worker_ttl_policy", worker_id = worker_id.to_string());
+
+        let response = self
+            .get_worker_ttl_policy_internal(worker_id, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_worker_ttl_policy_internal(
+        &self,
+        worker_id: WorkerId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let policy = self
+            .worker_service
+            .get_worker_ttl_policy(&worker_id, namespace)
+            .await?;
+
+        Ok(Json(WorkerTtlPolicyResponse { policy }))
+    }
+
+    /// Remove the time-to-live policy of a worker
+    ///
+    /// The worker falls back to the policy of its component, if any.
+    #[oai(
+        path = "/:component_id/workers/:worker_name/ttl-policy",
+        method = "delete",
+        operation_id = "delete_worker_ttl_policy"
+    )]
+    async fn delete_worker_ttl_policy(
+        &self,
+        component_id: Path<ComponentId>,
+        worker_name: Path<String>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let worker_id = validated_worker_id(component_id.0, worker_name.0)?;
+
+        let record = recorded_http_api_request!(
+            "delete_worker_ttl_policy",
+            worker_id = worker_id.to_string()
+        );
+
+        let response = self
+            .set_worker_ttl_policy_internal(worker_id, None, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn set_worker_ttl_policy_internal(
+        &self,
+        worker_id: WorkerId,
+        policy: Option<WorkerTtlPolicy>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlPolicyResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        self.worker_service
+            .set_worker_ttl_policy(&worker_id, policy.clone(), namespace)
+            .await?;
+
+        Ok(Json(WorkerTtlPolicyResponse { policy }))
+    }
+
+    /// Get the workers of a component deleted by their time-to-live policy
+    ///
+    /// Returns the audit log of deletions, latest first.
+    #[oai(
+        path = "/:component_id/ttl-deletions",
+        method = "get",
+        operation_id = "get_worker_ttl_deletions"
+    )]
+    async fn get_worker_ttl_deletions(
+        &self,
+        component_id: Path<ComponentId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlDeletionsResponse>> {
+        let record = recorded_http_api_request!(
+            "get_worker_ttl_deletions",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .get_worker_ttl_deletions_internal(component_id.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_worker_ttl_deletions_internal(
+        &self,
+        component_id: ComponentId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<WorkerTtlDeletionsResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let deletions = self
+            .worker_service
+            .get_ttl_deletions(&component_id, namespace)
+            .await?;
+
+        Ok(Json(WorkerTtlDeletionsResponse { deletions }))
+    }
+
+    /// Start a bulk operation on the workers of a component
+    ///
+    /// Performs an update, interrupt, resume, delete or invoke operation on every worker of the component matching the filter.
+    /// The operation runs in the background; its progress and the per-worker results can be queried using the returned operation id.
+    #[oai(
+        path = "/:component_id/bulk-operations",
+        method = "post",
+        operation_id = "start_bulk_operation"
+    )]
+    async fn start_bulk_operation(
+        &self,
+        component_id: Path<ComponentId>,
+        request: Json<BulkOperationRequest>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperation>> {
+        let record = recorded_http_api_request!(
+            "start_bulk_operation",
+            component_id = component_id.0.to_string(),
+            operation = request.0.operation.name()
+        );
+
+        let response = self
+            .start_bulk_operation_internal(component_id.0, request.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn start_bulk_operation_internal(
+        &self,
+        component_id: ComponentId,
+        request: BulkOperationRequest,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperation>> {
+        let action = match &request.operation {
+            BulkOperationKind::Delete(_) => ProjectAction::DeleteWorker,
+            _ => ProjectAction::UpdateWorker,
+        };
+
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&component_id, action, &auth)
+            .await?;
+
+        let operation = self
+            .bulk_operation_service
+            .start(&component_id, request, namespace)
+            .await?;
+
+        Ok(Json(operation))
+    }
+
+    /// Get the bulk operations of a component
+    #[oai(
+        path = "/:component_id/bulk-operations",
+        method = "get",
+        operation_id = "get_bulk_operations"
+    )]
+    async fn get_bulk_operations(
+        &self,
+        component_id: Path<ComponentId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperationsResponse>> {
+        let record = recorded_http_api_request!(
+            "get_bulk_operations",
+            component_id = component_id.0.to_string()
+        );
+
+        let response = self
+            .get_bulk_operations_internal(component_id.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_bulk_operations_internal(
+        &self,
+        component_id: ComponentId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperationsResponse>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let operations = self
+            .bulk_operation_service
+            .get_all(&component_id, namespace)
+            .await?;
+
+        Ok(Json(BulkOperationsResponse { operations }))
+    }
+
+    /// Get the progress and the per-worker results of a bulk operation
+    #[oai(
+        path = "/:component_id/bulk-operations/:operation_id",
+        method = "get",
+        operation_id = "get_bulk_operation"
+    )]
+    async fn get_bulk_operation(
+        &self,
+        component_id: Path<ComponentId>,
+        operation_id: Path<BulkOperationId>,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperationReport>> {
+        let record = recorded_http_api_request!(
+            "get_bulk_operation",
+            component_id = component_id.0.to_string(),
+            operation_id = operation_id.0.to_string()
+        );
+
+        let response = self
+            .get_bulk_operation_internal(component_id.0, operation_id.0, token)
+            .instrument(record.span.clone())
+            .await;
+
+        record.result(response)
+    }
+
+    async fn get_bulk_operation_internal(
+        &self,
+        component_id: ComponentId,
+        operation_id: BulkOperationId,
+        token: GolemSecurityScheme,
+    ) -> Result<Json<BulkOperationReport>> {
+        let auth = AuthCtx::new(token.secret());
+        let namespace = self
+            .worker_auth_service
+            .is_authorized_by_component(&component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let report = self
+            .bulk_operation_service
+            .get(&component_id, &operation_id, namespace)
+            .await?;
+
+        Ok(Json(report))
+    }
+
     /// Connect to a worker using a websocket and stream events
     #[oai(
         path = "/:component_id/workers/:worker_name/connect",
@@ -239,12 +239,17 @@ impl DefaultGatewayInputExecutor {
         request: &mut RichRequest,
     ) -> GatewayHttpResult<String> {
         let WorkerNameCompiled {
+            worker_name,
             compiled_worker_name,
             rib_input_type_info,
-            ..
         } = script;
 
-        let rib_input: RibInput = resolve_rib_input(request, &rib_input_type_info).await?;
+        let rib_input: RibInput = resolve_rib_input(
+            request,
+            &rib_input_type_info,
+            &worker_name.json_typed_inputs(),
+        )
+        .await?;
 
         let result = rib::interpret_pure(compiled_worker_name, rib_input, None)
             .await
@@ -264,12 +269,13 @@ impl DefaultGatewayInputExecutor {
         request: &mut RichRequest,
     ) -> GatewayHttpResult<IdempotencyKey> {
         let IdempotencyKeyCompiled {
+            idempotency_key,
             compiled_idempotency_key,
             rib_input,
-            ..
         } = script;
 
-        let rib_input: RibInput = resolve_rib_input(request, &rib_input).await?;
+        let rib_input: RibInput =
+            resolve_rib_input(request, &rib_input, &idempotency_key.json_typed_inputs()).await?;
 
         let value = rib::interpret_pure(compiled_idempotency_key, rib_input, None)
             .await
@@ -289,12 +295,13 @@ impl DefaultGatewayInputExecutor {
         request: &mut RichRequest,
     ) -> GatewayHttpResult<(Option<TraceId>, HashMap<String, ValueAndType>)> {
         let InvocationContextCompiled {
+            invocation_context,
             compiled_invocation_context,
             rib_input,
-            ..
         } = script;
 
-        let rib_input: RibInput = resolve_rib_input(request, &rib_input).await?;
+        let rib_input: RibInput =
+            resolve_rib_input(request, &rib_input, &invocation_context.json_typed_inputs()).await?;
 
         let value = rib::interpret_pure(compiled_invocation_context, rib_input, None)
             .await
@@ -456,12 +463,18 @@ impl DefaultGatewayInputExecutor {
         } = worker_detail;
 
         let ResponseMappingCompiled {
+            response_mapping_expr,
             response_mapping_compiled,
             rib_input,
             ..
         } = compiled_response_mapping;
 
-        let rib_input = resolve_rib_input(request, &rib_input).await?;
+        let rib_input = resolve_rib_input(
+            request,
+            &rib_input,
+            &response_mapping_expr.json_typed_inputs(),
+        )
+        .await?;
 
         self.evaluator
             .evaluate(
@@ -672,9 +685,35 @@ impl GatewayHttpInputExecutor for DefaultGatewayInputExecutor {
     }
 }
 
+fn json_inputs_as_text(
+    value: &serde_json::Value,
+    path: &str,
+    json_inputs: &[String],
+) -> serde_json::Value {
+    match value {
+        serde_json::Value::String(_) => value.clone(),
+        _ if json_inputs.iter().any(|input| input == path) => {
+            serde_json::Value::String(value.to_string())
+        }
+        serde_json::Value::Object(fields) => serde_json::Value::Object(
+            fields
+                .iter()
+                .map(|(name, field)| {
+                    (
+                        name.clone(),
+                        json_inputs_as_text(field, &format!("{path}.{name}"), json_inputs),
+                    )
+                })
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
 async fn resolve_rib_input(
     rich_request: &mut RichRequest,
     required_types: &RibInputTypeInfo,
+    json_inputs: &[String],
 ) -> Result<RibInput, GatewayHttpError> {
     let mut values: Vec<golem_wasm_rpc::Value> = vec![];
     let mut types: Vec<NameTypePair> = vec![];
@@ -699,8 +738,13 @@ async fn resolve_rib_input(
                             ))
                         })?;
 
-                        let body_value =
-                            ValueAndType::parse_with_type(body, &record.typ).map_err(|err| {
+                        // Parts of the body declared as `json` (such as `request.body` or
+                        // `request.body.user`) are strings holding json text at the WIT level,
+                        // so any json document given for them is passed through as json text
+                        let body = json_inputs_as_text(body, "request.body", json_inputs);
+
+                        let body_value = ValueAndType::parse_with_type(&body, &record.typ)
+                            .map_err(|err| {
                                 GatewayHttpError::BadRequest(format!(
                                     "invalid http request body\n{}\nexpected request body: {}",
                                     err.join("\n"),
@@ -708,9 +752,10 @@ async fn resolve_rib_input(
                                         .map(|x| x.to_string())
                                         .unwrap_or_else(|_| format!("{:?}", &record.typ))
                                 ))
-                            })?;
+                            })?
+                            .value;
 
-                        values.push(body_value.value);
+                        values.push(body_value);
                     }
                     "headers" | "header" => {
                         let header_values = get_wasm_rpc_value_for_primitives(
@@ -15,6 +15,7 @@
 use crate::gateway_execution::GatewayResolvedWorkerRequest;
 use crate::service::worker::WorkerService;
 use async_trait::async_trait;
+use golem_common::model::oplog::InvocationPriority;
 use golem_common::model::{TargetWorkerId, WorkerId};
 use golem_wasm_rpc::ValueAndType;
 use std::collections::BTreeMap;
@@ -106,6 +107,7 @@ impl GatewayWorkerRequestExecutor for GatewayWorkerRequestExecutorDefault {
                     wasi_config_vars: Some(BTreeMap::new().into()),
                     tracing: Some(resolved_worker_request.invocation_context.into()),
                 }),
+                InvocationPriority::Normal,
                 resolved_worker_request.namespace,
             )
             .await
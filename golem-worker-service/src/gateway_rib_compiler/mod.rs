@@ -14,7 +14,7 @@
 
 use rib::{
     CompilerOutput, ComponentDependency, Expr, GlobalVariableTypeSpec, InferredType, Path,
-    RibCompilationError, RibCompiler, RibCompilerConfig,
+    RibByteCodeOptimiser, RibCompilationError, RibCompiler, RibCompilerConfig,
 };
 
 // A wrapper service over original Rib Compiler concerning
@@ -60,6 +60,13 @@ impl WorkerServiceRibCompiler for DefaultWorkerServiceRibCompiler {
 
         let compiler = RibCompiler::new(compiler_config);
 
-        compiler.compile(rib.clone())
+        let mut compiler_output = compiler.compile(rib.clone())?;
+
+        // Compiled bindings are evaluated on every request hitting the gateway,
+        // so it's worth spending a bit more time here
+        compiler_output.byte_code =
+            RibByteCodeOptimiser::default().optimise(compiler_output.byte_code);
+
+        Ok(compiler_output)
     }
 }
@@ -261,6 +261,10 @@ pub fn error_to_status(error: WorkerError) -> Status {
                 worker_execution_error::Error::InvocationFailed(_) => {
                     "Invocation Failed".to_string()
                 }
+                worker_execution_error::Error::InvocationNotFound(err) => format!(
+                    "Invocation Not Found: Worker ID = {:?}, Idempotency Key = {:?}",
+                    err.worker_id, err.idempotency_key
+                ),
             };
             Status::internal(message)
         }
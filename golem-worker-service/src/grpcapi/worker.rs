@@ -27,30 +27,38 @@ use futures::StreamExt;
 use golem_api_grpc::proto::golem::common::{Empty, ErrorBody};
 use golem_api_grpc::proto::golem::worker::v1::worker_service_server::WorkerService as GrpcWorkerService;
 use golem_api_grpc::proto::golem::worker::v1::{
-    activate_plugin_response, cancel_invocation_response, complete_promise_response,
-    deactivate_plugin_response, delete_worker_response, fork_worker_response,
-    get_file_system_node_response, get_oplog_response, get_worker_metadata_response,
+    activate_plugin_response, cancel_invocation_response, cancel_invocation_schedule_response,
+    complete_promise_response, deactivate_plugin_response, delete_worker_response,
+    fork_worker_response, get_file_system_node_response, get_invocation_result_response,
+    get_invocation_schedules_response, get_oplog_response, get_worker_metadata_response,
     get_workers_metadata_response, interrupt_worker_response, invoke_and_await_json_response,
     invoke_and_await_response, invoke_and_await_typed_response, invoke_response,
     launch_new_worker_response, resume_worker_response, revert_worker_response,
-    search_oplog_response, update_worker_response, worker_error, worker_execution_error,
-    ActivatePluginRequest, ActivatePluginResponse, CancelInvocationRequest,
-    CancelInvocationResponse, CompletePromiseRequest, CompletePromiseResponse,
-    ConnectWorkerRequest, DeactivatePluginRequest, DeactivatePluginResponse, DeleteWorkerRequest,
-    DeleteWorkerResponse, ForkWorkerRequest, ForkWorkerResponse, GetFileContentsRequest,
-    GetFileContentsResponse, GetFileSystemNodeRequest, GetFileSystemNodeResponse, GetOplogRequest,
-    GetOplogResponse, GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
+    schedule_invocation_response, search_oplog_response, update_worker_labels_response,
+    update_worker_response, worker_error, worker_execution_error, ActivatePluginRequest,
+    ActivatePluginResponse, CancelInvocationRequest, CancelInvocationResponse,
+    CancelInvocationScheduleRequest, CancelInvocationScheduleResponse, CompletePromiseRequest,
+    CompletePromiseResponse, ConnectWorkerRequest, DeactivatePluginRequest,
+    DeactivatePluginResponse, DeleteWorkerRequest, DeleteWorkerResponse, ForkWorkerRequest,
+    ForkWorkerResponse, GetFileContentsRequest, GetFileContentsResponse, GetFileSystemNodeRequest,
+    GetFileSystemNodeResponse, GetInvocationResultRequest, GetInvocationResultResponse,
+    GetInvocationSchedulesRequest, GetInvocationSchedulesResponse,
+    GetInvocationSchedulesSuccessResponse, GetOplogRequest, GetOplogResponse,
+    GetOplogSuccessResponse, GetWorkerMetadataRequest, GetWorkerMetadataResponse,
     GetWorkersMetadataRequest, GetWorkersMetadataResponse, GetWorkersMetadataSuccessResponse,
-    InterruptWorkerRequest, InterruptWorkerResponse, InvokeAndAwaitJsonRequest,
-    InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest, InvokeAndAwaitResponse,
-    InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest, InvokeResponse,
-    LaunchNewWorkerRequest, LaunchNewWorkerResponse, LaunchNewWorkerSuccessResponse,
-    ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest, RevertWorkerResponse,
+    InterruptWorkerRequest, InterruptWorkerResponse, InvocationResultStatus,
+    InvokeAndAwaitJsonRequest, InvokeAndAwaitJsonResponse, InvokeAndAwaitRequest,
+    InvokeAndAwaitResponse, InvokeAndAwaitTypedResponse, InvokeJsonRequest, InvokeRequest,
+    InvokeResponse, LaunchNewWorkerRequest, LaunchNewWorkerResponse,
+    LaunchNewWorkerSuccessResponse, ResumeWorkerRequest, ResumeWorkerResponse, RevertWorkerRequest,
+    RevertWorkerResponse, ScheduleInvocationRequest, ScheduleInvocationResponse,
     SearchOplogRequest, SearchOplogResponse, SearchOplogSuccessResponse, UnknownError,
-    UpdateWorkerRequest, UpdateWorkerResponse, WorkerError as GrpcWorkerError,
-    WorkerExecutionError,
+    UpdateWorkerLabelsRequest, UpdateWorkerLabelsResponse, UpdateWorkerRequest,
+    UpdateWorkerResponse, WorkerError as GrpcWorkerError, WorkerExecutionError,
+};
+use golem_api_grpc::proto::golem::worker::{
+    InvocationSchedule, InvokeResult, InvokeResultTyped, WorkerMetadata,
 };
-use golem_api_grpc::proto::golem::worker::{InvokeResult, InvokeResultTyped, WorkerMetadata};
 use golem_common::grpc::{
     proto_component_id_string, proto_idempotency_key_string,
     proto_invocation_context_parent_worker_id_string, proto_plugin_installation_id_string,
@@ -58,7 +66,7 @@ use golem_common::grpc::{
 };
 use golem_common::model::auth::AuthCtx;
 use golem_common::model::auth::ProjectAction;
-use golem_common::model::oplog::OplogIndex;
+use golem_common::model::oplog::{InvocationPriority, OplogIndex};
 use golem_common::model::{ComponentVersion, ScanCursor, WorkerFilter, WorkerId};
 use golem_common::recorded_grpc_api_request;
 use golem_service_base::clients::get_authorisation_token;
@@ -645,6 +653,33 @@ impl GrpcWorkerService for WorkerGrpcApi {
         }))
     }
 
+    async fn update_worker_labels(
+        &self,
+        request: Request<UpdateWorkerLabelsRequest>,
+    ) -> Result<Response<UpdateWorkerLabelsResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "update_worker_labels",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .update_worker_labels(request, metadata)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(()) => record.succeed(update_worker_labels_response::Result::Success(Empty {})),
+            Err(error) => record.fail(
+                update_worker_labels_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(UpdateWorkerLabelsResponse {
+            result: Some(response),
+        }))
+    }
+
     async fn fork_worker(
         &self,
         request: Request<ForkWorkerRequest>,
@@ -726,6 +761,120 @@ impl GrpcWorkerService for WorkerGrpcApi {
             result: Some(response),
         }))
     }
+
+    async fn get_invocation_result(
+        &self,
+        request: Request<GetInvocationResultRequest>,
+    ) -> Result<Response<GetInvocationResultResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "get_invocation_result",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            idempotency_key = proto_idempotency_key_string(&request.idempotency_key),
+        );
+
+        let response = match self
+            .get_invocation_result(request, metadata)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(result) => record.succeed(get_invocation_result_response::Result::Success(result)),
+            Err(error) => record.fail(
+                get_invocation_result_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(GetInvocationResultResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn schedule_invocation(
+        &self,
+        request: Request<ScheduleInvocationRequest>,
+    ) -> Result<Response<ScheduleInvocationResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "schedule_invocation",
+            worker_id = proto_worker_id_string(&request.worker_id),
+            function = request.function,
+        );
+
+        let response = match self
+            .schedule_invocation(request, metadata)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(schedule) => record.succeed(schedule_invocation_response::Result::Success(schedule)),
+            Err(error) => record.fail(
+                schedule_invocation_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(ScheduleInvocationResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn get_invocation_schedules(
+        &self,
+        request: Request<GetInvocationSchedulesRequest>,
+    ) -> Result<Response<GetInvocationSchedulesResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "get_invocation_schedules",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .get_invocation_schedules(request, metadata)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(schedules) => record.succeed(get_invocation_schedules_response::Result::Success(
+                GetInvocationSchedulesSuccessResponse { schedules },
+            )),
+            Err(error) => record.fail(
+                get_invocation_schedules_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(GetInvocationSchedulesResponse {
+            result: Some(response),
+        }))
+    }
+
+    async fn cancel_invocation_schedule(
+        &self,
+        request: Request<CancelInvocationScheduleRequest>,
+    ) -> Result<Response<CancelInvocationScheduleResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let record = recorded_grpc_api_request!(
+            "cancel_invocation_schedule",
+            worker_id = proto_worker_id_string(&request.worker_id),
+        );
+
+        let response = match self
+            .cancel_invocation_schedule(request, metadata)
+            .instrument(record.span.clone())
+            .await
+        {
+            Ok(result) => {
+                record.succeed(cancel_invocation_schedule_response::Result::Success(result))
+            }
+            Err(error) => record.fail(
+                cancel_invocation_schedule_response::Result::Error(error.clone()),
+                &WorkerTraceErrorKind(&error),
+            ),
+        };
+
+        Ok(Response::new(CancelInvocationScheduleResponse {
+            result: Some(response),
+        }))
+    }
 }
 
 impl WorkerGrpcApi {
@@ -793,6 +942,7 @@ impl WorkerGrpcApi {
                 request.args,
                 request.env,
                 wasi_config_vars,
+                request.labels,
                 namespace,
             )
             .await?;
@@ -945,6 +1095,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                InvocationPriority::Normal,
                 namespace,
             )
             .await?;
@@ -983,6 +1134,7 @@ impl WorkerGrpcApi {
                         request.function,
                         params,
                         request.context,
+                        InvocationPriority::Normal,
                         namespace,
                     )
                     .await?
@@ -995,6 +1147,7 @@ impl WorkerGrpcApi {
                         request.function,
                         jsons,
                         request.context,
+                        InvocationPriority::Normal,
                         namespace,
                     )
                     .await?
@@ -1028,6 +1181,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                InvocationPriority::Normal,
                 namespace,
             )
             .await?;
@@ -1065,6 +1219,7 @@ impl WorkerGrpcApi {
                         request.function,
                         params,
                         request.context,
+                        InvocationPriority::Normal,
                         namespace,
                     )
                     .await?
@@ -1077,6 +1232,7 @@ impl WorkerGrpcApi {
                         request.function,
                         jsons,
                         request.context,
+                        InvocationPriority::Normal,
                         namespace,
                     )
                     .await?
@@ -1122,6 +1278,7 @@ impl WorkerGrpcApi {
                 request.function,
                 params.params,
                 request.context,
+                InvocationPriority::Normal,
                 namespace,
             )
             .await?;
@@ -1402,6 +1559,27 @@ impl WorkerGrpcApi {
         Ok(())
     }
 
+    async fn update_worker_labels(
+        &self,
+        request: UpdateWorkerLabelsRequest,
+        metadata: MetadataMap,
+    ) -> Result<(), GrpcWorkerError> {
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let auth = self.auth(metadata)?;
+
+        let namespace = self
+            .auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        self.worker_service
+            .update_labels(&worker_id, request.set, request.remove, namespace)
+            .await?;
+
+        Ok(())
+    }
+
     async fn fork_worker(
         &self,
         request: ForkWorkerRequest,
@@ -1480,4 +1658,123 @@ impl WorkerGrpcApi {
 
         Ok(result)
     }
+
+    async fn get_invocation_result(
+        &self,
+        request: GetInvocationResultRequest,
+        metadata: MetadataMap,
+    ) -> Result<InvocationResultStatus, GrpcWorkerError> {
+        let auth = self.auth(metadata)?;
+
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let idempotency_key = request
+            .idempotency_key
+            .ok_or_else(|| bad_request_error("Missing idempotency key"))?
+            .into();
+
+        let namespace = self
+            .auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let result = self
+            .worker_service
+            .get_invocation_result(
+                &worker_id,
+                &idempotency_key,
+                request.wait_timeout_millis,
+                namespace,
+            )
+            .await?;
+
+        Ok(result.into())
+    }
+
+    async fn schedule_invocation(
+        &self,
+        request: ScheduleInvocationRequest,
+        metadata: MetadataMap,
+    ) -> Result<InvocationSchedule, GrpcWorkerError> {
+        let auth = self.auth(metadata)?;
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let params = request
+            .invoke_parameters
+            .ok_or_else(|| bad_request_error("Missing invoke parameters"))?;
+        let schedule = request
+            .schedule
+            .ok_or_else(|| bad_request_error("Missing schedule"))?
+            .try_into()
+            .map_err(bad_request_error)?;
+
+        let namespace = self
+            .auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let schedule = self
+            .worker_service
+            .schedule_invocation(
+                &worker_id,
+                request.function,
+                params.params,
+                schedule,
+                request.context,
+                namespace,
+            )
+            .await?;
+
+        Ok(schedule.into())
+    }
+
+    async fn get_invocation_schedules(
+        &self,
+        request: GetInvocationSchedulesRequest,
+        metadata: MetadataMap,
+    ) -> Result<Vec<InvocationSchedule>, GrpcWorkerError> {
+        let auth = self.auth(metadata)?;
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+
+        let namespace = self
+            .auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::ViewWorker, &auth)
+            .await?;
+
+        let schedules = self
+            .worker_service
+            .get_invocation_schedules(&worker_id, namespace)
+            .await?;
+
+        Ok(schedules
+            .into_iter()
+            .map(|schedule| schedule.into())
+            .collect())
+    }
+
+    async fn cancel_invocation_schedule(
+        &self,
+        request: CancelInvocationScheduleRequest,
+        metadata: MetadataMap,
+    ) -> Result<bool, GrpcWorkerError> {
+        let auth = self.auth(metadata)?;
+
+        let worker_id = validate_protobuf_worker_id(request.worker_id)?;
+        let schedule_id = request
+            .schedule_id
+            .ok_or_else(|| bad_request_error("Missing schedule id"))?
+            .try_into()
+            .map_err(bad_request_error)?;
+
+        let namespace = self
+            .auth_service
+            .is_authorized_by_component(&worker_id.component_id, ProjectAction::UpdateWorker, &auth)
+            .await?;
+
+        let result = self
+            .worker_service
+            .cancel_invocation_schedule(&worker_id, &schedule_id, namespace)
+            .await?;
+
+        Ok(result)
+    }
 }
@@ -51,7 +51,7 @@ use poem::{EndpointExt, Route};
 use prometheus::Registry;
 use std::net::{Ipv4Addr, SocketAddrV4};
 use tokio::task::JoinSet;
-use tracing::{info, Instrument};
+use tracing::{error, info, Instrument};
 
 #[cfg(test)]
 test_r::enable!();
@@ -115,6 +115,7 @@ impl WorkerService {
         let grpc_port = self.start_grpc_server(join_set).await?;
         let http_port = self.start_http_server(join_set).await?;
         let custom_request_port = self.start_api_gateway_server(join_set).await?;
+        self.start_bulk_operation_recovery(join_set);
 
         info!(
             "Started worker service on ports: http: {}, grpc: {}, gateway: {}",
@@ -135,6 +136,7 @@ impl WorkerService {
     ) -> Result<TrafficReadyEndpoints, anyhow::Error> {
         let grpc_port = self.start_grpc_server(join_set).await?;
         let custom_request_port = self.start_api_gateway_server(join_set).await?;
+        self.start_bulk_operation_recovery(join_set);
         let api_endpoint = api::make_open_api_service(&self.services).boxed();
         Ok(TrafficReadyEndpoints {
             grpc_port,
@@ -231,4 +233,21 @@ impl WorkerService {
 
         Ok(port)
     }
+
+    /// Periodically takes over the bulk operations abandoned by stopped worker service instances,
+    /// including the ones of the previous run of this instance
+    fn start_bulk_operation_recovery(&self, join_set: &mut JoinSet<anyhow::Result<()>>) {
+        let bulk_operation_service = self.services.bulk_operation_service.clone();
+        join_set.spawn(
+            async move {
+                loop {
+                    if let Err(err) = bulk_operation_service.resume_abandoned().await {
+                        error!("Failed to resume abandoned bulk operations: {err}");
+                    }
+                    tokio::time::sleep(service::worker::BULK_OPERATION_HEARTBEAT_INTERVAL).await;
+                }
+            }
+            .in_current_span(),
+        );
+    }
 }
@@ -65,6 +65,8 @@ pub struct WorkerMetadata {
     pub last_error: Option<String>,
     pub component_size: u64,
     pub total_linear_memory_size: u64,
+    /// Size of the worker's file system in bytes, only known while the worker is loaded in memory
+    pub file_system_usage: u64,
     pub exported_resource_instances: Vec<ExportedResourceMetadata>,
     pub agent_instances: Vec<AgentInstanceMetadata>,
     pub active_plugins: HashSet<PluginInstallationId>,
@@ -74,6 +76,8 @@ pub struct WorkerMetadata {
     pub skipped_regions: Vec<OplogRegion>,
     /// Oplog regions permanently deleted from the workers using the revert functionality.
     pub deleted_regions: Vec<OplogRegion>,
+    /// User-defined labels of the worker
+    pub labels: HashMap<String, String>,
 }
 
 impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMetadata {
@@ -144,6 +148,7 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMet
             last_error: value.last_error,
             component_size: value.component_size,
             total_linear_memory_size: value.total_linear_memory_size,
+            file_system_usage: value.file_system_usage,
             exported_resource_instances,
             agent_instances,
             active_plugins: value
@@ -161,6 +166,7 @@ impl TryFrom<golem_api_grpc::proto::golem::worker::WorkerMetadata> for WorkerMet
                 .into_iter()
                 .map(OplogRegion::from)
                 .collect::<Vec<_>>(),
+            labels: value.labels,
         })
     }
 }
@@ -238,6 +244,8 @@ impl From<WorkerMetadata> for golem_api_grpc::proto::golem::worker::WorkerMetada
                 .into_iter()
                 .map(|region| region.into())
                 .collect(),
+            file_system_usage: value.file_system_usage,
+            labels: value.labels,
         }
     }
 }
@@ -0,0 +1,490 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use conditional_trait_gen::{trait_gen, when};
+use golem_common::model::ComponentId;
+use golem_service_base::db::Pool;
+use golem_service_base::model::bulk_operation::{
+    BulkOperation, BulkOperationId, BulkOperationStatus, BulkOperationWorkerResult,
+};
+use golem_service_base::repo::RepoError;
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct BulkOperationRecord {
+    pub namespace: String,
+    pub id: Uuid,
+    pub component_id: Uuid,
+    pub operation: String,
+    pub filter: Option<String>,
+    pub concurrency: i32,
+    pub status: String,
+    pub total: Option<i64>,
+    pub succeeded: i64,
+    pub failed: i64,
+    pub error: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub finished_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl BulkOperationRecord {
+    pub fn new(namespace: String, operation: &BulkOperation) -> Result<Self, String> {
+        Ok(Self {
+            namespace,
+            id: operation.operation_id.0,
+            component_id: operation.component_id.0,
+            operation: serde_json::to_string(&operation.operation)
+                .map_err(|err| format!("Failed to serialize bulk operation: {err}"))?,
+            filter: operation
+                .filter
+                .as_ref()
+                .map(serde_json::to_string)
+                .transpose()
+                .map_err(|err| format!("Failed to serialize worker filter: {err}"))?,
+            concurrency: operation.concurrency as i32,
+            status: operation.status.to_string(),
+            total: operation.total.map(|total| total as i64),
+            succeeded: operation.succeeded as i64,
+            failed: operation.failed as i64,
+            error: operation.error.clone(),
+            created_at: operation.created_at,
+            finished_at: operation.finished_at,
+        })
+    }
+}
+
+impl TryFrom<BulkOperationRecord> for BulkOperation {
+    type Error = String;
+
+    fn try_from(value: BulkOperationRecord) -> Result<Self, Self::Error> {
+        Ok(BulkOperation {
+            operation_id: BulkOperationId(value.id),
+            component_id: ComponentId(value.component_id),
+            filter: value
+                .filter
+                .map(|filter| serde_json::from_str(&filter))
+                .transpose()
+                .map_err(|err| format!("Failed to deserialize worker filter: {err}"))?,
+            operation: serde_json::from_str(&value.operation)
+                .map_err(|err| format!("Failed to deserialize bulk operation: {err}"))?,
+            concurrency: value.concurrency as u32,
+            status: value.status.parse()?,
+            total: value.total.map(|total| total as u64),
+            succeeded: value.succeeded as u64,
+            failed: value.failed as u64,
+            error: value.error,
+            created_at: value.created_at,
+            finished_at: value.finished_at,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, Debug, Clone)]
+pub struct BulkOperationResultRecord {
+    pub worker_name: String,
+    pub error: Option<String>,
+}
+
+impl From<BulkOperationResultRecord> for BulkOperationWorkerResult {
+    fn from(value: BulkOperationResultRecord) -> Self {
+        Self {
+            worker_name: value.worker_name,
+            error: value.error,
+        }
+    }
+}
+
+#[async_trait]
+pub trait BulkOperationRepo: Send + Sync {
+    async fn create(&self, record: &BulkOperationRecord) -> Result<(), RepoError>;
+
+    async fn get(
+        &self,
+        namespace: &str,
+        id: &Uuid,
+    ) -> Result<Option<BulkOperationRecord>, RepoError>;
+
+    async fn get_all(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError>;
+
+    async fn set_total(&self, id: &Uuid, total: u64) -> Result<(), RepoError>;
+
+    /// Records the outcome of the operation on a single worker and updates the counters of the operation
+    async fn add_result(
+        &self,
+        id: &Uuid,
+        worker_name: &str,
+        error: Option<String>,
+    ) -> Result<(), RepoError>;
+
+    async fn finish(
+        &self,
+        id: &Uuid,
+        status: BulkOperationStatus,
+        error: Option<String>,
+        finished_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError>;
+
+    async fn get_results(&self, id: &Uuid) -> Result<Vec<BulkOperationResultRecord>, RepoError>;
+
+    /// Marks a running operation as still being worked on
+    async fn heartbeat(
+        &self,
+        id: &Uuid,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError>;
+
+    /// Takes over the running operations without a heartbeat since `stale_before`, for example
+    /// because the worker service instance running them was stopped. Each operation is only
+    /// returned to one of the concurrent callers.
+    async fn claim_abandoned(
+        &self,
+        stale_before: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError>;
+}
+
+pub struct DbBulkOperationRepo<DB: Pool> {
+    db_pool: DB,
+}
+
+impl<DB: Pool> DbBulkOperationRepo<DB> {
+    pub fn new(db_pool: DB) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[trait_gen(golem_service_base::db::postgres::PostgresPool -> golem_service_base::db::postgres::PostgresPool, golem_service_base::db::sqlite::SqlitePool
+)]
+#[async_trait]
+impl BulkOperationRepo for DbBulkOperationRepo<golem_service_base::db::postgres::PostgresPool> {
+    async fn create(&self, record: &BulkOperationRecord) -> Result<(), RepoError> {
+        let query = sqlx::query(
+            r#"
+              INSERT INTO bulk_operations
+                (namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at, finished_at, heartbeat_at)
+              VALUES
+                ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            "#,
+        )
+        .bind(record.namespace.clone())
+        .bind(record.id)
+        .bind(record.component_id)
+        .bind(record.operation.clone())
+        .bind(record.filter.clone())
+        .bind(record.concurrency)
+        .bind(record.status.clone())
+        .bind(record.total)
+        .bind(record.succeeded)
+        .bind(record.failed)
+        .bind(record.error.clone())
+        .bind(record.created_at)
+        .bind(record.finished_at)
+        .bind(record.created_at);
+
+        self.db_pool
+            .with_rw("bulk_operation", "create")
+            .execute(query)
+            .await?;
+
+        Ok(())
+    }
+
+    #[when(golem_service_base::db::sqlite::SqlitePool -> get)]
+    async fn get_sqlite(
+        &self,
+        namespace: &str,
+        id: &Uuid,
+    ) -> Result<Option<BulkOperationRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationRecord>(
+            r#"
+              SELECT namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at, finished_at
+              FROM bulk_operations
+              WHERE namespace = $1 AND id = $2
+            "#,
+        )
+        .bind(namespace)
+        .bind(id);
+
+        self.db_pool
+            .with_ro("bulk_operation", "get")
+            .fetch_optional_as(query)
+            .await
+    }
+
+    #[when(golem_service_base::db::postgres::PostgresPool -> get)]
+    async fn get_postgres(
+        &self,
+        namespace: &str,
+        id: &Uuid,
+    ) -> Result<Option<BulkOperationRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationRecord>(
+            r#"
+              SELECT namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at::timestamptz, finished_at::timestamptz
+              FROM bulk_operations
+              WHERE namespace = $1 AND id = $2
+            "#,
+        )
+        .bind(namespace)
+        .bind(id);
+
+        self.db_pool
+            .with_ro("bulk_operation", "get")
+            .fetch_optional_as(query)
+            .await
+    }
+
+    #[when(golem_service_base::db::sqlite::SqlitePool -> get_all)]
+    async fn get_all_sqlite(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationRecord>(
+            r#"
+              SELECT namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at, finished_at
+              FROM bulk_operations
+              WHERE namespace = $1 AND component_id = $2
+              ORDER BY created_at DESC
+            "#,
+        )
+        .bind(namespace)
+        .bind(component_id);
+
+        self.db_pool
+            .with_ro("bulk_operation", "get_all")
+            .fetch_all(query)
+            .await
+    }
+
+    #[when(golem_service_base::db::postgres::PostgresPool -> get_all)]
+    async fn get_all_postgres(
+        &self,
+        namespace: &str,
+        component_id: &Uuid,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationRecord>(
+            r#"
+              SELECT namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at::timestamptz, finished_at::timestamptz
+              FROM bulk_operations
+              WHERE namespace = $1 AND component_id = $2
+              ORDER BY created_at DESC
+            "#,
+        )
+        .bind(namespace)
+        .bind(component_id);
+
+        self.db_pool
+            .with_ro("bulk_operation", "get_all")
+            .fetch_all(query)
+            .await
+    }
+
+    async fn set_total(&self, id: &Uuid, total: u64) -> Result<(), RepoError> {
+        let query = sqlx::query("UPDATE bulk_operations SET total = $1 WHERE id = $2")
+            .bind(total as i64)
+            .bind(id);
+
+        self.db_pool
+            .with_rw("bulk_operation", "set_total")
+            .execute(query)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add_result(
+        &self,
+        id: &Uuid,
+        worker_name: &str,
+        error: Option<String>,
+    ) -> Result<(), RepoError> {
+        let mut transaction = self
+            .db_pool
+            .with_rw("bulk_operation", "add_result")
+            .begin()
+            .await?;
+
+        let counter_update = if error.is_some() {
+            "UPDATE bulk_operations SET failed = failed + 1 WHERE id = $1"
+        } else {
+            "UPDATE bulk_operations SET succeeded = succeeded + 1 WHERE id = $1"
+        };
+
+        let query = sqlx::query(
+            r#"
+              INSERT INTO bulk_operation_results
+                (operation_id, worker_name, error)
+              VALUES
+                ($1, $2, $3)
+            "#,
+        )
+        .bind(id)
+        .bind(worker_name)
+        .bind(error);
+
+        transaction.execute(query).await?;
+
+        transaction
+            .execute(sqlx::query(counter_update).bind(id))
+            .await?;
+
+        self.db_pool
+            .with_rw("bulk_operation", "add_result")
+            .commit(transaction)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn finish(
+        &self,
+        id: &Uuid,
+        status: BulkOperationStatus,
+        error: Option<String>,
+        finished_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        let query = sqlx::query(
+            "UPDATE bulk_operations SET status = $1, error = $2, finished_at = $3 WHERE id = $4",
+        )
+        .bind(status.to_string())
+        .bind(error)
+        .bind(finished_at)
+        .bind(id);
+
+        self.db_pool
+            .with_rw("bulk_operation", "finish")
+            .execute(query)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_results(&self, id: &Uuid) -> Result<Vec<BulkOperationResultRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationResultRecord>(
+            "SELECT worker_name, error FROM bulk_operation_results WHERE operation_id = $1 ORDER BY worker_name",
+        )
+        .bind(id);
+
+        self.db_pool
+            .with_ro("bulk_operation", "get_results")
+            .fetch_all(query)
+            .await
+    }
+
+    async fn heartbeat(
+        &self,
+        id: &Uuid,
+        at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), RepoError> {
+        let query = sqlx::query("UPDATE bulk_operations SET heartbeat_at = $1 WHERE id = $2")
+            .bind(at)
+            .bind(id);
+
+        self.db_pool
+            .with_rw("bulk_operation", "heartbeat")
+            .execute(query)
+            .await?;
+
+        Ok(())
+    }
+
+    #[when(golem_service_base::db::sqlite::SqlitePool -> claim_abandoned)]
+    async fn claim_abandoned_sqlite(
+        &self,
+        stale_before: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationRecord>(
+            r#"
+              SELECT namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at, finished_at
+              FROM bulk_operations
+              WHERE status = $1 AND heartbeat_at < $2
+            "#,
+        )
+        .bind(BulkOperationStatus::Running.to_string())
+        .bind(stale_before);
+
+        let candidates = self
+            .db_pool
+            .with_ro("bulk_operation", "claim_abandoned")
+            .fetch_all(query)
+            .await?;
+
+        self.claim(candidates, stale_before, now).await
+    }
+
+    #[when(golem_service_base::db::postgres::PostgresPool -> claim_abandoned)]
+    async fn claim_abandoned_postgres(
+        &self,
+        stale_before: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError> {
+        let query = sqlx::query_as::<_, BulkOperationRecord>(
+            r#"
+              SELECT namespace, id, component_id, operation, filter, concurrency, status, total, succeeded, failed, error, created_at::timestamptz, finished_at::timestamptz
+              FROM bulk_operations
+              WHERE status = $1 AND heartbeat_at < $2
+            "#,
+        )
+        .bind(BulkOperationStatus::Running.to_string())
+        .bind(stale_before);
+
+        let candidates = self
+            .db_pool
+            .with_ro("bulk_operation", "claim_abandoned")
+            .fetch_all(query)
+            .await?;
+
+        self.claim(candidates, stale_before, now).await
+    }
+}
+
+#[trait_gen(golem_service_base::db::postgres::PostgresPool -> golem_service_base::db::postgres::PostgresPool, golem_service_base::db::sqlite::SqlitePool
+)]
+impl DbBulkOperationRepo<golem_service_base::db::postgres::PostgresPool> {
+    /// Claims each candidate by refreshing its heartbeat, only if no one else did it since the candidates were queried
+    async fn claim(
+        &self,
+        candidates: Vec<BulkOperationRecord>,
+        stale_before: chrono::DateTime<chrono::Utc>,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<BulkOperationRecord>, RepoError> {
+        let mut claimed = Vec::new();
+        for candidate in candidates {
+            let query = sqlx::query(
+                "UPDATE bulk_operations SET heartbeat_at = $1 WHERE id = $2 AND status = $3 AND heartbeat_at < $4",
+            )
+            .bind(now)
+            .bind(candidate.id)
+            .bind(BulkOperationStatus::Running.to_string())
+            .bind(stale_before);
+
+            let result = self
+                .db_pool
+                .with_rw("bulk_operation", "claim_abandoned")
+                .execute(query)
+                .await?;
+
+            if result.rows_affected() == 1 {
+                claimed.push(candidate);
+            }
+        }
+        Ok(claimed)
+    }
+}
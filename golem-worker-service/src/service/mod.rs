@@ -43,6 +43,7 @@ use crate::repo::api_certificate::{ApiCertificateRepo, DbApiCertificateRepo};
 use crate::repo::api_definition::{ApiDefinitionRepo, DbApiDefinitionRepo};
 use crate::repo::api_deployment::{ApiDeploymentRepo, DbApiDeploymentRepo};
 use crate::repo::api_domain::{ApiDomainRepo, DbApiDomainRepo};
+use crate::repo::bulk_operation::{BulkOperationRepo, DbBulkOperationRepo};
 use crate::repo::security_scheme::{DbSecuritySchemeRepo, SecuritySchemeRepo};
 use crate::service::api_certificate::{
     AwsCertificateManager, CertificateManager, CertificateService, CertificateServiceDefault,
@@ -63,7 +64,9 @@ use crate::service::gateway::api_definition_validator::ApiDefinitionValidatorSer
 use crate::service::gateway::api_deployment::{ApiDeploymentService, ApiDeploymentServiceDefault};
 use crate::service::gateway::http_api_definition_validator::HttpApiDefinitionValidator;
 use crate::service::gateway::security_scheme::DefaultSecuritySchemeService as BaseDefaultSecuritySchemeService;
-use crate::service::worker::{WorkerService, WorkerServiceDefault};
+use crate::service::worker::{
+    BulkOperationService, BulkOperationServiceDefault, WorkerService, WorkerServiceDefault,
+};
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
 use golem_common::client::{GrpcClientConfig, MultiTargetGrpcClient};
 use golem_common::config::DbConfig;
@@ -95,6 +98,7 @@ pub struct Services {
     pub certificate_service: Arc<dyn CertificateService>,
     pub component_service: Arc<dyn ComponentService>,
     pub worker_service: Arc<dyn WorkerService>,
+    pub bulk_operation_service: Arc<dyn BulkOperationService>,
     pub worker_request_to_http_service: Arc<dyn GatewayWorkerRequestExecutor>,
     pub http_request_api_definition_lookup_service: Arc<dyn HttpApiDefinitionsLookup>,
     pub file_server_binding_handler: Arc<dyn FileServerBindingHandler>,
@@ -119,6 +123,7 @@ impl Services {
             api_certificate_repo,
             api_domain_repo,
             security_scheme_repo,
+            bulk_operation_repo,
         ) = match config.db.clone() {
             DbConfig::Postgres(config) => {
                 let db_pool = PostgresPool::configured(&config)
@@ -134,12 +139,15 @@ impl Services {
                     Arc::new(DbApiDomainRepo::new(db_pool.clone()));
                 let security_scheme_repo: Arc<dyn SecuritySchemeRepo> =
                     Arc::new(DbSecuritySchemeRepo::new(db_pool.clone()));
+                let bulk_operation_repo: Arc<dyn BulkOperationRepo> =
+                    Arc::new(DbBulkOperationRepo::new(db_pool.clone()));
                 (
                     api_definition_repo,
                     api_deployment_repo,
                     api_certificate_repo,
                     api_domain_repo,
                     security_scheme_repo,
+                    bulk_operation_repo,
                 )
             }
             DbConfig::Sqlite(config) => {
@@ -156,6 +164,8 @@ impl Services {
                     Arc::new(DbApiDomainRepo::new(db_pool.clone()));
                 let security_scheme_repo: Arc<dyn SecuritySchemeRepo> =
                     Arc::new(DbSecuritySchemeRepo::new(db_pool.clone()));
+                let bulk_operation_repo: Arc<dyn BulkOperationRepo> =
+                    Arc::new(DbBulkOperationRepo::new(db_pool.clone()));
 
                 (
                     api_definition_repo,
@@ -163,6 +173,7 @@ impl Services {
                     api_certificate_repo,
                     api_domain_repo,
                     security_scheme_repo,
+                    bulk_operation_repo,
                 )
             }
         };
@@ -385,6 +396,10 @@ impl Services {
             config.cloud_service.clone(),
         ));
 
+        let bulk_operation_service: Arc<dyn BulkOperationService> = Arc::new(
+            BulkOperationServiceDefault::new(worker_service.clone(), bulk_operation_repo),
+        );
+
         let worker_request_to_http_service: Arc<dyn GatewayWorkerRequestExecutor> = Arc::new(
             GatewayWorkerRequestExecutorDefault::new(worker_service.clone()),
         );
@@ -416,6 +431,7 @@ impl Services {
             certificate_service,
             component_service,
             worker_service,
+            bulk_operation_service,
             worker_request_to_http_service,
             http_request_api_definition_lookup_service,
             file_server_binding_handler,
@@ -0,0 +1,510 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{InvocationParameters, WorkerService, WorkerServiceError};
+use crate::repo::bulk_operation::{BulkOperationRecord, BulkOperationRepo};
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::{stream, StreamExt};
+use golem_api_grpc::proto::golem::worker::UpdateMode;
+use golem_common::model::auth::Namespace;
+use golem_common::model::oplog::InvocationPriority;
+use golem_common::model::{ComponentId, ComponentVersion, IdempotencyKey, ScanCursor, WorkerId};
+use golem_common::SafeDisplay;
+use golem_service_base::model::bulk_operation::{
+    BulkOperation, BulkOperationId, BulkOperationKind, BulkOperationReport, BulkOperationRequest,
+    BulkOperationStatus, BulkOperationWorkerResult,
+};
+use golem_service_base::repo::RepoError;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, info_span, warn, Instrument};
+
+const DEFAULT_CONCURRENCY: u32 = 8;
+const MAX_CONCURRENCY: u32 = 64;
+const SCAN_PAGE_SIZE: u64 = 100;
+
+/// How often a running operation refreshes its heartbeat
+pub const BULK_OPERATION_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+/// A running operation without a heartbeat for this long is considered abandoned
+const ABANDONED_AFTER_SECONDS: i64 = 60;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BulkOperationServiceError {
+    #[error("Bulk operation not found: {0}")]
+    NotFound(BulkOperationId),
+    #[error("Invalid bulk operation: {0}")]
+    BadRequest(String),
+    #[error("Internal repository error: {0}")]
+    InternalRepoError(#[from] RepoError),
+    #[error("Internal error: {0}")]
+    Internal(String),
+}
+
+impl SafeDisplay for BulkOperationServiceError {
+    fn to_safe_string(&self) -> String {
+        match self {
+            BulkOperationServiceError::NotFound(_) => self.to_string(),
+            BulkOperationServiceError::BadRequest(_) => self.to_string(),
+            BulkOperationServiceError::InternalRepoError(inner) => inner.to_safe_string(),
+            BulkOperationServiceError::Internal(_) => self.to_string(),
+        }
+    }
+}
+
+/// Runs an operation on every worker of a component matching a filter, as a background job
+/// whose progress and per-worker results are stored in the database.
+#[async_trait]
+pub trait BulkOperationService: Send + Sync {
+    async fn start(
+        &self,
+        component_id: &ComponentId,
+        request: BulkOperationRequest,
+        namespace: Namespace,
+    ) -> Result<BulkOperation, BulkOperationServiceError>;
+
+    async fn get(
+        &self,
+        component_id: &ComponentId,
+        operation_id: &BulkOperationId,
+        namespace: Namespace,
+    ) -> Result<BulkOperationReport, BulkOperationServiceError>;
+
+    async fn get_all(
+        &self,
+        component_id: &ComponentId,
+        namespace: Namespace,
+    ) -> Result<Vec<BulkOperation>, BulkOperationServiceError>;
+
+    /// Continues the running operations whose jobs were lost, for example because the worker
+    /// service instance running them was stopped. Operations which cannot be continued are
+    /// marked as failed.
+    async fn resume_abandoned(&self) -> Result<(), BulkOperationServiceError>;
+}
+
+pub struct BulkOperationServiceDefault {
+    worker_service: Arc<dyn WorkerService>,
+    bulk_operation_repo: Arc<dyn BulkOperationRepo>,
+}
+
+impl BulkOperationServiceDefault {
+    pub fn new(
+        worker_service: Arc<dyn WorkerService>,
+        bulk_operation_repo: Arc<dyn BulkOperationRepo>,
+    ) -> Self {
+        Self {
+            worker_service,
+            bulk_operation_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl BulkOperationService for BulkOperationServiceDefault {
+    async fn start(
+        &self,
+        component_id: &ComponentId,
+        request: BulkOperationRequest,
+        namespace: Namespace,
+    ) -> Result<BulkOperation, BulkOperationServiceError> {
+        let concurrency = request.concurrency.unwrap_or(DEFAULT_CONCURRENCY);
+        if concurrency == 0 || concurrency > MAX_CONCURRENCY {
+            return Err(BulkOperationServiceError::BadRequest(format!(
+                "concurrency must be between 1 and {MAX_CONCURRENCY}"
+            )));
+        }
+
+        let action = BulkAction::new(&request.operation)?;
+
+        let operation = BulkOperation {
+            operation_id: BulkOperationId::new_v4(),
+            component_id: component_id.clone(),
+            filter: request.filter,
+            operation: request.operation,
+            concurrency,
+            status: BulkOperationStatus::Running,
+            total: None,
+            succeeded: 0,
+            failed: 0,
+            error: None,
+            created_at: Utc::now(),
+            finished_at: None,
+        };
+
+        let record = BulkOperationRecord::new(namespace.to_string(), &operation)
+            .map_err(BulkOperationServiceError::Internal)?;
+        self.bulk_operation_repo.create(&record).await?;
+
+        self.spawn_job(operation.clone(), action, namespace);
+
+        Ok(operation)
+    }
+
+    async fn get(
+        &self,
+        component_id: &ComponentId,
+        operation_id: &BulkOperationId,
+        namespace: Namespace,
+    ) -> Result<BulkOperationReport, BulkOperationServiceError> {
+        let operation: BulkOperation = self
+            .bulk_operation_repo
+            .get(&namespace.to_string(), &operation_id.0)
+            .await?
+            .ok_or(BulkOperationServiceError::NotFound(*operation_id))?
+            .try_into()
+            .map_err(BulkOperationServiceError::Internal)?;
+
+        if &operation.component_id != component_id {
+            return Err(BulkOperationServiceError::NotFound(*operation_id));
+        }
+
+        let results = self
+            .bulk_operation_repo
+            .get_results(&operation_id.0)
+            .await?
+            .into_iter()
+            .map(BulkOperationWorkerResult::from)
+            .collect();
+
+        Ok(BulkOperationReport { operation, results })
+    }
+
+    async fn get_all(
+        &self,
+        component_id: &ComponentId,
+        namespace: Namespace,
+    ) -> Result<Vec<BulkOperation>, BulkOperationServiceError> {
+        self.bulk_operation_repo
+            .get_all(&namespace.to_string(), &component_id.0)
+            .await?
+            .into_iter()
+            .map(BulkOperation::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(BulkOperationServiceError::Internal)
+    }
+
+    async fn resume_abandoned(&self) -> Result<(), BulkOperationServiceError> {
+        let now = Utc::now();
+        let stale_before = now - chrono::Duration::seconds(ABANDONED_AFTER_SECONDS);
+
+        for record in self
+            .bulk_operation_repo
+            .claim_abandoned(stale_before, now)
+            .await?
+        {
+            let operation_id = record.id;
+            info!(
+                operation_id = operation_id.to_string(),
+                "Resuming abandoned bulk operation"
+            );
+
+            let resumed = Namespace::try_from(record.namespace.clone()).and_then(|namespace| {
+                let operation = BulkOperation::try_from(record)?;
+                let action =
+                    BulkAction::new(&operation.operation).map_err(|err| err.to_safe_string())?;
+                Ok((operation, action, namespace))
+            });
+
+            match resumed {
+                Ok((operation, action, namespace)) => {
+                    self.spawn_job(operation, action, namespace);
+                }
+                Err(err) => {
+                    error!(
+                        operation_id = operation_id.to_string(),
+                        "Failed to resume abandoned bulk operation: {err}"
+                    );
+                    self.bulk_operation_repo
+                        .finish(
+                            &operation_id,
+                            BulkOperationStatus::Failed,
+                            Some(format!("Failed to resume the operation: {err}")),
+                            Utc::now(),
+                        )
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BulkOperationServiceDefault {
+    fn spawn_job(&self, operation: BulkOperation, action: BulkAction, namespace: Namespace) {
+        let span = info_span!(
+            "bulk operation",
+            operation_id = operation.operation_id.to_string(),
+            component_id = operation.component_id.to_string(),
+            operation = operation.operation.name()
+        );
+        let job = BulkOperationJob {
+            worker_service: self.worker_service.clone(),
+            bulk_operation_repo: self.bulk_operation_repo.clone(),
+            operation,
+            action,
+            namespace,
+        };
+        tokio::spawn(job.run().instrument(span));
+    }
+}
+
+/// The per-worker action of a bulk operation, with its parameters already validated
+enum BulkAction {
+    Update {
+        mode: UpdateMode,
+        target_version: ComponentVersion,
+    },
+    Interrupt {
+        recover_immediately: bool,
+    },
+    Resume {
+        force: bool,
+    },
+    Delete,
+    Invoke {
+        function: String,
+        params: InvocationParameters,
+    },
+}
+
+impl BulkAction {
+    fn new(operation: &BulkOperationKind) -> Result<Self, BulkOperationServiceError> {
+        match operation {
+            BulkOperationKind::Update(update) => Ok(BulkAction::Update {
+                mode: update.mode.clone().into(),
+                target_version: update.target_version,
+            }),
+            BulkOperationKind::Interrupt(interrupt) => Ok(BulkAction::Interrupt {
+                recover_immediately: interrupt.recover_immediately.unwrap_or(false),
+            }),
+            BulkOperationKind::Resume(resume) => Ok(BulkAction::Resume {
+                force: resume.force.unwrap_or(false),
+            }),
+            BulkOperationKind::Delete(_) => Ok(BulkAction::Delete),
+            BulkOperationKind::Invoke(invoke) => {
+                let params = InvocationParameters::from_optionally_type_annotated_value_jsons(
+                    invoke.params.clone(),
+                )
+                .map_err(|errors| BulkOperationServiceError::BadRequest(errors.join(", ")))?;
+                Ok(BulkAction::Invoke {
+                    function: invoke.function.clone(),
+                    params,
+                })
+            }
+        }
+    }
+}
+
+struct BulkOperationJob {
+    worker_service: Arc<dyn WorkerService>,
+    bulk_operation_repo: Arc<dyn BulkOperationRepo>,
+    operation: BulkOperation,
+    action: BulkAction,
+    namespace: Namespace,
+}
+
+impl BulkOperationJob {
+    async fn run(self) {
+        let operation_id = self.operation.operation_id.0;
+
+        let result = tokio::select! {
+            result = self.perform() => result,
+            _ = self.heartbeat() => unreachable!("the heartbeat never finishes"),
+        };
+
+        let (status, error) = match result {
+            Ok(()) => (BulkOperationStatus::Completed, None),
+            Err(err) => {
+                error!("Bulk operation failed: {err}");
+                (BulkOperationStatus::Failed, Some(err.to_safe_string()))
+            }
+        };
+
+        if let Err(err) = self
+            .bulk_operation_repo
+            .finish(&operation_id, status, error, Utc::now())
+            .await
+        {
+            error!("Failed to store the final status of the bulk operation: {err}");
+        }
+    }
+
+    async fn heartbeat(&self) {
+        loop {
+            tokio::time::sleep(BULK_OPERATION_HEARTBEAT_INTERVAL).await;
+            if let Err(err) = self
+                .bulk_operation_repo
+                .heartbeat(&self.operation.operation_id.0, Utc::now())
+                .await
+            {
+                warn!("Failed to store the heartbeat of the bulk operation: {err}");
+            }
+        }
+    }
+
+    async fn perform(&self) -> Result<(), WorkerServiceError> {
+        // A resumed operation already has results for some of the workers, which are skipped
+        let done = self
+            .bulk_operation_repo
+            .get_results(&self.operation.operation_id.0)
+            .await
+            .map_err(|err| WorkerServiceError::Internal(err.to_safe_string()))?
+            .into_iter()
+            .map(|result| result.worker_name)
+            .collect::<HashSet<_>>();
+
+        let worker_ids = self
+            .find_workers()
+            .await?
+            .into_iter()
+            .filter(|worker_id| !done.contains(&worker_id.worker_name))
+            .collect::<Vec<_>>();
+        info!(
+            "Performing bulk operation on {} workers ({} already done)",
+            worker_ids.len(),
+            done.len()
+        );
+
+        self.bulk_operation_repo
+            .set_total(
+                &self.operation.operation_id.0,
+                (done.len() + worker_ids.len()) as u64,
+            )
+            .await
+            .map_err(|err| WorkerServiceError::Internal(err.to_safe_string()))?;
+
+        stream::iter(worker_ids)
+            .map(|worker_id| async move {
+                let result = self.perform_on(&worker_id).await;
+                (worker_id, result)
+            })
+            .buffer_unordered(self.operation.concurrency as usize)
+            .for_each(|(worker_id, result)| async move {
+                let error = result.err().map(|err| err.to_safe_string());
+                if let Err(err) = self
+                    .bulk_operation_repo
+                    .add_result(
+                        &self.operation.operation_id.0,
+                        &worker_id.worker_name,
+                        error,
+                    )
+                    .await
+                {
+                    error!(
+                        worker_id = worker_id.to_string(),
+                        "Failed to store the result of the bulk operation: {err}"
+                    );
+                }
+            })
+            .await;
+
+        Ok(())
+    }
+
+    /// Enumerates all the matching workers up front, so the operation is not affected by the
+    /// changes it makes to the workers (for example deleting them while scanning).
+    async fn find_workers(&self) -> Result<Vec<WorkerId>, WorkerServiceError> {
+        let mut worker_ids = Vec::new();
+        let mut cursor = ScanCursor::default();
+
+        loop {
+            let (next_cursor, workers) = self
+                .worker_service
+                .find_metadata(
+                    &self.operation.component_id,
+                    self.operation.filter.clone(),
+                    cursor,
+                    SCAN_PAGE_SIZE,
+                    true,
+                    self.namespace.clone(),
+                )
+                .await?;
+
+            worker_ids.extend(workers.into_iter().map(|worker| worker.worker_id));
+
+            match next_cursor {
+                Some(next_cursor) => cursor = next_cursor,
+                None => break,
+            }
+        }
+
+        Ok(worker_ids)
+    }
+
+    async fn perform_on(&self, worker_id: &WorkerId) -> Result<(), WorkerServiceError> {
+        let namespace = self.namespace.clone();
+
+        match &self.action {
+            BulkAction::Update {
+                mode,
+                target_version,
+            } => {
+                self.worker_service
+                    .update(worker_id, *mode, *target_version, namespace)
+                    .await
+            }
+            BulkAction::Interrupt {
+                recover_immediately,
+            } => {
+                self.worker_service
+                    .interrupt(worker_id, *recover_immediately, namespace)
+                    .await
+            }
+            BulkAction::Resume { force } => {
+                self.worker_service
+                    .resume(worker_id, namespace, *force)
+                    .await
+            }
+            BulkAction::Delete => self.worker_service.delete(worker_id, namespace).await,
+            BulkAction::Invoke { function, params } => {
+                // The operation id is used as the idempotency key, so each worker gets the
+                // invocation at most once
+                let idempotency_key =
+                    Some(IdempotencyKey::from_uuid(self.operation.operation_id.0));
+                let target_worker_id = worker_id.clone().into_target_worker_id();
+
+                match params {
+                    InvocationParameters::TypedProtoVals(vals) => {
+                        self.worker_service
+                            .validate_and_invoke(
+                                &target_worker_id,
+                                idempotency_key,
+                                function.clone(),
+                                vals.clone(),
+                                None,
+                                InvocationPriority::Normal,
+                                namespace,
+                            )
+                            .await
+                    }
+                    InvocationParameters::RawJsonStrings(jsons) => {
+                        self.worker_service
+                            .invoke_json(
+                                &target_worker_id,
+                                idempotency_key,
+                                function.clone(),
+                                jsons.clone(),
+                                None,
+                                InvocationPriority::Normal,
+                                namespace,
+                            )
+                            .await
+                    }
+                }
+            }
+        }
+    }
+}
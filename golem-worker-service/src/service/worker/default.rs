@@ -25,26 +25,39 @@ use golem_api_grpc::proto::golem::worker::UpdateMode;
 use golem_api_grpc::proto::golem::worker::{InvocationContext, InvokeResult};
 use golem_api_grpc::proto::golem::workerexecutor;
 use golem_api_grpc::proto::golem::workerexecutor::v1::worker_executor_client::WorkerExecutorClient;
+use golem_api_grpc::proto::golem::workerexecutor::v1::{
+    worker_ttl_policy_target, AbortSagaRequest, CancelInvocationScheduleRequest,
+    ChangeInvocationPriorityRequest, GetInvocationResultRequest, GetInvocationSchedulesRequest,
+    GetPendingInvocationsRequest, GetPendingPromisesRequest, GetSagasRequest,
+    GetWorkerTtlDeletionsRequest, GetWorkerTtlPolicyRequest, PurgePendingInvocationsRequest,
+    ScheduleInvocationRequest, SetWorkerTtlPolicyRequest, WorkerTtlPolicyTarget,
+};
 use golem_api_grpc::proto::golem::workerexecutor::v1::{
     ActivatePluginRequest, CancelInvocationRequest, CompletePromiseRequest, ConnectWorkerRequest,
     CreateWorkerRequest, DeactivatePluginRequest, ForkWorkerRequest, InterruptWorkerRequest,
     InvokeAndAwaitWorkerJsonRequest, InvokeAndAwaitWorkerRequest, ResumeWorkerRequest,
-    RevertWorkerRequest, SearchOplogResponse, UpdateWorkerRequest,
+    RevertWorkerRequest, SearchOplogResponse, UpdateWorkerLabelsRequest, UpdateWorkerRequest,
 };
 use golem_common::client::MultiTargetGrpcClient;
 use golem_common::model::auth::{Namespace, TokenSecret};
-use golem_common::model::oplog::OplogIndex;
+use golem_common::model::oplog::{InvocationPriority, OplogIndex};
 use golem_common::model::public_oplog::{OplogCursor, PublicOplogEntry};
 use golem_common::model::RetryConfig;
 use golem_common::model::{
     ComponentFilePath, ComponentFileSystemNode, ComponentId, ComponentVersion, FilterComparator,
-    IdempotencyKey, PluginInstallationId, PromiseId, ScanCursor, TargetWorkerId, WorkerFilter,
-    WorkerId, WorkerStatus,
+    IdempotencyKey, InvocationScheduleId, PluginInstallationId, PromiseId, SagaId, ScanCursor,
+    TargetWorkerId, WorkerFilter, WorkerId, WorkerStatus,
 };
 use golem_service_base::clients::limit::LimitService;
 use golem_service_base::clients::project::ProjectService;
 use golem_service_base::clients::RemoteServiceConfig;
 use golem_service_base::error::worker_executor::WorkerExecutorError;
+use golem_service_base::model::invocation_result::InvocationResultStatus;
+use golem_service_base::model::invocation_schedule::{InvocationSchedule, InvocationScheduleSpec};
+use golem_service_base::model::pending_invocation::PendingInvocation;
+use golem_service_base::model::pending_promise::PendingPromise;
+use golem_service_base::model::saga::Saga;
+use golem_service_base::model::worker_ttl::{WorkerTtlDeletion, WorkerTtlPolicy};
 use golem_service_base::model::RevertWorkerTarget;
 use golem_service_base::model::{GetOplogResponse, PublicOplogEntryWithIndex, ResourceLimits};
 use golem_service_base::service::routing_table::{HasRoutingTableService, RoutingTableService};
@@ -68,6 +81,7 @@ pub trait WorkerService: Send + Sync {
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
         wasi_config_vars: BTreeMap<String, String>,
+        labels: HashMap<String, String>,
         namespace: Namespace,
     ) -> WorkerResult<WorkerId>;
 
@@ -90,6 +104,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<ValueAndType>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<Option<ValueAndType>> {
         let params = self.validate_typed_parameters(params)?;
@@ -99,6 +114,7 @@ pub trait WorkerService: Send + Sync {
             function_name,
             params,
             invocation_context,
+            priority,
             namespace,
         )
         .await
@@ -113,6 +129,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<Option<ValueAndType>>;
 
@@ -125,6 +142,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<InvokeResult>;
 
@@ -138,6 +156,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<String>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<Option<ValueAndType>>;
 
@@ -150,6 +169,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<ValueAndType>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<()> {
         let params = self.validate_typed_parameters(params)?;
@@ -159,6 +179,7 @@ pub trait WorkerService: Send + Sync {
             function_name,
             params,
             invocation_context,
+            priority,
             namespace,
         )
         .await
@@ -173,6 +194,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<()>;
 
@@ -186,6 +208,7 @@ pub trait WorkerService: Send + Sync {
         function_name: String,
         params: Vec<String>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<()>;
 
@@ -197,6 +220,13 @@ pub trait WorkerService: Send + Sync {
         namespace: Namespace,
     ) -> WorkerResult<bool>;
 
+    /// Gets the promises created by the worker which are not completed or deleted yet
+    async fn get_pending_promises(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<PendingPromise>>;
+
     async fn interrupt(
         &self,
         worker_id: &WorkerId,
@@ -281,6 +311,15 @@ pub trait WorkerService: Send + Sync {
         namespace: Namespace,
     ) -> WorkerResult<()>;
 
+    /// Sets the labels in `set` and then removes the ones listed in `remove`
+    async fn update_labels(
+        &self,
+        worker_id: &WorkerId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+        namespace: Namespace,
+    ) -> WorkerResult<()>;
+
     async fn fork_worker(
         &self,
         source_worker_id: &WorkerId,
@@ -302,6 +341,154 @@ pub trait WorkerService: Send + Sync {
         idempotency_key: &IdempotencyKey,
         namespace: Namespace,
     ) -> WorkerResult<bool>;
+
+    /// Gets the state of an invocation identified by its idempotency key, and its result
+    /// if it has already finished. If `wait_timeout_millis` is set, waits at most that long
+    /// (capped by the worker executor) for an unfinished invocation to finish.
+    async fn get_invocation_result(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        wait_timeout_millis: Option<u64>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationResultStatus>;
+
+    /// Gets the pending invocations of the worker in the order they are going to be processed
+    async fn get_pending_invocations(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<PendingInvocation>>;
+
+    /// Changes the priority of a pending invocation. Returns false if the invocation has already started.
+    async fn change_invocation_priority(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        priority: InvocationPriority,
+        namespace: Namespace,
+    ) -> WorkerResult<bool>;
+
+    /// Cancels all the pending invocations of the worker which have not started yet,
+    /// returning their idempotency keys
+    async fn purge_pending_invocations(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<IdempotencyKey>>;
+
+    /// Gets the sagas coordinated by the worker, oldest first
+    async fn get_sagas(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<Saga>>;
+
+    /// Aborts a saga coordinated by the worker, or retries the compensation of a saga whose
+    /// compensation failed. The compensations are performed in the background.
+    async fn abort_saga(
+        &self,
+        worker_id: &WorkerId,
+        saga_id: &SagaId,
+        reason: Option<String>,
+        namespace: Namespace,
+    ) -> WorkerResult<Saga>;
+
+    /// Validates the provided list of `TypeAnnotatedValue` parameters, and then creates
+    /// an invocation schedule for the worker.
+    async fn validate_and_schedule_invocation(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<ValueAndType>,
+        schedule: InvocationScheduleSpec,
+        invocation_context: Option<InvocationContext>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationSchedule> {
+        let params = self.validate_typed_parameters(params)?;
+        self.schedule_invocation(
+            worker_id,
+            function_name,
+            params,
+            schedule,
+            invocation_context,
+            namespace,
+        )
+        .await
+    }
+
+    /// Creates a one-shot or recurring invocation schedule for the worker, using raw `Val`
+    /// parameters.
+    async fn schedule_invocation(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        schedule: InvocationScheduleSpec,
+        invocation_context: Option<InvocationContext>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationSchedule>;
+
+    /// Creates a one-shot or recurring invocation schedule for the worker, using JSON value
+    /// encoding represented as raw strings, interpreted by the executor.
+    async fn schedule_invocation_json(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<String>,
+        schedule: InvocationScheduleSpec,
+        invocation_context: Option<InvocationContext>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationSchedule>;
+
+    async fn get_invocation_schedules(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<InvocationSchedule>>;
+
+    async fn cancel_invocation_schedule(
+        &self,
+        worker_id: &WorkerId,
+        schedule_id: &InvocationScheduleId,
+        namespace: Namespace,
+    ) -> WorkerResult<bool>;
+
+    /// Sets the time-to-live policy of all the workers of a component, or removes it if `policy` is `None`
+    async fn set_component_ttl_policy(
+        &self,
+        component_id: &ComponentId,
+        policy: Option<WorkerTtlPolicy>,
+        namespace: Namespace,
+    ) -> WorkerResult<()>;
+
+    async fn get_component_ttl_policy(
+        &self,
+        component_id: &ComponentId,
+        namespace: Namespace,
+    ) -> WorkerResult<Option<WorkerTtlPolicy>>;
+
+    /// Sets the time-to-live policy of a single worker, overriding its component's policy,
+    /// or removes it if `policy` is `None`
+    async fn set_worker_ttl_policy(
+        &self,
+        worker_id: &WorkerId,
+        policy: Option<WorkerTtlPolicy>,
+        namespace: Namespace,
+    ) -> WorkerResult<()>;
+
+    async fn get_worker_ttl_policy(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Option<WorkerTtlPolicy>>;
+
+    /// Gets the audit log of the workers of a component deleted by their time-to-live policy
+    async fn get_ttl_deletions(
+        &self,
+        component_id: &ComponentId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<WorkerTtlDeletion>>;
 }
 
 pub struct TypedResult {
@@ -341,6 +528,39 @@ impl WorkerServiceDefault {
         }
     }
 
+    async fn create_invocation_schedule(
+        &self,
+        worker_id: &WorkerId,
+        invocation: workerexecutor::v1::schedule_invocation_request::Invocation,
+        schedule: InvocationScheduleSpec,
+    ) -> WorkerResult<InvocationSchedule> {
+        let worker_id = worker_id.clone();
+        let schedule = self.call_worker_executor(
+            worker_id.clone(),
+            "schedule_invocation",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.schedule_invocation(ScheduleInvocationRequest {
+                    invocation: Some(invocation.clone()),
+                    schedule: Some(schedule.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ScheduleInvocationResponse {
+                    result: Some(workerexecutor::v1::schedule_invocation_response::Result::Success(schedule)),
+                } => schedule
+                    .try_into()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::ScheduleInvocationResponse {
+                    result: Some(workerexecutor::v1::schedule_invocation_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ScheduleInvocationResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(schedule)
+    }
+
     async fn get_resource_limits(&self, namespace: &Namespace) -> WorkerResult<ResourceLimits> {
         // TODO: cache this?
         let project_owner = self
@@ -500,6 +720,7 @@ impl WorkerService for WorkerServiceDefault {
         arguments: Vec<String>,
         environment_variables: HashMap<String, String>,
         wasi_config_vars: BTreeMap<String, String>,
+        labels: HashMap<String, String>,
         namespace: Namespace,
     ) -> WorkerResult<WorkerId> {
         let resource_limits = self.get_resource_limits(&namespace).await?;
@@ -520,6 +741,7 @@ impl WorkerService for WorkerServiceDefault {
                     project_id: Some(namespace.project_id.clone().into()),
                     account_limits: Some(resource_limits.clone().into()),
                     wasi_config_vars: Some(wasi_config_vars.clone().into()),
+                    labels: labels.clone(),
                 }))
             },
             |response| match response.into_inner() {
@@ -643,6 +865,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<Option<ValueAndType>> {
         let resource_limits = self.get_resource_limits(&namespace).await?;
@@ -663,6 +886,7 @@ impl WorkerService for WorkerServiceDefault {
                         account_limits: Some(resource_limits.clone().into()),
                         context: invocation_context.clone(),
                         project_id: Some(namespace.project_id.clone().into()),
+                        priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(priority) as i32,
                     }
                 )
                 )
@@ -706,6 +930,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<InvokeResult> {
         let resource_limits = self.get_resource_limits(&namespace).await?;
@@ -726,6 +951,7 @@ impl WorkerService for WorkerServiceDefault {
                         account_limits: Some(resource_limits.clone().into()),
                         context: invocation_context.clone(),
                         project_id: Some(namespace.project_id.clone().into()),
+                        priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(priority) as i32,
                     }
                 )
                 )
@@ -766,6 +992,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<String>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<Option<ValueAndType>> {
         let resource_limits = self.get_resource_limits(&namespace).await?;
@@ -786,6 +1013,7 @@ impl WorkerService for WorkerServiceDefault {
                         account_limits: Some(resource_limits.clone().into()),
                         context: invocation_context.clone(),
                         project_id: Some(namespace.project_id.clone().into()),
+                        priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(priority) as i32,
                     }
                 )
                 )
@@ -831,6 +1059,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<ProtoVal>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<()> {
         let resource_limits = self.get_resource_limits(&namespace).await?;
@@ -850,6 +1079,9 @@ impl WorkerService for WorkerServiceDefault {
                         account_limits: Some(resource_limits.clone().into()),
                         context: invocation_context.clone(),
                         project_id: Some(namespace.project_id.clone().into()),
+                        priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(
+                            priority,
+                        ) as i32,
                     },
                 ))
             },
@@ -875,6 +1107,7 @@ impl WorkerService for WorkerServiceDefault {
         function_name: String,
         params: Vec<String>,
         invocation_context: Option<InvocationContext>,
+        priority: InvocationPriority,
         namespace: Namespace,
     ) -> WorkerResult<()> {
         let resource_limits = self.get_resource_limits(&namespace).await?;
@@ -894,6 +1127,9 @@ impl WorkerService for WorkerServiceDefault {
                         account_limits: Some(resource_limits.clone().into()),
                         context: invocation_context.clone(),
                         project_id: Some(namespace.project_id.clone().into()),
+                        priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(
+                            priority,
+                        ) as i32,
                     },
                 ))
             },
@@ -966,6 +1202,41 @@ impl WorkerService for WorkerServiceDefault {
         Ok(result)
     }
 
+    async fn get_pending_promises(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<PendingPromise>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_pending_promises",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.get_pending_promises(GetPendingPromisesRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetPendingPromisesResponse {
+                    result: Some(workerexecutor::v1::get_pending_promises_response::Result::Success(success)),
+                } => success
+                    .promises
+                    .into_iter()
+                    .map(|promise| promise.try_into())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::GetPendingPromisesResponse {
+                    result: Some(workerexecutor::v1::get_pending_promises_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetPendingPromisesResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
     async fn interrupt(
         &self,
         worker_id: &WorkerId,
@@ -1500,6 +1771,51 @@ impl WorkerService for WorkerServiceDefault {
         Ok(())
     }
 
+    async fn update_labels(
+        &self,
+        worker_id: &WorkerId,
+        set: HashMap<String, String>,
+        remove: Vec<String>,
+        namespace: Namespace,
+    ) -> WorkerResult<()> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "update_worker_labels",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(
+                    worker_executor_client.update_worker_labels(UpdateWorkerLabelsRequest {
+                        worker_id: Some(worker_id.into()),
+                        account_id: Some(namespace.account_id.clone().into()),
+                        project_id: Some(namespace.project_id.clone().into()),
+                        set: set.clone(),
+                        remove: remove.clone(),
+                    }),
+                )
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::UpdateWorkerLabelsResponse {
+                    result:
+                        Some(workerexecutor::v1::update_worker_labels_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::UpdateWorkerLabelsResponse {
+                    result:
+                        Some(workerexecutor::v1::update_worker_labels_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::UpdateWorkerLabelsResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await?;
+
+        Ok(())
+    }
+
     async fn fork_worker(
         &self,
         source_worker_id: &WorkerId,
@@ -1608,6 +1924,532 @@ impl WorkerService for WorkerServiceDefault {
             .await?;
         Ok(canceled)
     }
+
+    async fn get_invocation_result(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        wait_timeout_millis: Option<u64>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationResultStatus> {
+        let worker_id = worker_id.clone();
+        let idempotency_key = idempotency_key.clone();
+        let status = self.call_worker_executor(
+            worker_id.clone(),
+            "get_invocation_result",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let idempotency_key = idempotency_key.clone();
+                Box::pin(worker_executor_client.get_invocation_result(GetInvocationResultRequest {
+                    worker_id: Some(worker_id.into()),
+                    idempotency_key: Some(idempotency_key.into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                    wait_timeout_millis,
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetInvocationResultResponse {
+                    result: Some(workerexecutor::v1::get_invocation_result_response::Result::Success(status)),
+                } => status
+                    .try_into()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::GetInvocationResultResponse {
+                    result: Some(workerexecutor::v1::get_invocation_result_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetInvocationResultResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(status)
+    }
+
+    async fn get_pending_invocations(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<PendingInvocation>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_pending_invocations",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.get_pending_invocations(GetPendingInvocationsRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetPendingInvocationsResponse {
+                    result: Some(workerexecutor::v1::get_pending_invocations_response::Result::Success(success)),
+                } => success
+                    .invocations
+                    .into_iter()
+                    .map(|invocation| invocation.try_into())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::GetPendingInvocationsResponse {
+                    result: Some(workerexecutor::v1::get_pending_invocations_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetPendingInvocationsResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn change_invocation_priority(
+        &self,
+        worker_id: &WorkerId,
+        idempotency_key: &IdempotencyKey,
+        priority: InvocationPriority,
+        namespace: Namespace,
+    ) -> WorkerResult<bool> {
+        let worker_id = worker_id.clone();
+        let idempotency_key = idempotency_key.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "change_invocation_priority",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let idempotency_key = idempotency_key.clone();
+                Box::pin(worker_executor_client.change_invocation_priority(ChangeInvocationPriorityRequest {
+                    worker_id: Some(worker_id.into()),
+                    idempotency_key: Some(idempotency_key.into()),
+                    priority: golem_api_grpc::proto::golem::worker::InvocationPriority::from(priority) as i32,
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::ChangeInvocationPriorityResponse {
+                    result: Some(workerexecutor::v1::change_invocation_priority_response::Result::Success(changed)),
+                } => Ok(changed),
+                workerexecutor::v1::ChangeInvocationPriorityResponse {
+                    result: Some(workerexecutor::v1::change_invocation_priority_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::ChangeInvocationPriorityResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn purge_pending_invocations(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<IdempotencyKey>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "purge_pending_invocations",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.purge_pending_invocations(PurgePendingInvocationsRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::PurgePendingInvocationsResponse {
+                    result: Some(workerexecutor::v1::purge_pending_invocations_response::Result::Success(success)),
+                } => Ok(success.canceled.into_iter().map(|key| key.into()).collect()),
+                workerexecutor::v1::PurgePendingInvocationsResponse {
+                    result: Some(workerexecutor::v1::purge_pending_invocations_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::PurgePendingInvocationsResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn get_sagas(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<Saga>> {
+        let worker_id = worker_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_sagas",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.get_sagas(GetSagasRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetSagasResponse {
+                    result: Some(workerexecutor::v1::get_sagas_response::Result::Success(success)),
+                } => success
+                    .sagas
+                    .into_iter()
+                    .map(|saga| saga.try_into())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::GetSagasResponse {
+                    result: Some(workerexecutor::v1::get_sagas_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetSagasResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn abort_saga(
+        &self,
+        worker_id: &WorkerId,
+        saga_id: &SagaId,
+        reason: Option<String>,
+        namespace: Namespace,
+    ) -> WorkerResult<Saga> {
+        let worker_id = worker_id.clone();
+        let saga_id = saga_id.clone();
+        self.call_worker_executor(
+            worker_id.clone(),
+            "abort_saga",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.abort_saga(AbortSagaRequest {
+                    worker_id: Some(worker_id.clone().into()),
+                    saga_id: Some(saga_id.clone().into()),
+                    reason: reason.clone(),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::AbortSagaResponse {
+                    result: Some(workerexecutor::v1::abort_saga_response::Result::Success(saga)),
+                } => saga
+                    .try_into()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::AbortSagaResponse {
+                    result: Some(workerexecutor::v1::abort_saga_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::AbortSagaResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
+
+    async fn schedule_invocation(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<ProtoVal>,
+        schedule: InvocationScheduleSpec,
+        invocation_context: Option<InvocationContext>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationSchedule> {
+        let resource_limits = self.get_resource_limits(&namespace).await?;
+        let invocation = workerexecutor::v1::schedule_invocation_request::Invocation::Invoke(
+            workerexecutor::v1::InvokeWorkerRequest {
+                worker_id: Some(worker_id.clone().into_target_worker_id().into()),
+                idempotency_key: None,
+                name: function_name,
+                input: params,
+                account_id: Some(namespace.account_id.clone().into()),
+                account_limits: Some(resource_limits.into()),
+                context: invocation_context,
+                project_id: Some(namespace.project_id.clone().into()),
+                priority: golem_api_grpc::proto::golem::worker::InvocationPriority::Normal as i32,
+            },
+        );
+        self.create_invocation_schedule(worker_id, invocation, schedule)
+            .await
+    }
+
+    async fn schedule_invocation_json(
+        &self,
+        worker_id: &WorkerId,
+        function_name: String,
+        params: Vec<String>,
+        schedule: InvocationScheduleSpec,
+        invocation_context: Option<InvocationContext>,
+        namespace: Namespace,
+    ) -> WorkerResult<InvocationSchedule> {
+        let resource_limits = self.get_resource_limits(&namespace).await?;
+        let invocation = workerexecutor::v1::schedule_invocation_request::Invocation::InvokeJson(
+            workerexecutor::v1::InvokeJsonWorkerRequest {
+                worker_id: Some(worker_id.clone().into_target_worker_id().into()),
+                idempotency_key: None,
+                name: function_name,
+                input: params,
+                account_id: Some(namespace.account_id.clone().into()),
+                account_limits: Some(resource_limits.into()),
+                context: invocation_context,
+                project_id: Some(namespace.project_id.clone().into()),
+                priority: golem_api_grpc::proto::golem::worker::InvocationPriority::Normal as i32,
+            },
+        );
+        self.create_invocation_schedule(worker_id, invocation, schedule)
+            .await
+    }
+
+    async fn get_invocation_schedules(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<InvocationSchedule>> {
+        let worker_id = worker_id.clone();
+        let schedules = self.call_worker_executor(
+            worker_id.clone(),
+            "get_invocation_schedules",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                Box::pin(worker_executor_client.get_invocation_schedules(GetInvocationSchedulesRequest {
+                    worker_id: Some(worker_id.into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetInvocationSchedulesResponse {
+                    result: Some(workerexecutor::v1::get_invocation_schedules_response::Result::Success(success)),
+                } => success
+                    .schedules
+                    .into_iter()
+                    .map(|schedule| schedule.try_into())
+                    .collect::<Result<Vec<InvocationSchedule>, String>>()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::GetInvocationSchedulesResponse {
+                    result: Some(workerexecutor::v1::get_invocation_schedules_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetInvocationSchedulesResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(schedules)
+    }
+
+    async fn cancel_invocation_schedule(
+        &self,
+        worker_id: &WorkerId,
+        schedule_id: &InvocationScheduleId,
+        namespace: Namespace,
+    ) -> WorkerResult<bool> {
+        let worker_id = worker_id.clone();
+        let schedule_id = schedule_id.clone();
+        let canceled = self.call_worker_executor(
+            worker_id.clone(),
+            "cancel_invocation_schedule",
+            move |worker_executor_client| {
+                let worker_id = worker_id.clone();
+                let schedule_id = schedule_id.clone();
+                Box::pin(worker_executor_client.cancel_invocation_schedule(CancelInvocationScheduleRequest {
+                    worker_id: Some(worker_id.into()),
+                    schedule_id: Some(schedule_id.into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::CancelInvocationScheduleResponse {
+                    result: Some(workerexecutor::v1::cancel_invocation_schedule_response::Result::Success(canceled)),
+                } => Ok(canceled),
+                workerexecutor::v1::CancelInvocationScheduleResponse {
+                    result: Some(workerexecutor::v1::cancel_invocation_schedule_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::CancelInvocationScheduleResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await?;
+        Ok(canceled)
+    }
+
+    async fn set_component_ttl_policy(
+        &self,
+        component_id: &ComponentId,
+        policy: Option<WorkerTtlPolicy>,
+        namespace: Namespace,
+    ) -> WorkerResult<()> {
+        let target = WorkerTtlPolicyTarget {
+            target: Some(worker_ttl_policy_target::Target::ComponentId(
+                component_id.clone().into(),
+            )),
+        };
+        self.call_worker_executor(
+            RandomExecutor,
+            "set_worker_ttl_policy",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.set_worker_ttl_policy(SetWorkerTtlPolicyRequest {
+                    target: Some(target.clone()),
+                    policy: policy.clone().map(|policy| policy.into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::SetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::set_worker_ttl_policy_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::SetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::set_worker_ttl_policy_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::SetWorkerTtlPolicyResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn get_component_ttl_policy(
+        &self,
+        component_id: &ComponentId,
+        namespace: Namespace,
+    ) -> WorkerResult<Option<WorkerTtlPolicy>> {
+        let target = WorkerTtlPolicyTarget {
+            target: Some(worker_ttl_policy_target::Target::ComponentId(
+                component_id.clone().into(),
+            )),
+        };
+        self.call_worker_executor(
+            RandomExecutor,
+            "get_worker_ttl_policy",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.get_worker_ttl_policy(GetWorkerTtlPolicyRequest {
+                    target: Some(target.clone()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::get_worker_ttl_policy_response::Result::Success(success)),
+                } => Ok(success.policy.map(|policy| policy.into())),
+                workerexecutor::v1::GetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::get_worker_ttl_policy_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetWorkerTtlPolicyResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn set_worker_ttl_policy(
+        &self,
+        worker_id: &WorkerId,
+        policy: Option<WorkerTtlPolicy>,
+        namespace: Namespace,
+    ) -> WorkerResult<()> {
+        let target = WorkerTtlPolicyTarget {
+            target: Some(worker_ttl_policy_target::Target::WorkerId(
+                worker_id.clone().into(),
+            )),
+        };
+        self.call_worker_executor(
+            worker_id.clone(),
+            "set_worker_ttl_policy",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.set_worker_ttl_policy(SetWorkerTtlPolicyRequest {
+                    target: Some(target.clone()),
+                    policy: policy.clone().map(|policy| policy.into()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::SetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::set_worker_ttl_policy_response::Result::Success(_)),
+                } => Ok(()),
+                workerexecutor::v1::SetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::set_worker_ttl_policy_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::SetWorkerTtlPolicyResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn get_worker_ttl_policy(
+        &self,
+        worker_id: &WorkerId,
+        namespace: Namespace,
+    ) -> WorkerResult<Option<WorkerTtlPolicy>> {
+        let target = WorkerTtlPolicyTarget {
+            target: Some(worker_ttl_policy_target::Target::WorkerId(
+                worker_id.clone().into(),
+            )),
+        };
+        self.call_worker_executor(
+            worker_id.clone(),
+            "get_worker_ttl_policy",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.get_worker_ttl_policy(GetWorkerTtlPolicyRequest {
+                    target: Some(target.clone()),
+                    account_id: Some(namespace.account_id.clone().into()),
+                    project_id: Some(namespace.project_id.clone().into()),
+                }))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::get_worker_ttl_policy_response::Result::Success(success)),
+                } => Ok(success.policy.map(|policy| policy.into())),
+                workerexecutor::v1::GetWorkerTtlPolicyResponse {
+                    result: Some(workerexecutor::v1::get_worker_ttl_policy_response::Result::Failure(err)),
+                } => Err(err.into()),
+                workerexecutor::v1::GetWorkerTtlPolicyResponse { .. } => Err("Empty response".into()),
+            },
+            WorkerServiceError::InternalCallError,
+        )
+            .await
+    }
+
+    async fn get_ttl_deletions(
+        &self,
+        component_id: &ComponentId,
+        namespace: Namespace,
+    ) -> WorkerResult<Vec<WorkerTtlDeletion>> {
+        let component_id = component_id.clone();
+        self.call_worker_executor(
+            RandomExecutor,
+            "get_worker_ttl_deletions",
+            move |worker_executor_client| {
+                Box::pin(worker_executor_client.get_worker_ttl_deletions(
+                    GetWorkerTtlDeletionsRequest {
+                        component_id: Some(component_id.clone().into()),
+                        account_id: Some(namespace.account_id.clone().into()),
+                        project_id: Some(namespace.project_id.clone().into()),
+                    },
+                ))
+            },
+            |response| match response.into_inner() {
+                workerexecutor::v1::GetWorkerTtlDeletionsResponse {
+                    result:
+                        Some(workerexecutor::v1::get_worker_ttl_deletions_response::Result::Success(
+                            success,
+                        )),
+                } => success
+                    .deletions
+                    .into_iter()
+                    .map(|deletion| deletion.try_into())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| WorkerServiceError::Internal(err).into()),
+                workerexecutor::v1::GetWorkerTtlDeletionsResponse {
+                    result:
+                        Some(workerexecutor::v1::get_worker_ttl_deletions_response::Result::Failure(
+                            err,
+                        )),
+                } => Err(err.into()),
+                workerexecutor::v1::GetWorkerTtlDeletionsResponse { .. } => {
+                    Err("Empty response".into())
+                }
+            },
+            WorkerServiceError::InternalCallError,
+        )
+        .await
+    }
 }
 
 #[derive(Clone)]
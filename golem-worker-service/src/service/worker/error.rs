@@ -93,7 +93,8 @@ impl From<WorkerServiceError> for golem_api_grpc::proto::golem::worker::v1::work
             | WorkerServiceError::VersionedComponentIdNotFound(_)
             | WorkerServiceError::WorkerNotFound(_)
             | WorkerServiceError::FileNotFound(_)
-            | WorkerServiceError::GolemError(WorkerExecutorError::WorkerNotFound { .. }) => {
+            | WorkerServiceError::GolemError(WorkerExecutorError::WorkerNotFound { .. })
+            | WorkerServiceError::GolemError(WorkerExecutorError::InvocationNotFound { .. }) => {
                 Self::NotFound(ErrorBody {
                     error: error.to_safe_string(),
                 })
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bulk_operation;
 mod connect;
 mod connect_proxy;
 mod default;
@@ -20,6 +21,7 @@ mod invocation_parameters;
 mod routing_logic;
 mod worker_stream;
 
+pub use bulk_operation::*;
 pub use connect::*;
 pub use connect_proxy::*;
 pub use default::*;
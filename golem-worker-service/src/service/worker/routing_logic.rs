@@ -334,7 +334,8 @@ impl From<WorkerExecutorError> for ResponseMapResult {
             },
             WorkerExecutorError::ShardingNotReady => ResponseMapResult::ShardingNotReady,
             WorkerExecutorError::WorkerNotFound { .. }
-            | WorkerExecutorError::WorkerAlreadyExists { .. } => {
+            | WorkerExecutorError::WorkerAlreadyExists { .. }
+            | WorkerExecutorError::InvocationNotFound { .. } => {
                 ResponseMapResult::Expected(error.into())
             }
             other => ResponseMapResult::Other(other.into()),
@@ -846,6 +846,144 @@ async fn test_api_def_with_request_body_2() {
     assert_eq!(result, expected);
 }
 
+#[test]
+async fn test_api_def_with_json_request_body() {
+    let empty_headers = HeaderMap::new();
+
+    let api_request = get_gateway_request(
+        "/foo/john",
+        None,
+        &empty_headers,
+        serde_json::json!({"user": {"name": "address"}}),
+    );
+
+    let response_mapping = r#"
+        let body: json = request.body;
+        let worker = instance("shopping-cart-1");
+        let response = worker.get-cart-contents(body["user"]["name"], body["user"]["name"]);
+        response
+    "#;
+
+    let api_specification: HttpApiDefinition =
+        get_api_def_with_worker_binding("/foo/{user-id}", response_mapping).await;
+
+    let session_store = internal::get_session_store();
+
+    let test_response = execute(
+        api_request,
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+    )
+    .await;
+
+    let test_response = internal::get_details_from_response(test_response).await;
+
+    let result = (
+        test_response.worker_name,
+        test_response.function_name,
+        test_response.function_params,
+    );
+
+    let expected = (
+        "shopping-cart-1".to_string(),
+        "golem:it/api.{get-cart-contents}".to_string(),
+        JsonValue::Array(vec![
+            JsonValue::String("address".to_string()),
+            JsonValue::String("address".to_string()),
+        ]),
+    );
+
+    assert_eq!(result, expected);
+}
+
+// Only the parts of the request body declared as `json` are passed as json text
+#[test]
+async fn test_api_def_with_json_request_body_field() {
+    let empty_headers = HeaderMap::new();
+
+    let api_request = get_gateway_request(
+        "/foo/john",
+        None,
+        &empty_headers,
+        serde_json::json!({"user": {"name": "address"}, "cart": "cart-1"}),
+    );
+
+    let response_mapping = r#"
+        let user: json = request.body.user;
+        let worker = instance("shopping-cart-1");
+        let response = worker.get-cart-contents(user["name"], request.body.cart);
+        response
+    "#;
+
+    let api_specification: HttpApiDefinition =
+        get_api_def_with_worker_binding("/foo/{user-id}", response_mapping).await;
+
+    let session_store = internal::get_session_store();
+
+    let test_response = execute(
+        api_request,
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+    )
+    .await;
+
+    let test_response = internal::get_details_from_response(test_response).await;
+
+    let result = (
+        test_response.worker_name,
+        test_response.function_name,
+        test_response.function_params,
+    );
+
+    let expected = (
+        "shopping-cart-1".to_string(),
+        "golem:it/api.{get-cart-contents}".to_string(),
+        JsonValue::Array(vec![
+            JsonValue::String("address".to_string()),
+            JsonValue::String("cart-1".to_string()),
+        ]),
+    );
+
+    assert_eq!(result, expected);
+}
+
+// A string typed request body is not coerced from other json values
+// unless it is declared as `json` in the rib script
+#[test]
+async fn test_api_def_with_non_string_request_body_for_string() {
+    let empty_headers = HeaderMap::new();
+
+    let api_request = get_gateway_request(
+        "/foo/john",
+        None,
+        &empty_headers,
+        serde_json::json!({"user": {"name": "address"}}),
+    );
+
+    let response_mapping = r#"
+        let worker = instance("shopping-cart-1");
+        let response = worker.get-cart-contents(request.body, request.body);
+        response
+    "#;
+
+    let api_specification: HttpApiDefinition =
+        get_api_def_with_worker_binding("/foo/{user-id}", response_mapping).await;
+
+    let session_store = internal::get_session_store();
+
+    let response = execute(
+        api_request,
+        &api_specification,
+        &session_store,
+        &TestIdentityProvider::default(),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 // A test where one of the keys in the request body is expected to be a number
 // based on rib script, but the actual value is a string
 #[test]
@@ -25,6 +25,9 @@ use golem_service_base::db;
 use golem_service_base::db::postgres::PostgresPool;
 use golem_service_base::db::sqlite::SqlitePool;
 use golem_service_base::migration::{Migrations, MigrationsDir};
+use golem_service_base::model::bulk_operation::{
+    BulkDeleteOperation, BulkOperation, BulkOperationId, BulkOperationKind, BulkOperationStatus,
+};
 use golem_service_base::model::{Component, ComponentName};
 use golem_wasm_ast::analysis::analysed_type::str;
 use golem_wasm_ast::analysis::{
@@ -47,6 +50,9 @@ use golem_worker_service::gateway_security::{
 use golem_worker_service::model::{ApiDomain, Certificate, CertificateRequest, DomainRequest};
 use golem_worker_service::repo::api_certificate::{ApiCertificateRepo, DbApiCertificateRepo};
 use golem_worker_service::repo::api_domain::{ApiDomainRepo, DbApiDomainRepo};
+use golem_worker_service::repo::bulk_operation::{
+    BulkOperationRecord, BulkOperationRepo, DbBulkOperationRepo,
+};
 use golem_worker_service::repo::security_scheme::{DbSecuritySchemeRepo, SecuritySchemeRepo};
 use golem_worker_service::repo::{api_definition, api_deployment};
 use golem_worker_service::service::api_certificate::{
@@ -617,12 +623,16 @@ pub async fn test_with_postgres_db() {
 
     let api_domain_repo: Arc<dyn ApiDomainRepo> = Arc::new(DbApiDomainRepo::new(db_pool.clone()));
 
+    let bulk_operation_repo: Arc<dyn BulkOperationRepo> =
+        Arc::new(DbBulkOperationRepo::new(db_pool.clone()));
+
     test_services(
         api_definition_repo,
         api_deployment_repo,
         security_scheme_repo,
         api_certificate_repo,
         api_domain_repo,
+        bulk_operation_repo,
     )
     .await;
 }
@@ -805,12 +815,16 @@ pub async fn test_with_sqlite_db() {
 
     let api_domain_repo: Arc<dyn ApiDomainRepo> = Arc::new(DbApiDomainRepo::new(db_pool.clone()));
 
+    let bulk_operation_repo: Arc<dyn BulkOperationRepo> =
+        Arc::new(DbBulkOperationRepo::new(db_pool.clone()));
+
     test_services(
         api_definition_repo,
         api_deployment_repo,
         security_scheme_repo,
         api_certificate_repo,
         api_domain_repo,
+        bulk_operation_repo,
     )
     .await;
 }
@@ -821,6 +835,7 @@ async fn test_services(
     security_scheme_repo: Arc<dyn SecuritySchemeRepo>,
     api_certificate_repo: Arc<dyn ApiCertificateRepo>,
     api_domain_repo: Arc<dyn ApiDomainRepo>,
+    bulk_operation_repo: Arc<dyn BulkOperationRepo>,
 ) {
     let component_service: Arc<dyn ComponentService> = Arc::new(TestComponentService {});
 
@@ -877,6 +892,8 @@ async fn test_services(
     test_deployment_conflict(definition_service.clone(), deployment_service.clone()).await;
     test_certificate_service(certificate_service).await;
     test_domain_service(domain_service).await;
+    test_bulk_operation_repo(bulk_operation_repo.clone()).await;
+    test_bulk_operation_repo_claims_abandoned_operations(bulk_operation_repo).await;
 }
 
 async fn test_deployment(
@@ -1591,3 +1608,123 @@ async fn test_domain_service(domain_service: Arc<dyn ApiDomainService>) {
     assert_eq!(result[0].name_servers, domain.name_servers);
     assert!(result2.is_empty());
 }
+
+async fn test_bulk_operation_repo(bulk_operation_repo: Arc<dyn BulkOperationRepo>) {
+    let namespace = test_namespace().to_string();
+
+    let operation = BulkOperation {
+        operation_id: BulkOperationId::new_v4(),
+        component_id: ComponentId::new_v4(),
+        filter: None,
+        operation: BulkOperationKind::Delete(BulkDeleteOperation {}),
+        concurrency: 4,
+        status: BulkOperationStatus::Running,
+        total: None,
+        succeeded: 0,
+        failed: 0,
+        error: None,
+        created_at: Utc::now(),
+        finished_at: None,
+    };
+    let id = operation.operation_id.0;
+
+    bulk_operation_repo
+        .create(&BulkOperationRecord::new(namespace.clone(), &operation).unwrap())
+        .await
+        .unwrap();
+
+    bulk_operation_repo.set_total(&id, 2).await.unwrap();
+    bulk_operation_repo
+        .add_result(&id, "worker-1", None)
+        .await
+        .unwrap();
+    bulk_operation_repo
+        .add_result(&id, "worker-2", Some("failed".to_string()))
+        .await
+        .unwrap();
+    bulk_operation_repo
+        .finish(&id, BulkOperationStatus::Completed, None, Utc::now())
+        .await
+        .unwrap();
+
+    let result: BulkOperation = bulk_operation_repo
+        .get(&namespace, &id)
+        .await
+        .unwrap()
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    assert_eq!(result.operation, operation.operation);
+    assert_eq!(result.status, BulkOperationStatus::Completed);
+    assert_eq!(result.total, Some(2));
+    assert_eq!(result.succeeded, 1);
+    assert_eq!(result.failed, 1);
+    assert!(result.finished_at.is_some());
+
+    let all = bulk_operation_repo
+        .get_all(&namespace, &operation.component_id.0)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 1);
+
+    let results = bulk_operation_repo.get_results(&id).await.unwrap();
+    assert_eq!(results.len(), 2);
+
+    let other_namespace = bulk_operation_repo
+        .get("other-namespace", &id)
+        .await
+        .unwrap();
+    assert!(other_namespace.is_none());
+}
+
+async fn test_bulk_operation_repo_claims_abandoned_operations(
+    bulk_operation_repo: Arc<dyn BulkOperationRepo>,
+) {
+    let namespace = test_namespace().to_string();
+    let started_at = Utc::now() - chrono::Duration::minutes(10);
+
+    let new_operation = |status: BulkOperationStatus| BulkOperation {
+        operation_id: BulkOperationId::new_v4(),
+        component_id: ComponentId::new_v4(),
+        filter: None,
+        operation: BulkOperationKind::Delete(BulkDeleteOperation {}),
+        concurrency: 4,
+        status,
+        total: None,
+        succeeded: 0,
+        failed: 0,
+        error: None,
+        created_at: started_at,
+        finished_at: None,
+    };
+    let abandoned = new_operation(BulkOperationStatus::Running);
+    let alive = new_operation(BulkOperationStatus::Running);
+    let completed = new_operation(BulkOperationStatus::Completed);
+
+    for operation in [&abandoned, &alive, &completed] {
+        bulk_operation_repo
+            .create(&BulkOperationRecord::new(namespace.clone(), operation).unwrap())
+            .await
+            .unwrap();
+    }
+    bulk_operation_repo
+        .heartbeat(&alive.operation_id.0, Utc::now())
+        .await
+        .unwrap();
+
+    let stale_before = Utc::now() - chrono::Duration::minutes(1);
+    let claimed = bulk_operation_repo
+        .claim_abandoned(stale_before, Utc::now())
+        .await
+        .unwrap();
+    let claimed_again = bulk_operation_repo
+        .claim_abandoned(stale_before, Utc::now())
+        .await
+        .unwrap();
+
+    let claimed_ids = claimed.iter().map(|record| record.id).collect::<Vec<_>>();
+    assert_eq!(claimed_ids, vec![abandoned.operation_id.0]);
+    assert_eq!(claimed[0].namespace, namespace);
+    assert!(claimed_again.is_empty());
+}
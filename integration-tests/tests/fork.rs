@@ -497,6 +497,47 @@ async fn fork_worker_with_invalid_oplog_index_cut_off(
     assert!(error.contains("oplog_index_cut_off must be at least 2"));
 }
 
+#[test]
+#[tracing::instrument]
+#[timeout(120000)]
+async fn fork_worker_with_oplog_index_cut_off_beyond_end(
+    deps: &EnvBasedTestDependencies,
+    _tracing: &Tracing,
+) {
+    let admin = deps.admin().await;
+    let component_id = admin.component("shopping-cart").store().await;
+
+    let source_worker_id = WorkerId {
+        component_id: component_id.clone(),
+        worker_name: Uuid::new_v4().to_string(),
+    };
+
+    let _ = admin
+        .invoke_and_await(
+            &source_worker_id,
+            "golem:it/api.{initialize-cart}",
+            vec!["test-user-1".into_value_and_type()],
+        )
+        .await;
+
+    let target_worker_id = WorkerId {
+        component_id: component_id.clone(),
+        worker_name: Uuid::new_v4().to_string(),
+    };
+
+    let error = golem_test_framework::dsl::TestDsl::fork_worker(
+        &admin,
+        &source_worker_id,
+        &target_worker_id,
+        OplogIndex::from_u64(10000),
+    )
+    .await
+    .unwrap_err()
+    .to_string();
+
+    assert!(error.contains("must not be greater than the last oplog index"));
+}
+
 #[test]
 #[tracing::instrument]
 #[timeout(120000)]
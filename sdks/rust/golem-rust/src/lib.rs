@@ -54,6 +54,8 @@ pub mod load_snapshot {
             "golem:durability/durability@1.2.1": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@0.0.1": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@0.0.1": crate::bindings::golem::rdbms::postgres,
+            "golem:rdbms/sqlite@0.0.1": crate::bindings::golem::rdbms::sqlite,
+            "golem:rdbms/mssql@0.0.1": crate::bindings::golem::rdbms::mssql,
             "golem:rdbms/types@0.0.1": crate::bindings::golem::rdbms::types,
             "wasi:blobstore/blobstore": crate::bindings::wasi::blobstore::blobstore,
             "wasi:blobstore/container": crate::bindings::wasi::blobstore::container,
@@ -100,6 +102,8 @@ pub mod save_snapshot {
             "golem:durability/durability@1.2.1": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@0.0.1": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@0.0.1": crate::bindings::golem::rdbms::postgres,
+            "golem:rdbms/sqlite@0.0.1": crate::bindings::golem::rdbms::sqlite,
+            "golem:rdbms/mssql@0.0.1": crate::bindings::golem::rdbms::mssql,
             "golem:rdbms/types@0.0.1": crate::bindings::golem::rdbms::types,
             "wasi:blobstore/blobstore": crate::bindings::wasi::blobstore::blobstore,
             "wasi:blobstore/container": crate::bindings::wasi::blobstore::container,
@@ -146,6 +150,8 @@ pub mod oplog_processor {
             "golem:durability/durability@1.2.1": crate::bindings::golem::durability::durability,
             "golem:rdbms/mysql@0.0.1": crate::bindings::golem::rdbms::mysql,
             "golem:rdbms/postgres@0.0.1": crate::bindings::golem::rdbms::postgres,
+            "golem:rdbms/sqlite@0.0.1": crate::bindings::golem::rdbms::sqlite,
+            "golem:rdbms/mssql@0.0.1": crate::bindings::golem::rdbms::mssql,
             "golem:rdbms/types@0.0.1": crate::bindings::golem::rdbms::types,
             "wasi:blobstore/blobstore": crate::bindings::wasi::blobstore::blobstore,
             "wasi:blobstore/container": crate::bindings::wasi::blobstore::container,
@@ -0,0 +1,44 @@
+#[allow(static_mut_refs)]
+mod bindings;
+
+use crate::bindings::exports::golem::it::api::Guest;
+use crate::bindings::golem::api::pubsub;
+
+struct State {
+    received: Vec<(String, String, Vec<u8>)>,
+}
+
+static mut STATE: State = State {
+    received: Vec::new(),
+};
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    unsafe { f(&mut STATE) }
+}
+
+struct Component;
+
+impl Guest for Component {
+    fn subscribe(topic: String) -> Result<(), String> {
+        pubsub::subscribe(&topic, "golem:it/api.{on-message}")
+    }
+
+    fn unsubscribe(topic: String) {
+        pubsub::unsubscribe(&topic)
+    }
+
+    fn publish(topic: String, payload: Vec<u8>) -> u32 {
+        pubsub::publish(&topic, &payload)
+    }
+
+    fn on_message(topic: String, message_id: String, payload: Vec<u8>) {
+        println!("Received message {message_id} on topic {topic}");
+        with_state(|state| state.received.push((topic, message_id, payload)));
+    }
+
+    fn get_received() -> Vec<(String, String, Vec<u8>)> {
+        with_state(|state| state.received.clone())
+    }
+}
+
+bindings::export!(Component with_types_in bindings);
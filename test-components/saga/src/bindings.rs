@@ -0,0 +1,10026 @@
+// Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
+// Options used:
+//   * runtime_path: "wit_bindgen_rt"
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod golem {
+    pub mod api {
+        /// The Golem host API provides low level access to Golem specific features such as promises and control over
+        /// the durability and transactional guarantees the executor provides.
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod host {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            pub type Duration = super::super::super::wasi::clocks::monotonic_clock::Duration;
+            pub type ComponentId = super::super::super::golem::rpc::types::ComponentId;
+            pub type Uuid = super::super::super::golem::rpc::types::Uuid;
+            pub type WorkerId = super::super::super::golem::rpc::types::WorkerId;
+            /// An index into the persistent log storing all performed operations of a worker
+            pub type OplogIndex = u64;
+            /// A promise ID is a value that can be passed to an external Golem API to complete that promise
+            /// from an arbitrary external source, while Golem workers can await for this completion.
+            #[derive(Clone)]
+            pub struct PromiseId {
+                pub worker_id: WorkerId,
+                pub oplog_idx: OplogIndex,
+            }
+            impl ::core::fmt::Debug for PromiseId {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("PromiseId")
+                        .field("worker-id", &self.worker_id)
+                        .field("oplog-idx", &self.oplog_idx)
+                        .finish()
+                }
+            }
+            /// Represents a Golem component's version
+            pub type ComponentVersion = u64;
+            /// Configures how the executor retries failures
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct RetryPolicy {
+                /// The maximum number of retries before the worker becomes permanently failed
+                pub max_attempts: u32,
+                /// The minimum delay between retries (applied to the first retry)
+                pub min_delay: Duration,
+                /// The maximum delay between retries
+                pub max_delay: Duration,
+                /// Multiplier applied to the delay on each retry to implement exponential backoff
+                pub multiplier: f64,
+                /// The maximum amount of jitter to add to the delay
+                pub max_jitter_factor: Option<f64>,
+            }
+            impl ::core::fmt::Debug for RetryPolicy {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RetryPolicy")
+                        .field("max-attempts", &self.max_attempts)
+                        .field("min-delay", &self.min_delay)
+                        .field("max-delay", &self.max_delay)
+                        .field("multiplier", &self.multiplier)
+                        .field("max-jitter-factor", &self.max_jitter_factor)
+                        .finish()
+                }
+            }
+            /// Configurable persistence level for workers
+            #[derive(Clone, Copy)]
+            pub enum PersistenceLevel {
+                PersistNothing,
+                PersistRemoteSideEffects,
+                Smart,
+            }
+            impl ::core::fmt::Debug for PersistenceLevel {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        PersistenceLevel::PersistNothing => {
+                            f.debug_tuple("PersistenceLevel::PersistNothing").finish()
+                        }
+                        PersistenceLevel::PersistRemoteSideEffects => {
+                            f.debug_tuple("PersistenceLevel::PersistRemoteSideEffects")
+                                .finish()
+                        }
+                        PersistenceLevel::Smart => {
+                            f.debug_tuple("PersistenceLevel::Smart").finish()
+                        }
+                    }
+                }
+            }
+            /// Describes how to update a worker to a different component version
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum UpdateMode {
+                /// Automatic update tries to recover the worker using the new component version
+                /// and may fail if there is a divergence.
+                Automatic,
+                /// Manual, snapshot-based update uses a user-defined implementation of the `save-snapshot` interface
+                /// to store the worker's state, and a user-defined implementation of the `load-snapshot` interface to
+                /// load it into the new version.
+                SnapshotBased,
+            }
+            impl ::core::fmt::Debug for UpdateMode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        UpdateMode::Automatic => {
+                            f.debug_tuple("UpdateMode::Automatic").finish()
+                        }
+                        UpdateMode::SnapshotBased => {
+                            f.debug_tuple("UpdateMode::SnapshotBased").finish()
+                        }
+                    }
+                }
+            }
+            impl UpdateMode {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> UpdateMode {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => UpdateMode::Automatic,
+                        1 => UpdateMode::SnapshotBased,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum FilterComparator {
+                Equal,
+                NotEqual,
+                GreaterEqual,
+                Greater,
+                LessEqual,
+                Less,
+            }
+            impl ::core::fmt::Debug for FilterComparator {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        FilterComparator::Equal => {
+                            f.debug_tuple("FilterComparator::Equal").finish()
+                        }
+                        FilterComparator::NotEqual => {
+                            f.debug_tuple("FilterComparator::NotEqual").finish()
+                        }
+                        FilterComparator::GreaterEqual => {
+                            f.debug_tuple("FilterComparator::GreaterEqual").finish()
+                        }
+                        FilterComparator::Greater => {
+                            f.debug_tuple("FilterComparator::Greater").finish()
+                        }
+                        FilterComparator::LessEqual => {
+                            f.debug_tuple("FilterComparator::LessEqual").finish()
+                        }
+                        FilterComparator::Less => {
+                            f.debug_tuple("FilterComparator::Less").finish()
+                        }
+                    }
+                }
+            }
+            impl FilterComparator {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> FilterComparator {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => FilterComparator::Equal,
+                        1 => FilterComparator::NotEqual,
+                        2 => FilterComparator::GreaterEqual,
+                        3 => FilterComparator::Greater,
+                        4 => FilterComparator::LessEqual,
+                        5 => FilterComparator::Less,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum StringFilterComparator {
+                Equal,
+                NotEqual,
+                Like,
+                NotLike,
+            }
+            impl ::core::fmt::Debug for StringFilterComparator {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        StringFilterComparator::Equal => {
+                            f.debug_tuple("StringFilterComparator::Equal").finish()
+                        }
+                        StringFilterComparator::NotEqual => {
+                            f.debug_tuple("StringFilterComparator::NotEqual").finish()
+                        }
+                        StringFilterComparator::Like => {
+                            f.debug_tuple("StringFilterComparator::Like").finish()
+                        }
+                        StringFilterComparator::NotLike => {
+                            f.debug_tuple("StringFilterComparator::NotLike").finish()
+                        }
+                    }
+                }
+            }
+            impl StringFilterComparator {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> StringFilterComparator {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => StringFilterComparator::Equal,
+                        1 => StringFilterComparator::NotEqual,
+                        2 => StringFilterComparator::Like,
+                        3 => StringFilterComparator::NotLike,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum WorkerStatus {
+                /// The worker is running an invoked function
+                Running,
+                /// The worker is ready to run an invoked function
+                Idle,
+                /// An invocation is active but waiting for something (sleeping, waiting for a promise)
+                Suspended,
+                /// The last invocation was interrupted but will be resumed
+                Interrupted,
+                /// The last invocation failed and a retry was scheduled
+                Retrying,
+                /// The last invocation failed and the worker can no longer be used
+                Failed,
+                /// The worker exited after a successful invocation and can no longer be invoked
+                Exited,
+            }
+            impl ::core::fmt::Debug for WorkerStatus {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        WorkerStatus::Running => {
+                            f.debug_tuple("WorkerStatus::Running").finish()
+                        }
+                        WorkerStatus::Idle => {
+                            f.debug_tuple("WorkerStatus::Idle").finish()
+                        }
+                        WorkerStatus::Suspended => {
+                            f.debug_tuple("WorkerStatus::Suspended").finish()
+                        }
+                        WorkerStatus::Interrupted => {
+                            f.debug_tuple("WorkerStatus::Interrupted").finish()
+                        }
+                        WorkerStatus::Retrying => {
+                            f.debug_tuple("WorkerStatus::Retrying").finish()
+                        }
+                        WorkerStatus::Failed => {
+                            f.debug_tuple("WorkerStatus::Failed").finish()
+                        }
+                        WorkerStatus::Exited => {
+                            f.debug_tuple("WorkerStatus::Exited").finish()
+                        }
+                    }
+                }
+            }
+            impl WorkerStatus {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> WorkerStatus {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => WorkerStatus::Running,
+                        1 => WorkerStatus::Idle,
+                        2 => WorkerStatus::Suspended,
+                        3 => WorkerStatus::Interrupted,
+                        4 => WorkerStatus::Retrying,
+                        5 => WorkerStatus::Failed,
+                        6 => WorkerStatus::Exited,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct WorkerNameFilter {
+                pub comparator: StringFilterComparator,
+                pub value: _rt::String,
+            }
+            impl ::core::fmt::Debug for WorkerNameFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerNameFilter")
+                        .field("comparator", &self.comparator)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct WorkerStatusFilter {
+                pub comparator: FilterComparator,
+                pub value: WorkerStatus,
+            }
+            impl ::core::fmt::Debug for WorkerStatusFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerStatusFilter")
+                        .field("comparator", &self.comparator)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct WorkerVersionFilter {
+                pub comparator: FilterComparator,
+                pub value: u64,
+            }
+            impl ::core::fmt::Debug for WorkerVersionFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerVersionFilter")
+                        .field("comparator", &self.comparator)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct WorkerCreatedAtFilter {
+                pub comparator: FilterComparator,
+                pub value: u64,
+            }
+            impl ::core::fmt::Debug for WorkerCreatedAtFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerCreatedAtFilter")
+                        .field("comparator", &self.comparator)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct WorkerEnvFilter {
+                pub name: _rt::String,
+                pub comparator: StringFilterComparator,
+                pub value: _rt::String,
+            }
+            impl ::core::fmt::Debug for WorkerEnvFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerEnvFilter")
+                        .field("name", &self.name)
+                        .field("comparator", &self.comparator)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct WorkerWasiConfigVarsFilter {
+                pub name: _rt::String,
+                pub comparator: StringFilterComparator,
+                pub value: _rt::String,
+            }
+            impl ::core::fmt::Debug for WorkerWasiConfigVarsFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerWasiConfigVarsFilter")
+                        .field("name", &self.name)
+                        .field("comparator", &self.comparator)
+                        .field("value", &self.value)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum WorkerPropertyFilter {
+                Name(WorkerNameFilter),
+                Status(WorkerStatusFilter),
+                Version(WorkerVersionFilter),
+                CreatedAt(WorkerCreatedAtFilter),
+                Env(WorkerEnvFilter),
+                WasiConfigVars(WorkerWasiConfigVarsFilter),
+            }
+            impl ::core::fmt::Debug for WorkerPropertyFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        WorkerPropertyFilter::Name(e) => {
+                            f.debug_tuple("WorkerPropertyFilter::Name").field(e).finish()
+                        }
+                        WorkerPropertyFilter::Status(e) => {
+                            f.debug_tuple("WorkerPropertyFilter::Status")
+                                .field(e)
+                                .finish()
+                        }
+                        WorkerPropertyFilter::Version(e) => {
+                            f.debug_tuple("WorkerPropertyFilter::Version")
+                                .field(e)
+                                .finish()
+                        }
+                        WorkerPropertyFilter::CreatedAt(e) => {
+                            f.debug_tuple("WorkerPropertyFilter::CreatedAt")
+                                .field(e)
+                                .finish()
+                        }
+                        WorkerPropertyFilter::Env(e) => {
+                            f.debug_tuple("WorkerPropertyFilter::Env").field(e).finish()
+                        }
+                        WorkerPropertyFilter::WasiConfigVars(e) => {
+                            f.debug_tuple("WorkerPropertyFilter::WasiConfigVars")
+                                .field(e)
+                                .finish()
+                        }
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct WorkerAllFilter {
+                pub filters: _rt::Vec<WorkerPropertyFilter>,
+            }
+            impl ::core::fmt::Debug for WorkerAllFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerAllFilter")
+                        .field("filters", &self.filters)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct WorkerAnyFilter {
+                pub filters: _rt::Vec<WorkerAllFilter>,
+            }
+            impl ::core::fmt::Debug for WorkerAnyFilter {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerAnyFilter")
+                        .field("filters", &self.filters)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct WorkerMetadata {
+                pub worker_id: WorkerId,
+                pub args: _rt::Vec<_rt::String>,
+                pub env: _rt::Vec<(_rt::String, _rt::String)>,
+                pub wasi_config_vars: _rt::Vec<(_rt::String, _rt::String)>,
+                pub status: WorkerStatus,
+                pub component_version: u64,
+                pub retry_count: u64,
+            }
+            impl ::core::fmt::Debug for WorkerMetadata {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerMetadata")
+                        .field("worker-id", &self.worker_id)
+                        .field("args", &self.args)
+                        .field("env", &self.env)
+                        .field("wasi-config-vars", &self.wasi_config_vars)
+                        .field("status", &self.status)
+                        .field("component-version", &self.component_version)
+                        .field("retry-count", &self.retry_count)
+                        .finish()
+                }
+            }
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct GetWorkers {
+                handle: _rt::Resource<GetWorkers>,
+            }
+            impl GetWorkers {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for GetWorkers {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]get-workers"]
+                            fn drop(_: u32);
+                        }
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            /// Target parameter for the `revert-worker` operation
+            #[derive(Clone, Copy)]
+            pub enum RevertWorkerTarget {
+                /// Revert to a specific oplog index. The given index will be the last one to be kept.
+                RevertToOplogIndex(OplogIndex),
+                /// Revert the last N invocations.
+                RevertLastInvocations(u64),
+            }
+            impl ::core::fmt::Debug for RevertWorkerTarget {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        RevertWorkerTarget::RevertToOplogIndex(e) => {
+                            f.debug_tuple("RevertWorkerTarget::RevertToOplogIndex")
+                                .field(e)
+                                .finish()
+                        }
+                        RevertWorkerTarget::RevertLastInvocations(e) => {
+                            f.debug_tuple("RevertWorkerTarget::RevertLastInvocations")
+                                .field(e)
+                                .finish()
+                        }
+                    }
+                }
+            }
+            /// Indicates which worker the code is running on after `fork`
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum ForkResult {
+                /// The original worker that called `fork`
+                Original,
+                /// The new worker
+                Forked,
+            }
+            impl ::core::fmt::Debug for ForkResult {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ForkResult::Original => {
+                            f.debug_tuple("ForkResult::Original").finish()
+                        }
+                        ForkResult::Forked => {
+                            f.debug_tuple("ForkResult::Forked").finish()
+                        }
+                    }
+                }
+            }
+            impl ForkResult {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> ForkResult {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => ForkResult::Original,
+                        1 => ForkResult::Forked,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            /// The result of `await-promise-with-timeout`
+            #[derive(Clone)]
+            pub enum AwaitPromiseResult {
+                /// The promise got completed with the given payload
+                Completed(_rt::Vec<u8>),
+                /// The timeout elapsed before the promise got completed
+                TimedOut,
+            }
+            impl ::core::fmt::Debug for AwaitPromiseResult {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        AwaitPromiseResult::Completed(e) => {
+                            f.debug_tuple("AwaitPromiseResult::Completed")
+                                .field(e)
+                                .finish()
+                        }
+                        AwaitPromiseResult::TimedOut => {
+                            f.debug_tuple("AwaitPromiseResult::TimedOut").finish()
+                        }
+                    }
+                }
+            }
+            impl GetWorkers {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new(
+                    component_id: ComponentId,
+                    filter: Option<&WorkerAnyFilter>,
+                    precise: bool,
+                ) -> Self {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        let super::super::super::golem::rpc::types::ComponentId {
+                            uuid: uuid0,
+                        } = component_id;
+                        let super::super::super::golem::rpc::types::Uuid {
+                            high_bits: high_bits1,
+                            low_bits: low_bits1,
+                        } = uuid0;
+                        let (result17_0, result17_1, result17_2) = match filter {
+                            Some(e) => {
+                                let WorkerAnyFilter { filters: filters2 } = e;
+                                let vec16 = filters2;
+                                let len16 = vec16.len();
+                                let layout16 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec16.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                let result16 = if layout16.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout16).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout16);
+                                    }
+                                    ptr
+                                } else {
+                                    ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec16.into_iter().enumerate() {
+                                    let base = result16
+                                        .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                    {
+                                        let WorkerAllFilter { filters: filters3 } = e;
+                                        let vec15 = filters3;
+                                        let len15 = vec15.len();
+                                        let layout15 = _rt::alloc::Layout::from_size_align_unchecked(
+                                            vec15.len()
+                                                * (16 + 4 * ::core::mem::size_of::<*const u8>()),
+                                            8,
+                                        );
+                                        let result15 = if layout15.size() != 0 {
+                                            let ptr = _rt::alloc::alloc(layout15).cast::<u8>();
+                                            if ptr.is_null() {
+                                                _rt::alloc::handle_alloc_error(layout15);
+                                            }
+                                            ptr
+                                        } else {
+                                            ::core::ptr::null_mut()
+                                        };
+                                        for (i, e) in vec15.into_iter().enumerate() {
+                                            let base = result15
+                                                .add(i * (16 + 4 * ::core::mem::size_of::<*const u8>()));
+                                            {
+                                                match e {
+                                                    WorkerPropertyFilter::Name(e) => {
+                                                        *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                        let WorkerNameFilter {
+                                                            comparator: comparator4,
+                                                            value: value4,
+                                                        } = e;
+                                                        *base.add(8).cast::<u8>() = (comparator4.clone() as i32)
+                                                            as u8;
+                                                        let vec5 = value4;
+                                                        let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                        let len5 = vec5.len();
+                                                        *base
+                                                            .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len5;
+                                                        *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr5.cast_mut();
+                                                    }
+                                                    WorkerPropertyFilter::Status(e) => {
+                                                        *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                        let WorkerStatusFilter {
+                                                            comparator: comparator6,
+                                                            value: value6,
+                                                        } = e;
+                                                        *base.add(8).cast::<u8>() = (comparator6.clone() as i32)
+                                                            as u8;
+                                                        *base.add(9).cast::<u8>() = (value6.clone() as i32) as u8;
+                                                    }
+                                                    WorkerPropertyFilter::Version(e) => {
+                                                        *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                        let WorkerVersionFilter {
+                                                            comparator: comparator7,
+                                                            value: value7,
+                                                        } = e;
+                                                        *base.add(8).cast::<u8>() = (comparator7.clone() as i32)
+                                                            as u8;
+                                                        *base.add(16).cast::<i64>() = _rt::as_i64(value7);
+                                                    }
+                                                    WorkerPropertyFilter::CreatedAt(e) => {
+                                                        *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                        let WorkerCreatedAtFilter {
+                                                            comparator: comparator8,
+                                                            value: value8,
+                                                        } = e;
+                                                        *base.add(8).cast::<u8>() = (comparator8.clone() as i32)
+                                                            as u8;
+                                                        *base.add(16).cast::<i64>() = _rt::as_i64(value8);
+                                                    }
+                                                    WorkerPropertyFilter::Env(e) => {
+                                                        *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                        let WorkerEnvFilter {
+                                                            name: name9,
+                                                            comparator: comparator9,
+                                                            value: value9,
+                                                        } = e;
+                                                        let vec10 = name9;
+                                                        let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                        let len10 = vec10.len();
+                                                        *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len10;
+                                                        *base.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                                                        *base
+                                                            .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<u8>() = (comparator9.clone() as i32) as u8;
+                                                        let vec11 = value9;
+                                                        let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                        let len11 = vec11.len();
+                                                        *base
+                                                            .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len11;
+                                                        *base
+                                                            .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr11.cast_mut();
+                                                    }
+                                                    WorkerPropertyFilter::WasiConfigVars(e) => {
+                                                        *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                        let WorkerWasiConfigVarsFilter {
+                                                            name: name12,
+                                                            comparator: comparator12,
+                                                            value: value12,
+                                                        } = e;
+                                                        let vec13 = name12;
+                                                        let ptr13 = vec13.as_ptr().cast::<u8>();
+                                                        let len13 = vec13.len();
+                                                        *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len13;
+                                                        *base.add(8).cast::<*mut u8>() = ptr13.cast_mut();
+                                                        *base
+                                                            .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<u8>() = (comparator12.clone() as i32) as u8;
+                                                        let vec14 = value12;
+                                                        let ptr14 = vec14.as_ptr().cast::<u8>();
+                                                        let len14 = vec14.len();
+                                                        *base
+                                                            .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>() = len14;
+                                                        *base
+                                                            .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<*mut u8>() = ptr14.cast_mut();
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>() = len15;
+                                        *base.add(0).cast::<*mut u8>() = result15;
+                                        cleanup_list.extend_from_slice(&[(result15, layout15)]);
+                                    }
+                                }
+                                cleanup_list.extend_from_slice(&[(result16, layout16)]);
+                                (1i32, result16, len16)
+                            }
+                            None => (0i32, ::core::ptr::null_mut(), 0usize),
+                        };
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                        unsafe extern "C" {
+                            #[link_name = "[constructor]get-workers"]
+                            fn wit_import18(
+                                _: i64,
+                                _: i64,
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: i32,
+                            ) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import18(
+                            _: i64,
+                            _: i64,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                        ) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe {
+                            wit_import18(
+                                _rt::as_i64(high_bits1),
+                                _rt::as_i64(low_bits1),
+                                result17_0,
+                                result17_1,
+                                result17_2,
+                                match &precise {
+                                    true => 1,
+                                    false => 0,
+                                },
+                            )
+                        };
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        unsafe { GetWorkers::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl GetWorkers {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get_next(&self) -> Option<_rt::Vec<WorkerMetadata>> {
+                    unsafe {
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 3 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 3
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]get-workers.get-next"]
+                            fn wit_import1(_: i32, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result38 = match l2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let l3 = *ptr0
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l4 = *ptr0
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base37 = l3;
+                                    let len37 = l4;
+                                    let mut result37 = _rt::Vec::with_capacity(len37);
+                                    for i in 0..len37 {
+                                        let base = base37
+                                            .add(i * (40 + 8 * ::core::mem::size_of::<*const u8>()));
+                                        let e37 = {
+                                            let l5 = *base.add(0).cast::<i64>();
+                                            let l6 = *base.add(8).cast::<i64>();
+                                            let l7 = *base.add(16).cast::<*mut u8>();
+                                            let l8 = *base
+                                                .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len9 = l8;
+                                            let bytes9 = _rt::Vec::from_raw_parts(
+                                                l7.cast(),
+                                                len9,
+                                                len9,
+                                            );
+                                            let l10 = *base
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l11 = *base
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base15 = l10;
+                                            let len15 = l11;
+                                            let mut result15 = _rt::Vec::with_capacity(len15);
+                                            for i in 0..len15 {
+                                                let base = base15
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                let e15 = {
+                                                    let l12 = *base.add(0).cast::<*mut u8>();
+                                                    let l13 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len14 = l13;
+                                                    let bytes14 = _rt::Vec::from_raw_parts(
+                                                        l12.cast(),
+                                                        len14,
+                                                        len14,
+                                                    );
+                                                    _rt::string_lift(bytes14)
+                                                };
+                                                result15.push(e15);
+                                            }
+                                            _rt::cabi_dealloc(
+                                                base15,
+                                                len15 * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let l16 = *base
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l17 = *base
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base24 = l16;
+                                            let len24 = l17;
+                                            let mut result24 = _rt::Vec::with_capacity(len24);
+                                            for i in 0..len24 {
+                                                let base = base24
+                                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                                let e24 = {
+                                                    let l18 = *base.add(0).cast::<*mut u8>();
+                                                    let l19 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len20 = l19;
+                                                    let bytes20 = _rt::Vec::from_raw_parts(
+                                                        l18.cast(),
+                                                        len20,
+                                                        len20,
+                                                    );
+                                                    let l21 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l22 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len23 = l22;
+                                                    let bytes23 = _rt::Vec::from_raw_parts(
+                                                        l21.cast(),
+                                                        len23,
+                                                        len23,
+                                                    );
+                                                    (_rt::string_lift(bytes20), _rt::string_lift(bytes23))
+                                                };
+                                                result24.push(e24);
+                                            }
+                                            _rt::cabi_dealloc(
+                                                base24,
+                                                len24 * (4 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let l25 = *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l26 = *base
+                                                .add(16 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let base33 = l25;
+                                            let len33 = l26;
+                                            let mut result33 = _rt::Vec::with_capacity(len33);
+                                            for i in 0..len33 {
+                                                let base = base33
+                                                    .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                                let e33 = {
+                                                    let l27 = *base.add(0).cast::<*mut u8>();
+                                                    let l28 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len29 = l28;
+                                                    let bytes29 = _rt::Vec::from_raw_parts(
+                                                        l27.cast(),
+                                                        len29,
+                                                        len29,
+                                                    );
+                                                    let l30 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l31 = *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    let len32 = l31;
+                                                    let bytes32 = _rt::Vec::from_raw_parts(
+                                                        l30.cast(),
+                                                        len32,
+                                                        len32,
+                                                    );
+                                                    (_rt::string_lift(bytes29), _rt::string_lift(bytes32))
+                                                };
+                                                result33.push(e33);
+                                            }
+                                            _rt::cabi_dealloc(
+                                                base33,
+                                                len33 * (4 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let l34 = i32::from(
+                                                *base
+                                                    .add(16 + 8 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            let l35 = *base
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>();
+                                            let l36 = *base
+                                                .add(32 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>();
+                                            WorkerMetadata {
+                                                worker_id: super::super::super::golem::rpc::types::WorkerId {
+                                                    component_id: super::super::super::golem::rpc::types::ComponentId {
+                                                        uuid: super::super::super::golem::rpc::types::Uuid {
+                                                            high_bits: l5 as u64,
+                                                            low_bits: l6 as u64,
+                                                        },
+                                                    },
+                                                    worker_name: _rt::string_lift(bytes9),
+                                                },
+                                                args: result15,
+                                                env: result24,
+                                                wasi_config_vars: result33,
+                                                status: WorkerStatus::_lift(l34 as u8),
+                                                component_version: l35 as u64,
+                                                retry_count: l36 as u64,
+                                            }
+                                        };
+                                        result37.push(e37);
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base37,
+                                        len37 * (40 + 8 * ::core::mem::size_of::<*const u8>()),
+                                        8,
+                                    );
+                                    result37
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        result38
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Create a new promise
+            pub fn create_promise() -> PromiseId {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 24 + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 24
+                            + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "create-promise"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i64>();
+                    let l4 = *ptr0.add(16).cast::<*mut u8>();
+                    let l5 = *ptr0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len6 = l5;
+                    let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+                    let l7 = *ptr0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i64>();
+                    let result8 = PromiseId {
+                        worker_id: super::super::super::golem::rpc::types::WorkerId {
+                            component_id: super::super::super::golem::rpc::types::ComponentId {
+                                uuid: super::super::super::golem::rpc::types::Uuid {
+                                    high_bits: l2 as u64,
+                                    low_bits: l3 as u64,
+                                },
+                            },
+                            worker_name: _rt::string_lift(bytes6),
+                        },
+                        oplog_idx: l7 as u64,
+                    };
+                    result8
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Suspends execution until the given promise gets completed, and returns the payload passed to
+            /// the promise completion.
+            pub fn await_promise(promise_id: &PromiseId) -> _rt::Vec<u8> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 2
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let PromiseId { worker_id: worker_id0, oplog_idx: oplog_idx0 } = promise_id;
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id1,
+                        worker_name: worker_name1,
+                    } = worker_id0;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid2,
+                    } = component_id1;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits3,
+                        low_bits: low_bits3,
+                    } = uuid2;
+                    let vec4 = worker_name1;
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    let ptr5 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "await-promise"]
+                        fn wit_import6(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import6(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import6(
+                            _rt::as_i64(high_bits3),
+                            _rt::as_i64(low_bits3),
+                            ptr4.cast_mut(),
+                            len4,
+                            _rt::as_i64(oplog_idx0),
+                            ptr5,
+                        )
+                    };
+                    let l7 = *ptr5.add(0).cast::<*mut u8>();
+                    let l8 = *ptr5
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len9 = l8;
+                    let result10 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                    result10
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Suspends execution until the given promise gets completed or the given timeout elapses. Returns the
+            /// payload passed to the promise completion, or `timed-out` if the promise was not completed in time.
+            /// The timeout is measured from the first time the call is made, it does not restart when the worker
+            /// gets recovered.
+            pub fn await_promise_with_timeout(
+                promise_id: &PromiseId,
+                timeout: Duration,
+            ) -> AwaitPromiseResult {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 3
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let PromiseId { worker_id: worker_id0, oplog_idx: oplog_idx0 } = promise_id;
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id1,
+                        worker_name: worker_name1,
+                    } = worker_id0;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid2,
+                    } = component_id1;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits3,
+                        low_bits: low_bits3,
+                    } = uuid2;
+                    let vec4 = worker_name1;
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    let ptr5 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "await-promise-with-timeout"]
+                        fn wit_import6(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import6(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import6(
+                            _rt::as_i64(high_bits3),
+                            _rt::as_i64(low_bits3),
+                            ptr4.cast_mut(),
+                            len4,
+                            _rt::as_i64(oplog_idx0),
+                            _rt::as_i64(timeout),
+                            ptr5,
+                        )
+                    };
+                    let l7 = i32::from(*ptr5.add(0).cast::<u8>());
+                    let v11 = match l7 {
+                        0 => {
+                            let e11 = {
+                                let l8 = *ptr5
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l9 = *ptr5
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len10 = l9;
+                                _rt::Vec::from_raw_parts(l8.cast(), len10, len10)
+                            };
+                            AwaitPromiseResult::Completed(e11)
+                        }
+                        n => {
+                            debug_assert_eq!(n, 1, "invalid enum discriminant");
+                            AwaitPromiseResult::TimedOut
+                        }
+                    };
+                    let result12 = v11;
+                    result12
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Checks whether the given promise is completed. If not, it returns None. If the promise is completed,
+            /// it returns the payload passed to the promise completion.
+            pub fn poll_promise(promise_id: &PromiseId) -> Option<_rt::Vec<u8>> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 3
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let PromiseId { worker_id: worker_id0, oplog_idx: oplog_idx0 } = promise_id;
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id1,
+                        worker_name: worker_name1,
+                    } = worker_id0;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid2,
+                    } = component_id1;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits3,
+                        low_bits: low_bits3,
+                    } = uuid2;
+                    let vec4 = worker_name1;
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    let ptr5 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "poll-promise"]
+                        fn wit_import6(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import6(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import6(
+                            _rt::as_i64(high_bits3),
+                            _rt::as_i64(low_bits3),
+                            ptr4.cast_mut(),
+                            len4,
+                            _rt::as_i64(oplog_idx0),
+                            ptr5,
+                        )
+                    };
+                    let l7 = i32::from(*ptr5.add(0).cast::<u8>());
+                    let result11 = match l7 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l8 = *ptr5
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l9 = *ptr5
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len10 = l9;
+                                _rt::Vec::from_raw_parts(l8.cast(), len10, len10)
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result11
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Completes the given promise with the given payload. Returns true if the promise was completed, false
+            /// if the promise was already completed. The payload is passed to the worker that is awaiting the promise.
+            pub fn complete_promise(promise_id: &PromiseId, data: &[u8]) -> bool {
+                unsafe {
+                    let PromiseId { worker_id: worker_id0, oplog_idx: oplog_idx0 } = promise_id;
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id1,
+                        worker_name: worker_name1,
+                    } = worker_id0;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid2,
+                    } = component_id1;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits3,
+                        low_bits: low_bits3,
+                    } = uuid2;
+                    let vec4 = worker_name1;
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    let vec5 = data;
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "complete-promise"]
+                        fn wit_import6(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                        ) -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import6(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                    ) -> i32 {
+                        unreachable!()
+                    }
+                    let ret = unsafe {
+                        wit_import6(
+                            _rt::as_i64(high_bits3),
+                            _rt::as_i64(low_bits3),
+                            ptr4.cast_mut(),
+                            len4,
+                            _rt::as_i64(oplog_idx0),
+                            ptr5.cast_mut(),
+                            len5,
+                        )
+                    };
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Deletes the given promise
+            pub fn delete_promise(promise_id: &PromiseId) -> () {
+                unsafe {
+                    let PromiseId { worker_id: worker_id0, oplog_idx: oplog_idx0 } = promise_id;
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id1,
+                        worker_name: worker_name1,
+                    } = worker_id0;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid2,
+                    } = component_id1;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits3,
+                        low_bits: low_bits3,
+                    } = uuid2;
+                    let vec4 = worker_name1;
+                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                    let len4 = vec4.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "delete-promise"]
+                        fn wit_import5(_: i64, _: i64, _: *mut u8, _: usize, _: i64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import5(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import5(
+                            _rt::as_i64(high_bits3),
+                            _rt::as_i64(low_bits3),
+                            ptr4.cast_mut(),
+                            len4,
+                            _rt::as_i64(oplog_idx0),
+                        )
+                    };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Returns the current position in the persistent op log
+            pub fn get_oplog_index() -> OplogIndex {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-oplog-index"]
+                        fn wit_import0() -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i64 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Makes the current worker travel back in time and continue execution from the given position in the persistent
+            /// op log.
+            pub fn set_oplog_index(oplog_idx: OplogIndex) -> () {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "set-oplog-index"]
+                        fn wit_import0(_: i64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i64) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import0(_rt::as_i64(oplog_idx)) };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Blocks the execution until the oplog has been written to at least the specified number of replicas,
+            /// or the maximum number of replicas if the requested number is higher.
+            pub fn oplog_commit(replicas: u8) -> () {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "oplog-commit"]
+                        fn wit_import0(_: i32);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i32) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import0(_rt::as_i32(&replicas)) };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Marks the beginning of an atomic operation.
+            /// In case of a failure within the region selected by `mark-begin-operation` and `mark-end-operation`
+            /// the whole region will be reexecuted on retry.
+            /// The end of the region is when `mark-end-operation` is called with the returned oplog-index.
+            pub fn mark_begin_operation() -> OplogIndex {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "mark-begin-operation"]
+                        fn wit_import0() -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i64 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Commits this atomic operation. After `mark-end-operation` is called for a given index, further calls
+            /// with the same parameter will do nothing.
+            pub fn mark_end_operation(begin: OplogIndex) -> () {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "mark-end-operation"]
+                        fn wit_import0(_: i64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i64) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import0(_rt::as_i64(begin)) };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets the current retry policy associated with the worker
+            pub fn get_retry_policy() -> RetryPolicy {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 48]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 48]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-retry-policy"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i32>();
+                    let l3 = *ptr0.add(8).cast::<i64>();
+                    let l4 = *ptr0.add(16).cast::<i64>();
+                    let l5 = *ptr0.add(24).cast::<f64>();
+                    let l6 = i32::from(*ptr0.add(32).cast::<u8>());
+                    let result8 = RetryPolicy {
+                        max_attempts: l2 as u32,
+                        min_delay: l3 as u64,
+                        max_delay: l4 as u64,
+                        multiplier: l5,
+                        max_jitter_factor: match l6 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let l7 = *ptr0.add(40).cast::<f64>();
+                                    l7
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        },
+                    };
+                    result8
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Overrides the current retry policy associated with the worker. Following this call, `get-retry-policy` will return the
+            /// new retry policy.
+            pub fn set_retry_policy(new_retry_policy: RetryPolicy) -> () {
+                unsafe {
+                    let RetryPolicy {
+                        max_attempts: max_attempts0,
+                        min_delay: min_delay0,
+                        max_delay: max_delay0,
+                        multiplier: multiplier0,
+                        max_jitter_factor: max_jitter_factor0,
+                    } = new_retry_policy;
+                    let (result1_0, result1_1) = match max_jitter_factor0 {
+                        Some(e) => (1i32, _rt::as_f64(e)),
+                        None => (0i32, 0.0f64),
+                    };
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "set-retry-policy"]
+                        fn wit_import2(_: i32, _: i64, _: i64, _: f64, _: i32, _: f64);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(
+                        _: i32,
+                        _: i64,
+                        _: i64,
+                        _: f64,
+                        _: i32,
+                        _: f64,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import2(
+                            _rt::as_i32(max_attempts0),
+                            _rt::as_i64(min_delay0),
+                            _rt::as_i64(max_delay0),
+                            _rt::as_f64(multiplier0),
+                            result1_0,
+                            result1_1,
+                        )
+                    };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets the worker's current persistence level.
+            pub fn get_oplog_persistence_level() -> PersistenceLevel {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-oplog-persistence-level"]
+                        fn wit_import0() -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i32 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    let v1 = match ret {
+                        0 => PersistenceLevel::PersistNothing,
+                        1 => PersistenceLevel::PersistRemoteSideEffects,
+                        n => {
+                            debug_assert_eq!(n, 2, "invalid enum discriminant");
+                            PersistenceLevel::Smart
+                        }
+                    };
+                    v1
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Sets the worker's current persistence level. This can increase the performance of execution in cases where durable
+            /// execution is not required.
+            pub fn set_oplog_persistence_level(
+                new_persistence_level: PersistenceLevel,
+            ) -> () {
+                unsafe {
+                    let result0 = match new_persistence_level {
+                        PersistenceLevel::PersistNothing => 0i32,
+                        PersistenceLevel::PersistRemoteSideEffects => 1i32,
+                        PersistenceLevel::Smart => 2i32,
+                    };
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "set-oplog-persistence-level"]
+                        fn wit_import1(_: i32);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: i32) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(result0) };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets the current idempotence mode. See `set-idempotence-mode` for details.
+            pub fn get_idempotence_mode() -> bool {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-idempotence-mode"]
+                        fn wit_import0() -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i32 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    _rt::bool_lift(ret as u8)
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Sets the current idempotence mode. The default is true.
+            /// True means side-effects are treated idempotent and Golem guarantees at-least-once semantics.
+            /// In case of false the executor provides at-most-once semantics, failing the worker in case it is
+            /// not known if the side effect was already executed.
+            pub fn set_idempotence_mode(idempotent: bool) -> () {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "set-idempotence-mode"]
+                        fn wit_import0(_: i32);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i32) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import0(
+                            match &idempotent {
+                                true => 1,
+                                false => 0,
+                            },
+                        )
+                    };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Generates an idempotency key. This operation will never be replayed —
+            /// i.e. not only is this key generated, but it is persisted and committed, such that the key can be used in third-party systems (e.g. payment processing)
+            /// to introduce idempotence.
+            pub fn generate_idempotency_key() -> Uuid {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "generate-idempotency-key"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i64>();
+                    let result4 = super::super::super::golem::rpc::types::Uuid {
+                        high_bits: l2 as u64,
+                        low_bits: l3 as u64,
+                    };
+                    result4
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Initiates an update attempt for the given worker. The function returns immediately once the request has been processed,
+            /// not waiting for the worker to get updated.
+            pub fn update_worker(
+                worker_id: &WorkerId,
+                target_version: ComponentVersion,
+                mode: UpdateMode,
+            ) -> () {
+                unsafe {
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id0,
+                        worker_name: worker_name0,
+                    } = worker_id;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid1,
+                    } = component_id0;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits2,
+                        low_bits: low_bits2,
+                    } = uuid1;
+                    let vec3 = worker_name0;
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "update-worker"]
+                        fn wit_import4(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: i32,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import4(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                        _: i32,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import4(
+                            _rt::as_i64(high_bits2),
+                            _rt::as_i64(low_bits2),
+                            ptr3.cast_mut(),
+                            len3,
+                            _rt::as_i64(target_version),
+                            mode.clone() as i32,
+                        )
+                    };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Get current worker metadata
+            pub fn get_self_metadata() -> WorkerMetadata {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 40 + 8 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 40
+                            + 8 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-self-metadata"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i64>();
+                    let l4 = *ptr0.add(16).cast::<*mut u8>();
+                    let l5 = *ptr0
+                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len6 = l5;
+                    let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+                    let l7 = *ptr0
+                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l8 = *ptr0
+                        .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base12 = l7;
+                    let len12 = l8;
+                    let mut result12 = _rt::Vec::with_capacity(len12);
+                    for i in 0..len12 {
+                        let base = base12
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e12 = {
+                            let l9 = *base.add(0).cast::<*mut u8>();
+                            let l10 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len11 = l10;
+                            let bytes11 = _rt::Vec::from_raw_parts(
+                                l9.cast(),
+                                len11,
+                                len11,
+                            );
+                            _rt::string_lift(bytes11)
+                        };
+                        result12.push(e12);
+                    }
+                    _rt::cabi_dealloc(
+                        base12,
+                        len12 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l13 = *ptr0
+                        .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l14 = *ptr0
+                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base21 = l13;
+                    let len21 = l14;
+                    let mut result21 = _rt::Vec::with_capacity(len21);
+                    for i in 0..len21 {
+                        let base = base21
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e21 = {
+                            let l15 = *base.add(0).cast::<*mut u8>();
+                            let l16 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len17 = l16;
+                            let bytes17 = _rt::Vec::from_raw_parts(
+                                l15.cast(),
+                                len17,
+                                len17,
+                            );
+                            let l18 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l19 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len20 = l19;
+                            let bytes20 = _rt::Vec::from_raw_parts(
+                                l18.cast(),
+                                len20,
+                                len20,
+                            );
+                            (_rt::string_lift(bytes17), _rt::string_lift(bytes20))
+                        };
+                        result21.push(e21);
+                    }
+                    _rt::cabi_dealloc(
+                        base21,
+                        len21 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l22 = *ptr0
+                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                        .cast::<*mut u8>();
+                    let l23 = *ptr0
+                        .add(16 + 7 * ::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base30 = l22;
+                    let len30 = l23;
+                    let mut result30 = _rt::Vec::with_capacity(len30);
+                    for i in 0..len30 {
+                        let base = base30
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e30 = {
+                            let l24 = *base.add(0).cast::<*mut u8>();
+                            let l25 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len26 = l25;
+                            let bytes26 = _rt::Vec::from_raw_parts(
+                                l24.cast(),
+                                len26,
+                                len26,
+                            );
+                            let l27 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l28 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len29 = l28;
+                            let bytes29 = _rt::Vec::from_raw_parts(
+                                l27.cast(),
+                                len29,
+                                len29,
+                            );
+                            (_rt::string_lift(bytes26), _rt::string_lift(bytes29))
+                        };
+                        result30.push(e30);
+                    }
+                    _rt::cabi_dealloc(
+                        base30,
+                        len30 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let l31 = i32::from(
+                        *ptr0
+                            .add(16 + 8 * ::core::mem::size_of::<*const u8>())
+                            .cast::<u8>(),
+                    );
+                    let l32 = *ptr0
+                        .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i64>();
+                    let l33 = *ptr0
+                        .add(32 + 8 * ::core::mem::size_of::<*const u8>())
+                        .cast::<i64>();
+                    let result34 = WorkerMetadata {
+                        worker_id: super::super::super::golem::rpc::types::WorkerId {
+                            component_id: super::super::super::golem::rpc::types::ComponentId {
+                                uuid: super::super::super::golem::rpc::types::Uuid {
+                                    high_bits: l2 as u64,
+                                    low_bits: l3 as u64,
+                                },
+                            },
+                            worker_name: _rt::string_lift(bytes6),
+                        },
+                        args: result12,
+                        env: result21,
+                        wasi_config_vars: result30,
+                        status: WorkerStatus::_lift(l31 as u8),
+                        component_version: l32 as u64,
+                        retry_count: l33 as u64,
+                    };
+                    result34
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Get worker metadata
+            pub fn get_worker_metadata(worker_id: &WorkerId) -> Option<WorkerMetadata> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 48 + 8 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 48
+                            + 8 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id0,
+                        worker_name: worker_name0,
+                    } = worker_id;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid1,
+                    } = component_id0;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits2,
+                        low_bits: low_bits2,
+                    } = uuid1;
+                    let vec3 = worker_name0;
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    let ptr4 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-worker-metadata"]
+                        fn wit_import5(_: i64, _: i64, _: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import5(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import5(
+                            _rt::as_i64(high_bits2),
+                            _rt::as_i64(low_bits2),
+                            ptr3.cast_mut(),
+                            len3,
+                            ptr4,
+                        )
+                    };
+                    let l6 = i32::from(*ptr4.add(0).cast::<u8>());
+                    let result39 = match l6 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l7 = *ptr4.add(8).cast::<i64>();
+                                let l8 = *ptr4.add(16).cast::<i64>();
+                                let l9 = *ptr4.add(24).cast::<*mut u8>();
+                                let l10 = *ptr4
+                                    .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len11 = l10;
+                                let bytes11 = _rt::Vec::from_raw_parts(
+                                    l9.cast(),
+                                    len11,
+                                    len11,
+                                );
+                                let l12 = *ptr4
+                                    .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l13 = *ptr4
+                                    .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base17 = l12;
+                                let len17 = l13;
+                                let mut result17 = _rt::Vec::with_capacity(len17);
+                                for i in 0..len17 {
+                                    let base = base17
+                                        .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                    let e17 = {
+                                        let l14 = *base.add(0).cast::<*mut u8>();
+                                        let l15 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len16 = l15;
+                                        let bytes16 = _rt::Vec::from_raw_parts(
+                                            l14.cast(),
+                                            len16,
+                                            len16,
+                                        );
+                                        _rt::string_lift(bytes16)
+                                    };
+                                    result17.push(e17);
+                                }
+                                _rt::cabi_dealloc(
+                                    base17,
+                                    len17 * (2 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                let l18 = *ptr4
+                                    .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l19 = *ptr4
+                                    .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base26 = l18;
+                                let len26 = l19;
+                                let mut result26 = _rt::Vec::with_capacity(len26);
+                                for i in 0..len26 {
+                                    let base = base26
+                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                    let e26 = {
+                                        let l20 = *base.add(0).cast::<*mut u8>();
+                                        let l21 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len22 = l21;
+                                        let bytes22 = _rt::Vec::from_raw_parts(
+                                            l20.cast(),
+                                            len22,
+                                            len22,
+                                        );
+                                        let l23 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l24 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len25 = l24;
+                                        let bytes25 = _rt::Vec::from_raw_parts(
+                                            l23.cast(),
+                                            len25,
+                                            len25,
+                                        );
+                                        (_rt::string_lift(bytes22), _rt::string_lift(bytes25))
+                                    };
+                                    result26.push(e26);
+                                }
+                                _rt::cabi_dealloc(
+                                    base26,
+                                    len26 * (4 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                let l27 = *ptr4
+                                    .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l28 = *ptr4
+                                    .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base35 = l27;
+                                let len35 = l28;
+                                let mut result35 = _rt::Vec::with_capacity(len35);
+                                for i in 0..len35 {
+                                    let base = base35
+                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                    let e35 = {
+                                        let l29 = *base.add(0).cast::<*mut u8>();
+                                        let l30 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len31 = l30;
+                                        let bytes31 = _rt::Vec::from_raw_parts(
+                                            l29.cast(),
+                                            len31,
+                                            len31,
+                                        );
+                                        let l32 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l33 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len34 = l33;
+                                        let bytes34 = _rt::Vec::from_raw_parts(
+                                            l32.cast(),
+                                            len34,
+                                            len34,
+                                        );
+                                        (_rt::string_lift(bytes31), _rt::string_lift(bytes34))
+                                    };
+                                    result35.push(e35);
+                                }
+                                _rt::cabi_dealloc(
+                                    base35,
+                                    len35 * (4 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                let l36 = i32::from(
+                                    *ptr4
+                                        .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>(),
+                                );
+                                let l37 = *ptr4
+                                    .add(32 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i64>();
+                                let l38 = *ptr4
+                                    .add(40 + 8 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<i64>();
+                                WorkerMetadata {
+                                    worker_id: super::super::super::golem::rpc::types::WorkerId {
+                                        component_id: super::super::super::golem::rpc::types::ComponentId {
+                                            uuid: super::super::super::golem::rpc::types::Uuid {
+                                                high_bits: l7 as u64,
+                                                low_bits: l8 as u64,
+                                            },
+                                        },
+                                        worker_name: _rt::string_lift(bytes11),
+                                    },
+                                    args: result17,
+                                    env: result26,
+                                    wasi_config_vars: result35,
+                                    status: WorkerStatus::_lift(l36 as u8),
+                                    component_version: l37 as u64,
+                                    retry_count: l38 as u64,
+                                }
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result39
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Fork a worker to another worker at a given oplog index
+            pub fn fork_worker(
+                source_worker_id: &WorkerId,
+                target_worker_id: &WorkerId,
+                oplog_idx_cut_off: OplogIndex,
+            ) -> () {
+                unsafe {
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id0,
+                        worker_name: worker_name0,
+                    } = source_worker_id;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid1,
+                    } = component_id0;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits2,
+                        low_bits: low_bits2,
+                    } = uuid1;
+                    let vec3 = worker_name0;
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id4,
+                        worker_name: worker_name4,
+                    } = target_worker_id;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid5,
+                    } = component_id4;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits6,
+                        low_bits: low_bits6,
+                    } = uuid5;
+                    let vec7 = worker_name4;
+                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                    let len7 = vec7.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "fork-worker"]
+                        fn wit_import8(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i64,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import8(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i64,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import8(
+                            _rt::as_i64(high_bits2),
+                            _rt::as_i64(low_bits2),
+                            ptr3.cast_mut(),
+                            len3,
+                            _rt::as_i64(high_bits6),
+                            _rt::as_i64(low_bits6),
+                            ptr7.cast_mut(),
+                            len7,
+                            _rt::as_i64(oplog_idx_cut_off),
+                        )
+                    };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Revert a worker to a previous state
+            pub fn revert_worker(
+                worker_id: &WorkerId,
+                revert_target: RevertWorkerTarget,
+            ) -> () {
+                unsafe {
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id0,
+                        worker_name: worker_name0,
+                    } = worker_id;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid1,
+                    } = component_id0;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits2,
+                        low_bits: low_bits2,
+                    } = uuid1;
+                    let vec3 = worker_name0;
+                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                    let len3 = vec3.len();
+                    let (result4_0, result4_1) = match revert_target {
+                        RevertWorkerTarget::RevertToOplogIndex(e) => {
+                            (0i32, _rt::as_i64(e))
+                        }
+                        RevertWorkerTarget::RevertLastInvocations(e) => {
+                            (1i32, _rt::as_i64(e))
+                        }
+                    };
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "revert-worker"]
+                        fn wit_import5(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: i32,
+                            _: i64,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import5(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: i32,
+                        _: i64,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import5(
+                            _rt::as_i64(high_bits2),
+                            _rt::as_i64(low_bits2),
+                            ptr3.cast_mut(),
+                            len3,
+                            result4_0,
+                            result4_1,
+                        )
+                    };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Get the user-defined labels of the current worker
+            pub fn get_labels() -> _rt::Vec<(_rt::String, _rt::String)> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 2
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "get-labels"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<*mut u8>();
+                    let l3 = *ptr0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base10 = l2;
+                    let len10 = l3;
+                    let mut result10 = _rt::Vec::with_capacity(len10);
+                    for i in 0..len10 {
+                        let base = base10
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        let e10 = {
+                            let l4 = *base.add(0).cast::<*mut u8>();
+                            let l5 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len6 = l5;
+                            let bytes6 = _rt::Vec::from_raw_parts(l4.cast(), len6, len6);
+                            let l7 = *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l8 = *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len9 = l8;
+                            let bytes9 = _rt::Vec::from_raw_parts(l7.cast(), len9, len9);
+                            (_rt::string_lift(bytes6), _rt::string_lift(bytes9))
+                        };
+                        result10.push(e10);
+                    }
+                    _rt::cabi_dealloc(
+                        base10,
+                        len10 * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result11 = result10;
+                    result11
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Set a user-defined label on the current worker, overwriting the existing value with the same key
+            pub fn set_label(key: &str, value: &str) -> () {
+                unsafe {
+                    let vec0 = key;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let vec1 = value;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "set-label"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8, _: usize);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1.cast_mut(), len1) };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Remove a user-defined label from the current worker
+            pub fn remove_label(key: &str) -> () {
+                unsafe {
+                    let vec0 = key;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "remove-label"]
+                        fn wit_import1(_: *mut u8, _: usize);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8, _: usize) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0.cast_mut(), len0) };
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Get the component-id for a given component reference.
+            /// Returns none when no component with the specified reference exists.
+            /// The syntax of the component reference is implementation dependent.
+            ///
+            /// Golem OSS: "{component_name}"
+            /// Golem Cloud:
+            ///     1: "{component_name}" -> will resolve in current account and project
+            ///     2: "{project_name}/{component_name}" -> will resolve in current account
+            ///     3: "{account_id}/{project_name}/{component_name}"
+            pub fn resolve_component_id(
+                component_reference: &str,
+            ) -> Option<ComponentId> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 24]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 24]);
+                    let vec0 = component_reference;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "resolve-component-id"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result6 = match l3 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l4 = *ptr1.add(8).cast::<i64>();
+                                let l5 = *ptr1.add(16).cast::<i64>();
+                                super::super::super::golem::rpc::types::ComponentId {
+                                    uuid: super::super::super::golem::rpc::types::Uuid {
+                                        high_bits: l4 as u64,
+                                        low_bits: l5 as u64,
+                                    },
+                                }
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result6
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Get the worker-id for a given component and worker name.
+            /// Returns none when no component for the specified reference exists.
+            pub fn resolve_worker_id(
+                component_reference: &str,
+                worker_name: &str,
+            ) -> Option<WorkerId> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 24 + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 24
+                            + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = component_reference;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let vec1 = worker_name;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "resolve-worker-id"]
+                        fn wit_import3(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import3(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import3(ptr0.cast_mut(), len0, ptr1.cast_mut(), len1, ptr2)
+                    };
+                    let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                    let result10 = match l4 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l5 = *ptr2.add(8).cast::<i64>();
+                                let l6 = *ptr2.add(16).cast::<i64>();
+                                let l7 = *ptr2.add(24).cast::<*mut u8>();
+                                let l8 = *ptr2
+                                    .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len9 = l8;
+                                let bytes9 = _rt::Vec::from_raw_parts(
+                                    l7.cast(),
+                                    len9,
+                                    len9,
+                                );
+                                super::super::super::golem::rpc::types::WorkerId {
+                                    component_id: super::super::super::golem::rpc::types::ComponentId {
+                                        uuid: super::super::super::golem::rpc::types::Uuid {
+                                            high_bits: l5 as u64,
+                                            low_bits: l6 as u64,
+                                        },
+                                    },
+                                    worker_name: _rt::string_lift(bytes9),
+                                }
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result10
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Get the worker-id for a given component and worker name.
+            /// Returns none when no component for the specified component-reference or no worker with the specified worker-name exists.
+            pub fn resolve_worker_id_strict(
+                component_reference: &str,
+                worker_name: &str,
+            ) -> Option<WorkerId> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 24 + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 24
+                            + 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = component_reference;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let vec1 = worker_name;
+                    let ptr1 = vec1.as_ptr().cast::<u8>();
+                    let len1 = vec1.len();
+                    let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "resolve-worker-id-strict"]
+                        fn wit_import3(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import3(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import3(ptr0.cast_mut(), len0, ptr1.cast_mut(), len1, ptr2)
+                    };
+                    let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                    let result10 = match l4 {
+                        0 => None,
+                        1 => {
+                            let e = {
+                                let l5 = *ptr2.add(8).cast::<i64>();
+                                let l6 = *ptr2.add(16).cast::<i64>();
+                                let l7 = *ptr2.add(24).cast::<*mut u8>();
+                                let l8 = *ptr2
+                                    .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len9 = l8;
+                                let bytes9 = _rt::Vec::from_raw_parts(
+                                    l7.cast(),
+                                    len9,
+                                    len9,
+                                );
+                                super::super::super::golem::rpc::types::WorkerId {
+                                    component_id: super::super::super::golem::rpc::types::ComponentId {
+                                        uuid: super::super::super::golem::rpc::types::Uuid {
+                                            high_bits: l5 as u64,
+                                            low_bits: l6 as u64,
+                                        },
+                                    },
+                                    worker_name: _rt::string_lift(bytes9),
+                                }
+                            };
+                            Some(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result10
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Forks the current worker at the current execution point. The new worker gets the `new-name` worker name,
+            /// and this worker continues running as well. The return value is going to be different in this worker and
+            /// the forked worker.
+            pub fn fork(new_name: &str) -> ForkResult {
+                unsafe {
+                    let vec0 = new_name;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/host@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "fork"]
+                        fn wit_import1(_: *mut u8, _: usize) -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8, _: usize) -> i32 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import1(ptr0.cast_mut(), len0) };
+                    ForkResult::_lift(ret as u8)
+                }
+            }
+        }
+        /// Interface for sagas spanning several workers. The compensation plan of a saga is tracked by the executor
+        /// instead of the worker's memory, so it is performed even if the coordinator worker gets reverted or fails.
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod saga {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            pub type Uuid = super::super::super::golem::rpc::types::Uuid;
+            pub type ValueAndType = super::super::super::golem::rpc::types::ValueAndType;
+            pub type WorkerId = super::super::super::golem::rpc::types::WorkerId;
+            /// Identifies a saga coordinated by the current worker
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct SagaId {
+                pub uuid: Uuid,
+            }
+            impl ::core::fmt::Debug for SagaId {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("SagaId").field("uuid", &self.uuid).finish()
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Starts a new saga coordinated by the current worker
+            pub fn begin_saga() -> SagaId {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/saga@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "begin-saga"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i64>();
+                    let result4 = SagaId {
+                        uuid: super::super::super::golem::rpc::types::Uuid {
+                            high_bits: l2 as u64,
+                            low_bits: l3 as u64,
+                        },
+                    };
+                    result4
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Registers the compensation of a step of a running saga, typically right after the remote call performing
+            /// the step succeeded.
+            ///
+            /// If the saga gets aborted, the executor invokes the given exported function of the given worker with the
+            /// given parameters. Compensations are performed in the reverse order of their registration, each one waiting
+            /// for the previous one to finish.
+            pub fn register_compensation(
+                saga_id: SagaId,
+                worker_id: &WorkerId,
+                function_name: &str,
+                function_params: &[ValueAndType],
+            ) -> Result<(), _rt::String> {
+                unsafe {
+                    let mut cleanup_list = _rt::Vec::new();
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 3
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let SagaId { uuid: uuid0 } = saga_id;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits1,
+                        low_bits: low_bits1,
+                    } = uuid0;
+                    let super::super::super::golem::rpc::types::WorkerId {
+                        component_id: component_id2,
+                        worker_name: worker_name2,
+                    } = worker_id;
+                    let super::super::super::golem::rpc::types::ComponentId {
+                        uuid: uuid3,
+                    } = component_id2;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits4,
+                        low_bits: low_bits4,
+                    } = uuid3;
+                    let vec5 = worker_name2;
+                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                    let len5 = vec5.len();
+                    let vec6 = function_name;
+                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                    let len6 = vec6.len();
+                    let vec39 = function_params;
+                    let len39 = vec39.len();
+                    let layout39 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec39.len() * (4 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result39 = if layout39.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout39).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout39);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec39.into_iter().enumerate() {
+                        let base = result39
+                            .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let super::super::super::golem::rpc::types::ValueAndType {
+                                value: value7,
+                                typ: typ7,
+                            } = e;
+                            let super::super::super::golem::rpc::types::WitValue {
+                                nodes: nodes8,
+                            } = value7;
+                            let vec19 = nodes8;
+                            let len19 = vec19.len();
+                            let layout19 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec19.len()
+                                    * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                8,
+                            );
+                            let result19 = if layout19.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout19).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout19);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec19.into_iter().enumerate() {
+                                let base = result19
+                                    .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    use super::super::super::golem::rpc::types::WitNode as V18;
+                                    match e {
+                                        V18::RecordValue(e) => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                            let vec9 = e;
+                                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                                            let len9 = vec9.len();
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len9;
+                                            *base.add(8).cast::<*mut u8>() = ptr9.cast_mut();
+                                        }
+                                        V18::VariantValue(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            let (t10_0, t10_1) = e;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(t10_0);
+                                            match t10_1 {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        V18::EnumValue(e) => {
+                                            *base.add(0).cast::<u8>() = (2i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V18::FlagsValue(e) => {
+                                            *base.add(0).cast::<u8>() = (3i32) as u8;
+                                            let vec11 = e;
+                                            let len11 = vec11.len();
+                                            let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec11.len() * 1,
+                                                1,
+                                            );
+                                            let result11 = if layout11.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout11);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec11.into_iter().enumerate() {
+                                                let base = result11.add(i * 1);
+                                                {
+                                                    *base.add(0).cast::<u8>() = (match e {
+                                                        true => 1,
+                                                        false => 0,
+                                                    }) as u8;
+                                                }
+                                            }
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len11;
+                                            *base.add(8).cast::<*mut u8>() = result11;
+                                            cleanup_list.extend_from_slice(&[(result11, layout11)]);
+                                        }
+                                        V18::TupleValue(e) => {
+                                            *base.add(0).cast::<u8>() = (4i32) as u8;
+                                            let vec12 = e;
+                                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                                            let len12 = vec12.len();
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len12;
+                                            *base.add(8).cast::<*mut u8>() = ptr12.cast_mut();
+                                        }
+                                        V18::ListValue(e) => {
+                                            *base.add(0).cast::<u8>() = (5i32) as u8;
+                                            let vec13 = e;
+                                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                                            let len13 = vec13.len();
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len13;
+                                            *base.add(8).cast::<*mut u8>() = ptr13.cast_mut();
+                                        }
+                                        V18::OptionValue(e) => {
+                                            *base.add(0).cast::<u8>() = (6i32) as u8;
+                                            match e {
+                                                Some(e) => {
+                                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        V18::ResultValue(e) => {
+                                            *base.add(0).cast::<u8>() = (7i32) as u8;
+                                            match e {
+                                                Ok(e) => {
+                                                    *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                    match e {
+                                                        Some(e) => {
+                                                            *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                            *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                        }
+                                                        None => {
+                                                            *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                        }
+                                                    };
+                                                }
+                                                Err(e) => {
+                                                    *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                    match e {
+                                                        Some(e) => {
+                                                            *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                            *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                        }
+                                                        None => {
+                                                            *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                        }
+                                                    };
+                                                }
+                                            };
+                                        }
+                                        V18::PrimU8(e) => {
+                                            *base.add(0).cast::<u8>() = (8i32) as u8;
+                                            *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                        }
+                                        V18::PrimU16(e) => {
+                                            *base.add(0).cast::<u8>() = (9i32) as u8;
+                                            *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                        }
+                                        V18::PrimU32(e) => {
+                                            *base.add(0).cast::<u8>() = (10i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V18::PrimU64(e) => {
+                                            *base.add(0).cast::<u8>() = (11i32) as u8;
+                                            *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        V18::PrimS8(e) => {
+                                            *base.add(0).cast::<u8>() = (12i32) as u8;
+                                            *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                        }
+                                        V18::PrimS16(e) => {
+                                            *base.add(0).cast::<u8>() = (13i32) as u8;
+                                            *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                        }
+                                        V18::PrimS32(e) => {
+                                            *base.add(0).cast::<u8>() = (14i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V18::PrimS64(e) => {
+                                            *base.add(0).cast::<u8>() = (15i32) as u8;
+                                            *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        V18::PrimFloat32(e) => {
+                                            *base.add(0).cast::<u8>() = (16i32) as u8;
+                                            *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                        }
+                                        V18::PrimFloat64(e) => {
+                                            *base.add(0).cast::<u8>() = (17i32) as u8;
+                                            *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                        }
+                                        V18::PrimChar(e) => {
+                                            *base.add(0).cast::<u8>() = (18i32) as u8;
+                                            *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V18::PrimBool(e) => {
+                                            *base.add(0).cast::<u8>() = (19i32) as u8;
+                                            *base.add(8).cast::<u8>() = (match e {
+                                                true => 1,
+                                                false => 0,
+                                            }) as u8;
+                                        }
+                                        V18::PrimString(e) => {
+                                            *base.add(0).cast::<u8>() = (20i32) as u8;
+                                            let vec14 = e;
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len14;
+                                            *base.add(8).cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        V18::Handle(e) => {
+                                            *base.add(0).cast::<u8>() = (21i32) as u8;
+                                            let (t15_0, t15_1) = e;
+                                            let super::super::super::golem::rpc::types::Uri {
+                                                value: value16,
+                                            } = t15_0;
+                                            let vec17 = value16;
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            *base
+                                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len17;
+                                            *base.add(8).cast::<*mut u8>() = ptr17.cast_mut();
+                                            *base
+                                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(t15_1);
+                                        }
+                                    }
+                                }
+                            }
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len19;
+                            *base.add(0).cast::<*mut u8>() = result19;
+                            let super::super::super::golem::rpc::types::WitType {
+                                nodes: nodes20,
+                            } = typ7;
+                            let vec38 = nodes20;
+                            let len38 = vec38.len();
+                            let layout38 = _rt::alloc::Layout::from_size_align_unchecked(
+                                vec38.len()
+                                    * (24 + 6 * ::core::mem::size_of::<*const u8>()),
+                                8,
+                            );
+                            let result38 = if layout38.size() != 0 {
+                                let ptr = _rt::alloc::alloc(layout38).cast::<u8>();
+                                if ptr.is_null() {
+                                    _rt::alloc::handle_alloc_error(layout38);
+                                }
+                                ptr
+                            } else {
+                                ::core::ptr::null_mut()
+                            };
+                            for (i, e) in vec38.into_iter().enumerate() {
+                                let base = result38
+                                    .add(i * (24 + 6 * ::core::mem::size_of::<*const u8>()));
+                                {
+                                    let super::super::super::golem::rpc::types::NamedWitTypeNode {
+                                        name: name21,
+                                        owner: owner21,
+                                        type_: type_21,
+                                    } = e;
+                                    match name21 {
+                                        Some(e) => {
+                                            *base.add(0).cast::<u8>() = (1i32) as u8;
+                                            let vec22 = e;
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len22;
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr22.cast_mut();
+                                        }
+                                        None => {
+                                            *base.add(0).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match owner21 {
+                                        Some(e) => {
+                                            *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec23 = e;
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            *base
+                                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len23;
+                                            *base
+                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr23.cast_mut();
+                                        }
+                                        None => {
+                                            *base
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    use super::super::super::golem::rpc::types::WitTypeNode as V37;
+                                    match type_21 {
+                                        V37::RecordType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                            let vec26 = e;
+                                            let len26 = vec26.len();
+                                            let layout26 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec26.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result26 = if layout26.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout26).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout26);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec26.into_iter().enumerate() {
+                                                let base = result26
+                                                    .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let (t24_0, t24_1) = e;
+                                                    let vec25 = t24_0;
+                                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                                    let len25 = vec25.len();
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len25;
+                                                    *base.add(0).cast::<*mut u8>() = ptr25.cast_mut();
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>() = _rt::as_i32(t24_1);
+                                                }
+                                            }
+                                            *base
+                                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len26;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = result26;
+                                            cleanup_list.extend_from_slice(&[(result26, layout26)]);
+                                        }
+                                        V37::VariantType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec29 = e;
+                                            let len29 = vec29.len();
+                                            let layout29 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec29.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result29 = if layout29.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout29).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout29);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec29.into_iter().enumerate() {
+                                                let base = result29
+                                                    .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let (t27_0, t27_1) = e;
+                                                    let vec28 = t27_0;
+                                                    let ptr28 = vec28.as_ptr().cast::<u8>();
+                                                    let len28 = vec28.len();
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len28;
+                                                    *base.add(0).cast::<*mut u8>() = ptr28.cast_mut();
+                                                    match t27_1 {
+                                                        Some(e) => {
+                                                            *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<u8>() = (1i32) as u8;
+                                                            *base
+                                                                .add(4 + 2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<i32>() = _rt::as_i32(e);
+                                                        }
+                                                        None => {
+                                                            *base
+                                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<u8>() = (0i32) as u8;
+                                                        }
+                                                    };
+                                                }
+                                            }
+                                            *base
+                                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len29;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = result29;
+                                            cleanup_list.extend_from_slice(&[(result29, layout29)]);
+                                        }
+                                        V37::EnumType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (2i32) as u8;
+                                            let vec31 = e;
+                                            let len31 = vec31.len();
+                                            let layout31 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec31.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result31 = if layout31.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout31).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout31);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec31.into_iter().enumerate() {
+                                                let base = result31
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let vec30 = e;
+                                                    let ptr30 = vec30.as_ptr().cast::<u8>();
+                                                    let len30 = vec30.len();
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len30;
+                                                    *base.add(0).cast::<*mut u8>() = ptr30.cast_mut();
+                                                }
+                                            }
+                                            *base
+                                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len31;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = result31;
+                                            cleanup_list.extend_from_slice(&[(result31, layout31)]);
+                                        }
+                                        V37::FlagsType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (3i32) as u8;
+                                            let vec33 = e;
+                                            let len33 = vec33.len();
+                                            let layout33 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                vec33.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                                ::core::mem::size_of::<*const u8>(),
+                                            );
+                                            let result33 = if layout33.size() != 0 {
+                                                let ptr = _rt::alloc::alloc(layout33).cast::<u8>();
+                                                if ptr.is_null() {
+                                                    _rt::alloc::handle_alloc_error(layout33);
+                                                }
+                                                ptr
+                                            } else {
+                                                ::core::ptr::null_mut()
+                                            };
+                                            for (i, e) in vec33.into_iter().enumerate() {
+                                                let base = result33
+                                                    .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                                {
+                                                    let vec32 = e;
+                                                    let ptr32 = vec32.as_ptr().cast::<u8>();
+                                                    let len32 = vec32.len();
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len32;
+                                                    *base.add(0).cast::<*mut u8>() = ptr32.cast_mut();
+                                                }
+                                            }
+                                            *base
+                                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len33;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = result33;
+                                            cleanup_list.extend_from_slice(&[(result33, layout33)]);
+                                        }
+                                        V37::TupleType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (4i32) as u8;
+                                            let vec34 = e;
+                                            let ptr34 = vec34.as_ptr().cast::<u8>();
+                                            let len34 = vec34.len();
+                                            *base
+                                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len34;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr34.cast_mut();
+                                        }
+                                        V37::ListType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (5i32) as u8;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V37::OptionType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (6i32) as u8;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        V37::ResultType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (7i32) as u8;
+                                            let (t35_0, t35_1) = e;
+                                            match t35_0 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    *base
+                                                        .add(12 + 6 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match t35_1 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    *base
+                                                        .add(20 + 6 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        V37::PrimU8Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (8i32) as u8;
+                                        }
+                                        V37::PrimU16Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (9i32) as u8;
+                                        }
+                                        V37::PrimU32Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (10i32) as u8;
+                                        }
+                                        V37::PrimU64Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (11i32) as u8;
+                                        }
+                                        V37::PrimS8Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (12i32) as u8;
+                                        }
+                                        V37::PrimS16Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (13i32) as u8;
+                                        }
+                                        V37::PrimS32Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (14i32) as u8;
+                                        }
+                                        V37::PrimS64Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (15i32) as u8;
+                                        }
+                                        V37::PrimF32Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (16i32) as u8;
+                                        }
+                                        V37::PrimF64Type => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (17i32) as u8;
+                                        }
+                                        V37::PrimCharType => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (18i32) as u8;
+                                        }
+                                        V37::PrimBoolType => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (19i32) as u8;
+                                        }
+                                        V37::PrimStringType => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (20i32) as u8;
+                                        }
+                                        V37::HandleType(e) => {
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (21i32) as u8;
+                                            let (t36_0, t36_1) = e;
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(t36_0);
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (t36_1.clone() as i32) as u8;
+                                        }
+                                    }
+                                }
+                            }
+                            *base
+                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len38;
+                            *base
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = result38;
+                            cleanup_list
+                                .extend_from_slice(
+                                    &[(result19, layout19), (result38, layout38)],
+                                );
+                        }
+                    }
+                    let ptr40 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/saga@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "register-compensation"]
+                        fn wit_import41(
+                            _: i64,
+                            _: i64,
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import41(
+                        _: i64,
+                        _: i64,
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import41(
+                            _rt::as_i64(high_bits1),
+                            _rt::as_i64(low_bits1),
+                            _rt::as_i64(high_bits4),
+                            _rt::as_i64(low_bits4),
+                            ptr5.cast_mut(),
+                            len5,
+                            ptr6.cast_mut(),
+                            len6,
+                            result39,
+                            len39,
+                            ptr40,
+                        )
+                    };
+                    let l42 = i32::from(*ptr40.add(0).cast::<u8>());
+                    let result46 = match l42 {
+                        0 => {
+                            let e = ();
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l43 = *ptr40
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l44 = *ptr40
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len45 = l44;
+                                let bytes45 = _rt::Vec::from_raw_parts(
+                                    l43.cast(),
+                                    len45,
+                                    len45,
+                                );
+                                _rt::string_lift(bytes45)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    if layout39.size() != 0 {
+                        _rt::alloc::dealloc(result39.cast(), layout39);
+                    }
+                    for (ptr, layout) in cleanup_list {
+                        if layout.size() != 0 {
+                            _rt::alloc::dealloc(ptr.cast(), layout);
+                        }
+                    }
+                    result46
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Marks the saga as successfully finished, its compensations are never performed
+            pub fn complete_saga(saga_id: SagaId) -> Result<(), _rt::String> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 3
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let SagaId { uuid: uuid0 } = saga_id;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits1,
+                        low_bits: low_bits1,
+                    } = uuid0;
+                    let ptr2 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/saga@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "complete-saga"]
+                        fn wit_import3(_: i64, _: i64, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import3(_: i64, _: i64, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import3(
+                            _rt::as_i64(high_bits1),
+                            _rt::as_i64(low_bits1),
+                            ptr2,
+                        )
+                    };
+                    let l4 = i32::from(*ptr2.add(0).cast::<u8>());
+                    let result8 = match l4 {
+                        0 => {
+                            let e = ();
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l5 = *ptr2
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l6 = *ptr2
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len7 = l6;
+                                let bytes7 = _rt::Vec::from_raw_parts(
+                                    l5.cast(),
+                                    len7,
+                                    len7,
+                                );
+                                _rt::string_lift(bytes7)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result8
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Aborts the saga. The registered compensations are performed by the executor in the background.
+            ///
+            /// Sagas are also aborted automatically when the coordinator worker fails permanently, or when it gets reverted
+            /// to a point before the saga was started.
+            pub fn abort_saga(saga_id: SagaId, reason: &str) -> Result<(), _rt::String> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 3 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 3
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let SagaId { uuid: uuid0 } = saga_id;
+                    let super::super::super::golem::rpc::types::Uuid {
+                        high_bits: high_bits1,
+                        low_bits: low_bits1,
+                    } = uuid0;
+                    let vec2 = reason;
+                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                    let len2 = vec2.len();
+                    let ptr3 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:api/saga@1.1.7")]
+                    unsafe extern "C" {
+                        #[link_name = "abort-saga"]
+                        fn wit_import4(_: i64, _: i64, _: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import4(
+                        _: i64,
+                        _: i64,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import4(
+                            _rt::as_i64(high_bits1),
+                            _rt::as_i64(low_bits1),
+                            ptr2.cast_mut(),
+                            len2,
+                            ptr3,
+                        )
+                    };
+                    let l5 = i32::from(*ptr3.add(0).cast::<u8>());
+                    let result9 = match l5 {
+                        0 => {
+                            let e = ();
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l6 = *ptr3
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l7 = *ptr3
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len8 = l7;
+                                let bytes8 = _rt::Vec::from_raw_parts(
+                                    l6.cast(),
+                                    len8,
+                                    len8,
+                                );
+                                _rt::string_lift(bytes8)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result9
+                }
+            }
+        }
+    }
+    pub mod rpc {
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod types {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            pub type Datetime = super::super::super::wasi::clocks::wall_clock::Datetime;
+            pub type Pollable = super::super::super::wasi::io::poll::Pollable;
+            /// UUID
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Uuid {
+                pub high_bits: u64,
+                pub low_bits: u64,
+            }
+            impl ::core::fmt::Debug for Uuid {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Uuid")
+                        .field("high-bits", &self.high_bits)
+                        .field("low-bits", &self.low_bits)
+                        .finish()
+                }
+            }
+            /// Represents a Golem component
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct ComponentId {
+                pub uuid: Uuid,
+            }
+            impl ::core::fmt::Debug for ComponentId {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ComponentId").field("uuid", &self.uuid).finish()
+                }
+            }
+            /// Represents a Golem worker
+            #[derive(Clone)]
+            pub struct WorkerId {
+                pub component_id: ComponentId,
+                pub worker_name: _rt::String,
+            }
+            impl ::core::fmt::Debug for WorkerId {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WorkerId")
+                        .field("component-id", &self.component_id)
+                        .field("worker-name", &self.worker_name)
+                        .finish()
+                }
+            }
+            pub type NodeIndex = i32;
+            pub type ResourceId = u64;
+            #[repr(u8)]
+            #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+            pub enum ResourceMode {
+                Owned,
+                Borrowed,
+            }
+            impl ::core::fmt::Debug for ResourceMode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        ResourceMode::Owned => {
+                            f.debug_tuple("ResourceMode::Owned").finish()
+                        }
+                        ResourceMode::Borrowed => {
+                            f.debug_tuple("ResourceMode::Borrowed").finish()
+                        }
+                    }
+                }
+            }
+            impl ResourceMode {
+                #[doc(hidden)]
+                pub unsafe fn _lift(val: u8) -> ResourceMode {
+                    if !cfg!(debug_assertions) {
+                        return ::core::mem::transmute(val);
+                    }
+                    match val {
+                        0 => ResourceMode::Owned,
+                        1 => ResourceMode::Borrowed,
+                        _ => panic!("invalid enum discriminant"),
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub enum WitTypeNode {
+                RecordType(_rt::Vec<(_rt::String, NodeIndex)>),
+                VariantType(_rt::Vec<(_rt::String, Option<NodeIndex>)>),
+                EnumType(_rt::Vec<_rt::String>),
+                FlagsType(_rt::Vec<_rt::String>),
+                TupleType(_rt::Vec<NodeIndex>),
+                ListType(NodeIndex),
+                OptionType(NodeIndex),
+                ResultType((Option<NodeIndex>, Option<NodeIndex>)),
+                PrimU8Type,
+                PrimU16Type,
+                PrimU32Type,
+                PrimU64Type,
+                PrimS8Type,
+                PrimS16Type,
+                PrimS32Type,
+                PrimS64Type,
+                PrimF32Type,
+                PrimF64Type,
+                PrimCharType,
+                PrimBoolType,
+                PrimStringType,
+                HandleType((ResourceId, ResourceMode)),
+            }
+            impl ::core::fmt::Debug for WitTypeNode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        WitTypeNode::RecordType(e) => {
+                            f.debug_tuple("WitTypeNode::RecordType").field(e).finish()
+                        }
+                        WitTypeNode::VariantType(e) => {
+                            f.debug_tuple("WitTypeNode::VariantType").field(e).finish()
+                        }
+                        WitTypeNode::EnumType(e) => {
+                            f.debug_tuple("WitTypeNode::EnumType").field(e).finish()
+                        }
+                        WitTypeNode::FlagsType(e) => {
+                            f.debug_tuple("WitTypeNode::FlagsType").field(e).finish()
+                        }
+                        WitTypeNode::TupleType(e) => {
+                            f.debug_tuple("WitTypeNode::TupleType").field(e).finish()
+                        }
+                        WitTypeNode::ListType(e) => {
+                            f.debug_tuple("WitTypeNode::ListType").field(e).finish()
+                        }
+                        WitTypeNode::OptionType(e) => {
+                            f.debug_tuple("WitTypeNode::OptionType").field(e).finish()
+                        }
+                        WitTypeNode::ResultType(e) => {
+                            f.debug_tuple("WitTypeNode::ResultType").field(e).finish()
+                        }
+                        WitTypeNode::PrimU8Type => {
+                            f.debug_tuple("WitTypeNode::PrimU8Type").finish()
+                        }
+                        WitTypeNode::PrimU16Type => {
+                            f.debug_tuple("WitTypeNode::PrimU16Type").finish()
+                        }
+                        WitTypeNode::PrimU32Type => {
+                            f.debug_tuple("WitTypeNode::PrimU32Type").finish()
+                        }
+                        WitTypeNode::PrimU64Type => {
+                            f.debug_tuple("WitTypeNode::PrimU64Type").finish()
+                        }
+                        WitTypeNode::PrimS8Type => {
+                            f.debug_tuple("WitTypeNode::PrimS8Type").finish()
+                        }
+                        WitTypeNode::PrimS16Type => {
+                            f.debug_tuple("WitTypeNode::PrimS16Type").finish()
+                        }
+                        WitTypeNode::PrimS32Type => {
+                            f.debug_tuple("WitTypeNode::PrimS32Type").finish()
+                        }
+                        WitTypeNode::PrimS64Type => {
+                            f.debug_tuple("WitTypeNode::PrimS64Type").finish()
+                        }
+                        WitTypeNode::PrimF32Type => {
+                            f.debug_tuple("WitTypeNode::PrimF32Type").finish()
+                        }
+                        WitTypeNode::PrimF64Type => {
+                            f.debug_tuple("WitTypeNode::PrimF64Type").finish()
+                        }
+                        WitTypeNode::PrimCharType => {
+                            f.debug_tuple("WitTypeNode::PrimCharType").finish()
+                        }
+                        WitTypeNode::PrimBoolType => {
+                            f.debug_tuple("WitTypeNode::PrimBoolType").finish()
+                        }
+                        WitTypeNode::PrimStringType => {
+                            f.debug_tuple("WitTypeNode::PrimStringType").finish()
+                        }
+                        WitTypeNode::HandleType(e) => {
+                            f.debug_tuple("WitTypeNode::HandleType").field(e).finish()
+                        }
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct NamedWitTypeNode {
+                pub name: Option<_rt::String>,
+                pub owner: Option<_rt::String>,
+                pub type_: WitTypeNode,
+            }
+            impl ::core::fmt::Debug for NamedWitTypeNode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("NamedWitTypeNode")
+                        .field("name", &self.name)
+                        .field("owner", &self.owner)
+                        .field("type", &self.type_)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct WitType {
+                pub nodes: _rt::Vec<NamedWitTypeNode>,
+            }
+            impl ::core::fmt::Debug for WitType {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WitType").field("nodes", &self.nodes).finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct Uri {
+                pub value: _rt::String,
+            }
+            impl ::core::fmt::Debug for Uri {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Uri").field("value", &self.value).finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum WitNode {
+                RecordValue(_rt::Vec<NodeIndex>),
+                VariantValue((u32, Option<NodeIndex>)),
+                EnumValue(u32),
+                FlagsValue(_rt::Vec<bool>),
+                TupleValue(_rt::Vec<NodeIndex>),
+                ListValue(_rt::Vec<NodeIndex>),
+                OptionValue(Option<NodeIndex>),
+                ResultValue(Result<Option<NodeIndex>, Option<NodeIndex>>),
+                PrimU8(u8),
+                PrimU16(u16),
+                PrimU32(u32),
+                PrimU64(u64),
+                PrimS8(i8),
+                PrimS16(i16),
+                PrimS32(i32),
+                PrimS64(i64),
+                PrimFloat32(f32),
+                PrimFloat64(f64),
+                PrimChar(char),
+                PrimBool(bool),
+                PrimString(_rt::String),
+                Handle((Uri, u64)),
+            }
+            impl ::core::fmt::Debug for WitNode {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        WitNode::RecordValue(e) => {
+                            f.debug_tuple("WitNode::RecordValue").field(e).finish()
+                        }
+                        WitNode::VariantValue(e) => {
+                            f.debug_tuple("WitNode::VariantValue").field(e).finish()
+                        }
+                        WitNode::EnumValue(e) => {
+                            f.debug_tuple("WitNode::EnumValue").field(e).finish()
+                        }
+                        WitNode::FlagsValue(e) => {
+                            f.debug_tuple("WitNode::FlagsValue").field(e).finish()
+                        }
+                        WitNode::TupleValue(e) => {
+                            f.debug_tuple("WitNode::TupleValue").field(e).finish()
+                        }
+                        WitNode::ListValue(e) => {
+                            f.debug_tuple("WitNode::ListValue").field(e).finish()
+                        }
+                        WitNode::OptionValue(e) => {
+                            f.debug_tuple("WitNode::OptionValue").field(e).finish()
+                        }
+                        WitNode::ResultValue(e) => {
+                            f.debug_tuple("WitNode::ResultValue").field(e).finish()
+                        }
+                        WitNode::PrimU8(e) => {
+                            f.debug_tuple("WitNode::PrimU8").field(e).finish()
+                        }
+                        WitNode::PrimU16(e) => {
+                            f.debug_tuple("WitNode::PrimU16").field(e).finish()
+                        }
+                        WitNode::PrimU32(e) => {
+                            f.debug_tuple("WitNode::PrimU32").field(e).finish()
+                        }
+                        WitNode::PrimU64(e) => {
+                            f.debug_tuple("WitNode::PrimU64").field(e).finish()
+                        }
+                        WitNode::PrimS8(e) => {
+                            f.debug_tuple("WitNode::PrimS8").field(e).finish()
+                        }
+                        WitNode::PrimS16(e) => {
+                            f.debug_tuple("WitNode::PrimS16").field(e).finish()
+                        }
+                        WitNode::PrimS32(e) => {
+                            f.debug_tuple("WitNode::PrimS32").field(e).finish()
+                        }
+                        WitNode::PrimS64(e) => {
+                            f.debug_tuple("WitNode::PrimS64").field(e).finish()
+                        }
+                        WitNode::PrimFloat32(e) => {
+                            f.debug_tuple("WitNode::PrimFloat32").field(e).finish()
+                        }
+                        WitNode::PrimFloat64(e) => {
+                            f.debug_tuple("WitNode::PrimFloat64").field(e).finish()
+                        }
+                        WitNode::PrimChar(e) => {
+                            f.debug_tuple("WitNode::PrimChar").field(e).finish()
+                        }
+                        WitNode::PrimBool(e) => {
+                            f.debug_tuple("WitNode::PrimBool").field(e).finish()
+                        }
+                        WitNode::PrimString(e) => {
+                            f.debug_tuple("WitNode::PrimString").field(e).finish()
+                        }
+                        WitNode::Handle(e) => {
+                            f.debug_tuple("WitNode::Handle").field(e).finish()
+                        }
+                    }
+                }
+            }
+            #[derive(Clone)]
+            pub struct WitValue {
+                pub nodes: _rt::Vec<WitNode>,
+            }
+            impl ::core::fmt::Debug for WitValue {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("WitValue").field("nodes", &self.nodes).finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct ValueAndType {
+                pub value: WitValue,
+                pub typ: WitType,
+            }
+            impl ::core::fmt::Debug for ValueAndType {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ValueAndType")
+                        .field("value", &self.value)
+                        .field("typ", &self.typ)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub enum RpcError {
+                ProtocolError(_rt::String),
+                Denied(_rt::String),
+                NotFound(_rt::String),
+                RemoteInternalError(_rt::String),
+            }
+            impl ::core::fmt::Debug for RpcError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        RpcError::ProtocolError(e) => {
+                            f.debug_tuple("RpcError::ProtocolError").field(e).finish()
+                        }
+                        RpcError::Denied(e) => {
+                            f.debug_tuple("RpcError::Denied").field(e).finish()
+                        }
+                        RpcError::NotFound(e) => {
+                            f.debug_tuple("RpcError::NotFound").field(e).finish()
+                        }
+                        RpcError::RemoteInternalError(e) => {
+                            f.debug_tuple("RpcError::RemoteInternalError")
+                                .field(e)
+                                .finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for RpcError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+            impl std::error::Error for RpcError {}
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct WasmRpc {
+                handle: _rt::Resource<WasmRpc>,
+            }
+            impl WasmRpc {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for WasmRpc {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]wasm-rpc"]
+                            fn drop(_: u32);
+                        }
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct FutureInvokeResult {
+                handle: _rt::Resource<FutureInvokeResult>,
+            }
+            impl FutureInvokeResult {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for FutureInvokeResult {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]future-invoke-result"]
+                            fn drop(_: u32);
+                        }
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct CancellationToken {
+                handle: _rt::Resource<CancellationToken>,
+            }
+            impl CancellationToken {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for CancellationToken {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]cancellation-token"]
+                            fn drop(_: u32);
+                        }
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Parses a UUID from a string
+            pub fn parse_uuid(uuid: &str) -> Result<Uuid, _rt::String> {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 24]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 24]);
+                    let vec0 = uuid;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                    unsafe extern "C" {
+                        #[link_name = "parse-uuid"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result9 = match l3 {
+                        0 => {
+                            let e = {
+                                let l4 = *ptr1.add(8).cast::<i64>();
+                                let l5 = *ptr1.add(16).cast::<i64>();
+                                Uuid {
+                                    high_bits: l4 as u64,
+                                    low_bits: l5 as u64,
+                                }
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l6 = *ptr1.add(8).cast::<*mut u8>();
+                                let l7 = *ptr1
+                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let len8 = l7;
+                                let bytes8 = _rt::Vec::from_raw_parts(
+                                    l6.cast(),
+                                    len8,
+                                    len8,
+                                );
+                                _rt::string_lift(bytes8)
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result9
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Converts a UUID to a string
+            pub fn uuid_to_string(uuid: Uuid) -> _rt::String {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 2
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let Uuid { high_bits: high_bits0, low_bits: low_bits0 } = uuid;
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                    unsafe extern "C" {
+                        #[link_name = "uuid-to-string"]
+                        fn wit_import2(_: i64, _: i64, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: i64, _: i64, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe {
+                        wit_import2(
+                            _rt::as_i64(high_bits0),
+                            _rt::as_i64(low_bits0),
+                            ptr1,
+                        )
+                    };
+                    let l3 = *ptr1.add(0).cast::<*mut u8>();
+                    let l4 = *ptr1
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len5 = l4;
+                    let bytes5 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                    let result6 = _rt::string_lift(bytes5);
+                    result6
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn new(worker_id: &WorkerId) -> Self {
+                    unsafe {
+                        let WorkerId {
+                            component_id: component_id0,
+                            worker_name: worker_name0,
+                        } = worker_id;
+                        let ComponentId { uuid: uuid1 } = component_id0;
+                        let Uuid { high_bits: high_bits2, low_bits: low_bits2 } = uuid1;
+                        let vec3 = worker_name0;
+                        let ptr3 = vec3.as_ptr().cast::<u8>();
+                        let len3 = vec3.len();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[constructor]wasm-rpc"]
+                            fn wit_import4(_: i64, _: i64, _: *mut u8, _: usize) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import4(
+                            _: i64,
+                            _: i64,
+                            _: *mut u8,
+                            _: usize,
+                        ) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe {
+                            wit_import4(
+                                _rt::as_i64(high_bits2),
+                                _rt::as_i64(low_bits2),
+                                ptr3.cast_mut(),
+                                len3,
+                            )
+                        };
+                        unsafe { WasmRpc::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn ephemeral(component_id: ComponentId) -> WasmRpc {
+                    unsafe {
+                        let ComponentId { uuid: uuid0 } = component_id;
+                        let Uuid { high_bits: high_bits1, low_bits: low_bits1 } = uuid0;
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[static]wasm-rpc.ephemeral"]
+                            fn wit_import2(_: i64, _: i64) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import2(_: i64, _: i64) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe {
+                            wit_import2(_rt::as_i64(high_bits1), _rt::as_i64(low_bits1))
+                        };
+                        unsafe { WasmRpc::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn invoke_and_await(
+                    &self,
+                    function_name: &str,
+                    function_params: &[WitValue],
+                ) -> Result<WitValue, RpcError> {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 4 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 4
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let vec0 = function_name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let vec12 = function_params;
+                        let len12 = vec12.len();
+                        let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec12.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result12 = if layout12.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout12);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec12.into_iter().enumerate() {
+                            let base = result12
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let WitValue { nodes: nodes1 } = e;
+                                let vec11 = nodes1;
+                                let len11 = vec11.len();
+                                let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec11.len()
+                                        * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                    8,
+                                );
+                                let result11 = if layout11.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout11);
+                                    }
+                                    ptr
+                                } else {
+                                    ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec11.into_iter().enumerate() {
+                                    let base = result11
+                                        .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                    {
+                                        match e {
+                                            WitNode::RecordValue(e) => {
+                                                *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                let vec2 = e;
+                                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                                let len2 = vec2.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len2;
+                                                *base.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                                            }
+                                            WitNode::VariantValue(e) => {
+                                                *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                let (t3_0, t3_1) = e;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(t3_0);
+                                                match t3_1 {
+                                                    Some(e) => {
+                                                        *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::EnumValue(e) => {
+                                                *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::FlagsValue(e) => {
+                                                *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                let vec4 = e;
+                                                let len4 = vec4.len();
+                                                let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec4.len() * 1,
+                                                    1,
+                                                );
+                                                let result4 = if layout4.size() != 0 {
+                                                    let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                                    if ptr.is_null() {
+                                                        _rt::alloc::handle_alloc_error(layout4);
+                                                    }
+                                                    ptr
+                                                } else {
+                                                    ::core::ptr::null_mut()
+                                                };
+                                                for (i, e) in vec4.into_iter().enumerate() {
+                                                    let base = result4.add(i * 1);
+                                                    {
+                                                        *base.add(0).cast::<u8>() = (match e {
+                                                            true => 1,
+                                                            false => 0,
+                                                        }) as u8;
+                                                    }
+                                                }
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len4;
+                                                *base.add(8).cast::<*mut u8>() = result4;
+                                                cleanup_list.extend_from_slice(&[(result4, layout4)]);
+                                            }
+                                            WitNode::TupleValue(e) => {
+                                                *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                let vec5 = e;
+                                                let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                let len5 = vec5.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len5;
+                                                *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                            }
+                                            WitNode::ListValue(e) => {
+                                                *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                let vec6 = e;
+                                                let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                let len6 = vec6.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len6;
+                                                *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                            }
+                                            WitNode::OptionValue(e) => {
+                                                *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                match e {
+                                                    Some(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::ResultValue(e) => {
+                                                *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                match e {
+                                                    Ok(e) => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                    Err(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                };
+                                            }
+                                            WitNode::PrimU8(e) => {
+                                                *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimU16(e) => {
+                                                *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimU32(e) => {
+                                                *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimU64(e) => {
+                                                *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimS8(e) => {
+                                                *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimS16(e) => {
+                                                *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimS32(e) => {
+                                                *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimS64(e) => {
+                                                *base.add(0).cast::<u8>() = (15i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimFloat32(e) => {
+                                                *base.add(0).cast::<u8>() = (16i32) as u8;
+                                                *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                            }
+                                            WitNode::PrimFloat64(e) => {
+                                                *base.add(0).cast::<u8>() = (17i32) as u8;
+                                                *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                            }
+                                            WitNode::PrimChar(e) => {
+                                                *base.add(0).cast::<u8>() = (18i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimBool(e) => {
+                                                *base.add(0).cast::<u8>() = (19i32) as u8;
+                                                *base.add(8).cast::<u8>() = (match e {
+                                                    true => 1,
+                                                    false => 0,
+                                                }) as u8;
+                                            }
+                                            WitNode::PrimString(e) => {
+                                                *base.add(0).cast::<u8>() = (20i32) as u8;
+                                                let vec7 = e;
+                                                let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                let len7 = vec7.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len7;
+                                                *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                            }
+                                            WitNode::Handle(e) => {
+                                                *base.add(0).cast::<u8>() = (21i32) as u8;
+                                                let (t8_0, t8_1) = e;
+                                                let Uri { value: value9 } = t8_0;
+                                                let vec10 = value9;
+                                                let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                let len10 = vec10.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len10;
+                                                *base.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                                                *base
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i64>() = _rt::as_i64(t8_1);
+                                            }
+                                        }
+                                    }
+                                }
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len11;
+                                *base.add(0).cast::<*mut u8>() = result11;
+                                cleanup_list.extend_from_slice(&[(result11, layout11)]);
+                            }
+                        }
+                        let ptr13 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]wasm-rpc.invoke-and-await"]
+                            fn wit_import14(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                            );
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import14(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import14(
+                                (self).handle() as i32,
+                                ptr0.cast_mut(),
+                                len0,
+                                result12,
+                                len12,
+                                ptr13,
+                            )
+                        };
+                        let l15 = i32::from(*ptr13.add(0).cast::<u8>());
+                        let result78 = match l15 {
+                            0 => {
+                                let e = {
+                                    let l16 = *ptr13
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l17 = *ptr13
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base63 = l16;
+                                    let len63 = l17;
+                                    let mut result63 = _rt::Vec::with_capacity(len63);
+                                    for i in 0..len63 {
+                                        let base = base63
+                                            .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                        let e63 = {
+                                            let l18 = i32::from(*base.add(0).cast::<u8>());
+                                            let v62 = match l18 {
+                                                0 => {
+                                                    let e62 = {
+                                                        let l19 = *base.add(8).cast::<*mut u8>();
+                                                        let l20 = *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len21 = l20;
+                                                        _rt::Vec::from_raw_parts(l19.cast(), len21, len21)
+                                                    };
+                                                    WitNode::RecordValue(e62)
+                                                }
+                                                1 => {
+                                                    let e62 = {
+                                                        let l22 = *base.add(8).cast::<i32>();
+                                                        let l23 = i32::from(*base.add(12).cast::<u8>());
+                                                        (
+                                                            l22 as u32,
+                                                            match l23 {
+                                                                0 => None,
+                                                                1 => {
+                                                                    let e = {
+                                                                        let l24 = *base.add(16).cast::<i32>();
+                                                                        l24
+                                                                    };
+                                                                    Some(e)
+                                                                }
+                                                                _ => _rt::invalid_enum_discriminant(),
+                                                            },
+                                                        )
+                                                    };
+                                                    WitNode::VariantValue(e62)
+                                                }
+                                                2 => {
+                                                    let e62 = {
+                                                        let l25 = *base.add(8).cast::<i32>();
+                                                        l25 as u32
+                                                    };
+                                                    WitNode::EnumValue(e62)
+                                                }
+                                                3 => {
+                                                    let e62 = {
+                                                        let l26 = *base.add(8).cast::<*mut u8>();
+                                                        let l27 = *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let base29 = l26;
+                                                        let len29 = l27;
+                                                        let mut result29 = _rt::Vec::with_capacity(len29);
+                                                        for i in 0..len29 {
+                                                            let base = base29.add(i * 1);
+                                                            let e29 = {
+                                                                let l28 = i32::from(*base.add(0).cast::<u8>());
+                                                                _rt::bool_lift(l28 as u8)
+                                                            };
+                                                            result29.push(e29);
+                                                        }
+                                                        _rt::cabi_dealloc(base29, len29 * 1, 1);
+                                                        result29
+                                                    };
+                                                    WitNode::FlagsValue(e62)
+                                                }
+                                                4 => {
+                                                    let e62 = {
+                                                        let l30 = *base.add(8).cast::<*mut u8>();
+                                                        let l31 = *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len32 = l31;
+                                                        _rt::Vec::from_raw_parts(l30.cast(), len32, len32)
+                                                    };
+                                                    WitNode::TupleValue(e62)
+                                                }
+                                                5 => {
+                                                    let e62 = {
+                                                        let l33 = *base.add(8).cast::<*mut u8>();
+                                                        let l34 = *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len35 = l34;
+                                                        _rt::Vec::from_raw_parts(l33.cast(), len35, len35)
+                                                    };
+                                                    WitNode::ListValue(e62)
+                                                }
+                                                6 => {
+                                                    let e62 = {
+                                                        let l36 = i32::from(*base.add(8).cast::<u8>());
+                                                        match l36 {
+                                                            0 => None,
+                                                            1 => {
+                                                                let e = {
+                                                                    let l37 = *base.add(12).cast::<i32>();
+                                                                    l37
+                                                                };
+                                                                Some(e)
+                                                            }
+                                                            _ => _rt::invalid_enum_discriminant(),
+                                                        }
+                                                    };
+                                                    WitNode::OptionValue(e62)
+                                                }
+                                                7 => {
+                                                    let e62 = {
+                                                        let l38 = i32::from(*base.add(8).cast::<u8>());
+                                                        match l38 {
+                                                            0 => {
+                                                                let e = {
+                                                                    let l39 = i32::from(*base.add(12).cast::<u8>());
+                                                                    match l39 {
+                                                                        0 => None,
+                                                                        1 => {
+                                                                            let e = {
+                                                                                let l40 = *base.add(16).cast::<i32>();
+                                                                                l40
+                                                                            };
+                                                                            Some(e)
+                                                                        }
+                                                                        _ => _rt::invalid_enum_discriminant(),
+                                                                    }
+                                                                };
+                                                                Ok(e)
+                                                            }
+                                                            1 => {
+                                                                let e = {
+                                                                    let l41 = i32::from(*base.add(12).cast::<u8>());
+                                                                    match l41 {
+                                                                        0 => None,
+                                                                        1 => {
+                                                                            let e = {
+                                                                                let l42 = *base.add(16).cast::<i32>();
+                                                                                l42
+                                                                            };
+                                                                            Some(e)
+                                                                        }
+                                                                        _ => _rt::invalid_enum_discriminant(),
+                                                                    }
+                                                                };
+                                                                Err(e)
+                                                            }
+                                                            _ => _rt::invalid_enum_discriminant(),
+                                                        }
+                                                    };
+                                                    WitNode::ResultValue(e62)
+                                                }
+                                                8 => {
+                                                    let e62 = {
+                                                        let l43 = i32::from(*base.add(8).cast::<u8>());
+                                                        l43 as u8
+                                                    };
+                                                    WitNode::PrimU8(e62)
+                                                }
+                                                9 => {
+                                                    let e62 = {
+                                                        let l44 = i32::from(*base.add(8).cast::<u16>());
+                                                        l44 as u16
+                                                    };
+                                                    WitNode::PrimU16(e62)
+                                                }
+                                                10 => {
+                                                    let e62 = {
+                                                        let l45 = *base.add(8).cast::<i32>();
+                                                        l45 as u32
+                                                    };
+                                                    WitNode::PrimU32(e62)
+                                                }
+                                                11 => {
+                                                    let e62 = {
+                                                        let l46 = *base.add(8).cast::<i64>();
+                                                        l46 as u64
+                                                    };
+                                                    WitNode::PrimU64(e62)
+                                                }
+                                                12 => {
+                                                    let e62 = {
+                                                        let l47 = i32::from(*base.add(8).cast::<i8>());
+                                                        l47 as i8
+                                                    };
+                                                    WitNode::PrimS8(e62)
+                                                }
+                                                13 => {
+                                                    let e62 = {
+                                                        let l48 = i32::from(*base.add(8).cast::<i16>());
+                                                        l48 as i16
+                                                    };
+                                                    WitNode::PrimS16(e62)
+                                                }
+                                                14 => {
+                                                    let e62 = {
+                                                        let l49 = *base.add(8).cast::<i32>();
+                                                        l49
+                                                    };
+                                                    WitNode::PrimS32(e62)
+                                                }
+                                                15 => {
+                                                    let e62 = {
+                                                        let l50 = *base.add(8).cast::<i64>();
+                                                        l50
+                                                    };
+                                                    WitNode::PrimS64(e62)
+                                                }
+                                                16 => {
+                                                    let e62 = {
+                                                        let l51 = *base.add(8).cast::<f32>();
+                                                        l51
+                                                    };
+                                                    WitNode::PrimFloat32(e62)
+                                                }
+                                                17 => {
+                                                    let e62 = {
+                                                        let l52 = *base.add(8).cast::<f64>();
+                                                        l52
+                                                    };
+                                                    WitNode::PrimFloat64(e62)
+                                                }
+                                                18 => {
+                                                    let e62 = {
+                                                        let l53 = *base.add(8).cast::<i32>();
+                                                        _rt::char_lift(l53 as u32)
+                                                    };
+                                                    WitNode::PrimChar(e62)
+                                                }
+                                                19 => {
+                                                    let e62 = {
+                                                        let l54 = i32::from(*base.add(8).cast::<u8>());
+                                                        _rt::bool_lift(l54 as u8)
+                                                    };
+                                                    WitNode::PrimBool(e62)
+                                                }
+                                                20 => {
+                                                    let e62 = {
+                                                        let l55 = *base.add(8).cast::<*mut u8>();
+                                                        let l56 = *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len57 = l56;
+                                                        let bytes57 = _rt::Vec::from_raw_parts(
+                                                            l55.cast(),
+                                                            len57,
+                                                            len57,
+                                                        );
+                                                        _rt::string_lift(bytes57)
+                                                    };
+                                                    WitNode::PrimString(e62)
+                                                }
+                                                n => {
+                                                    debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                                    let e62 = {
+                                                        let l58 = *base.add(8).cast::<*mut u8>();
+                                                        let l59 = *base
+                                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<usize>();
+                                                        let len60 = l59;
+                                                        let bytes60 = _rt::Vec::from_raw_parts(
+                                                            l58.cast(),
+                                                            len60,
+                                                            len60,
+                                                        );
+                                                        let l61 = *base
+                                                            .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i64>();
+                                                        (
+                                                            Uri {
+                                                                value: _rt::string_lift(bytes60),
+                                                            },
+                                                            l61 as u64,
+                                                        )
+                                                    };
+                                                    WitNode::Handle(e62)
+                                                }
+                                            };
+                                            v62
+                                        };
+                                        result63.push(e63);
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base63,
+                                        len63 * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                        8,
+                                    );
+                                    WitValue { nodes: result63 }
+                                };
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l64 = i32::from(
+                                        *ptr13.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    let v77 = match l64 {
+                                        0 => {
+                                            let e77 = {
+                                                let l65 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l66 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len67 = l66;
+                                                let bytes67 = _rt::Vec::from_raw_parts(
+                                                    l65.cast(),
+                                                    len67,
+                                                    len67,
+                                                );
+                                                _rt::string_lift(bytes67)
+                                            };
+                                            RpcError::ProtocolError(e77)
+                                        }
+                                        1 => {
+                                            let e77 = {
+                                                let l68 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l69 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len70 = l69;
+                                                let bytes70 = _rt::Vec::from_raw_parts(
+                                                    l68.cast(),
+                                                    len70,
+                                                    len70,
+                                                );
+                                                _rt::string_lift(bytes70)
+                                            };
+                                            RpcError::Denied(e77)
+                                        }
+                                        2 => {
+                                            let e77 = {
+                                                let l71 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l72 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len73 = l72;
+                                                let bytes73 = _rt::Vec::from_raw_parts(
+                                                    l71.cast(),
+                                                    len73,
+                                                    len73,
+                                                );
+                                                _rt::string_lift(bytes73)
+                                            };
+                                            RpcError::NotFound(e77)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                            let e77 = {
+                                                let l74 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l75 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len76 = l75;
+                                                let bytes76 = _rt::Vec::from_raw_parts(
+                                                    l74.cast(),
+                                                    len76,
+                                                    len76,
+                                                );
+                                                _rt::string_lift(bytes76)
+                                            };
+                                            RpcError::RemoteInternalError(e77)
+                                        }
+                                    };
+                                    v77
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        if layout12.size() != 0 {
+                            _rt::alloc::dealloc(result12.cast(), layout12);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        result78
+                    }
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn invoke(
+                    &self,
+                    function_name: &str,
+                    function_params: &[WitValue],
+                ) -> Result<(), RpcError> {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 4 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 4
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let vec0 = function_name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let vec12 = function_params;
+                        let len12 = vec12.len();
+                        let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec12.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result12 = if layout12.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout12);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec12.into_iter().enumerate() {
+                            let base = result12
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let WitValue { nodes: nodes1 } = e;
+                                let vec11 = nodes1;
+                                let len11 = vec11.len();
+                                let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec11.len()
+                                        * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                    8,
+                                );
+                                let result11 = if layout11.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout11);
+                                    }
+                                    ptr
+                                } else {
+                                    ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec11.into_iter().enumerate() {
+                                    let base = result11
+                                        .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                    {
+                                        match e {
+                                            WitNode::RecordValue(e) => {
+                                                *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                let vec2 = e;
+                                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                                let len2 = vec2.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len2;
+                                                *base.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                                            }
+                                            WitNode::VariantValue(e) => {
+                                                *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                let (t3_0, t3_1) = e;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(t3_0);
+                                                match t3_1 {
+                                                    Some(e) => {
+                                                        *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::EnumValue(e) => {
+                                                *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::FlagsValue(e) => {
+                                                *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                let vec4 = e;
+                                                let len4 = vec4.len();
+                                                let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec4.len() * 1,
+                                                    1,
+                                                );
+                                                let result4 = if layout4.size() != 0 {
+                                                    let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                                    if ptr.is_null() {
+                                                        _rt::alloc::handle_alloc_error(layout4);
+                                                    }
+                                                    ptr
+                                                } else {
+                                                    ::core::ptr::null_mut()
+                                                };
+                                                for (i, e) in vec4.into_iter().enumerate() {
+                                                    let base = result4.add(i * 1);
+                                                    {
+                                                        *base.add(0).cast::<u8>() = (match e {
+                                                            true => 1,
+                                                            false => 0,
+                                                        }) as u8;
+                                                    }
+                                                }
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len4;
+                                                *base.add(8).cast::<*mut u8>() = result4;
+                                                cleanup_list.extend_from_slice(&[(result4, layout4)]);
+                                            }
+                                            WitNode::TupleValue(e) => {
+                                                *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                let vec5 = e;
+                                                let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                let len5 = vec5.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len5;
+                                                *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                            }
+                                            WitNode::ListValue(e) => {
+                                                *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                let vec6 = e;
+                                                let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                let len6 = vec6.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len6;
+                                                *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                            }
+                                            WitNode::OptionValue(e) => {
+                                                *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                match e {
+                                                    Some(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::ResultValue(e) => {
+                                                *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                match e {
+                                                    Ok(e) => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                    Err(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                };
+                                            }
+                                            WitNode::PrimU8(e) => {
+                                                *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimU16(e) => {
+                                                *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimU32(e) => {
+                                                *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimU64(e) => {
+                                                *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimS8(e) => {
+                                                *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimS16(e) => {
+                                                *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimS32(e) => {
+                                                *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimS64(e) => {
+                                                *base.add(0).cast::<u8>() = (15i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimFloat32(e) => {
+                                                *base.add(0).cast::<u8>() = (16i32) as u8;
+                                                *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                            }
+                                            WitNode::PrimFloat64(e) => {
+                                                *base.add(0).cast::<u8>() = (17i32) as u8;
+                                                *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                            }
+                                            WitNode::PrimChar(e) => {
+                                                *base.add(0).cast::<u8>() = (18i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimBool(e) => {
+                                                *base.add(0).cast::<u8>() = (19i32) as u8;
+                                                *base.add(8).cast::<u8>() = (match e {
+                                                    true => 1,
+                                                    false => 0,
+                                                }) as u8;
+                                            }
+                                            WitNode::PrimString(e) => {
+                                                *base.add(0).cast::<u8>() = (20i32) as u8;
+                                                let vec7 = e;
+                                                let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                let len7 = vec7.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len7;
+                                                *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                            }
+                                            WitNode::Handle(e) => {
+                                                *base.add(0).cast::<u8>() = (21i32) as u8;
+                                                let (t8_0, t8_1) = e;
+                                                let Uri { value: value9 } = t8_0;
+                                                let vec10 = value9;
+                                                let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                let len10 = vec10.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len10;
+                                                *base.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                                                *base
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i64>() = _rt::as_i64(t8_1);
+                                            }
+                                        }
+                                    }
+                                }
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len11;
+                                *base.add(0).cast::<*mut u8>() = result11;
+                                cleanup_list.extend_from_slice(&[(result11, layout11)]);
+                            }
+                        }
+                        let ptr13 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]wasm-rpc.invoke"]
+                            fn wit_import14(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                            );
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import14(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import14(
+                                (self).handle() as i32,
+                                ptr0.cast_mut(),
+                                len0,
+                                result12,
+                                len12,
+                                ptr13,
+                            )
+                        };
+                        let l15 = i32::from(*ptr13.add(0).cast::<u8>());
+                        let result30 = match l15 {
+                            0 => {
+                                let e = ();
+                                Ok(e)
+                            }
+                            1 => {
+                                let e = {
+                                    let l16 = i32::from(
+                                        *ptr13.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    let v29 = match l16 {
+                                        0 => {
+                                            let e29 = {
+                                                let l17 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l18 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len19 = l18;
+                                                let bytes19 = _rt::Vec::from_raw_parts(
+                                                    l17.cast(),
+                                                    len19,
+                                                    len19,
+                                                );
+                                                _rt::string_lift(bytes19)
+                                            };
+                                            RpcError::ProtocolError(e29)
+                                        }
+                                        1 => {
+                                            let e29 = {
+                                                let l20 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l21 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len22 = l21;
+                                                let bytes22 = _rt::Vec::from_raw_parts(
+                                                    l20.cast(),
+                                                    len22,
+                                                    len22,
+                                                );
+                                                _rt::string_lift(bytes22)
+                                            };
+                                            RpcError::Denied(e29)
+                                        }
+                                        2 => {
+                                            let e29 = {
+                                                let l23 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l24 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len25 = l24;
+                                                let bytes25 = _rt::Vec::from_raw_parts(
+                                                    l23.cast(),
+                                                    len25,
+                                                    len25,
+                                                );
+                                                _rt::string_lift(bytes25)
+                                            };
+                                            RpcError::NotFound(e29)
+                                        }
+                                        n => {
+                                            debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                            let e29 = {
+                                                let l26 = *ptr13
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l27 = *ptr13
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len28 = l27;
+                                                let bytes28 = _rt::Vec::from_raw_parts(
+                                                    l26.cast(),
+                                                    len28,
+                                                    len28,
+                                                );
+                                                _rt::string_lift(bytes28)
+                                            };
+                                            RpcError::RemoteInternalError(e29)
+                                        }
+                                    };
+                                    v29
+                                };
+                                Err(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        if layout12.size() != 0 {
+                            _rt::alloc::dealloc(result12.cast(), layout12);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        result30
+                    }
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn async_invoke_and_await(
+                    &self,
+                    function_name: &str,
+                    function_params: &[WitValue],
+                ) -> FutureInvokeResult {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        let vec0 = function_name;
+                        let ptr0 = vec0.as_ptr().cast::<u8>();
+                        let len0 = vec0.len();
+                        let vec12 = function_params;
+                        let len12 = vec12.len();
+                        let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec12.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result12 = if layout12.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout12);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec12.into_iter().enumerate() {
+                            let base = result12
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let WitValue { nodes: nodes1 } = e;
+                                let vec11 = nodes1;
+                                let len11 = vec11.len();
+                                let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec11.len()
+                                        * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                    8,
+                                );
+                                let result11 = if layout11.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout11);
+                                    }
+                                    ptr
+                                } else {
+                                    ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec11.into_iter().enumerate() {
+                                    let base = result11
+                                        .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                    {
+                                        match e {
+                                            WitNode::RecordValue(e) => {
+                                                *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                let vec2 = e;
+                                                let ptr2 = vec2.as_ptr().cast::<u8>();
+                                                let len2 = vec2.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len2;
+                                                *base.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                                            }
+                                            WitNode::VariantValue(e) => {
+                                                *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                let (t3_0, t3_1) = e;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(t3_0);
+                                                match t3_1 {
+                                                    Some(e) => {
+                                                        *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::EnumValue(e) => {
+                                                *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::FlagsValue(e) => {
+                                                *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                let vec4 = e;
+                                                let len4 = vec4.len();
+                                                let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec4.len() * 1,
+                                                    1,
+                                                );
+                                                let result4 = if layout4.size() != 0 {
+                                                    let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                                    if ptr.is_null() {
+                                                        _rt::alloc::handle_alloc_error(layout4);
+                                                    }
+                                                    ptr
+                                                } else {
+                                                    ::core::ptr::null_mut()
+                                                };
+                                                for (i, e) in vec4.into_iter().enumerate() {
+                                                    let base = result4.add(i * 1);
+                                                    {
+                                                        *base.add(0).cast::<u8>() = (match e {
+                                                            true => 1,
+                                                            false => 0,
+                                                        }) as u8;
+                                                    }
+                                                }
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len4;
+                                                *base.add(8).cast::<*mut u8>() = result4;
+                                                cleanup_list.extend_from_slice(&[(result4, layout4)]);
+                                            }
+                                            WitNode::TupleValue(e) => {
+                                                *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                let vec5 = e;
+                                                let ptr5 = vec5.as_ptr().cast::<u8>();
+                                                let len5 = vec5.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len5;
+                                                *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                            }
+                                            WitNode::ListValue(e) => {
+                                                *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                let vec6 = e;
+                                                let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                let len6 = vec6.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len6;
+                                                *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                            }
+                                            WitNode::OptionValue(e) => {
+                                                *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                match e {
+                                                    Some(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::ResultValue(e) => {
+                                                *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                match e {
+                                                    Ok(e) => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                    Err(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                };
+                                            }
+                                            WitNode::PrimU8(e) => {
+                                                *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimU16(e) => {
+                                                *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimU32(e) => {
+                                                *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimU64(e) => {
+                                                *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimS8(e) => {
+                                                *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimS16(e) => {
+                                                *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimS32(e) => {
+                                                *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimS64(e) => {
+                                                *base.add(0).cast::<u8>() = (15i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimFloat32(e) => {
+                                                *base.add(0).cast::<u8>() = (16i32) as u8;
+                                                *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                            }
+                                            WitNode::PrimFloat64(e) => {
+                                                *base.add(0).cast::<u8>() = (17i32) as u8;
+                                                *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                            }
+                                            WitNode::PrimChar(e) => {
+                                                *base.add(0).cast::<u8>() = (18i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimBool(e) => {
+                                                *base.add(0).cast::<u8>() = (19i32) as u8;
+                                                *base.add(8).cast::<u8>() = (match e {
+                                                    true => 1,
+                                                    false => 0,
+                                                }) as u8;
+                                            }
+                                            WitNode::PrimString(e) => {
+                                                *base.add(0).cast::<u8>() = (20i32) as u8;
+                                                let vec7 = e;
+                                                let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                let len7 = vec7.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len7;
+                                                *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                            }
+                                            WitNode::Handle(e) => {
+                                                *base.add(0).cast::<u8>() = (21i32) as u8;
+                                                let (t8_0, t8_1) = e;
+                                                let Uri { value: value9 } = t8_0;
+                                                let vec10 = value9;
+                                                let ptr10 = vec10.as_ptr().cast::<u8>();
+                                                let len10 = vec10.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len10;
+                                                *base.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                                                *base
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i64>() = _rt::as_i64(t8_1);
+                                            }
+                                        }
+                                    }
+                                }
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len11;
+                                *base.add(0).cast::<*mut u8>() = result11;
+                                cleanup_list.extend_from_slice(&[(result11, layout11)]);
+                            }
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]wasm-rpc.async-invoke-and-await"]
+                            fn wit_import13(
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                            ) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import13(
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                        ) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe {
+                            wit_import13(
+                                (self).handle() as i32,
+                                ptr0.cast_mut(),
+                                len0,
+                                result12,
+                                len12,
+                            )
+                        };
+                        if layout12.size() != 0 {
+                            _rt::alloc::dealloc(result12.cast(), layout12);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        unsafe { FutureInvokeResult::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Schedule invocation for later
+                pub fn schedule_invocation(
+                    &self,
+                    scheduled_time: Datetime,
+                    function_name: &str,
+                    function_params: &[WitValue],
+                ) -> () {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        let super::super::super::wasi::clocks::wall_clock::Datetime {
+                            seconds: seconds0,
+                            nanoseconds: nanoseconds0,
+                        } = scheduled_time;
+                        let vec1 = function_name;
+                        let ptr1 = vec1.as_ptr().cast::<u8>();
+                        let len1 = vec1.len();
+                        let vec13 = function_params;
+                        let len13 = vec13.len();
+                        let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result13 = if layout13.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout13);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec13.into_iter().enumerate() {
+                            let base = result13
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let WitValue { nodes: nodes2 } = e;
+                                let vec12 = nodes2;
+                                let len12 = vec12.len();
+                                let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec12.len()
+                                        * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                    8,
+                                );
+                                let result12 = if layout12.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout12);
+                                    }
+                                    ptr
+                                } else {
+                                    ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec12.into_iter().enumerate() {
+                                    let base = result12
+                                        .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                    {
+                                        match e {
+                                            WitNode::RecordValue(e) => {
+                                                *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                let vec3 = e;
+                                                let ptr3 = vec3.as_ptr().cast::<u8>();
+                                                let len3 = vec3.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len3;
+                                                *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                            }
+                                            WitNode::VariantValue(e) => {
+                                                *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                let (t4_0, t4_1) = e;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(t4_0);
+                                                match t4_1 {
+                                                    Some(e) => {
+                                                        *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::EnumValue(e) => {
+                                                *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::FlagsValue(e) => {
+                                                *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                let vec5 = e;
+                                                let len5 = vec5.len();
+                                                let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec5.len() * 1,
+                                                    1,
+                                                );
+                                                let result5 = if layout5.size() != 0 {
+                                                    let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                                                    if ptr.is_null() {
+                                                        _rt::alloc::handle_alloc_error(layout5);
+                                                    }
+                                                    ptr
+                                                } else {
+                                                    ::core::ptr::null_mut()
+                                                };
+                                                for (i, e) in vec5.into_iter().enumerate() {
+                                                    let base = result5.add(i * 1);
+                                                    {
+                                                        *base.add(0).cast::<u8>() = (match e {
+                                                            true => 1,
+                                                            false => 0,
+                                                        }) as u8;
+                                                    }
+                                                }
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len5;
+                                                *base.add(8).cast::<*mut u8>() = result5;
+                                                cleanup_list.extend_from_slice(&[(result5, layout5)]);
+                                            }
+                                            WitNode::TupleValue(e) => {
+                                                *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                let vec6 = e;
+                                                let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                let len6 = vec6.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len6;
+                                                *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                            }
+                                            WitNode::ListValue(e) => {
+                                                *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                let vec7 = e;
+                                                let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                let len7 = vec7.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len7;
+                                                *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                            }
+                                            WitNode::OptionValue(e) => {
+                                                *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                match e {
+                                                    Some(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::ResultValue(e) => {
+                                                *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                match e {
+                                                    Ok(e) => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                    Err(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                };
+                                            }
+                                            WitNode::PrimU8(e) => {
+                                                *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimU16(e) => {
+                                                *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimU32(e) => {
+                                                *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimU64(e) => {
+                                                *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimS8(e) => {
+                                                *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimS16(e) => {
+                                                *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimS32(e) => {
+                                                *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimS64(e) => {
+                                                *base.add(0).cast::<u8>() = (15i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimFloat32(e) => {
+                                                *base.add(0).cast::<u8>() = (16i32) as u8;
+                                                *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                            }
+                                            WitNode::PrimFloat64(e) => {
+                                                *base.add(0).cast::<u8>() = (17i32) as u8;
+                                                *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                            }
+                                            WitNode::PrimChar(e) => {
+                                                *base.add(0).cast::<u8>() = (18i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimBool(e) => {
+                                                *base.add(0).cast::<u8>() = (19i32) as u8;
+                                                *base.add(8).cast::<u8>() = (match e {
+                                                    true => 1,
+                                                    false => 0,
+                                                }) as u8;
+                                            }
+                                            WitNode::PrimString(e) => {
+                                                *base.add(0).cast::<u8>() = (20i32) as u8;
+                                                let vec8 = e;
+                                                let ptr8 = vec8.as_ptr().cast::<u8>();
+                                                let len8 = vec8.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len8;
+                                                *base.add(8).cast::<*mut u8>() = ptr8.cast_mut();
+                                            }
+                                            WitNode::Handle(e) => {
+                                                *base.add(0).cast::<u8>() = (21i32) as u8;
+                                                let (t9_0, t9_1) = e;
+                                                let Uri { value: value10 } = t9_0;
+                                                let vec11 = value10;
+                                                let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                let len11 = vec11.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len11;
+                                                *base.add(8).cast::<*mut u8>() = ptr11.cast_mut();
+                                                *base
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i64>() = _rt::as_i64(t9_1);
+                                            }
+                                        }
+                                    }
+                                }
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len12;
+                                *base.add(0).cast::<*mut u8>() = result12;
+                                cleanup_list.extend_from_slice(&[(result12, layout12)]);
+                            }
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]wasm-rpc.schedule-invocation"]
+                            fn wit_import14(
+                                _: i32,
+                                _: i64,
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                            );
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import14(
+                            _: i32,
+                            _: i64,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                        ) {
+                            unreachable!()
+                        }
+                        unsafe {
+                            wit_import14(
+                                (self).handle() as i32,
+                                _rt::as_i64(seconds0),
+                                _rt::as_i32(nanoseconds0),
+                                ptr1.cast_mut(),
+                                len1,
+                                result13,
+                                len13,
+                            )
+                        };
+                        if layout13.size() != 0 {
+                            _rt::alloc::dealloc(result13.cast(), layout13);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                    }
+                }
+            }
+            impl WasmRpc {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Schedule invocation for later. Call cancel on the returned resource to cancel the invocation before the scheduled time.
+                pub fn schedule_cancelable_invocation(
+                    &self,
+                    scheduled_time: Datetime,
+                    function_name: &str,
+                    function_params: &[WitValue],
+                ) -> CancellationToken {
+                    unsafe {
+                        let mut cleanup_list = _rt::Vec::new();
+                        let super::super::super::wasi::clocks::wall_clock::Datetime {
+                            seconds: seconds0,
+                            nanoseconds: nanoseconds0,
+                        } = scheduled_time;
+                        let vec1 = function_name;
+                        let ptr1 = vec1.as_ptr().cast::<u8>();
+                        let len1 = vec1.len();
+                        let vec13 = function_params;
+                        let len13 = vec13.len();
+                        let layout13 = _rt::alloc::Layout::from_size_align_unchecked(
+                            vec13.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                            ::core::mem::size_of::<*const u8>(),
+                        );
+                        let result13 = if layout13.size() != 0 {
+                            let ptr = _rt::alloc::alloc(layout13).cast::<u8>();
+                            if ptr.is_null() {
+                                _rt::alloc::handle_alloc_error(layout13);
+                            }
+                            ptr
+                        } else {
+                            ::core::ptr::null_mut()
+                        };
+                        for (i, e) in vec13.into_iter().enumerate() {
+                            let base = result13
+                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                            {
+                                let WitValue { nodes: nodes2 } = e;
+                                let vec12 = nodes2;
+                                let len12 = vec12.len();
+                                let layout12 = _rt::alloc::Layout::from_size_align_unchecked(
+                                    vec12.len()
+                                        * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                    8,
+                                );
+                                let result12 = if layout12.size() != 0 {
+                                    let ptr = _rt::alloc::alloc(layout12).cast::<u8>();
+                                    if ptr.is_null() {
+                                        _rt::alloc::handle_alloc_error(layout12);
+                                    }
+                                    ptr
+                                } else {
+                                    ::core::ptr::null_mut()
+                                };
+                                for (i, e) in vec12.into_iter().enumerate() {
+                                    let base = result12
+                                        .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                    {
+                                        match e {
+                                            WitNode::RecordValue(e) => {
+                                                *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                let vec3 = e;
+                                                let ptr3 = vec3.as_ptr().cast::<u8>();
+                                                let len3 = vec3.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len3;
+                                                *base.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                                            }
+                                            WitNode::VariantValue(e) => {
+                                                *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                let (t4_0, t4_1) = e;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(t4_0);
+                                                match t4_1 {
+                                                    Some(e) => {
+                                                        *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::EnumValue(e) => {
+                                                *base.add(0).cast::<u8>() = (2i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::FlagsValue(e) => {
+                                                *base.add(0).cast::<u8>() = (3i32) as u8;
+                                                let vec5 = e;
+                                                let len5 = vec5.len();
+                                                let layout5 = _rt::alloc::Layout::from_size_align_unchecked(
+                                                    vec5.len() * 1,
+                                                    1,
+                                                );
+                                                let result5 = if layout5.size() != 0 {
+                                                    let ptr = _rt::alloc::alloc(layout5).cast::<u8>();
+                                                    if ptr.is_null() {
+                                                        _rt::alloc::handle_alloc_error(layout5);
+                                                    }
+                                                    ptr
+                                                } else {
+                                                    ::core::ptr::null_mut()
+                                                };
+                                                for (i, e) in vec5.into_iter().enumerate() {
+                                                    let base = result5.add(i * 1);
+                                                    {
+                                                        *base.add(0).cast::<u8>() = (match e {
+                                                            true => 1,
+                                                            false => 0,
+                                                        }) as u8;
+                                                    }
+                                                }
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len5;
+                                                *base.add(8).cast::<*mut u8>() = result5;
+                                                cleanup_list.extend_from_slice(&[(result5, layout5)]);
+                                            }
+                                            WitNode::TupleValue(e) => {
+                                                *base.add(0).cast::<u8>() = (4i32) as u8;
+                                                let vec6 = e;
+                                                let ptr6 = vec6.as_ptr().cast::<u8>();
+                                                let len6 = vec6.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len6;
+                                                *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                            }
+                                            WitNode::ListValue(e) => {
+                                                *base.add(0).cast::<u8>() = (5i32) as u8;
+                                                let vec7 = e;
+                                                let ptr7 = vec7.as_ptr().cast::<u8>();
+                                                let len7 = vec7.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len7;
+                                                *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                            }
+                                            WitNode::OptionValue(e) => {
+                                                *base.add(0).cast::<u8>() = (6i32) as u8;
+                                                match e {
+                                                    Some(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                                    }
+                                                    None => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                    }
+                                                };
+                                            }
+                                            WitNode::ResultValue(e) => {
+                                                *base.add(0).cast::<u8>() = (7i32) as u8;
+                                                match e {
+                                                    Ok(e) => {
+                                                        *base.add(8).cast::<u8>() = (0i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                    Err(e) => {
+                                                        *base.add(8).cast::<u8>() = (1i32) as u8;
+                                                        match e {
+                                                            Some(e) => {
+                                                                *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                                *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                            }
+                                                            None => {
+                                                                *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                            }
+                                                        };
+                                                    }
+                                                };
+                                            }
+                                            WitNode::PrimU8(e) => {
+                                                *base.add(0).cast::<u8>() = (8i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimU16(e) => {
+                                                *base.add(0).cast::<u8>() = (9i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimU32(e) => {
+                                                *base.add(0).cast::<u8>() = (10i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimU64(e) => {
+                                                *base.add(0).cast::<u8>() = (11i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimS8(e) => {
+                                                *base.add(0).cast::<u8>() = (12i32) as u8;
+                                                *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                            }
+                                            WitNode::PrimS16(e) => {
+                                                *base.add(0).cast::<u8>() = (13i32) as u8;
+                                                *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                            }
+                                            WitNode::PrimS32(e) => {
+                                                *base.add(0).cast::<u8>() = (14i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimS64(e) => {
+                                                *base.add(0).cast::<u8>() = (15i32) as u8;
+                                                *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                            }
+                                            WitNode::PrimFloat32(e) => {
+                                                *base.add(0).cast::<u8>() = (16i32) as u8;
+                                                *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                            }
+                                            WitNode::PrimFloat64(e) => {
+                                                *base.add(0).cast::<u8>() = (17i32) as u8;
+                                                *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                            }
+                                            WitNode::PrimChar(e) => {
+                                                *base.add(0).cast::<u8>() = (18i32) as u8;
+                                                *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                            }
+                                            WitNode::PrimBool(e) => {
+                                                *base.add(0).cast::<u8>() = (19i32) as u8;
+                                                *base.add(8).cast::<u8>() = (match e {
+                                                    true => 1,
+                                                    false => 0,
+                                                }) as u8;
+                                            }
+                                            WitNode::PrimString(e) => {
+                                                *base.add(0).cast::<u8>() = (20i32) as u8;
+                                                let vec8 = e;
+                                                let ptr8 = vec8.as_ptr().cast::<u8>();
+                                                let len8 = vec8.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len8;
+                                                *base.add(8).cast::<*mut u8>() = ptr8.cast_mut();
+                                            }
+                                            WitNode::Handle(e) => {
+                                                *base.add(0).cast::<u8>() = (21i32) as u8;
+                                                let (t9_0, t9_1) = e;
+                                                let Uri { value: value10 } = t9_0;
+                                                let vec11 = value10;
+                                                let ptr11 = vec11.as_ptr().cast::<u8>();
+                                                let len11 = vec11.len();
+                                                *base
+                                                    .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>() = len11;
+                                                *base.add(8).cast::<*mut u8>() = ptr11.cast_mut();
+                                                *base
+                                                    .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i64>() = _rt::as_i64(t9_1);
+                                            }
+                                        }
+                                    }
+                                }
+                                *base
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>() = len12;
+                                *base.add(0).cast::<*mut u8>() = result12;
+                                cleanup_list.extend_from_slice(&[(result12, layout12)]);
+                            }
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]wasm-rpc.schedule-cancelable-invocation"]
+                            fn wit_import14(
+                                _: i32,
+                                _: i64,
+                                _: i32,
+                                _: *mut u8,
+                                _: usize,
+                                _: *mut u8,
+                                _: usize,
+                            ) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import14(
+                            _: i32,
+                            _: i64,
+                            _: i32,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                        ) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe {
+                            wit_import14(
+                                (self).handle() as i32,
+                                _rt::as_i64(seconds0),
+                                _rt::as_i32(nanoseconds0),
+                                ptr1.cast_mut(),
+                                len1,
+                                result13,
+                                len13,
+                            )
+                        };
+                        if layout13.size() != 0 {
+                            _rt::alloc::dealloc(result13.cast(), layout13);
+                        }
+                        for (ptr, layout) in cleanup_list {
+                            if layout.size() != 0 {
+                                _rt::alloc::dealloc(ptr.cast(), layout);
+                            }
+                        }
+                        unsafe { CancellationToken::from_handle(ret as u32) }
+                    }
+                }
+            }
+            impl FutureInvokeResult {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn subscribe(&self) -> Pollable {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]future-invoke-result.subscribe"]
+                            fn wit_import0(_: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
+                        unsafe {
+                            super::super::super::wasi::io::poll::Pollable::from_handle(
+                                ret as u32,
+                            )
+                        }
+                    }
+                }
+            }
+            impl FutureInvokeResult {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn get(&self) -> Option<Result<WitValue, RpcError>> {
+                    unsafe {
+                        #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                        #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                        struct RetArea(
+                            [::core::mem::MaybeUninit<
+                                u8,
+                            >; 5 * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let mut ret_area = RetArea(
+                            [::core::mem::MaybeUninit::uninit(); 5
+                                * ::core::mem::size_of::<*const u8>()],
+                        );
+                        let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]future-invoke-result.get"]
+                            fn wit_import1(_: i32, _: *mut u8);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import1(_: i32, _: *mut u8) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import1((self).handle() as i32, ptr0) };
+                        let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                        let result66 = match l2 {
+                            0 => None,
+                            1 => {
+                                let e = {
+                                    let l3 = i32::from(
+                                        *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                    );
+                                    match l3 {
+                                        0 => {
+                                            let e = {
+                                                let l4 = *ptr0
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<*mut u8>();
+                                                let l5 = *ptr0
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let base51 = l4;
+                                                let len51 = l5;
+                                                let mut result51 = _rt::Vec::with_capacity(len51);
+                                                for i in 0..len51 {
+                                                    let base = base51
+                                                        .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                                                    let e51 = {
+                                                        let l6 = i32::from(*base.add(0).cast::<u8>());
+                                                        let v50 = match l6 {
+                                                            0 => {
+                                                                let e50 = {
+                                                                    let l7 = *base.add(8).cast::<*mut u8>();
+                                                                    let l8 = *base
+                                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len9 = l8;
+                                                                    _rt::Vec::from_raw_parts(l7.cast(), len9, len9)
+                                                                };
+                                                                WitNode::RecordValue(e50)
+                                                            }
+                                                            1 => {
+                                                                let e50 = {
+                                                                    let l10 = *base.add(8).cast::<i32>();
+                                                                    let l11 = i32::from(*base.add(12).cast::<u8>());
+                                                                    (
+                                                                        l10 as u32,
+                                                                        match l11 {
+                                                                            0 => None,
+                                                                            1 => {
+                                                                                let e = {
+                                                                                    let l12 = *base.add(16).cast::<i32>();
+                                                                                    l12
+                                                                                };
+                                                                                Some(e)
+                                                                            }
+                                                                            _ => _rt::invalid_enum_discriminant(),
+                                                                        },
+                                                                    )
+                                                                };
+                                                                WitNode::VariantValue(e50)
+                                                            }
+                                                            2 => {
+                                                                let e50 = {
+                                                                    let l13 = *base.add(8).cast::<i32>();
+                                                                    l13 as u32
+                                                                };
+                                                                WitNode::EnumValue(e50)
+                                                            }
+                                                            3 => {
+                                                                let e50 = {
+                                                                    let l14 = *base.add(8).cast::<*mut u8>();
+                                                                    let l15 = *base
+                                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let base17 = l14;
+                                                                    let len17 = l15;
+                                                                    let mut result17 = _rt::Vec::with_capacity(len17);
+                                                                    for i in 0..len17 {
+                                                                        let base = base17.add(i * 1);
+                                                                        let e17 = {
+                                                                            let l16 = i32::from(*base.add(0).cast::<u8>());
+                                                                            _rt::bool_lift(l16 as u8)
+                                                                        };
+                                                                        result17.push(e17);
+                                                                    }
+                                                                    _rt::cabi_dealloc(base17, len17 * 1, 1);
+                                                                    result17
+                                                                };
+                                                                WitNode::FlagsValue(e50)
+                                                            }
+                                                            4 => {
+                                                                let e50 = {
+                                                                    let l18 = *base.add(8).cast::<*mut u8>();
+                                                                    let l19 = *base
+                                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len20 = l19;
+                                                                    _rt::Vec::from_raw_parts(l18.cast(), len20, len20)
+                                                                };
+                                                                WitNode::TupleValue(e50)
+                                                            }
+                                                            5 => {
+                                                                let e50 = {
+                                                                    let l21 = *base.add(8).cast::<*mut u8>();
+                                                                    let l22 = *base
+                                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len23 = l22;
+                                                                    _rt::Vec::from_raw_parts(l21.cast(), len23, len23)
+                                                                };
+                                                                WitNode::ListValue(e50)
+                                                            }
+                                                            6 => {
+                                                                let e50 = {
+                                                                    let l24 = i32::from(*base.add(8).cast::<u8>());
+                                                                    match l24 {
+                                                                        0 => None,
+                                                                        1 => {
+                                                                            let e = {
+                                                                                let l25 = *base.add(12).cast::<i32>();
+                                                                                l25
+                                                                            };
+                                                                            Some(e)
+                                                                        }
+                                                                        _ => _rt::invalid_enum_discriminant(),
+                                                                    }
+                                                                };
+                                                                WitNode::OptionValue(e50)
+                                                            }
+                                                            7 => {
+                                                                let e50 = {
+                                                                    let l26 = i32::from(*base.add(8).cast::<u8>());
+                                                                    match l26 {
+                                                                        0 => {
+                                                                            let e = {
+                                                                                let l27 = i32::from(*base.add(12).cast::<u8>());
+                                                                                match l27 {
+                                                                                    0 => None,
+                                                                                    1 => {
+                                                                                        let e = {
+                                                                                            let l28 = *base.add(16).cast::<i32>();
+                                                                                            l28
+                                                                                        };
+                                                                                        Some(e)
+                                                                                    }
+                                                                                    _ => _rt::invalid_enum_discriminant(),
+                                                                                }
+                                                                            };
+                                                                            Ok(e)
+                                                                        }
+                                                                        1 => {
+                                                                            let e = {
+                                                                                let l29 = i32::from(*base.add(12).cast::<u8>());
+                                                                                match l29 {
+                                                                                    0 => None,
+                                                                                    1 => {
+                                                                                        let e = {
+                                                                                            let l30 = *base.add(16).cast::<i32>();
+                                                                                            l30
+                                                                                        };
+                                                                                        Some(e)
+                                                                                    }
+                                                                                    _ => _rt::invalid_enum_discriminant(),
+                                                                                }
+                                                                            };
+                                                                            Err(e)
+                                                                        }
+                                                                        _ => _rt::invalid_enum_discriminant(),
+                                                                    }
+                                                                };
+                                                                WitNode::ResultValue(e50)
+                                                            }
+                                                            8 => {
+                                                                let e50 = {
+                                                                    let l31 = i32::from(*base.add(8).cast::<u8>());
+                                                                    l31 as u8
+                                                                };
+                                                                WitNode::PrimU8(e50)
+                                                            }
+                                                            9 => {
+                                                                let e50 = {
+                                                                    let l32 = i32::from(*base.add(8).cast::<u16>());
+                                                                    l32 as u16
+                                                                };
+                                                                WitNode::PrimU16(e50)
+                                                            }
+                                                            10 => {
+                                                                let e50 = {
+                                                                    let l33 = *base.add(8).cast::<i32>();
+                                                                    l33 as u32
+                                                                };
+                                                                WitNode::PrimU32(e50)
+                                                            }
+                                                            11 => {
+                                                                let e50 = {
+                                                                    let l34 = *base.add(8).cast::<i64>();
+                                                                    l34 as u64
+                                                                };
+                                                                WitNode::PrimU64(e50)
+                                                            }
+                                                            12 => {
+                                                                let e50 = {
+                                                                    let l35 = i32::from(*base.add(8).cast::<i8>());
+                                                                    l35 as i8
+                                                                };
+                                                                WitNode::PrimS8(e50)
+                                                            }
+                                                            13 => {
+                                                                let e50 = {
+                                                                    let l36 = i32::from(*base.add(8).cast::<i16>());
+                                                                    l36 as i16
+                                                                };
+                                                                WitNode::PrimS16(e50)
+                                                            }
+                                                            14 => {
+                                                                let e50 = {
+                                                                    let l37 = *base.add(8).cast::<i32>();
+                                                                    l37
+                                                                };
+                                                                WitNode::PrimS32(e50)
+                                                            }
+                                                            15 => {
+                                                                let e50 = {
+                                                                    let l38 = *base.add(8).cast::<i64>();
+                                                                    l38
+                                                                };
+                                                                WitNode::PrimS64(e50)
+                                                            }
+                                                            16 => {
+                                                                let e50 = {
+                                                                    let l39 = *base.add(8).cast::<f32>();
+                                                                    l39
+                                                                };
+                                                                WitNode::PrimFloat32(e50)
+                                                            }
+                                                            17 => {
+                                                                let e50 = {
+                                                                    let l40 = *base.add(8).cast::<f64>();
+                                                                    l40
+                                                                };
+                                                                WitNode::PrimFloat64(e50)
+                                                            }
+                                                            18 => {
+                                                                let e50 = {
+                                                                    let l41 = *base.add(8).cast::<i32>();
+                                                                    _rt::char_lift(l41 as u32)
+                                                                };
+                                                                WitNode::PrimChar(e50)
+                                                            }
+                                                            19 => {
+                                                                let e50 = {
+                                                                    let l42 = i32::from(*base.add(8).cast::<u8>());
+                                                                    _rt::bool_lift(l42 as u8)
+                                                                };
+                                                                WitNode::PrimBool(e50)
+                                                            }
+                                                            20 => {
+                                                                let e50 = {
+                                                                    let l43 = *base.add(8).cast::<*mut u8>();
+                                                                    let l44 = *base
+                                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len45 = l44;
+                                                                    let bytes45 = _rt::Vec::from_raw_parts(
+                                                                        l43.cast(),
+                                                                        len45,
+                                                                        len45,
+                                                                    );
+                                                                    _rt::string_lift(bytes45)
+                                                                };
+                                                                WitNode::PrimString(e50)
+                                                            }
+                                                            n => {
+                                                                debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                                                let e50 = {
+                                                                    let l46 = *base.add(8).cast::<*mut u8>();
+                                                                    let l47 = *base
+                                                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<usize>();
+                                                                    let len48 = l47;
+                                                                    let bytes48 = _rt::Vec::from_raw_parts(
+                                                                        l46.cast(),
+                                                                        len48,
+                                                                        len48,
+                                                                    );
+                                                                    let l49 = *base
+                                                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                                                        .cast::<i64>();
+                                                                    (
+                                                                        Uri {
+                                                                            value: _rt::string_lift(bytes48),
+                                                                        },
+                                                                        l49 as u64,
+                                                                    )
+                                                                };
+                                                                WitNode::Handle(e50)
+                                                            }
+                                                        };
+                                                        v50
+                                                    };
+                                                    result51.push(e51);
+                                                }
+                                                _rt::cabi_dealloc(
+                                                    base51,
+                                                    len51 * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                                                    8,
+                                                );
+                                                WitValue { nodes: result51 }
+                                            };
+                                            Ok(e)
+                                        }
+                                        1 => {
+                                            let e = {
+                                                let l52 = i32::from(
+                                                    *ptr0
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>(),
+                                                );
+                                                let v65 = match l52 {
+                                                    0 => {
+                                                        let e65 = {
+                                                            let l53 = *ptr0
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l54 = *ptr0
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len55 = l54;
+                                                            let bytes55 = _rt::Vec::from_raw_parts(
+                                                                l53.cast(),
+                                                                len55,
+                                                                len55,
+                                                            );
+                                                            _rt::string_lift(bytes55)
+                                                        };
+                                                        RpcError::ProtocolError(e65)
+                                                    }
+                                                    1 => {
+                                                        let e65 = {
+                                                            let l56 = *ptr0
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l57 = *ptr0
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len58 = l57;
+                                                            let bytes58 = _rt::Vec::from_raw_parts(
+                                                                l56.cast(),
+                                                                len58,
+                                                                len58,
+                                                            );
+                                                            _rt::string_lift(bytes58)
+                                                        };
+                                                        RpcError::Denied(e65)
+                                                    }
+                                                    2 => {
+                                                        let e65 = {
+                                                            let l59 = *ptr0
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l60 = *ptr0
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len61 = l60;
+                                                            let bytes61 = _rt::Vec::from_raw_parts(
+                                                                l59.cast(),
+                                                                len61,
+                                                                len61,
+                                                            );
+                                                            _rt::string_lift(bytes61)
+                                                        };
+                                                        RpcError::NotFound(e65)
+                                                    }
+                                                    n => {
+                                                        debug_assert_eq!(n, 3, "invalid enum discriminant");
+                                                        let e65 = {
+                                                            let l62 = *ptr0
+                                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<*mut u8>();
+                                                            let l63 = *ptr0
+                                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                                .cast::<usize>();
+                                                            let len64 = l63;
+                                                            let bytes64 = _rt::Vec::from_raw_parts(
+                                                                l62.cast(),
+                                                                len64,
+                                                                len64,
+                                                            );
+                                                            _rt::string_lift(bytes64)
+                                                        };
+                                                        RpcError::RemoteInternalError(e65)
+                                                    }
+                                                };
+                                                v65
+                                            };
+                                            Err(e)
+                                        }
+                                        _ => _rt::invalid_enum_discriminant(),
+                                    }
+                                };
+                                Some(e)
+                            }
+                            _ => _rt::invalid_enum_discriminant(),
+                        };
+                        result66
+                    }
+                }
+            }
+            impl CancellationToken {
+                #[allow(unused_unsafe, clippy::all)]
+                pub fn cancel(&self) -> () {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]cancellation-token.cancel"]
+                            fn wit_import0(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import0((self).handle() as i32) };
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn extract_value(vnt: &ValueAndType) -> WitValue {
+                unsafe {
+                    let mut cleanup_list = _rt::Vec::new();
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 2
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ValueAndType { value: value0, typ: typ0 } = vnt;
+                    let WitValue { nodes: nodes1 } = value0;
+                    let vec11 = nodes1;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11
+                            .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            match e {
+                                WitNode::RecordValue(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let vec2 = e;
+                                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                                    let len2 = vec2.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len2;
+                                    *base.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                                }
+                                WitNode::VariantValue(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let (t3_0, t3_1) = e;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(t3_0);
+                                    match t3_1 {
+                                        Some(e) => {
+                                            *base.add(12).cast::<u8>() = (1i32) as u8;
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(12).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                WitNode::EnumValue(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::FlagsValue(e) => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                    let vec4 = e;
+                                    let len4 = vec4.len();
+                                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec4.len() * 1,
+                                        1,
+                                    );
+                                    let result4 = if layout4.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout4);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec4.into_iter().enumerate() {
+                                        let base = result4.add(i * 1);
+                                        {
+                                            *base.add(0).cast::<u8>() = (match e {
+                                                true => 1,
+                                                false => 0,
+                                            }) as u8;
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len4;
+                                    *base.add(8).cast::<*mut u8>() = result4;
+                                    cleanup_list.extend_from_slice(&[(result4, layout4)]);
+                                }
+                                WitNode::TupleValue(e) => {
+                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                    let vec5 = e;
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                WitNode::ListValue(e) => {
+                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                    let vec6 = e;
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                WitNode::OptionValue(e) => {
+                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                WitNode::ResultValue(e) => {
+                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                    match e {
+                                        Ok(e) => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                            match e {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Err(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            match e {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    };
+                                }
+                                WitNode::PrimU8(e) => {
+                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                    *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                }
+                                WitNode::PrimU16(e) => {
+                                    *base.add(0).cast::<u8>() = (9i32) as u8;
+                                    *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                }
+                                WitNode::PrimU32(e) => {
+                                    *base.add(0).cast::<u8>() = (10i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::PrimU64(e) => {
+                                    *base.add(0).cast::<u8>() = (11i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                WitNode::PrimS8(e) => {
+                                    *base.add(0).cast::<u8>() = (12i32) as u8;
+                                    *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                }
+                                WitNode::PrimS16(e) => {
+                                    *base.add(0).cast::<u8>() = (13i32) as u8;
+                                    *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                }
+                                WitNode::PrimS32(e) => {
+                                    *base.add(0).cast::<u8>() = (14i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::PrimS64(e) => {
+                                    *base.add(0).cast::<u8>() = (15i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                WitNode::PrimFloat32(e) => {
+                                    *base.add(0).cast::<u8>() = (16i32) as u8;
+                                    *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                }
+                                WitNode::PrimFloat64(e) => {
+                                    *base.add(0).cast::<u8>() = (17i32) as u8;
+                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                }
+                                WitNode::PrimChar(e) => {
+                                    *base.add(0).cast::<u8>() = (18i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::PrimBool(e) => {
+                                    *base.add(0).cast::<u8>() = (19i32) as u8;
+                                    *base.add(8).cast::<u8>() = (match e {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                }
+                                WitNode::PrimString(e) => {
+                                    *base.add(0).cast::<u8>() = (20i32) as u8;
+                                    let vec7 = e;
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                WitNode::Handle(e) => {
+                                    *base.add(0).cast::<u8>() = (21i32) as u8;
+                                    let (t8_0, t8_1) = e;
+                                    let Uri { value: value9 } = t8_0;
+                                    let vec10 = value9;
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *base.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                                    *base
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(t8_1);
+                                }
+                            }
+                        }
+                    }
+                    let WitType { nodes: nodes12 } = typ0;
+                    let vec29 = nodes12;
+                    let len29 = vec29.len();
+                    let layout29 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec29.len() * (24 + 6 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let result29 = if layout29.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout29).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout29);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec29.into_iter().enumerate() {
+                        let base = result29
+                            .add(i * (24 + 6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let NamedWitTypeNode {
+                                name: name13,
+                                owner: owner13,
+                                type_: type_13,
+                            } = e;
+                            match name13 {
+                                Some(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let vec14 = e;
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len14;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                None => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match owner13 {
+                                Some(e) => {
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec15 = e;
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    *base
+                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *base
+                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                None => {
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match type_13 {
+                                WitTypeNode::RecordType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    let vec18 = e;
+                                    let len18 = vec18.len();
+                                    let layout18 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec18.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result18 = if layout18.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout18).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout18);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec18.into_iter().enumerate() {
+                                        let base = result18
+                                            .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let (t16_0, t16_1) = e;
+                                            let vec17 = t16_0;
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len17;
+                                            *base.add(0).cast::<*mut u8>() = ptr17.cast_mut();
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(t16_1);
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len18;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result18;
+                                    cleanup_list.extend_from_slice(&[(result18, layout18)]);
+                                }
+                                WitTypeNode::VariantType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec21 = e;
+                                    let len21 = vec21.len();
+                                    let layout21 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec21.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result21 = if layout21.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout21).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout21);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec21.into_iter().enumerate() {
+                                        let base = result21
+                                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let (t19_0, t19_1) = e;
+                                            let vec20 = t19_0;
+                                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                                            let len20 = vec20.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len20;
+                                            *base.add(0).cast::<*mut u8>() = ptr20.cast_mut();
+                                            match t19_1 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    *base
+                                                        .add(4 + 2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len21;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result21;
+                                    cleanup_list.extend_from_slice(&[(result21, layout21)]);
+                                }
+                                WitTypeNode::EnumType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    let vec23 = e;
+                                    let len23 = vec23.len();
+                                    let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec23.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result23 = if layout23.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout23);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec23.into_iter().enumerate() {
+                                        let base = result23
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec22 = e;
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len22;
+                                            *base.add(0).cast::<*mut u8>() = ptr22.cast_mut();
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len23;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result23;
+                                    cleanup_list.extend_from_slice(&[(result23, layout23)]);
+                                }
+                                WitTypeNode::FlagsType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (3i32) as u8;
+                                    let vec25 = e;
+                                    let len25 = vec25.len();
+                                    let layout25 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec25.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result25 = if layout25.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout25);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec25.into_iter().enumerate() {
+                                        let base = result25
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec24 = e;
+                                            let ptr24 = vec24.as_ptr().cast::<u8>();
+                                            let len24 = vec24.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len24;
+                                            *base.add(0).cast::<*mut u8>() = ptr24.cast_mut();
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len25;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result25;
+                                    cleanup_list.extend_from_slice(&[(result25, layout25)]);
+                                }
+                                WitTypeNode::TupleType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (4i32) as u8;
+                                    let vec26 = e;
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len26;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                                WitTypeNode::ListType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (5i32) as u8;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitTypeNode::OptionType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (6i32) as u8;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitTypeNode::ResultType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (7i32) as u8;
+                                    let (t27_0, t27_1) = e;
+                                    match t27_0 {
+                                        Some(e) => {
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *base
+                                                .add(12 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match t27_1 {
+                                        Some(e) => {
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *base
+                                                .add(20 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                WitTypeNode::PrimU8Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (8i32) as u8;
+                                }
+                                WitTypeNode::PrimU16Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (9i32) as u8;
+                                }
+                                WitTypeNode::PrimU32Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (10i32) as u8;
+                                }
+                                WitTypeNode::PrimU64Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (11i32) as u8;
+                                }
+                                WitTypeNode::PrimS8Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (12i32) as u8;
+                                }
+                                WitTypeNode::PrimS16Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (13i32) as u8;
+                                }
+                                WitTypeNode::PrimS32Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (14i32) as u8;
+                                }
+                                WitTypeNode::PrimS64Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (15i32) as u8;
+                                }
+                                WitTypeNode::PrimF32Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (16i32) as u8;
+                                }
+                                WitTypeNode::PrimF64Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (17i32) as u8;
+                                }
+                                WitTypeNode::PrimCharType => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (18i32) as u8;
+                                }
+                                WitTypeNode::PrimBoolType => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (19i32) as u8;
+                                }
+                                WitTypeNode::PrimStringType => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (20i32) as u8;
+                                }
+                                WitTypeNode::HandleType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (21i32) as u8;
+                                    let (t28_0, t28_1) = e;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(t28_0);
+                                    *base
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t28_1.clone() as i32) as u8;
+                                }
+                            }
+                        }
+                    }
+                    let ptr30 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                    unsafe extern "C" {
+                        #[link_name = "extract-value"]
+                        fn wit_import31(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import31(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import31(result11, len11, result29, len29, ptr30) };
+                    let l32 = *ptr30.add(0).cast::<*mut u8>();
+                    let l33 = *ptr30
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base79 = l32;
+                    let len79 = l33;
+                    let mut result79 = _rt::Vec::with_capacity(len79);
+                    for i in 0..len79 {
+                        let base = base79
+                            .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                        let e79 = {
+                            let l34 = i32::from(*base.add(0).cast::<u8>());
+                            let v78 = match l34 {
+                                0 => {
+                                    let e78 = {
+                                        let l35 = *base.add(8).cast::<*mut u8>();
+                                        let l36 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len37 = l36;
+                                        _rt::Vec::from_raw_parts(l35.cast(), len37, len37)
+                                    };
+                                    WitNode::RecordValue(e78)
+                                }
+                                1 => {
+                                    let e78 = {
+                                        let l38 = *base.add(8).cast::<i32>();
+                                        let l39 = i32::from(*base.add(12).cast::<u8>());
+                                        (
+                                            l38 as u32,
+                                            match l39 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l40 = *base.add(16).cast::<i32>();
+                                                        l40
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            },
+                                        )
+                                    };
+                                    WitNode::VariantValue(e78)
+                                }
+                                2 => {
+                                    let e78 = {
+                                        let l41 = *base.add(8).cast::<i32>();
+                                        l41 as u32
+                                    };
+                                    WitNode::EnumValue(e78)
+                                }
+                                3 => {
+                                    let e78 = {
+                                        let l42 = *base.add(8).cast::<*mut u8>();
+                                        let l43 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base45 = l42;
+                                        let len45 = l43;
+                                        let mut result45 = _rt::Vec::with_capacity(len45);
+                                        for i in 0..len45 {
+                                            let base = base45.add(i * 1);
+                                            let e45 = {
+                                                let l44 = i32::from(*base.add(0).cast::<u8>());
+                                                _rt::bool_lift(l44 as u8)
+                                            };
+                                            result45.push(e45);
+                                        }
+                                        _rt::cabi_dealloc(base45, len45 * 1, 1);
+                                        result45
+                                    };
+                                    WitNode::FlagsValue(e78)
+                                }
+                                4 => {
+                                    let e78 = {
+                                        let l46 = *base.add(8).cast::<*mut u8>();
+                                        let l47 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len48 = l47;
+                                        _rt::Vec::from_raw_parts(l46.cast(), len48, len48)
+                                    };
+                                    WitNode::TupleValue(e78)
+                                }
+                                5 => {
+                                    let e78 = {
+                                        let l49 = *base.add(8).cast::<*mut u8>();
+                                        let l50 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len51 = l50;
+                                        _rt::Vec::from_raw_parts(l49.cast(), len51, len51)
+                                    };
+                                    WitNode::ListValue(e78)
+                                }
+                                6 => {
+                                    let e78 = {
+                                        let l52 = i32::from(*base.add(8).cast::<u8>());
+                                        match l52 {
+                                            0 => None,
+                                            1 => {
+                                                let e = {
+                                                    let l53 = *base.add(12).cast::<i32>();
+                                                    l53
+                                                };
+                                                Some(e)
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        }
+                                    };
+                                    WitNode::OptionValue(e78)
+                                }
+                                7 => {
+                                    let e78 = {
+                                        let l54 = i32::from(*base.add(8).cast::<u8>());
+                                        match l54 {
+                                            0 => {
+                                                let e = {
+                                                    let l55 = i32::from(*base.add(12).cast::<u8>());
+                                                    match l55 {
+                                                        0 => None,
+                                                        1 => {
+                                                            let e = {
+                                                                let l56 = *base.add(16).cast::<i32>();
+                                                                l56
+                                                            };
+                                                            Some(e)
+                                                        }
+                                                        _ => _rt::invalid_enum_discriminant(),
+                                                    }
+                                                };
+                                                Ok(e)
+                                            }
+                                            1 => {
+                                                let e = {
+                                                    let l57 = i32::from(*base.add(12).cast::<u8>());
+                                                    match l57 {
+                                                        0 => None,
+                                                        1 => {
+                                                            let e = {
+                                                                let l58 = *base.add(16).cast::<i32>();
+                                                                l58
+                                                            };
+                                                            Some(e)
+                                                        }
+                                                        _ => _rt::invalid_enum_discriminant(),
+                                                    }
+                                                };
+                                                Err(e)
+                                            }
+                                            _ => _rt::invalid_enum_discriminant(),
+                                        }
+                                    };
+                                    WitNode::ResultValue(e78)
+                                }
+                                8 => {
+                                    let e78 = {
+                                        let l59 = i32::from(*base.add(8).cast::<u8>());
+                                        l59 as u8
+                                    };
+                                    WitNode::PrimU8(e78)
+                                }
+                                9 => {
+                                    let e78 = {
+                                        let l60 = i32::from(*base.add(8).cast::<u16>());
+                                        l60 as u16
+                                    };
+                                    WitNode::PrimU16(e78)
+                                }
+                                10 => {
+                                    let e78 = {
+                                        let l61 = *base.add(8).cast::<i32>();
+                                        l61 as u32
+                                    };
+                                    WitNode::PrimU32(e78)
+                                }
+                                11 => {
+                                    let e78 = {
+                                        let l62 = *base.add(8).cast::<i64>();
+                                        l62 as u64
+                                    };
+                                    WitNode::PrimU64(e78)
+                                }
+                                12 => {
+                                    let e78 = {
+                                        let l63 = i32::from(*base.add(8).cast::<i8>());
+                                        l63 as i8
+                                    };
+                                    WitNode::PrimS8(e78)
+                                }
+                                13 => {
+                                    let e78 = {
+                                        let l64 = i32::from(*base.add(8).cast::<i16>());
+                                        l64 as i16
+                                    };
+                                    WitNode::PrimS16(e78)
+                                }
+                                14 => {
+                                    let e78 = {
+                                        let l65 = *base.add(8).cast::<i32>();
+                                        l65
+                                    };
+                                    WitNode::PrimS32(e78)
+                                }
+                                15 => {
+                                    let e78 = {
+                                        let l66 = *base.add(8).cast::<i64>();
+                                        l66
+                                    };
+                                    WitNode::PrimS64(e78)
+                                }
+                                16 => {
+                                    let e78 = {
+                                        let l67 = *base.add(8).cast::<f32>();
+                                        l67
+                                    };
+                                    WitNode::PrimFloat32(e78)
+                                }
+                                17 => {
+                                    let e78 = {
+                                        let l68 = *base.add(8).cast::<f64>();
+                                        l68
+                                    };
+                                    WitNode::PrimFloat64(e78)
+                                }
+                                18 => {
+                                    let e78 = {
+                                        let l69 = *base.add(8).cast::<i32>();
+                                        _rt::char_lift(l69 as u32)
+                                    };
+                                    WitNode::PrimChar(e78)
+                                }
+                                19 => {
+                                    let e78 = {
+                                        let l70 = i32::from(*base.add(8).cast::<u8>());
+                                        _rt::bool_lift(l70 as u8)
+                                    };
+                                    WitNode::PrimBool(e78)
+                                }
+                                20 => {
+                                    let e78 = {
+                                        let l71 = *base.add(8).cast::<*mut u8>();
+                                        let l72 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len73 = l72;
+                                        let bytes73 = _rt::Vec::from_raw_parts(
+                                            l71.cast(),
+                                            len73,
+                                            len73,
+                                        );
+                                        _rt::string_lift(bytes73)
+                                    };
+                                    WitNode::PrimString(e78)
+                                }
+                                n => {
+                                    debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                    let e78 = {
+                                        let l74 = *base.add(8).cast::<*mut u8>();
+                                        let l75 = *base
+                                            .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len76 = l75;
+                                        let bytes76 = _rt::Vec::from_raw_parts(
+                                            l74.cast(),
+                                            len76,
+                                            len76,
+                                        );
+                                        let l77 = *base
+                                            .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i64>();
+                                        (
+                                            Uri {
+                                                value: _rt::string_lift(bytes76),
+                                            },
+                                            l77 as u64,
+                                        )
+                                    };
+                                    WitNode::Handle(e78)
+                                }
+                            };
+                            v78
+                        };
+                        result79.push(e79);
+                    }
+                    _rt::cabi_dealloc(
+                        base79,
+                        len79 * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let result80 = WitValue { nodes: result79 };
+                    if layout11.size() != 0 {
+                        _rt::alloc::dealloc(result11.cast(), layout11);
+                    }
+                    if layout29.size() != 0 {
+                        _rt::alloc::dealloc(result29.cast(), layout29);
+                    }
+                    for (ptr, layout) in cleanup_list {
+                        if layout.size() != 0 {
+                            _rt::alloc::dealloc(ptr.cast(), layout);
+                        }
+                    }
+                    result80
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            pub fn extract_type(vnt: &ValueAndType) -> WitType {
+                unsafe {
+                    let mut cleanup_list = _rt::Vec::new();
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 2
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ValueAndType { value: value0, typ: typ0 } = vnt;
+                    let WitValue { nodes: nodes1 } = value0;
+                    let vec11 = nodes1;
+                    let len11 = vec11.len();
+                    let layout11 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec11.len() * (16 + 2 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let result11 = if layout11.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout11).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout11);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec11.into_iter().enumerate() {
+                        let base = result11
+                            .add(i * (16 + 2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            match e {
+                                WitNode::RecordValue(e) => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                    let vec2 = e;
+                                    let ptr2 = vec2.as_ptr().cast::<u8>();
+                                    let len2 = vec2.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len2;
+                                    *base.add(8).cast::<*mut u8>() = ptr2.cast_mut();
+                                }
+                                WitNode::VariantValue(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let (t3_0, t3_1) = e;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(t3_0);
+                                    match t3_1 {
+                                        Some(e) => {
+                                            *base.add(12).cast::<u8>() = (1i32) as u8;
+                                            *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(12).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                WitNode::EnumValue(e) => {
+                                    *base.add(0).cast::<u8>() = (2i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::FlagsValue(e) => {
+                                    *base.add(0).cast::<u8>() = (3i32) as u8;
+                                    let vec4 = e;
+                                    let len4 = vec4.len();
+                                    let layout4 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec4.len() * 1,
+                                        1,
+                                    );
+                                    let result4 = if layout4.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout4).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout4);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec4.into_iter().enumerate() {
+                                        let base = result4.add(i * 1);
+                                        {
+                                            *base.add(0).cast::<u8>() = (match e {
+                                                true => 1,
+                                                false => 0,
+                                            }) as u8;
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len4;
+                                    *base.add(8).cast::<*mut u8>() = result4;
+                                    cleanup_list.extend_from_slice(&[(result4, layout4)]);
+                                }
+                                WitNode::TupleValue(e) => {
+                                    *base.add(0).cast::<u8>() = (4i32) as u8;
+                                    let vec5 = e;
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *base.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                WitNode::ListValue(e) => {
+                                    *base.add(0).cast::<u8>() = (5i32) as u8;
+                                    let vec6 = e;
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *base.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                WitNode::OptionValue(e) => {
+                                    *base.add(0).cast::<u8>() = (6i32) as u8;
+                                    match e {
+                                        Some(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            *base.add(12).cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                WitNode::ResultValue(e) => {
+                                    *base.add(0).cast::<u8>() = (7i32) as u8;
+                                    match e {
+                                        Ok(e) => {
+                                            *base.add(8).cast::<u8>() = (0i32) as u8;
+                                            match e {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                        Err(e) => {
+                                            *base.add(8).cast::<u8>() = (1i32) as u8;
+                                            match e {
+                                                Some(e) => {
+                                                    *base.add(12).cast::<u8>() = (1i32) as u8;
+                                                    *base.add(16).cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base.add(12).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    };
+                                }
+                                WitNode::PrimU8(e) => {
+                                    *base.add(0).cast::<u8>() = (8i32) as u8;
+                                    *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                }
+                                WitNode::PrimU16(e) => {
+                                    *base.add(0).cast::<u8>() = (9i32) as u8;
+                                    *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                }
+                                WitNode::PrimU32(e) => {
+                                    *base.add(0).cast::<u8>() = (10i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::PrimU64(e) => {
+                                    *base.add(0).cast::<u8>() = (11i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                WitNode::PrimS8(e) => {
+                                    *base.add(0).cast::<u8>() = (12i32) as u8;
+                                    *base.add(8).cast::<u8>() = (_rt::as_i32(e)) as u8;
+                                }
+                                WitNode::PrimS16(e) => {
+                                    *base.add(0).cast::<u8>() = (13i32) as u8;
+                                    *base.add(8).cast::<u16>() = (_rt::as_i32(e)) as u16;
+                                }
+                                WitNode::PrimS32(e) => {
+                                    *base.add(0).cast::<u8>() = (14i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::PrimS64(e) => {
+                                    *base.add(0).cast::<u8>() = (15i32) as u8;
+                                    *base.add(8).cast::<i64>() = _rt::as_i64(e);
+                                }
+                                WitNode::PrimFloat32(e) => {
+                                    *base.add(0).cast::<u8>() = (16i32) as u8;
+                                    *base.add(8).cast::<f32>() = _rt::as_f32(e);
+                                }
+                                WitNode::PrimFloat64(e) => {
+                                    *base.add(0).cast::<u8>() = (17i32) as u8;
+                                    *base.add(8).cast::<f64>() = _rt::as_f64(e);
+                                }
+                                WitNode::PrimChar(e) => {
+                                    *base.add(0).cast::<u8>() = (18i32) as u8;
+                                    *base.add(8).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitNode::PrimBool(e) => {
+                                    *base.add(0).cast::<u8>() = (19i32) as u8;
+                                    *base.add(8).cast::<u8>() = (match e {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                }
+                                WitNode::PrimString(e) => {
+                                    *base.add(0).cast::<u8>() = (20i32) as u8;
+                                    let vec7 = e;
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *base.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                WitNode::Handle(e) => {
+                                    *base.add(0).cast::<u8>() = (21i32) as u8;
+                                    let (t8_0, t8_1) = e;
+                                    let Uri { value: value9 } = t8_0;
+                                    let vec10 = value9;
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    *base
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *base.add(8).cast::<*mut u8>() = ptr10.cast_mut();
+                                    *base
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(t8_1);
+                                }
+                            }
+                        }
+                    }
+                    let WitType { nodes: nodes12 } = typ0;
+                    let vec29 = nodes12;
+                    let len29 = vec29.len();
+                    let layout29 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec29.len() * (24 + 6 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let result29 = if layout29.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout29).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout29);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec29.into_iter().enumerate() {
+                        let base = result29
+                            .add(i * (24 + 6 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let NamedWitTypeNode {
+                                name: name13,
+                                owner: owner13,
+                                type_: type_13,
+                            } = e;
+                            match name13 {
+                                Some(e) => {
+                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                    let vec14 = e;
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    *base
+                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len14;
+                                    *base
+                                        .add(::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr14.cast_mut();
+                                }
+                                None => {
+                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match owner13 {
+                                Some(e) => {
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec15 = e;
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    *base
+                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *base
+                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                None => {
+                                    *base
+                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            match type_13 {
+                                WitTypeNode::RecordType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                    let vec18 = e;
+                                    let len18 = vec18.len();
+                                    let layout18 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec18.len() * (3 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result18 = if layout18.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout18).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout18);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec18.into_iter().enumerate() {
+                                        let base = result18
+                                            .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let (t16_0, t16_1) = e;
+                                            let vec17 = t16_0;
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len17;
+                                            *base.add(0).cast::<*mut u8>() = ptr17.cast_mut();
+                                            *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(t16_1);
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len18;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result18;
+                                    cleanup_list.extend_from_slice(&[(result18, layout18)]);
+                                }
+                                WitTypeNode::VariantType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec21 = e;
+                                    let len21 = vec21.len();
+                                    let layout21 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec21.len() * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result21 = if layout21.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout21).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout21);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec21.into_iter().enumerate() {
+                                        let base = result21
+                                            .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let (t19_0, t19_1) = e;
+                                            let vec20 = t19_0;
+                                            let ptr20 = vec20.as_ptr().cast::<u8>();
+                                            let len20 = vec20.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len20;
+                                            *base.add(0).cast::<*mut u8>() = ptr20.cast_mut();
+                                            match t19_1 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    *base
+                                                        .add(4 + 2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<i32>() = _rt::as_i32(e);
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len21;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result21;
+                                    cleanup_list.extend_from_slice(&[(result21, layout21)]);
+                                }
+                                WitTypeNode::EnumType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (2i32) as u8;
+                                    let vec23 = e;
+                                    let len23 = vec23.len();
+                                    let layout23 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec23.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result23 = if layout23.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout23).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout23);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec23.into_iter().enumerate() {
+                                        let base = result23
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec22 = e;
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len22;
+                                            *base.add(0).cast::<*mut u8>() = ptr22.cast_mut();
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len23;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result23;
+                                    cleanup_list.extend_from_slice(&[(result23, layout23)]);
+                                }
+                                WitTypeNode::FlagsType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (3i32) as u8;
+                                    let vec25 = e;
+                                    let len25 = vec25.len();
+                                    let layout25 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec25.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result25 = if layout25.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout25);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec25.into_iter().enumerate() {
+                                        let base = result25
+                                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let vec24 = e;
+                                            let ptr24 = vec24.as_ptr().cast::<u8>();
+                                            let len24 = vec24.len();
+                                            *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len24;
+                                            *base.add(0).cast::<*mut u8>() = ptr24.cast_mut();
+                                        }
+                                    }
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len25;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result25;
+                                    cleanup_list.extend_from_slice(&[(result25, layout25)]);
+                                }
+                                WitTypeNode::TupleType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (4i32) as u8;
+                                    let vec26 = e;
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    *base
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len26;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr26.cast_mut();
+                                }
+                                WitTypeNode::ListType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (5i32) as u8;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitTypeNode::OptionType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (6i32) as u8;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i32>() = _rt::as_i32(e);
+                                }
+                                WitTypeNode::ResultType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (7i32) as u8;
+                                    let (t27_0, t27_1) = e;
+                                    match t27_0 {
+                                        Some(e) => {
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *base
+                                                .add(12 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match t27_1 {
+                                        Some(e) => {
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *base
+                                                .add(20 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                WitTypeNode::PrimU8Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (8i32) as u8;
+                                }
+                                WitTypeNode::PrimU16Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (9i32) as u8;
+                                }
+                                WitTypeNode::PrimU32Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (10i32) as u8;
+                                }
+                                WitTypeNode::PrimU64Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (11i32) as u8;
+                                }
+                                WitTypeNode::PrimS8Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (12i32) as u8;
+                                }
+                                WitTypeNode::PrimS16Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (13i32) as u8;
+                                }
+                                WitTypeNode::PrimS32Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (14i32) as u8;
+                                }
+                                WitTypeNode::PrimS64Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (15i32) as u8;
+                                }
+                                WitTypeNode::PrimF32Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (16i32) as u8;
+                                }
+                                WitTypeNode::PrimF64Type => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (17i32) as u8;
+                                }
+                                WitTypeNode::PrimCharType => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (18i32) as u8;
+                                }
+                                WitTypeNode::PrimBoolType => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (19i32) as u8;
+                                }
+                                WitTypeNode::PrimStringType => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (20i32) as u8;
+                                }
+                                WitTypeNode::HandleType(e) => {
+                                    *base
+                                        .add(6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (21i32) as u8;
+                                    let (t28_0, t28_1) = e;
+                                    *base
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(t28_0);
+                                    *base
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (t28_1.clone() as i32) as u8;
+                                }
+                            }
+                        }
+                    }
+                    let ptr30 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "golem:rpc/types@0.2.2")]
+                    unsafe extern "C" {
+                        #[link_name = "extract-type"]
+                        fn wit_import31(
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                            _: usize,
+                            _: *mut u8,
+                        );
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import31(
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                        _: usize,
+                        _: *mut u8,
+                    ) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import31(result11, len11, result29, len29, ptr30) };
+                    let l32 = *ptr30.add(0).cast::<*mut u8>();
+                    let l33 = *ptr30
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base82 = l32;
+                    let len82 = l33;
+                    let mut result82 = _rt::Vec::with_capacity(len82);
+                    for i in 0..len82 {
+                        let base = base82
+                            .add(i * (24 + 6 * ::core::mem::size_of::<*const u8>()));
+                        let e82 = {
+                            let l34 = i32::from(*base.add(0).cast::<u8>());
+                            let l38 = i32::from(
+                                *base
+                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let l42 = i32::from(
+                                *base
+                                    .add(6 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            let v81 = match l42 {
+                                0 => {
+                                    let e81 = {
+                                        let l43 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l44 = *base
+                                            .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base49 = l43;
+                                        let len49 = l44;
+                                        let mut result49 = _rt::Vec::with_capacity(len49);
+                                        for i in 0..len49 {
+                                            let base = base49
+                                                .add(i * (3 * ::core::mem::size_of::<*const u8>()));
+                                            let e49 = {
+                                                let l45 = *base.add(0).cast::<*mut u8>();
+                                                let l46 = *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len47 = l46;
+                                                let bytes47 = _rt::Vec::from_raw_parts(
+                                                    l45.cast(),
+                                                    len47,
+                                                    len47,
+                                                );
+                                                let l48 = *base
+                                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<i32>();
+                                                (_rt::string_lift(bytes47), l48)
+                                            };
+                                            result49.push(e49);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base49,
+                                            len49 * (3 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        result49
+                                    };
+                                    WitTypeNode::RecordType(e81)
+                                }
+                                1 => {
+                                    let e81 = {
+                                        let l50 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l51 = *base
+                                            .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base57 = l50;
+                                        let len57 = l51;
+                                        let mut result57 = _rt::Vec::with_capacity(len57);
+                                        for i in 0..len57 {
+                                            let base = base57
+                                                .add(i * (8 + 2 * ::core::mem::size_of::<*const u8>()));
+                                            let e57 = {
+                                                let l52 = *base.add(0).cast::<*mut u8>();
+                                                let l53 = *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len54 = l53;
+                                                let bytes54 = _rt::Vec::from_raw_parts(
+                                                    l52.cast(),
+                                                    len54,
+                                                    len54,
+                                                );
+                                                let l55 = i32::from(
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>(),
+                                                );
+                                                (
+                                                    _rt::string_lift(bytes54),
+                                                    match l55 {
+                                                        0 => None,
+                                                        1 => {
+                                                            let e = {
+                                                                let l56 = *base
+                                                                    .add(4 + 2 * ::core::mem::size_of::<*const u8>())
+                                                                    .cast::<i32>();
+                                                                l56
+                                                            };
+                                                            Some(e)
+                                                        }
+                                                        _ => _rt::invalid_enum_discriminant(),
+                                                    },
+                                                )
+                                            };
+                                            result57.push(e57);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base57,
+                                            len57 * (8 + 2 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        result57
+                                    };
+                                    WitTypeNode::VariantType(e81)
+                                }
+                                2 => {
+                                    let e81 = {
+                                        let l58 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l59 = *base
+                                            .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base63 = l58;
+                                        let len63 = l59;
+                                        let mut result63 = _rt::Vec::with_capacity(len63);
+                                        for i in 0..len63 {
+                                            let base = base63
+                                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                            let e63 = {
+                                                let l60 = *base.add(0).cast::<*mut u8>();
+                                                let l61 = *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len62 = l61;
+                                                let bytes62 = _rt::Vec::from_raw_parts(
+                                                    l60.cast(),
+                                                    len62,
+                                                    len62,
+                                                );
+                                                _rt::string_lift(bytes62)
+                                            };
+                                            result63.push(e63);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base63,
+                                            len63 * (2 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        result63
+                                    };
+                                    WitTypeNode::EnumType(e81)
+                                }
+                                3 => {
+                                    let e81 = {
+                                        let l64 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l65 = *base
+                                            .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let base69 = l64;
+                                        let len69 = l65;
+                                        let mut result69 = _rt::Vec::with_capacity(len69);
+                                        for i in 0..len69 {
+                                            let base = base69
+                                                .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                                            let e69 = {
+                                                let l66 = *base.add(0).cast::<*mut u8>();
+                                                let l67 = *base
+                                                    .add(::core::mem::size_of::<*const u8>())
+                                                    .cast::<usize>();
+                                                let len68 = l67;
+                                                let bytes68 = _rt::Vec::from_raw_parts(
+                                                    l66.cast(),
+                                                    len68,
+                                                    len68,
+                                                );
+                                                _rt::string_lift(bytes68)
+                                            };
+                                            result69.push(e69);
+                                        }
+                                        _rt::cabi_dealloc(
+                                            base69,
+                                            len69 * (2 * ::core::mem::size_of::<*const u8>()),
+                                            ::core::mem::size_of::<*const u8>(),
+                                        );
+                                        result69
+                                    };
+                                    WitTypeNode::FlagsType(e81)
+                                }
+                                4 => {
+                                    let e81 = {
+                                        let l70 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l71 = *base
+                                            .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len72 = l71;
+                                        _rt::Vec::from_raw_parts(l70.cast(), len72, len72)
+                                    };
+                                    WitTypeNode::TupleType(e81)
+                                }
+                                5 => {
+                                    let e81 = {
+                                        let l73 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l73
+                                    };
+                                    WitTypeNode::ListType(e81)
+                                }
+                                6 => {
+                                    let e81 = {
+                                        let l74 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i32>();
+                                        l74
+                                    };
+                                    WitTypeNode::OptionType(e81)
+                                }
+                                7 => {
+                                    let e81 = {
+                                        let l75 = i32::from(
+                                            *base
+                                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        let l77 = i32::from(
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        (
+                                            match l75 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l76 = *base
+                                                            .add(12 + 6 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        l76
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            },
+                                            match l77 {
+                                                0 => None,
+                                                1 => {
+                                                    let e = {
+                                                        let l78 = *base
+                                                            .add(20 + 6 * ::core::mem::size_of::<*const u8>())
+                                                            .cast::<i32>();
+                                                        l78
+                                                    };
+                                                    Some(e)
+                                                }
+                                                _ => _rt::invalid_enum_discriminant(),
+                                            },
+                                        )
+                                    };
+                                    WitTypeNode::ResultType(e81)
+                                }
+                                8 => WitTypeNode::PrimU8Type,
+                                9 => WitTypeNode::PrimU16Type,
+                                10 => WitTypeNode::PrimU32Type,
+                                11 => WitTypeNode::PrimU64Type,
+                                12 => WitTypeNode::PrimS8Type,
+                                13 => WitTypeNode::PrimS16Type,
+                                14 => WitTypeNode::PrimS32Type,
+                                15 => WitTypeNode::PrimS64Type,
+                                16 => WitTypeNode::PrimF32Type,
+                                17 => WitTypeNode::PrimF64Type,
+                                18 => WitTypeNode::PrimCharType,
+                                19 => WitTypeNode::PrimBoolType,
+                                20 => WitTypeNode::PrimStringType,
+                                n => {
+                                    debug_assert_eq!(n, 21, "invalid enum discriminant");
+                                    let e81 = {
+                                        let l79 = *base
+                                            .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<i64>();
+                                        let l80 = i32::from(
+                                            *base
+                                                .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>(),
+                                        );
+                                        (l79 as u64, ResourceMode::_lift(l80 as u8))
+                                    };
+                                    WitTypeNode::HandleType(e81)
+                                }
+                            };
+                            NamedWitTypeNode {
+                                name: match l34 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l35 = *base
+                                                .add(::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l36 = *base
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len37 = l36;
+                                            let bytes37 = _rt::Vec::from_raw_parts(
+                                                l35.cast(),
+                                                len37,
+                                                len37,
+                                            );
+                                            _rt::string_lift(bytes37)
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                owner: match l38 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l39 = *base
+                                                .add(4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l40 = *base
+                                                .add(5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len41 = l40;
+                                            let bytes41 = _rt::Vec::from_raw_parts(
+                                                l39.cast(),
+                                                len41,
+                                                len41,
+                                            );
+                                            _rt::string_lift(bytes41)
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                },
+                                type_: v81,
+                            }
+                        };
+                        result82.push(e82);
+                    }
+                    _rt::cabi_dealloc(
+                        base82,
+                        len82 * (24 + 6 * ::core::mem::size_of::<*const u8>()),
+                        8,
+                    );
+                    let result83 = WitType { nodes: result82 };
+                    if layout11.size() != 0 {
+                        _rt::alloc::dealloc(result11.cast(), layout11);
+                    }
+                    if layout29.size() != 0 {
+                        _rt::alloc::dealloc(result29.cast(), layout29);
+                    }
+                    for (ptr, layout) in cleanup_list {
+                        if layout.size() != 0 {
+                            _rt::alloc::dealloc(ptr.cast(), layout);
+                        }
+                    }
+                    result83
+                }
+            }
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod wasi {
+    pub mod clocks {
+        /// WASI Monotonic Clock is a clock API intended to let users measure elapsed
+        /// time.
+        ///
+        /// It is intended to be portable at least between Unix-family platforms and
+        /// Windows.
+        ///
+        /// A monotonic clock is a clock which has an unspecified initial value, and
+        /// successive reads of the clock will produce non-decreasing values.
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod monotonic_clock {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            pub type Pollable = super::super::super::wasi::io::poll::Pollable;
+            /// An instant in time, in nanoseconds. An instant is relative to an
+            /// unspecified initial value, and can only be compared to instances from
+            /// the same monotonic-clock.
+            pub type Instant = u64;
+            /// A duration of time, in nanoseconds.
+            pub type Duration = u64;
+            #[allow(unused_unsafe, clippy::all)]
+            /// Read the current value of the clock.
+            ///
+            /// The clock is monotonic, therefore calling this function repeatedly will
+            /// produce a sequence of non-decreasing values.
+            pub fn now() -> Instant {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "now"]
+                        fn wit_import0() -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i64 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Query the resolution of the clock. Returns the duration of time
+            /// corresponding to a clock tick.
+            pub fn resolution() -> Duration {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "resolution"]
+                        fn wit_import0() -> i64;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0() -> i64 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0() };
+                    ret as u64
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Create a `pollable` which will resolve once the specified instant
+            /// has occurred.
+            pub fn subscribe_instant(when: Instant) -> Pollable {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "subscribe-instant"]
+                        fn wit_import0(_: i64) -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i64) -> i32 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0(_rt::as_i64(when)) };
+                    unsafe {
+                        super::super::super::wasi::io::poll::Pollable::from_handle(
+                            ret as u32,
+                        )
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Create a `pollable` that will resolve after the specified duration has
+            /// elapsed from the time this function is invoked.
+            pub fn subscribe_duration(when: Duration) -> Pollable {
+                unsafe {
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/monotonic-clock@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "subscribe-duration"]
+                        fn wit_import0(_: i64) -> i32;
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import0(_: i64) -> i32 {
+                        unreachable!()
+                    }
+                    let ret = unsafe { wit_import0(_rt::as_i64(when)) };
+                    unsafe {
+                        super::super::super::wasi::io::poll::Pollable::from_handle(
+                            ret as u32,
+                        )
+                    }
+                }
+            }
+        }
+        /// WASI Wall Clock is a clock API intended to let users query the current
+        /// time. The name "wall" makes an analogy to a "clock on the wall", which
+        /// is not necessarily monotonic as it may be reset.
+        ///
+        /// It is intended to be portable at least between Unix-family platforms and
+        /// Windows.
+        ///
+        /// A wall clock is a clock which measures the date and time according to
+        /// some external reference.
+        ///
+        /// External references may be reset, so this clock is not necessarily
+        /// monotonic, making it unsuitable for measuring elapsed time.
+        ///
+        /// It is intended for reporting the current date and time for humans.
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod wall_clock {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            /// A time and date in seconds plus nanoseconds.
+            #[repr(C)]
+            #[derive(Clone, Copy)]
+            pub struct Datetime {
+                pub seconds: u64,
+                pub nanoseconds: u32,
+            }
+            impl ::core::fmt::Debug for Datetime {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Datetime")
+                        .field("seconds", &self.seconds)
+                        .field("nanoseconds", &self.nanoseconds)
+                        .finish()
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Read the current value of the clock.
+            ///
+            /// This clock is not monotonic, therefore calling this function repeatedly
+            /// will not necessarily produce a sequence of non-decreasing values.
+            ///
+            /// The returned timestamps represent the number of seconds since
+            /// 1970-01-01T00:00:00Z, also known as [POSIX's Seconds Since the Epoch],
+            /// also known as [Unix Time].
+            ///
+            /// The nanoseconds field of the output is always less than 1000000000.
+            ///
+            /// [POSIX's Seconds Since the Epoch]: https://pubs.opengroup.org/onlinepubs/9699919799/xrat/V4_xbd_chap04.html#tag_21_04_16
+            /// [Unix Time]: https://en.wikipedia.org/wiki/Unix_time
+            pub fn now() -> Datetime {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/wall-clock@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "now"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i32>();
+                    let result4 = Datetime {
+                        seconds: l2 as u64,
+                        nanoseconds: l3 as u32,
+                    };
+                    result4
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Query the resolution of the clock.
+            ///
+            /// The nanoseconds field of the output is always less than 1000000000.
+            pub fn resolution() -> Datetime {
+                unsafe {
+                    #[repr(align(8))]
+                    struct RetArea([::core::mem::MaybeUninit<u8>; 16]);
+                    let mut ret_area = RetArea([::core::mem::MaybeUninit::uninit(); 16]);
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:clocks/wall-clock@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "resolution"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = *ptr0.add(0).cast::<i64>();
+                    let l3 = *ptr0.add(8).cast::<i32>();
+                    let result4 = Datetime {
+                        seconds: l2 as u64,
+                        nanoseconds: l3 as u32,
+                    };
+                    result4
+                }
+            }
+        }
+    }
+    pub mod io {
+        /// A poll API intended to let users wait for I/O events on multiple handles
+        /// at once.
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod poll {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            /// `pollable` represents a single I/O event which may be ready, or not.
+            #[derive(Debug)]
+            #[repr(transparent)]
+            pub struct Pollable {
+                handle: _rt::Resource<Pollable>,
+            }
+            impl Pollable {
+                #[doc(hidden)]
+                pub unsafe fn from_handle(handle: u32) -> Self {
+                    Self {
+                        handle: unsafe { _rt::Resource::from_handle(handle) },
+                    }
+                }
+                #[doc(hidden)]
+                pub fn take_handle(&self) -> u32 {
+                    _rt::Resource::take_handle(&self.handle)
+                }
+                #[doc(hidden)]
+                pub fn handle(&self) -> u32 {
+                    _rt::Resource::handle(&self.handle)
+                }
+            }
+            unsafe impl _rt::WasmResource for Pollable {
+                #[inline]
+                unsafe fn drop(_handle: u32) {
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unreachable!();
+                    #[cfg(target_arch = "wasm32")]
+                    {
+                        #[link(wasm_import_module = "wasi:io/poll@0.2.3")]
+                        unsafe extern "C" {
+                            #[link_name = "[resource-drop]pollable"]
+                            fn drop(_: u32);
+                        }
+                        unsafe { drop(_handle) };
+                    }
+                }
+            }
+            impl Pollable {
+                #[allow(unused_unsafe, clippy::all)]
+                /// Return the readiness of a pollable. This function never blocks.
+                ///
+                /// Returns `true` when the pollable is ready, and `false` otherwise.
+                pub fn ready(&self) -> bool {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "wasi:io/poll@0.2.3")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]pollable.ready"]
+                            fn wit_import0(_: i32) -> i32;
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) -> i32 {
+                            unreachable!()
+                        }
+                        let ret = unsafe { wit_import0((self).handle() as i32) };
+                        _rt::bool_lift(ret as u8)
+                    }
+                }
+            }
+            impl Pollable {
+                #[allow(unused_unsafe, clippy::all)]
+                /// `block` returns immediately if the pollable is ready, and otherwise
+                /// blocks until ready.
+                ///
+                /// This function is equivalent to calling `poll.poll` on a list
+                /// containing only this pollable.
+                pub fn block(&self) -> () {
+                    unsafe {
+                        #[cfg(target_arch = "wasm32")]
+                        #[link(wasm_import_module = "wasi:io/poll@0.2.3")]
+                        unsafe extern "C" {
+                            #[link_name = "[method]pollable.block"]
+                            fn wit_import0(_: i32);
+                        }
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unsafe extern "C" fn wit_import0(_: i32) {
+                            unreachable!()
+                        }
+                        unsafe { wit_import0((self).handle() as i32) };
+                    }
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Poll for completion on a set of pollables.
+            ///
+            /// This function takes a list of pollables, which identify I/O sources of
+            /// interest, and waits until one or more of the events is ready for I/O.
+            ///
+            /// The result `list<u32>` contains one or more indices of handles in the
+            /// argument list that is ready for I/O.
+            ///
+            /// This function traps if either:
+            /// - the list is empty, or:
+            /// - the list contains more elements than can be indexed with a `u32` value.
+            ///
+            /// A timeout can be implemented by adding a pollable from the
+            /// wasi-clocks API to the list.
+            ///
+            /// This function does not return a `result`; polling in itself does not
+            /// do any I/O so it doesn't fail. If any of the I/O sources identified by
+            /// the pollables has an error, it is indicated by marking the source as
+            /// being ready for I/O.
+            pub fn poll(in_: &[&Pollable]) -> _rt::Vec<u32> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 2 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 2
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = in_;
+                    let len0 = vec0.len();
+                    let layout0 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec0.len() * 4,
+                        4,
+                    );
+                    let result0 = if layout0.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout0).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout0);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec0.into_iter().enumerate() {
+                        let base = result0.add(i * 4);
+                        {
+                            *base.add(0).cast::<i32>() = (e).handle() as i32;
+                        }
+                    }
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:io/poll@0.2.3")]
+                    unsafe extern "C" {
+                        #[link_name = "poll"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(result0, len0, ptr1) };
+                    let l3 = *ptr1.add(0).cast::<*mut u8>();
+                    let l4 = *ptr1
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let len5 = l4;
+                    let result6 = _rt::Vec::from_raw_parts(l3.cast(), len5, len5);
+                    if layout0.size() != 0 {
+                        _rt::alloc::dealloc(result0.cast(), layout0);
+                    }
+                    result6
+                }
+            }
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod exports {
+    pub mod golem {
+        pub mod it {
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod api {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                /// How the coordinator finishes the saga after reserving all the items
+                #[repr(u8)]
+                #[derive(Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+                pub enum Outcome {
+                    Complete,
+                    Abort,
+                    Fail,
+                    LeaveRunning,
+                }
+                impl ::core::fmt::Debug for Outcome {
+                    fn fmt(
+                        &self,
+                        f: &mut ::core::fmt::Formatter<'_>,
+                    ) -> ::core::fmt::Result {
+                        match self {
+                            Outcome::Complete => {
+                                f.debug_tuple("Outcome::Complete").finish()
+                            }
+                            Outcome::Abort => f.debug_tuple("Outcome::Abort").finish(),
+                            Outcome::Fail => f.debug_tuple("Outcome::Fail").finish(),
+                            Outcome::LeaveRunning => {
+                                f.debug_tuple("Outcome::LeaveRunning").finish()
+                            }
+                        }
+                    }
+                }
+                impl Outcome {
+                    #[doc(hidden)]
+                    pub unsafe fn _lift(val: u8) -> Outcome {
+                        if !cfg!(debug_assertions) {
+                            return ::core::mem::transmute(val);
+                        }
+                        match val {
+                            0 => Outcome::Complete,
+                            1 => Outcome::Abort,
+                            2 => Outcome::Fail,
+                            3 => Outcome::LeaveRunning,
+                            _ => panic!("invalid enum discriminant"),
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_reserve_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    T::reserve(_rt::string_lift(bytes0));
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_release_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                ) {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    T::release(_rt::string_lift(bytes0));
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_get_log_cabi<T: Guest>() -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_log();
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    let vec3 = result0;
+                    let len3 = vec3.len();
+                    let layout3 = _rt::alloc::Layout::from_size_align_unchecked(
+                        vec3.len() * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result3 = if layout3.size() != 0 {
+                        let ptr = _rt::alloc::alloc(layout3).cast::<u8>();
+                        if ptr.is_null() {
+                            _rt::alloc::handle_alloc_error(layout3);
+                        }
+                        ptr
+                    } else {
+                        ::core::ptr::null_mut()
+                    };
+                    for (i, e) in vec3.into_iter().enumerate() {
+                        let base = result3
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let vec2 = (e.into_bytes()).into_boxed_slice();
+                            let ptr2 = vec2.as_ptr().cast::<u8>();
+                            let len2 = vec2.len();
+                            ::core::mem::forget(vec2);
+                            *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len2;
+                            *base.add(0).cast::<*mut u8>() = ptr2.cast_mut();
+                        }
+                    }
+                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len3;
+                    *ptr1.add(0).cast::<*mut u8>() = result3;
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_get_log<T: Guest>(arg0: *mut u8) {
+                    let l0 = *arg0.add(0).cast::<*mut u8>();
+                    let l1 = *arg0
+                        .add(::core::mem::size_of::<*const u8>())
+                        .cast::<usize>();
+                    let base4 = l0;
+                    let len4 = l1;
+                    for i in 0..len4 {
+                        let base = base4
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        {
+                            let l2 = *base.add(0).cast::<*mut u8>();
+                            let l3 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l2, l3, 1);
+                        }
+                    }
+                    _rt::cabi_dealloc(
+                        base4,
+                        len4 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_run_saga_cabi<T: Guest>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: *mut u8,
+                    arg3: usize,
+                    arg4: i32,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let base4 = arg2;
+                    let len4 = arg3;
+                    let mut result4 = _rt::Vec::with_capacity(len4);
+                    for i in 0..len4 {
+                        let base = base4
+                            .add(i * (2 * ::core::mem::size_of::<*const u8>()));
+                        let e4 = {
+                            let l1 = *base.add(0).cast::<*mut u8>();
+                            let l2 = *base
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            let len3 = l2;
+                            let bytes3 = _rt::Vec::from_raw_parts(l1.cast(), len3, len3);
+                            _rt::string_lift(bytes3)
+                        };
+                        result4.push(e4);
+                    }
+                    _rt::cabi_dealloc(
+                        base4,
+                        len4 * (2 * ::core::mem::size_of::<*const u8>()),
+                        ::core::mem::size_of::<*const u8>(),
+                    );
+                    let result5 = T::run_saga(
+                        _rt::string_lift(bytes0),
+                        result4,
+                        Outcome::_lift(arg4 as u8),
+                    );
+                    let ptr6 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result5 {
+                        Ok(_) => {
+                            *ptr6.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr6.add(0).cast::<u8>() = (1i32) as u8;
+                            let vec7 = (e.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr6
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr6
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr7.cast_mut();
+                        }
+                    };
+                    ptr6
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_run_saga<T: Guest>(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = *arg0
+                                .add(::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                    }
+                }
+                pub trait Guest {
+                    fn reserve(item: _rt::String) -> ();
+                    fn release(item: _rt::String) -> ();
+                    fn get_log() -> _rt::Vec<_rt::String>;
+                    fn run_saga(
+                        participant: _rt::String,
+                        items: _rt::Vec<_rt::String>,
+                        outcome: Outcome,
+                    ) -> Result<(), _rt::String>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_golem_it_api_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name = "golem:it/api#reserve")]
+                        unsafe extern "C" fn export_reserve(arg0 : * mut u8, arg1 :
+                        usize,) { unsafe { $($path_to_types)*::
+                        _export_reserve_cabi::<$ty > (arg0, arg1) } } #[unsafe
+                        (export_name = "golem:it/api#release")] unsafe extern "C" fn
+                        export_release(arg0 : * mut u8, arg1 : usize,) { unsafe {
+                        $($path_to_types)*:: _export_release_cabi::<$ty > (arg0, arg1) }
+                        } #[unsafe (export_name = "golem:it/api#get-log")] unsafe extern
+                        "C" fn export_get_log() -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_get_log_cabi::<$ty > () } } #[unsafe
+                        (export_name = "cabi_post_golem:it/api#get-log")] unsafe extern
+                        "C" fn _post_return_get_log(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_get_log::<$ty > (arg0) } }
+                        #[unsafe (export_name = "golem:it/api#run-saga")] unsafe extern
+                        "C" fn export_run_saga(arg0 : * mut u8, arg1 : usize, arg2 : *
+                        mut u8, arg3 : usize, arg4 : i32,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_run_saga_cabi::<$ty > (arg0, arg1,
+                        arg2, arg3, arg4) } } #[unsafe (export_name =
+                        "cabi_post_golem:it/api#run-saga")] unsafe extern "C" fn
+                        _post_return_run_saga(arg0 : * mut u8,) { unsafe {
+                        $($path_to_types)*:: __post_return_run_saga::<$ty > (arg0) } } };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_golem_it_api_cabi;
+                #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 3 * ::core::mem::size_of::<*const u8>()],
+                );
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 3
+                        * ::core::mem::size_of::<*const u8>()],
+                );
+            }
+        }
+    }
+}
+#[rustfmt::skip]
+mod _rt {
+    #![allow(dead_code, clippy::all)]
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
+        }
+    }
+    pub unsafe fn bool_lift(val: u8) -> bool {
+        if cfg!(debug_assertions) {
+            match val {
+                0 => false,
+                1 => true,
+                _ => panic!("invalid bool discriminant"),
+            }
+        } else {
+            val != 0
+        }
+    }
+    pub use alloc_crate::vec::Vec;
+    pub use alloc_crate::alloc;
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    pub use alloc_crate::string::String;
+    pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
+        if cfg!(debug_assertions) {
+            String::from_utf8(bytes).unwrap()
+        } else {
+            String::from_utf8_unchecked(bytes)
+        }
+    }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
+    pub fn as_i32<T: AsI32>(t: T) -> i32 {
+        t.as_i32()
+    }
+    pub trait AsI32 {
+        fn as_i32(self) -> i32;
+    }
+    impl<'a, T: Copy + AsI32> AsI32 for &'a T {
+        fn as_i32(self) -> i32 {
+            (*self).as_i32()
+        }
+    }
+    impl AsI32 for i32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u32 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u16 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for i8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for u8 {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for char {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    impl AsI32 for usize {
+        #[inline]
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+    }
+    pub fn as_f32<T: AsF32>(t: T) -> f32 {
+        t.as_f32()
+    }
+    pub trait AsF32 {
+        fn as_f32(self) -> f32;
+    }
+    impl<'a, T: Copy + AsF32> AsF32 for &'a T {
+        fn as_f32(self) -> f32 {
+            (*self).as_f32()
+        }
+    }
+    impl AsF32 for f32 {
+        #[inline]
+        fn as_f32(self) -> f32 {
+            self as f32
+        }
+    }
+    pub fn as_f64<T: AsF64>(t: T) -> f64 {
+        t.as_f64()
+    }
+    pub trait AsF64 {
+        fn as_f64(self) -> f64;
+    }
+    impl<'a, T: Copy + AsF64> AsF64 for &'a T {
+        fn as_f64(self) -> f64 {
+            (*self).as_f64()
+        }
+    }
+    impl AsF64 for f64 {
+        #[inline]
+        fn as_f64(self) -> f64 {
+            self as f64
+        }
+    }
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    pub unsafe fn char_lift(val: u32) -> char {
+        if cfg!(debug_assertions) {
+            core::char::from_u32(val).unwrap()
+        } else {
+            core::char::from_u32_unchecked(val)
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
+    extern crate alloc as alloc_crate;
+}
+/// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
+/// the root implementation of all generated traits.
+///
+/// For more information see the documentation of `wit_bindgen::generate!`.
+///
+/// ```rust
+/// # macro_rules! export{ ($($t:tt)*) => (); }
+/// # trait Guest {}
+/// struct MyType;
+///
+/// impl Guest for MyType {
+///     // ...
+/// }
+///
+/// export!(MyType);
+/// ```
+#[allow(unused_macros)]
+#[doc(hidden)]
+macro_rules! __export_saga_impl {
+    ($ty:ident) => {
+        self::export!($ty with_types_in self);
+    };
+    ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
+        $($path_to_types_root)*::
+        exports::golem::it::api::__export_golem_it_api_cabi!($ty with_types_in
+        $($path_to_types_root)*:: exports::golem::it::api);
+    };
+}
+#[doc(inline)]
+pub(crate) use __export_saga_impl as export;
+#[cfg(target_arch = "wasm32")]
+#[unsafe(link_section = "component-type:wit-bindgen:0.41.0:golem:it:saga:encoded world")]
+#[doc(hidden)]
+#[allow(clippy::octal_escapes)]
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 6083] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xc8.\x01A\x02\x01A\x15\
+\x01B\x0a\x04\0\x08pollable\x03\x01\x01h\0\x01@\x01\x04self\x01\0\x7f\x04\0\x16[\
+method]pollable.ready\x01\x02\x01@\x01\x04self\x01\x01\0\x04\0\x16[method]pollab\
+le.block\x01\x03\x01p\x01\x01py\x01@\x01\x02in\x04\0\x05\x04\0\x04poll\x01\x06\x03\
+\0\x12wasi:io/poll@0.2.3\x05\0\x02\x03\0\0\x08pollable\x01B\x0f\x02\x03\x02\x01\x01\
+\x04\0\x08pollable\x03\0\0\x01w\x04\0\x07instant\x03\0\x02\x01w\x04\0\x08duratio\
+n\x03\0\x04\x01@\0\0\x03\x04\0\x03now\x01\x06\x01@\0\0\x05\x04\0\x0aresolution\x01\
+\x07\x01i\x01\x01@\x01\x04when\x03\0\x08\x04\0\x11subscribe-instant\x01\x09\x01@\
+\x01\x04when\x05\0\x08\x04\0\x12subscribe-duration\x01\x0a\x03\0!wasi:clocks/mon\
+otonic-clock@0.2.3\x05\x02\x01B\x05\x01r\x02\x07secondsw\x0bnanosecondsy\x04\0\x08\
+datetime\x03\0\0\x01@\0\0\x01\x04\0\x03now\x01\x02\x04\0\x0aresolution\x01\x02\x03\
+\0\x1cwasi:clocks/wall-clock@0.2.3\x05\x03\x02\x03\0\x02\x08datetime\x01B[\x02\x03\
+\x02\x01\x04\x04\0\x08datetime\x03\0\0\x02\x03\x02\x01\x01\x04\0\x08pollable\x03\
+\0\x02\x01r\x02\x09high-bitsw\x08low-bitsw\x04\0\x04uuid\x03\0\x04\x01r\x01\x04u\
+uid\x05\x04\0\x0ccomponent-id\x03\0\x06\x01r\x02\x0ccomponent-id\x07\x0bworker-n\
+ames\x04\0\x09worker-id\x03\0\x08\x01z\x04\0\x0anode-index\x03\0\x0a\x01w\x04\0\x0b\
+resource-id\x03\0\x0c\x01m\x02\x05owned\x08borrowed\x04\0\x0dresource-mode\x03\0\
+\x0e\x01o\x02s\x0b\x01p\x10\x01k\x0b\x01o\x02s\x12\x01p\x13\x01ps\x01p\x0b\x01o\x02\
+\x12\x12\x01o\x02\x0d\x0f\x01q\x16\x0brecord-type\x01\x11\0\x0cvariant-type\x01\x14\
+\0\x09enum-type\x01\x15\0\x0aflags-type\x01\x15\0\x0atuple-type\x01\x16\0\x09lis\
+t-type\x01\x0b\0\x0boption-type\x01\x0b\0\x0bresult-type\x01\x17\0\x0cprim-u8-ty\
+pe\0\0\x0dprim-u16-type\0\0\x0dprim-u32-type\0\0\x0dprim-u64-type\0\0\x0cprim-s8\
+-type\0\0\x0dprim-s16-type\0\0\x0dprim-s32-type\0\0\x0dprim-s64-type\0\0\x0dprim\
+-f32-type\0\0\x0dprim-f64-type\0\0\x0eprim-char-type\0\0\x0eprim-bool-type\0\0\x10\
+prim-string-type\0\0\x0bhandle-type\x01\x18\0\x04\0\x0dwit-type-node\x03\0\x19\x01\
+ks\x01r\x03\x04name\x1b\x05owner\x1b\x04type\x1a\x04\0\x13named-wit-type-node\x03\
+\0\x1c\x01p\x1d\x01r\x01\x05nodes\x1e\x04\0\x08wit-type\x03\0\x1f\x01r\x01\x05va\
+lues\x04\0\x03uri\x03\0!\x01o\x02y\x12\x01p\x7f\x01j\x01\x12\x01\x12\x01o\x02\"w\
+\x01q\x16\x0crecord-value\x01\x16\0\x0dvariant-value\x01#\0\x0aenum-value\x01y\0\
+\x0bflags-value\x01$\0\x0btuple-value\x01\x16\0\x0alist-value\x01\x16\0\x0coptio\
+n-value\x01\x12\0\x0cresult-value\x01%\0\x07prim-u8\x01}\0\x08prim-u16\x01{\0\x08\
+prim-u32\x01y\0\x08prim-u64\x01w\0\x07prim-s8\x01~\0\x08prim-s16\x01|\0\x08prim-\
+s32\x01z\0\x08prim-s64\x01x\0\x0cprim-float32\x01v\0\x0cprim-float64\x01u\0\x09p\
+rim-char\x01t\0\x09prim-bool\x01\x7f\0\x0bprim-string\x01s\0\x06handle\x01&\0\x04\
+\0\x08wit-node\x03\0'\x01p(\x01r\x01\x05nodes)\x04\0\x09wit-value\x03\0*\x01r\x02\
+\x05value+\x03typ\x20\x04\0\x0evalue-and-type\x03\0,\x01q\x04\x0eprotocol-error\x01\
+s\0\x06denied\x01s\0\x09not-found\x01s\0\x15remote-internal-error\x01s\0\x04\0\x09\
+rpc-error\x03\0.\x04\0\x08wasm-rpc\x03\x01\x04\0\x14future-invoke-result\x03\x01\
+\x04\0\x12cancellation-token\x03\x01\x01i0\x01@\x01\x09worker-id\x09\03\x04\0\x15\
+[constructor]wasm-rpc\x014\x01@\x01\x0ccomponent-id\x07\03\x04\0\x1a[static]wasm\
+-rpc.ephemeral\x015\x01h0\x01p+\x01j\x01+\x01/\x01@\x03\x04self6\x0dfunction-nam\
+es\x0ffunction-params7\08\x04\0![method]wasm-rpc.invoke-and-await\x019\x01j\0\x01\
+/\x01@\x03\x04self6\x0dfunction-names\x0ffunction-params7\0:\x04\0\x17[method]wa\
+sm-rpc.invoke\x01;\x01i1\x01@\x03\x04self6\x0dfunction-names\x0ffunction-params7\
+\0<\x04\0'[method]wasm-rpc.async-invoke-and-await\x01=\x01@\x04\x04self6\x0esche\
+duled-time\x01\x0dfunction-names\x0ffunction-params7\x01\0\x04\0$[method]wasm-rp\
+c.schedule-invocation\x01>\x01i2\x01@\x04\x04self6\x0escheduled-time\x01\x0dfunc\
+tion-names\x0ffunction-params7\0?\x04\0/[method]wasm-rpc.schedule-cancelable-inv\
+ocation\x01@\x01h1\x01i\x03\x01@\x01\x04self\xc1\0\0\xc2\0\x04\0&[method]future-\
+invoke-result.subscribe\x01C\x01k8\x01@\x01\x04self\xc1\0\0\xc4\0\x04\0\x20[meth\
+od]future-invoke-result.get\x01E\x01h2\x01@\x01\x04self\xc6\0\x01\0\x04\0![metho\
+d]cancellation-token.cancel\x01G\x01j\x01\x05\x01s\x01@\x01\x04uuids\0\xc8\0\x04\
+\0\x0aparse-uuid\x01I\x01@\x01\x04uuid\x05\0s\x04\0\x0euuid-to-string\x01J\x01@\x01\
+\x03vnt-\0+\x04\0\x0dextract-value\x01K\x01@\x01\x03vnt-\0\x20\x04\0\x0cextract-\
+type\x01L\x03\0\x15golem:rpc/types@0.2.2\x05\x05\x02\x03\0\x01\x08duration\x02\x03\
+\0\x03\x0ccomponent-id\x02\x03\0\x03\x04uuid\x02\x03\0\x03\x0evalue-and-type\x02\
+\x03\0\x03\x09worker-id\x01B\x89\x01\x02\x03\x02\x01\x06\x04\0\x08duration\x03\0\
+\0\x02\x03\x02\x01\x07\x04\0\x0ccomponent-id\x03\0\x02\x02\x03\x02\x01\x08\x04\0\
+\x04uuid\x03\0\x04\x02\x03\x02\x01\x09\x04\0\x0evalue-and-type\x03\0\x06\x02\x03\
+\x02\x01\x0a\x04\0\x09worker-id\x03\0\x08\x01w\x04\0\x0boplog-index\x03\0\x0a\x01\
+r\x02\x09worker-id\x09\x09oplog-idx\x0b\x04\0\x0apromise-id\x03\0\x0c\x01w\x04\0\
+\x11component-version\x03\0\x0e\x01r\x01\x05values\x04\0\x0aaccount-id\x03\0\x10\
+\x01r\x01\x04uuid\x05\x04\0\x0aproject-id\x03\0\x12\x01ku\x01r\x05\x0cmax-attemp\
+tsy\x09min-delay\x01\x09max-delay\x01\x0amultiplieru\x11max-jitter-factor\x14\x04\
+\0\x0cretry-policy\x03\0\x15\x01q\x03\x0fpersist-nothing\0\0\x1bpersist-remote-s\
+ide-effects\0\0\x05smart\0\0\x04\0\x11persistence-level\x03\0\x17\x01m\x02\x09au\
+tomatic\x0esnapshot-based\x04\0\x0bupdate-mode\x03\0\x19\x01m\x06\x05equal\x09no\
+t-equal\x0dgreater-equal\x07greater\x0aless-equal\x04less\x04\0\x11filter-compar\
+ator\x03\0\x1b\x01m\x04\x05equal\x09not-equal\x04like\x08not-like\x04\0\x18strin\
+g-filter-comparator\x03\0\x1d\x01m\x07\x07running\x04idle\x09suspended\x0binterr\
+upted\x08retrying\x06failed\x06exited\x04\0\x0dworker-status\x03\0\x1f\x01r\x02\x0a\
+comparator\x1e\x05values\x04\0\x12worker-name-filter\x03\0!\x01r\x02\x0acomparat\
+or\x1c\x05value\x20\x04\0\x14worker-status-filter\x03\0#\x01r\x02\x0acomparator\x1c\
+\x05valuew\x04\0\x15worker-version-filter\x03\0%\x01r\x02\x0acomparator\x1c\x05v\
+aluew\x04\0\x18worker-created-at-filter\x03\0'\x01r\x03\x04names\x0acomparator\x1e\
+\x05values\x04\0\x11worker-env-filter\x03\0)\x01r\x03\x04names\x0acomparator\x1e\
+\x05values\x04\0\x1eworker-wasi-config-vars-filter\x03\0+\x01q\x06\x04name\x01\"\
+\0\x06status\x01$\0\x07version\x01&\0\x0acreated-at\x01(\0\x03env\x01*\0\x10wasi\
+-config-vars\x01,\0\x04\0\x16worker-property-filter\x03\0-\x01p.\x01r\x01\x07fil\
+ters/\x04\0\x11worker-all-filter\x03\00\x01p1\x01r\x01\x07filters2\x04\0\x11work\
+er-any-filter\x03\03\x01ps\x01o\x02ss\x01p6\x01r\x07\x09worker-id\x09\x04args5\x03\
+env7\x10wasi-config-vars7\x06status\x20\x11component-versionw\x0bretry-countw\x04\
+\0\x0fworker-metadata\x03\08\x04\0\x0bget-workers\x03\x01\x01q\x02\x15revert-to-\
+oplog-index\x01\x0b\0\x17revert-last-invocations\x01w\0\x04\0\x14revert-worker-t\
+arget\x03\0;\x01m\x02\x08original\x06forked\x04\0\x0bfork-result\x03\0=\x01p}\x01\
+q\x02\x09completed\x01?\0\x09timed-out\0\0\x04\0\x14await-promise-result\x03\0@\x01\
+k4\x01i:\x01@\x03\x0ccomponent-id\x03\x06filter\xc2\0\x07precise\x7f\0\xc3\0\x04\
+\0\x18[constructor]get-workers\x01D\x01h:\x01p9\x01k\xc6\0\x01@\x01\x04self\xc5\0\
+\0\xc7\0\x04\0\x1c[method]get-workers.get-next\x01H\x01@\0\0\x0d\x04\0\x0ecreate\
+-promise\x01I\x01@\x01\x0apromise-id\x0d\0?\x04\0\x0dawait-promise\x01J\x01@\x02\
+\x0apromise-id\x0d\x07timeout\x01\0\xc1\0\x04\0\x1aawait-promise-with-timeout\x01\
+K\x01k?\x01@\x01\x0apromise-id\x0d\0\xcc\0\x04\0\x0cpoll-promise\x01M\x01@\x02\x0a\
+promise-id\x0d\x04data?\0\x7f\x04\0\x10complete-promise\x01N\x01@\x01\x0apromise\
+-id\x0d\x01\0\x04\0\x0edelete-promise\x01O\x01@\0\0\x0b\x04\0\x0fget-oplog-index\
+\x01P\x01@\x01\x09oplog-idx\x0b\x01\0\x04\0\x0fset-oplog-index\x01Q\x01@\x01\x08\
+replicas}\x01\0\x04\0\x0coplog-commit\x01R\x04\0\x14mark-begin-operation\x01P\x01\
+@\x01\x05begin\x0b\x01\0\x04\0\x12mark-end-operation\x01S\x01@\0\0\x16\x04\0\x10\
+get-retry-policy\x01T\x01@\x01\x10new-retry-policy\x16\x01\0\x04\0\x10set-retry-\
+policy\x01U\x01@\0\0\x18\x04\0\x1bget-oplog-persistence-level\x01V\x01@\x01\x15n\
+ew-persistence-level\x18\x01\0\x04\0\x1bset-oplog-persistence-level\x01W\x01@\0\0\
+\x7f\x04\0\x14get-idempotence-mode\x01X\x01@\x01\x0aidempotent\x7f\x01\0\x04\0\x14\
+set-idempotence-mode\x01Y\x01@\0\0\x05\x04\0\x18generate-idempotency-key\x01Z\x01\
+@\x03\x09worker-id\x09\x0etarget-version\x0f\x04mode\x1a\x01\0\x04\0\x0dupdate-w\
+orker\x01[\x01@\0\09\x04\0\x11get-self-metadata\x01\\\x01k9\x01@\x01\x09worker-i\
+d\x09\0\xdd\0\x04\0\x13get-worker-metadata\x01^\x01@\x03\x10source-worker-id\x09\
+\x10target-worker-id\x09\x11oplog-idx-cut-off\x0b\x01\0\x04\0\x0bfork-worker\x01\
+_\x01@\x02\x09worker-id\x09\x0drevert-target<\x01\0\x04\0\x0drevert-worker\x01`\x01\
+@\0\07\x04\0\x0aget-labels\x01a\x01@\x02\x03keys\x05values\x01\0\x04\0\x09set-la\
+bel\x01b\x01@\x01\x03keys\x01\0\x04\0\x0cremove-label\x01c\x01k\x03\x01@\x01\x13\
+component-references\0\xe4\0\x04\0\x14resolve-component-id\x01e\x01k\x09\x01@\x02\
+\x13component-references\x0bworker-names\0\xe6\0\x04\0\x11resolve-worker-id\x01g\
+\x04\0\x18resolve-worker-id-strict\x01g\x01@\x01\x08new-names\0>\x04\0\x04fork\x01\
+h\x03\0\x14golem:api/host@1.1.7\x05\x0b\x01B\x12\x02\x03\x02\x01\x08\x04\0\x04uu\
+id\x03\0\0\x02\x03\x02\x01\x09\x04\0\x0evalue-and-type\x03\0\x02\x02\x03\x02\x01\
+\x0a\x04\0\x09worker-id\x03\0\x04\x01r\x01\x04uuid\x01\x04\0\x07saga-id\x03\0\x06\
+\x01@\0\0\x07\x04\0\x0abegin-saga\x01\x08\x01p\x03\x01j\0\x01s\x01@\x04\x07saga-\
+id\x07\x09worker-id\x05\x0dfunction-names\x0ffunction-params\x09\0\x0a\x04\0\x15\
+register-compensation\x01\x0b\x01@\x01\x07saga-id\x07\0\x0a\x04\0\x0dcomplete-sa\
+ga\x01\x0c\x01@\x02\x07saga-id\x07\x06reasons\0\x0a\x04\0\x0aabort-saga\x01\x0d\x03\
+\0\x14golem:api/saga@1.1.7\x05\x0c\x01B\x0b\x01m\x04\x08complete\x05abort\x04fai\
+l\x0dleave-running\x04\0\x07outcome\x03\0\0\x01@\x01\x04items\x01\0\x04\0\x07res\
+erve\x01\x02\x04\0\x07release\x01\x02\x01ps\x01@\0\0\x03\x04\0\x07get-log\x01\x04\
+\x01j\0\x01s\x01@\x03\x0bparticipants\x05items\x03\x07outcome\x01\0\x05\x04\0\x08\
+run-saga\x01\x06\x04\0\x0cgolem:it/api\x05\x0d\x04\0\x0dgolem:it/saga\x04\0\x0b\x0a\
+\x01\0\x04saga\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x07\
+0.227.1\x10wit-bindgen-rust\x060.41.0";
+#[inline(never)]
+#[doc(hidden)]
+pub fn __link_custom_section_describing_imports() {
+    wit_bindgen_rt::maybe_link_cabi_realloc();
+}
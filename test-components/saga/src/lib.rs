@@ -0,0 +1,81 @@
+#[allow(static_mut_refs)]
+mod bindings;
+
+use crate::bindings::exports::golem::it::api::{Guest, Outcome};
+use crate::bindings::golem::api::host::get_self_metadata;
+use crate::bindings::golem::api::saga;
+use crate::bindings::golem::rpc::types::{
+    NamedWitTypeNode, ValueAndType, WasmRpc, WitNode, WitType, WitTypeNode, WitValue, WorkerId,
+};
+
+struct State {
+    log: Vec<String>,
+}
+
+static mut STATE: State = State { log: Vec::new() };
+
+fn with_state<T>(f: impl FnOnce(&mut State) -> T) -> T {
+    unsafe { f(&mut STATE) }
+}
+
+struct Component;
+
+impl Guest for Component {
+    fn reserve(item: String) {
+        println!("Reserving {item}");
+        with_state(|state| state.log.push(format!("reserve {item}")));
+    }
+
+    fn release(item: String) {
+        println!("Releasing {item}");
+        with_state(|state| state.log.push(format!("release {item}")));
+    }
+
+    fn get_log() -> Vec<String> {
+        with_state(|state| state.log.clone())
+    }
+
+    fn run_saga(participant: String, items: Vec<String>, outcome: Outcome) -> Result<(), String> {
+        let participant = WorkerId {
+            component_id: get_self_metadata().worker_id.component_id,
+            worker_name: participant,
+        };
+        let rpc = WasmRpc::new(&participant);
+
+        let saga_id = saga::begin_saga();
+        for item in items {
+            rpc.invoke_and_await("golem:it/api.{reserve}", &[string_value(&item)])
+                .map_err(|err| format!("Failed to reserve {item}: {err:?}"))?;
+            saga::register_compensation(
+                saga_id,
+                &participant,
+                "golem:it/api.{release}",
+                &[ValueAndType {
+                    value: string_value(&item),
+                    typ: WitType {
+                        nodes: vec![NamedWitTypeNode {
+                            name: None,
+                            owner: None,
+                            type_: WitTypeNode::PrimStringType,
+                        }],
+                    },
+                }],
+            )?;
+        }
+
+        match outcome {
+            Outcome::Complete => saga::complete_saga(saga_id),
+            Outcome::Abort => saga::abort_saga(saga_id, "Aborted by the coordinator"),
+            Outcome::Fail => panic!("The coordinator failed"),
+            Outcome::LeaveRunning => Ok(()),
+        }
+    }
+}
+
+fn string_value(value: &str) -> WitValue {
+    WitValue {
+        nodes: vec![WitNode::PrimString(value.to_string())],
+    }
+}
+
+bindings::export!(Component with_types_in bindings);